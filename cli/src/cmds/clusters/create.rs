@@ -207,7 +207,7 @@ pub async fn provision_local_query_service(
             );
             writer.write_ok(
                 format!(
-                    "To process HTTP REST queries, run: curl --location --request POST '{}:{}/v1/statement/' --header 'Content-Type: text/plain' --data-raw 'your SQL'",
+                    "To process HTTP REST queries, run: curl -u root: --location --request POST '{}:{}/v1/statement/' --header 'Content-Type: text/plain' --data-raw 'your SQL'",
                     query_config.config.query.http_handler_host,
                     query_config.config.query.http_handler_port
                 )
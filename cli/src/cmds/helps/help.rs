@@ -76,6 +76,19 @@ impl Command for HelpCommand {
             "\\admin",
             "Switch to cluster administration mode, you could profile/view/update databend cluster",
         );
+        writer.write_ok("Query commands:".to_string());
+        writer.writeln_width(
+            "\\format",
+            "Switch the format of the query results, one of table, csv and json",
+        );
+        writer.writeln_width(
+            "\\progress",
+            "Toggle the progress bar of the queries, showing the rows and bytes read",
+        );
+        writer.writeln_width(
+            "\\upload",
+            "Upload local files to a stage, for example: \\upload foo.csv @my_stage/path",
+        );
         writer.write_ok("Admin commands:".to_string());
         writer.writeln(&table.trim_fmt());
         Ok(())
@@ -27,6 +27,7 @@ mod packages;
 mod processor;
 mod queries;
 mod root;
+mod uploads;
 mod ups;
 mod versions;
 mod writer;
@@ -43,8 +44,11 @@ pub use packages::list::ListCommand;
 pub use packages::package::PackageCommand;
 pub use packages::switch::SwitchCommand;
 pub use processor::Processor;
+pub use queries::output::OutputFormat;
 pub use queries::query::build_query_endpoint;
 pub use root::RootCommand;
 pub use status::Status;
+pub use uploads::upload::parse_stage_location;
+pub use uploads::upload::UploadCommand;
 pub use versions::version::VersionCommand;
 pub use writer::Writer;
@@ -21,8 +21,10 @@ use rustyline::Editor;
 use crate::cmds::command::Command;
 use crate::cmds::config::Mode;
 use crate::cmds::loads::load::LoadCommand;
+use crate::cmds::queries::output::OutputFormat;
 use crate::cmds::queries::query::QueryCommand;
 use crate::cmds::root::RootCommand;
+use crate::cmds::uploads::upload::UploadCommand;
 use crate::cmds::ups::up::UpCommand;
 use crate::cmds::ClusterCommand;
 use crate::cmds::CommentCommand;
@@ -42,6 +44,7 @@ pub struct Processor {
     comment: CommentCommand,
     help: HelpCommand,
     query: QueryCommand,
+    upload: UploadCommand,
 }
 
 enum MultilineType {
@@ -60,6 +63,7 @@ impl Processor {
             Box::new(PackageCommand::create(conf.clone())),
             Box::new(ClusterCommand::create(conf.clone())),
             Box::new(UpCommand::create(conf.clone())),
+            Box::new(UploadCommand::create(conf.clone())),
             Box::new(LoadCommand::create(conf.clone())),
         ];
         let help_command = HelpCommand::create(admin_commands.clone());
@@ -69,7 +73,8 @@ impl Processor {
             admin_commands,
             comment: CommentCommand::create(),
             help: help_command,
-            query: QueryCommand::create(conf),
+            query: QueryCommand::create(conf.clone()),
+            upload: UploadCommand::create(conf),
         }
     }
 
@@ -199,6 +204,36 @@ impl Processor {
             self.env.load_mode(Mode::Admin);
             return Ok(());
         }
+        if let Some(arg) = line.trim().strip_prefix("\\format") {
+            match arg.trim().parse::<OutputFormat>() {
+                Ok(format) => {
+                    self.query.set_format(format);
+                    writeln!(writer, "Output format switched to {:?}", format).unwrap();
+                }
+                Err(e) => writer.write_err(e.to_string()),
+            }
+            return Ok(());
+        }
+        if line.to_lowercase().trim().eq("\\progress") {
+            let progress = !self.query.progress();
+            self.query.set_progress(progress);
+            let state = if progress { "enabled" } else { "disabled" };
+            writeln!(writer, "Query progress {}", state).unwrap();
+            return Ok(());
+        }
+        // \upload <file>... @stage[/path]
+        if let Some(arg) = line.trim().strip_prefix("\\upload") {
+            let mut args = arg.split_whitespace().collect::<Vec<_>>();
+            match args.pop() {
+                Some(stage) if !args.is_empty() => {
+                    let args = format!("upload {} --stage {}", args.join(" "), stage);
+                    self.upload.exec(&mut writer, args).await?;
+                }
+                _ => writer.write_err("usage: \\upload <file>... @stage[/path]".to_string()),
+            }
+            writer.flush()?;
+            return Ok(());
+        }
 
         if self.comment.is(&*line) {
             self.comment
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use comfy_table::Cell;
+use comfy_table::Color;
+use comfy_table::Table;
+use common_datavalues::DataSchemaRef;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::error::CliError;
+use crate::error::Result;
+
+// The formats to print the query results in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<OutputFormat, &'static str> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err("no match for output format, supported formats: table, csv, json"),
+        }
+    }
+}
+
+impl OutputFormat {
+    pub fn format(&self, schema: Option<&DataSchemaRef>, data: &[Vec<Value>]) -> Result<String> {
+        if data.is_empty() {
+            return Ok("".to_string());
+        }
+        let names: Vec<String> = schema
+            .map(|s| s.fields().iter().map(|f| f.name().clone()).collect())
+            .unwrap_or_else(Vec::new);
+        match self {
+            OutputFormat::Table => Ok(format_table(&names, data)),
+            OutputFormat::Csv => format_csv(&names, data),
+            OutputFormat::Json => format_json(&names, data),
+        }
+    }
+}
+
+fn format_table(names: &[String], data: &[Vec<Value>]) -> String {
+    let mut table = Table::new();
+    table.load_preset("||--+-++|    ++++++");
+    if !names.is_empty() {
+        table.set_header(
+            names
+                .iter()
+                .map(|name| Cell::new(name.as_str()).fg(Color::Green)),
+        );
+    }
+    for row in data {
+        table.add_row(row.iter().map(|elem| Cell::new(elem.to_string())));
+    }
+    table.trim_fmt()
+}
+
+// Unlike the json rendering, the strings are written unquoted and the NULLs as empty fields.
+fn csv_field(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_string(),
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+fn format_csv(names: &[String], data: &[Vec<Value>]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    let write_err = |e: csv::Error| CliError::Unknown(format!("cannot write csv: {:?}", e));
+    if !names.is_empty() {
+        writer.write_record(names).map_err(write_err)?;
+    }
+    for row in data {
+        writer
+            .write_record(row.iter().map(csv_field))
+            .map_err(write_err)?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| CliError::Unknown(format!("cannot write csv: {:?}", e)))?;
+    Ok(String::from_utf8_lossy(&bytes).trim_end().to_string())
+}
+
+// One object per line, keyed by the column names.
+fn format_json(names: &[String], data: &[Vec<Value>]) -> Result<String> {
+    let mut lines = Vec::with_capacity(data.len());
+    for row in data {
+        let mut object = Map::new();
+        for (i, value) in row.iter().enumerate() {
+            let name = names.get(i).cloned().unwrap_or_else(|| format!("_{}", i));
+            object.insert(name, value.clone());
+        }
+        lines.push(serde_json::to_string(&Value::Object(object))?);
+    }
+    Ok(lines.join("\n"))
+}
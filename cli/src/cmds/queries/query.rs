@@ -23,14 +23,18 @@ use clap::App;
 use clap::AppSettings;
 use clap::Arg;
 use clap::ArgMatches;
-use comfy_table::Cell;
-use comfy_table::Color;
-use comfy_table::Table;
 use common_base::ProgressValues;
 use common_datavalues::DataSchemaRef;
+use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::QueryStats;
+use databend_query::servers::http::v1::UploadToStageResponse;
+use http::header::AUTHORIZATION;
 use http::HeaderMap;
+use http::HeaderValue;
 use http::StatusCode;
 use http::Uri;
+use indicatif::ProgressBar;
+use indicatif::ProgressStyle;
 use lexical_util::num::AsPrimitive;
 use num_format::Locale;
 use num_format::ToFormattedString;
@@ -40,6 +44,7 @@ use serde_json::Value;
 
 use crate::cmds::clusters::cluster::ClusterProfile;
 use crate::cmds::command::Command;
+use crate::cmds::queries::output::OutputFormat;
 use crate::cmds::status::LocalRuntime;
 use crate::cmds::Config;
 use crate::cmds::Status;
@@ -50,11 +55,29 @@ use crate::error::Result;
 #[derive(Clone)]
 pub struct QueryCommand {
     conf: Config,
+    format: OutputFormat,
+    progress: bool,
 }
 
 impl QueryCommand {
     pub fn create(conf: Config) -> Self {
-        QueryCommand { conf }
+        QueryCommand {
+            conf,
+            format: OutputFormat::Table,
+            progress: false,
+        }
+    }
+
+    pub fn set_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    pub fn set_progress(&mut self, progress: bool) {
+        self.progress = progress;
+    }
+
+    pub fn progress(&self) -> bool {
+        self.progress
     }
 
     pub fn default() -> Self {
@@ -93,6 +116,13 @@ impl QueryCommand {
                     }
                 };
 
+                let format = match args.value_of("format") {
+                    Some(_) => args.value_of_t::<OutputFormat>("format").map_err(|e| {
+                        CliError::Unknown(format!("Cannot parse the output format: {}", e))
+                    })?,
+                    None => self.format,
+                };
+                let progress = self.progress || args.is_present("progress");
                 let res = build_query_endpoint(&status);
 
                 if let Ok((cli, url)) = res {
@@ -103,8 +133,15 @@ impl QueryCommand {
                         .collect::<Vec<String>>()
                     {
                         writer.write_debug(format!("Execute query {} on {}", query.clone(), url));
-                        if let Err(e) =
-                            query_writer(&cli, url.as_str(), query.clone(), writer).await
+                        if let Err(e) = query_writer(
+                            &cli,
+                            url.as_str(),
+                            query.clone(),
+                            format,
+                            progress,
+                            writer,
+                        )
+                        .await
                         {
                             writer.write_err(format!("Query {} execution error: {:?}", query, e));
                         }
@@ -166,13 +203,22 @@ async fn query_writer(
     cli: &reqwest::Client,
     url: &str,
     query: String,
+    format: OutputFormat,
+    progress: bool,
     writer: &mut Writer,
 ) -> Result<()> {
     let start = std::time::Instant::now();
-    match execute_query(cli, url, query).await {
+    match execute_query(cli, url, query, format, progress).await {
         Ok((res, stats)) => {
             let elapsed = start.elapsed();
-            writer.writeln(res.as_str());
+            if !res.is_empty() {
+                writer.writeln(res.as_str());
+            }
+            // Only the tables are followed by the stats, the csv and json outputs are kept
+            // parsable.
+            if format != OutputFormat::Table {
+                return Ok(());
+            }
             if let Some(stat) = stats {
                 let time = elapsed.as_millis() as f64 / 1000f64;
                 let byte_per_sec = byte_unit::Byte::from_unit(
@@ -209,7 +255,12 @@ fn build_endpoint(status: &Status, path: &str) -> Result<(reqwest::Client, Strin
     let query_configs = status.get_local_query_configs();
 
     let (_, query) = query_configs.get(0).expect("cannot find query configs");
+    // The http handler requires credentials, the local cluster is queried as the builtin root
+    // user, which has no password.
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("Basic cm9vdDo="));
     let client = reqwest::Client::builder()
+        .default_headers(headers)
         .build()
         .expect("Cannot build query client");
 
@@ -239,75 +290,124 @@ pub fn build_load_endpoint(status: &Status) -> Result<(reqwest::Client, String)>
     build_endpoint(status, "/v1/streaming_load")
 }
 
-pub async fn execute_query_json(
+pub fn build_upload_endpoint(status: &Status) -> Result<(reqwest::Client, String)> {
+    build_endpoint(status, "/v1/upload_to_stage")
+}
+
+// The `scheme://host:port` of an endpoint, to resolve the uris returned by the http handler.
+fn endpoint_base(url: &str) -> Result<String> {
+    let uri = url
+        .parse::<Uri>()
+        .map_err(|e| CliError::Unknown(format!("cannot parse url {}: {:?}", url, e)))?;
+    match (uri.scheme_str(), uri.authority()) {
+        (Some(scheme), Some(authority)) => Ok(format!("{}://{}", scheme, authority)),
+        _ => Err(CliError::Unknown(format!("not an absolute url: {}", url))),
+    }
+}
+
+fn progress_message(progress: &ProgressValues) -> String {
+    format!(
+        "read rows: {}, read bytes: {}",
+        progress.read_rows.to_formatted_string(&Locale::en),
+        byte_unit::Byte::from_bytes(progress.read_bytes as u128).get_appropriate_unit(false)
+    )
+}
+
+async fn get_query_response(cli: &reqwest::Client, uri: String) -> Result<QueryResponse> {
+    cli.get(uri)
+        .send()
+        .await
+        .map_err(|e| CliError::Unknown(format!("cannot get from http handler: {:?}", e)))?
+        .json::<QueryResponse>()
+        .await
+        .map_err(|e| CliError::Unknown(format!("Cannot retrieve query result: {:?}", e)))
+}
+
+/// Runs the query and fetches all the pages of its result, following the `next_uri` of the
+/// responses. The scan progress of the query is shown on the bar while the pages are polled.
+pub async fn execute_query_pages(
     cli: &reqwest::Client,
     url: &str,
     query: String,
-) -> Result<(
-    Option<DataSchemaRef>,
-    Arc<Vec<Vec<Value>>>,
-    databend_query::servers::http::v1::QueryStats,
-)> {
-    let ans = cli
-        .post(url)
-        .json(&json!({"sql": query.clone()}))
+    bar: Option<&ProgressBar>,
+) -> Result<(Option<DataSchemaRef>, Vec<Vec<Value>>, QueryStats)> {
+    let base = endpoint_base(url)?;
+    // Without the bar the first page is waited for synchronously, as the url asks for.
+    let post_url = match bar {
+        Some(_) => format!("{}/v1/query?wait_time=1", base),
+        None => url.to_string(),
+    };
+    let mut resp = cli
+        .post(post_url)
+        .json(&json!({ "sql": query }))
         .send()
         .await
-        .expect("cannot post to http handler")
-        .json::<databend_query::servers::http::v1::QueryResponse>()
-        .await;
-    if let Err(e) = ans {
-        return Err(CliError::Unknown(format!(
-            "Cannot retrieve query result: {:?}",
-            e
-        )));
-    } else {
-        let ans = ans.unwrap();
-        if let Some(final_uri) = ans.final_uri {
-            let uri = url.parse::<Uri>().unwrap();
-            let uri = format!(
-                "{}://{}:{}/{}",
-                uri.scheme().unwrap(),
-                uri.host().unwrap(),
-                uri.port().unwrap(),
-                final_uri
-            );
-            cli.get(uri).send().await.expect("fail to get final_uri");
+        .map_err(|e| CliError::Unknown(format!("cannot post to http handler: {:?}", e)))?
+        .json::<QueryResponse>()
+        .await
+        .map_err(|e| CliError::Unknown(format!("Cannot retrieve query result: {:?}", e)))?;
+    let schema = resp.schema.clone();
+    let mut data = vec![];
+    loop {
+        if resp.error.is_some() {
+            break;
         }
-        if ans.error.is_some() {
-            return Err(CliError::Unknown(format!(
-                "Query has error: {:?}",
-                ans.error.unwrap()
-            )));
+        data.extend(resp.data.iter().cloned());
+        if let (Some(bar), Some(progress)) = (bar, &resp.stats.progress) {
+            bar.set_message(progress_message(progress));
+        }
+        match resp.next_uri.take() {
+            Some(next_uri) => {
+                let uri = format!("{}{}?wait_time=1", base, next_uri);
+                resp = get_query_response(cli, uri).await?;
+            }
+            None => break,
         }
-        Ok((ans.schema, ans.data, ans.stats))
     }
+    if let Some(final_uri) = &resp.final_uri {
+        cli.get(format!("{}{}", base, final_uri))
+            .send()
+            .await
+            .map_err(|e| CliError::Unknown(format!("fail to get final_uri: {:?}", e)))?;
+    }
+    if let Some(error) = resp.error {
+        return Err(CliError::Unknown(format!("Query has error: {:?}", error)));
+    }
+    Ok((schema, data, resp.stats))
+}
+
+pub async fn execute_query_json(
+    cli: &reqwest::Client,
+    url: &str,
+    query: String,
+) -> Result<(Option<DataSchemaRef>, Arc<Vec<Vec<Value>>>, QueryStats)> {
+    let (schema, data, stats) = execute_query_pages(cli, url, query, None).await?;
+    Ok((schema, Arc::new(data), stats))
 }
 
 async fn execute_query(
     cli: &reqwest::Client,
     url: &str,
     query: String,
+    format: OutputFormat,
+    progress: bool,
 ) -> Result<(String, Option<ProgressValues>)> {
-    let (columns, data, stats) = execute_query_json(cli, url, query).await?;
-    let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
-    if let Some(column) = columns {
-        table.set_header(
-            column
-                .fields()
-                .iter()
-                .map(|field| Cell::new(field.name().as_str()).fg(Color::Green)),
+    let bar = if progress {
+        let bar = ProgressBar::new_spinner();
+        bar.set_style(
+            ProgressStyle::default_spinner().template("{spinner:.green} [{elapsed_precise}] {msg}"),
         );
-    }
-    if data.is_empty() {
-        Ok(("".to_string(), stats.progress))
+        bar.enable_steady_tick(100);
+        Some(bar)
     } else {
-        for row in data.as_ref() {
-            table.add_row(row.iter().map(|elem| Cell::new(elem.to_string())));
-        }
-        Ok((table.trim_fmt(), stats.progress))
+        None
+    };
+    let res = execute_query_pages(cli, url, query, bar.as_ref()).await;
+    if let Some(bar) = bar {
+        bar.finish_and_clear();
     }
+    let (schema, data, stats) = res?;
+    Ok((format.format(schema.as_ref(), &data)?, stats.progress))
 }
 
 pub async fn execute_load(
@@ -350,6 +450,41 @@ pub async fn execute_load(
     }
 }
 
+/// Puts the files to the stage, under the relative path in it, returning their paths in the stage.
+pub async fn execute_upload(
+    cli: &reqwest::Client,
+    url: &str,
+    stage_name: &str,
+    relative_path: &str,
+    files: Vec<(String, Vec<u8>)>,
+) -> Result<Vec<String>> {
+    let mut form = multipart::Form::new();
+    for (name, data) in files {
+        form = form.part("upload", multipart::Part::stream(data).file_name(name));
+    }
+
+    let resp = cli
+        .put(url)
+        .header("stage_name", stage_name)
+        .header("relative_path", relative_path)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|e| CliError::Unknown(format!("cannot put to http handler: {:?}", e)))?;
+    if resp.status() != StatusCode::OK {
+        let status = resp.status();
+        let reason = resp.text().await.unwrap_or_default();
+        return Err(CliError::Unknown(format!(
+            "upload to stage fail, status_code={}, reason={}",
+            status, reason
+        )));
+    }
+    match resp.json::<UploadToStageResponse>().await {
+        Ok(v) => Ok(v.files),
+        Err(e) => Err(CliError::Unknown(format!("json decode error={}", e))),
+    }
+}
+
 #[async_trait]
 impl Command for QueryCommand {
     fn name(&self) -> &str {
@@ -368,6 +503,20 @@ impl Command for QueryCommand {
                     .possible_values(&["local"])
                     .default_value("local"),
             )
+            .arg(
+                Arg::new("format")
+                    .long("format")
+                    .help("Format of the query results")
+                    .required(false)
+                    .takes_value(true)
+                    .possible_values(&["table", "csv", "json"]),
+            )
+            .arg(
+                Arg::new("progress")
+                    .long("progress")
+                    .help("Shows the scan progress of the queries")
+                    .required(false),
+            )
             .arg(
                 Arg::new("query")
                     .help("Query statements to run")
@@ -26,6 +26,7 @@ use crate::cmds::config::Config;
 use crate::cmds::generates::generate::GenerateCommand;
 use crate::cmds::loads::load::LoadCommand;
 use crate::cmds::queries::query::QueryCommand;
+use crate::cmds::uploads::upload::UploadCommand;
 use crate::cmds::ups::up::UpCommand;
 use crate::cmds::ClusterCommand;
 use crate::cmds::PackageCommand;
@@ -121,6 +122,7 @@ impl Command for RootCommand {
             .subcommand(QueryCommand::default().clap())
             .subcommand(UpCommand::default().clap())
             .subcommand(LoadCommand::default().clap())
+            .subcommand(UploadCommand::default().clap())
             .subcommand(GenerateCommand::default().clap())
     }
 
@@ -136,6 +138,7 @@ impl Command for RootCommand {
             Arc::new(ClusterCommand::create(config.clone())),
             Arc::new(QueryCommand::create(config.clone())),
             Arc::new(LoadCommand::create(config.clone())),
+            Arc::new(UploadCommand::create(config.clone())),
             Arc::new(UpCommand::create(config.clone())),
             Arc::new(CompletionCommand::create()),
             Arc::new(GenerateCommand::create(config)),
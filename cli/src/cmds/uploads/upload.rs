@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use clap::App;
+use clap::AppSettings;
+use clap::Arg;
+use clap::ArgMatches;
+
+use crate::cmds::clusters::cluster::ClusterProfile;
+use crate::cmds::command::Command;
+use crate::cmds::queries::query::build_upload_endpoint;
+use crate::cmds::queries::query::execute_upload;
+use crate::cmds::Config;
+use crate::cmds::Status;
+use crate::cmds::Writer;
+use crate::error::CliError;
+use crate::error::Result;
+
+#[derive(Clone)]
+pub struct UploadCommand {
+    conf: Config,
+}
+
+impl UploadCommand {
+    pub fn create(conf: Config) -> Self {
+        UploadCommand { conf }
+    }
+
+    pub fn default() -> Self {
+        UploadCommand::create(Config::default())
+    }
+
+    async fn local_exec_match(&self, writer: &mut Writer, args: &ArgMatches) -> Result<()> {
+        let status = Status::read(self.conf.clone())?;
+        if status.current_profile.is_none() {
+            writer.write_err(format!(
+                "Upload command error: cannot find local configs in {}, please run `bendctl cluster create` to create a new local cluster",
+                status.local_config_dir
+            ));
+            return Ok(());
+        }
+
+        let (stage_name, relative_path) =
+            match parse_stage_location(args.value_of("stage").unwrap()) {
+                Ok(v) => v,
+                Err(e) => {
+                    writer.write_err(format!("Upload command error: {}", e));
+                    return Ok(());
+                }
+            };
+        let mut files = vec![];
+        for file in args.values_of("file").unwrap() {
+            let path = Path::new(file);
+            let name = match path.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => {
+                    writer.write_err(format!("Upload command error: {} is not a file", file));
+                    return Ok(());
+                }
+            };
+            files.push((name, std::fs::read(path)?));
+        }
+
+        let (cli, url) = build_upload_endpoint(&status)?;
+        match execute_upload(&cli, &url, &stage_name, &relative_path, files).await {
+            Ok(uploaded) => {
+                for file in uploaded {
+                    writer.write_ok(format!("uploaded @{}/{}", stage_name, file));
+                }
+            }
+            Err(e) => writer.write_err(format!("Upload command error: {:?}", e)),
+        }
+        Ok(())
+    }
+}
+
+/// Splits a stage location `@stage[/path]` into the stage name and the path in it.
+pub fn parse_stage_location(location: &str) -> Result<(String, String)> {
+    let location = location.strip_prefix('@').ok_or_else(|| {
+        CliError::Unknown(format!(
+            "stage location {} should be in format @stage[/path]",
+            location
+        ))
+    })?;
+    let (stage, path) = match location.split_once('/') {
+        Some((stage, path)) => (stage, path.trim_matches('/')),
+        None => (location, ""),
+    };
+    if stage.is_empty() {
+        return Err(CliError::Unknown("stage name is empty".to_string()));
+    }
+    Ok((stage.to_string(), path.to_string()))
+}
+
+#[async_trait]
+impl Command for UploadCommand {
+    fn name(&self) -> &str {
+        "upload"
+    }
+
+    fn clap(&self) -> App<'static> {
+        App::new("upload")
+            .setting(AppSettings::DisableVersionFlag)
+            .about("Upload local files to a stage")
+            .arg(
+                Arg::new("profile")
+                    .long("profile")
+                    .help("Profile to run queries")
+                    .required(false)
+                    .possible_values(&["local"])
+                    .default_value("local"),
+            )
+            .arg(
+                Arg::new("file")
+                    .help("files to upload, for example foo.csv")
+                    .takes_value(true)
+                    .multiple_values(true)
+                    .required(true),
+            )
+            .arg(
+                Arg::new("stage")
+                    .long("stage")
+                    .help("stage location to upload to, for example @my_stage/path")
+                    .takes_value(true)
+                    .required(true),
+            )
+    }
+
+    fn about(&self) -> &'static str {
+        "Upload local files to a stage"
+    }
+
+    fn is(&self, s: &str) -> bool {
+        s.contains(self.name())
+    }
+
+    fn subcommands(&self) -> Vec<Arc<dyn Command>> {
+        vec![]
+    }
+
+    async fn exec_matches(&self, writer: &mut Writer, args: Option<&ArgMatches>) -> Result<()> {
+        match args {
+            Some(matches) => {
+                let profile = matches.value_of_t("profile");
+                match profile {
+                    Ok(ClusterProfile::Local) => {
+                        return self.local_exec_match(writer, matches).await;
+                    }
+                    Ok(ClusterProfile::Cluster) => {
+                        return Err(CliError::Unknown(
+                            "Upload does not support the cluster profile yet, use --profile local"
+                                .to_string(),
+                        ));
+                    }
+                    Err(_) => writer
+                        .write_err("Currently profile only support cluster or local".to_string()),
+                }
+            }
+            None => {}
+        }
+        Ok(())
+    }
+}
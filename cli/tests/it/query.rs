@@ -16,13 +16,19 @@ use bendctl::cmds::build_query_endpoint;
 use bendctl::cmds::config::GithubMirror;
 use bendctl::cmds::config::MirrorAsset;
 use bendctl::cmds::config::Mode;
+use bendctl::cmds::parse_stage_location;
 use bendctl::cmds::status::LocalMetaConfig;
 use bendctl::cmds::status::LocalQueryConfig;
 use bendctl::cmds::Config;
+use bendctl::cmds::OutputFormat;
 use bendctl::cmds::Status;
 use bendctl::error::Result;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use databend_meta::configs::Config as MetaConfig;
 use databend_query::configs::Config as QueryConfig;
+use serde_json::json;
 use tempfile::tempdir;
 
 macro_rules! build_status {
@@ -86,3 +92,47 @@ fn test_generate_query_probe() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_output_format() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, true),
+    ]);
+    let data = vec![vec![json!(1), json!("x,y")], vec![json!(2), json!(null)]];
+
+    assert_eq!(
+        "table".parse::<OutputFormat>().unwrap(),
+        OutputFormat::Table
+    );
+    assert_eq!("CSV".parse::<OutputFormat>().unwrap(), OutputFormat::Csv);
+    assert!("xml".parse::<OutputFormat>().is_err());
+
+    assert_eq!(
+        OutputFormat::Csv.format(Some(&schema), &data)?,
+        "a,b\n1,\"x,y\"\n2,"
+    );
+    assert_eq!(
+        OutputFormat::Json.format(Some(&schema), &data)?,
+        "{\"a\":1,\"b\":\"x,y\"}\n{\"a\":2,\"b\":null}"
+    );
+    let table = OutputFormat::Table.format(Some(&schema), &data)?;
+    assert!(table.contains("x,y"), "{}", table);
+    assert_eq!(OutputFormat::Csv.format(Some(&schema), &[])?, "");
+    Ok(())
+}
+
+#[test]
+fn test_parse_stage_location() -> Result<()> {
+    assert_eq!(
+        parse_stage_location("@s1")?,
+        ("s1".to_string(), "".to_string())
+    );
+    assert_eq!(
+        parse_stage_location("@s1/a/b/")?,
+        ("s1".to_string(), "a/b".to_string())
+    );
+    assert!(parse_stage_location("s1/a").is_err());
+    assert!(parse_stage_location("@/a").is_err());
+    Ok(())
+}
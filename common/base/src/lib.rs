@@ -32,6 +32,9 @@ pub use progress::ProgressValues;
 pub use runtime::Dropper;
 pub use runtime::Runtime;
 pub use runtime::TrySpawn;
+pub use runtime_tracker::AttachedMemoryTracker;
+pub use runtime_tracker::MemoryTrackedFuture;
+pub use runtime_tracker::MemoryTracker;
 pub use runtime_tracker::RuntimeTracker;
 pub use runtime_tracker::ThreadTracker;
 pub use shutdown_signal::signal_stream;
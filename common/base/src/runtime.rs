@@ -22,6 +22,7 @@ use tokio::runtime::Handle;
 use tokio::sync::oneshot;
 use tokio::task::JoinHandle;
 
+use crate::runtime_tracker::MemoryTracker;
 use crate::runtime_tracker::RuntimeTracker;
 
 /// Methods to spawn tasks.
@@ -126,6 +127,13 @@ impl Runtime {
         Self::create(tracker, runtime_builder.worker_threads(workers))
     }
 
+    /// The threads of the runtime allocate on behalf of the memory tracker, e.g. of a query.
+    pub fn with_memory_tracker(workers: usize, memory_tracker: Arc<MemoryTracker>) -> Result<Self> {
+        let tracker = RuntimeTracker::create_with_memory_tracker(memory_tracker);
+        let mut runtime_builder = Self::tracker_builder(tracker.clone());
+        Self::create(tracker, runtime_builder.worker_threads(workers))
+    }
+
     pub fn block_on<F: Future>(&self, future: F) -> F::Output {
         self.handle.block_on(future)
     }
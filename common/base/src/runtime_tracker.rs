@@ -13,9 +13,17 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 #[thread_local]
 static mut TRACKER: *mut ThreadTracker = std::ptr::null_mut();
@@ -24,6 +32,8 @@ static UNTRACKED_MEMORY_LIMIT: i64 = 4 * 1024 * 1024;
 
 pub struct ThreadTracker {
     rt_tracker: Arc<RuntimeTracker>,
+    // The tracker the thread allocates on behalf of, instead of the one of the runtime.
+    attached: Option<Arc<MemoryTracker>>,
     untracked_memory: i64,
 }
 
@@ -32,6 +42,7 @@ impl ThreadTracker {
         unsafe {
             TRACKER = Box::into_raw(Box::new(ThreadTracker {
                 rt_tracker,
+                attached: None,
                 untracked_memory: 0,
             }));
 
@@ -39,6 +50,50 @@ impl ThreadTracker {
         }
     }
 
+    #[inline]
+    fn memory_tracker(&self) -> &Arc<MemoryTracker> {
+        match &self.attached {
+            Some(attached) => attached,
+            None => &self.rt_tracker.memory_tracker,
+        }
+    }
+
+    /// Attributes the memory allocated by the current thread to the tracker until the guard is
+    /// dropped, e.g. while a stream of a query is polled by a thread of another runtime.
+    ///
+    /// Nothing is attributed if the thread is not started by a [`Runtime`].
+    ///
+    /// [`Runtime`]: crate::Runtime
+    pub fn attach(memory_tracker: Arc<MemoryTracker>) -> AttachedMemoryTracker {
+        unsafe {
+            if TRACKER.is_null() {
+                return AttachedMemoryTracker {
+                    previous: None,
+                    attached: false,
+                    _not_send: PhantomData,
+                };
+            }
+
+            Self::flush_untracked_memory();
+            let previous = (*TRACKER).attached.replace(memory_tracker);
+            AttachedMemoryTracker {
+                previous,
+                attached: true,
+                _not_send: PhantomData,
+            }
+        }
+    }
+
+    unsafe fn flush_untracked_memory() {
+        let untracked_memory = std::mem::replace(&mut (*TRACKER).untracked_memory, 0);
+        match untracked_memory > 0 {
+            true => (*TRACKER).memory_tracker().alloc_memory(untracked_memory),
+            false => (*TRACKER)
+                .memory_tracker()
+                .dealloc_memory(-untracked_memory),
+        }
+    }
+
     #[inline]
     pub fn current() -> *mut ThreadTracker {
         unsafe { TRACKER }
@@ -62,8 +117,7 @@ impl ThreadTracker {
 
                 if (*TRACKER).untracked_memory > UNTRACKED_MEMORY_LIMIT {
                     (*TRACKER)
-                        .rt_tracker
-                        .memory_tracker
+                        .memory_tracker()
                         .alloc_memory((*TRACKER).untracked_memory);
                     (*TRACKER).untracked_memory = 0;
                 }
@@ -79,8 +133,7 @@ impl ThreadTracker {
 
                 if (*TRACKER).untracked_memory < -UNTRACKED_MEMORY_LIMIT {
                     (*TRACKER)
-                        .rt_tracker
-                        .memory_tracker
+                        .memory_tracker()
                         .dealloc_memory(-(*TRACKER).untracked_memory);
                     (*TRACKER).untracked_memory = 0;
                 }
@@ -98,8 +151,31 @@ impl ThreadTracker {
     }
 }
 
+/// Restores the tracker the thread allocates on behalf of, see [`ThreadTracker::attach`].
+pub struct AttachedMemoryTracker {
+    previous: Option<Arc<MemoryTracker>>,
+    attached: bool,
+    // The tracker is attached to the current thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for AttachedMemoryTracker {
+    fn drop(&mut self) {
+        unsafe {
+            if self.attached && !TRACKER.is_null() {
+                ThreadTracker::flush_untracked_memory();
+                (*TRACKER).attached = self.previous.take();
+            }
+        }
+    }
+}
+
+/// The memory usage of a runtime or a query, also accounted to the parent tracker.
 pub struct MemoryTracker {
     memory_usage: AtomicI64,
+    peak_memory_usage: AtomicI64,
+    // 0 for unlimited.
+    limit: AtomicI64,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -108,12 +184,48 @@ impl MemoryTracker {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
             memory_usage: AtomicI64::new(0),
+            peak_memory_usage: AtomicI64::new(0),
+            limit: AtomicI64::new(0),
         })
     }
 
+    /// Limits the memory usage in bytes, 0 for unlimited.
+    ///
+    /// The allocations can not fail, the usage is checked by [`MemoryTracker::check_limit`]
+    /// where the memory grows in bulk, e.g. by the blocks and the hash tables.
+    pub fn set_limit(&self, limit: i64) {
+        self.limit.store(limit, Ordering::Relaxed);
+    }
+
+    /// Fails if the usage of the tracker or of any of its parents exceeds the limit.
+    pub fn check_limit(&self) -> Result<()> {
+        let limit = self.limit.load(Ordering::Relaxed);
+        let usage = self.get_memory_usage();
+        if limit > 0 && usage > limit {
+            return Err(ErrorCode::MemoryLimitExceeded(format!(
+                "Memory usage {} bytes exceeds the limit of {} bytes",
+                usage, limit
+            )));
+        }
+
+        match &self.parent_memory_tracker {
+            Some(parent_memory_tracker) => parent_memory_tracker.check_limit(),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks the limit of the tracker the current thread allocates on behalf of, if any.
+    pub fn check_current_limit() -> Result<()> {
+        match Self::current() {
+            Some(memory_tracker) => memory_tracker.check_limit(),
+            None => Ok(()),
+        }
+    }
+
     #[inline]
     pub fn alloc_memory(&self, size: i64) {
-        self.memory_usage.fetch_add(size, Ordering::Relaxed);
+        let usage = self.memory_usage.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_memory_usage.fetch_max(usage, Ordering::Relaxed);
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.alloc_memory(size);
@@ -135,7 +247,7 @@ impl MemoryTracker {
             let thread_tracker = ThreadTracker::current();
             match thread_tracker.is_null() {
                 true => None,
-                false => Some((*thread_tracker).rt_tracker.memory_tracker.clone()),
+                false => Some((*thread_tracker).memory_tracker().clone()),
             }
         }
     }
@@ -144,6 +256,12 @@ impl MemoryTracker {
     pub fn get_memory_usage(&self) -> i64 {
         self.memory_usage.load(Ordering::Relaxed)
     }
+
+    /// The highest memory usage since the tracker is created.
+    #[inline]
+    pub fn get_peak_memory_usage(&self) -> i64 {
+        self.peak_memory_usage.load(Ordering::Relaxed)
+    }
 }
 
 pub struct RuntimeTracker {
@@ -153,9 +271,12 @@ pub struct RuntimeTracker {
 impl RuntimeTracker {
     pub fn create() -> Arc<RuntimeTracker> {
         let parent_memory_tracker = MemoryTracker::current();
-        Arc::new(RuntimeTracker {
-            memory_tracker: MemoryTracker::create(parent_memory_tracker),
-        })
+        Self::create_with_memory_tracker(MemoryTracker::create(parent_memory_tracker))
+    }
+
+    /// The threads of the runtime allocate on behalf of the tracker, e.g. the one of a query.
+    pub fn create_with_memory_tracker(memory_tracker: Arc<MemoryTracker>) -> Arc<RuntimeTracker> {
+        Arc::new(RuntimeTracker { memory_tracker })
     }
 
     #[inline]
@@ -181,3 +302,28 @@ impl RuntimeTracker {
         }
     }
 }
+
+/// A future whose polls allocate on behalf of the tracker, see [`ThreadTracker::attach`].
+pub struct MemoryTrackedFuture<F: Future> {
+    memory_tracker: Arc<MemoryTracker>,
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> MemoryTrackedFuture<F> {
+    pub fn create(memory_tracker: Arc<MemoryTracker>, inner: F) -> Self {
+        MemoryTrackedFuture {
+            memory_tracker,
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl<F: Future> Future for MemoryTrackedFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _attached = ThreadTracker::attach(this.memory_tracker.clone());
+        this.inner.as_mut().poll(ctx)
+    }
+}
@@ -59,3 +59,33 @@ async fn test_runtime() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_memory_tracker_limit() -> Result<()> {
+    let parent = MemoryTracker::create(None);
+    let query = MemoryTracker::create(Some(parent.clone()));
+    query.set_limit(1024);
+
+    query.alloc_memory(1000);
+    assert!(query.check_limit().is_ok());
+    assert_eq!(parent.get_memory_usage(), 1000);
+
+    query.alloc_memory(100);
+    let cause = query.check_limit().unwrap_err();
+    assert_eq!(cause.code(), 66);
+    assert_eq!(
+        cause.message(),
+        "Memory usage 1100 bytes exceeds the limit of 1024 bytes"
+    );
+
+    query.dealloc_memory(600);
+    assert!(query.check_limit().is_ok());
+    assert_eq!(query.get_peak_memory_usage(), 1100);
+
+    // The limit of the parent applies to its children.
+    query.set_limit(0);
+    parent.set_limit(100);
+    assert!(query.check_limit().is_err());
+
+    Ok(())
+}
@@ -25,6 +25,7 @@ use rusoto_core::Client;
 use rusoto_core::HttpClient;
 use rusoto_core::Region;
 use rusoto_s3::DeleteObjectRequest;
+use rusoto_s3::ListObjectsV2Request;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
@@ -189,4 +190,32 @@ impl DataAccessor for S3 {
 
         Ok(())
     }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut paths = vec![];
+        let mut continuation_token = None;
+        loop {
+            let req = ListObjectsV2Request {
+                bucket: self.bucket.clone(),
+                prefix: Some(prefix.to_owned()),
+                continuation_token,
+                ..Default::default()
+            };
+            let output = self
+                .client
+                .list_objects_v2(req)
+                .await
+                .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+
+            let keys = output.contents.unwrap_or_default().into_iter();
+            paths.extend(keys.filter_map(|object| object.key));
+
+            continuation_token = output.next_continuation_token;
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+        // the keys are listed in the order of their UTF-8 bytes already
+        Ok(paths)
+    }
 }
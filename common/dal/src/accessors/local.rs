@@ -113,6 +113,38 @@ impl DataAccessor for Local {
         std::fs::remove_file(path)?; // use std fs
         Ok(())
     }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        // walk the directory holding the prefix, the file names are matched against the rest
+        let dir = match prefix.rfind('/') {
+            Some(pos) => &prefix[..pos],
+            None => "",
+        };
+        let dir = self.prefix_with_root(dir)?;
+        let mut paths = vec![];
+        if dir.is_dir() {
+            list_files(&self.root, &dir, &mut paths)?;
+        }
+        paths.retain(|path| path.starts_with(prefix));
+        paths.sort();
+        Ok(paths)
+    }
+}
+
+fn list_files(root: &Path, dir: &Path, paths: &mut Vec<String>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(root, &path, paths)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>();
+            paths.push(relative.join("/"));
+        }
+    }
+    Ok(())
 }
 
 async fn mk_parent_dir(path: &Path) -> Result<()> {
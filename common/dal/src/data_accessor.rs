@@ -68,4 +68,11 @@ pub trait DataAccessor: Send + Sync {
     }
 
     async fn remove(&self, _path: &str) -> Result<()>;
+
+    /// The paths of the objects whose path starts with `prefix`, in lexicographical order.
+    async fn list(&self, _prefix: &str) -> Result<Vec<String>> {
+        Err(ErrorCode::UnImplement(
+            "Listing objects is not supported by this storage",
+        ))
+    }
 }
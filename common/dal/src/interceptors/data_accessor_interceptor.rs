@@ -75,4 +75,8 @@ impl DataAccessor for DataAccessorInterceptor {
     async fn remove(&self, path: &str) -> common_exception::Result<()> {
         self.inner.remove(path).await
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<String>> {
+        self.inner.list(prefix).await
+    }
 }
@@ -42,3 +42,34 @@ async fn test_da_local_normal() -> common_exception::Result<()> {
     let read_fut = local_read(1000);
     read_fut.await
 }
+
+#[tokio::test]
+async fn test_da_local_list() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let local_da = Local::new(tmp_root_dir.path().to_str().unwrap());
+
+    for path in [
+        "data/b.csv",
+        "data/a.csv",
+        "data/sub/c.csv",
+        "data_2/d.csv",
+        "e.csv",
+    ] {
+        local_da.put(path, vec![1]).await?;
+    }
+
+    assert_eq!(local_da.list("data/").await?, vec![
+        "data/a.csv",
+        "data/b.csv",
+        "data/sub/c.csv"
+    ]);
+    assert_eq!(local_da.list("data").await?, vec![
+        "data/a.csv",
+        "data/b.csv",
+        "data/sub/c.csv",
+        "data_2/d.csv"
+    ]);
+    assert_eq!(local_da.list("data/a").await?, vec!["data/a.csv"]);
+    assert!(local_da.list("not_exist/").await?.is_empty());
+    Ok(())
+}
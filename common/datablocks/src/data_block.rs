@@ -142,6 +142,7 @@ impl DataBlock {
         column.try_get(column.len() - 1)
     }
 
+    /// Take the rows in [offset, offset + length), the columns share the buffers of this block.
     #[inline]
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
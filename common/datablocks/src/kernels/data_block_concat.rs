@@ -19,6 +19,7 @@ use common_exception::Result;
 use crate::DataBlock;
 
 impl DataBlock {
+    /// Concat the blocks into one, the data is only copied when more than one of them has rows.
     pub fn concat_blocks(blocks: &[DataBlock]) -> Result<DataBlock> {
         if blocks.is_empty() {
             return Result::Err(ErrorCode::EmptyData("Can't concat empty blocks"));
@@ -31,14 +32,24 @@ impl DataBlock {
             }
         }
 
+        let non_empty_blocks = blocks
+            .iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>();
+        match non_empty_blocks.len() {
+            0 => return Ok(first_block.clone()),
+            1 => return Ok(non_empty_blocks[0].clone()),
+            _ => {}
+        }
+
         let mut concat_columns = Vec::with_capacity(first_block.num_columns());
         for (i, _f) in blocks[0].schema().fields().iter().enumerate() {
-            let mut columns = Vec::with_capacity(blocks.len());
-            for block in blocks.iter() {
+            let mut columns = Vec::with_capacity(non_empty_blocks.len());
+            for block in non_empty_blocks.iter() {
                 columns.push(block.column(i).clone());
             }
 
-            concat_columns.push(DataColumnCommon::concat(&columns)?);
+            concat_columns.push(Self::concat_columns(&columns)?);
         }
 
         Ok(DataBlock::create(
@@ -46,4 +57,20 @@ impl DataBlock {
             concat_columns,
         ))
     }
+
+    // The constant columns of the same value are concatenated without materializing them.
+    fn concat_columns(columns: &[DataColumn]) -> Result<DataColumn> {
+        if let DataColumn::Constant(first_value, _) = &columns[0] {
+            let mut rows = 0;
+            for column in columns {
+                match column {
+                    DataColumn::Constant(value, size) if value == first_value => rows += size,
+                    _ => return DataColumnCommon::concat(columns),
+                }
+            }
+            return Ok(DataColumn::Constant(first_value.clone(), rows));
+        }
+
+        DataColumnCommon::concat(columns)
+    }
 }
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::min;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DataBlock;
+
+impl DataBlock {
+    /// Re-chunk the blocks into blocks of `target_rows` rows, only the last one may have fewer.
+    ///
+    /// The rows of a result block coming from one input block are a slice of it sharing its
+    /// buffers, the rows are only copied for the result blocks spanning several input blocks.
+    pub fn rechunk_blocks(blocks: &[DataBlock], target_rows: usize) -> Result<Vec<DataBlock>> {
+        if target_rows == 0 {
+            return Err(ErrorCode::BadArguments(
+                "The target rows of the re-chunked blocks must be greater than 0",
+            ));
+        }
+
+        let mut result = vec![];
+        let mut pending = vec![];
+        let mut pending_rows = 0;
+        for block in blocks {
+            let rows = block.num_rows();
+            let mut offset = 0;
+            while offset < rows {
+                let length = min(rows - offset, target_rows - pending_rows);
+                let sliced = block.slice(offset, length);
+                offset += length;
+
+                if length == target_rows {
+                    result.push(sliced);
+                    continue;
+                }
+
+                pending.push(sliced);
+                pending_rows += length;
+                if pending_rows == target_rows {
+                    result.push(DataBlock::concat_blocks(&pending)?);
+                    pending.clear();
+                    pending_rows = 0;
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            result.push(DataBlock::concat_blocks(&pending)?);
+        }
+
+        Ok(result)
+    }
+}
@@ -37,13 +37,9 @@ impl DataBlock {
         Ok(blocks)
     }
 
+    /// Slice the block without copying, the sliced block shares the buffers of the block.
     #[inline]
     pub fn slice_block(block: &DataBlock, offset: usize, length: usize) -> DataBlock {
-        let mut columns = Vec::with_capacity(block.num_columns());
-        for column_index in 0..block.num_columns() {
-            let column = block.column(column_index);
-            columns.push(column.slice(offset, length));
-        }
-        DataBlock::create(block.schema().clone(), columns)
+        block.slice(offset, length)
     }
 }
@@ -59,3 +59,37 @@ fn test_data_block_concat() -> Result<()> {
     common_datablocks::assert_blocks_eq(expected, &[results]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_concat_without_copy() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+
+    let block = DataBlock::create(schema.clone(), vec![
+        DataColumn::Array(Series::new(vec![1i64, 2, 3])),
+        DataColumn::Constant(DataValue::String(Some(b"x".to_vec())), 3),
+    ]);
+    let empty = block.slice(0, 0);
+
+    // The empty blocks are skipped, a single block is returned as it is.
+    {
+        let result = DataBlock::concat_blocks(&[empty.clone(), block.clone(), empty])?;
+        let array = result.column(0).to_array()?;
+        let expected = block.column(0).to_array()?;
+        assert_eq!(
+            expected.i64()?.inner().values().as_ptr(),
+            array.i64()?.inner().values().as_ptr()
+        );
+    }
+
+    // The constant columns of the same value stay constant.
+    {
+        let result = DataBlock::concat_blocks(&[block.clone(), block])?;
+        assert_eq!(6, result.num_rows());
+        assert!(matches!(result.column(1), DataColumn::Constant(_, 6)));
+    }
+
+    Ok(())
+}
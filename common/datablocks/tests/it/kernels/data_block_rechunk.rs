@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+fn int64_values_ptr(block: &DataBlock) -> Result<*const i64> {
+    let array = block.column(0).to_array()?;
+    Ok(array.i64()?.inner().values().as_ptr())
+}
+
+#[test]
+fn test_data_block_rechunk() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+
+    let blocks = vec![
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i64, 2, 3, 4, 5])]),
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![6i64])]),
+        DataBlock::create_by_array(schema, vec![Series::new(vec![7i64, 8, 9])]),
+    ];
+
+    let results = DataBlock::rechunk_blocks(&blocks, 2)?;
+    let rows = results.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(vec![2, 2, 2, 2, 1], rows);
+
+    // The chunks within one input block share its buffer.
+    let first_ptr = int64_values_ptr(&blocks[0])?;
+    assert_eq!(first_ptr, int64_values_ptr(&results[0])?);
+    assert_eq!(unsafe { first_ptr.add(2) }, int64_values_ptr(&results[1])?);
+
+    let expected = vec![
+        "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "| 4 |", "| 5 |", "| 6 |", "| 7 |",
+        "| 8 |", "| 9 |", "+---+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &results);
+
+    // A target larger than the input concats all of it.
+    let results = DataBlock::rechunk_blocks(&blocks, 100)?;
+    assert_eq!(1, results.len());
+    assert_eq!(9, results[0].num_rows());
+
+    assert!(DataBlock::rechunk_blocks(&blocks, 0).is_err());
+    Ok(())
+}
@@ -16,6 +16,7 @@ mod data_block_concat;
 mod data_block_filter;
 mod data_block_group_by;
 mod data_block_group_by_hash;
+mod data_block_rechunk;
 mod data_block_scatter;
 mod data_block_slice;
 mod data_block_sort;
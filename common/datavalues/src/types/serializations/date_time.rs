@@ -13,22 +13,92 @@
 // limitations under the License.
 
 use std::marker::PhantomData;
-use std::ops::AddAssign;
 
-use chrono::Duration;
-use chrono::NaiveDateTime;
+use chrono_tz::Tz;
 use common_exception::*;
 
 use crate::prelude::*;
 
+/// How the DateTime values of the results are rendered, told by the settings of the session.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DateTimeOutput {
+    /// The wall clock time in the time zone of the type, or in this one if the type has none.
+    TimeZone(Tz),
+    /// The wall clock time in UTC, whatever the time zone of the type is.
+    Utc,
+    /// The seconds since 1970-01-01 00:00:00 UTC.
+    Epoch,
+}
+
+impl Default for DateTimeOutput {
+    fn default() -> Self {
+        DateTimeOutput::TimeZone(Tz::UTC)
+    }
+}
+
+impl DateTimeOutput {
+    /// `format` is one of `time_zone`, `utc` and `epoch`.
+    pub fn try_create(time_zone: &str, format: &str) -> Result<Self> {
+        match format.to_lowercase().as_str() {
+            "time_zone" => Ok(DateTimeOutput::TimeZone(Self::parse_time_zone(time_zone)?)),
+            "utc" => Ok(DateTimeOutput::Utc),
+            "epoch" => Ok(DateTimeOutput::Epoch),
+            other => Err(ErrorCode::BadArguments(format!(
+                "DateTime output format must be one of time_zone, utc, epoch, but got {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn parse_time_zone(time_zone: &str) -> Result<Tz> {
+        time_zone.parse::<Tz>().map_err(|_| {
+            ErrorCode::BadArguments(format!(
+                "Unknown time zone {}, expecting a name of the tz database, e.g. Asia/Shanghai",
+                time_zone
+            ))
+        })
+    }
+
+    /// The time zone the values of a DateTime type of the time zone `type_tz` are rendered in,
+    /// none if they are rendered as epoch.
+    pub fn time_zone(&self, type_tz: &Option<String>) -> Option<Tz> {
+        match self {
+            DateTimeOutput::TimeZone(tz) => Some(
+                type_tz
+                    .as_ref()
+                    .and_then(|v| v.parse::<Tz>().ok())
+                    .unwrap_or(*tz),
+            ),
+            DateTimeOutput::Utc => Some(Tz::UTC),
+            DateTimeOutput::Epoch => None,
+        }
+    }
+}
+
 pub struct DateTimeSerializer<T: DFPrimitiveType> {
     t: PhantomData<T>,
+    // none for the seconds since epoch
+    tz: Option<Tz>,
 }
 
 impl<T: DFPrimitiveType> Default for DateTimeSerializer<T> {
     fn default() -> Self {
+        Self::create(Some(Tz::UTC))
+    }
+}
+
+impl<T: DFPrimitiveType> DateTimeSerializer<T> {
+    pub fn create(tz: Option<Tz>) -> Self {
         Self {
             t: Default::default(),
+            tz,
+        }
+    }
+
+    fn serialize_seconds(&self, v: i64) -> String {
+        match &self.tz {
+            Some(tz) => v.to_date_time(tz).format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => v.to_string(),
         }
     }
 }
@@ -38,11 +108,7 @@ impl<T: DFPrimitiveType> TypeSerializer for DateTimeSerializer<T> {
         if value.is_null() {
             return Ok("NULL".to_owned());
         }
-
-        let mut dt = NaiveDateTime::from_timestamp(0, 0);
-        let d = Duration::seconds(value.as_i64()?);
-        dt.add_assign(d);
-        Ok(dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        Ok(self.serialize_seconds(value.as_i64()?))
     }
 
     fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
@@ -52,13 +118,8 @@ impl<T: DFPrimitiveType> TypeSerializer for DateTimeSerializer<T> {
         let result: Vec<String> = array
             .iter()
             .map(|x| {
-                x.map(|v| {
-                    let mut dt = NaiveDateTime::from_timestamp(0, 0);
-                    let d = Duration::seconds(v.to_i64().unwrap());
-                    dt.add_assign(d);
-                    dt.format("%Y-%m-%d %H:%M:%S").to_string()
-                })
-                .unwrap_or_else(|| "NULL".to_owned())
+                x.map(|v| self.serialize_seconds(v.to_i64().unwrap()))
+                    .unwrap_or_else(|| "NULL".to_owned())
             })
             .collect();
         Ok(result)
@@ -39,6 +39,11 @@ pub trait TypeSerializer: Send + Sync {
 
 impl DataType {
     pub fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        self.create_serializer_with(&DateTimeOutput::default())
+    }
+
+    /// The serializer of the type, the DateTime values are rendered as told by `output`.
+    pub fn create_serializer_with(&self, output: &DateTimeOutput) -> Box<dyn TypeSerializer> {
         match self {
             DataType::Null => Box::new(NullSerializer {}),
             DataType::Boolean => Box::new(BooleanSerializer {}),
@@ -54,10 +59,13 @@ impl DataType {
             DataType::Float64 => Box::new(NumberSerializer::<f64>::default()),
             DataType::Date16 => Box::new(DateSerializer::<u16>::default()),
             DataType::Date32 => Box::new(DateSerializer::<i32>::default()),
-            DataType::DateTime32(_) => Box::new(DateTimeSerializer::<u32>::default()),
+            DataType::DateTime32(tz) => {
+                Box::new(DateTimeSerializer::<u32>::create(output.time_zone(tz)))
+            }
             DataType::String => Box::new(StringSerializer {}),
             DataType::Struct(fields) => Box::new(StructSerializer {
                 fields: fields.to_vec(),
+                output: output.clone(),
             }),
             _ => todo!(),
         }
@@ -19,6 +19,7 @@ use crate::prelude::*;
 
 pub struct StructSerializer {
     pub fields: Vec<DataField>,
+    pub output: DateTimeOutput,
 }
 
 impl TypeSerializer for StructSerializer {
@@ -36,7 +37,7 @@ impl TypeSerializer for StructSerializer {
                     first = false;
 
                     let data_type = field.data_type();
-                    let serializer = data_type.create_serializer();
+                    let serializer = data_type.create_serializer_with(&self.output);
                     let s = serializer.serialize_value(val).unwrap();
                     if matches!(
                         data_type,
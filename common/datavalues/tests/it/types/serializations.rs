@@ -86,6 +86,46 @@ fn test_serializers() -> Result<()> {
         assert_eq!(col_res, test.col_str, "{:#?}", test.name);
     }
 
+    {
+        let column: DataColumn = Series::new(vec![Some(1630320462u32), None]).into();
+        let value = DataValue::UInt32(Some(1630320462));
+        let tests = vec![
+            (
+                DataType::DateTime32(None),
+                DateTimeOutput::try_create("Asia/Shanghai", "time_zone")?,
+                "2021-08-30 18:47:42",
+            ),
+            (
+                DataType::DateTime32(Some("Asia/Tokyo".to_string())),
+                DateTimeOutput::try_create("Asia/Shanghai", "time_zone")?,
+                "2021-08-30 19:47:42",
+            ),
+            (
+                DataType::DateTime32(Some("Asia/Tokyo".to_string())),
+                DateTimeOutput::try_create("Asia/Shanghai", "UTC")?,
+                "2021-08-30 10:47:42",
+            ),
+            (
+                DataType::DateTime32(None),
+                DateTimeOutput::try_create("Asia/Shanghai", "epoch")?,
+                "1630320462",
+            ),
+        ];
+        for (data_type, output, expect) in tests {
+            let serializer = data_type.create_serializer_with(&output);
+            assert_eq!(serializer.serialize_value(&value)?, expect, "{:?}", output);
+            assert_eq!(
+                serializer.serialize_column(&column)?,
+                vec![expect.to_owned(), "NULL".to_owned()],
+                "{:?}",
+                output
+            );
+        }
+
+        assert!(DateTimeOutput::try_create("Mars/Olympus", "time_zone").is_err());
+        assert!(DateTimeOutput::try_create("UTC", "iso").is_err());
+    }
+
     {
         let data_type = DataType::Struct(vec![
             DataField::new("item_0", DataType::Float64, false),
@@ -193,6 +193,11 @@ build_exceptions! {
     InvalidSourceFormat(59),
     StrParseError(60),
     IllegalGrant(61),
+    PermissionDenied(62),
+    OrcError(63),
+    AvroError(64),
+    UnknownFormatVersion(65),
+    MemoryLimitExceeded(66),
 
     SemanticError(100),
 
@@ -226,6 +231,8 @@ build_exceptions! {
     MetaServiceShutdown(2202),
     // meta service is unavailable for now.
     MetaServiceUnavailable(2203),
+    // meta service and its client do not understand each other.
+    MetaServiceIncompatible(2204),
 
     // config errors
 
@@ -293,6 +300,38 @@ build_exceptions! {
     IllegalUDFParams(4073),
     RegisterUDFError(4074),
 
+    // row access policy error.
+    UnknownRowAccessPolicy(4080),
+    RowAccessPolicyAlreadyExists(4081),
+    IllegalRowAccessPolicyFormat(4082),
+
+    // recycle bin error.
+    UnknownDroppedObject(4090),
+    IllegalDroppedObjectFormat(4091),
+
+    // read-only error.
+    TableReadOnly(4100),
+    DatabaseReadOnly(4101),
+    MaintenanceMode(4102),
+
+    // network policy error.
+    UnknownNetworkPolicy(4110),
+    NetworkPolicyAlreadyExists(4111),
+    IllegalNetworkPolicyFormat(4112),
+    NetworkPolicyIsUsedByUser(4113),
+
+    // connection error.
+    UnknownConnection(4120),
+    ConnectionAlreadyExists(4121),
+    IllegalConnectionFormat(4122),
+    ConnectionCredentialsError(4123),
+
+    // role error.
+    RoleNotGranted(4130),
+    UnknownRole(4131),
+    RoleAlreadyExists(4132),
+    IllegalRoleInfoFormat(4133),
+
     // storage-api error codes
     ReadFileError(5001),
     BrokenChannel(5002),
@@ -312,6 +351,7 @@ build_exceptions! {
     UnknownDatabaseEngine(8001),
     UnknownTableEngine(8002),
     DuplicatedDatabaseEngineProvider(8003),
+    UnsupportedEngineOperation(8004),
 
     // http query error
     HttpNotFound(9404),
@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use twox_hash::XxHash64;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// The number of bits of the hash choosing the register, 2^12 registers of 4KB per group,
+/// with a standard error of 1.04 / sqrt(2^12) = 1.6%.
+const PRECISION: u32 = 12;
+const REGISTERS: usize = 1 << PRECISION;
+
+/// A HyperLogLog sketch of the distinct values.
+pub struct AggregateApproxCountDistinctState {
+    registers: Vec<u8>,
+}
+
+impl AggregateApproxCountDistinctState {
+    fn add(&mut self, values: &[DataValue]) -> Result<()> {
+        let mut hasher = XxHash64::with_seed(0);
+        for value in values {
+            DataGroupValue::try_from(value)?.hash(&mut hasher);
+        }
+        let hash = hasher.finish();
+
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // The rank of the first 1 bit in the remaining bits, a guard bit bounds it.
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+        Ok(())
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum = self
+            .registers
+            .iter()
+            .map(|r| 2f64.powi(-(*r as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        // Linear counting is more accurate for the small cardinalities.
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+}
+
+/// `approx_count_distinct(x, ...)`, the approximate number of the distinct non-NULL values,
+/// estimated by HyperLogLog in a fixed memory per group.
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+}
+
+impl AggregateApproxCountDistinctFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+        Ok(Arc::new(AggregateApproxCountDistinctFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn add_row(&self, place: StateAddr, arrays: &[Series], row: usize) -> Result<()> {
+        let values = arrays
+            .iter()
+            .map(|s| s.try_get(row))
+            .collect::<Result<Vec<_>>>()?;
+
+        if !values.iter().any(|v| v.is_null()) {
+            let state = place.get::<AggregateApproxCountDistinctState>();
+            state.add(&values)?;
+        }
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateApproxCountDistinctState {
+            registers: vec![0; REGISTERS],
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateApproxCountDistinctState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        for row in 0..input_rows {
+            self.add_row(place, arrays, row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            self.add_row(place.next(offset), arrays, row)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        serialize_into_buf(writer, &state.registers)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.registers = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let rhs = rhs.get::<AggregateApproxCountDistinctState>();
+        for (register, rhs) in state.registers.iter_mut().zip(rhs.registers.iter()) {
+            *register = (*register).max(*rhs);
+        }
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableArrayBuilder) -> Result<()> {
+        let mut array = array
+            .as_mut_any()
+            .downcast_mut::<MutablePrimitiveArrayBuilder<u64, true>>()
+            .ok_or_else(|| {
+                ErrorCode::UnexpectedError("error occured when downcast MutableArray".to_string())
+            })?;
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        array.push(state.estimate());
+        Ok(())
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -34,7 +34,7 @@ use crate::aggregates::AggregateFunctionRef;
 use crate::with_match_primitive_type;
 
 pub trait AggregateArgMinMaxState: Send + Sync + 'static {
-    fn new(data_type: &DataType) -> Self;
+    fn new() -> Self;
     fn add_keys(
         places: &[StateAddr],
         offset: usize,
@@ -50,203 +50,122 @@ pub trait AggregateArgMinMaxState: Send + Sync + 'static {
     fn merge_result(&mut self, array: &mut dyn MutableArrayBuilder) -> Result<()>;
 }
 
-#[derive(Serialize, Deserialize)]
-struct NumericState<T: DFPrimitiveType> {
-    #[serde(bound(deserialize = "T: DeserializeOwned"))]
-    pub value: Option<T>,
-    pub data: DataValue,
-}
+/// A value of the key or of the argument of argMin/argMax, read from the arrow array of its
+/// series in place and pushed into the result as it is.
+pub trait ArgMinMaxValue:
+    PartialOrd + Clone + Serialize + DeserializeOwned + Send + Sync + 'static
+{
+    // The value of the row, None if it is null. The series must be of the type of the value.
+    fn get(series: &Series, row: usize) -> Option<Self>;
 
-#[derive(Serialize, Deserialize)]
-struct StringState {
-    pub value: Option<Vec<u8>>,
-    pub data: DataValue,
+    fn push_result(value: Option<&Self>, array: &mut dyn MutableArrayBuilder) -> Result<()>;
 }
 
-impl<T> NumericState<T>
-where
-    T: DFPrimitiveType,
-    Option<T>: Into<DataValue>,
-{
-    #[inline]
-    fn merge_value(&mut self, data: DataValue, other: T, is_min: bool) {
-        match &self.value {
-            Some(a) => {
-                let ord = a.partial_cmp(&other);
-                match (ord, is_min) {
-                    (Some(Ordering::Greater), true) | (Some(Ordering::Less), false) => {
-                        self.value = Some(other);
-                        self.data = data;
+macro_rules! impl_primitive_arg_min_max_value {
+    ($($t:ty),*) => {
+        $(
+            impl ArgMinMaxValue for $t {
+                fn get(series: &Series, row: usize) -> Option<Self> {
+                    let array: &DFPrimitiveArray<$t> = series.static_cast();
+                    match array.is_null(row) {
+                        true => None,
+                        false => Some(array.inner().value(row)),
                     }
-                    _ => {}
                 }
-            }
-            None => {
-                self.value = Some(other);
-                self.data = data;
-            }
-        }
-    }
-}
-
-impl<T> AggregateArgMinMaxState for NumericState<T>
-where
-    T: DFPrimitiveType,
-    Option<T>: Into<DataValue>,
-{
-    fn new(data_type: &DataType) -> Self {
-        Self {
-            value: None,
-            data: data_type.into(),
-        }
-    }
 
-    fn add_keys(
-        places: &[StateAddr],
-        offset: usize,
-        data_series: &Series,
-        series: &Series,
-        _rows: usize,
-        is_min: bool,
-    ) -> Result<()> {
-        let array: &DFPrimitiveArray<T> = series.static_cast();
-        array
-            .into_iter()
-            .zip(places.iter().enumerate())
-            .try_for_each(|(key, (idx, addr))| -> Result<()> {
-                let data = data_series.try_get(idx)?;
-                let place = addr.next(offset);
-                let state = place.get::<Self>();
-                if let Some(v) = key {
-                    state.merge_value(data, *v, is_min);
+                fn push_result(
+                    value: Option<&Self>,
+                    array: &mut dyn MutableArrayBuilder,
+                ) -> Result<()> {
+                    let array = array
+                        .as_mut_any()
+                        .downcast_mut::<MutablePrimitiveArrayBuilder<$t, true>>()
+                        .ok_or_else(|| {
+                            ErrorCode::UnexpectedError(
+                                "error occured when downcast MutableArray".to_string(),
+                            )
+                        })?;
+                    array.push_option(value.copied());
+                    Ok(())
                 }
-                Ok(())
-            })
-    }
-
-    fn add_batch(&mut self, data_series: &Series, series: &Series, is_min: bool) -> Result<()> {
-        let arg_result = if is_min {
-            series.arg_min()
-        } else {
-            series.arg_max()
-        }?;
-
-        if let DataValue::Struct(index_value) = arg_result {
-            if index_value[0].is_null() {
-                return Ok(());
             }
-            let value: Result<T> = DFTryFrom::try_from(index_value[1].clone());
+        )*
+    };
+}
 
-            if let Ok(other) = value {
-                let data = data_series.try_get(index_value[0].as_u64()? as usize)?;
-                self.merge_value(data, other, is_min);
-            }
-        }
+impl_primitive_arg_min_max_value!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
 
-        Ok(())
+impl ArgMinMaxValue for Vec<u8> {
+    fn get(series: &Series, row: usize) -> Option<Self> {
+        let array: &DFStringArray = series.static_cast();
+        match array.is_null(row) {
+            true => None,
+            false => Some(array.inner().value(row).to_vec()),
+        }
     }
 
-    fn merge(&mut self, rhs: &Self, is_min: bool) -> Result<()> {
-        if let Some(other) = rhs.value {
-            self.merge_value(rhs.data.clone(), other, is_min);
-        }
+    fn push_result(value: Option<&Self>, array: &mut dyn MutableArrayBuilder) -> Result<()> {
+        let array = array
+            .as_mut_any()
+            .downcast_mut::<MutableStringArrayBuilder<true>>()
+            .ok_or_else(|| {
+                ErrorCode::UnexpectedError("error occured when downcast MutableArray".to_string())
+            })?;
+        array.push_option(value);
         Ok(())
     }
+}
 
-    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
-        serialize_into_buf(writer, self)
+impl ArgMinMaxValue for bool {
+    fn get(series: &Series, row: usize) -> Option<Self> {
+        let array: &DFBooleanArray = series.static_cast();
+        match array.is_null(row) {
+            true => None,
+            false => Some(array.inner().value(row)),
+        }
     }
 
-    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
-        *self = deserialize_from_slice(reader)?;
-
+    fn push_result(value: Option<&Self>, array: &mut dyn MutableArrayBuilder) -> Result<()> {
+        let array = array
+            .as_mut_any()
+            .downcast_mut::<MutableBooleanArrayBuilder<true>>()
+            .ok_or_else(|| {
+                ErrorCode::UnexpectedError("error occured when downcast MutableArray".to_string())
+            })?;
+        array.push_option(value.copied());
         Ok(())
     }
+}
 
-    #[allow(unused_mut)]
-    fn merge_result(&mut self, array: &mut dyn MutableArrayBuilder) -> Result<()> {
-        let datatype = array.data_type();
-        with_match_primitive_type!(datatype, |$T| {
-            let mut array = array
-                .as_mut_any()
-                .downcast_mut::<MutablePrimitiveArrayBuilder<$T, true>>()
-                .ok_or_else(|| {
-                    ErrorCode::UnexpectedError(
-                        "error occured when downcast MutableArray".to_string(),
-                    )
-                })?;
-            if self.data.is_null() {
-                array.push_null();
-            }else {
-                if self.data.is_integer() {
-                    let x = self.data.as_i64()?;
-                    array.push(x as $T);
-                }else {
-                    let x = self.data.as_f64()?;
-                    array.push(x as $T);
-                }
-            }
-        },
-        {
-            match &self.data {
-                DataValue::Boolean(val) => {
-                    let mut array = array
-                    .as_mut_any()
-                    .downcast_mut::<MutableBooleanArrayBuilder<true>>()
-                    .ok_or_else(|| {
-                        ErrorCode::UnexpectedError(
-                            "error occured when downcast MutableArray".to_string(),
-                        )
-                    })?;
-                    array.push_option(*val);
-                }
-                DataValue::String(val) => {
-                    let mut array = array
-                    .as_mut_any()
-                    .downcast_mut::<MutableStringArrayBuilder<true>>()
-                    .ok_or_else(|| {
-                        ErrorCode::UnexpectedError(
-                            "error occured when downcast MutableArray".to_string(),
-                        )
-                    })?;
-                    array.push_option(val.as_ref());
-                },
-                _ => {
-                    return Err(ErrorCode::UnexpectedError(
-                        "aggregate arg_min_max unexpected datatype".to_string(),
-                    ))
-                }
-            }
-        });
-        Ok(())
-    }
+/// The state keeps the key and the argument of its row typed, as the states of the other
+/// aggregate functions. The argument is only read when its key wins.
+#[derive(Serialize, Deserialize)]
+struct ArgMinMaxState<K: ArgMinMaxValue, A: ArgMinMaxValue> {
+    #[serde(bound(deserialize = "K: DeserializeOwned"))]
+    pub key: Option<K>,
+    #[serde(bound(deserialize = "A: DeserializeOwned"))]
+    pub data: Option<A>,
 }
 
-impl StringState {
-    fn merge_value(&mut self, data: DataValue, other: &[u8], is_min: bool) {
-        match &self.value {
-            Some(a) => {
-                let ord = a.as_slice().partial_cmp(other);
-                match (ord, is_min) {
-                    (Some(Ordering::Greater), true) | (Some(Ordering::Less), false) => {
-                        self.value = Some(other.to_vec());
-                        self.data = data;
-                    }
-                    _ => {}
-                }
-            }
-            _ => {
-                self.value = Some(other.to_vec());
-                self.data = data;
-            }
+impl<K: ArgMinMaxValue, A: ArgMinMaxValue> ArgMinMaxState<K, A> {
+    // Whether the key wins over the one kept, the first of the equal keys is kept.
+    #[inline]
+    fn wins(&self, key: &K, is_min: bool) -> bool {
+        match &self.key {
+            Some(a) => matches!(
+                (a.partial_cmp(key), is_min),
+                (Some(Ordering::Greater), true) | (Some(Ordering::Less), false)
+            ),
+            None => true,
         }
     }
 }
-impl AggregateArgMinMaxState for StringState {
-    fn new(data_type: &DataType) -> Self {
+
+impl<K: ArgMinMaxValue, A: ArgMinMaxValue> AggregateArgMinMaxState for ArgMinMaxState<K, A> {
+    fn new() -> Self {
         Self {
-            value: None,
-            data: data_type.into(),
+            key: None,
+            data: None,
         }
     }
 
@@ -258,47 +177,42 @@ impl AggregateArgMinMaxState for StringState {
         _rows: usize,
         is_min: bool,
     ) -> Result<()> {
-        let array: &DFStringArray = series.static_cast();
-        array
-            .into_iter()
-            .zip(places.iter().enumerate())
-            .try_for_each(|(key, (idx, addr))| -> Result<()> {
-                let data = data_series.try_get(idx)?;
-
+        for (row, addr) in places.iter().enumerate() {
+            if let Some(key) = K::get(series, row) {
                 let place = addr.next(offset);
                 let state = place.get::<Self>();
-                if let Some(v) = key {
-                    state.merge_value(data, v, is_min);
+                if state.wins(&key, is_min) {
+                    state.key = Some(key);
+                    state.data = A::get(data_series, row);
                 }
-                Ok(())
-            })
+            }
+        }
+        Ok(())
     }
 
     fn add_batch(&mut self, data_series: &Series, series: &Series, is_min: bool) -> Result<()> {
-        let arg_result = if is_min {
-            series.arg_min()
-        } else {
-            series.arg_max()
-        }?;
-
-        if let DataValue::Struct(index_value) = arg_result {
-            if index_value[0].is_null() {
-                return Ok(());
-            }
-            let value: Result<Vec<u8>> = DFTryFrom::try_from(index_value[1].clone());
-
-            if let Ok(other) = value {
-                let data = data_series.try_get(index_value[0].as_u64()? as usize)?;
-                self.merge_value(data, &other, is_min);
+        let mut winner = None;
+        for row in 0..series.len() {
+            if let Some(key) = K::get(series, row) {
+                if self.wins(&key, is_min) {
+                    self.key = Some(key);
+                    winner = Some(row);
+                }
             }
         }
 
+        if let Some(row) = winner {
+            self.data = A::get(data_series, row);
+        }
         Ok(())
     }
 
     fn merge(&mut self, rhs: &Self, is_min: bool) -> Result<()> {
-        if let Some(other) = &rhs.value {
-            self.merge_value(rhs.data.clone(), other.as_slice(), is_min);
+        if let Some(key) = &rhs.key {
+            if self.wins(key, is_min) {
+                self.key = rhs.key.clone();
+                self.data = rhs.data.clone();
+            }
         }
         Ok(())
     }
@@ -313,62 +227,8 @@ impl AggregateArgMinMaxState for StringState {
         Ok(())
     }
 
-    #[allow(unused_mut)]
     fn merge_result(&mut self, array: &mut dyn MutableArrayBuilder) -> Result<()> {
-        let datatype = array.data_type();
-        with_match_primitive_type!(datatype, |$T| {
-            let mut array = array
-                .as_mut_any()
-                .downcast_mut::<MutablePrimitiveArrayBuilder<$T, true>>()
-                .ok_or_else(|| {
-                    ErrorCode::UnexpectedError(
-                        "error occured when downcast MutableArray".to_string(),
-                    )
-                })?;
-            if self.data.is_null() {
-                array.push_null();
-            }else {
-                if self.data.is_integer() {
-                    let x = self.data.as_i64()?;
-                    array.push(x as $T);
-                }else {
-                    let x = self.data.as_f64()?;
-                    array.push(x as $T);
-                }
-            }
-        },
-        {
-            match &self.data {
-                DataValue::Boolean(val) => {
-                    let mut array = array
-                    .as_mut_any()
-                    .downcast_mut::<MutableBooleanArrayBuilder<true>>()
-                    .ok_or_else(|| {
-                        ErrorCode::UnexpectedError(
-                            "error occured when downcast MutableArray".to_string(),
-                        )
-                    })?;
-                    array.push_option(*val);
-                }
-                DataValue::String(val) => {
-                    let mut array = array
-                    .as_mut_any()
-                    .downcast_mut::<MutableStringArrayBuilder<true>>()
-                    .ok_or_else(|| {
-                        ErrorCode::UnexpectedError(
-                            "error occured when downcast MutableArray".to_string(),
-                        )
-                    })?;
-                array.push_option(val.as_ref());
-                },
-                _ => {
-                    return Err(ErrorCode::UnexpectedError(
-                        "aggregate arg_min_max unexpected datatype".to_string(),
-                    ))
-                }
-            }
-        });
-        Ok(())
+        A::push_result(self.data.as_ref(), array)
     }
 }
 
@@ -396,7 +256,7 @@ where T: AggregateArgMinMaxState //  std::cmp::PartialOrd + DFTryFrom<DataValue>
     }
 
     fn init_state(&self, place: StateAddr) {
-        place.write(|| T::new(self.arguments[0].data_type()));
+        place.write(T::new);
     }
 
     fn state_layout(&self) -> Layout {
@@ -487,46 +347,57 @@ pub fn try_create_aggregate_arg_minmax_function<const IS_MIN: bool>(
     arguments: Vec<DataField>,
 ) -> Result<Arc<dyn AggregateFunction>> {
     assert_binary_arguments(display_name, arguments.len())?;
-    let data_type = arguments[1].data_type();
-
-    with_match_primitive_type!(data_type, |$T| {
-        type AggState = NumericState<$T>;
-        if IS_MIN {
-             AggregateArgMinMaxFunction::<AggState>::try_create_arg_min(
-                display_name,
-                arguments,
-            )
-        } else {
-             AggregateArgMinMaxFunction::<AggState>::try_create_arg_max(
-                display_name,
-                arguments,
-            )
-        }
+    let key_type = arguments[1].data_type().clone();
 
+    with_match_primitive_type!(&key_type, |$K| {
+        try_create_with_key::<$K, IS_MIN>(display_name, arguments)
     },
+    {
+        match key_type {
+            DataType::String => try_create_with_key::<Vec<u8>, IS_MIN>(display_name, arguments),
+            _ => Err(ErrorCode::BadDataValueType(format!(
+                "AggregateArgMinMaxFunction does not support type '{:?}'",
+                key_type
+            ))),
+        }
+    })
+}
 
+fn try_create_with_key<K: ArgMinMaxValue, const IS_MIN: bool>(
+    display_name: &str,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    let data_type = arguments[0].data_type().clone();
+
+    with_match_primitive_type!(&data_type, |$A| {
+        try_create_with_state::<ArgMinMaxState<K, $A>, IS_MIN>(display_name, arguments)
+    },
     {
-        if data_type == &DataType::String {
-            if IS_MIN {
-                return AggregateArgMinMaxFunction::<StringState>::try_create_arg_min(
-                    display_name,
-                    arguments,
-                );
-            } else {
-                return AggregateArgMinMaxFunction::<StringState>::try_create_arg_max(
-                    display_name,
-                    arguments,
-                );
+        match data_type {
+            DataType::String => {
+                try_create_with_state::<ArgMinMaxState<K, Vec<u8>>, IS_MIN>(display_name, arguments)
             }
+            DataType::Boolean => {
+                try_create_with_state::<ArgMinMaxState<K, bool>, IS_MIN>(display_name, arguments)
+            }
+            _ => Err(ErrorCode::BadDataValueType(format!(
+                "AggregateArgMinMaxFunction does not support argument type '{:?}'",
+                data_type
+            ))),
         }
-
-        Err(ErrorCode::BadDataValueType(format!(
-            "AggregateArgMinMaxFunction does not support type '{:?}'",
-            data_type
-        )))
     })
 }
 
+fn try_create_with_state<T: AggregateArgMinMaxState, const IS_MIN: bool>(
+    display_name: &str,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    match IS_MIN {
+        true => AggregateArgMinMaxFunction::<T>::try_create_arg_min(display_name, arguments),
+        false => AggregateArgMinMaxFunction::<T>::try_create_arg_max(display_name, arguments),
+    }
+}
+
 pub fn aggregate_arg_min_function_desc() -> AggregateFunctionDescription {
     AggregateFunctionDescription::creator(Box::new(
         try_create_aggregate_arg_minmax_function::<true>,
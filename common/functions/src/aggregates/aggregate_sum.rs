@@ -31,23 +31,64 @@ use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
 use crate::aggregates::AggregateFunction;
+use crate::scalars::OverflowMode;
 use crate::with_match_primitive_type;
 
+/// The addition of the sums, following the numeric_overflow_mode.
+pub trait SumAdd: Sized {
+    /// None if the result overflows in error mode.
+    fn sum_add(self, other: Self, mode: OverflowMode) -> Option<Self>;
+}
+
+macro_rules! impl_integer_sum_add {
+    ($t: ty) => {
+        impl SumAdd for $t {
+            #[inline(always)]
+            fn sum_add(self, other: Self, mode: OverflowMode) -> Option<Self> {
+                match mode {
+                    OverflowMode::Error => self.checked_add(other),
+                    OverflowMode::Saturate => Some(self.saturating_add(other)),
+                    _ => Some(self.wrapping_add(other)),
+                }
+            }
+        }
+    };
+}
+
+impl_integer_sum_add!(u64);
+impl_integer_sum_add!(i64);
+
+impl SumAdd for f64 {
+    #[inline(always)]
+    fn sum_add(self, other: Self, _mode: OverflowMode) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
 struct AggregateSumState<T> {
     pub value: Option<T>,
 }
 
 impl<T> AggregateSumState<T>
 where
-    T: std::ops::Add<Output = T> + Copy + Clone,
+    T: DFPrimitiveType + SumAdd,
     Option<T>: Serialize + DeserializeOwned,
 {
     #[inline(always)]
-    fn add(&mut self, other: T) {
+    fn add(&mut self, other: T, mode: OverflowMode) -> Result<()> {
         match &self.value {
-            Some(a) => self.value = Some(a.add(other)),
+            Some(a) => match a.sum_add(other, mode) {
+                Some(v) => self.value = Some(v),
+                None => {
+                    return Err(ErrorCode::Overflow(format!(
+                        "Arithmetic overflow: sum is out of the range of {:?}",
+                        T::data_type()
+                    )));
+                }
+            },
             None => self.value = Some(other),
         }
+        Ok(())
     }
 
     fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
@@ -64,6 +105,7 @@ where
 #[derive(Clone)]
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
+    overflow: OverflowMode,
     _arguments: Vec<DataField>,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
@@ -72,7 +114,7 @@ pub struct AggregateSumFunction<T, SumT> {
 impl<T, SumT> AggregateFunction for AggregateSumFunction<T, SumT>
 where
     T: DFPrimitiveType + AsPrimitive<SumT>,
-    SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
+    SumT: DFPrimitiveType + SumAdd,
     Option<SumT>: Into<DataValue>,
 {
     fn name(&self) -> &str {
@@ -96,12 +138,21 @@ where
     }
 
     fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
-        let value = arrays[0].sum()?;
-        let opt_sum: Result<SumT> = DFTryFrom::try_from(value);
+        let state = place.get::<AggregateSumState<SumT>>();
+        if self.overflow == OverflowMode::Wrap {
+            let value = arrays[0].sum()?;
+            let opt_sum: Result<SumT> = DFTryFrom::try_from(value);
 
-        if let Ok(s) = opt_sum {
-            let state = place.get::<AggregateSumState<SumT>>();
-            state.add(s);
+            if let Ok(s) = opt_sum {
+                state.add(s, self.overflow)?;
+            }
+            return Ok(());
+        }
+
+        // The sum of the array may already overflow, add the values one by one.
+        let darray: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        for v in darray.into_iter().flatten() {
+            state.add(v.as_(), self.overflow)?;
         }
 
         Ok(())
@@ -116,28 +167,19 @@ where
     ) -> Result<()> {
         let darray: &DFPrimitiveArray<T> = arrays[0].static_cast();
         if darray.null_count() == 0 {
-            darray
-                .inner()
-                .values()
-                .as_slice()
-                .iter()
-                .zip(places.iter())
-                .for_each(|(v, place)| {
+            for (v, place) in darray.inner().values().as_slice().iter().zip(places.iter()) {
+                let place = place.next(offset);
+                let state = place.get::<AggregateSumState<SumT>>();
+                state.add(v.as_(), self.overflow)?;
+            }
+        } else {
+            for (c, place) in darray.into_iter().zip(places.iter()) {
+                if let Some(v) = c {
                     let place = place.next(offset);
                     let state = place.get::<AggregateSumState<SumT>>();
-                    state.add(v.as_());
-                });
-        } else {
-            darray
-                .into_iter()
-                .zip(places.iter())
-                .for_each(|(c, place)| {
-                    if let Some(v) = c {
-                        let place = place.next(offset);
-                        let state = place.get::<AggregateSumState<SumT>>();
-                        state.add(v.as_());
-                    }
-                });
+                    state.add(v.as_(), self.overflow)?;
+                }
+            }
         }
 
         Ok(())
@@ -157,7 +199,7 @@ where
         let rhs = rhs.get::<AggregateSumState<SumT>>();
         if let Some(s) = &rhs.value {
             let state = place.get::<AggregateSumState<SumT>>();
-            state.add(*s);
+            state.add(*s, self.overflow)?;
         }
         Ok(())
     }
@@ -191,15 +233,17 @@ impl<T, SumT> fmt::Display for AggregateSumFunction<T, SumT> {
 impl<T, SumT> AggregateSumFunction<T, SumT>
 where
     T: DFPrimitiveType + AsPrimitive<SumT>,
-    SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
+    SumT: DFPrimitiveType + SumAdd,
     Option<SumT>: Into<DataValue>,
 {
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+        overflow: OverflowMode,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_owned(),
+            overflow,
             _arguments: arguments,
             t: PhantomData,
             sum_t: PhantomData,
@@ -211,15 +255,28 @@ pub fn try_create_aggregate_sum_function(
     display_name: &str,
     _params: Vec<DataValue>,
     arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create_sum_function(display_name, arguments, OverflowMode::Wrap)
+}
+
+fn try_create_sum_function(
+    display_name: &str,
+    arguments: Vec<DataField>,
+    overflow: OverflowMode,
 ) -> Result<AggregateFunctionRef> {
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
     with_match_primitive_type!(data_type, |$T| {
-        AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
-             display_name,
-             arguments,
-        )
+        if overflow == OverflowMode::Promote {
+            AggregateSumFunction::<$T, f64>::try_create(display_name, arguments, overflow)
+        } else {
+            AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
+                 display_name,
+                 arguments,
+                 overflow,
+            )
+        }
     },
 
     // no matching branch
@@ -234,3 +291,12 @@ pub fn try_create_aggregate_sum_function(
 pub fn aggregate_sum_function_desc() -> AggregateFunctionDescription {
     AggregateFunctionDescription::creator(Box::new(try_create_aggregate_sum_function))
 }
+
+/// The sum of a numeric_overflow_mode other than wrap, e.g. checked_sum.
+pub fn aggregate_overflow_sum_function_desc(
+    overflow: OverflowMode,
+) -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(move |display_name, _params, arguments| {
+        try_create_sum_function(display_name, arguments, overflow)
+    }))
+}
@@ -21,11 +21,14 @@ use crate::aggregates::aggregate_function_factory::AggregateFunctionFactory;
 use crate::aggregates::aggregate_min_max::aggregate_max_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_min_function_desc;
 use crate::aggregates::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
+use crate::aggregates::aggregate_sum::aggregate_overflow_sum_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 use crate::aggregates::aggregate_window_funnel::aggregate_window_funnel_function_desc;
+use crate::aggregates::AggregateApproxCountDistinctFunction;
 use crate::aggregates::AggregateCountFunction;
 use crate::aggregates::AggregateDistinctCombinator;
 use crate::aggregates::AggregateIfCombinator;
+use crate::scalars::OverflowMode;
 
 pub struct Aggregators;
 
@@ -44,8 +47,23 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register(
+            "approx_count_distinct",
+            AggregateApproxCountDistinctFunction::desc(),
+        );
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+
+        // The sum of the numeric_overflow_mode other than wrap.
+        for mode in [
+            OverflowMode::Error,
+            OverflowMode::Saturate,
+            OverflowMode::Promote,
+        ] {
+            if let Some(name) = mode.aggregate_function_name("sum") {
+                factory.register(&name, aggregate_overflow_sum_function_desc(mode));
+            }
+        }
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
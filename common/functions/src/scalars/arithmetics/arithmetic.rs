@@ -15,14 +15,16 @@
 use common_datavalues::prelude::*;
 use common_exception::Result;
 
+use crate::scalars::function_factory::ArithmeticDescription;
 use crate::scalars::function_factory::FunctionFactory;
-use crate::scalars::ArithmeticDivFunction;
-use crate::scalars::ArithmeticIntDivFunction;
+use crate::scalars::ArithmeticDivisionFunction;
 use crate::scalars::ArithmeticMinusFunction;
-use crate::scalars::ArithmeticModuloFunction;
 use crate::scalars::ArithmeticMulFunction;
 use crate::scalars::ArithmeticNegateFunction;
+use crate::scalars::ArithmeticOverflowFunction;
 use crate::scalars::ArithmeticPlusFunction;
+use crate::scalars::DivisionByZeroMode;
+use crate::scalars::OverflowMode;
 
 pub trait ArithmeticTrait {
     fn arithmetic(columns: &DataColumnsWithField) -> Result<DataColumn>;
@@ -40,10 +42,43 @@ impl ArithmeticFunction {
         factory.register_arithmetic("negate", ArithmeticNegateFunction::desc());
         factory.register_arithmetic("*", ArithmeticMulFunction::desc());
         factory.register_arithmetic("multiply", ArithmeticMulFunction::desc());
-        factory.register_arithmetic("/", ArithmeticDivFunction::desc());
-        factory.register_arithmetic("divide", ArithmeticDivFunction::desc());
-        factory.register_arithmetic("%", ArithmeticModuloFunction::desc());
-        factory.register_arithmetic("modulo", ArithmeticModuloFunction::desc());
-        factory.register_arithmetic("div", ArithmeticIntDivFunction::desc());
+        factory.register_arithmetic("/", Self::division(DataValueBinaryOperator::Div));
+        factory.register_arithmetic("divide", Self::division(DataValueBinaryOperator::Div));
+        factory.register_arithmetic("%", Self::division(DataValueBinaryOperator::Modulo));
+        factory.register_arithmetic("modulo", Self::division(DataValueBinaryOperator::Modulo));
+        factory.register_arithmetic("div", Self::division(DataValueBinaryOperator::IntDiv));
+
+        // The /, div and % erroring on a zero divisor, for error_on_division_by_zero.
+        let mode = DivisionByZeroMode::Error;
+        for (name, op) in [
+            ("divide", DataValueBinaryOperator::Div),
+            ("div", DataValueBinaryOperator::IntDiv),
+            ("modulo", DataValueBinaryOperator::Modulo),
+        ] {
+            if let Some(name) = mode.division_function_name(name) {
+                factory.register_arithmetic(&name, ArithmeticDivisionFunction::desc(mode, op));
+            }
+        }
+
+        // The +, - and * of the numeric_overflow_mode other than wrap.
+        for mode in [
+            OverflowMode::Error,
+            OverflowMode::Saturate,
+            OverflowMode::Promote,
+        ] {
+            for (name, op) in [
+                ("plus", DataValueBinaryOperator::Plus),
+                ("minus", DataValueBinaryOperator::Minus),
+                ("multiply", DataValueBinaryOperator::Mul),
+            ] {
+                if let Some(name) = mode.arithmetic_function_name(name) {
+                    factory.register_arithmetic(&name, ArithmeticOverflowFunction::desc(mode, op));
+                }
+            }
+        }
+    }
+
+    fn division(op: DataValueBinaryOperator) -> ArithmeticDescription {
+        ArithmeticDivisionFunction::desc(DivisionByZeroMode::Null, op)
     }
 }
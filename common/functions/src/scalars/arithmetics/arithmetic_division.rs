@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::columns::DataColumnValidity;
+use common_datavalues::prelude::*;
+use common_datavalues::DataTypeAndNullable;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::scalars::function_factory::ArithmeticDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ArithmeticDivFunction;
+use crate::scalars::ArithmeticIntDivFunction;
+use crate::scalars::ArithmeticModuloFunction;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// What `/`, `div` and `%` do when the divisor is zero, set by the
+/// `error_on_division_by_zero` setting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DivisionByZeroMode {
+    /// The result is NULL and a warning is logged, the default as in MySQL.
+    Null,
+    /// Fail the query with a BadArguments error.
+    Error,
+}
+
+impl DivisionByZeroMode {
+    pub fn create(error_on_division_by_zero: bool) -> DivisionByZeroMode {
+        match error_on_division_by_zero {
+            true => DivisionByZeroMode::Error,
+            false => DivisionByZeroMode::Null,
+        }
+    }
+
+    /// The name of the function computing the division `name` in this mode, e.g.
+    /// checked_divide, None if `name` is computed the same way in every mode.
+    pub fn division_function_name(&self, name: &str) -> Option<String> {
+        let name = match name.to_lowercase().as_str() {
+            "/" | "divide" => "divide",
+            "div" => "div",
+            "%" | "mod" | "modulo" => "modulo",
+            _ => return None,
+        };
+        match self {
+            DivisionByZeroMode::Null => None,
+            DivisionByZeroMode::Error => Some(format!("checked_{}", name)),
+        }
+    }
+}
+
+/// The `/`, `div` and `%` functions, computing the rows with a zero divisor by the
+/// [`DivisionByZeroMode`].
+///
+/// The zero divisors are replaced by one before the division is computed, so the inner
+/// function never sees a zero, and the rows are then masked as NULL.
+#[derive(Clone)]
+pub struct ArithmeticDivisionFunction {
+    mode: DivisionByZeroMode,
+    inner: Box<dyn Function>,
+    result_type: DataType,
+}
+
+impl ArithmeticDivisionFunction {
+    pub fn try_create_func(
+        mode: DivisionByZeroMode,
+        op: DataValueBinaryOperator,
+        display_name: &str,
+        args: &[DataTypeAndNullable],
+    ) -> Result<Box<dyn Function>> {
+        let inner = match op {
+            DataValueBinaryOperator::Div => {
+                ArithmeticDivFunction::try_create_func(display_name, args)
+            }
+            DataValueBinaryOperator::IntDiv => {
+                ArithmeticIntDivFunction::try_create_func(display_name, args)
+            }
+            _ => ArithmeticModuloFunction::try_create_func(display_name, args),
+        }?;
+        let result_type = inner.return_type(args)?.data_type().clone();
+
+        Ok(Box::new(ArithmeticDivisionFunction {
+            mode,
+            inner,
+            result_type,
+        }))
+    }
+
+    pub fn desc(mode: DivisionByZeroMode, op: DataValueBinaryOperator) -> ArithmeticDescription {
+        ArithmeticDescription::creator(Box::new(move |display_name, args| {
+            Self::try_create_func(mode, op.clone(), display_name, args)
+        }))
+        .features(
+            FunctionFeatures::default()
+                .deterministic()
+                .monotonicity()
+                .num_arguments(2),
+        )
+    }
+
+    fn on_division_by_zero(&self, rows: usize) -> Result<()> {
+        match self.mode {
+            DivisionByZeroMode::Error => Err(ErrorCode::BadArguments("Division by zero")),
+            DivisionByZeroMode::Null => {
+                tracing::warn!(
+                    "Division by zero in {} rows of {}, the results are NULL",
+                    rows,
+                    self.inner
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Function for ArithmeticDivisionFunction {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        self.inner.get_monotonicity(args)
+    }
+
+    fn return_type(&self, args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
+        let nullable = match self.mode {
+            DivisionByZeroMode::Null => true,
+            DivisionByZeroMode::Error => args.iter().any(|arg| arg.is_nullable()),
+        };
+        Ok(DataTypeAndNullable::create(&self.result_type, nullable))
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let divisor = columns[1].column().cast_with_type(&DataType::Float64)?;
+        let non_zero: Bitmap = match &divisor {
+            DataColumn::Constant(value, _) => {
+                if value.is_null() || value.as_f64()? != 0.0 {
+                    return self.inner.eval(columns, input_rows);
+                }
+                self.on_division_by_zero(input_rows)?;
+                let null_value = DataValue::new_from_data_type(&self.result_type, true);
+                return Ok(DataColumn::Constant(null_value, input_rows));
+            }
+            DataColumn::Array(series) => series
+                .f64()?
+                .iter()
+                .map(|v| v.map_or(true, |v| *v != 0.0))
+                .collect(),
+        };
+
+        let zeros = non_zero.null_count();
+        if zeros == 0 {
+            return self.inner.eval(columns, input_rows);
+        }
+        self.on_division_by_zero(zeros)?;
+
+        let divisor = columns[1].column().to_array()?;
+        let ones = DataColumn::Constant(DataValue::UInt8(Some(1)), input_rows)
+            .cast_with_type(divisor.data_type())?
+            .to_array()?;
+        let is_zero = DFBooleanArray::new_from_iter(non_zero.iter().map(|v| !v)).into_series();
+        let divisor = DataColumnWithField::new(
+            DataColumn::Array(ones.if_then_else(&divisor, &is_zero)?),
+            columns[1].field().clone(),
+        );
+
+        let columns = vec![columns[0].clone(), divisor];
+        let result = self.inner.eval(&columns, input_rows)?;
+        result.apply_validities(&[DataColumnValidity::Array(Some(non_zero), input_rows)])
+    }
+}
+
+impl fmt::Display for ArithmeticDivisionFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
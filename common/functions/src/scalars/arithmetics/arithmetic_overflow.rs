@@ -0,0 +1,193 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataTypeAndNullable;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use num::cast::AsPrimitive;
+use num_traits::CheckedAdd;
+use num_traits::CheckedMul;
+use num_traits::CheckedSub;
+use num_traits::SaturatingAdd;
+use num_traits::SaturatingMul;
+use num_traits::SaturatingSub;
+
+use super::arithmetic::ArithmeticTrait;
+use super::arithmetic_minus::ArithmeticSub;
+use super::arithmetic_mul::ArithmeticMul;
+use super::arithmetic_plus::ArithmeticAdd;
+use crate::binary_arithmetic;
+use crate::impl_checked_binary_arith;
+use crate::impl_saturating_binary_arith;
+use crate::scalars::function_factory::ArithmeticDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ArithmeticMinusFunction;
+use crate::scalars::ArithmeticMulFunction;
+use crate::scalars::ArithmeticPlusFunction;
+use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::Function;
+use crate::try_binary_arithmetic;
+use crate::with_match_primitive_type;
+
+impl_checked_binary_arith!(ArithmeticCheckedAdd, checked_add, +);
+impl_checked_binary_arith!(ArithmeticCheckedSub, checked_sub, -);
+impl_checked_binary_arith!(ArithmeticCheckedMul, checked_mul, *);
+
+impl_saturating_binary_arith!(ArithmeticSaturatingAdd, saturating_add);
+impl_saturating_binary_arith!(ArithmeticSaturatingSub, saturating_sub);
+impl_saturating_binary_arith!(ArithmeticSaturatingMul, saturating_mul);
+
+/// What the integer arithmetic does when a result does not fit in its type, set by the
+/// `numeric_overflow_mode` setting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverflowMode {
+    /// Wrap around on the boundary of the type, the default.
+    Wrap,
+    /// Fail the query with an Overflow error.
+    Error,
+    /// Clamp the result to the minimum or maximum value of the type.
+    Saturate,
+    /// Compute the result in Float64.
+    Promote,
+}
+
+impl OverflowMode {
+    pub fn try_create(mode: &str) -> Result<OverflowMode> {
+        match mode.to_lowercase().as_str() {
+            "wrap" => Ok(OverflowMode::Wrap),
+            "error" => Ok(OverflowMode::Error),
+            "saturate" => Ok(OverflowMode::Saturate),
+            "promote" => Ok(OverflowMode::Promote),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown numeric_overflow_mode '{}', must be one of wrap, error, saturate or promote",
+                mode
+            ))),
+        }
+    }
+
+    fn prefix(&self) -> Option<&'static str> {
+        match self {
+            OverflowMode::Wrap => None,
+            OverflowMode::Error => Some("checked"),
+            OverflowMode::Saturate => Some("saturating"),
+            OverflowMode::Promote => Some("promoting"),
+        }
+    }
+
+    /// The name of the function computing the arithmetic function `name` in this mode,
+    /// None if `name` is computed the same way in every mode.
+    pub fn arithmetic_function_name(&self, name: &str) -> Option<String> {
+        let name = match name.to_lowercase().as_str() {
+            "+" | "plus" => "plus",
+            "-" | "minus" => "minus",
+            "*" | "multiply" => "multiply",
+            _ => return None,
+        };
+        self.prefix().map(|prefix| format!("{}_{}", prefix, name))
+    }
+
+    /// The name of the aggregate function computing `name` in this mode, keeping the
+    /// combinator suffix of sumIf or sumDistinct, None if `name` is computed the same way
+    /// in every mode.
+    pub fn aggregate_function_name(&self, name: &str) -> Option<String> {
+        let lowercase_name = name.to_lowercase();
+        match lowercase_name.strip_prefix("sum") {
+            Some("") | Some("if") | Some("distinct") => self
+                .prefix()
+                .map(|prefix| format!("{}_{}", prefix, lowercase_name)),
+            _ => None,
+        }
+    }
+}
+
+/// The `+`, `-` and `*` of the modes other than wrap, e.g. checked_plus.
+///
+/// Only a 64 bits integer result may overflow, the smaller ones are computed in a
+/// larger type, so the other arguments are handled by the default functions.
+pub struct ArithmeticOverflowFunction;
+
+impl ArithmeticOverflowFunction {
+    pub fn try_create_func(
+        mode: OverflowMode,
+        op: DataValueBinaryOperator,
+        display_name: &str,
+        args: &[DataTypeAndNullable],
+    ) -> Result<Box<dyn Function>> {
+        let left_type = &args[0].data_type();
+        let right_type = &args[1].data_type();
+
+        let default_fn = || match op {
+            DataValueBinaryOperator::Plus => {
+                ArithmeticPlusFunction::try_create_func(display_name, args)
+            }
+            DataValueBinaryOperator::Minus => {
+                ArithmeticMinusFunction::try_create_func(display_name, args)
+            }
+            _ => ArithmeticMulFunction::try_create_func(display_name, args),
+        };
+
+        if mode == OverflowMode::Wrap || !left_type.is_integer() || !right_type.is_integer() {
+            return default_fn();
+        }
+
+        macro_rules! create_overflow_func {
+            ($T: ty, $D: ty, $R: ty, $result_type: expr) => {
+                match (mode, &op) {
+                    (OverflowMode::Error, DataValueBinaryOperator::Plus) => BinaryArithmeticFunction::<ArithmeticCheckedAdd<$T, $D, $R>>::try_create_func(op.clone(), $result_type),
+                    (OverflowMode::Error, DataValueBinaryOperator::Minus) => BinaryArithmeticFunction::<ArithmeticCheckedSub<$T, $D, $R>>::try_create_func(op.clone(), $result_type),
+                    (OverflowMode::Error, _) => BinaryArithmeticFunction::<ArithmeticCheckedMul<$T, $D, $R>>::try_create_func(op.clone(), $result_type),
+                    (OverflowMode::Saturate, DataValueBinaryOperator::Plus) => BinaryArithmeticFunction::<ArithmeticSaturatingAdd<$T, $D, $R>>::try_create_func(op.clone(), $result_type),
+                    (OverflowMode::Saturate, DataValueBinaryOperator::Minus) => BinaryArithmeticFunction::<ArithmeticSaturatingSub<$T, $D, $R>>::try_create_func(op.clone(), $result_type),
+                    (OverflowMode::Saturate, _) => BinaryArithmeticFunction::<ArithmeticSaturatingMul<$T, $D, $R>>::try_create_func(op.clone(), $result_type),
+                    (_, DataValueBinaryOperator::Plus) => BinaryArithmeticFunction::<ArithmeticAdd<$T, $D, f64>>::try_create_func(op.clone(), DataType::Float64),
+                    (_, DataValueBinaryOperator::Minus) => BinaryArithmeticFunction::<ArithmeticSub<$T, $D, f64>>::try_create_func(op.clone(), DataType::Float64),
+                    (_, _) => BinaryArithmeticFunction::<ArithmeticMul<$T, $D, f64>>::try_create_func(op.clone(), DataType::Float64),
+                }
+            };
+        }
+
+        with_match_primitive_type!(left_type, |$T| {
+            with_match_primitive_type!(right_type, |$D| {
+                let result_type = match op {
+                    DataValueBinaryOperator::Minus => <($T, $D) as ResultTypeOfBinary>::Minus::data_type(),
+                    _ => <($T, $D) as ResultTypeOfBinary>::AddMul::data_type(),
+                };
+                match result_type {
+                    DataType::UInt64 => create_overflow_func!($T, $D, u64, result_type),
+                    DataType::Int64 => create_overflow_func!($T, $D, i64, result_type),
+                    _ => default_fn(),
+                }
+            }, {
+                default_fn()
+            })
+        }, {
+            default_fn()
+        })
+    }
+
+    pub fn desc(mode: OverflowMode, op: DataValueBinaryOperator) -> ArithmeticDescription {
+        ArithmeticDescription::creator(Box::new(move |display_name, args| {
+            Self::try_create_func(mode, op.clone(), display_name, args)
+        }))
+        .features(
+            FunctionFeatures::default()
+                .deterministic()
+                .monotonicity()
+                .num_arguments(2),
+        )
+    }
+}
@@ -152,6 +152,79 @@ macro_rules! impl_wrapping_binary_arith {
     };
 }
 
+#[macro_export]
+macro_rules! impl_checked_binary_arith {
+    ($name: ident, $method: ident, $op: tt) => {
+        #[derive(Clone)]
+        pub struct $name<T, D, R> {
+            t: PhantomData<T>,
+            d: PhantomData<D>,
+            r: PhantomData<R>,
+        }
+
+        impl<T, D, R> ArithmeticTrait for $name<T, D, R>
+        where
+            T: DFPrimitiveType + AsPrimitive<R>,
+            D: DFPrimitiveType + AsPrimitive<R> + num::One,
+            R: DFIntegerType
+                + std::fmt::Debug
+                + CheckedAdd<Output = R>
+                + CheckedSub<Output = R>
+                + CheckedMul<Output = R>,
+            DFPrimitiveArray<R>: IntoSeries,
+        {
+            fn arithmetic(columns: &DataColumnsWithField) -> Result<DataColumn> {
+                let op = |l: R, r: R| {
+                    l.$method(&r).ok_or_else(|| {
+                        ErrorCode::Overflow(format!(
+                            "Arithmetic overflow: {:?} {} {:?} is out of the range of {:?}",
+                            l,
+                            stringify!($op),
+                            r,
+                            R::data_type()
+                        ))
+                    })
+                };
+
+                try_binary_arithmetic! {
+                    columns[0].column(),
+                    columns[1].column(),
+                    R,
+                    op,
+                    |lhs: &DFPrimitiveArray<T>, r: R| -> Result<DataColumn> {
+                        Ok(try_unary(lhs, |l| op(l.as_(), r))?.into())
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_saturating_binary_arith {
+    ($name: ident, $method: ident) => {
+        #[derive(Clone)]
+        pub struct $name<T, D, R> {
+            t: PhantomData<T>,
+            d: PhantomData<D>,
+            r: PhantomData<R>,
+        }
+
+        impl<T, D, R> ArithmeticTrait for $name<T, D, R>
+        where
+            T: DFPrimitiveType + AsPrimitive<R>,
+            D: DFPrimitiveType + AsPrimitive<R>,
+            R: DFIntegerType + SaturatingAdd + SaturatingSub + SaturatingMul,
+            DFPrimitiveArray<R>: IntoSeries,
+        {
+            fn arithmetic(columns: &DataColumnsWithField) -> Result<DataColumn> {
+                binary_arithmetic!(columns[0].column(), columns[1].column(), R, |l: R, r: R| l
+                    .$method(&r))
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_binary_arith {
     ($name: ident, $method: tt) => {
@@ -14,11 +14,13 @@
 
 mod arithmetic;
 mod arithmetic_div;
+mod arithmetic_division;
 mod arithmetic_intdiv;
 mod arithmetic_minus;
 mod arithmetic_modulo;
 mod arithmetic_mul;
 mod arithmetic_negate;
+mod arithmetic_overflow;
 mod arithmetic_plus;
 mod binary_arithmetic;
 mod interval;
@@ -28,11 +30,15 @@ mod utils;
 
 pub use arithmetic::ArithmeticFunction;
 pub use arithmetic_div::ArithmeticDivFunction;
+pub use arithmetic_division::ArithmeticDivisionFunction;
+pub use arithmetic_division::DivisionByZeroMode;
 pub use arithmetic_intdiv::ArithmeticIntDivFunction;
 pub use arithmetic_minus::ArithmeticMinusFunction;
 pub use arithmetic_modulo::ArithmeticModuloFunction;
 pub use arithmetic_mul::ArithmeticMulFunction;
 pub use arithmetic_negate::ArithmeticNegateFunction;
+pub use arithmetic_overflow::ArithmeticOverflowFunction;
+pub use arithmetic_overflow::OverflowMode;
 pub use arithmetic_plus::ArithmeticPlusFunction;
 pub use binary_arithmetic::BinaryArithmeticFunction;
 pub use unary_arithmetic::UnaryArithmeticFunction;
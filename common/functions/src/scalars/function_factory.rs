@@ -35,6 +35,7 @@ use crate::scalars::ToCastFunction;
 use crate::scalars::TupleClassFunction;
 use crate::scalars::UUIDFunction;
 use crate::scalars::UdfFunction;
+use crate::scalars::VectorFunction;
 
 pub type FactoryCreator = Box<dyn Fn(&str) -> Result<Box<dyn Function>> + Send + Sync>;
 
@@ -166,6 +167,7 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     MathsFunction::register(&mut function_factory);
     TupleClassFunction::register(&mut function_factory);
     UUIDFunction::register(&mut function_factory);
+    VectorFunction::register(&mut function_factory);
 
     Arc::new(function_factory)
 });
@@ -11,13 +11,15 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use common_datavalues::DataValueBinaryOperator;
 
 use crate::scalars::function_factory::FunctionFactory;
 use crate::scalars::AbsFunction;
-use crate::scalars::ArithmeticModuloFunction;
+use crate::scalars::ArithmeticDivisionFunction;
 use crate::scalars::CRC32Function;
 use crate::scalars::CeilFunction;
 use crate::scalars::DegressFunction;
+use crate::scalars::DivisionByZeroMode;
 use crate::scalars::ExpFunction;
 use crate::scalars::FloorFunction;
 use crate::scalars::LnFunction;
@@ -61,7 +63,13 @@ impl MathsFunction {
         factory.register("ceil", CeilFunction::desc());
         factory.register("ceiling", CeilFunction::desc());
         factory.register("floor", FloorFunction::desc());
-        factory.register_arithmetic("mod", ArithmeticModuloFunction::desc());
+        factory.register_arithmetic(
+            "mod",
+            ArithmeticDivisionFunction::desc(
+                DivisionByZeroMode::Null,
+                DataValueBinaryOperator::Modulo,
+            ),
+        );
         factory.register("exp", ExpFunction::desc());
         factory.register("asin", TrigonometricAsinFunction::desc());
         factory.register("acos", TrigonometricAcosFunction::desc());
@@ -32,6 +32,7 @@ mod strings;
 mod tuples;
 mod udfs;
 mod uuids;
+mod vectors;
 
 pub use arithmetics::*;
 pub use comparisons::*;
@@ -53,3 +54,4 @@ pub use strings::*;
 pub use tuples::*;
 pub use udfs::*;
 pub use uuids::*;
+pub use vectors::*;
@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataTypeAndNullable;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// The distance of two vectors of the same dimension, e.g. the embeddings of a model. The
+/// vectors are arrays of numbers, e.g. Array(Float32), or tuples of numbers.
+#[derive(Clone)]
+pub struct VectorDistanceFunction {
+    d: VectorDistance,
+}
+
+#[derive(Clone, Debug)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl fmt::Display for VectorDistance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = match &self {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+        };
+        write!(f, "{}", display)
+    }
+}
+
+impl VectorDistanceFunction {
+    pub fn try_create_func(d: VectorDistance) -> Result<Box<dyn Function>> {
+        Ok(Box::new(VectorDistanceFunction { d }))
+    }
+
+    fn is_vector(data_type: &DataType) -> bool {
+        match data_type {
+            DataType::List(field) => field.data_type().is_numeric(),
+            DataType::Struct(fields) => fields.iter().all(|f| f.data_type().is_numeric()),
+            _ => false,
+        }
+    }
+
+    // The vector of each row of the minimal array of the column, NULL if any of its values is.
+    fn vectors(column: &DataColumn) -> Result<Vec<Option<Vec<f64>>>> {
+        let series = column.to_minimal_array()?;
+        let array = series.get_array_ref();
+        match series.data_type() {
+            DataType::List(_) => {
+                let lists = DFListArray::from_arrow_array(array.as_ref());
+                lists
+                    .into_iter()
+                    .map(|values| -> Result<Option<Vec<f64>>> {
+                        match values {
+                            None => Ok(None),
+                            Some(values) => {
+                                let values = values.cast_with_type(&DataType::Float64)?;
+                                Ok(values.f64()?.into_iter().map(|v| v.copied()).collect())
+                            }
+                        }
+                    })
+                    .collect()
+            }
+            DataType::Struct(_) => {
+                let tuples = DFStructArray::from_arrow_array(array.as_ref());
+                let fields = tuples
+                    .inner()
+                    .values()
+                    .iter()
+                    .map(|values| -> Result<Vec<Option<f64>>> {
+                        let values = values.clone().into_series();
+                        let values = values.cast_with_type(&DataType::Float64)?;
+                        Ok(values.f64()?.into_iter().map(|v| v.copied()).collect())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok((0..tuples.len())
+                    .map(|row| match tuples.is_null(row) {
+                        true => None,
+                        false => fields.iter().map(|values| values[row]).collect(),
+                    })
+                    .collect())
+            }
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected an array or a tuple of numbers, but got {}",
+                other
+            ))),
+        }
+    }
+
+    fn distance(&self, a: &[f64], b: &[f64]) -> Result<Option<f64>> {
+        if a.len() != b.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The vectors of {} must have the same dimension, but got {} and {}",
+                self.d,
+                a.len(),
+                b.len()
+            )));
+        }
+
+        let pairs = a.iter().zip(b.iter());
+        Ok(match self.d {
+            VectorDistance::Cosine => {
+                let dot = pairs.map(|(x, y)| x * y).sum::<f64>();
+                let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+                let norms = norm(a) * norm(b);
+                // The angle with a zero vector is undefined.
+                match norms == 0.0 {
+                    true => None,
+                    false => Some(1.0 - dot / norms),
+                }
+            }
+            VectorDistance::L2 => Some(pairs.map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()),
+        })
+    }
+}
+
+impl Function for VectorDistanceFunction {
+    fn name(&self) -> &str {
+        "VectorDistanceFunction"
+    }
+
+    fn return_type(&self, args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
+        for arg in args {
+            if !Self::is_vector(arg.data_type()) {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected an array or a tuple of numbers, but got {}",
+                    arg
+                )));
+            }
+        }
+
+        // NULL for the NULL vectors, and the zero vectors of the cosine distance.
+        Ok(DataTypeAndNullable::create(&DataType::Float64, true))
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let a = Self::vectors(columns[0].column())?;
+        let b = Self::vectors(columns[1].column())?;
+
+        // The constant vector, e.g. of the query, is not repeated for each row.
+        let rows = a.len().max(b.len());
+        let mut distances = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let a = &a[if a.len() == 1 { 0 } else { row }];
+            let b = &b[if b.len() == 1 { 0 } else { row }];
+            distances.push(match (a, b) {
+                (Some(a), Some(b)) => self.distance(a, b)?,
+                _ => None,
+            });
+        }
+
+        let column: DataColumn = DFFloat64Array::new_from_opt_iter(distances.into_iter()).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for VectorDistanceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.d)
+    }
+}
+
+pub struct VectorCosineDistanceFunction;
+
+impl VectorCosineDistanceFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        VectorDistanceFunction::try_create_func(VectorDistance::Cosine)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+pub struct VectorL2DistanceFunction;
+
+impl VectorL2DistanceFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        VectorDistanceFunction::try_create_func(VectorDistance::L2)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
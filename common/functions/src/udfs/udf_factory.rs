@@ -96,7 +96,7 @@ impl UDFFactory {
         }
     }
 
-    fn is_builtin_function(name: &str) -> bool {
+    pub fn is_builtin_function(name: &str) -> bool {
         FunctionFactory::instance().check(name) || AggregateFunctionFactory::instance().check(name)
     }
 
@@ -61,7 +61,8 @@ impl UDFParser {
         }
     }
 
-    fn verify_definition_expr(
+    /// Checks the parameters of the definition are all used, and it is not recursive.
+    pub fn verify_definition_expr(
         &mut self,
         tenant_id: &str,
         name: &str,
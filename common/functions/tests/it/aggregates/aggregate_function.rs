@@ -1064,3 +1064,157 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_aggregate_sum_overflow() -> Result<()> {
+    let arena = Bump::new();
+    let arrays: Vec<Series> = vec![Series::new(vec![i64::MAX, 1])];
+    let args = vec![DataField::new("a", DataType::Int64, false)];
+
+    let factory = AggregateFunctionFactory::instance();
+
+    // Accumulates the arrays into two states and merges them.
+    let run_test = |func_name: &'static str, array: &mut dyn MutableArrayBuilder| -> Result<()> {
+        let func = factory.get(func_name, vec![], args.clone())?;
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate(addr1.into(), &arrays[..], 2)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.accumulate(addr2.into(), &arrays[..], 2)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+        func.merge_result(addr1.into(), array)
+    };
+
+    let mut array = MutablePrimitiveArrayBuilder::<i64, true>::default();
+    let result = run_test("checked_sum", &mut array);
+    assert_eq!(
+        "Code: 49, displayText = Arithmetic overflow: sum is out of the range of Int64.",
+        result.unwrap_err().to_string()
+    );
+
+    let mut array = MutablePrimitiveArrayBuilder::<i64, true>::default();
+    run_test("saturating_sum", &mut array)?;
+    assert_eq!(array.values()[0], i64::MAX);
+
+    let mut array = MutablePrimitiveArrayBuilder::<f64, true>::default();
+    run_test("promoting_sum", &mut array)?;
+    assert!(approx_eq!(
+        f64,
+        (i64::MAX as f64 + 1.0) * 2.0,
+        array.values()[0]
+    ));
+
+    let func = factory.get("promoting_sum", vec![], args.clone())?;
+    assert_eq!(func.return_type()?, DataType::Float64);
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_arg_min_max_typed_states() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+
+    // The null keys are skipped, the first of the equal keys wins.
+    let arrays: Vec<Series> = vec![
+        Series::new(vec!["a", "b", "c", "d"]),
+        Series::new(vec![Some(3i64), None, Some(1), Some(1)]),
+    ];
+    let args = vec![
+        DataField::new("a", DataType::String, false),
+        DataField::new("b", DataType::Int64, true),
+    ];
+
+    // Accumulates the arrays into the states of the groups by row, and into a whole state, which
+    // is merged into the first group.
+    let run_test = |func_name: &'static str| -> Result<Vec<DataValue>> {
+        let func = factory.get(func_name, vec![], args.clone())?;
+        let places = (0..4)
+            .map(|_| {
+                let addr = arena.alloc_layout(func.state_layout());
+                func.init_state(addr.into());
+                addr.into()
+            })
+            .collect::<Vec<StateAddr>>();
+        let groups = vec![places[0], places[1], places[0], places[1]];
+        func.accumulate_keys(&groups, 0, &arrays, 4)?;
+        func.accumulate(places[2], &arrays, 4)?;
+        func.merge(places[3], places[2])?;
+
+        let mut array = MutableStringArrayBuilder::<true>::default();
+        for place in [places[0], places[1], places[3]] {
+            func.merge_result(place, &mut array)?;
+        }
+        let series = array.as_series();
+        (0..series.len()).map(|row| series.try_get(row)).collect()
+    };
+
+    assert_eq!(run_test("argmin")?, vec![
+        DataValue::String(Some(b"c".to_vec())),
+        DataValue::String(Some(b"d".to_vec())),
+        DataValue::String(Some(b"c".to_vec())),
+    ]);
+    assert_eq!(run_test("argmax")?, vec![
+        DataValue::String(Some(b"a".to_vec())),
+        DataValue::String(Some(b"d".to_vec())),
+        DataValue::String(Some(b"a".to_vec())),
+    ]);
+
+    // The states of the types not supported are refused when the function is created.
+    let args = vec![
+        DataField::new("a", DataType::Date16, false),
+        DataField::new("b", DataType::Int64, false),
+    ];
+    let result = factory.get("argmin", vec![], args);
+    assert_eq!(
+        "Code: 10, displayText = AggregateArgMinMaxFunction does not support argument type 'Date16'.",
+        result.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_approx_count_distinct() -> Result<()> {
+    let arena = Bump::new();
+    let args = vec![DataField::new("a", DataType::UInt64, false)];
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory.get("approx_count_distinct", vec![], args)?;
+    assert_eq!(func.return_type()?, DataType::UInt64);
+
+    // Accumulates 0..n into one state and n/2..n*3/2 into another, and merges them.
+    let run_test = |n: u64| -> Result<u64> {
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        let arrays = vec![Series::new((0..n).collect::<Vec<_>>())];
+        func.accumulate(addr1.into(), &arrays, n as usize)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        let arrays = vec![Series::new((n / 2..n * 3 / 2).collect::<Vec<_>>())];
+        func.accumulate(addr2.into(), &arrays, n as usize)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+        let mut array = MutablePrimitiveArrayBuilder::<u64, true>::default();
+        func.merge_result(addr1.into(), &mut array)?;
+        Ok(array.values()[0])
+    };
+
+    // Nearly exact for the small cardinalities, within a few standard errors of 1.6% otherwise.
+    assert!((run_test(10)? as i64 - 15).abs() <= 1);
+    for n in [10000u64, 200000] {
+        let expected = (n * 3 / 2) as f64;
+        let estimate = run_test(n)? as f64;
+        assert!(
+            (estimate - expected).abs() / expected < 0.05,
+            "estimate {} of {} distinct values",
+            estimate,
+            expected
+        );
+    }
+
+    Ok(())
+}
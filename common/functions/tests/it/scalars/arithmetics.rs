@@ -147,6 +147,267 @@ fn test_arithmetic_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_arithmetic_overflow_function() -> Result<()> {
+    let int64_args = [
+        DataTypeAndNullable::create(&DataType::Int64, false),
+        DataTypeAndNullable::create(&DataType::Int64, false),
+    ];
+    let uint64_args = [
+        DataTypeAndNullable::create(&DataType::UInt64, false),
+        DataTypeAndNullable::create(&DataType::UInt64, false),
+    ];
+
+    let tests = vec![
+        (
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Error,
+                DataValueBinaryOperator::Plus,
+                "checked_plus",
+                &int64_args,
+            )?,
+            ScalarFunctionTest {
+                name: "checked-plus-int64-passed",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![i64::MAX - 1, -1]).into(),
+                    Series::new(vec![1i64, i64::MIN + 1]).into(),
+                ],
+                expect: Series::new(vec![i64::MAX, i64::MIN]).into(),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Error,
+                DataValueBinaryOperator::Plus,
+                "checked_plus",
+                &int64_args,
+            )?,
+            ScalarFunctionTest {
+                name: "checked-plus-int64-overflow",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![1i64, i64::MAX]).into(),
+                    Series::new(vec![1i64, 1]).into(),
+                ],
+                expect: Series::new(vec![0i64]).into(),
+                error: "Arithmetic overflow: 9223372036854775807 + 1 is out of the range of Int64",
+            },
+        ),
+        (
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Error,
+                DataValueBinaryOperator::Mul,
+                "checked_multiply",
+                &uint64_args,
+            )?,
+            ScalarFunctionTest {
+                name: "checked-multiply-uint64-overflow",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![u64::MAX]).into(),
+                    Series::new(vec![2u64]).into(),
+                ],
+                expect: Series::new(vec![0u64]).into(),
+                error:
+                    "Arithmetic overflow: 18446744073709551615 * 2 is out of the range of UInt64",
+            },
+        ),
+        (
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Saturate,
+                DataValueBinaryOperator::Plus,
+                "saturating_plus",
+                &int64_args,
+            )?,
+            ScalarFunctionTest {
+                name: "saturating-plus-int64-passed",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![i64::MAX, i64::MIN, 1]).into(),
+                    Series::new(vec![1i64, -1, 2]).into(),
+                ],
+                expect: Series::new(vec![i64::MAX, i64::MIN, 3]).into(),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Saturate,
+                DataValueBinaryOperator::Minus,
+                "saturating_minus",
+                &int64_args,
+            )?,
+            ScalarFunctionTest {
+                name: "saturating-minus-int64-passed",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![i64::MIN, 3]).into(),
+                    Series::new(vec![1i64, 2]).into(),
+                ],
+                expect: Series::new(vec![i64::MIN, 1]).into(),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Promote,
+                DataValueBinaryOperator::Mul,
+                "promoting_multiply",
+                &int64_args,
+            )?,
+            ScalarFunctionTest {
+                name: "promoting-multiply-int64-passed",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![i64::MAX, 3]).into(),
+                    Series::new(vec![2i64, 2]).into(),
+                ],
+                expect: Series::new(vec![i64::MAX as f64 * 2.0, 6.0]).into(),
+                error: "",
+            },
+        ),
+        (
+            // Int32 + Int32 is computed in Int64, it does not overflow
+            ArithmeticOverflowFunction::try_create_func(
+                OverflowMode::Error,
+                DataValueBinaryOperator::Plus,
+                "checked_plus",
+                &[
+                    DataTypeAndNullable::create(&DataType::Int32, false),
+                    DataTypeAndNullable::create(&DataType::Int32, false),
+                ],
+            )?,
+            ScalarFunctionTest {
+                name: "checked-plus-int32-passed",
+                nullable: false,
+                columns: vec![
+                    Series::new(vec![i32::MAX]).into(),
+                    Series::new(vec![i32::MAX]).into(),
+                ],
+                expect: Series::new(vec![i32::MAX as i64 * 2]).into(),
+                error: "",
+            },
+        ),
+    ];
+
+    for (test_function, test) in tests {
+        test_scalar_functions(test_function, &[test])?
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_overflow_mode() -> Result<()> {
+    assert_eq!(
+        OverflowMode::try_create("Saturate")?,
+        OverflowMode::Saturate
+    );
+    assert!(OverflowMode::try_create("clamp").is_err());
+
+    assert_eq!(OverflowMode::Wrap.arithmetic_function_name("+"), None);
+    assert_eq!(
+        OverflowMode::Error.arithmetic_function_name("+"),
+        Some("checked_plus".to_string())
+    );
+    assert_eq!(
+        OverflowMode::Promote.arithmetic_function_name("multiply"),
+        Some("promoting_multiply".to_string())
+    );
+    assert_eq!(OverflowMode::Error.arithmetic_function_name("/"), None);
+    assert_eq!(
+        OverflowMode::Saturate.aggregate_function_name("sumIf"),
+        Some("saturating_sumif".to_string())
+    );
+    assert_eq!(OverflowMode::Error.aggregate_function_name("avg"), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_division_by_zero() -> Result<()> {
+    let args = [
+        DataTypeAndNullable::create(&DataType::Int64, false),
+        DataTypeAndNullable::create(&DataType::Int64, false),
+    ];
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec![7i64, 8, -9]).into(),
+            DataField::new("a", DataType::Int64, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![2i64, 0, 3]).into(),
+            DataField::new("b", DataType::Int64, false),
+        ),
+    ];
+
+    let tests = vec![
+        (DataValueBinaryOperator::Div, vec![
+            DataValue::Float64(Some(3.5)),
+            DataValue::Float64(None),
+            DataValue::Float64(Some(-3.0)),
+        ]),
+        (DataValueBinaryOperator::IntDiv, vec![
+            DataValue::Int64(Some(3)),
+            DataValue::Int64(None),
+            DataValue::Int64(Some(-3)),
+        ]),
+        (DataValueBinaryOperator::Modulo, vec![
+            DataValue::Int64(Some(1)),
+            DataValue::Int64(None),
+            DataValue::Int64(Some(0)),
+        ]),
+    ];
+
+    for (op, expect) in tests {
+        // NULL on a zero divisor
+        let func = ArithmeticDivisionFunction::try_create_func(
+            DivisionByZeroMode::Null,
+            op.clone(),
+            "",
+            &args,
+        )?;
+        assert!(func.return_type(&args)?.is_nullable());
+        let result = func.eval(&columns, 3)?;
+        assert_eq!(result.to_values()?, expect, "{}", op);
+
+        // a constant zero divisor
+        let constant_zero = vec![
+            columns[0].clone(),
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Int64(Some(0)), 3),
+                DataField::new("b", DataType::Int64, false),
+            ),
+        ];
+        let result = func.eval(&constant_zero, 3)?;
+        assert_eq!(result.to_values()?, vec![expect[1].clone(); 3], "{}", op);
+
+        // error on a zero divisor
+        let func = ArithmeticDivisionFunction::try_create_func(
+            DivisionByZeroMode::Error,
+            op.clone(),
+            "",
+            &args,
+        )?;
+        assert!(!func.return_type(&args)?.is_nullable());
+        match func.eval(&columns, 3) {
+            Ok(_) => panic!("{} by zero must fail", op),
+            Err(cause) => assert_eq!(cause.message(), "Division by zero"),
+        }
+    }
+
+    assert_eq!(DivisionByZeroMode::Null.division_function_name("/"), None);
+    assert_eq!(
+        DivisionByZeroMode::Error.division_function_name("%"),
+        Some("checked_modulo".to_string())
+    );
+    assert_eq!(DivisionByZeroMode::Error.division_function_name("+"), None);
+
+    Ok(())
+}
+
 #[test]
 fn test_arithmetic_date_interval() -> Result<()> {
     let to_seconds = |y: i32, m: u32, d: u32, h: u32, min: u32, s: u32| -> u32 {
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function_test::test_scalar_functions;
+use crate::scalars::scalar_function_test::ScalarFunctionTest;
+
+fn vector(values: &[f32]) -> DataValue {
+    let values = values
+        .iter()
+        .map(|v| DataValue::Float32(Some(*v)))
+        .collect();
+    DataValue::List(Some(values), DataType::Float32)
+}
+
+fn vectors(rows: &[&[f32]]) -> DataColumn {
+    let mut builder = ListPrimitiveArrayBuilder::<f32>::with_capacity(0, rows.len());
+    for row in rows {
+        builder.append_slice(Some(row));
+    }
+    builder.finish().into_series().into()
+}
+
+#[test]
+fn test_cosine_distance_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "cosine distance of arrays",
+            nullable: true,
+            columns: vec![
+                vectors(&[&[1.0, 0.0], &[0.0, 3.0], &[0.0, 0.0]]),
+                DataColumn::Constant(vector(&[1.0, 0.0]), 3),
+            ],
+            expect: Series::new([Some(0.0_f64), Some(1.0), None]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "cosine distance of tuples",
+            nullable: true,
+            columns: vec![
+                DataColumn::Constant(
+                    DataValue::Struct(vec![DataValue::UInt8(Some(1)), DataValue::UInt8(Some(0))]),
+                    1,
+                ),
+                DataColumn::Constant(
+                    DataValue::Struct(vec![DataValue::UInt8(Some(0)), DataValue::UInt8(Some(2))]),
+                    1,
+                ),
+            ],
+            expect: Series::new([1.0_f64]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "cosine distance of different dimensions",
+            nullable: true,
+            columns: vec![
+                DataColumn::Constant(vector(&[1.0, 0.0]), 1),
+                DataColumn::Constant(vector(&[1.0, 0.0, 0.0]), 1),
+            ],
+            expect: Series::new([0.0_f64]).into(),
+            error: "The vectors of cosine_distance must have the same dimension, but got 2 and 3",
+        },
+        ScalarFunctionTest {
+            name: "cosine distance of numbers",
+            nullable: true,
+            columns: vec![Series::new([1_u8]).into(), Series::new([1_u8]).into()],
+            expect: Series::new([0.0_f64]).into(),
+            error: "Expected an array or a tuple of numbers, but got UInt8",
+        },
+    ];
+
+    test_scalar_functions(VectorCosineDistanceFunction::try_create_func("")?, &tests)
+}
+
+#[test]
+fn test_l2_distance_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "l2 distance of arrays",
+        nullable: true,
+        columns: vec![
+            vectors(&[&[0.0, 0.0], &[3.0, 4.0]]),
+            vectors(&[&[0.0, 0.0], &[0.0, 0.0]]),
+        ],
+        expect: Series::new([0.0_f64, 5.0]).into(),
+        error: "",
+    }];
+
+    test_scalar_functions(VectorL2DistanceFunction::try_create_func("")?, &tests)
+}
@@ -0,0 +1,31 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait ConnectionMgrApi: Sync + Send {
+    // Add a connection to /tenant/connection-name.
+    async fn add_connection(&self, connection: ConnectionInfo) -> Result<u64>;
+
+    async fn get_connection(&self, name: &str, seq: Option<u64>) -> Result<SeqV<ConnectionInfo>>;
+
+    // Get all the connections for a tenant.
+    async fn get_connections(&self) -> Result<Vec<ConnectionInfo>>;
+
+    // Drop the tenant's connection by name.
+    async fn drop_connection(&self, name: &str, seq: Option<u64>) -> Result<()>;
+}
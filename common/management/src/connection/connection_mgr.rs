@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::ConnectionInfo;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::OkOrExist;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVAction;
+
+use crate::connection::ConnectionMgrApi;
+
+static CONNECTION_API_KEY_PREFIX: &str = "__fd_connections";
+
+pub struct ConnectionMgr {
+    kv_api: Arc<dyn KVApi>,
+    connection_prefix: String,
+}
+
+impl ConnectionMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        ConnectionMgr {
+            kv_api,
+            connection_prefix: format!("{}/{}", CONNECTION_API_KEY_PREFIX, tenant),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionMgrApi for ConnectionMgr {
+    async fn add_connection(&self, connection: ConnectionInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&connection)?);
+        let key = format!("{}/{}", self.connection_prefix, connection.name);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVAction::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.into_add_result()?;
+
+        match res.res {
+            OkOrExist::Ok(v) => Ok(v.seq),
+            OkOrExist::Exists(v) => Err(ErrorCode::ConnectionAlreadyExists(format!(
+                "Connection already exists, seq [{}]",
+                v.seq
+            ))),
+        }
+    }
+
+    async fn get_connection(&self, name: &str, seq: Option<u64>) -> Result<SeqV<ConnectionInfo>> {
+        let key = format!("{}/{}", self.connection_prefix, name);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res
+            .ok_or_else(|| ErrorCode::UnknownConnection(format!("Unknown connection {}", name)))?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownConnection(format!(
+                "Unknown connection {}",
+                name
+            ))),
+        }
+    }
+
+    async fn get_connections(&self) -> Result<Vec<ConnectionInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.connection_prefix).await?;
+
+        let mut connections = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            connections.push(ConnectionInfo::try_from(value.data)?);
+        }
+        Ok(connections)
+    }
+
+    async fn drop_connection(&self, name: &str, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.connection_prefix, name);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVAction::new(
+                &key,
+                seq.into(),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownConnection(format!(
+                "Unknown connection {}",
+                name
+            )))
+        }
+    }
+}
@@ -14,12 +14,33 @@
 //
 
 mod cluster;
+mod connection;
+mod network_policy;
+mod ownership;
+mod read_only;
+mod recycle_bin;
+mod role;
+mod row_access_policy;
 mod stage;
 mod udf;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use connection::ConnectionMgr;
+pub use connection::ConnectionMgrApi;
+pub use network_policy::NetworkPolicyMgr;
+pub use network_policy::NetworkPolicyMgrApi;
+pub use ownership::OwnershipMgr;
+pub use ownership::OwnershipMgrApi;
+pub use read_only::ReadOnlyMgr;
+pub use read_only::ReadOnlyMgrApi;
+pub use recycle_bin::RecycleBinMgr;
+pub use recycle_bin::RecycleBinMgrApi;
+pub use role::RoleMgr;
+pub use role::RoleMgrApi;
+pub use row_access_policy::RowAccessPolicyMgr;
+pub use row_access_policy::RowAccessPolicyMgrApi;
 pub use stage::StageMgr;
 pub use stage::StageMgrApi;
 pub use udf::UdfMgr;
@@ -0,0 +1,37 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserIdentity;
+
+#[async_trait::async_trait]
+pub trait OwnershipMgrApi: Sync + Send {
+    // Record the owner of an object, if the seq of the ownership entry matches.
+    // `Some(0)` only records the owner when the object has none yet.
+    async fn set_owner(
+        &self,
+        object: &OwnershipObject,
+        owner: &UserIdentity,
+        seq: Option<u64>,
+    ) -> Result<()>;
+
+    // Get the owner of an object, None if no owner is recorded.
+    async fn get_owner(&self, object: &OwnershipObject) -> Result<Option<UserIdentity>>;
+
+    // Drop the ownership entry of an object.
+    // Dropping a database also drops the entries of the tables in it.
+    async fn drop_owner(&self, object: &OwnershipObject) -> Result<()>;
+}
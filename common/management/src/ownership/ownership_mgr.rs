@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UpsertKVAction;
+use common_meta_types::UserIdentity;
+
+use crate::ownership::OwnershipMgrApi;
+
+static OWNERSHIP_API_KEY_PREFIX: &str = "__fd_object_owners";
+
+pub struct OwnershipMgr {
+    kv_api: Arc<dyn KVApi>,
+    ownership_prefix: String,
+}
+
+impl OwnershipMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        OwnershipMgr {
+            kv_api,
+            ownership_prefix: format!("{}/{}", OWNERSHIP_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    async fn delete_key(&self, key: &str) -> Result<()> {
+        self.kv_api
+            .upsert_kv(UpsertKVAction::new(
+                key,
+                MatchSeq::Any,
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl OwnershipMgrApi for OwnershipMgr {
+    async fn set_owner(
+        &self,
+        object: &OwnershipObject,
+        owner: &UserIdentity,
+        seq: Option<u64>,
+    ) -> Result<()> {
+        let key = format!("{}/{}", self.ownership_prefix, object.key());
+        let val = Operation::Update(serde_json::to_vec(owner)?);
+        self.kv_api
+            .upsert_kv(UpsertKVAction::new(&key, seq.into(), val, None))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_owner(&self, object: &OwnershipObject) -> Result<Option<UserIdentity>> {
+        let key = format!("{}/{}", self.ownership_prefix, object.key());
+        let res = self.kv_api.get_kv(&key).await?;
+        match res {
+            None => Ok(None),
+            Some(seq_value) => match serde_json::from_slice(&seq_value.data) {
+                Ok(owner) => Ok(Some(owner)),
+                Err(serialize_error) => Err(ErrorCode::IllegalUserInfoFormat(format!(
+                    "Cannot deserialize owner of {} from bytes. cause {}",
+                    object, serialize_error
+                ))),
+            },
+        }
+    }
+
+    async fn drop_owner(&self, object: &OwnershipObject) -> Result<()> {
+        if let OwnershipObject::Database(db) = object {
+            let table_prefix = format!("{}/table/{}/", self.ownership_prefix, db);
+            let values = self.kv_api.prefix_list_kv(&table_prefix).await?;
+            for (key, _) in values {
+                self.delete_key(&key).await?;
+            }
+        }
+
+        let key = format!("{}/{}", self.ownership_prefix, object.key());
+        self.delete_key(&key).await
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_exception::Result;
+use common_meta_types::ReadOnlyObject;
+
+#[async_trait::async_trait]
+pub trait ReadOnlyMgrApi: Sync + Send {
+    // Switch the object to read-only, or back to writable.
+    async fn set_read_only(&self, object: &ReadOnlyObject, read_only: bool) -> Result<()>;
+
+    // Whether the object is read-only, the objects are writable by default.
+    async fn is_read_only(&self, object: &ReadOnlyObject) -> Result<bool>;
+
+    // Whether each of the objects is read-only, read together in one request.
+    async fn mget_read_only(&self, objects: &[ReadOnlyObject]) -> Result<Vec<bool>>;
+
+    // Drop the read-only entry of an object.
+    // Dropping a database also drops the entries of the tables in it.
+    async fn drop_read_only(&self, object: &ReadOnlyObject) -> Result<()>;
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::ReadOnlyObject;
+use common_meta_types::UpsertKVAction;
+
+use crate::read_only::ReadOnlyMgrApi;
+
+static READ_ONLY_API_KEY_PREFIX: &str = "__fd_read_only";
+
+pub struct ReadOnlyMgr {
+    kv_api: Arc<dyn KVApi>,
+    read_only_prefix: String,
+}
+
+impl ReadOnlyMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        ReadOnlyMgr {
+            kv_api,
+            read_only_prefix: format!("{}/{}", READ_ONLY_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    async fn upsert_key(&self, key: &str, value: Operation<Vec<u8>>) -> Result<()> {
+        self.kv_api
+            .upsert_kv(UpsertKVAction::new(key, MatchSeq::Any, value, None))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReadOnlyMgrApi for ReadOnlyMgr {
+    async fn set_read_only(&self, object: &ReadOnlyObject, read_only: bool) -> Result<()> {
+        let key = format!("{}/{}", self.read_only_prefix, object.key());
+        match read_only {
+            true => self.upsert_key(&key, Operation::Update(vec![])).await,
+            false => self.upsert_key(&key, Operation::Delete).await,
+        }
+    }
+
+    async fn is_read_only(&self, object: &ReadOnlyObject) -> Result<bool> {
+        let key = format!("{}/{}", self.read_only_prefix, object.key());
+        let res = self.kv_api.get_kv(&key).await?;
+        Ok(res.is_some())
+    }
+
+    async fn mget_read_only(&self, objects: &[ReadOnlyObject]) -> Result<Vec<bool>> {
+        let keys = objects
+            .iter()
+            .map(|object| format!("{}/{}", self.read_only_prefix, object.key()))
+            .collect::<Vec<_>>();
+        let values = self.kv_api.mget_kv(&keys).await?;
+        Ok(values.iter().map(|value| value.is_some()).collect())
+    }
+
+    async fn drop_read_only(&self, object: &ReadOnlyObject) -> Result<()> {
+        if let ReadOnlyObject::Database(db) = object {
+            let table_prefix = format!("{}/table/{}/", self.read_only_prefix, db);
+            let values = self.kv_api.prefix_list_kv(&table_prefix).await?;
+            for (key, _) in values {
+                self.upsert_key(&key, Operation::Delete).await?;
+            }
+        }
+
+        self.set_read_only(object, false).await
+    }
+}
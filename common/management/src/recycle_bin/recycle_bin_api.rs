@@ -0,0 +1,39 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_exception::Result;
+use common_meta_types::DroppedDatabase;
+use common_meta_types::DroppedTable;
+
+#[async_trait::async_trait]
+pub trait RecycleBinMgrApi: Sync + Send {
+    // Put a dropped table into the recycle bin.
+    async fn add_table(&self, table: DroppedTable) -> Result<u64>;
+
+    // Get all the dropped tables of a tenant, the earliest dropped first.
+    async fn get_tables(&self) -> Result<Vec<DroppedTable>>;
+
+    // Remove a dropped table from the recycle bin.
+    async fn remove_table(&self, table: &DroppedTable) -> Result<()>;
+
+    // Put a dropped database into the recycle bin.
+    async fn add_database(&self, database: DroppedDatabase) -> Result<u64>;
+
+    // Get all the dropped databases of a tenant, the earliest dropped first.
+    async fn get_databases(&self) -> Result<Vec<DroppedDatabase>>;
+
+    // Remove a dropped database from the recycle bin.
+    async fn remove_database(&self, database: &DroppedDatabase) -> Result<()>;
+}
@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::DroppedDatabase;
+use common_meta_types::DroppedTable;
+use common_meta_types::MatchSeq;
+use common_meta_types::OkOrExist;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVAction;
+
+use crate::recycle_bin::RecycleBinMgrApi;
+
+static RECYCLE_BIN_API_KEY_PREFIX: &str = "__fd_recycle_bin";
+
+/// The dropped objects are keyed by name and drop time, so the same name can be
+/// dropped many times:
+/// - `<prefix>/<tenant>/table/<db>/<table>/<dropped_on>`
+/// - `<prefix>/<tenant>/database/<db>/<dropped_on>`
+pub struct RecycleBinMgr {
+    kv_api: Arc<dyn KVApi>,
+    table_prefix: String,
+    database_prefix: String,
+}
+
+impl RecycleBinMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        RecycleBinMgr {
+            kv_api,
+            table_prefix: format!("{}/{}/table", RECYCLE_BIN_API_KEY_PREFIX, tenant),
+            database_prefix: format!("{}/{}/database", RECYCLE_BIN_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    fn table_key(&self, table: &DroppedTable) -> String {
+        format!(
+            "{}/{}/{}/{:020}",
+            self.table_prefix,
+            table.db,
+            table.name(),
+            table.dropped_on.timestamp_nanos()
+        )
+    }
+
+    fn database_key(&self, database: &DroppedDatabase) -> String {
+        format!(
+            "{}/{}/{:020}",
+            self.database_prefix,
+            database.db,
+            database.dropped_on.timestamp_nanos()
+        )
+    }
+
+    async fn add(&self, key: &str, value: Vec<u8>) -> Result<u64> {
+        let upsert_info = self.kv_api.upsert_kv(UpsertKVAction::new(
+            key,
+            MatchSeq::Exact(0),
+            Operation::Update(value),
+            None,
+        ));
+
+        let res = upsert_info.await?.into_add_result()?;
+        match res.res {
+            OkOrExist::Ok(v) => Ok(v.seq),
+            OkOrExist::Exists(v) => Err(ErrorCode::IllegalMetaState(format!(
+                "Dropped object {} already exists, seq [{}]",
+                key, v.seq
+            ))),
+        }
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVAction::new(
+                key,
+                MatchSeq::Any,
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownDroppedObject(format!(
+                "Unknown dropped object {}",
+                key
+            )))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RecycleBinMgrApi for RecycleBinMgr {
+    async fn add_table(&self, table: DroppedTable) -> Result<u64> {
+        let key = self.table_key(&table);
+        self.add(&key, serde_json::to_vec(&table)?).await
+    }
+
+    async fn get_tables(&self) -> Result<Vec<DroppedTable>> {
+        let values = self.kv_api.prefix_list_kv(&self.table_prefix).await?;
+
+        let mut tables = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            tables.push(DroppedTable::try_from(value.data)?);
+        }
+        tables.sort_by_key(|table| table.dropped_on);
+        Ok(tables)
+    }
+
+    async fn remove_table(&self, table: &DroppedTable) -> Result<()> {
+        self.remove(&self.table_key(table)).await
+    }
+
+    async fn add_database(&self, database: DroppedDatabase) -> Result<u64> {
+        let key = self.database_key(&database);
+        self.add(&key, serde_json::to_vec(&database)?).await
+    }
+
+    async fn get_databases(&self) -> Result<Vec<DroppedDatabase>> {
+        let values = self.kv_api.prefix_list_kv(&self.database_prefix).await?;
+
+        let mut databases = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            databases.push(DroppedDatabase::try_from(value.data)?);
+        }
+        databases.sort_by_key(|database| database.dropped_on);
+        Ok(databases)
+    }
+
+    async fn remove_database(&self, database: &DroppedDatabase) -> Result<()> {
+        self.remove(&self.database_key(database)).await
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::GrantObject;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::OkOrExist;
+use common_meta_types::Operation;
+use common_meta_types::RoleInfo;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVAction;
+use common_meta_types::UserPrivilegeSet;
+
+use crate::role::RoleMgrApi;
+
+static ROLE_API_KEY_PREFIX: &str = "__fd_roles";
+
+pub struct RoleMgr {
+    kv_api: Arc<dyn KVApi>,
+    role_prefix: String,
+}
+
+impl RoleMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        RoleMgr {
+            kv_api,
+            role_prefix: format!("{}/{}", ROLE_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    // Write the role back, if it is still at the seq it was read at.
+    async fn upsert_role_info(&self, role_info: &RoleInfo, seq: u64) -> Result<u64> {
+        let key = format!("{}/{}", self.role_prefix, role_info.name);
+        let val = Operation::Update(serde_json::to_vec(role_info)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVAction::new(&key, MatchSeq::Exact(seq), val, None))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownRole(format!(
+                "Unknown role {}, or it was changed concurrently",
+                role_info.name
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleMgrApi for RoleMgr {
+    async fn add_role(&self, role_info: RoleInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&role_info)?);
+        let key = format!("{}/{}", self.role_prefix, role_info.name);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVAction::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.into_add_result()?;
+
+        match res.res {
+            OkOrExist::Ok(v) => Ok(v.seq),
+            OkOrExist::Exists(v) => Err(ErrorCode::RoleAlreadyExists(format!(
+                "Role already exists, seq [{}]",
+                v.seq
+            ))),
+        }
+    }
+
+    async fn get_role(&self, name: &str, seq: Option<u64>) -> Result<SeqV<RoleInfo>> {
+        let key = format!("{}/{}", self.role_prefix, name);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownRole(format!("Unknown role {}", name)))?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownRole(format!("Unknown role {}", name))),
+        }
+    }
+
+    async fn get_roles(&self) -> Result<Vec<RoleInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.role_prefix).await?;
+
+        let mut roles = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            roles.push(RoleInfo::try_from(value.data)?);
+        }
+        Ok(roles)
+    }
+
+    async fn grant_role_privileges(
+        &self,
+        name: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<u64> {
+        let SeqV { seq, data, .. } = self.get_role(name, seq).await?;
+        let mut role_info = data;
+        role_info.grant_privileges(&object, privileges);
+        self.upsert_role_info(&role_info, seq).await
+    }
+
+    async fn revoke_role_privileges(
+        &self,
+        name: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        seq: Option<u64>,
+    ) -> Result<u64> {
+        let SeqV { seq, data, .. } = self.get_role(name, seq).await?;
+        let mut role_info = data;
+        role_info.revoke_privileges(&object, privileges);
+        self.upsert_role_info(&role_info, seq).await
+    }
+
+    async fn drop_role(&self, name: &str, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.role_prefix, name);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVAction::new(
+                &key,
+                seq.into(),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownRole(format!("Unknown role {}", name)))
+        }
+    }
+}
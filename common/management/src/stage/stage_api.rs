@@ -22,6 +22,9 @@ pub trait StageMgrApi: Sync + Send {
     // Add a stage info to /tenant/stage-name.
     async fn add_stage(&self, stage: UserStageInfo) -> Result<u64>;
 
+    // Add a stage info to /tenant/stage-name, overwriting the present one in a single write.
+    async fn replace_stage(&self, stage: UserStageInfo) -> Result<u64>;
+
     async fn get_stage(&self, stage_name: &str, seq: Option<u64>) -> Result<SeqV<UserStageInfo>>;
 
     // Get all the stages for a tenant.
@@ -67,6 +67,23 @@ impl StageMgrApi for StageMgr {
         }
     }
 
+    async fn replace_stage(&self, info: UserStageInfo) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = format!("{}/{}", self.stage_prefix, info.stage_name);
+        let upsert_info =
+            self.kv_api
+                .upsert_kv(UpsertKVAction::new(&key, MatchSeq::Any, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownException(format!(
+                "replace stage failed: {}",
+                info.stage_name
+            ))),
+        }
+    }
+
     async fn get_stage(&self, name: &str, seq: Option<u64>) -> Result<SeqV<UserStageInfo>> {
         let key = format!("{}/{}", self.stage_prefix, name);
         let kv_api = self.kv_api.clone();
@@ -101,6 +101,47 @@ impl UdfMgrApi for UdfMgr {
         }
     }
 
+    async fn replace_udf(&self, info: UserDefinedFunction) -> Result<u64> {
+        if UdfMgr::is_builtin_function(info.name.as_str()) {
+            return Err(ErrorCode::UDFAlreadyExists(format!(
+                "Builtin function can not be replaced: {}",
+                info.name.as_str()
+            )));
+        }
+
+        let mut udf_parser = UDFParser::default();
+        udf_parser.parse_definition(
+            &self.tenant,
+            &info.name,
+            &info.parameters,
+            &info.definition,
+        )?;
+
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = format!("{}/{}", self.udf_prefix, info.name);
+        let upsert_info =
+            self.kv_api
+                .upsert_kv(UpsertKVAction::new(&key, MatchSeq::Any, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => {
+                UDFFactory::register(
+                    self.tenant.as_str(),
+                    info.name.as_str(),
+                    &info.parameters,
+                    info.definition.as_str(),
+                )?;
+
+                Ok(s)
+            }
+            None => Err(ErrorCode::UnknownException(format!(
+                "replace UDF failed: {}",
+                info.name
+            ))),
+        }
+    }
+
     async fn update_udf(&self, info: UserDefinedFunction, seq: Option<u64>) -> Result<u64> {
         if UdfMgr::is_builtin_function(info.name.as_str()) {
             return Err(ErrorCode::UDFAlreadyExists(format!(
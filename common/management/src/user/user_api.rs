@@ -60,5 +60,36 @@ pub trait UserMgrApi: Sync + Send {
         seq: Option<u64>,
     ) -> Result<Option<u64>>;
 
+    async fn revoke_all_user_privileges(
+        &self,
+        username: String,
+        hostname: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    async fn update_user_network_policy(
+        &self,
+        username: String,
+        hostname: String,
+        network_policy: Option<String>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    async fn grant_user_role(
+        &self,
+        username: String,
+        hostname: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
+    async fn revoke_user_role(
+        &self,
+        username: String,
+        hostname: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
     async fn drop_user(&self, username: String, hostname: String, seq: Option<u64>) -> Result<()>;
 }
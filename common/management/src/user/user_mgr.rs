@@ -168,6 +168,7 @@ impl UserMgrApi for UserMgr {
             new_password_type.unwrap_or(user_info.password_type),
         );
         new_user_info.grants = user_info.grants;
+        new_user_info.network_policy = user_info.network_policy;
 
         let user_key = format_user_key(&new_user_info.name, &new_user_info.hostname);
         let key = format!("{}/{}", self.user_prefix, user_key);
@@ -233,6 +234,63 @@ impl UserMgrApi for UserMgr {
         Ok(Some(seq))
     }
 
+    async fn revoke_all_user_privileges(
+        &self,
+        username: String,
+        hostname: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        let user_val_seq = self.get_user(username.clone(), hostname.clone(), seq);
+        let mut user_info = user_val_seq.await?.data;
+        user_info.grants.revoke_all_privileges(&username, &hostname);
+        let seq = self.upsert_user_info(&user_info, seq).await?;
+        Ok(Some(seq))
+    }
+
+    async fn update_user_network_policy(
+        &self,
+        username: String,
+        hostname: String,
+        network_policy: Option<String>,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        let user_val_seq = self.get_user(username, hostname, seq);
+        let mut user_info = user_val_seq.await?.data;
+        user_info.network_policy = network_policy;
+        let seq = self.upsert_user_info(&user_info, seq).await?;
+        Ok(Some(seq))
+    }
+
+    async fn grant_user_role(
+        &self,
+        username: String,
+        hostname: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        let user_val_seq = self.get_user(username, hostname, seq);
+        let mut user_info = user_val_seq.await?.data;
+        if !user_info.roles.contains(&role) {
+            user_info.roles.push(role);
+        }
+        let seq = self.upsert_user_info(&user_info, seq).await?;
+        Ok(Some(seq))
+    }
+
+    async fn revoke_user_role(
+        &self,
+        username: String,
+        hostname: String,
+        role: String,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        let user_val_seq = self.get_user(username, hostname, seq);
+        let mut user_info = user_val_seq.await?.data;
+        user_info.roles.retain(|r| r != &role);
+        let seq = self.upsert_user_info(&user_info, seq).await?;
+        Ok(Some(seq))
+    }
+
     async fn drop_user(&self, username: String, hostname: String, seq: Option<u64>) -> Result<()> {
         let user_key = format_user_key(&username, &hostname);
         let key = format!("{}/{}", self.user_prefix, user_key);
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::ConnectionInfo;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_connection() -> Result<()> {
+    let (kv_api, connection_api) = new_connection_api().await?;
+
+    let connection = create_test_connection();
+    connection_api.add_connection(connection.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_connections/databend_query/s3_conn")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&connection)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_already_exists_add_connection() -> Result<()> {
+    let (_, connection_api) = new_connection_api().await?;
+
+    let connection = create_test_connection();
+    connection_api.add_connection(connection.clone()).await?;
+
+    match connection_api.add_connection(connection.clone()).await {
+        Ok(_) => panic!("Already exists add connection must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4121),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_get_connections() -> Result<()> {
+    let (_, connection_api) = new_connection_api().await?;
+
+    let connections = connection_api.get_connections().await?;
+    assert_eq!(connections, vec![]);
+
+    let connection = create_test_connection();
+    connection_api.add_connection(connection.clone()).await?;
+
+    let connections = connection_api.get_connections().await?;
+    assert_eq!(connections, vec![connection.clone()]);
+
+    let got = connection_api.get_connection("s3_conn", None).await?;
+    assert_eq!(got.data, connection);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_connection() -> Result<()> {
+    let (_, connection_api) = new_connection_api().await?;
+
+    let connection = create_test_connection();
+    connection_api.add_connection(connection.clone()).await?;
+    connection_api
+        .drop_connection(&connection.name, None)
+        .await?;
+
+    let connections = connection_api.get_connections().await?;
+    assert_eq!(connections, vec![]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unknown_connection_drop_connection() -> Result<()> {
+    let (_, connection_api) = new_connection_api().await?;
+
+    match connection_api.drop_connection("UNKNOWN_NAME", None).await {
+        Ok(_) => panic!("Unknown connection drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4120),
+    }
+
+    Ok(())
+}
+
+fn create_test_connection() -> ConnectionInfo {
+    let mut credentials = BTreeMap::new();
+    credentials.insert("access_key_id".to_string(), "encrypted_id".to_string());
+    credentials.insert("secret_access_key".to_string(), "encrypted_key".to_string());
+    ConnectionInfo::new("s3_conn", "s3", BTreeMap::new(), credentials, "")
+}
+
+async fn new_connection_api() -> Result<(Arc<MetaEmbedded>, ConnectionMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = ConnectionMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
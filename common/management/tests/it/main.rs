@@ -13,6 +13,13 @@
 // limitations under the License.
 
 mod cluster;
+mod connection;
+mod network_policy;
+mod ownership;
+mod read_only;
+mod recycle_bin;
+mod role;
+mod row_access_policy;
 mod stage;
 mod udf;
 mod user;
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::OwnershipObject;
+use common_meta_types::SeqV;
+use common_meta_types::UserIdentity;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_owner() -> Result<()> {
+    let (kv_api, ownership_api) = new_ownership_api().await?;
+
+    let object = OwnershipObject::Table("db1".to_string(), "t1".to_string());
+    let owner = create_test_owner("u1");
+    ownership_api.set_owner(&object, &owner, None).await?;
+
+    let value = kv_api
+        .get_kv("__fd_object_owners/databend_query/table/db1/t1")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&owner)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_owner_if_absent() -> Result<()> {
+    let (_, ownership_api) = new_ownership_api().await?;
+
+    let object = OwnershipObject::Stage("s1".to_string());
+    assert_eq!(ownership_api.get_owner(&object).await?, None);
+
+    ownership_api
+        .set_owner(&object, &create_test_owner("u1"), Some(0))
+        .await?;
+    ownership_api
+        .set_owner(&object, &create_test_owner("u2"), Some(0))
+        .await?;
+    assert_eq!(
+        ownership_api.get_owner(&object).await?,
+        Some(create_test_owner("u1"))
+    );
+
+    ownership_api
+        .set_owner(&object, &create_test_owner("u2"), None)
+        .await?;
+    assert_eq!(
+        ownership_api.get_owner(&object).await?,
+        Some(create_test_owner("u2"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_database_owner() -> Result<()> {
+    let (_, ownership_api) = new_ownership_api().await?;
+
+    let owner = create_test_owner("u1");
+    let db = OwnershipObject::Database("db1".to_string());
+    let table = OwnershipObject::Table("db1".to_string(), "t1".to_string());
+    let other_table = OwnershipObject::Table("db2".to_string(), "t1".to_string());
+    ownership_api.set_owner(&db, &owner, None).await?;
+    ownership_api.set_owner(&table, &owner, None).await?;
+    ownership_api.set_owner(&other_table, &owner, None).await?;
+
+    ownership_api.drop_owner(&db).await?;
+    assert_eq!(ownership_api.get_owner(&db).await?, None);
+    assert_eq!(ownership_api.get_owner(&table).await?, None);
+    assert_eq!(ownership_api.get_owner(&other_table).await?, Some(owner));
+
+    // Dropping an unknown owner is not an error.
+    ownership_api.drop_owner(&db).await?;
+    Ok(())
+}
+
+fn create_test_owner(username: &str) -> UserIdentity {
+    UserIdentity {
+        username: username.to_string(),
+        hostname: "%".to_string(),
+    }
+}
+
+async fn new_ownership_api() -> Result<(Arc<MetaEmbedded>, OwnershipMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = OwnershipMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::ReadOnlyObject;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_read_only() -> Result<()> {
+    let (kv_api, read_only_api) = new_read_only_api().await?;
+
+    let object = ReadOnlyObject::Table("db1".to_string(), "t1".to_string());
+    assert!(!read_only_api.is_read_only(&object).await?);
+
+    read_only_api.set_read_only(&object, true).await?;
+    assert!(read_only_api.is_read_only(&object).await?);
+    let value = kv_api
+        .get_kv("__fd_read_only/databend_query/table/db1/t1")
+        .await?;
+    assert!(value.is_some());
+
+    // Setting it twice is not an error.
+    read_only_api.set_read_only(&object, true).await?;
+    assert!(read_only_api.is_read_only(&object).await?);
+
+    read_only_api.set_read_only(&object, false).await?;
+    assert!(!read_only_api.is_read_only(&object).await?);
+    let value = kv_api
+        .get_kv("__fd_read_only/databend_query/table/db1/t1")
+        .await?;
+    assert!(value.is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_maintenance_mode() -> Result<()> {
+    let (_, read_only_api) = new_read_only_api().await?;
+
+    let cluster = ReadOnlyObject::Cluster;
+    let db = ReadOnlyObject::Database("db1".to_string());
+    read_only_api.set_read_only(&cluster, true).await?;
+    assert!(read_only_api.is_read_only(&cluster).await?);
+    assert!(!read_only_api.is_read_only(&db).await?);
+
+    read_only_api.set_read_only(&cluster, false).await?;
+    assert!(!read_only_api.is_read_only(&cluster).await?);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mget_read_only() -> Result<()> {
+    let (_, read_only_api) = new_read_only_api().await?;
+
+    let objects = vec![
+        ReadOnlyObject::Cluster,
+        ReadOnlyObject::Database("db1".to_string()),
+        ReadOnlyObject::Table("db1".to_string(), "t1".to_string()),
+    ];
+    assert_eq!(
+        vec![false, false, false],
+        read_only_api.mget_read_only(&objects).await?
+    );
+
+    read_only_api.set_read_only(&objects[2], true).await?;
+    assert_eq!(
+        vec![false, false, true],
+        read_only_api.mget_read_only(&objects).await?
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_database_read_only() -> Result<()> {
+    let (_, read_only_api) = new_read_only_api().await?;
+
+    let db = ReadOnlyObject::Database("db1".to_string());
+    let table = ReadOnlyObject::Table("db1".to_string(), "t1".to_string());
+    let other_table = ReadOnlyObject::Table("db2".to_string(), "t1".to_string());
+    read_only_api.set_read_only(&db, true).await?;
+    read_only_api.set_read_only(&table, true).await?;
+    read_only_api.set_read_only(&other_table, true).await?;
+
+    read_only_api.drop_read_only(&db).await?;
+    assert!(!read_only_api.is_read_only(&db).await?);
+    assert!(!read_only_api.is_read_only(&table).await?);
+    assert!(read_only_api.is_read_only(&other_table).await?);
+
+    // Dropping an unknown entry is not an error.
+    read_only_api.drop_read_only(&db).await?;
+    Ok(())
+}
+
+async fn new_read_only_api() -> Result<(Arc<MetaEmbedded>, ReadOnlyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = ReadOnlyMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::DatabaseMeta;
+use common_meta_types::DroppedDatabase;
+use common_meta_types::DroppedTable;
+use common_meta_types::TableInfo;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_recycle_bin_tables() -> Result<()> {
+    let recycle_bin_api = new_recycle_bin_api().await?;
+    assert_eq!(recycle_bin_api.get_tables().await?, vec![]);
+
+    // The same table can be dropped many times.
+    let first = create_test_table("t1");
+    recycle_bin_api.add_table(first.clone()).await?;
+    let second = create_test_table("t1");
+    recycle_bin_api.add_table(second.clone()).await?;
+
+    let tables = recycle_bin_api.get_tables().await?;
+    assert_eq!(tables, vec![first.clone(), second.clone()]);
+
+    recycle_bin_api.remove_table(&first).await?;
+    assert_eq!(recycle_bin_api.get_tables().await?, vec![second]);
+
+    match recycle_bin_api.remove_table(&first).await {
+        Ok(_) => panic!("Unknown dropped table remove must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4090),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_recycle_bin_databases() -> Result<()> {
+    let recycle_bin_api = new_recycle_bin_api().await?;
+    assert_eq!(recycle_bin_api.get_databases().await?, vec![]);
+
+    let database = DroppedDatabase::new(
+        "db1",
+        DatabaseMeta::default(),
+        vec![create_test_table("t1"), create_test_table("t2")],
+        None,
+    );
+    recycle_bin_api.add_database(database.clone()).await?;
+
+    // Dropped databases and tables are kept apart.
+    assert_eq!(recycle_bin_api.get_databases().await?, vec![
+        database.clone()
+    ]);
+    assert_eq!(recycle_bin_api.get_tables().await?, vec![]);
+
+    recycle_bin_api.remove_database(&database).await?;
+    assert_eq!(recycle_bin_api.get_databases().await?, vec![]);
+    Ok(())
+}
+
+fn create_test_table(name: &str) -> DroppedTable {
+    let table_info = TableInfo {
+        name: name.to_string(),
+        desc: format!("'db1'.'{}'", name),
+        ..Default::default()
+    };
+    DroppedTable::new("db1", table_info, None)
+}
+
+async fn new_recycle_bin_api() -> Result<RecycleBinMgr> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    Ok(RecycleBinMgr::new(test_api, "databend_query"))
+}
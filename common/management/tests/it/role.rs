@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::SeqV;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_role() -> Result<()> {
+    let (kv_api, role_api) = new_role_api().await?;
+
+    let role = RoleInfo::new("analyst");
+    role_api.add_role(role.clone()).await?;
+    let value = kv_api.get_kv("__fd_roles/databend_query/analyst").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&role)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match role_api.add_role(role).await {
+        Ok(_) => panic!("Already exists add role must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4132),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grant_and_revoke_role_privileges() -> Result<()> {
+    let (_, role_api) = new_role_api().await?;
+
+    role_api.add_role(RoleInfo::new("analyst")).await?;
+    let object = GrantObject::Database("db1".to_string());
+    let mut privileges = UserPrivilegeSet::empty();
+    privileges.set_privilege(UserPrivilegeType::Select);
+    privileges.set_privilege(UserPrivilegeType::Insert);
+    role_api
+        .grant_role_privileges("analyst", object.clone(), privileges, None)
+        .await?;
+
+    let (select, insert) = (UserPrivilegeType::Select, UserPrivilegeType::Insert);
+    let grants = role_api.get_role("analyst", None).await?.data.grants;
+    assert!(grants.verify_database_privilege("analyst", "%", "db1", select));
+    assert!(grants.verify_database_privilege("analyst", "%", "db1", insert));
+    assert!(!grants.verify_database_privilege("analyst", "%", "db2", select));
+
+    let mut insert_only = UserPrivilegeSet::empty();
+    insert_only.set_privilege(insert);
+    role_api
+        .revoke_role_privileges("analyst", object, insert_only, None)
+        .await?;
+    let grants = role_api.get_role("analyst", None).await?.data.grants;
+    assert!(grants.verify_database_privilege("analyst", "%", "db1", select));
+    assert!(!grants.verify_database_privilege("analyst", "%", "db1", insert));
+
+    match role_api
+        .grant_role_privileges("unknown", GrantObject::Global, privileges, None)
+        .await
+    {
+        Ok(_) => panic!("Unknown role grant must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4131),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_get_and_drop_roles() -> Result<()> {
+    let (_, role_api) = new_role_api().await?;
+
+    let roles = role_api.get_roles().await?;
+    assert_eq!(roles, vec![]);
+
+    let role = RoleInfo::new("analyst");
+    role_api.add_role(role.clone()).await?;
+    let roles = role_api.get_roles().await?;
+    assert_eq!(roles, vec![role.clone()]);
+
+    role_api.drop_role("analyst", None).await?;
+    let roles = role_api.get_roles().await?;
+    assert_eq!(roles, vec![]);
+
+    match role_api.drop_role("analyst", None).await {
+        Ok(_) => panic!("Unknown role drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4131),
+    }
+    Ok(())
+}
+
+async fn new_role_api() -> Result<(Arc<MetaEmbedded>, RoleMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = RoleMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
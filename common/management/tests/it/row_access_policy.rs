@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::SeqV;
+use common_meta_types::UserIdentity;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_row_access_policies/databend_query/eu_only")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&policy)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_already_exists_add_policy() -> Result<()> {
+    let (_, policy_api) = new_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+
+    match policy_api.add_policy(policy.clone()).await {
+        Ok(_) => panic!("Already exists add policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4081),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_get_policies() -> Result<()> {
+    let (_, policy_api) = new_policy_api().await?;
+
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![]);
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![policy.clone()]);
+
+    let got = policy_api.get_policy("eu_only", None).await?;
+    assert_eq!(got.data, policy);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_policy() -> Result<()> {
+    let (_, policy_api) = new_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+    policy_api.drop_policy(&policy.name, None).await?;
+
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unknown_policy_drop_policy() -> Result<()> {
+    let (_, policy_api) = new_policy_api().await?;
+
+    match policy_api.drop_policy("UNKNOWN_NAME", None).await {
+        Ok(_) => panic!("Unknown policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 4080),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_policy_applies_to() {
+    let mut policy = create_test_policy();
+    assert!(policy.is_protecting("db1", "sales"));
+    assert!(!policy.is_protecting("db1", "orders"));
+    assert!(policy.applies_to("anyone", "%", &[]));
+
+    policy.users = vec![UserIdentity {
+        username: "u1".to_string(),
+        hostname: "%".to_string(),
+    }];
+    assert!(policy.applies_to("u1", "%", &[]));
+    assert!(!policy.applies_to("u2", "%", &[]));
+
+    // Applies to the sessions with one of the roles active as well.
+    policy.roles = vec!["analyst".to_string()];
+    assert!(policy.applies_to("u1", "%", &[]));
+    assert!(policy.applies_to("u2", "%", &["analyst".to_string()]));
+    assert!(!policy.applies_to("u2", "%", &["admin".to_string()]));
+
+    policy.users = vec![];
+    assert!(!policy.applies_to("u1", "%", &[]));
+    assert!(policy.applies_to("u1", "%", &["admin".to_string(), "analyst".to_string()]));
+}
+
+fn create_test_policy() -> RowAccessPolicy {
+    RowAccessPolicy::new("eu_only", "db1", "sales", "region = 'eu'", vec![], vec![])
+}
+
+async fn new_policy_api() -> Result<(Arc<MetaEmbedded>, RowAccessPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = RowAccessPolicyMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
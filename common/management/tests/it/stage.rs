@@ -63,6 +63,21 @@ async fn test_already_exists_add_stage() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_replace_stage() -> Result<()> {
+    let (_, stage_api) = new_stage_api().await?;
+
+    let mut stage_info = create_test_stage_info();
+    stage_api.replace_stage(stage_info.clone()).await?;
+
+    stage_info.comments = String::from("replaced");
+    stage_api.replace_stage(stage_info.clone()).await?;
+
+    let stages = stage_api.get_stages().await?;
+    assert_eq!(stages, vec![stage_info]);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_get_stages() -> Result<()> {
     let (_, stage_api) = new_stage_api().await?;
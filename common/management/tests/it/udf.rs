@@ -60,6 +60,27 @@ async fn test_already_exists_add_udf() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_replace_udf() -> Result<()> {
+    let (_, udf_api) = new_udf_api().await?;
+
+    let udf = create_test_udf();
+    udf_api.replace_udf(udf.clone()).await?;
+    assert_eq!(udf_api.get_udf(&udf.name, None).await?.data, udf);
+
+    let new_udf = UserDefinedFunction::new(
+        "isnotempty",
+        vec!["d".to_string()],
+        "not(isnull(d))",
+        "This is a new description",
+    );
+    udf_api.replace_udf(new_udf.clone()).await?;
+
+    let udfs = udf_api.get_udfs().await?;
+    assert_eq!(udfs, vec![new_udf]);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_get_udfs() -> Result<()> {
     let (_, udf_api) = new_udf_api().await?;
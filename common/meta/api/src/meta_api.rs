@@ -16,6 +16,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -65,5 +67,8 @@ pub trait MetaApi: Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    /// Upserts the options of several tables atomically, for committing a transaction.
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply>;
+
     fn name(&self) -> String;
 }
@@ -227,6 +227,7 @@ impl MetaApiTestSuite {
 
             let mut req = CreateTableReq {
                 if_not_exists: false,
+                or_replace: false,
                 db: db_name.to_string(),
                 table: tbl_name.to_string(),
                 table_meta: TableMeta {
@@ -424,6 +425,7 @@ impl MetaApiTestSuite {
 
             let mut plan = CreateTableReq {
                 if_not_exists: false,
+                or_replace: false,
                 db: db_name.to_string(),
                 table: "tb1".to_string(),
                 table_meta: TableMeta {
@@ -453,6 +455,72 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn table_create_or_replace<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let db_name = "db1";
+        let tbl_name = "tb2";
+
+        tracing::info!("--- prepare db");
+        {
+            let res = self.create_database(mt, db_name).await?;
+            assert_eq!(1, res.database_id, "first database id is 1");
+        }
+
+        let schema = Arc::new(DataSchema::new(vec![DataField::new(
+            "number",
+            DataType::UInt64,
+            false,
+        )]));
+
+        let mut req = CreateTableReq {
+            if_not_exists: false,
+            or_replace: true,
+            db: db_name.to_string(),
+            table: tbl_name.to_string(),
+            table_meta: TableMeta {
+                schema: schema.clone(),
+                engine: "JSON".to_string(),
+                ..TableMeta::default()
+            },
+        };
+
+        tracing::info!("--- create or replace an absent table");
+        {
+            let res = mt.create_table(req.clone()).await?;
+            assert_eq!(1, res.table_id, "table id is 1");
+
+            let got = mt.get_table((db_name, tbl_name).into()).await?;
+            assert_eq!(1, got.ident.table_id);
+            assert_eq!("JSON", got.meta.engine);
+        }
+
+        tracing::info!("--- create or replace a present table");
+        {
+            req.table_meta.engine = "FUSE".to_string();
+            let res = mt.create_table(req.clone()).await?;
+            assert_eq!(2, res.table_id, "replaced table gets a new id");
+
+            let got = mt.get_table((db_name, tbl_name).into()).await?;
+            assert_eq!(2, got.ident.table_id);
+            assert_eq!("FUSE", got.meta.engine);
+
+            let tables = mt.list_tables(ListTableReq::new(db_name)).await?;
+            assert_eq!(1, tables.len(), "the replaced table is gone");
+        }
+
+        tracing::info!("--- create without or_replace still fails on a present table");
+        {
+            req.or_replace = false;
+            let res = mt.create_table(req).await;
+            let status = res.err().unwrap();
+            assert_eq!(
+                format!("Code: 4003, displayText = table exists: {}.", tbl_name),
+                status.to_string()
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl MetaApiTestSuite {
@@ -599,6 +667,7 @@ impl MetaApiTestSuite {
             for tb in tables {
                 let req = CreateTableReq {
                     if_not_exists: false,
+                    or_replace: false,
                     db: db_name.to_string(),
                     table: tb.to_string(),
                     table_meta: TableMeta {
@@ -660,6 +729,7 @@ impl MetaApiTestSuite {
 
             let req = CreateTableReq {
                 if_not_exists: false,
+                or_replace: false,
                 db: db_name.to_string(),
                 table: "tb1".to_string(),
                 table_meta: TableMeta {
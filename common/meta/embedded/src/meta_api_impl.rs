@@ -17,6 +17,8 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use common_exception::Result;
 use common_meta_api::MetaApi;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -94,6 +96,11 @@ impl MetaApi for MetaEmbedded {
         sm.upsert_table_option(req).await
     }
 
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply> {
+        let sm = self.inner.lock().await;
+        sm.commit_tables(req).await
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
@@ -19,6 +19,8 @@ use std::sync::Arc;
 
 use common_arrow::arrow_format::flight::data::Action;
 use common_exception::ErrorCode;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -64,6 +66,7 @@ pub enum MetaFlightAction {
     GetTableExt(GetTableExtReq),
     ListTables(ListTableReq),
     CommitTable(UpsertTableOptionReq),
+    CommitTables(CommitTablesReq),
 
     UpsertKV(UpsertKVAction),
     GetKV(GetKVAction),
@@ -182,6 +185,10 @@ impl RequestFor for UpsertTableOptionReq {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for CommitTablesReq {
+    type Reply = CommitTablesReply;
+}
+
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
 }
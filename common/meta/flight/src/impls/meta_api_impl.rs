@@ -17,6 +17,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_meta_api::MetaApi;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -98,6 +100,10 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
     fn name(&self) -> String {
         "MetaFlightClient".to_string()
     }
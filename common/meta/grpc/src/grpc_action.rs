@@ -19,6 +19,8 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_meta_types::protobuf::GetRequest;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -56,6 +58,7 @@ pub enum MetaGrpcWriteReq {
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
     CommitTable(UpsertTableOptionReq),
+    CommitTables(CommitTablesReq),
     UpsertKV(UpsertKVAction),
 }
 
@@ -199,6 +202,10 @@ impl RequestFor for UpsertTableOptionReq {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for CommitTablesReq {
+    type Reply = CommitTablesReply;
+}
+
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
 }
@@ -21,11 +21,13 @@ use common_exception::Result;
 use common_exception::SerializedError;
 use common_grpc::ConnectionFactory;
 use common_grpc::RpcClientTlsConfig;
+use common_meta_types::check_metasrv_protocol_version;
 use common_meta_types::protobuf::meta_client::MetaClient;
 use common_meta_types::protobuf::GetReply;
 use common_meta_types::protobuf::GetRequest;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::META_PROTOCOL_VERSION;
 use common_tracing::tracing;
 use futures::stream::StreamExt;
 use prost::Message;
@@ -45,6 +47,7 @@ use crate::MetaGrpcClientConf;
 pub struct MetaGrpcClient {
     #[allow(dead_code)]
     token: Vec<u8>,
+    server_protocol_version: u64,
     pub(crate) client: MetaClient<InterceptedService<Channel, AuthInterceptor>>,
 }
 
@@ -82,24 +85,37 @@ impl MetaGrpcClient {
         let channel = res?;
 
         let mut client = MetaClient::new(channel.clone());
-        let token = MetaGrpcClient::handshake(&mut client, username, password).await?;
+        let (token, server_protocol_version) =
+            MetaGrpcClient::handshake(&mut client, username, password).await?;
 
         let client = {
             let token = token.clone();
             MetaClient::with_interceptor(channel, AuthInterceptor { token })
         };
 
-        let rx = Self { token, client };
+        let rx = Self {
+            token,
+            server_protocol_version,
+            client,
+        };
         Ok(rx)
     }
 
-    /// Handshake.
+    /// The protocol version the meta service told in handshake.
+    pub fn server_protocol_version(&self) -> u64 {
+        self.server_protocol_version
+    }
+
+    /// Handshake, returns the token and the protocol version of the meta service.
+    ///
+    /// It fails if the meta service and this client do not support the protocol version of
+    /// each other.
     #[tracing::instrument(level = "debug", skip(client, password))]
     async fn handshake(
         client: &mut MetaClient<Channel>,
         username: &str,
         password: &str,
-    ) -> Result<Vec<u8>> {
+    ) -> Result<(Vec<u8>, u64)> {
         let auth = BasicAuth {
             username: username.to_string(),
             password: password.to_string(),
@@ -109,8 +125,8 @@ impl MetaGrpcClient {
 
         let req = Request::new(futures::stream::once(async {
             HandshakeRequest {
+                protocol_version: META_PROTOCOL_VERSION,
                 payload,
-                ..HandshakeRequest::default()
             }
         }));
 
@@ -118,8 +134,8 @@ impl MetaGrpcClient {
         let mut rx = rx.into_inner();
 
         let resp = rx.next().await.expect("Must respond from handshake")?;
-        let token = resp.payload;
-        Ok(token)
+        check_metasrv_protocol_version(resp.protocol_version)?;
+        Ok((resp.payload, resp.protocol_version))
     }
 
     #[tracing::instrument(level = "debug", skip(self, v))]
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_meta_api::MetaApi;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -105,6 +107,13 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn commit_tables(
+        &self,
+        req: CommitTablesReq,
+    ) -> common_exception::Result<CommitTablesReply> {
+        self.do_write(req).await
+    }
+
     fn name(&self) -> String {
         "MetaGrpcClient".to_string()
     }
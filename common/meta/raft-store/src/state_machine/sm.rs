@@ -46,6 +46,7 @@ use common_meta_types::NodeId;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
+use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 use serde::Deserialize;
 use serde::Serialize;
@@ -562,6 +563,92 @@ impl StateMachine {
                 )))
             }
 
+            Cmd::ReplaceTable {
+                ref db_name,
+                ref table_name,
+                ref table_meta,
+            } => {
+                let db_id = self.txn_get_database_id(db_name, txn_tree).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let lookup_key = TableLookupKey {
+                    database_id: db_id.unwrap(),
+                    table_name: table_name.to_string(),
+                };
+
+                let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+                let seq_table_id = table_lookup_tree.get(&lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                // The old table is removed in the same transaction in which the new one is
+                // added, thus a reader sees either the old table or the new one, never none.
+                let table_tree = txn_tree.key_space::<Tables>();
+                let replaced = match seq_table_id {
+                    Some(u) => {
+                        let (prev, _) = self
+                            .sub_txn_tree_upsert(
+                                &table_tree,
+                                &u.data.0,
+                                &MatchSeq::Any,
+                                Operation::Delete,
+                                None,
+                            )
+                            .map_err(|e| {
+                                let e: ConflictableTransactionError<Infallible> = e.into();
+                                ErrorCode::from(e)
+                            })?;
+                        prev
+                    }
+                    None => None,
+                };
+
+                let table_id = self.txn_incr_seq(SEQ_TABLE_ID, txn_tree).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                self.sub_txn_tree_upsert(
+                    &table_lookup_tree,
+                    &lookup_key,
+                    &MatchSeq::Any,
+                    Operation::Update(TableLookupValue(table_id)),
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let (_, result) = self
+                    .sub_txn_tree_upsert(
+                        &table_tree,
+                        &table_id,
+                        &MatchSeq::Exact(0),
+                        Operation::Update(table_meta.clone()),
+                        None,
+                    )
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                tracing::debug!("applied replace Table: {}={:?}", table_name, result);
+
+                self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                Ok(AppliedState::TableMeta(Change::new_with_id(
+                    table_id, replaced, result,
+                )))
+            }
+
             Cmd::DropTable {
                 ref db_name,
                 ref table_name,
@@ -642,60 +729,94 @@ impl StateMachine {
                 Ok(Change::new(prev, result).into())
             }
 
-            Cmd::UpsertTableOptions(ref req) => {
+            Cmd::UpsertTableOptions(ref req) => self.txn_upsert_table_options(req, txn_tree),
+
+            Cmd::CommitTables(ref req) => {
+                // checks the versions of all the tables before changing any of them
                 let table_tree = txn_tree.key_space::<Tables>();
-                let prev = table_tree.get(&req.table_id).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
+                for req in &req.reqs {
+                    let prev = table_tree.get(&req.table_id).map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+                    let prev = prev.ok_or_else(|| {
+                        ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id))
+                    })?;
 
-                // Unlike other Cmd, prev to be None is not allowed for upsert-options.
-                let prev = prev.ok_or_else(|| {
-                    ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id))
-                })?;
+                    if req.seq.match_seq(&prev).is_err() {
+                        let ch = Change::new_with_id(req.table_id, Some(prev.clone()), Some(prev));
+                        return Ok(AppliedState::TableMeta(ch));
+                    }
+                }
 
-                if req.seq.match_seq(&prev).is_err() {
-                    let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
-                    return Ok(res);
+                let mut res = AppliedState::None;
+                for req in &req.reqs {
+                    res = self.txn_upsert_table_options(req, txn_tree)?;
                 }
+                tracing::debug!("applied CommitTables: {:?}", res);
+                Ok(res)
+            }
+        }
+    }
 
-                let meta = prev.meta.clone();
-                let mut table_meta = prev.data.clone();
-                let opts = &mut table_meta.options;
+    fn txn_upsert_table_options(
+        &self,
+        req: &UpsertTableOptionReq,
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<AppliedState> {
+        let table_tree = txn_tree.key_space::<Tables>();
+        let prev = table_tree.get(&req.table_id).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
 
-                for (k, opt_v) in &req.options {
-                    match opt_v {
-                        None => {
-                            opts.remove(k);
-                        }
-                        Some(v) => {
-                            opts.insert(k.to_string(), v.to_string());
-                        }
-                    }
-                }
+        // Unlike other Cmd, prev to be None is not allowed for upsert-options.
+        let prev =
+            prev.ok_or_else(|| ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id)))?;
 
-                let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
-                let sv = SeqV {
-                    seq: new_seq,
-                    meta,
-                    data: table_meta,
-                };
+        if req.seq.match_seq(&prev).is_err() {
+            let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+            return Ok(res);
+        }
 
-                table_tree.insert(&req.table_id, &sv).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
+        let meta = prev.meta.clone();
+        let mut table_meta = prev.data.clone();
+        if let Some(schema) = &req.schema {
+            table_meta.schema = schema.clone();
+        }
+        let opts = &mut table_meta.options;
 
-                Ok(AppliedState::TableMeta(Change::new_with_id(
-                    req.table_id,
-                    Some(prev),
-                    Some(sv),
-                )))
+        for (k, opt_v) in &req.options {
+            match opt_v {
+                None => {
+                    opts.remove(k);
+                }
+                Some(v) => {
+                    opts.insert(k.to_string(), v.to_string());
+                }
             }
         }
+
+        let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
+        let sv = SeqV {
+            seq: new_seq,
+            meta,
+            data: table_meta,
+        };
+
+        table_tree.insert(&req.table_id, &sv).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
+
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            req.table_id,
+            Some(prev),
+            Some(sv),
+        )))
     }
 
     async fn sub_tree_upsert<'s, V, KS>(
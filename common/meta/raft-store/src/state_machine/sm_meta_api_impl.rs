@@ -19,6 +19,8 @@ use common_exception::ErrorCode;
 use common_meta_api::MetaApi;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -136,15 +138,24 @@ impl MetaApi for StateMachine {
         let db_name = &req.db;
         let table_name = &req.table;
         let if_not_exists = req.if_not_exists;
+        let or_replace = req.or_replace;
 
         tracing::info!("create table: {:}: {:?}", &db_name, &table_name);
 
         let table_meta = req.table_meta;
 
-        let cr = Cmd::CreateTable {
-            db_name: db_name.clone(),
-            table_name: table_name.clone(),
-            table_meta,
+        let cr = if or_replace {
+            Cmd::ReplaceTable {
+                db_name: db_name.clone(),
+                table_name: table_name.clone(),
+                table_meta,
+            }
+        } else {
+            Cmd::CreateTable {
+                db_name: db_name.clone(),
+                table_name: table_name.clone(),
+                table_meta,
+            }
         };
 
         let res = self.sm_tree.txn(true, |t| {
@@ -158,7 +169,7 @@ impl MetaApi for StateMachine {
 
         assert!(result.is_some());
 
-        if prev.is_some() && !if_not_exists {
+        if prev.is_some() && !if_not_exists && !or_replace {
             Err(ErrorCode::TableAlreadyExists(format!(
                 "table exists: {}",
                 table_name
@@ -303,6 +314,30 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply, ErrorCode> {
+        if req.reqs.is_empty() {
+            return Ok(CommitTablesReply {});
+        }
+        let cmd = Cmd::CommitTables(req.clone());
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let table_id = ch.ident.unwrap_or_default();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "table of id {} is changed, current version {}",
+                table_id, prev.seq,
+            )));
+        }
+
+        Ok(CommitTablesReply {})
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
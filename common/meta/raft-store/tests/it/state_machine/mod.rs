@@ -33,6 +33,7 @@ use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_types::AppliedState;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
@@ -241,6 +242,7 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
                         "a".to_string() => Some("A".to_string()),
                         "b".to_string() => None,
                     },
+                    schema: None,
                 }),
                 &t,
             )
@@ -291,6 +293,7 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
                     table_id: 0,
                     seq: MatchSeq::Exact(version - 1),
                     options: hashmap! {},
+                    schema: None,
                 }),
                 &t,
             );
@@ -314,6 +317,7 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
                     table_id,
                     seq: MatchSeq::Exact(version - 1),
                     options: hashmap! {},
+                    schema: None,
                 }),
                 &t,
             )
@@ -337,6 +341,7 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
                         "a".to_string() => None,
                         "c".to_string() => Some("C".to_string()),
                     },
+                    schema: None,
                 }),
                 &t,
             )
@@ -380,6 +385,110 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_commit_tables() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let m = StateMachine::open(&tc.raft_config, 1).await?;
+
+    tracing::info!("--- prepare two tables");
+
+    m.sm_tree.txn(true, |t| {
+        Ok(m.apply_cmd(
+            &Cmd::CreateDatabase {
+                name: "db1".to_string(),
+                meta: DatabaseMeta {
+                    engine: "defeault".to_string(),
+                    ..Default::default()
+                },
+            },
+            &t,
+        )
+        .unwrap())
+    })?;
+
+    let mut tables = vec![];
+    for table_name in ["tb1", "tb2"] {
+        let resp = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateTable {
+                    db_name: "db1".to_string(),
+                    table_name: table_name.to_string(),
+                    table_meta: Default::default(),
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+        let mut ch: Change<TableMeta, u64> = resp.try_into().unwrap();
+        let table_id = ch.ident.take().unwrap();
+        tables.push((table_id, ch.result.unwrap().seq));
+    }
+    let (table_id_1, version_1) = tables[0];
+    let (table_id_2, version_2) = tables[1];
+
+    let commit = |version_2: u64| {
+        Cmd::CommitTables(CommitTablesReq {
+            reqs: vec![
+                UpsertTableOptionReq {
+                    table_id: table_id_1,
+                    seq: MatchSeq::Exact(version_1),
+                    options: hashmap! {"a".to_string() => Some("A".to_string())},
+                    schema: None,
+                },
+                UpsertTableOptionReq {
+                    table_id: table_id_2,
+                    seq: MatchSeq::Exact(version_2),
+                    options: hashmap! {"b".to_string() => Some("B".to_string())},
+                    schema: None,
+                },
+            ],
+        })
+    };
+
+    tracing::info!("--- a mismatched seq of one table wont update any table");
+    {
+        let resp = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(&commit(version_2 - 1), &t).unwrap())
+        })?;
+
+        let ch: Change<TableMeta> = resp.try_into().unwrap();
+        assert_eq!(Some(table_id_2), ch.ident);
+        let (prev, result) = ch.unwrap();
+        assert_eq!(prev, result);
+
+        let got = m.get_table_meta_by_id(&table_id_1)?.unwrap();
+        assert_eq!(version_1, got.seq);
+        assert_eq!(HashMap::new(), got.data.options);
+    }
+
+    tracing::info!("--- commit OK, all tables are updated");
+    {
+        let resp = m
+            .sm_tree
+            .txn(true, |t| Ok(m.apply_cmd(&commit(version_2), &t).unwrap()))?;
+        assert!(resp.changed());
+
+        let got = m.get_table_meta_by_id(&table_id_1)?.unwrap();
+        assert!(got.seq > version_1);
+        assert_eq!(
+            hashmap! {"a".to_string() => "A".to_string()},
+            got.data.options
+        );
+
+        let got = m.get_table_meta_by_id(&table_id_2)?.unwrap();
+        assert!(got.seq > version_2);
+        assert_eq!(
+            hashmap! {"b".to_string() => "B".to_string()},
+            got.data.options
+        );
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
@@ -18,6 +18,7 @@ use async_raft::NodeId;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::CommitTablesReq;
 use crate::DatabaseMeta;
 use crate::KVMeta;
 use crate::MatchSeq;
@@ -50,6 +51,15 @@ pub enum Cmd {
         table_meta: TableMeta,
     },
 
+    /// Create a table, or atomically replace the present one with the same name.
+    ///
+    /// The replaced table is dropped and the new table gets a new table id.
+    ReplaceTable {
+        db_name: String,
+        table_name: String,
+        table_meta: TableMeta,
+    },
+
     /// Drop a table if absent
     DropTable { db_name: String, table_name: String },
 
@@ -62,6 +72,13 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Update, remove or insert the options of several tables, all or none.
+    ///
+    /// With the seq of any table mismatched, no table is changed, and it returns the unchanged
+    /// state of the first mismatched table, with its id: (prev:TableMeta, prev:TableMeta).
+    /// Otherwise it returns the TableMeta before and after update of the last table.
+    CommitTables(CommitTablesReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -102,6 +119,13 @@ impl fmt::Display for Cmd {
             } => {
                 write!(f, "create_table:{}-{}={}", db_name, table_name, table_meta)
             }
+            Cmd::ReplaceTable {
+                db_name,
+                table_name,
+                table_meta,
+            } => {
+                write!(f, "replace_table:{}-{}={}", db_name, table_name, table_meta)
+            }
             Cmd::DropTable {
                 db_name,
                 table_name,
@@ -127,6 +151,17 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::CommitTables(req) => {
+                write!(f, "commit-tables:")?;
+                for req in &req.reqs {
+                    write!(
+                        f,
+                        " table-id:{}({:?}) = {:?}",
+                        req.table_id, req.seq, req.options
+                    )?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -25,13 +25,21 @@ mod kv_message;
 mod log_entry;
 mod match_seq;
 mod message;
+mod network_policy;
 mod operation;
+mod ownership;
+mod protocol_version;
 mod raft_txid;
 mod raft_types;
+mod read_only;
+mod recycle_bin;
+mod role_info;
+mod row_access_policy;
 mod seq_num;
 mod seq_value;
 mod table;
 mod user_auth;
+mod user_connection;
 mod user_defined_function;
 mod user_grant;
 mod user_identity;
@@ -78,18 +86,34 @@ pub use message::ForwardRequest;
 pub use message::ForwardRequestBody;
 pub use message::ForwardResponse;
 pub use message::JoinRequest;
+pub use network_policy::NetworkPolicy;
 pub use operation::MetaId;
 pub use operation::MetaVersion;
 pub use operation::Operation;
+pub use ownership::OwnershipObject;
+pub use protocol_version::check_metacli_protocol_version;
+pub use protocol_version::check_metasrv_protocol_version;
+pub use protocol_version::check_protocol_version;
+pub use protocol_version::MetaCompatibility;
+pub use protocol_version::META_PROTOCOL_VERSION;
+pub use protocol_version::MIN_METACLI_PROTOCOL_VERSION;
+pub use protocol_version::MIN_METASRV_PROTOCOL_VERSION;
 pub use raft_txid::RaftTxId;
 pub use raft_types::LogId;
 pub use raft_types::LogIndex;
 pub use raft_types::NodeId;
 pub use raft_types::Term;
+pub use read_only::ReadOnlyObject;
+pub use recycle_bin::DroppedDatabase;
+pub use recycle_bin::DroppedTable;
+pub use role_info::RoleInfo;
+pub use row_access_policy::RowAccessPolicy;
 pub use seq_num::SeqNum;
 pub use seq_value::IntoSeqV;
 pub use seq_value::KVMeta;
 pub use seq_value::SeqV;
+pub use table::CommitTablesReply;
+pub use table::CommitTablesReq;
 pub use table::CreateTableReply;
 pub use table::CreateTableReq;
 pub use table::DropTableReply;
@@ -103,6 +127,8 @@ pub use table::TableNameIndent;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
 pub use user_auth::PasswordType;
+pub use user_connection::ConnectionInfo;
+pub use user_connection::CONNECTION_STORAGE_TYPES;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
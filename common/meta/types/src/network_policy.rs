@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::convert::TryFrom;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// A network policy restricts the client addresses a user can connect from.
+///
+/// Both lists hold IP addresses or CIDR ranges, e.g. `192.168.1.0/24`. A client is
+/// rejected if it matches the blocked list, or if the allowed list is not empty and
+/// the client matches none of it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: String,
+}
+
+impl NetworkPolicy {
+    pub fn new(
+        name: &str,
+        allowed_ip_list: Vec<String>,
+        blocked_ip_list: Vec<String>,
+        comment: &str,
+    ) -> Self {
+        NetworkPolicy {
+            name: name.to_string(),
+            allowed_ip_list,
+            blocked_ip_list,
+            comment: comment.to_string(),
+        }
+    }
+
+    /// Check that all the entries of the lists are valid addresses or ranges.
+    pub fn validate(&self) -> Result<()> {
+        for ip_range in self.allowed_ip_list.iter().chain(&self.blocked_ip_list) {
+            IpRange::from_str(ip_range)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_allowed(&self, addr: &IpAddr) -> bool {
+        let contains = |list: &Vec<String>| {
+            list.iter()
+                .any(|ip_range| match IpRange::from_str(ip_range) {
+                    Ok(ip_range) => ip_range.contains(addr),
+                    Err(_) => false,
+                })
+        };
+
+        if contains(&self.blocked_ip_list) {
+            return false;
+        }
+        self.allowed_ip_list.is_empty() || contains(&self.allowed_ip_list)
+    }
+}
+
+/// An address range in CIDR notation, a single address being a range of its own.
+struct IpRange {
+    addr: IpAddr,
+    prefix_len: u32,
+}
+
+impl IpRange {
+    fn contains(&self, addr: &IpAddr) -> bool {
+        match (&self.addr, normalize(addr)) {
+            (IpAddr::V4(range), IpAddr::V4(addr)) => Self::prefix_matches(
+                u32::from(*range) as u128,
+                u32::from(addr) as u128,
+                32,
+                self.prefix_len,
+            ),
+            (IpAddr::V6(range), IpAddr::V6(addr)) => {
+                Self::prefix_matches(u128::from(*range), u128::from(addr), 128, self.prefix_len)
+            }
+            _ => false,
+        }
+    }
+
+    fn prefix_matches(range: u128, addr: u128, bits: u32, prefix_len: u32) -> bool {
+        let shift = bits - prefix_len;
+        shift >= bits || (range >> shift) == (addr >> shift)
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let illegal = || {
+            ErrorCode::IllegalNetworkPolicyFormat(format!(
+                "Illegal IP address or range '{}', expected e.g. '192.168.1.1' or '192.168.1.0/24'",
+                s
+            ))
+        };
+
+        let (addr, prefix_len) = match s.trim().split_once('/') {
+            None => (s.trim(), None),
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+        };
+        let addr = IpAddr::from_str(addr).map_err(|_| illegal())?;
+        let bits = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_len = match prefix_len {
+            None => bits,
+            Some(prefix_len) => match prefix_len.parse::<u32>() {
+                Ok(prefix_len) if prefix_len <= bits => prefix_len,
+                _ => return Err(illegal()),
+            },
+        };
+
+        Ok(IpRange { addr, prefix_len })
+    }
+}
+
+// An IPv4 client may show up as an IPv4-mapped IPv6 address.
+fn normalize(addr: &IpAddr) -> IpAddr {
+    match addr {
+        IpAddr::V6(v6) => match v6.to_ipv4() {
+            Some(v4) if v6.segments()[..5] == [0; 5] && v6.segments()[5] == 0xffff => {
+                IpAddr::V4(v4)
+            }
+            _ => *addr,
+        },
+        IpAddr::V4(_) => *addr,
+    }
+}
+
+impl TryFrom<Vec<u8>> for NetworkPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalNetworkPolicyFormat(format!(
+                "Cannot deserialize network policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::fmt;
+
+/// An object that has an owner recorded in meta.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum OwnershipObject {
+    Database(String),
+    Table(String, String),
+    Stage(String),
+    UDF(String),
+    Connection(String),
+}
+
+impl OwnershipObject {
+    /// The key of the object, relative to the tenant's ownership prefix.
+    pub fn key(&self) -> String {
+        match self {
+            OwnershipObject::Database(db) => format!("database/{}", db),
+            OwnershipObject::Table(db, table) => format!("table/{}/{}", db, table),
+            OwnershipObject::Stage(name) => format!("stage/{}", name),
+            OwnershipObject::UDF(name) => format!("udf/{}", name),
+            OwnershipObject::Connection(name) => format!("connection/{}", name),
+        }
+    }
+}
+
+impl fmt::Display for OwnershipObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
+        match self {
+            OwnershipObject::Database(ref db) => write!(f, "DATABASE '{}'", db),
+            OwnershipObject::Table(ref db, ref table) => {
+                write!(f, "TABLE '{}'.'{}'", db, table)
+            }
+            OwnershipObject::Stage(ref name) => write!(f, "STAGE '{}'", name),
+            OwnershipObject::UDF(ref name) => write!(f, "FUNCTION '{}'", name),
+            OwnershipObject::Connection(ref name) => write!(f, "CONNECTION '{}'", name),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The version of the meta data format and of the protocol between the meta service and its
+/// clients. It is bumped on every change that a binary of an older version does not understand,
+/// and is exchanged in handshake.
+pub const META_PROTOCOL_VERSION: u64 = 1;
+
+/// The oldest meta service a client of this binary works with.
+///
+/// 0 stands for the meta services built before the versions are exchanged in handshake.
+pub const MIN_METASRV_PROTOCOL_VERSION: u64 = 0;
+
+/// The oldest client the meta service of this binary accepts.
+///
+/// 0 stands for the clients built before the versions are exchanged in handshake.
+pub const MIN_METACLI_PROTOCOL_VERSION: u64 = 0;
+
+/// How the peer of a handshake, of a supported protocol version, works with this binary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetaCompatibility {
+    Same,
+    /// The peer does not know about the changes made after its version, which are not used by it.
+    Older,
+    /// The meta data written by the peer may not be fully understood by this binary, which is
+    /// expected to be upgraded soon.
+    Newer,
+}
+
+/// Checks the protocol version of the meta service a client connects to.
+pub fn check_metasrv_protocol_version(ver: u64) -> Result<MetaCompatibility> {
+    check_protocol_version("meta service", ver, MIN_METASRV_PROTOCOL_VERSION)
+}
+
+/// Checks the protocol version of a client connecting to the meta service.
+pub fn check_metacli_protocol_version(ver: u64) -> Result<MetaCompatibility> {
+    check_protocol_version("meta client", ver, MIN_METACLI_PROTOCOL_VERSION)
+}
+
+pub fn check_protocol_version(peer: &str, ver: u64, min_ver: u64) -> Result<MetaCompatibility> {
+    if ver < min_ver {
+        return Err(ErrorCode::MetaServiceIncompatible(format!(
+            "{} of protocol version {} is too old, the oldest supported is {}, \
+             upgrade the meta service before its clients",
+            peer, ver, min_ver
+        )));
+    }
+
+    Ok(match ver.cmp(&META_PROTOCOL_VERSION) {
+        std::cmp::Ordering::Equal => MetaCompatibility::Same,
+        std::cmp::Ordering::Less => MetaCompatibility::Older,
+        std::cmp::Ordering::Greater => MetaCompatibility::Newer,
+    })
+}
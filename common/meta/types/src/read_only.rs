@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::fmt;
+
+/// An object that can be switched to read-only, the whole cluster being in maintenance mode.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ReadOnlyObject {
+    Cluster,
+    Database(String),
+    Table(String, String),
+}
+
+impl ReadOnlyObject {
+    /// The key of the object, relative to the tenant's read-only prefix.
+    pub fn key(&self) -> String {
+        match self {
+            ReadOnlyObject::Cluster => "cluster".to_string(),
+            ReadOnlyObject::Database(db) => format!("database/{}", db),
+            ReadOnlyObject::Table(db, table) => format!("table/{}/{}", db, table),
+        }
+    }
+}
+
+impl fmt::Display for ReadOnlyObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
+        match self {
+            ReadOnlyObject::Cluster => write!(f, "CLUSTER"),
+            ReadOnlyObject::Database(ref db) => write!(f, "DATABASE '{}'", db),
+            ReadOnlyObject::Table(ref db, ref table) => {
+                write!(f, "TABLE '{}'.'{}'", db, table)
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::convert::TryFrom;
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DatabaseMeta;
+use crate::TableInfo;
+use crate::UserIdentity;
+
+/// A dropped table, kept in the recycle bin until it is undropped or vacuumed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DroppedTable {
+    pub db: String,
+    pub table_info: TableInfo,
+    pub owner: Option<UserIdentity>,
+    pub dropped_on: DateTime<Utc>,
+}
+
+impl DroppedTable {
+    pub fn new(db: &str, table_info: TableInfo, owner: Option<UserIdentity>) -> Self {
+        DroppedTable {
+            db: db.to_string(),
+            table_info,
+            owner,
+            dropped_on: Utc::now(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.table_info.name
+    }
+}
+
+/// A dropped database with the tables it contained when it was dropped.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DroppedDatabase {
+    pub db: String,
+    pub meta: DatabaseMeta,
+    pub tables: Vec<DroppedTable>,
+    pub owner: Option<UserIdentity>,
+    pub dropped_on: DateTime<Utc>,
+}
+
+impl DroppedDatabase {
+    pub fn new(
+        db: &str,
+        meta: DatabaseMeta,
+        tables: Vec<DroppedTable>,
+        owner: Option<UserIdentity>,
+    ) -> Self {
+        DroppedDatabase {
+            db: db.to_string(),
+            meta,
+            tables,
+            owner,
+            dropped_on: Utc::now(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for DroppedTable {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(table) => Ok(table),
+            Err(serialize_error) => Err(ErrorCode::IllegalDroppedObjectFormat(format!(
+                "Cannot deserialize dropped table from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for DroppedDatabase {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(database) => Ok(database),
+            Err(serialize_error) => Err(ErrorCode::IllegalDroppedObjectFormat(format!(
+                "Cannot deserialize dropped database from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
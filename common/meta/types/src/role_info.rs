@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::GrantObject;
+use crate::UserGrantSet;
+use crate::UserPrivilegeSet;
+
+/// A role holds privileges on behalf of the users it is granted to, the privileges of the
+/// active roles of a session are checked as if they were granted to its user.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct RoleInfo {
+    pub name: String,
+    pub grants: UserGrantSet,
+}
+
+impl RoleInfo {
+    // The grants of a role are keyed by its name and any host.
+    const HOST_PATTERN: &'static str = "%";
+
+    pub fn new(name: &str) -> Self {
+        RoleInfo {
+            name: name.to_string(),
+            grants: UserGrantSet::empty(),
+        }
+    }
+
+    pub fn grant_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
+        self.grants
+            .grant_privileges(&self.name, Self::HOST_PATTERN, object, privileges);
+    }
+
+    pub fn revoke_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
+        self.grants
+            .revoke_privileges(&self.name, Self::HOST_PATTERN, object, privileges);
+    }
+}
+
+impl TryFrom<Vec<u8>> for RoleInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(role_info) => Ok(role_info),
+            Err(serialize_error) => Err(ErrorCode::IllegalRoleInfoFormat(format!(
+                "Cannot deserialize role info from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::UserIdentity;
+
+/// A row access policy restricts the rows of a table visible to a set of users and roles.
+///
+/// The predicate is a SQL boolean expression over the columns of the table, it is
+/// appended to the filter of every scan on the table issued by one of the users, or by
+/// a session with one of the roles active. Empty `users` and `roles` mean the policy
+/// applies to every user.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct RowAccessPolicy {
+    pub name: String,
+    pub database: String,
+    pub table: String,
+    pub predicate: String,
+    pub users: Vec<UserIdentity>,
+    pub roles: Vec<String>,
+}
+
+impl RowAccessPolicy {
+    pub fn new(
+        name: &str,
+        database: &str,
+        table: &str,
+        predicate: &str,
+        users: Vec<UserIdentity>,
+        roles: Vec<String>,
+    ) -> Self {
+        RowAccessPolicy {
+            name: name.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            predicate: predicate.to_string(),
+            users,
+            roles,
+        }
+    }
+
+    pub fn is_protecting(&self, database: &str, table: &str) -> bool {
+        self.database == database && self.table == table
+    }
+
+    pub fn applies_to(&self, username: &str, hostname: &str, active_roles: &[String]) -> bool {
+        (self.users.is_empty() && self.roles.is_empty())
+            || self
+                .users
+                .iter()
+                .any(|u| u.username == username && u.hostname == hostname)
+            || self.roles.iter().any(|role| active_roles.contains(role))
+    }
+}
+
+impl TryFrom<Vec<u8>> for RowAccessPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalRowAccessPolicyFormat(format!(
+                "Cannot deserialize row access policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
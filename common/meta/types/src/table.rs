@@ -185,6 +185,7 @@ impl Display for TableInfo {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CreateTableReq {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     pub db: String,
     pub table: String,
     pub table_meta: TableMeta,
@@ -215,6 +216,10 @@ pub struct UpsertTableOptionReq {
     /// Some(String): add or update an option.
     /// None: delete an option.
     pub options: HashMap<String, Option<String>>,
+
+    /// Replaces the schema of the table along with the options, for ALTER TABLE.
+    #[serde(default)]
+    pub schema: Option<Arc<DataSchema>>,
 }
 
 impl UpsertTableOptionReq {
@@ -227,6 +232,7 @@ impl UpsertTableOptionReq {
             table_id: table_ident.table_id,
             seq: MatchSeq::Exact(table_ident.version),
             options: hashmap! {key.into() => Some(value.into())},
+            schema: None,
         }
     }
 }
@@ -234,6 +240,16 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+/// Upserts the options of several tables at once: either all of them are changed, or none of
+/// them is if the version of any table mismatches.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CommitTablesReq {
+    pub reqs: Vec<UpsertTableOptionReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CommitTablesReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
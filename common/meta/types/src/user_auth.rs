@@ -18,6 +18,7 @@ pub enum PasswordType {
     PlainText = 1,
     DoubleSha1 = 2,
     Sha256 = 3,
+    Pbkdf2Sha256 = 4,
 }
 
 impl Default for PasswordType {
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The storage types a connection can hold the credentials of.
+pub const CONNECTION_STORAGE_TYPES: [&str; 3] = ["s3", "gcs", "azblob"];
+
+/// A named set of external storage credentials, referenced by name instead of
+/// putting the credentials inline in the SQL text.
+///
+/// The values of `credentials` are encrypted before the connection is stored in meta,
+/// `params` holds the non-secret options, e.g. the region or the endpoint url.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct ConnectionInfo {
+    pub name: String,
+    pub storage_type: String,
+    pub params: BTreeMap<String, String>,
+    pub credentials: BTreeMap<String, String>,
+    pub comment: String,
+}
+
+impl ConnectionInfo {
+    pub fn new(
+        name: &str,
+        storage_type: &str,
+        params: BTreeMap<String, String>,
+        credentials: BTreeMap<String, String>,
+        comment: &str,
+    ) -> Self {
+        ConnectionInfo {
+            name: name.to_string(),
+            storage_type: storage_type.to_lowercase(),
+            params,
+            credentials,
+            comment: comment.to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !CONNECTION_STORAGE_TYPES.contains(&self.storage_type.as_str()) {
+            return Err(ErrorCode::IllegalConnectionFormat(format!(
+                "Unknown storage type '{}' of connection {}, expected one of {}",
+                self.storage_type,
+                self.name,
+                CONNECTION_STORAGE_TYPES.join(", ")
+            )));
+        }
+        if self.credentials.is_empty() {
+            return Err(ErrorCode::IllegalConnectionFormat(format!(
+                "Connection {} has no credentials",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Vec<u8>> for ConnectionInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(connection) => Ok(connection),
+            Err(serialize_error) => Err(ErrorCode::IllegalConnectionFormat(format!(
+                "Cannot deserialize connection from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -20,11 +20,16 @@ use enumflags2::BitFlags;
 use crate::UserPrivilegeSet;
 use crate::UserPrivilegeType;
 
+/// The objects privileges are granted on. There is no warehouse object: the compute of a tenant
+/// is the cluster of its query nodes, which is not an object of the tenant to be granted on.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum GrantObject {
     Global,
     Database(String),
     Table(String, String),
+    Connection(String),
+    Stage(String),
+    UDF(String),
 }
 
 impl GrantObject {
@@ -40,6 +45,9 @@ impl GrantObject {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Connection(_) => UserPrivilegeSet::available_privileges_on_connection(),
+            GrantObject::Stage(_) => UserPrivilegeSet::available_privileges_on_stage(),
+            GrantObject::UDF(_) => UserPrivilegeSet::available_privileges_on_udf(),
         }
     }
 
@@ -61,6 +69,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Global => write!(f, "*.*"),
             GrantObject::Database(ref db) => write!(f, "'{}'.*", db),
             GrantObject::Table(ref db, ref table) => write!(f, "'{}'.'{}'", db, table),
+            GrantObject::Connection(ref name) => write!(f, "CONNECTION '{}'", name),
+            GrantObject::Stage(ref name) => write!(f, "STAGE '{}'", name),
+            GrantObject::UDF(ref name) => write!(f, "FUNCTION '{}'", name),
         }
     }
 }
@@ -145,6 +156,7 @@ impl GrantEntry {
             GrantObject::Table(ref expected_db, ref expected_table) => {
                 expected_db == db && expected_table == table
             }
+            _ => false,
         } {
             return false;
         }
@@ -152,6 +164,58 @@ impl GrantEntry {
         self.privileges.contains(privilege)
     }
 
+    pub fn verify_connection_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        name: &str,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        let object = GrantObject::Connection(name.to_string());
+        self.verify_named_object_privilege(user, host, &object, privilege)
+    }
+
+    pub fn verify_stage_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        name: &str,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        let object = GrantObject::Stage(name.to_string());
+        self.verify_named_object_privilege(user, host, &object, privilege)
+    }
+
+    pub fn verify_udf_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        name: &str,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        let object = GrantObject::UDF(name.to_string());
+        self.verify_named_object_privilege(user, host, &object, privilege)
+    }
+
+    // Connections, stages and UDFs are not in databases, only a grant on the object itself counts.
+    fn verify_named_object_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        if !self.matches_user_host(user, host) {
+            return false;
+        }
+
+        if &self.object != object {
+            return false;
+        }
+
+        self.privileges.contains(privilege)
+    }
+
     pub fn matches_entry(&self, user: &str, host_pattern: &str, object: &GrantObject) -> bool {
         self.user == user && self.host_pattern == host_pattern && &self.object == object
     }
@@ -241,6 +305,42 @@ impl UserGrantSet {
             .any(|e| e.verify_table_privilege(user, host, db, table, privilege))
     }
 
+    pub fn verify_connection_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        name: &str,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        self.grants
+            .iter()
+            .any(|e| e.verify_connection_privilege(user, host, name, privilege))
+    }
+
+    pub fn verify_stage_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        name: &str,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        self.grants
+            .iter()
+            .any(|e| e.verify_stage_privilege(user, host, name, privilege))
+    }
+
+    pub fn verify_udf_privilege(
+        &self,
+        user: &str,
+        host: &str,
+        name: &str,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        self.grants
+            .iter()
+            .any(|e| e.verify_udf_privilege(user, host, name, privilege))
+    }
+
     pub fn grant_privileges(
         &mut self,
         user: &str,
@@ -297,4 +397,18 @@ impl UserGrantSet {
             .collect::<Vec<_>>();
         self.grants = grants;
     }
+
+    /// Grant `user`@`host_pattern` all the privileges of the other set, whoever they were
+    /// granted to there, e.g. the grants of the roles of a user.
+    pub fn grant_all_of(&mut self, user: &str, host_pattern: &str, other: &UserGrantSet) {
+        for entry in other.grants.iter() {
+            self.grant_privileges(user, host_pattern, &entry.object, entry.privileges.into());
+        }
+    }
+
+    /// Revoke the privileges of the user on all the objects, the GRANT privilege included.
+    pub fn revoke_all_privileges(&mut self, user: &str, host_pattern: &str) {
+        self.grants
+            .retain(|e| !(e.user == user && e.host_pattern == host_pattern));
+    }
 }
@@ -22,6 +22,7 @@ use serde::Serialize;
 
 use crate::user_grant::UserGrantSet;
 use crate::PasswordType;
+use crate::UserIdentity;
 use crate::UserQuota;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
@@ -38,6 +39,10 @@ pub struct UserInfo {
     pub grants: UserGrantSet,
 
     pub quota: UserQuota,
+
+    pub network_policy: Option<String>,
+
+    pub roles: Vec<String>,
 }
 
 impl UserInfo {
@@ -58,6 +63,15 @@ impl UserInfo {
             password_type,
             grants,
             quota,
+            network_policy: None,
+            roles: vec![],
+        }
+    }
+
+    pub fn identity(&self) -> UserIdentity {
+        UserIdentity {
+            username: self.name.clone(),
+            hostname: self.hostname.clone(),
         }
     }
 }
@@ -121,6 +121,21 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
     }
 
+    /// A connection can only be used, the USAGE privilege is the only one available to it
+    pub fn available_privileges_on_connection() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage }).into()
+    }
+
+    /// A stage can only be used, e.g. by COPY, the USAGE privilege is the only one available to it
+    pub fn available_privileges_on_stage() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage }).into()
+    }
+
+    /// A UDF can only be called, the USAGE privilege is the only one available to it
+    pub fn available_privileges_on_udf() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
 use std::str::FromStr;
 
 use common_exception::ErrorCode;
@@ -21,9 +22,13 @@ use common_exception::Result;
 pub struct StageParams {
     pub url: String,
     pub credentials: Credentials,
+    /// The name of the connection holding the credentials, the inline
+    /// credentials are left empty when it is set.
+    #[serde(default)]
+    pub connection: Option<String>,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 #[serde(rename_all = "lowercase")]
 #[serde(default)]
@@ -32,6 +37,16 @@ pub struct Credentials {
     pub secret_access_key: String,
 }
 
+// The secret is left out, the plans and statements holding credentials are logged.
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"******")
+            .finish()
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -147,6 +162,15 @@ impl StageParams {
         StageParams {
             url: url.to_string(),
             credentials,
+            connection: None,
+        }
+    }
+
+    pub fn with_connection(url: &str, connection: &str) -> Self {
+        StageParams {
+            url: url.to_string(),
+            credentials: Credentials::default(),
+            connection: Some(connection.to_string()),
         }
     }
 }
@@ -14,6 +14,9 @@
 
 mod cluster;
 mod match_seq;
+mod network_policy;
+mod protocol_version;
+mod user_connection;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use common_exception::exception::Result;
+use common_meta_types::NetworkPolicy;
+
+fn ip(addr: &str) -> IpAddr {
+    IpAddr::from_str(addr).unwrap()
+}
+
+#[test]
+fn test_network_policy_allowed_list() -> Result<()> {
+    let policy = NetworkPolicy::new(
+        "p1",
+        vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+        vec![],
+        "",
+    );
+    policy.validate()?;
+
+    assert!(policy.is_allowed(&ip("192.168.1.1")));
+    assert!(policy.is_allowed(&ip("192.168.1.255")));
+    assert!(policy.is_allowed(&ip("10.0.0.1")));
+    assert!(policy.is_allowed(&ip("::ffff:192.168.1.7")));
+    assert!(!policy.is_allowed(&ip("192.168.2.1")));
+    assert!(!policy.is_allowed(&ip("10.0.0.2")));
+    assert!(!policy.is_allowed(&ip("::1")));
+
+    Ok(())
+}
+
+#[test]
+fn test_network_policy_blocked_list() -> Result<()> {
+    let policy = NetworkPolicy::new(
+        "p1",
+        vec!["0.0.0.0/0".to_string()],
+        vec!["192.168.1.99".to_string(), "fe80::/10".to_string()],
+        "",
+    );
+    policy.validate()?;
+
+    assert!(policy.is_allowed(&ip("192.168.1.1")));
+    assert!(!policy.is_allowed(&ip("192.168.1.99")));
+    assert!(!policy.is_allowed(&ip("fe80::1")));
+
+    // An empty allowed list allows everything but the blocked list.
+    let policy = NetworkPolicy::new("p2", vec![], vec!["127.0.0.0/8".to_string()], "");
+    assert!(policy.is_allowed(&ip("192.168.1.1")));
+    assert!(policy.is_allowed(&ip("::1")));
+    assert!(!policy.is_allowed(&ip("127.0.0.1")));
+
+    Ok(())
+}
+
+#[test]
+fn test_network_policy_validate() -> Result<()> {
+    for illegal in [
+        "192.168.1",
+        "192.168.1.0/33",
+        "::1/129",
+        "localhost",
+        "10.0.0.0/x",
+    ] {
+        let policy = NetworkPolicy::new("p1", vec![illegal.to_string()], vec![], "");
+        assert_eq!(policy.validate().unwrap_err().code(), 4112);
+    }
+
+    let policy = NetworkPolicy::new("p1", vec![], vec!["10.0.0.0/x".to_string()], "");
+    assert_eq!(policy.validate().unwrap_err().code(), 4112);
+    Ok(())
+}
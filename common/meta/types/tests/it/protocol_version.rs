@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_meta_types::check_protocol_version;
+use common_meta_types::MetaCompatibility;
+use common_meta_types::META_PROTOCOL_VERSION;
+
+#[test]
+fn test_check_protocol_version() -> anyhow::Result<()> {
+    let ver = META_PROTOCOL_VERSION;
+
+    assert_eq!(
+        MetaCompatibility::Same,
+        check_protocol_version("peer", ver, 0)?
+    );
+    assert_eq!(
+        MetaCompatibility::Older,
+        check_protocol_version("peer", ver - 1, ver - 1)?
+    );
+    assert_eq!(
+        MetaCompatibility::Newer,
+        check_protocol_version("peer", ver + 1, ver)?
+    );
+
+    let res = check_protocol_version("peer", ver - 1, ver);
+    assert_eq!(
+        ErrorCode::MetaServiceIncompatible("").code(),
+        res.unwrap_err().code()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::exception::Result;
+use common_meta_types::ConnectionInfo;
+
+#[test]
+fn test_connection_validate() -> Result<()> {
+    let mut credentials = BTreeMap::new();
+    credentials.insert("access_key_id".to_string(), "id".to_string());
+
+    let connection = ConnectionInfo::new("c1", "S3", BTreeMap::new(), credentials.clone(), "");
+    assert_eq!(connection.storage_type, "s3");
+    connection.validate()?;
+
+    let connection = ConnectionInfo::new("c1", "ftp", BTreeMap::new(), credentials, "");
+    assert_eq!(connection.validate().unwrap_err().code(), 4122);
+
+    let connection = ConnectionInfo::new("c1", "s3", BTreeMap::new(), BTreeMap::new(), "");
+    assert_eq!(connection.validate().unwrap_err().code(), 4122);
+
+    Ok(())
+}
+
+#[test]
+fn test_connection_from_bytes() -> Result<()> {
+    let connection = ConnectionInfo::try_from(br#"{"name":"c1","storage_type":"gcs"}"#.to_vec())?;
+    assert_eq!(connection.name, "c1");
+    assert!(connection.credentials.is_empty());
+
+    let res = ConnectionInfo::try_from(b"not json".to_vec());
+    assert_eq!(res.unwrap_err().code(), 4122);
+
+    Ok(())
+}
@@ -102,3 +102,102 @@ fn test_user_grant_set() -> Result<()> {
     assert!(grants.verify_table_privilege("u1", "h3", "db1", "table1", UserPrivilegeType::Select));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_connection() -> Result<()> {
+    let grant = GrantEntry::new(
+        "u1".into(),
+        "%".into(),
+        GrantObject::Connection("c1".into()),
+        make_bitflags!(UserPrivilegeType::{Usage}),
+    );
+    assert!(grant.verify_connection_privilege("u1", "h1", "c1", UserPrivilegeType::Usage));
+    assert!(!grant.verify_connection_privilege("u1", "h1", "c2", UserPrivilegeType::Usage));
+    assert!(!grant.verify_connection_privilege("u2", "h1", "c1", UserPrivilegeType::Usage));
+    // A connection grant gives nothing on databases and tables.
+    assert!(!grant.verify_database_privilege("u1", "h1", "c1", UserPrivilegeType::Usage));
+    assert!(!grant.verify_table_privilege("u1", "h1", "c1", "c1", UserPrivilegeType::Usage));
+
+    // Only USAGE can be granted on a connection.
+    let object = GrantObject::Connection("c1".into());
+    assert!(object.allow_privilege(UserPrivilegeType::Usage));
+    assert!(!object.allow_privilege(UserPrivilegeType::Select));
+    assert_eq!(object.to_string(), "CONNECTION 'c1'");
+
+    Ok(())
+}
+
+#[test]
+fn test_user_grant_stage_and_udf() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+    grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::Stage("s1".into()),
+        make_bitflags!(UserPrivilegeType::{Usage}).into(),
+    );
+    grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::UDF("f1".into()),
+        make_bitflags!(UserPrivilegeType::{Usage}).into(),
+    );
+    assert!(grants.verify_stage_privilege("u1", "h1", "s1", UserPrivilegeType::Usage));
+    assert!(!grants.verify_stage_privilege("u1", "h1", "s2", UserPrivilegeType::Usage));
+    assert!(!grants.verify_stage_privilege("u2", "h1", "s1", UserPrivilegeType::Usage));
+    assert!(grants.verify_udf_privilege("u1", "h1", "f1", UserPrivilegeType::Usage));
+    // A stage grant gives nothing on the UDF of the same name, and vice versa.
+    assert!(!grants.verify_udf_privilege("u1", "h1", "s1", UserPrivilegeType::Usage));
+    assert!(!grants.verify_stage_privilege("u1", "h1", "f1", UserPrivilegeType::Usage));
+
+    let stage = GrantObject::Stage("s1".into());
+    assert!(stage.allow_privilege(UserPrivilegeType::Usage));
+    assert!(!stage.allow_privilege(UserPrivilegeType::Insert));
+    assert_eq!(stage.to_string(), "STAGE 's1'");
+
+    let udf = GrantObject::UDF("f1".into());
+    assert!(udf.allow_privilege(UserPrivilegeType::Usage));
+    assert!(!udf.allow_privilege(UserPrivilegeType::Select));
+    assert_eq!(udf.to_string(), "FUNCTION 'f1'");
+
+    Ok(())
+}
+
+#[test]
+fn test_user_grant_revoke_all() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+    grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::Global,
+        make_bitflags!(UserPrivilegeType::{Create | Grant}).into(),
+    );
+    grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::Table("db1".into(), "table1".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::Connection("c1".into()),
+        make_bitflags!(UserPrivilegeType::{Usage}).into(),
+    );
+    grants.grant_privileges(
+        "u1",
+        "h1",
+        &GrantObject::Database("db1".into()),
+        make_bitflags!(UserPrivilegeType::{Insert}).into(),
+    );
+    assert_eq!(4, grants.entries().len());
+
+    grants.revoke_all_privileges("u1", "%");
+    assert_eq!(1, grants.entries().len());
+    assert!(!grants.verify_global_privilege("u1", "h2", UserPrivilegeType::Grant));
+    assert!(!grants.verify_table_privilege("u1", "h2", "db1", "table1", UserPrivilegeType::Select));
+    assert!(!grants.verify_connection_privilege("u1", "h2", "c1", UserPrivilegeType::Usage));
+    // The grants of another host pattern are kept.
+    assert!(grants.verify_database_privilege("u1", "h1", "db1", UserPrivilegeType::Insert));
+    Ok(())
+}
@@ -42,3 +42,29 @@ fn test_user_stage() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_user_stage_with_connection() -> Result<()> {
+    let stage = UserStageInfo::new(
+        "databend",
+        "",
+        StageParams::with_connection("s3://load/files/", "my_conn"),
+        FileFormat::default(),
+    );
+    let ser = serde_json::to_string(&stage)?;
+    let de = UserStageInfo::try_from(ser.into_bytes())?;
+    assert_eq!(stage, de);
+
+    // The stages stored before connections were supported have no connection.
+    let old = r#"{"stage_name":"databend","stage_params":{"url":"test","credentials":{"access_key_id":"a","secret_access_key":"b"}}}"#;
+    let de = UserStageInfo::try_from(old.as_bytes().to_vec())?;
+    assert_eq!(de.stage_params.connection, None);
+    assert_eq!(de.stage_params.credentials.access_key_id, "a");
+
+    // The secret never shows up in the logs.
+    let debug = format!("{:?}", de.stage_params);
+    assert!(debug.contains("access_key_id: \"a\""));
+    assert!(!debug.contains("secret_access_key: \"b\""));
+
+    Ok(())
+}
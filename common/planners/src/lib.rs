@@ -14,15 +14,25 @@
 
 mod plan_aggregator_final;
 mod plan_aggregator_partial;
+mod plan_alter_owner;
+mod plan_alter_read_only;
+mod plan_alter_table_column;
+mod plan_alter_table_options;
 mod plan_broadcast;
 mod plan_builder;
+mod plan_connection_create;
+mod plan_connection_drop;
 mod plan_copy;
+mod plan_copy_into_stage;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_database_undrop;
+mod plan_delete;
 mod plan_describe_stage;
 mod plan_describe_table;
 mod plan_display;
 mod plan_display_indent;
+mod plan_distinct;
 mod plan_empty;
 mod plan_explain;
 mod plan_expression;
@@ -39,19 +49,34 @@ mod plan_expression_visitor;
 mod plan_extras;
 mod plan_filter;
 mod plan_grant_privilege;
+mod plan_grant_role;
+mod plan_grant_role_privilege;
 mod plan_having;
 mod plan_insert_into;
+mod plan_join;
 mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
+mod plan_network_policy_create;
+mod plan_network_policy_drop;
 mod plan_node;
+mod plan_output_order;
 mod plan_partition;
 mod plan_projection;
 mod plan_read_datasource;
 mod plan_remote;
+mod plan_revoke_all_privileges;
 mod plan_revoke_privilege;
+mod plan_revoke_role;
+mod plan_revoke_role_privilege;
 mod plan_rewriter;
+mod plan_role_create;
+mod plan_role_drop;
+mod plan_row_access_policy_create;
+mod plan_row_access_policy_drop;
 mod plan_select;
+mod plan_set_role;
+mod plan_set_secondary_roles;
 mod plan_setting;
 mod plan_show_create_database;
 mod plan_show_grants;
@@ -61,14 +86,21 @@ mod plan_sort;
 mod plan_stage;
 mod plan_statistics;
 mod plan_subqueries_set;
+mod plan_table_analyze;
 mod plan_table_create;
 mod plan_table_drop;
+mod plan_table_export;
 mod plan_table_optimize;
+mod plan_table_undrop;
+mod plan_table_vacuum;
+mod plan_table_vacuum_drop;
+mod plan_transaction;
 mod plan_truncate_table;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
 mod plan_user_drop;
+mod plan_user_network_policy_alter;
 mod plan_user_stage_create;
 mod plan_user_stage_drop;
 mod plan_user_udf_alter;
@@ -79,14 +111,25 @@ mod plan_visitor;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
+pub use plan_alter_owner::AlterOwnerPlan;
+pub use plan_alter_read_only::AlterReadOnlyPlan;
+pub use plan_alter_table_column::AlterColumnOperation;
+pub use plan_alter_table_column::AlterTableColumnPlan;
+pub use plan_alter_table_options::AlterTableOptionsPlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_builder::PlanBuilder;
+pub use plan_connection_create::CreateConnectionPlan;
+pub use plan_connection_drop::DropConnectionPlan;
 pub use plan_copy::CopyPlan;
+pub use plan_copy_into_stage::CopyIntoStagePlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_database_undrop::UndropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_describe_stage::DescribeStagePlan;
 pub use plan_describe_table::DescribeTablePlan;
+pub use plan_distinct::DistinctPlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
 pub use plan_explain::ExplainType;
@@ -121,24 +164,41 @@ pub use plan_expression_validator::validate_expression;
 pub use plan_expression_visitor::ExpressionVisitor;
 pub use plan_expression_visitor::Recursion;
 pub use plan_extras::Extras;
+pub use plan_extras::Sample;
 pub use plan_filter::FilterPlan;
 pub use plan_grant_privilege::GrantPrivilegePlan;
+pub use plan_grant_role::GrantRolePlan;
+pub use plan_grant_role_privilege::GrantRolePrivilegePlan;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertInputSource;
 pub use plan_insert_into::InsertPlan;
+pub use plan_join::JoinPlan;
+pub use plan_join::JoinType;
 pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
+pub use plan_network_policy_create::CreateNetworkPolicyPlan;
+pub use plan_network_policy_drop::DropNetworkPolicyPlan;
 pub use plan_node::PlanNode;
+pub use plan_output_order::OutputOrder;
 pub use plan_partition::Part;
 pub use plan_partition::Partitions;
 pub use plan_projection::ProjectionPlan;
 pub use plan_read_datasource::ReadDataSourcePlan;
 pub use plan_remote::RemotePlan;
+pub use plan_revoke_all_privileges::RevokeAllPrivilegesPlan;
 pub use plan_revoke_privilege::RevokePrivilegePlan;
+pub use plan_revoke_role::RevokeRolePlan;
+pub use plan_revoke_role_privilege::RevokeRolePrivilegePlan;
 pub use plan_rewriter::PlanRewriter;
 pub use plan_rewriter::RewriteHelper;
+pub use plan_role_create::CreateRolePlan;
+pub use plan_role_drop::DropRolePlan;
+pub use plan_row_access_policy_create::CreateRowAccessPolicyPlan;
+pub use plan_row_access_policy_drop::DropRowAccessPolicyPlan;
 pub use plan_select::SelectPlan;
+pub use plan_set_role::SetRolePlan;
+pub use plan_set_secondary_roles::SetSecondaryRolesPlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
 pub use plan_show_create_database::ShowCreateDatabasePlan;
@@ -151,16 +211,24 @@ pub use plan_stage::StageKind;
 pub use plan_stage::StagePlan;
 pub use plan_statistics::Statistics;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_analyze::AnalyzeTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
+pub use plan_table_export::ExportTablePlan;
 pub use plan_table_optimize::Optimization;
 pub use plan_table_optimize::OptimizeTablePlan;
+pub use plan_table_undrop::UndropTablePlan;
+pub use plan_table_vacuum::VacuumTablePlan;
+pub use plan_table_vacuum_drop::VacuumDropTablePlan;
+pub use plan_transaction::TransactionAction;
+pub use plan_transaction::TransactionPlan;
 pub use plan_truncate_table::TruncateTablePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
 pub use plan_user_drop::DropUserPlan;
+pub use plan_user_network_policy_alter::AlterUserNetworkPolicyPlan;
 pub use plan_user_stage_create::CreateUserStagePlan;
 pub use plan_user_stage_drop::DropUserStagePlan;
 pub use plan_user_udf_alter::AlterUDFPlan;
@@ -20,6 +20,7 @@ use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
@@ -27,6 +28,7 @@ use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::validate_expression;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::DistinctPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::ExplainType;
@@ -34,6 +36,8 @@ use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::HavingPlan;
+use crate::JoinPlan;
+use crate::JoinType;
 use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
@@ -252,6 +256,77 @@ impl PlanBuilder {
         })))
     }
 
+    /// Remove the duplicate rows
+    pub fn distinct(&self) -> Result<Self> {
+        Ok(Self::from(&PlanNode::Distinct(DistinctPlan {
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
+    /// Join with the right plan on the equality of the key columns, the right plan is the build side.
+    pub fn join(
+        &self,
+        right: &PlanNode,
+        join_type: JoinType,
+        left_keys: &[Expression],
+        right_keys: &[Expression],
+    ) -> Result<Self> {
+        if left_keys.is_empty() || left_keys.len() != right_keys.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Join keys mismatch: {} left keys, {} right keys",
+                left_keys.len(),
+                right_keys.len()
+            )));
+        }
+
+        let left_schema = self.plan.schema();
+        let right_schema = right.schema();
+        for (left_key, right_key) in left_keys.iter().zip(right_keys.iter()) {
+            let left_field = Self::join_key_field(&left_schema, left_key)?;
+            let right_field = Self::join_key_field(&right_schema, right_key)?;
+            if left_field.data_type() != right_field.data_type() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Join keys {} and {} have different types: {:?} and {:?}",
+                    left_field.name(),
+                    right_field.name(),
+                    left_field.data_type(),
+                    right_field.data_type()
+                )));
+            }
+        }
+
+        // The side whose unmatched rows are filled with nulls becomes nullable.
+        let mut fields =
+            Vec::with_capacity(left_schema.fields().len() + right_schema.fields().len());
+        for (schema, nullable) in [
+            (&left_schema, join_type.keeps_right()),
+            (&right_schema, join_type.keeps_left()),
+        ] {
+            for field in schema.fields() {
+                if fields.iter().any(|f: &DataField| f.name() == field.name()) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Duplicate column {} in the join, alias it on one side",
+                        field.name()
+                    )));
+                }
+                fields.push(DataField::new(
+                    field.name(),
+                    field.data_type().clone(),
+                    field.is_nullable() || nullable,
+                ));
+            }
+        }
+
+        Ok(Self::from(&PlanNode::Join(JoinPlan {
+            join_type,
+            left_keys: left_keys.to_vec(),
+            right_keys: right_keys.to_vec(),
+            schema: DataSchemaRefExt::create(fields),
+            left: Arc::new(self.plan.clone()),
+            right: Arc::new(right.clone()),
+        })))
+    }
+
     pub fn select(&self) -> Result<Self> {
         Ok(Self::from(&PlanNode::Select(SelectPlan {
             input: Arc::new(self.plan.clone()),
@@ -281,4 +356,14 @@ impl PlanBuilder {
             }))),
         }
     }
+
+    fn join_key_field<'a>(schema: &'a DataSchemaRef, key: &Expression) -> Result<&'a DataField> {
+        match key {
+            Expression::Column(name) => schema.field_with_name(name),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Join key must be a column, but got: {:?}",
+                key
+            ))),
+        }
+    }
 }
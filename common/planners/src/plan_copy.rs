@@ -14,7 +14,10 @@
 
 use std::collections::HashMap;
 
+use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use common_meta_types::MetaId;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
@@ -22,14 +25,24 @@ pub struct CopyPlan {
     pub db_name: String,
     pub tbl_name: String,
     pub tbl_id: MetaId,
+    /// The columns loaded from the files
     pub schema: DataSchemaRef,
+    /// The stage location of a file, or of the files under it if it ends with `/`,
+    /// e.g. `@my_stage/data/`
     pub location: String,
     pub format: String,
     pub options: HashMap<String, String>,
 }
 
 impl CopyPlan {
+    /// The load result of each file.
     pub fn schema(&self) -> DataSchemaRef {
-        self.schema.clone()
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String, false),
+            DataField::new("status", DataType::String, false),
+            DataField::new("rows_loaded", DataType::UInt64, false),
+            DataField::new("errors_seen", DataType::UInt64, false),
+            DataField::new("first_error", DataType::String, true),
+        ])
     }
 }
@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+
+use crate::PlanNode;
+
+/// Unload the result of a query, or of `SELECT *` from a table, into the files of a stage.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct CopyIntoStagePlan {
+    /// The stage location the files are written under, e.g. `@my_stage/unload/`
+    pub location: String,
+    pub query: Box<PlanNode>,
+    pub format: String,
+    pub options: HashMap<String, String>,
+}
+
+impl CopyIntoStagePlan {
+    /// The manifest of the files written.
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String, false),
+            DataField::new("row_count", DataType::UInt64, false),
+            DataField::new("size_bytes", DataType::UInt64, false),
+        ])
+    }
+}
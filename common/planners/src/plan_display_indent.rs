@@ -24,6 +24,7 @@ use crate::DropDatabasePlan;
 use crate::DropTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::JoinPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -65,6 +66,8 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
+            PlanNode::Distinct(_) => write!(f, "Distinct"),
+            PlanNode::Join(plan) => Self::format_join(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
             PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
@@ -191,6 +194,22 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         }
     }
 
+    fn format_join(f: &mut Formatter, plan: &JoinPlan) -> fmt::Result {
+        write!(f, "HashJoin: type={}, on=[", plan.join_type)?;
+        for (i, (left, right)) in plan
+            .left_keys
+            .iter()
+            .zip(plan.right_keys.iter())
+            .enumerate()
+        {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?} = {:?}", left, right)?;
+        }
+        write!(f, "]")
+    }
+
     fn format_subquery_expr(f: &mut Formatter, plan: &SubQueriesSetPlan) -> fmt::Result {
         let mut names = Vec::with_capacity(plan.expressions.len());
         for expression in &plan.expressions {
@@ -237,6 +256,14 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
 
                     write!(f, "limit: {:?}", p.limit.unwrap())?;
                     write!(f, ", order_by: {:?}", p.order_by)?;
+                    comma = true;
+                }
+
+                if let Some(sample) = &p.sample {
+                    if comma {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "sample: {:?}", sample)?;
                 }
 
                 write!(f, "]")?;
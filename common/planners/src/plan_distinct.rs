@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::PlanNode;
+
+/// Remove the duplicate rows of the input, `SELECT DISTINCT`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct DistinctPlan {
+    /// The logical plan
+    pub input: Arc<PlanNode>,
+}
+
+impl DistinctPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.input.schema()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
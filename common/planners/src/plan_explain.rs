@@ -26,6 +26,11 @@ pub enum ExplainType {
     Syntax,
     Graph,
     Pipeline,
+    /// Estimates the cost of the query with the pruned partitions, without executing it.
+    Estimate,
+    /// Executes the query, the pipeline is annotated with the output and the busy time of the
+    /// processors.
+    Analyze,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -36,7 +41,16 @@ pub struct ExplainPlan {
 
 impl ExplainPlan {
     pub fn schema(&self) -> DataSchemaRef {
-        DataSchemaRefExt::create(vec![DataField::new("explain", DataType::String, false)])
+        match self.typ {
+            ExplainType::Estimate => DataSchemaRefExt::create(vec![
+                DataField::new("table", DataType::String, false),
+                DataField::new("partitions", DataType::UInt64, false),
+                DataField::new("rows", DataType::UInt64, false),
+                DataField::new("bytes", DataType::UInt64, false),
+                DataField::new("exact", DataType::Boolean, false),
+            ]),
+            _ => DataSchemaRefExt::create(vec![DataField::new("explain", DataType::String, false)]),
+        }
     }
 
     pub fn set_input(&mut self, node: &PlanNode) {
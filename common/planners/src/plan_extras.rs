@@ -25,6 +25,17 @@ pub struct Extras {
     pub limit: Option<usize>,
     /// Optional order_by expression plan
     pub order_by: Vec<Expression>,
+    /// Optional sampling of the table read
+    pub sample: Option<Sample>,
+}
+
+/// The sampling of `FROM t SAMPLE [BLOCK | ROW] (percent)`, of the percent of the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum Sample {
+    /// The blocks are kept or skipped as a whole, only the kept ones are read.
+    Block(f64),
+    /// The rows are kept each with the probability, of all the blocks read.
+    Row(f64),
 }
 
 impl Extras {
@@ -34,6 +45,7 @@ impl Extras {
             filters: vec![],
             limit: None,
             order_by: vec![],
+            sample: None,
         }
     }
 }
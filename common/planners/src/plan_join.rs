@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+impl JoinType {
+    /// Whether the unmatched rows of the left side are kept, with nulls on the right side.
+    pub fn keeps_left(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// Whether the unmatched rows of the right side are kept, with nulls on the left side.
+    pub fn keeps_right(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "Inner"),
+            JoinType::Left => write!(f, "Left"),
+            JoinType::Right => write!(f, "Right"),
+            JoinType::Full => write!(f, "Full"),
+        }
+    }
+}
+
+/// An equi-join of two inputs, the right one is the build side of the hash join.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct JoinPlan {
+    pub join_type: JoinType,
+    /// The key columns of the left input, paired with the right ones.
+    pub left_keys: Vec<Expression>,
+    pub right_keys: Vec<Expression>,
+    /// The columns of the left input followed by the ones of the right input.
+    pub schema: DataSchemaRef,
+    pub left: Arc<PlanNode>,
+    pub right: Arc<PlanNode>,
+}
+
+impl JoinPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
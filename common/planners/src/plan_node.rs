@@ -25,24 +25,45 @@ use crate::plan_user_udf_drop::DropUDFPlan;
 use crate::plan_user_udf_show::ShowUDFPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterOwnerPlan;
+use crate::AlterReadOnlyPlan;
+use crate::AlterTableColumnPlan;
+use crate::AlterTableOptionsPlan;
+use crate::AlterUserNetworkPolicyPlan;
 use crate::AlterUserPlan;
+use crate::AnalyzeTablePlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
+use crate::CreateConnectionPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateNetworkPolicyPlan;
+use crate::CreateRolePlan;
+use crate::CreateRowAccessPolicyPlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
+use crate::DistinctPlan;
+use crate::DropConnectionPlan;
 use crate::DropDatabasePlan;
+use crate::DropNetworkPolicyPlan;
+use crate::DropRolePlan;
+use crate::DropRowAccessPolicyPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
+use crate::ExportTablePlan;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
+use crate::GrantRolePlan;
+use crate::GrantRolePrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
@@ -50,8 +71,13 @@ use crate::OptimizeTablePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RevokeAllPrivilegesPlan;
 use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
+use crate::RevokeRolePrivilegePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
+use crate::SetSecondaryRolesPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -59,8 +85,13 @@ use crate::ShowGrantsPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
+use crate::UndropDatabasePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumDropTablePlan;
+use crate::VacuumTablePlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -78,22 +109,34 @@ pub enum PlanNode {
     Sort(SortPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
+    Distinct(DistinctPlan),
+    Join(JoinPlan),
     ReadSource(ReadDataSourcePlan),
     Sink(SinkPlan),
     Select(SelectPlan),
     Explain(ExplainPlan),
     CreateDatabase(CreateDatabasePlan),
     DropDatabase(DropDatabasePlan),
+    UndropDatabase(UndropDatabasePlan),
     CreateTable(CreateTablePlan),
     DescribeTable(DescribeTablePlan),
     DescribeStage(DescribeStagePlan),
     DropTable(DropTablePlan),
+    UndropTable(UndropTablePlan),
+    VacuumDropTable(VacuumDropTablePlan),
+    VacuumTable(VacuumTablePlan),
+    AnalyzeTable(AnalyzeTablePlan),
     OptimizeTable(OptimizeTablePlan),
     TruncateTable(TruncateTablePlan),
+    Delete(DeletePlan),
     UseDatabase(UseDatabasePlan),
+    SetRole(SetRolePlan),
+    SetSecondaryRoles(SetSecondaryRolesPlan),
     SetVariable(SettingPlan),
     Insert(InsertPlan),
     Copy(CopyPlan),
+    CopyIntoStage(CopyIntoStagePlan),
+    ExportTable(ExportTablePlan),
     ShowCreateTable(ShowCreateTablePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Kill(KillPlan),
@@ -102,6 +145,7 @@ pub enum PlanNode {
     DropUser(DropUserPlan),
     GrantPrivilege(GrantPrivilegePlan),
     RevokePrivilege(RevokePrivilegePlan),
+    RevokeAllPrivileges(RevokeAllPrivilegesPlan),
     CreateUserStage(CreateUserStagePlan),
     DropUserStage(DropUserStagePlan),
     ShowGrants(ShowGrantsPlan),
@@ -110,6 +154,24 @@ pub enum PlanNode {
     DropUDF(DropUDFPlan),
     ShowUDF(ShowUDFPlan),
     AlterUDF(AlterUDFPlan),
+    CreateRowAccessPolicy(CreateRowAccessPolicyPlan),
+    DropRowAccessPolicy(DropRowAccessPolicyPlan),
+    AlterOwner(AlterOwnerPlan),
+    AlterReadOnly(AlterReadOnlyPlan),
+    AlterTableColumn(AlterTableColumnPlan),
+    AlterTableOptions(AlterTableOptionsPlan),
+    CreateNetworkPolicy(CreateNetworkPolicyPlan),
+    DropNetworkPolicy(DropNetworkPolicyPlan),
+    AlterUserNetworkPolicy(AlterUserNetworkPolicyPlan),
+    CreateRole(CreateRolePlan),
+    DropRole(DropRolePlan),
+    GrantRole(GrantRolePlan),
+    RevokeRole(RevokeRolePlan),
+    GrantRolePrivilege(GrantRolePrivilegePlan),
+    RevokeRolePrivilege(RevokeRolePrivilegePlan),
+    CreateConnection(CreateConnectionPlan),
+    DropConnection(DropConnectionPlan),
+    Transaction(TransactionPlan),
 }
 
 impl PlanNode {
@@ -128,20 +190,30 @@ impl PlanNode {
             PlanNode::Having(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
+            PlanNode::Join(v) => v.schema(),
+            PlanNode::Distinct(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
             PlanNode::Select(v) => v.schema(),
             PlanNode::Explain(v) => v.schema(),
             PlanNode::CreateDatabase(v) => v.schema(),
             PlanNode::DropDatabase(v) => v.schema(),
+            PlanNode::UndropDatabase(v) => v.schema(),
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
+            PlanNode::UndropTable(v) => v.schema(),
+            PlanNode::VacuumDropTable(v) => v.schema(),
+            PlanNode::VacuumTable(v) => v.schema(),
+            PlanNode::AnalyzeTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::OptimizeTable(v) => v.schema(),
             PlanNode::DescribeStage(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
+            PlanNode::SetRole(v) => v.schema(),
+            PlanNode::SetSecondaryRoles(v) => v.schema(),
             PlanNode::Insert(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
@@ -151,8 +223,11 @@ impl PlanNode {
             PlanNode::DropUser(v) => v.schema(),
             PlanNode::GrantPrivilege(v) => v.schema(),
             PlanNode::RevokePrivilege(v) => v.schema(),
+            PlanNode::RevokeAllPrivileges(v) => v.schema(),
             PlanNode::Sink(v) => v.schema(),
             PlanNode::Copy(v) => v.schema(),
+            PlanNode::CopyIntoStage(v) => v.schema(),
+            PlanNode::ExportTable(v) => v.schema(),
             PlanNode::CreateUserStage(v) => v.schema(),
             PlanNode::DropUserStage(v) => v.schema(),
             PlanNode::ShowGrants(v) => v.schema(),
@@ -161,6 +236,24 @@ impl PlanNode {
             PlanNode::DropUDF(v) => v.schema(),
             PlanNode::ShowUDF(v) => v.schema(),
             PlanNode::AlterUDF(v) => v.schema(),
+            PlanNode::CreateRowAccessPolicy(v) => v.schema(),
+            PlanNode::DropRowAccessPolicy(v) => v.schema(),
+            PlanNode::AlterOwner(v) => v.schema(),
+            PlanNode::AlterReadOnly(v) => v.schema(),
+            PlanNode::AlterTableColumn(v) => v.schema(),
+            PlanNode::AlterTableOptions(v) => v.schema(),
+            PlanNode::CreateNetworkPolicy(v) => v.schema(),
+            PlanNode::DropNetworkPolicy(v) => v.schema(),
+            PlanNode::AlterUserNetworkPolicy(v) => v.schema(),
+            PlanNode::CreateRole(v) => v.schema(),
+            PlanNode::DropRole(v) => v.schema(),
+            PlanNode::GrantRole(v) => v.schema(),
+            PlanNode::RevokeRole(v) => v.schema(),
+            PlanNode::GrantRolePrivilege(v) => v.schema(),
+            PlanNode::RevokeRolePrivilege(v) => v.schema(),
+            PlanNode::CreateConnection(v) => v.schema(),
+            PlanNode::DropConnection(v) => v.schema(),
+            PlanNode::Transaction(v) => v.schema(),
         }
     }
 
@@ -178,20 +271,30 @@ impl PlanNode {
             PlanNode::Having(_) => "HavingPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
+            PlanNode::Join(_) => "JoinPlan",
+            PlanNode::Distinct(_) => "DistinctPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
             PlanNode::Select(_) => "SelectPlan",
             PlanNode::Explain(_) => "ExplainPlan",
             PlanNode::CreateDatabase(_) => "CreateDatabasePlan",
             PlanNode::DropDatabase(_) => "DropDatabasePlan",
+            PlanNode::UndropDatabase(_) => "UndropDatabasePlan",
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
             PlanNode::DescribeStage(_) => "DescribeStagePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::UndropTable(_) => "UndropTablePlan",
+            PlanNode::VacuumDropTable(_) => "VacuumDropTablePlan",
+            PlanNode::VacuumTable(_) => "VacuumTablePlan",
+            PlanNode::AnalyzeTable(_) => "AnalyzeTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
+            PlanNode::Delete(_) => "DeletePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
+            PlanNode::SetRole(_) => "SetRolePlan",
+            PlanNode::SetSecondaryRoles(_) => "SetSecondaryRolesPlan",
             PlanNode::Insert(_) => "InsertPlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
@@ -201,8 +304,11 @@ impl PlanNode {
             PlanNode::DropUser(_) => "DropUser",
             PlanNode::GrantPrivilege(_) => "GrantPrivilegePlan",
             PlanNode::RevokePrivilege(_) => "RevokePrivilegePlan",
+            PlanNode::RevokeAllPrivileges(_) => "RevokeAllPrivilegesPlan",
             PlanNode::Sink(_) => "SinkPlan",
             PlanNode::Copy(_) => "CopyPlan",
+            PlanNode::CopyIntoStage(_) => "CopyIntoStagePlan",
+            PlanNode::ExportTable(_) => "ExportTablePlan",
             PlanNode::CreateUserStage(_) => "CreateUserStagePlan",
             PlanNode::DropUserStage(_) => "DropUserStagePlan",
             PlanNode::ShowGrants(_) => "ShowGrantsPlan",
@@ -211,6 +317,24 @@ impl PlanNode {
             PlanNode::DropUDF(_) => "DropUDFPlan",
             PlanNode::ShowUDF(_) => "ShowUDF",
             PlanNode::AlterUDF(_) => "AlterUDF",
+            PlanNode::CreateRowAccessPolicy(_) => "CreateRowAccessPolicyPlan",
+            PlanNode::DropRowAccessPolicy(_) => "DropRowAccessPolicyPlan",
+            PlanNode::AlterOwner(_) => "AlterOwnerPlan",
+            PlanNode::AlterReadOnly(_) => "AlterReadOnlyPlan",
+            PlanNode::AlterTableColumn(_) => "AlterTableColumnPlan",
+            PlanNode::AlterTableOptions(_) => "AlterTableOptionsPlan",
+            PlanNode::CreateNetworkPolicy(_) => "CreateNetworkPolicyPlan",
+            PlanNode::DropNetworkPolicy(_) => "DropNetworkPolicyPlan",
+            PlanNode::AlterUserNetworkPolicy(_) => "AlterUserNetworkPolicyPlan",
+            PlanNode::CreateRole(_) => "CreateRolePlan",
+            PlanNode::DropRole(_) => "DropRolePlan",
+            PlanNode::GrantRole(_) => "GrantRolePlan",
+            PlanNode::RevokeRole(_) => "RevokeRolePlan",
+            PlanNode::GrantRolePrivilege(_) => "GrantRolePrivilegePlan",
+            PlanNode::RevokeRolePrivilege(_) => "RevokeRolePrivilegePlan",
+            PlanNode::CreateConnection(_) => "CreateConnectionPlan",
+            PlanNode::DropConnection(_) => "DropConnectionPlan",
+            PlanNode::Transaction(_) => "TransactionPlan",
         }
     }
 
@@ -225,6 +349,8 @@ impl PlanNode {
             PlanNode::Filter(v) => vec![v.input.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
+            PlanNode::Distinct(v) => vec![v.input.clone()],
+            PlanNode::Join(v) => vec![v.left.clone(), v.right.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
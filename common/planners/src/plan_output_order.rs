@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+/// The order the output rows of a plan are known to be in, so that what is sorted already is
+/// not sorted again.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OutputOrder {
+    /// The sort keys, `Expression::Sort`s of columns, empty if the rows are in no known order.
+    pub keys: Vec<Expression>,
+    /// Whether the whole output is in order, otherwise only the rows of each block are.
+    pub total: bool,
+}
+
+impl OutputOrder {
+    /// Whether the rows are in the order of `order_by`, which is a prefix of the keys.
+    pub fn satisfies(&self, order_by: &[Expression], schema: &DataSchemaRef) -> bool {
+        order_by.len() <= self.keys.len()
+            && order_by
+                .iter()
+                .zip(self.keys.iter())
+                .all(|(required, key)| match (required, key) {
+                    (
+                        Expression::Sort {
+                            expr,
+                            asc,
+                            nulls_first,
+                            ..
+                        },
+                        Expression::Sort {
+                            expr: key_expr,
+                            asc: key_asc,
+                            nulls_first: key_nulls_first,
+                            ..
+                        },
+                    ) => {
+                        // where the nulls go does not matter if there is none
+                        let nullable = schema
+                            .field_with_name(&expr.column_name())
+                            .map(|f| f.is_nullable())
+                            .unwrap_or(true);
+                        expr == key_expr
+                            && asc == key_asc
+                            && (!nullable || nulls_first == key_nulls_first)
+                    }
+                    _ => false,
+                })
+    }
+
+    // The keys kept are the leading ones whose columns are passed through as they are.
+    fn project(self, exprs: &[Expression]) -> OutputOrder {
+        let keys = self
+            .keys
+            .into_iter()
+            .take_while(|key| match key {
+                Expression::Sort { expr, .. } => exprs.contains(expr),
+                _ => false,
+            })
+            .collect();
+        OutputOrder {
+            keys,
+            total: self.total,
+        }
+    }
+}
+
+impl PlanNode {
+    /// The order the output rows are in, threaded from the order tables read their partitions
+    /// in through the nodes keeping it.
+    pub fn output_order(&self) -> OutputOrder {
+        match self {
+            // the rows of each partition are in order, so are the blocks it is read in
+            PlanNode::ReadSource(v) => OutputOrder {
+                keys: v.read_order.clone(),
+                total: v.parts.len() <= 1,
+            }
+            .project(
+                &v.schema()
+                    .fields()
+                    .iter()
+                    .map(|f| Expression::Column(f.name().clone()))
+                    .collect::<Vec<_>>(),
+            ),
+            PlanNode::Filter(v) => v.input.output_order(),
+            PlanNode::Having(v) => v.input.output_order(),
+            PlanNode::Limit(v) => v.input.output_order(),
+            PlanNode::Expression(v) => v.input.output_order().project(&v.exprs),
+            PlanNode::Projection(v) => v.input.output_order().project(&v.expr),
+            _ => OutputOrder::default(),
+        }
+    }
+}
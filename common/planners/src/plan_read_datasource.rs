@@ -43,6 +43,9 @@ pub struct ReadDataSourcePlan {
 
     pub tbl_args: Option<Vec<Expression>>,
     pub push_downs: Option<Extras>,
+
+    /// The sort keys the rows of each partition are read in, empty if in no particular order.
+    pub read_order: Vec<Expression>,
 }
 
 impl ReadDataSourcePlan {
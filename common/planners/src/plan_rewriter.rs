@@ -25,31 +25,52 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterOwnerPlan;
+use crate::AlterReadOnlyPlan;
+use crate::AlterTableColumnPlan;
+use crate::AlterTableOptionsPlan;
 use crate::AlterUDFPlan;
+use crate::AlterUserNetworkPolicyPlan;
 use crate::AlterUserPlan;
+use crate::AnalyzeTablePlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
+use crate::CreateConnectionPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateNetworkPolicyPlan;
+use crate::CreateRolePlan;
+use crate::CreateRowAccessPolicyPlan;
 use crate::CreateTablePlan;
 use crate::CreateUDFPlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
+use crate::DistinctPlan;
+use crate::DropConnectionPlan;
 use crate::DropDatabasePlan;
+use crate::DropNetworkPolicyPlan;
+use crate::DropRolePlan;
+use crate::DropRowAccessPolicyPlan;
 use crate::DropTablePlan;
 use crate::DropUDFPlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
+use crate::ExportTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::ExpressionRewriter;
 use crate::Expressions;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
+use crate::GrantRolePlan;
+use crate::GrantRolePrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
@@ -59,8 +80,13 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RevokeAllPrivilegesPlan;
 use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
+use crate::RevokeRolePrivilegePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
+use crate::SetSecondaryRolesPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -69,8 +95,13 @@ use crate::ShowUDFPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
+use crate::UndropDatabasePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumDropTablePlan;
+use crate::VacuumTablePlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -102,6 +133,8 @@ pub trait PlanRewriter: Sized {
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
+            PlanNode::Distinct(plan) => self.rewrite_distinct(plan),
+            PlanNode::Join(plan) => self.rewrite_join(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
             PlanNode::Select(plan) => self.rewrite_select(plan),
             PlanNode::Explain(plan) => self.rewrite_explain(plan),
@@ -109,6 +142,8 @@ pub trait PlanRewriter: Sized {
             PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
             PlanNode::CreateDatabase(plan) => self.rewrite_create_database(plan),
             PlanNode::UseDatabase(plan) => self.rewrite_use_database(plan),
+            PlanNode::SetRole(plan) => self.rewrite_set_role(plan),
+            PlanNode::SetSecondaryRoles(plan) => self.rewrite_set_secondary_roles(plan),
             PlanNode::SetVariable(plan) => self.rewrite_set_variable(plan),
             PlanNode::Stage(plan) => self.rewrite_stage(plan),
             PlanNode::Broadcast(plan) => self.rewrite_broadcast(plan),
@@ -119,17 +154,26 @@ pub trait PlanRewriter: Sized {
             PlanNode::DescribeStage(plan) => self.rewrite_describe_stage(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
             PlanNode::DropDatabase(plan) => self.rewrite_drop_database(plan),
+            PlanNode::UndropDatabase(plan) => self.rewrite_undrop_database(plan),
+            PlanNode::UndropTable(plan) => self.rewrite_undrop_table(plan),
+            PlanNode::VacuumDropTable(plan) => self.rewrite_vacuum_drop_table(plan),
+            PlanNode::VacuumTable(plan) => self.rewrite_vacuum_table(plan),
+            PlanNode::AnalyzeTable(plan) => self.rewrite_analyze_table(plan),
             PlanNode::Insert(plan) => self.rewrite_insert_into(plan),
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
+            PlanNode::CopyIntoStage(plan) => self.rewrite_copy_into_stage(plan),
+            PlanNode::ExportTable(plan) => self.rewrite_export_table(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
             PlanNode::CreateUser(plan) => self.create_user(plan),
             PlanNode::AlterUser(plan) => self.alter_user(plan),
             PlanNode::DropUser(plan) => self.drop_user(plan),
             PlanNode::GrantPrivilege(plan) => self.grant_privilege(plan),
             PlanNode::RevokePrivilege(plan) => self.revoke_privilege(plan),
+            PlanNode::RevokeAllPrivileges(plan) => self.revoke_all_privileges(plan),
             PlanNode::CreateUserStage(plan) => self.rewrite_create_stage(plan),
             PlanNode::Sink(plan) => self.rewrite_sink(plan),
             PlanNode::ShowGrants(plan) => self.rewrite_show_grants(plan),
@@ -139,6 +183,24 @@ pub trait PlanRewriter: Sized {
             PlanNode::DropUDF(plan) => self.rewrite_drop_udf(plan),
             PlanNode::ShowUDF(plan) => self.rewrite_show_udf(plan),
             PlanNode::AlterUDF(plan) => self.rewrite_alter_udf(plan),
+            PlanNode::CreateRowAccessPolicy(plan) => self.rewrite_create_row_access_policy(plan),
+            PlanNode::DropRowAccessPolicy(plan) => self.rewrite_drop_row_access_policy(plan),
+            PlanNode::AlterOwner(plan) => self.rewrite_alter_owner(plan),
+            PlanNode::AlterReadOnly(plan) => self.rewrite_alter_read_only(plan),
+            PlanNode::AlterTableColumn(plan) => self.rewrite_alter_table_column(plan),
+            PlanNode::AlterTableOptions(plan) => self.rewrite_alter_table_options(plan),
+            PlanNode::CreateNetworkPolicy(plan) => self.rewrite_create_network_policy(plan),
+            PlanNode::DropNetworkPolicy(plan) => self.rewrite_drop_network_policy(plan),
+            PlanNode::AlterUserNetworkPolicy(plan) => self.rewrite_alter_user_network_policy(plan),
+            PlanNode::CreateRole(plan) => self.rewrite_create_role(plan),
+            PlanNode::DropRole(plan) => self.rewrite_drop_role(plan),
+            PlanNode::GrantRole(plan) => self.rewrite_grant_role(plan),
+            PlanNode::RevokeRole(plan) => self.rewrite_revoke_role(plan),
+            PlanNode::GrantRolePrivilege(plan) => self.rewrite_grant_role_privilege(plan),
+            PlanNode::RevokeRolePrivilege(plan) => self.rewrite_revoke_role_privilege(plan),
+            PlanNode::CreateConnection(plan) => self.rewrite_create_connection(plan),
+            PlanNode::DropConnection(plan) => self.rewrite_drop_connection(plan),
+            PlanNode::Transaction(plan) => self.rewrite_transaction(plan),
         }
     }
 
@@ -276,6 +338,24 @@ pub trait PlanRewriter: Sized {
             .build()
     }
 
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input).distinct()?.build()
+    }
+
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_plan_node(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                &new_right,
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+            )?
+            .build()
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         Ok(PlanNode::ReadSource(plan.clone()))
     }
@@ -309,6 +389,14 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::UseDatabase(plan.clone()))
     }
 
+    fn rewrite_set_role(&mut self, plan: &SetRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::SetRole(plan.clone()))
+    }
+
+    fn rewrite_set_secondary_roles(&mut self, plan: &SetSecondaryRolesPlan) -> Result<PlanNode> {
+        Ok(PlanNode::SetSecondaryRoles(plan.clone()))
+    }
+
     fn rewrite_set_variable(&mut self, plan: &SettingPlan) -> Result<PlanNode> {
         Ok(PlanNode::SetVariable(plan.clone()))
     }
@@ -329,6 +417,26 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::DropDatabase(plan.clone()))
     }
 
+    fn rewrite_undrop_database(&mut self, plan: &UndropDatabasePlan) -> Result<PlanNode> {
+        Ok(PlanNode::UndropDatabase(plan.clone()))
+    }
+
+    fn rewrite_undrop_table(&mut self, plan: &UndropTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::UndropTable(plan.clone()))
+    }
+
+    fn rewrite_vacuum_drop_table(&mut self, plan: &VacuumDropTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::VacuumDropTable(plan.clone()))
+    }
+
+    fn rewrite_vacuum_table(&mut self, plan: &VacuumTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::VacuumTable(plan.clone()))
+    }
+
+    fn rewrite_analyze_table(&mut self, plan: &AnalyzeTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AnalyzeTable(plan.clone()))
+    }
+
     fn rewrite_insert_into(&mut self, plan: &InsertPlan) -> Result<PlanNode> {
         Ok(PlanNode::Insert(plan.clone()))
     }
@@ -337,6 +445,14 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Copy(plan.clone()))
     }
 
+    fn rewrite_copy_into_stage(&mut self, plan: &CopyIntoStagePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CopyIntoStage(plan.clone()))
+    }
+
+    fn rewrite_export_table(&mut self, plan: &ExportTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::ExportTable(plan.clone()))
+    }
+
     fn rewrite_show_create_table(&mut self, plan: &ShowCreateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
@@ -345,6 +461,10 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
@@ -369,6 +489,10 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::RevokePrivilege(plan.clone()))
     }
 
+    fn revoke_all_privileges(&mut self, plan: &RevokeAllPrivilegesPlan) -> Result<PlanNode> {
+        Ok(PlanNode::RevokeAllPrivileges(plan.clone()))
+    }
+
     fn rewrite_create_stage(&mut self, plan: &CreateUserStagePlan) -> Result<PlanNode> {
         Ok(PlanNode::CreateUserStage(plan.clone()))
     }
@@ -377,6 +501,93 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::DropUserStage(plan.clone()))
     }
 
+    fn rewrite_create_row_access_policy(
+        &mut self,
+        plan: &CreateRowAccessPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateRowAccessPolicy(plan.clone()))
+    }
+
+    fn rewrite_drop_row_access_policy(
+        &mut self,
+        plan: &DropRowAccessPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::DropRowAccessPolicy(plan.clone()))
+    }
+
+    fn rewrite_alter_owner(&mut self, plan: &AlterOwnerPlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterOwner(plan.clone()))
+    }
+
+    fn rewrite_alter_read_only(&mut self, plan: &AlterReadOnlyPlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterReadOnly(plan.clone()))
+    }
+
+    fn rewrite_alter_table_column(&mut self, plan: &AlterTableColumnPlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTableColumn(plan.clone()))
+    }
+
+    fn rewrite_alter_table_options(&mut self, plan: &AlterTableOptionsPlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTableOptions(plan.clone()))
+    }
+
+    fn rewrite_create_network_policy(
+        &mut self,
+        plan: &CreateNetworkPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateNetworkPolicy(plan.clone()))
+    }
+
+    fn rewrite_drop_network_policy(&mut self, plan: &DropNetworkPolicyPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropNetworkPolicy(plan.clone()))
+    }
+
+    fn rewrite_alter_user_network_policy(
+        &mut self,
+        plan: &AlterUserNetworkPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::AlterUserNetworkPolicy(plan.clone()))
+    }
+
+    fn rewrite_create_role(&mut self, plan: &CreateRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateRole(plan.clone()))
+    }
+
+    fn rewrite_drop_role(&mut self, plan: &DropRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropRole(plan.clone()))
+    }
+
+    fn rewrite_grant_role(&mut self, plan: &GrantRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::GrantRole(plan.clone()))
+    }
+
+    fn rewrite_revoke_role(&mut self, plan: &RevokeRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RevokeRole(plan.clone()))
+    }
+
+    fn rewrite_grant_role_privilege(&mut self, plan: &GrantRolePrivilegePlan) -> Result<PlanNode> {
+        Ok(PlanNode::GrantRolePrivilege(plan.clone()))
+    }
+
+    fn rewrite_revoke_role_privilege(
+        &mut self,
+        plan: &RevokeRolePrivilegePlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::RevokeRolePrivilege(plan.clone()))
+    }
+
+    fn rewrite_create_connection(&mut self, plan: &CreateConnectionPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateConnection(plan.clone()))
+    }
+
+    fn rewrite_drop_connection(&mut self, plan: &DropConnectionPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropConnection(plan.clone()))
+    }
+
+    fn rewrite_transaction(&mut self, plan: &TransactionPlan) -> Result<PlanNode> {
+        Ok(PlanNode::Transaction(plan.clone()))
+    }
+
     fn rewrite_show_grants(&mut self, plan: &ShowGrantsPlan) -> Result<PlanNode> {
         Ok(PlanNode::ShowGrants(plan.clone()))
     }
@@ -19,6 +19,8 @@ use common_datavalues::DataSchemaRef;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct VarValue {
+    /// Set by `SET GLOBAL`, for the node instead of the session.
+    pub is_global: bool,
     pub variable: String,
     pub value: String,
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
 pub struct Statistics {
     /// Total rows of the query read.
@@ -20,6 +22,10 @@ pub struct Statistics {
     pub read_bytes: usize,
     /// Is the statistics exact.
     pub is_exact: bool,
+    /// Estimated number of distinct values of the columns by name, for the columns known.
+    pub column_ndvs: BTreeMap<String, u64>,
+    /// Number of nulls of the columns by name, known of the analyzed tables only.
+    pub column_null_counts: BTreeMap<String, u64>,
 }
 
 impl Statistics {
@@ -28,6 +34,8 @@ impl Statistics {
             read_rows,
             read_bytes,
             is_exact: false,
+            column_ndvs: BTreeMap::new(),
+            column_null_counts: BTreeMap::new(),
         }
     }
 
@@ -36,6 +44,8 @@ impl Statistics {
             read_rows,
             read_bytes,
             is_exact: true,
+            column_ndvs: BTreeMap::new(),
+            column_null_counts: BTreeMap::new(),
         }
     }
 
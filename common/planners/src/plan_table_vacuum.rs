@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct VacuumTablePlan {
+    pub database: String,
+    pub table: String,
+    /// Overrides the configured retention of the table history.
+    pub retain_hours: Option<u64>,
+    /// Only reports what would be removed.
+    pub dry_run: bool,
+}
+
+impl VacuumTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("snapshots", DataType::UInt64, false),
+            DataField::new("segments", DataType::UInt64, false),
+            DataField::new("blocks", DataType::UInt64, false),
+            DataField::new("block_bytes", DataType::UInt64, false),
+        ])
+    }
+}
@@ -21,6 +21,7 @@ use common_meta_types::UserDefinedFunction;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CreateUDFPlan {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     pub udf: UserDefinedFunction,
 }
 
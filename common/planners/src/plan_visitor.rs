@@ -18,29 +18,50 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterOwnerPlan;
+use crate::AlterReadOnlyPlan;
+use crate::AlterTableColumnPlan;
+use crate::AlterTableOptionsPlan;
 use crate::AlterUDFPlan;
+use crate::AlterUserNetworkPolicyPlan;
 use crate::AlterUserPlan;
+use crate::AnalyzeTablePlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
+use crate::CreateConnectionPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateNetworkPolicyPlan;
+use crate::CreateRolePlan;
+use crate::CreateRowAccessPolicyPlan;
 use crate::CreateTablePlan;
 use crate::CreateUDFPlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
+use crate::DistinctPlan;
+use crate::DropConnectionPlan;
 use crate::DropDatabasePlan;
+use crate::DropNetworkPolicyPlan;
+use crate::DropRolePlan;
+use crate::DropRowAccessPolicyPlan;
 use crate::DropTablePlan;
 use crate::DropUDFPlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
+use crate::ExportTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
+use crate::GrantRolePlan;
+use crate::GrantRolePrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
@@ -49,8 +70,13 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RevokeAllPrivilegesPlan;
 use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
+use crate::RevokeRolePrivilegePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
+use crate::SetSecondaryRolesPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -59,8 +85,13 @@ use crate::ShowUDFPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
+use crate::UndropDatabasePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumDropTablePlan;
+use crate::VacuumTablePlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -114,18 +145,28 @@ pub trait PlanVisitor {
             PlanNode::Sort(plan) => self.visit_sort(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
+            PlanNode::Distinct(plan) => self.visit_distinct(plan),
+            PlanNode::Join(plan) => self.visit_join(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
             PlanNode::Select(plan) => self.visit_select(plan),
             PlanNode::Explain(plan) => self.visit_explain(plan),
             PlanNode::CreateDatabase(plan) => self.visit_create_database(plan),
             PlanNode::DropDatabase(plan) => self.visit_drop_database(plan),
+            PlanNode::UndropDatabase(plan) => self.visit_undrop_database(plan),
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
+            PlanNode::UndropTable(plan) => self.visit_undrop_table(plan),
+            PlanNode::VacuumDropTable(plan) => self.visit_vacuum_drop_table(plan),
+            PlanNode::VacuumTable(plan) => self.visit_vacuum_table(plan),
+            PlanNode::AnalyzeTable(plan) => self.visit_analyze_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
             PlanNode::DescribeStage(plan) => self.visit_describe_stage(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
+            PlanNode::SetRole(plan) => self.visit_set_role(plan),
+            PlanNode::SetSecondaryRoles(plan) => self.visit_set_secondary_roles(plan),
             PlanNode::SetVariable(plan) => self.visit_set_variable(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan),
@@ -134,6 +175,8 @@ pub trait PlanVisitor {
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::Insert(plan) => self.visit_insert_into(plan),
             PlanNode::Copy(plan) => self.visit_copy(plan),
+            PlanNode::CopyIntoStage(plan) => self.visit_copy_into_stage(plan),
+            PlanNode::ExportTable(plan) => self.visit_export_table(plan),
             PlanNode::ShowCreateTable(plan) => self.visit_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
@@ -142,6 +185,7 @@ pub trait PlanVisitor {
             PlanNode::DropUser(plan) => self.visit_drop_user(plan),
             PlanNode::GrantPrivilege(plan) => self.visit_grant_privilege(plan),
             PlanNode::RevokePrivilege(plan) => self.visit_revoke_privilege(plan),
+            PlanNode::RevokeAllPrivileges(plan) => self.visit_revoke_all_privileges(plan),
             PlanNode::Sink(plan) => self.visit_append(plan),
             PlanNode::CreateUserStage(plan) => self.visit_create_stage(plan),
             PlanNode::ShowGrants(plan) => self.visit_show_grants(plan),
@@ -151,6 +195,24 @@ pub trait PlanVisitor {
             PlanNode::DropUDF(plan) => self.visit_drop_udf(plan),
             PlanNode::ShowUDF(plan) => self.visit_show_udf(plan),
             PlanNode::AlterUDF(plan) => self.visit_alter_udf(plan),
+            PlanNode::CreateRowAccessPolicy(plan) => self.visit_create_row_access_policy(plan),
+            PlanNode::DropRowAccessPolicy(plan) => self.visit_drop_row_access_policy(plan),
+            PlanNode::AlterOwner(plan) => self.visit_alter_owner(plan),
+            PlanNode::AlterReadOnly(plan) => self.visit_alter_read_only(plan),
+            PlanNode::AlterTableColumn(plan) => self.visit_alter_table_column(plan),
+            PlanNode::AlterTableOptions(plan) => self.visit_alter_table_options(plan),
+            PlanNode::CreateNetworkPolicy(plan) => self.visit_create_network_policy(plan),
+            PlanNode::DropNetworkPolicy(plan) => self.visit_drop_network_policy(plan),
+            PlanNode::AlterUserNetworkPolicy(plan) => self.visit_alter_user_network_policy(plan),
+            PlanNode::CreateRole(plan) => self.visit_create_role(plan),
+            PlanNode::DropRole(plan) => self.visit_drop_role(plan),
+            PlanNode::GrantRole(plan) => self.visit_grant_role(plan),
+            PlanNode::RevokeRole(plan) => self.visit_revoke_role(plan),
+            PlanNode::GrantRolePrivilege(plan) => self.visit_grant_role_privilege(plan),
+            PlanNode::RevokeRolePrivilege(plan) => self.visit_revoke_role_privilege(plan),
+            PlanNode::CreateConnection(plan) => self.visit_create_connection(plan),
+            PlanNode::DropConnection(plan) => self.visit_drop_connection(plan),
+            PlanNode::Transaction(plan) => self.visit_transaction(plan),
         }
     }
 
@@ -246,6 +308,15 @@ pub trait PlanVisitor {
         self.visit_plan_node(plan.input.as_ref())
     }
 
+    fn visit_distinct(&mut self, plan: &DistinctPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref())?;
+        self.visit_plan_node(plan.right.as_ref())
+    }
+
     fn visit_read_data_source(&mut self, _: &ReadDataSourcePlan) -> Result<()> {
         Ok(())
     }
@@ -266,6 +337,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_undrop_database(&mut self, _: &UndropDatabasePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_create_table(&mut self, _: &CreateTablePlan) -> Result<()> {
         Ok(())
     }
@@ -290,6 +365,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_revoke_all_privileges(&mut self, _: &RevokeAllPrivilegesPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_describe_table(&mut self, _: &DescribeTablePlan) -> Result<()> {
         Ok(())
     }
@@ -306,10 +385,34 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_undrop_table(&mut self, _: &UndropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_vacuum_drop_table(&mut self, _: &VacuumDropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_vacuum_table(&mut self, _: &VacuumTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_analyze_table(&mut self, _: &AnalyzeTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_use_database(&mut self, _: &UseDatabasePlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_set_role(&mut self, _: &SetRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_set_secondary_roles(&mut self, _: &SetSecondaryRolesPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_set_variable(&mut self, _: &SettingPlan) -> Result<()> {
         Ok(())
     }
@@ -322,6 +425,14 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_copy_into_stage(&mut self, _: &CopyIntoStagePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_export_table(&mut self, _: &ExportTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_show_create_table(&mut self, _: &ShowCreateTablePlan) -> Result<()> {
         Ok(())
     }
@@ -330,6 +441,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
@@ -345,6 +460,78 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_create_row_access_policy(&mut self, _: &CreateRowAccessPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_row_access_policy(&mut self, _: &DropRowAccessPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_owner(&mut self, _: &AlterOwnerPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_read_only(&mut self, _: &AlterReadOnlyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_table_column(&mut self, _: &AlterTableColumnPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_table_options(&mut self, _: &AlterTableOptionsPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_network_policy(&mut self, _: &CreateNetworkPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_network_policy(&mut self, _: &DropNetworkPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_user_network_policy(&mut self, _: &AlterUserNetworkPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_role(&mut self, _: &CreateRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_role(&mut self, _: &DropRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_grant_role(&mut self, _: &GrantRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_revoke_role(&mut self, _: &RevokeRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_grant_role_privilege(&mut self, _: &GrantRolePrivilegePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_revoke_role_privilege(&mut self, _: &RevokeRolePrivilegePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_connection(&mut self, _: &CreateConnectionPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_connection(&mut self, _: &DropConnectionPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_transaction(&mut self, _: &TransactionPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_show_grants(&mut self, _: &ShowGrantsPlan) -> Result<()> {
         Ok(())
     }
@@ -21,7 +21,9 @@ mod plan_expression;
 mod plan_extras;
 mod plan_filter;
 mod plan_having;
+mod plan_join;
 mod plan_limit;
+mod plan_output_order;
 mod plan_projection;
 mod plan_rewriter;
 mod plan_select;
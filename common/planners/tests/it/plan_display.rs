@@ -33,6 +33,7 @@ fn test_plan_display_indent() -> Result<()> {
 
     let plan_create = PlanNode::CreateTable(CreateTablePlan {
         if_not_exists: true,
+        or_replace: false,
         db: "foo".into(),
         table: "bar".into(),
         table_meta: TableMeta {
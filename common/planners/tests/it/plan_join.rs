@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_planners::*;
+
+use crate::test::Test;
+
+#[test]
+fn test_join_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let left = PlanBuilder::from(&Test::create().generate_source_plan_for_test(10)?)
+        .project(&[col("number").alias("a")])?
+        .build()?;
+    let right = PlanBuilder::from(&Test::create().generate_source_plan_for_test(20)?)
+        .project(&[col("number").alias("b")])?
+        .build()?;
+    let plan = PlanBuilder::from(&left)
+        .join(&right, JoinType::Left, &[col("a")], &[col("b")])?
+        .build()?;
+
+    let expect = "\
+    HashJoin: type=Left, on=[a = b]\
+    \n  Projection: number as a:UInt64\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+    \n  Projection: number as b:UInt64\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 20, read_bytes: 160]";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    // The right side of a left join is filled with nulls.
+    let schema = plan.schema();
+    assert!(!schema.field_with_name("a")?.is_nullable());
+    assert!(schema.field_with_name("b")?.is_nullable());
+
+    // The same columns on both sides are ambiguous.
+    let result = PlanBuilder::from(&left).join(&left, JoinType::Inner, &[col("a")], &[col("a")]);
+    let expect = "Code: 6, displayText = Duplicate column a in the join, alias it on one side.";
+    assert_eq!(expect, result.err().unwrap().to_string());
+
+    // The keys are columns.
+    let result = PlanBuilder::from(&left).join(&right, JoinType::Full, &[lit(1u64)], &[col("b")]);
+    let expect = "Code: 6, displayText = Join key must be a column, but got: 1.";
+    assert_eq!(expect, result.err().unwrap().to_string());
+    Ok(())
+}
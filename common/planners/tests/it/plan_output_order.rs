@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::test::Test;
+
+#[test]
+fn test_output_order() -> Result<()> {
+    // the numbers are read in order in each of the 8 partitions
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let schema = source.schema();
+
+    // kept through the filters and the columns passed through
+    let plan = PlanBuilder::from(&source)
+        .filter(col("number").gt(lit(1)))?
+        .project(&[col("number")])?
+        .limit(3)?
+        .build()?;
+    let order = plan.output_order();
+    assert_eq!(order, OutputOrder {
+        keys: vec![sort("number", true, true)],
+        total: false,
+    });
+    assert!(order.satisfies(&[sort("number", true, true)], &schema));
+    // the column is not nullable
+    assert!(order.satisfies(&[sort("number", true, false)], &schema));
+    assert!(!order.satisfies(&[sort("number", false, false)], &schema));
+    assert!(!order.satisfies(
+        &[sort("number", true, true), sort("number", false, true)],
+        &schema
+    ));
+
+    // lost by the columns computed or renamed
+    let plan = PlanBuilder::from(&source)
+        .project(&[col("number").alias("n")])?
+        .build()?;
+    assert_eq!(plan.output_order(), OutputOrder::default());
+
+    let plan = PlanBuilder::from(&source)
+        .expression(&[add(col("number"), lit(1))], "")?
+        .build()?;
+    assert_eq!(plan.output_order().keys, vec![]);
+
+    // and by the aggregations
+    let plan = PlanBuilder::from(&source)
+        .aggregate_partial(&[], &[col("number")])?
+        .build()?;
+    assert_eq!(plan.output_order(), OutputOrder::default());
+
+    Ok(())
+}
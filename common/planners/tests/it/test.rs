@@ -17,6 +17,7 @@ use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::sort;
 use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::PlanNode;
@@ -34,11 +35,7 @@ impl Test {
         let schema =
             DataSchemaRefExt::create(vec![DataField::new("number", DataType::UInt64, false)]);
 
-        let statistics = Statistics {
-            read_rows: total,
-            read_bytes: total * 8,
-            is_exact: true,
-        };
+        let statistics = Statistics::new_exact(total, total * 8);
 
         Ok(PlanNode::ReadSource(ReadDataSourcePlan {
             table_info: TableInfo::simple("system", "numbers_mt", schema),
@@ -51,6 +48,7 @@ impl Test {
             ),
             tbl_args: None,
             push_downs: None,
+            read_order: vec![sort("number", true, true)],
         }))
     }
 
@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod metrics;
 mod sources;
+mod spill;
 mod stream;
 mod stream_abort;
 mod stream_cast;
 mod stream_correct_with_schema;
 mod stream_datablock;
+mod stream_distinct;
+mod stream_external_sort;
 mod stream_limit_by;
 mod stream_progress;
+mod stream_rechunk;
 mod stream_skip;
 mod stream_sort;
 mod stream_source;
@@ -27,13 +32,18 @@ mod stream_sub_queries;
 mod stream_take;
 
 pub use sources::*;
+pub use spill::SpillDir;
+pub use spill::SpillFile;
 pub use stream::*;
 pub use stream_abort::AbortStream;
 pub use stream_cast::CastStream;
 pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
+pub use stream_distinct::DistinctStream;
+pub use stream_external_sort::ExternalSortStream;
 pub use stream_limit_by::LimitByStream;
 pub use stream_progress::ProgressStream;
+pub use stream_rechunk::RechunkStream;
 pub use stream_skip::SkipStream;
 pub use stream_sort::SortStream;
 pub use stream_source::SourceStream;
@@ -13,15 +13,23 @@
 // limitations under the License.
 
 mod source;
+mod source_avro;
 mod source_csv;
 mod source_factory;
+mod source_ndjson;
+mod source_orc;
 mod source_parquet;
 mod source_values;
 
+pub use source::BadRows;
 pub use source::FormatSettings;
+pub use source::MatchByColumnName;
 pub use source::Source;
+pub use source_avro::AvroSource;
 pub use source_csv::CsvSource;
 pub use source_factory::SourceFactory;
 pub use source_factory::SourceParams;
+pub use source_ndjson::NdJsonSource;
+pub use source_orc::OrcSource;
 pub use source_parquet::ParquetSource;
 pub use source_values::ValueSource;
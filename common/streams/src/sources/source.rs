@@ -12,17 +12,97 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::Mutex;
 
 #[async_trait]
 pub trait Source: Send {
     async fn read(&mut self) -> Result<Option<DataBlock>>;
 }
 
+/// How the columns of a file are matched with the ones of the table, set by the option
+/// `match_by_column_name`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatchByColumnName {
+    /// By position, the default
+    None,
+    CaseSensitive,
+    CaseInsensitive,
+}
+
+impl MatchByColumnName {
+    pub fn try_create(option: &str) -> Result<MatchByColumnName> {
+        match option.to_lowercase().as_str() {
+            "none" => Ok(MatchByColumnName::None),
+            "case_sensitive" => Ok(MatchByColumnName::CaseSensitive),
+            "case_insensitive" => Ok(MatchByColumnName::CaseInsensitive),
+            other => Err(ErrorCode::BadOption(format!(
+                "Unknown match_by_column_name: {}, expecting one of none, case_sensitive, case_insensitive",
+                other
+            ))),
+        }
+    }
+
+    /// The position of the column named `column` in the `names` of the file, if any.
+    pub fn position<S: AsRef<str>>(&self, names: &[S], column: &str) -> Option<usize> {
+        names.iter().position(|name| match self {
+            MatchByColumnName::CaseInsensitive => name.as_ref().eq_ignore_ascii_case(column),
+            _ => name.as_ref() == column,
+        })
+    }
+}
+
 #[allow(dead_code)]
 pub struct FormatSettings {
     delimiter: u8,
     quote: u8,
 }
+
+/// The malformed rows skipped by the text sources instead of failing, up to `max_bad_rows`.
+/// Shared with the consumer of the source, to report them once the source is drained.
+pub struct BadRows {
+    max_bad_rows: usize,
+    count: AtomicUsize,
+    first_error: Mutex<Option<String>>,
+}
+
+impl BadRows {
+    pub fn create(max_bad_rows: usize) -> Arc<BadRows> {
+        Arc::new(BadRows {
+            max_bad_rows,
+            count: AtomicUsize::new(0),
+            first_error: Mutex::new(None),
+        })
+    }
+
+    /// Record the error of a malformed row, which is given back if it is one too many.
+    pub fn skip(&self, error: ErrorCode) -> Result<()> {
+        let count = self.count.fetch_add(1, Ordering::Relaxed) + 1;
+        {
+            let mut first_error = self.first_error.lock();
+            if first_error.is_none() {
+                *first_error = Some(error.message());
+            }
+        }
+
+        if count > self.max_bad_rows {
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    pub fn first_error(&self) -> Option<String> {
+        self.first_error.lock().clone()
+    }
+}
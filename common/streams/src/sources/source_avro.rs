@@ -0,0 +1,278 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::io::Cursor;
+use std::io::Read;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use avro_rs::types::Value;
+use avro_rs::Reader;
+use avro_rs::Schema;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::Source;
+
+// The magic byte of a message framed by the schema registry, followed by a 4-byte schema id.
+const FRAMED_MAGIC_BYTE: u8 = 0;
+
+enum AvroReader {
+    // An object container file, of which the schema is in the header.
+    Container(Reader<'static, Cursor<Vec<u8>>>),
+    // The messages framed by the schema registry, one after another.
+    Framed(Schema, Cursor<Vec<u8>>),
+}
+
+/// Avro object container files, or the messages framed by a schema registry (a zero byte and
+/// a 4-byte schema id before each message) of which the writer schema is given by the option
+/// `avro_schema`. The fields of the records are matched with the columns by name.
+///
+/// The logical types are read as their text: the decimals as `123.45`, the dates as
+/// `2022-01-01` and the timestamps as `2022-01-01 00:00:00.000` (UTC).
+pub struct AvroSource {
+    data_accessor: Arc<dyn DataAccessor>,
+    path: String,
+    schema: DataSchemaRef,
+    writer_schema: Option<String>,
+    block_size: usize,
+    reader: Option<AvroReader>,
+}
+
+impl AvroSource {
+    pub fn new(
+        data_accessor: Arc<dyn DataAccessor>,
+        path: String,
+        schema: DataSchemaRef,
+        writer_schema: Option<String>,
+        block_size: usize,
+    ) -> Self {
+        Self {
+            data_accessor,
+            path,
+            schema,
+            writer_schema,
+            block_size,
+            reader: None,
+        }
+    }
+
+    async fn open(&self) -> Result<AvroReader> {
+        let bytes = self.data_accessor.read(&self.path).await?;
+        if bytes.first() == Some(&FRAMED_MAGIC_BYTE) {
+            let writer_schema = self.writer_schema.as_ref().ok_or_else(|| {
+                ErrorCode::AvroError(
+                    "The avro_schema option is required to read the messages of schema registry",
+                )
+            })?;
+            let schema = Schema::parse_str(writer_schema).map_err(avro_error)?;
+            return Ok(AvroReader::Framed(schema, Cursor::new(bytes)));
+        }
+
+        let reader = Reader::new(Cursor::new(bytes)).map_err(avro_error)?;
+        Ok(AvroReader::Container(reader))
+    }
+}
+
+impl AvroReader {
+    fn schema(&self) -> &Schema {
+        match self {
+            AvroReader::Container(reader) => reader.writer_schema(),
+            AvroReader::Framed(schema, _) => schema,
+        }
+    }
+
+    fn next(&mut self) -> Result<Option<Value>> {
+        match self {
+            AvroReader::Container(reader) => reader.next().transpose().map_err(avro_error),
+            AvroReader::Framed(schema, cursor) => {
+                if cursor.position() >= cursor.get_ref().len() as u64 {
+                    return Ok(None);
+                }
+                let mut header = [0u8; 5];
+                cursor.read_exact(&mut header).map_err(|e| {
+                    ErrorCode::AvroError(format!("Cannot read the message header, {}", e))
+                })?;
+                if header[0] != FRAMED_MAGIC_BYTE {
+                    return Err(ErrorCode::AvroError(format!(
+                        "Unknown magic byte {} of the message",
+                        header[0]
+                    )));
+                }
+                avro_rs::from_avro_datum(schema, cursor, None)
+                    .map(Some)
+                    .map_err(avro_error)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for AvroSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        if self.reader.is_none() {
+            self.reader = Some(self.open().await?);
+        }
+        let reader = self.reader.as_mut().unwrap();
+
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        while rows < self.block_size {
+            let fields = match reader.next()? {
+                None => break,
+                Some(Value::Record(fields)) => fields,
+                Some(other) => {
+                    return Err(ErrorCode::AvroError(format!(
+                        "Expected an Avro record, but got {:?}",
+                        other
+                    )))
+                }
+            };
+
+            for (field, deser) in self.schema.fields().iter().zip(desers.iter_mut()) {
+                let value = fields
+                    .iter()
+                    .position(|(name, _)| name == field.name())
+                    .map(|idx| (&fields[idx].1, field_schema(reader.schema(), idx)));
+                match value {
+                    None => deser.de_null(),
+                    Some((value, schema)) => match value_to_text(value, schema)? {
+                        None => deser.de_null(),
+                        Some(text) => deser.de_text(&text).map_err(|e| {
+                            e.add_message_back(format!(" at record {} of {}", rows, self.path))
+                        })?,
+                    },
+                }
+            }
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            series,
+        )))
+    }
+}
+
+fn avro_error(e: avro_rs::Error) -> ErrorCode {
+    ErrorCode::AvroError(e.to_string())
+}
+
+fn field_schema(schema: &Schema, idx: usize) -> Option<&Schema> {
+    match schema {
+        Schema::Record { fields, .. } => fields.get(idx).map(|f| &f.schema),
+        _ => None,
+    }
+}
+
+// The scale of a decimal, or of the decimal of a nullable union.
+fn decimal_scale(schema: Option<&Schema>) -> Option<usize> {
+    match schema? {
+        Schema::Decimal { scale, .. } => Some(*scale),
+        Schema::Union(union) => union
+            .variants()
+            .iter()
+            .find_map(|variant| decimal_scale(Some(variant))),
+        _ => None,
+    }
+}
+
+fn value_to_text(value: &Value, schema: Option<&Schema>) -> Result<Option<Vec<u8>>> {
+    let text = match value {
+        Value::Null => return Ok(None),
+        Value::Union(inner) => return value_to_text(inner, schema),
+        Value::Boolean(v) => v.to_string().into_bytes(),
+        Value::Int(v) => v.to_string().into_bytes(),
+        Value::Long(v) => v.to_string().into_bytes(),
+        Value::Float(v) => v.to_string().into_bytes(),
+        Value::Double(v) => v.to_string().into_bytes(),
+        Value::Bytes(v) | Value::Fixed(_, v) => v.clone(),
+        Value::String(v) | Value::Enum(_, v) => v.clone().into_bytes(),
+        Value::Uuid(v) => v.to_string().into_bytes(),
+        Value::Date(days) => {
+            let epoch = NaiveDate::from_ymd(1970, 1, 1);
+            (epoch + chrono::Duration::days(*days as i64))
+                .to_string()
+                .into_bytes()
+        }
+        Value::TimestampMillis(ms) => {
+            format_timestamp(ms.div_euclid(1000), ms.rem_euclid(1000) * 1_000_000)
+        }
+        Value::TimestampMicros(us) => {
+            format_timestamp(us.div_euclid(1_000_000), us.rem_euclid(1_000_000) * 1000)
+        }
+        Value::TimeMillis(v) => v.to_string().into_bytes(),
+        Value::TimeMicros(v) => v.to_string().into_bytes(),
+        Value::Decimal(decimal) => {
+            let bytes = <Vec<u8>>::try_from(decimal).map_err(avro_error)?;
+            format_decimal(&bytes, decimal_scale(schema).unwrap_or(0))?.into_bytes()
+        }
+        other => {
+            let json = serde_json::Value::try_from(other.clone()).map_err(avro_error)?;
+            json.to_string().into_bytes()
+        }
+    };
+    Ok(Some(text))
+}
+
+fn format_timestamp(secs: i64, nanos: i64) -> Vec<u8> {
+    NaiveDateTime::from_timestamp(secs, nanos as u32)
+        .format("%Y-%m-%d %H:%M:%S%.3f")
+        .to_string()
+        .into_bytes()
+}
+
+// The unscaled value of a decimal is a two's-complement big-endian integer.
+fn format_decimal(bytes: &[u8], scale: usize) -> Result<String> {
+    if bytes.len() > 16 {
+        return Err(ErrorCode::AvroError(format!(
+            "Decimal of {} bytes is too large",
+            bytes.len()
+        )));
+    }
+    let negative = bytes.first().map(|b| b & 0x80 != 0).unwrap_or(false);
+    let mut unscaled: i128 = if negative { -1 } else { 0 };
+    for b in bytes {
+        unscaled = (unscaled << 8) | *b as i128;
+    }
+
+    let digits = unscaled.unsigned_abs().to_string();
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let sign = if unscaled < 0 { "-" } else { "" };
+    match scale {
+        0 => Ok(format!("{}{}", sign, integer)),
+        _ => Ok(format!("{}{}.{}", sign, integer, fraction)),
+    }
+}
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
@@ -24,6 +28,8 @@ use csv_async::Terminator;
 use futures::stream::StreamExt;
 use futures::AsyncRead;
 
+use crate::BadRows;
+use crate::MatchByColumnName;
 use crate::Source;
 
 pub struct CsvSource<R> {
@@ -31,6 +37,14 @@ pub struct CsvSource<R> {
     schema: DataSchemaRef,
     block_size: usize,
     rows: usize,
+    header: bool,
+    // TSV, the fields are not quoted but escaped by backslashes
+    escaped: bool,
+    bad_rows: Option<Arc<BadRows>>,
+    match_by_column_name: MatchByColumnName,
+    // the field of each column in the records, resolved by the header if matched by name
+    positions: Option<Vec<usize>>,
+    error_on_column_count_mismatch: bool,
 }
 
 impl<R> CsvSource<R>
@@ -44,24 +58,131 @@ where R: AsyncRead + Unpin + Send
         record_delimitor: u8,
         block_size: usize,
     ) -> Result<Self> {
-        let record_delimitor = if record_delimitor == b'\n' || record_delimitor == b'\r' {
-            Terminator::CRLF
-        } else {
-            Terminator::Any(record_delimitor)
-        };
-
         let reader = AsyncReaderBuilder::new()
             .has_headers(header)
+            .flexible(true)
             .delimiter(field_delimitor)
-            .terminator(record_delimitor)
+            .terminator(Self::terminator(record_delimitor))
+            .create_reader(reader);
+
+        Ok(Self::create(reader, schema, block_size, header, false))
+    }
+
+    /// Tab separated values, in which the tabs, line breaks and backslashes of the fields
+    /// are escaped as `\t`, `\n` and `\\`.
+    pub fn try_create_tsv(
+        reader: R,
+        schema: DataSchemaRef,
+        header: bool,
+        record_delimitor: u8,
+        block_size: usize,
+    ) -> Result<Self> {
+        let reader = AsyncReaderBuilder::new()
+            .has_headers(header)
+            .flexible(true)
+            .delimiter(b'\t')
+            .quoting(false)
+            .terminator(Self::terminator(record_delimitor))
             .create_reader(reader);
 
-        Ok(Self {
+        Ok(Self::create(reader, schema, block_size, header, true))
+    }
+
+    /// Skip the malformed rows instead of failing, as long as `bad_rows` tolerates them.
+    pub fn with_bad_rows(mut self, bad_rows: Arc<BadRows>) -> Self {
+        self.bad_rows = Some(bad_rows);
+        self
+    }
+
+    /// Match the columns with the fields of the header by name, instead of by position. The
+    /// blocks only have the columns found in the header, the extra fields are ignored.
+    pub fn with_match_by_column_name(mut self, match_by_column_name: MatchByColumnName) -> Self {
+        self.match_by_column_name = match_by_column_name;
+        self
+    }
+
+    /// Fail the rows of which the number of fields is not the number of the columns, instead
+    /// of reading the missing fields as NULLs and ignoring the extra ones.
+    pub fn with_error_on_column_count_mismatch(mut self, error: bool) -> Self {
+        self.error_on_column_count_mismatch = error;
+        self
+    }
+
+    fn create(
+        reader: AsyncReader<R>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        header: bool,
+        escaped: bool,
+    ) -> Self {
+        Self {
             reader,
             block_size,
             schema,
             rows: 0,
-        })
+            header,
+            escaped,
+            bad_rows: None,
+            match_by_column_name: MatchByColumnName::None,
+            positions: None,
+            error_on_column_count_mismatch: false,
+        }
+    }
+
+    // Keep the columns found in the header, once before the first block.
+    async fn match_columns(&mut self) -> Result<()> {
+        if self.match_by_column_name == MatchByColumnName::None || self.positions.is_some() {
+            return Ok(());
+        }
+        if !self.header {
+            return Err(ErrorCode::BadOption(
+                "match_by_column_name needs the header of the file, with csv_header = 1",
+            ));
+        }
+
+        let headers = self
+            .reader
+            .byte_headers()
+            .await
+            .map_err_to_code(ErrorCode::BadBytes, || "Parse csv header error")?;
+        let names = headers
+            .iter()
+            .map(|name| String::from_utf8_lossy(name).trim().to_string())
+            .collect::<Vec<_>>();
+
+        let mut fields = vec![];
+        let mut positions = vec![];
+        for field in self.schema.fields() {
+            if let Some(position) = self.match_by_column_name.position(&names, field.name()) {
+                fields.push(field.clone());
+                positions.push(position);
+            }
+        }
+        if fields.is_empty() {
+            return Err(ErrorCode::BadBytes(format!(
+                "None of the fields of the header {:?} matches a column",
+                names
+            )));
+        }
+
+        self.schema = DataSchemaRefExt::create(fields);
+        self.positions = Some(positions);
+        Ok(())
+    }
+
+    fn terminator(record_delimitor: u8) -> Terminator {
+        if record_delimitor == b'\n' || record_delimitor == b'\r' {
+            Terminator::CRLF
+        } else {
+            Terminator::Any(record_delimitor)
+        }
+    }
+
+    fn skip_bad_row(&self, error: ErrorCode) -> Result<()> {
+        match &self.bad_rows {
+            Some(bad_rows) => bad_rows.skip(error),
+            None => Err(error),
+        }
     }
 }
 
@@ -70,6 +191,7 @@ impl<R> Source for CsvSource<R>
 where R: AsyncRead + Unpin + Send
 {
     async fn read(&mut self) -> Result<Option<DataBlock>> {
+        self.match_columns().await?;
         let mut desers = self
             .schema
             .fields()
@@ -77,25 +199,78 @@ where R: AsyncRead + Unpin + Send
             .map(|f| f.data_type().create_deserializer(self.block_size))
             .collect::<Result<Vec<_>>>()?;
 
+        // matched by name, the records have the fields of the header
+        let positions = self.positions.clone();
+        let columns = self.schema.fields().len();
+        let check_column_count = self.error_on_column_count_mismatch && positions.is_none();
+
         let mut rows = 0;
+        // the rows of the block to keep, filled once a malformed row is met
+        let mut good_rows: Option<Vec<u32>> = None;
         let mut records = self.reader.byte_records();
 
         while let Some(record) = records.next().await {
-            let record = record.map_err_to_code(ErrorCode::BadBytes, || {
-                format!("Parse csv error at line {}", self.rows)
-            })?;
+            self.rows += 1;
+            let record = match record.map_err_to_code(ErrorCode::BadBytes, || {
+                format!("Parse csv error at line {}", self.rows - 1)
+            }) {
+                Ok(record) => record,
+                Err(e) => {
+                    self.skip_bad_row(e)?;
+                    continue;
+                }
+            };
 
             if record.is_empty() {
                 break;
             }
+            if check_column_count && record.len() != columns {
+                self.skip_bad_row(ErrorCode::BadBytes(format!(
+                    "Number of fields in the file ({}) does not match the number of columns ({}) at line {}",
+                    record.len(),
+                    columns,
+                    self.rows - 1
+                )))?;
+                continue;
+            }
+
+            let mut error = None;
             for (col, deser) in desers.iter_mut().enumerate() {
-                match record.get(col) {
-                    Some(bytes) => deser.de_text(bytes)?,
-                    None => deser.de_null(),
+                if error.is_some() {
+                    // the row is dropped, the columns are kept aligned
+                    deser.de_null();
+                    continue;
+                }
+                let field = match &positions {
+                    Some(positions) => positions[col],
+                    None => col,
+                };
+                let result = match record.get(field) {
+                    Some(bytes) if self.escaped => deser.de_text(&unescape_tsv(bytes)),
+                    Some(bytes) => deser.de_text(bytes),
+                    None => {
+                        deser.de_null();
+                        Ok(())
+                    }
+                };
+                if let Err(e) = result {
+                    deser.de_null();
+                    error = Some(e.add_message_back(format!(" at line {}", self.rows - 1)));
+                }
+            }
+
+            match error {
+                Some(e) => {
+                    self.skip_bad_row(e)?;
+                    good_rows.get_or_insert_with(|| (0..rows as u32).collect());
+                }
+                None => {
+                    if let Some(good_rows) = good_rows.as_mut() {
+                        good_rows.push(rows as u32);
+                    }
                 }
             }
             rows += 1;
-            self.rows += 1;
 
             if rows >= self.block_size {
                 break;
@@ -111,9 +286,39 @@ where R: AsyncRead + Unpin + Send
             .map(|deser| deser.finish_to_series())
             .collect::<Vec<_>>();
 
-        Ok(Some(DataBlock::create_by_array(
-            self.schema.clone(),
-            series,
-        )))
+        let block = DataBlock::create_by_array(self.schema.clone(), series);
+        match good_rows {
+            Some(good_rows) => Ok(Some(DataBlock::block_take_by_indices(
+                &block,
+                &[],
+                &good_rows,
+            )?)),
+            None => Ok(Some(block)),
+        }
+    }
+}
+
+fn unescape_tsv(bytes: &[u8]) -> Cow<[u8]> {
+    if !bytes.contains(&b'\\') {
+        return Cow::Borrowed(bytes);
+    }
+
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut iter = bytes.iter();
+    while let Some(b) = iter.next() {
+        match (b, iter.as_slice().first()) {
+            (b'\\', Some(next)) => {
+                unescaped.push(match next {
+                    b't' => b'\t',
+                    b'n' => b'\n',
+                    b'r' => b'\r',
+                    b'0' => b'\0',
+                    other => *other,
+                });
+                iter.next();
+            }
+            (b, _) => unescaped.push(*b),
+        }
     }
+    Cow::Owned(unescaped)
 }
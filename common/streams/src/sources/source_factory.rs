@@ -15,12 +15,21 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
 use common_dal::DataAccessor;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use futures::io::BufReader;
+use futures::AsyncRead;
 
+use crate::AvroSource;
+use crate::BadRows;
 use crate::CsvSource;
+use crate::MatchByColumnName;
+use crate::NdJsonSource;
+use crate::OrcSource;
 use crate::ParquetSource;
 use crate::Source;
 
@@ -34,48 +43,84 @@ pub struct SourceParams<'a> {
     pub max_block_size: usize,
     pub projection: Vec<usize>,
     pub options: &'a HashMap<String, String>,
+    /// The malformed rows of the text formats tolerated, none if None.
+    pub bad_rows: Option<Arc<BadRows>>,
 }
 
+type TextReader = Box<dyn AsyncRead + Send + Unpin>;
+
 impl SourceFactory {
+    /// The sources of the formats matching the columns by name, csv, tsv, ndjson and parquet,
+    /// may read a subset of the columns of `schema`, as told by the schemas of the blocks.
     pub fn try_get(params: SourceParams) -> Result<Box<dyn Source>> {
         let format = params.format.to_lowercase();
+        let match_by_column_name = Self::match_by_column_name(&params)?;
         match format.as_str() {
             "csv" => {
-                let has_header = params
-                    .options
-                    .get("csv_header")
-                    .cloned()
-                    .unwrap_or_else(|| "0".to_string());
-
-                let field_delimitor = params
-                    .options
-                    .get("field_delimitor")
-                    .map(|v| match v.len() {
-                        n if n >= 1 => v.as_bytes()[0],
-                        _ => b',',
-                    })
-                    .unwrap_or(b',');
-
-                let record_delimitor = params
-                    .options
-                    .get("record_delimitor")
-                    .map(|v| match v.len() {
-                        n if n >= 1 => v.as_bytes()[0],
-                        _ => b'\n',
-                    })
-                    .unwrap_or(b'\n');
-
-                let reader = params.acc.get_input_stream(params.path, None)?;
-                Ok(Box::new(CsvSource::try_create(
-                    reader,
-                    params.schema,
-                    has_header.eq_ignore_ascii_case("1"),
-                    field_delimitor,
-                    record_delimitor,
+                let source = CsvSource::try_create(
+                    Self::text_reader(&params)?,
+                    params.schema.clone(),
+                    Self::has_header(&params),
+                    Self::delimitor(&params, "field_delimitor", b','),
+                    Self::delimitor(&params, "record_delimitor", b'\n'),
+                    params.max_block_size,
+                )?
+                .with_match_by_column_name(match_by_column_name)
+                .with_error_on_column_count_mismatch(Self::error_on_column_count_mismatch(&params));
+                match params.bad_rows {
+                    Some(bad_rows) => Ok(Box::new(source.with_bad_rows(bad_rows))),
+                    None => Ok(Box::new(source)),
+                }
+            }
+            "tsv" => {
+                let source = CsvSource::try_create_tsv(
+                    Self::text_reader(&params)?,
+                    params.schema.clone(),
+                    Self::has_header(&params),
+                    Self::delimitor(&params, "record_delimitor", b'\n'),
+                    params.max_block_size,
+                )?
+                .with_match_by_column_name(match_by_column_name)
+                .with_error_on_column_count_mismatch(Self::error_on_column_count_mismatch(&params));
+                match params.bad_rows {
+                    Some(bad_rows) => Ok(Box::new(source.with_bad_rows(bad_rows))),
+                    None => Ok(Box::new(source)),
+                }
+            }
+            "ndjson" => {
+                let mut source = NdJsonSource::create(
+                    Self::text_reader(&params)?,
+                    params.schema.clone(),
                     params.max_block_size,
-                )?))
+                );
+                if match_by_column_name != MatchByColumnName::None {
+                    source = source.with_match_by_column_name(match_by_column_name);
+                }
+                match params.bad_rows {
+                    Some(bad_rows) => Ok(Box::new(source.with_bad_rows(bad_rows))),
+                    None => Ok(Box::new(source)),
+                }
             }
-            "parquet" => Ok(Box::new(ParquetSource::new(
+            "parquet" => Ok(Box::new(
+                ParquetSource::new(
+                    params.acc,
+                    params.path.to_owned(),
+                    params.schema,
+                    params.projection,
+                )
+                .with_match_by_column_name(match_by_column_name),
+            )),
+            _ if match_by_column_name != MatchByColumnName::None => Err(ErrorCode::BadOption(
+                format!("match_by_column_name is not supported by {}", format),
+            )),
+            "avro" => Ok(Box::new(AvroSource::new(
+                params.acc,
+                params.path.to_owned(),
+                params.schema,
+                params.options.get("avro_schema").cloned(),
+                params.max_block_size,
+            ))),
+            "orc" => Ok(Box::new(OrcSource::new(
                 params.acc,
                 params.path.to_owned(),
                 params.schema,
@@ -84,4 +129,77 @@ impl SourceFactory {
             _ => Err(ErrorCode::InvalidSourceFormat(format)),
         }
     }
+
+    // The options of the statements are case insensitive.
+    fn option<'a>(params: &'a SourceParams, name: &str) -> Option<&'a String> {
+        params
+            .options
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    fn match_by_column_name(params: &SourceParams) -> Result<MatchByColumnName> {
+        match Self::option(params, "match_by_column_name") {
+            Some(v) => MatchByColumnName::try_create(v),
+            None => Ok(MatchByColumnName::None),
+        }
+    }
+
+    fn error_on_column_count_mismatch(params: &SourceParams) -> bool {
+        Self::option(params, "error_on_column_count_mismatch")
+            .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+            .unwrap_or(false)
+    }
+
+    fn has_header(params: &SourceParams) -> bool {
+        params
+            .options
+            .get("csv_header")
+            .map(|v| v.eq_ignore_ascii_case("1"))
+            .unwrap_or(false)
+    }
+
+    fn delimitor(params: &SourceParams, option: &str, default: u8) -> u8 {
+        params
+            .options
+            .get(option)
+            .map(|v| match v.len() {
+                n if n >= 1 => v.as_bytes()[0],
+                _ => default,
+            })
+            .unwrap_or(default)
+    }
+
+    // The text files are decompressed on the fly, the compression is told by the extension
+    // of the file if it is `auto`, the default.
+    fn text_reader(params: &SourceParams) -> Result<TextReader> {
+        let compression = params
+            .options
+            .get("compression")
+            .map(|v| v.to_lowercase())
+            .unwrap_or_else(|| "auto".to_string());
+
+        let compression = match compression.as_str() {
+            "auto" if params.path.ends_with(".gz") => "gzip",
+            "auto" if params.path.ends_with(".zst") => "zstd",
+            "auto" => "none",
+            other => other,
+        };
+
+        let reader = params.acc.get_input_stream(params.path, None)?;
+        match compression {
+            "none" => Ok(Box::new(reader)),
+            "gzip" => {
+                let mut decoder = GzipDecoder::new(BufReader::new(reader));
+                decoder.multiple_members(true);
+                Ok(Box::new(decoder))
+            }
+            "zstd" => Ok(Box::new(ZstdDecoder::new(BufReader::new(reader)))),
+            other => Err(ErrorCode::BadOption(format!(
+                "Unsupported compression: {}, expecting one of auto, none, gzip, zstd",
+                other
+            ))),
+        }
+    }
 }
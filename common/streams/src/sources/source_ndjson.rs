@@ -0,0 +1,232 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::TypeDeserializer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::io::BufReader;
+use futures::AsyncBufReadExt;
+use futures::AsyncRead;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::BadRows;
+use crate::MatchByColumnName;
+use crate::Source;
+
+/// Newline delimited JSON, one object per line, of which the fields are matched with the
+/// columns by name. The missing fields and the JSON nulls are read as NULLs.
+pub struct NdJsonSource<R> {
+    reader: BufReader<R>,
+    schema: DataSchemaRef,
+    block_size: usize,
+    rows: usize,
+    bad_rows: Option<Arc<BadRows>>,
+    match_by_column_name: MatchByColumnName,
+}
+
+impl<R> NdJsonSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    pub fn create(reader: R, schema: DataSchemaRef, block_size: usize) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            schema,
+            block_size,
+            rows: 0,
+            bad_rows: None,
+            match_by_column_name: MatchByColumnName::CaseSensitive,
+        }
+    }
+
+    /// Skip the malformed rows instead of failing, as long as `bad_rows` tolerates them.
+    pub fn with_bad_rows(mut self, bad_rows: Arc<BadRows>) -> Self {
+        self.bad_rows = Some(bad_rows);
+        self
+    }
+
+    /// The fields are always matched by name, case insensitively for `CaseInsensitive`.
+    pub fn with_match_by_column_name(mut self, match_by_column_name: MatchByColumnName) -> Self {
+        self.match_by_column_name = match_by_column_name;
+        self
+    }
+
+    fn field<'a>(&self, object: &'a Map<String, Value>, name: &str) -> Option<&'a Value> {
+        match self.match_by_column_name {
+            MatchByColumnName::CaseInsensitive => object
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value),
+            _ => object.get(name),
+        }
+    }
+
+    // Parse the line before touching the deserializers, a malformed line leaves no values.
+    fn parse_line(&self, line: &[u8]) -> Result<Vec<Option<Vec<u8>>>> {
+        let object = match serde_json::from_slice::<Value>(line) {
+            Ok(Value::Object(object)) => object,
+            Ok(other) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Expected a json object at line {}, but got {}",
+                    self.rows, other
+                )))
+            }
+            Err(e) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Parse json error at line {}: {}",
+                    self.rows, e
+                )))
+            }
+        };
+
+        let values = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| match self.field(&object, f.name()) {
+                None | Some(Value::Null) => None,
+                Some(Value::String(s)) => Some(s.as_bytes().to_vec()),
+                Some(other) => Some(other.to_string().into_bytes()),
+            });
+        Ok(values.collect())
+    }
+
+    // A value not of the type of its column fails the row, of which the rest of the columns
+    // are filled by NULLs to keep them aligned.
+    fn deserialize(
+        &self,
+        desers: &mut [Box<dyn TypeDeserializer>],
+        values: &[Option<Vec<u8>>],
+    ) -> Result<()> {
+        let mut error = None;
+        for (deser, value) in desers.iter_mut().zip(values.iter()) {
+            if error.is_some() {
+                deser.de_null();
+                continue;
+            }
+            match value {
+                Some(bytes) => {
+                    if let Err(e) = deser.de_text(bytes) {
+                        deser.de_null();
+                        error = Some(e.add_message_back(format!(" at line {}", self.rows)));
+                    }
+                }
+                None => deser.de_null(),
+            }
+        }
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn skip_bad_row(&self, error: ErrorCode) -> Result<()> {
+        match &self.bad_rows {
+            Some(bad_rows) => bad_rows.skip(error),
+            None => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl<R> Source for NdJsonSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        // the rows of the block to keep, filled once a malformed row is met
+        let mut good_rows: Option<Vec<u32>> = None;
+        let mut line = Vec::new();
+
+        loop {
+            line.clear();
+            if self.reader.read_until(b'\n', &mut line).await? == 0 {
+                break;
+            }
+
+            let trimmed = trim_whitespaces(&line);
+            if trimmed.is_empty() {
+                self.rows += 1;
+                continue;
+            }
+
+            let values = match self.parse_line(trimmed) {
+                Ok(values) => values,
+                Err(e) => {
+                    self.rows += 1;
+                    self.skip_bad_row(e)?;
+                    continue;
+                }
+            };
+
+            match self.deserialize(&mut desers, &values) {
+                Ok(_) => {
+                    if let Some(good_rows) = good_rows.as_mut() {
+                        good_rows.push(rows as u32);
+                    }
+                }
+                Err(e) => {
+                    self.skip_bad_row(e)?;
+                    good_rows.get_or_insert_with(|| (0..rows as u32).collect());
+                }
+            }
+            rows += 1;
+            self.rows += 1;
+
+            if rows >= self.block_size {
+                break;
+            }
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+
+        let block = DataBlock::create_by_array(self.schema.clone(), series);
+        match good_rows {
+            Some(good_rows) => Ok(Some(DataBlock::block_take_by_indices(
+                &block,
+                &[],
+                &good_rows,
+            )?)),
+            None => Ok(Some(block)),
+        }
+    }
+}
+
+fn trim_whitespaces(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|b| !b.is_ascii_whitespace());
+    let end = bytes.iter().rposition(|b| !b.is_ascii_whitespace());
+    match (start, end) {
+        (Some(start), Some(end)) => &bytes[start..=end],
+        _ => &[],
+    }
+}
@@ -0,0 +1,350 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Read;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use orc_format::proto::column_encoding::Kind as ColumnEncodingKind;
+use orc_format::proto::r#type::Kind as TypeKind;
+use orc_format::proto::stream::Kind as StreamKind;
+use orc_format::read::decode::BooleanIter;
+use orc_format::read::decode::FloatIter;
+use orc_format::read::decode::SignedRleV2Iter;
+use orc_format::read::decode::UnsignedRleV2Iter;
+use orc_format::read::Column;
+use orc_format::read::FileMetadata;
+
+use crate::Source;
+
+/// Reads the ORC files produced by Hive or Spark, a block for each stripe. The columns of the
+/// file are matched with the columns of the schema by position, as in `ParquetSource`.
+///
+/// The whole file is fetched at the first read, the ORC readers require seeking.
+/// Only the RLE v2 encodings (of ORC 0.12 files) of the primitive types are supported.
+pub struct OrcSource {
+    data_accessor: Arc<dyn DataAccessor>,
+    path: String,
+
+    block_schema: DataSchemaRef,
+    projection: Vec<usize>,
+    file: Option<(Cursor<Vec<u8>>, FileMetadata)>,
+    stripe: usize,
+}
+
+impl OrcSource {
+    pub fn new(
+        data_accessor: Arc<dyn DataAccessor>,
+        path: String,
+        table_schema: DataSchemaRef,
+        projection: Vec<usize>,
+    ) -> Self {
+        let block_schema = Arc::new(table_schema.project(projection.clone()));
+        Self {
+            data_accessor,
+            path,
+            block_schema,
+            projection,
+            file: None,
+            stripe: 0,
+        }
+    }
+
+    /// The schema of an ORC file, all the columns are nullable.
+    pub async fn infer_schema(
+        data_accessor: Arc<dyn DataAccessor>,
+        path: &str,
+    ) -> Result<DataSchema> {
+        let (_, metadata) = Self::read_file(data_accessor.as_ref(), path).await?;
+        let types = &metadata.footer.types;
+        let root = types
+            .first()
+            .ok_or_else(|| ErrorCode::OrcError(format!("No type in the ORC file {}", path)))?;
+
+        let mut fields = Vec::with_capacity(root.subtypes.len());
+        for (subtype, name) in root.subtypes.iter().zip(root.field_names.iter()) {
+            let kind = types
+                .get(*subtype as usize)
+                .ok_or_else(|| ErrorCode::OrcError(format!("Unknown ORC type {}", subtype)))?
+                .kind();
+            fields.push(DataField::new(name, Self::data_type(kind)?, true));
+        }
+        Ok(DataSchema::new(fields))
+    }
+
+    fn data_type(kind: TypeKind) -> Result<DataType> {
+        match kind {
+            TypeKind::Boolean => Ok(DataType::Boolean),
+            TypeKind::Short => Ok(DataType::Int16),
+            TypeKind::Int => Ok(DataType::Int32),
+            TypeKind::Long => Ok(DataType::Int64),
+            TypeKind::Float => Ok(DataType::Float32),
+            TypeKind::Double => Ok(DataType::Float64),
+            TypeKind::Date => Ok(DataType::Date32),
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                Ok(DataType::String)
+            }
+            other => Err(ErrorCode::OrcError(format!(
+                "Unsupported ORC type {:?}",
+                other
+            ))),
+        }
+    }
+
+    async fn read_file(
+        data_accessor: &dyn DataAccessor,
+        path: &str,
+    ) -> Result<(Cursor<Vec<u8>>, FileMetadata)> {
+        let bytes = data_accessor.read(path).await?;
+        let mut reader = Cursor::new(bytes);
+        let metadata = orc_format::read::read_metadata(&mut reader).map_err(orc_error)?;
+        Ok((reader, metadata))
+    }
+
+    fn read_stripe(&mut self) -> Result<Option<DataBlock>> {
+        let (reader, metadata) = match &mut self.file {
+            Some((reader, metadata)) => (reader, metadata),
+            None => return Ok(None),
+        };
+        if self.stripe >= metadata.footer.stripes.len() {
+            return Ok(None);
+        }
+
+        let footer =
+            orc_format::read::read_stripe_footer(reader, metadata, self.stripe, &mut vec![])
+                .map_err(orc_error)?;
+
+        let mut columns = Vec::with_capacity(self.projection.len());
+        for (idx, field) in self.projection.iter().zip(self.block_schema.fields()) {
+            // the column 0 is the struct of the rows
+            let column = orc_format::read::read_stripe_column(
+                reader,
+                metadata,
+                self.stripe,
+                footer.clone(),
+                *idx as u32 + 1,
+                vec![],
+            )
+            .map_err(orc_error)?;
+            let series = deserialize(&column, metadata, *idx as u32 + 1)?;
+            let series = match series.data_type() == field.data_type() {
+                true => series,
+                false => series.cast_with_type(field.data_type())?,
+            };
+            columns.push(DataColumn::Array(series));
+        }
+
+        self.stripe += 1;
+        Ok(Some(DataBlock::create(self.block_schema.clone(), columns)))
+    }
+}
+
+#[async_trait]
+impl Source for OrcSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        if self.file.is_none() {
+            let file = Self::read_file(self.data_accessor.as_ref(), &self.path).await?;
+            self.file = Some(file);
+        }
+        self.read_stripe()
+            .map_err(|e| ErrorCode::OrcError(format!("Cannot read ORC file {}, {}", self.path, e)))
+    }
+}
+
+fn orc_error(e: orc_format::error::Error) -> ErrorCode {
+    ErrorCode::OrcError(format!("{:?}", e))
+}
+
+fn deserialize(column: &Column, metadata: &FileMetadata, idx: u32) -> Result<Series> {
+    let kind = metadata
+        .footer
+        .types
+        .get(idx as usize)
+        .ok_or_else(|| ErrorCode::OrcError(format!("No column {} in the ORC file", idx)))?
+        .kind();
+
+    let validity = deserialize_validity(column)?;
+    let num_of_values = match &validity {
+        Some(validity) => validity.iter().filter(|v| **v).count(),
+        None => column.number_of_rows(),
+    };
+
+    match kind {
+        TypeKind::Boolean => {
+            let stream = column
+                .get_stream(StreamKind::Data, vec![])
+                .map_err(orc_error)?;
+            let values = BooleanIter::new(stream, num_of_values)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(orc_error)?;
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        TypeKind::Short => {
+            let values = deserialize_signed(column, num_of_values)?;
+            let values = values.into_iter().map(|v| v as i16).collect();
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        TypeKind::Int | TypeKind::Date => {
+            let values = deserialize_signed(column, num_of_values)?;
+            let values = values.into_iter().map(|v| v as i32).collect();
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        TypeKind::Long => {
+            let values = deserialize_signed(column, num_of_values)?;
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        TypeKind::Float => {
+            let stream = column
+                .get_stream(StreamKind::Data, vec![])
+                .map_err(orc_error)?;
+            let values = FloatIter::<f32, _>::new(stream, num_of_values)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(orc_error)?;
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        TypeKind::Double => {
+            let stream = column
+                .get_stream(StreamKind::Data, vec![])
+                .map_err(orc_error)?;
+            let values = FloatIter::<f64, _>::new(stream, num_of_values)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(orc_error)?;
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+            let values = deserialize_binary(column, num_of_values)?;
+            Ok(Series::new(with_validity(values, &validity)))
+        }
+        other => Err(ErrorCode::OrcError(format!(
+            "Unsupported ORC type {:?}",
+            other
+        ))),
+    }
+}
+
+// None if all the values are present.
+fn deserialize_validity(column: &Column) -> Result<Option<Vec<bool>>> {
+    let stream = match column.get_stream(StreamKind::Present, vec![]) {
+        Ok(stream) => stream,
+        Err(orc_format::error::Error::InvalidKind(_, _)) => return Ok(None),
+        Err(e) => return Err(orc_error(e)),
+    };
+    BooleanIter::new(stream, column.number_of_rows())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map(Some)
+        .map_err(orc_error)
+}
+
+fn deserialize_signed(column: &Column, num_of_values: usize) -> Result<Vec<i64>> {
+    expect_v2_encoding(column)?;
+    let stream = column
+        .get_stream(StreamKind::Data, vec![])
+        .map_err(orc_error)?;
+    SignedRleV2Iter::new(stream, num_of_values, vec![])
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(orc_error)
+}
+
+fn deserialize_unsigned(column: &Column, kind: StreamKind, num: usize) -> Result<Vec<u64>> {
+    let stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    UnsignedRleV2Iter::new(stream, num, vec![])
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(orc_error)
+}
+
+fn read_stream(column: &Column, kind: StreamKind) -> Result<Vec<u8>> {
+    let mut stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    let mut bytes = vec![];
+    stream
+        .read_to_end(&mut bytes)
+        .map_err(|e| ErrorCode::OrcError(e.to_string()))?;
+    Ok(bytes)
+}
+
+// The strings are either concatenated with their lengths, or in a dictionary.
+fn deserialize_binary(column: &Column, num_of_values: usize) -> Result<Vec<Vec<u8>>> {
+    expect_v2_encoding(column)?;
+    let split = |bytes: &[u8], lengths: &[u64]| {
+        let mut offset = 0;
+        let mut values = Vec::with_capacity(lengths.len());
+        for length in lengths {
+            let end = offset + *length as usize;
+            let value = bytes
+                .get(offset..end)
+                .ok_or_else(|| ErrorCode::OrcError("The lengths of the strings exceed the data"))?;
+            values.push(value.to_vec());
+            offset = end;
+        }
+        Ok::<_, ErrorCode>(values)
+    };
+
+    match column.encoding().kind() {
+        ColumnEncodingKind::DirectV2 => {
+            let lengths = deserialize_unsigned(column, StreamKind::Length, num_of_values)?;
+            split(&read_stream(column, StreamKind::Data)?, &lengths)
+        }
+        _ => {
+            let dictionary_size = column.dictionary_size().unwrap_or(0);
+            let lengths = deserialize_unsigned(column, StreamKind::Length, dictionary_size)?;
+            let dictionary = split(&read_stream(column, StreamKind::DictionaryData)?, &lengths)?;
+            deserialize_unsigned(column, StreamKind::Data, num_of_values)?
+                .into_iter()
+                .map(|index| {
+                    dictionary.get(index as usize).cloned().ok_or_else(|| {
+                        ErrorCode::OrcError(format!("Index {} out of the dictionary", index))
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+fn expect_v2_encoding(column: &Column) -> Result<()> {
+    match column.encoding().kind() {
+        ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2 => Ok(()),
+        other => Err(ErrorCode::OrcError(format!(
+            "Unsupported ORC encoding {:?}, only the RLE v2 encodings are supported",
+            other
+        ))),
+    }
+}
+
+// The values are of the present rows only.
+fn with_validity<T>(values: Vec<T>, validity: &Option<Vec<bool>>) -> Vec<Option<T>> {
+    match validity {
+        None => values.into_iter().map(Some).collect(),
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|present| match present {
+                    true => values.next(),
+                    false => None,
+                })
+                .collect()
+        }
+    }
+}
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read::decompress;
+use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::page_stream_to_array;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
@@ -25,7 +30,9 @@ use common_dal::DataAccessor;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::DataColumn;
 use common_datavalues::series::IntoSeries;
+use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
@@ -38,6 +45,7 @@ use futures::TryStreamExt;
 /// default buffer size of BufferedReader, 1MB
 const DEFAULT_READ_BUFFER_SIZE: u64 = 1024 * 1024;
 
+use crate::MatchByColumnName;
 use crate::Source;
 
 pub struct ParquetSource {
@@ -52,6 +60,11 @@ pub struct ParquetSource {
     metadata: Option<FileMetaData>,
     file_len: Option<u64>,
     read_buffer_size: Option<u64>,
+    read_bytes: u64,
+    decode_nanos: Arc<AtomicU64>,
+    match_by_column_name: MatchByColumnName,
+    // the column of the file of each projected column, resolved by name if matched by name
+    file_columns: Option<Vec<usize>>,
 }
 
 impl ParquetSource {
@@ -93,42 +106,108 @@ impl ParquetSource {
             metadata,
             file_len,
             read_buffer_size,
+            read_bytes: 0,
+            decode_nanos: Arc::new(AtomicU64::new(0)),
+            match_by_column_name: MatchByColumnName::None,
+            file_columns: None,
         }
     }
+
+    /// Match the columns with the top level fields of the file by name, instead of by position.
+    /// The blocks only have the columns found in the file.
+    pub fn with_match_by_column_name(mut self, match_by_column_name: MatchByColumnName) -> Self {
+        self.match_by_column_name = match_by_column_name;
+        self
+    }
+
+    fn match_columns(&mut self, metadata: &FileMetaData) -> Result<()> {
+        let file_schema =
+            infer_schema(metadata).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        let file_schema = DataSchema::from(file_schema);
+        let names = file_schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+
+        let mut fields = vec![];
+        let mut projection = vec![];
+        let mut file_columns = vec![];
+        for (idx, field) in self.projection.iter().zip(self.block_schema.fields()) {
+            if let Some(column) = self.match_by_column_name.position(&names, field.name()) {
+                fields.push(field.clone());
+                projection.push(*idx);
+                file_columns.push(column);
+            }
+        }
+        if fields.is_empty() {
+            return Err(ErrorCode::ParquetError(format!(
+                "None of the fields of the file {:?} matches a column",
+                names
+            )));
+        }
+
+        self.block_schema = DataSchemaRefExt::create(fields);
+        self.projection = projection;
+        self.file_columns = Some(file_columns);
+        Ok(())
+    }
+
+    /// The compressed bytes of the column chunks read so far.
+    pub fn read_bytes(&self) -> u64 {
+        self.read_bytes
+    }
+
+    /// The time spent in decompressing the pages read so far.
+    pub fn decode_cost(&self) -> Duration {
+        Duration::from_nanos(self.decode_nanos.load(Ordering::Relaxed))
+    }
 }
 
 #[async_trait]
 impl Source for ParquetSource {
     #[tracing::instrument(level = "debug", skip_all)]
     async fn read(&mut self) -> Result<Option<DataBlock>> {
-        let fetched_metadata;
-        let metadata = match &self.metadata {
+        let metadata = match self.metadata.take() {
             Some(m) => m,
             None => {
                 let mut reader = self
                     .data_accessor
                     .get_input_stream(self.path.as_str(), None)?;
-                fetched_metadata = read_metadata_async(&mut reader)
+                read_metadata_async(&mut reader)
                     .instrument(debug_span!("parquet_source_read_meta"))
                     .await
-                    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-                &fetched_metadata
+                    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?
             }
         };
+        if self.match_by_column_name != MatchByColumnName::None && self.file_columns.is_none() {
+            self.match_columns(&metadata)?;
+        }
+        // the row groups are read one by one, the metadata is kept for the next ones
+        let metadata = &*self.metadata.insert(metadata);
 
         self.row_groups = metadata.row_groups.len();
-        self.row_group = 0;
-
         if self.row_group >= self.row_groups {
             return Ok(None);
         }
         let col_num = self.projection.len();
         let row_group = self.row_group;
-        let cols = self
-            .projection
-            .clone()
+        let columns = match &self.file_columns {
+            Some(file_columns) => file_columns
+                .iter()
+                .copied()
+                .zip(self.projection.iter().copied())
+                .collect::<Vec<_>>(),
+            None => self.projection.iter().map(|idx| (*idx, *idx)).collect(),
+        };
+        let cols = columns
             .into_iter()
-            .map(|idx| (metadata.row_groups[row_group].column(idx).clone(), idx));
+            .map(|(column, idx)| (metadata.row_groups[row_group].column(column).clone(), idx))
+            .collect::<Vec<_>>();
+        self.read_bytes += cols
+            .iter()
+            .map(|(col_meta, _)| col_meta.compressed_size() as u64)
+            .sum::<u64>();
 
         let fields = self.arrow_table_schema.fields();
         let stream_len = self.file_len;
@@ -137,6 +216,7 @@ impl Source for ParquetSource {
         let stream = futures::stream::iter(cols).map(|(col_meta, idx)| {
             let data_accessor = self.data_accessor.clone();
             let path = self.path.clone();
+            let decode_nanos = self.decode_nanos.clone();
 
             async move {
                 let reader = data_accessor.get_input_stream(path.as_str(), stream_len)?;
@@ -147,9 +227,12 @@ impl Source for ParquetSource {
                         .instrument(debug_span!("parquet_source_get_column_page"))
                         .await
                         .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-                let pages = col_pages.map(|compressed_page| {
-                    debug_span!("parquet_source_decompress_page")
-                        .in_scope(|| decompress(compressed_page?, &mut vec![]))
+                let pages = col_pages.map(move |compressed_page| {
+                    let start = Instant::now();
+                    let page = debug_span!("parquet_source_decompress_page")
+                        .in_scope(|| decompress(compressed_page?, &mut vec![]));
+                    decode_nanos.fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+                    page
                 });
                 let array = page_stream_to_array(pages, &col_meta, fields[idx].data_type.clone())
                     .instrument(debug_span!("parquet_source_page_stream_to_array"))
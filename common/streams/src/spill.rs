@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_tracing::tracing;
+use metrics::counter;
+use tempfile::NamedTempFile;
+
+use crate::metrics::METRIC_SPILL_BYTES;
+use crate::metrics::METRIC_SPILL_FILES;
+
+/// The working directory of the files spilled by a query, `<root>/<query_id>`, created with
+/// the first file and removed with the last one once the query ends. The bytes written into
+/// the files are bounded by the quota of the query, 0 means no limit.
+pub struct SpillDir {
+    path: PathBuf,
+    quota_bytes: u64,
+    written_bytes: AtomicU64,
+}
+
+impl SpillDir {
+    pub fn create(root: impl AsRef<Path>, query_id: &str, quota_bytes: u64) -> Arc<SpillDir> {
+        Arc::new(SpillDir {
+            path: root.as_ref().join(query_id),
+            quota_bytes,
+            written_bytes: AtomicU64::new(0),
+        })
+    }
+
+    /// Removes the working directories left under the root, e.g. by the queries of a crashed
+    /// process. It is called before any query runs.
+    pub fn cleanup(root: impl AsRef<Path>) -> Result<()> {
+        match fs::read_dir(root.as_ref()) {
+            Err(cause) if cause.kind() == ErrorKind::NotFound => Ok(()),
+            Err(cause) => Err(cause.into()),
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry?.path();
+                    tracing::warn!("Remove the spill files left in {:?}", path);
+                    match path.is_dir() {
+                        true => fs::remove_dir_all(&path)?,
+                        false => fs::remove_file(&path)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn written_bytes(&self) -> u64 {
+        self.written_bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn create_file(self: &Arc<Self>) -> Result<SpillFile> {
+        fs::create_dir_all(&self.path)?;
+        let file = tempfile::Builder::new()
+            .prefix("spill-")
+            .tempfile_in(&self.path)?;
+        counter!(METRIC_SPILL_FILES, 1);
+        Ok(SpillFile {
+            dir: self.clone(),
+            file,
+        })
+    }
+
+    fn reserve(&self, bytes: u64) -> io::Result<()> {
+        let written = self.written_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if self.quota_bytes != 0 && written > self.quota_bytes {
+            self.written_bytes.fetch_sub(bytes, Ordering::Relaxed);
+            return Err(io::Error::new(
+                ErrorKind::Other,
+                format!(
+                    "The query spills more than the quota of {} bytes, spill_quota_bytes",
+                    self.quota_bytes
+                ),
+            ));
+        }
+        counter!(METRIC_SPILL_BYTES, bytes);
+        Ok(())
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        if let Err(cause) = fs::remove_dir_all(&self.path) {
+            if cause.kind() != ErrorKind::NotFound {
+                tracing::warn!("Cannot remove the spill dir {:?}: {}", self.path, cause);
+            }
+        }
+    }
+}
+
+/// A spilled file in the working directory of a query, removed once dropped.
+pub struct SpillFile {
+    dir: Arc<SpillDir>,
+    file: NamedTempFile,
+}
+
+impl Write for SpillFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.dir.reserve(buf.len() as u64)?;
+        self.file.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+impl Read for SpillFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+impl Seek for SpillFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.file.seek(pos)
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::convert::TryInto;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_arrow::arrow;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+use crate::SpillDir;
+use crate::SpillFile;
+
+const SPILL_PARTITIONS: usize = 16;
+
+/// Removes the duplicate rows of the input stream, the first occurrence of a row is emitted
+/// as soon as it is read, so the order of the input is kept and a `limit` ends the stream
+/// early.
+///
+/// The rows are remembered by their serialized keys. Once the keys take more than
+/// `max_memory_bytes` bytes (0 means no limit), they are spilled into the files of the spill
+/// dir of the query partitioned by hash, and so are the keys of the following rows. The spilled rows are
+/// deduplicated partition by partition after the input is drained, they are emitted last.
+pub struct DistinctStream {
+    input: SendableDataBlockStream,
+    schema: DataSchemaRef,
+    limit: Option<usize>,
+    max_memory_bytes: usize,
+    method: HashMethodSerializer,
+    keys: HashSet<Vec<u8>>,
+    keys_bytes: usize,
+    emitted_rows: usize,
+    spill_dir: Arc<SpillDir>,
+    spilled: Vec<SpilledPartition>,
+    input_finished: bool,
+}
+
+impl DistinctStream {
+    pub fn try_create(
+        input: SendableDataBlockStream,
+        schema: DataSchemaRef,
+        limit: Option<usize>,
+        max_memory_bytes: usize,
+        spill_dir: Arc<SpillDir>,
+    ) -> Result<Self> {
+        Ok(DistinctStream {
+            input,
+            schema,
+            limit,
+            max_memory_bytes,
+            method: HashMethodSerializer::default(),
+            keys: HashSet::new(),
+            keys_bytes: 0,
+            emitted_rows: 0,
+            spill_dir,
+            spilled: vec![],
+            input_finished: false,
+        })
+    }
+
+    fn remaining(&self) -> usize {
+        match self.limit {
+            None => usize::MAX,
+            Some(limit) => limit.saturating_sub(self.emitted_rows),
+        }
+    }
+
+    fn distinct(&mut self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let rows = block.num_rows();
+        let columns = block.columns().iter().collect::<Vec<_>>();
+        let keys = self.method.build_keys(&columns, rows)?;
+
+        if !self.spilled.is_empty() {
+            for key in keys {
+                self.spilled[partition_of(&key)].push_row(&key)?;
+            }
+            return Ok(None);
+        }
+
+        let mut filter = MutableBitmap::from_len_zeroed(rows);
+        let mut remaining = self.remaining();
+        for (row, key) in keys.into_iter().enumerate() {
+            if remaining == 0 {
+                break;
+            }
+
+            if !self.keys.contains(&key) {
+                self.keys_bytes += key.len();
+                self.keys.insert(key);
+                filter.set(row, true);
+                remaining -= 1;
+            }
+        }
+
+        let array = BooleanArray::from_data(ArrowType::Boolean, filter.into(), None);
+        let batch = block.clone().try_into()?;
+        let batch = arrow::compute::filter::filter_record_batch(&batch, &array)?;
+        let block: DataBlock = batch.try_into()?;
+        self.emitted_rows += block.num_rows();
+
+        if self.max_memory_bytes != 0 && self.keys_bytes > self.max_memory_bytes {
+            self.spill()?;
+        }
+
+        match block.num_rows() {
+            0 => Ok(None),
+            _ => Ok(Some(block)),
+        }
+    }
+
+    fn spill(&mut self) -> Result<()> {
+        let mut partitions = (0..SPILL_PARTITIONS)
+            .map(|_| SpilledPartition::try_create(&self.spill_dir))
+            .collect::<Result<Vec<_>>>()?;
+
+        for key in std::mem::take(&mut self.keys) {
+            partitions[partition_of(&key)].push_emitted(&key)?;
+        }
+
+        self.keys_bytes = 0;
+        self.spilled = partitions;
+        Ok(())
+    }
+
+    fn next_spilled(&mut self) -> Result<Option<DataBlock>> {
+        while let Some(partition) = self.spilled.pop() {
+            let (emitted, rows) = partition.finish()?;
+            let mut keys = emitted.into_iter().collect::<HashSet<_>>();
+
+            let mut remaining = self.remaining();
+            let mut distinct_rows = vec![];
+            for key in rows {
+                if remaining == 0 {
+                    break;
+                }
+
+                if !keys.contains(&key) {
+                    keys.insert(key.clone());
+                    distinct_rows.push(key);
+                    remaining -= 1;
+                }
+            }
+
+            if !distinct_rows.is_empty() {
+                self.emitted_rows += distinct_rows.len();
+                let columns = self
+                    .method
+                    .de_group_columns(distinct_rows, self.schema.fields())?;
+                return Ok(Some(DataBlock::create_by_array(
+                    self.schema.clone(),
+                    columns,
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl Stream for DistinctStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.remaining() == 0 {
+                return Poll::Ready(None);
+            }
+
+            if self.input_finished {
+                return Poll::Ready(self.next_spilled().transpose());
+            }
+
+            match self.input.poll_next_unpin(ctx) {
+                Poll::Ready(Some(Ok(block))) => match self.distinct(&block) {
+                    Ok(None) => continue,
+                    other => return Poll::Ready(other.transpose()),
+                },
+                Poll::Ready(None) => self.input_finished = true,
+                other => return other,
+            }
+        }
+    }
+}
+
+fn partition_of(key: &[u8]) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    (hasher.finish() % SPILL_PARTITIONS as u64) as usize
+}
+
+/// The keys of a spilled partition: the keys emitted before spilling and the keys of the
+/// rows read after, each one prefixed by its length.
+struct SpilledPartition {
+    emitted: BufWriter<SpillFile>,
+    rows: BufWriter<SpillFile>,
+}
+
+impl SpilledPartition {
+    fn try_create(spill_dir: &Arc<SpillDir>) -> Result<Self> {
+        Ok(SpilledPartition {
+            emitted: BufWriter::new(spill_dir.create_file()?),
+            rows: BufWriter::new(spill_dir.create_file()?),
+        })
+    }
+
+    fn push_emitted(&mut self, key: &[u8]) -> Result<()> {
+        write_key(&mut self.emitted, key)
+    }
+
+    fn push_row(&mut self, key: &[u8]) -> Result<()> {
+        write_key(&mut self.rows, key)
+    }
+
+    fn finish(self) -> Result<(Vec<Vec<u8>>, Vec<Vec<u8>>)> {
+        Ok((read_keys(self.emitted)?, read_keys(self.rows)?))
+    }
+}
+
+fn write_key(writer: &mut BufWriter<SpillFile>, key: &[u8]) -> Result<()> {
+    writer.write_all(&(key.len() as u32).to_le_bytes())?;
+    writer.write_all(key)?;
+    Ok(())
+}
+
+fn read_keys(writer: BufWriter<SpillFile>) -> Result<Vec<Vec<u8>>> {
+    let mut file = writer.into_inner().map_err(|e| e.into_error())?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let mut reader = BufReader::new(file);
+    let mut keys = vec![];
+    let mut len = [0u8; 4];
+    loop {
+        match reader.read_exact(&mut len) {
+            Ok(_) => {}
+            Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => break,
+            Err(cause) => return Err(cause.into()),
+        }
+
+        let mut key = vec![0u8; u32::from_le_bytes(len) as usize];
+        reader.read_exact(&mut key)?;
+        keys.push(key);
+    }
+
+    Ok(keys)
+}
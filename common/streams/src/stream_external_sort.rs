@@ -0,0 +1,368 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_arrow::arrow::compute::merge_sort::build_comparator;
+use common_arrow::arrow::compute::sort::SortOptions;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+use crate::SpillDir;
+use crate::SpillFile;
+
+/// Merges the sorted blocks of the input stream into one sorted stream.
+///
+/// The blocks are kept in memory up to `max_memory_bytes` bytes (0 means no limit), beyond
+/// which they are merged into a sorted run spilled into a file of the spill dir of the query,
+/// in blocks of `block_size` rows. Once the input is drained, the runs are merged by reading
+/// one block of each run at a time, so the memory is bounded by the number of the runs.
+pub struct ExternalSortStream {
+    input: SendableDataBlockStream,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    limit: Option<usize>,
+    block_size: usize,
+    max_memory_bytes: usize,
+    spill_dir: Arc<SpillDir>,
+    blocks: Vec<DataBlock>,
+    blocks_rows: usize,
+    blocks_bytes: usize,
+    spilled: Vec<(BufWriter<SpillFile>, DataSchemaRef)>,
+    runs: Vec<SortedRun>,
+    emitted_rows: usize,
+    input_finished: bool,
+}
+
+impl ExternalSortStream {
+    pub fn try_create(
+        input: SendableDataBlockStream,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        limit: Option<usize>,
+        block_size: usize,
+        max_memory_bytes: usize,
+        spill_dir: Arc<SpillDir>,
+    ) -> Result<Self> {
+        Ok(ExternalSortStream {
+            input,
+            sort_columns_descriptions,
+            limit,
+            block_size: block_size.max(1),
+            max_memory_bytes,
+            spill_dir,
+            blocks: vec![],
+            blocks_rows: 0,
+            blocks_bytes: 0,
+            spilled: vec![],
+            runs: vec![],
+            emitted_rows: 0,
+            input_finished: false,
+        })
+    }
+
+    fn remaining(&self) -> usize {
+        match self.limit {
+            None => usize::MAX,
+            Some(limit) => limit.saturating_sub(self.emitted_rows),
+        }
+    }
+
+    fn push(&mut self, block: DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+
+        self.blocks_rows += block.num_rows();
+        self.blocks_bytes += block.memory_size();
+        self.blocks.push(block);
+
+        // With a limit, only the top rows so far are kept, merged once the blocks hold twice
+        // of them.
+        if let Some(limit) = self.limit {
+            if self.blocks.len() > 1 && self.blocks_rows >= limit.saturating_mul(2) {
+                let top = self.merge_blocks()?;
+                self.blocks_rows = top.num_rows();
+                self.blocks_bytes = top.memory_size();
+                self.blocks = vec![top];
+            }
+        }
+
+        if self.max_memory_bytes != 0 && self.blocks_bytes > self.max_memory_bytes {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn merge_blocks(&mut self) -> Result<DataBlock> {
+        let blocks = std::mem::take(&mut self.blocks);
+        self.blocks_rows = 0;
+        self.blocks_bytes = 0;
+        DataBlock::merge_sort_blocks(&blocks, &self.sort_columns_descriptions, self.limit)
+    }
+
+    fn spill(&mut self) -> Result<()> {
+        let sorted = self.merge_blocks()?;
+        let mut writer = BufWriter::new(self.spill_dir.create_file()?);
+        for block in DataBlock::split_block_by_size(&sorted, self.block_size)? {
+            write_block(&mut writer, &block)?;
+        }
+
+        self.spilled.push((writer, sorted.schema().clone()));
+        Ok(())
+    }
+
+    fn finish_input(&mut self) -> Result<()> {
+        if self.spilled.is_empty() {
+            return Ok(());
+        }
+
+        if !self.blocks.is_empty() {
+            self.spill()?;
+        }
+
+        for (writer, schema) in std::mem::take(&mut self.spilled) {
+            let mut file = writer.into_inner().map_err(|e| e.into_error())?;
+            file.seek(SeekFrom::Start(0))?;
+            if let Some(run) = SortedRun::try_read(BufReader::new(file), schema)? {
+                self.runs.push(run);
+            }
+        }
+        Ok(())
+    }
+
+    fn next_sorted(&mut self) -> Result<Option<DataBlock>> {
+        // Nothing spilled, the blocks are merged in memory.
+        if self.runs.is_empty() {
+            if self.blocks.is_empty() {
+                return Ok(None);
+            }
+
+            let block = self.merge_blocks()?;
+            self.emitted_rows += block.num_rows();
+            return Ok(Some(block));
+        }
+
+        let heads = self
+            .runs
+            .iter()
+            .map(|run| run.head.clone())
+            .collect::<Vec<_>>();
+        let bounds = rows_before_bound(&heads, &self.sort_columns_descriptions)?;
+        let prefixes = heads
+            .iter()
+            .zip(bounds.iter())
+            .filter(|(_, rows)| **rows != 0)
+            .map(|(head, rows)| head.slice(0, *rows))
+            .collect::<Vec<_>>();
+
+        let limit = self.limit.map(|_| self.remaining());
+        let block =
+            DataBlock::merge_sort_blocks(&prefixes, &self.sort_columns_descriptions, limit)?;
+        self.emitted_rows += block.num_rows();
+
+        let mut runs = Vec::with_capacity(self.runs.len());
+        for (mut run, rows) in std::mem::take(&mut self.runs).into_iter().zip(bounds) {
+            let head_rows = run.head.num_rows();
+            if rows < head_rows {
+                run.head = run.head.slice(rows, head_rows - rows);
+                runs.push(run);
+            } else if let Some(run) = run.try_next()? {
+                runs.push(run);
+            }
+        }
+
+        self.runs = runs;
+        Ok(Some(block))
+    }
+}
+
+impl Stream for ExternalSortStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.remaining() == 0 {
+                return Poll::Ready(None);
+            }
+
+            if self.input_finished {
+                return Poll::Ready(self.next_sorted().transpose());
+            }
+
+            match self.input.poll_next_unpin(ctx) {
+                Poll::Ready(Some(Ok(block))) => {
+                    if let Err(cause) = self.push(block) {
+                        return Poll::Ready(Some(Err(cause)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.input_finished = true;
+                    if let Err(cause) = self.finish_input() {
+                        return Poll::Ready(Some(Err(cause)));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+/// The number of the rows of each sorted block up to the least of their last rows. These
+/// rows sort before any row of the blocks following them in their runs.
+fn rows_before_bound(
+    blocks: &[DataBlock],
+    sort_columns_descriptions: &[SortColumnDescription],
+) -> Result<Vec<usize>> {
+    let sort_arrays = sort_columns_descriptions
+        .iter()
+        .map(|f| {
+            blocks
+                .iter()
+                .map(|block| Ok(block.try_array_by_name(&f.column_name)?.get_array_ref()))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let sort_dyn_arrays = sort_arrays
+        .iter()
+        .map(|arrays| {
+            arrays
+                .iter()
+                .map(|array| array.as_ref())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let sort_options = sort_columns_descriptions
+        .iter()
+        .map(|f| SortOptions {
+            descending: !f.asc,
+            nulls_first: f.nulls_first,
+        })
+        .collect::<Vec<_>>();
+
+    let sort_options_with_array = sort_dyn_arrays
+        .iter()
+        .zip(sort_options.iter())
+        .map(|(arrays, options)| (arrays.as_slice(), options))
+        .collect::<Vec<_>>();
+
+    let comparator = build_comparator(&sort_options_with_array)?;
+    let last = |block: usize| blocks[block].num_rows() - 1;
+
+    let mut bound = 0;
+    for block in 1..blocks.len() {
+        if comparator(block, last(block), bound, last(bound)) == Ordering::Less {
+            bound = block;
+        }
+    }
+
+    let bounds = (0..blocks.len())
+        .map(|block| {
+            if block == bound {
+                return blocks[block].num_rows();
+            }
+
+            // The first row after the bound, the rows are sorted.
+            let (mut low, mut high) = (0, blocks[block].num_rows());
+            while low < high {
+                let mid = (low + high) / 2;
+                match comparator(block, mid, bound, last(bound)) {
+                    Ordering::Greater => high = mid,
+                    _ => low = mid + 1,
+                }
+            }
+            low
+        })
+        .collect();
+    Ok(bounds)
+}
+
+/// A sorted run spilled into a file, read back one block at a time.
+struct SortedRun {
+    reader: BufReader<SpillFile>,
+    schema: DataSchemaRef,
+    head: DataBlock,
+}
+
+impl SortedRun {
+    fn try_read(mut reader: BufReader<SpillFile>, schema: DataSchemaRef) -> Result<Option<Self>> {
+        match read_block(&mut reader, &schema)? {
+            None => Ok(None),
+            Some(head) => Ok(Some(SortedRun {
+                reader,
+                schema,
+                head,
+            })),
+        }
+    }
+
+    fn try_next(self) -> Result<Option<Self>> {
+        SortedRun::try_read(self.reader, self.schema)
+    }
+}
+
+/// The rows of a block are written as their serialized keys, each one prefixed by its length,
+/// after the number of the rows.
+fn write_block(writer: &mut BufWriter<SpillFile>, block: &DataBlock) -> Result<()> {
+    let columns = block.columns().iter().collect::<Vec<_>>();
+    let keys = HashMethodSerializer::default().build_keys(&columns, block.num_rows())?;
+
+    writer.write_all(&(keys.len() as u32).to_le_bytes())?;
+    for key in keys {
+        writer.write_all(&(key.len() as u32).to_le_bytes())?;
+        writer.write_all(&key)?;
+    }
+    Ok(())
+}
+
+fn read_block(
+    reader: &mut BufReader<SpillFile>,
+    schema: &DataSchemaRef,
+) -> Result<Option<DataBlock>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Ok(_) => {}
+        Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(cause) => return Err(cause.into()),
+    }
+
+    let rows = u32::from_le_bytes(len) as usize;
+    let mut keys = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        reader.read_exact(&mut len)?;
+        let mut key = vec![0u8; u32::from_le_bytes(len) as usize];
+        reader.read_exact(&mut key)?;
+        keys.push(key);
+    }
+
+    let columns = HashMethodSerializer::default().de_group_columns(keys, schema.fields())?;
+    Ok(Some(DataBlock::create_by_array(schema.clone(), columns)))
+}
@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::max;
+use std::cmp::min;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Normalizes the size of the blocks of the input stream: tiny blocks are merged and huge
+/// blocks are split, so that every output block except the last one has the target size.
+///
+/// The target size is `target_rows` rows, lowered to fit `target_bytes` bytes according to
+/// the average row size of the buffered blocks. A `target_bytes` of 0 means no byte limit.
+pub struct RechunkStream {
+    input: SendableDataBlockStream,
+    target_rows: usize,
+    target_bytes: usize,
+    pending: Vec<DataBlock>,
+    pending_rows: usize,
+    pending_bytes: usize,
+    output: VecDeque<DataBlock>,
+    finished: bool,
+}
+
+impl RechunkStream {
+    pub fn new(input: SendableDataBlockStream, target_rows: usize, target_bytes: usize) -> Self {
+        RechunkStream {
+            input,
+            target_rows: max(target_rows, 1),
+            target_bytes,
+            pending: vec![],
+            pending_rows: 0,
+            pending_bytes: 0,
+            output: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    fn pending_target_rows(&self) -> usize {
+        if self.target_bytes == 0 || self.pending_rows == 0 {
+            return self.target_rows;
+        }
+
+        let row_bytes = max(self.pending_bytes / self.pending_rows, 1);
+        min(self.target_rows, max(self.target_bytes / row_bytes, 1))
+    }
+
+    fn push_pending(&mut self, block: DataBlock) -> Result<()> {
+        self.pending_rows += block.num_rows();
+        self.pending_bytes += block.memory_size();
+        self.pending.push(block);
+
+        let target_rows = self.pending_target_rows();
+        if self.pending_rows < target_rows {
+            return Ok(());
+        }
+
+        let mut chunks = DataBlock::rechunk_blocks(&self.pending, target_rows)?;
+        self.pending.clear();
+        self.pending_rows = 0;
+        self.pending_bytes = 0;
+
+        // Keep the trailing partial chunk, it may be completed by the next blocks.
+        if let Some(last) = chunks.pop() {
+            match last.num_rows() < target_rows {
+                true => {
+                    self.pending_rows = last.num_rows();
+                    self.pending_bytes = last.memory_size();
+                    self.pending.push(last);
+                }
+                false => chunks.push(last),
+            }
+        }
+
+        self.output.extend(chunks);
+        Ok(())
+    }
+
+    fn flush_pending(&mut self) -> Result<()> {
+        if !self.pending.is_empty() {
+            let block = DataBlock::concat_blocks(&self.pending)?;
+            self.pending.clear();
+            self.pending_rows = 0;
+            self.pending_bytes = 0;
+            self.output.push_back(block);
+        }
+
+        Ok(())
+    }
+}
+
+impl Stream for RechunkStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(block) = self.output.pop_front() {
+                return Poll::Ready(Some(Ok(block)));
+            }
+
+            if self.finished {
+                return Poll::Ready(None);
+            }
+
+            match self.input.poll_next_unpin(ctx) {
+                Poll::Ready(Some(Ok(block))) if block.num_rows() == 0 => continue,
+                Poll::Ready(Some(Ok(block))) => {
+                    if let Err(cause) = self.push_pending(block) {
+                        return Poll::Ready(Some(Err(cause)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.finished = true;
+                    if let Err(cause) = self.flush_pending() {
+                        return Poll::Ready(Some(Err(cause)));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
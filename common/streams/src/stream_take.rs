@@ -23,15 +23,17 @@ use futures::StreamExt;
 
 use crate::SendableDataBlockStream;
 
+/// Takes the first `n` rows of the input, which is dropped as soon as they are taken, so that
+/// the sources stop reading instead of waiting for one more block.
 pub struct TakeStream {
-    input: SendableDataBlockStream,
+    input: Option<SendableDataBlockStream>,
     remaining: usize,
 }
 
 impl TakeStream {
     pub fn new(input: SendableDataBlockStream, n: usize) -> Self {
         TakeStream {
-            input,
+            input: Some(input),
             remaining: n,
         }
     }
@@ -41,22 +43,32 @@ impl Stream for TakeStream {
     type Item = Result<DataBlock>;
 
     fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.input.poll_next_unpin(ctx).map(|x| match x {
-            Some(Ok(ref block)) => {
-                let rows = block.num_rows();
-                if self.remaining == 0 {
-                    None
-                } else if self.remaining >= rows {
-                    self.remaining -= rows;
-                    Some(block.clone())
-                } else {
-                    let remaining = self.remaining;
-                    self.remaining = 0;
-                    Some(block.slice(0, remaining))
-                }
-            }
-            .map(Ok),
-            other => other,
-        })
+        if self.remaining == 0 {
+            self.input = None;
+            return Poll::Ready(None);
+        }
+
+        let poll = match self.input.as_mut() {
+            None => return Poll::Ready(None),
+            Some(input) => input.poll_next_unpin(ctx),
+        };
+        let block = match poll {
+            Poll::Ready(Some(Ok(block))) => block,
+            other => return other,
+        };
+
+        let rows = block.num_rows();
+        if self.remaining > rows {
+            self.remaining -= rows;
+            return Poll::Ready(Some(Ok(block)));
+        }
+
+        let remaining = self.remaining;
+        self.remaining = 0;
+        self.input = None;
+        match remaining == rows {
+            true => Poll::Ready(Some(Ok(block))),
+            false => Poll::Ready(Some(Ok(block.slice(0, remaining)))),
+        }
     }
 }
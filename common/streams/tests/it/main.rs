@@ -13,8 +13,13 @@
 // limitations under the License.
 
 mod source;
+mod spill;
 mod stream_cast;
 mod stream_datablock;
+mod stream_distinct;
+mod stream_external_sort;
 mod stream_limit_by;
 mod stream_progress;
+mod stream_rechunk;
 mod stream_skip;
+mod stream_take;
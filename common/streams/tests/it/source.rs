@@ -12,19 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::sync::Arc;
 
+use async_compression::futures::bufread::GzipEncoder;
+use avro_rs::types::Record;
+use avro_rs::types::Value;
+use avro_rs::Decimal;
+use avro_rs::Schema;
+use avro_rs::Writer;
 use common_base::tokio;
 use common_dal::DataAccessor;
 use common_dal::Local;
 use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
 use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::BadRows;
 use common_streams::CsvSource;
+use common_streams::MatchByColumnName;
+use common_streams::NdJsonSource;
+use common_streams::OrcSource;
 use common_streams::Source;
+use common_streams::SourceFactory;
+use common_streams::SourceParams;
 use common_streams::ValueSource;
+use futures::AsyncReadExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_parse_values() {
@@ -113,3 +132,365 @@ async fn test_parse_csvs() {
         }
     }
 }
+
+fn test_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+    ])
+}
+
+async fn read_all(source: &mut dyn Source) -> Result<Vec<DataBlock>> {
+    let mut blocks = vec![];
+    while let Some(block) = source.read().await? {
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_tsv() -> Result<()> {
+    // the backslashes are escaped, the quotes are kept
+    let data = "1\ta\\\\b\n2\t\"c\"\n";
+    let mut source = CsvSource::try_create_tsv(data.as_bytes(), test_schema(), false, b'\n', 10)?;
+    assert_blocks_eq(
+        vec![
+            "+---+-----+",
+            "| a | b   |",
+            "+---+-----+",
+            "| 1 | a\\b |",
+            "| 2 | \"c\" |",
+            "+---+-----+",
+        ],
+        &read_all(&mut source).await?,
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_ndjson() -> Result<()> {
+    let data = r#"{"a": 1, "b": "x"}
+
+{"b": "y", "a": 2, "c": true}
+{"a": 3}
+{"a": 4, "b": null}
+"#;
+    let mut source = NdJsonSource::create(data.as_bytes(), test_schema(), 2);
+    let blocks = read_all(&mut source).await?;
+    assert_eq!(blocks.len(), 2);
+    assert_blocks_eq(
+        vec![
+            "+---+------+",
+            "| a | b    |",
+            "+---+------+",
+            "| 1 | x    |",
+            "| 2 | y    |",
+            "| 3 | NULL |",
+            "| 4 | NULL |",
+            "+---+------+",
+        ],
+        &blocks,
+    );
+
+    let mut source = NdJsonSource::create("[1, 2]".as_bytes(), test_schema(), 2);
+    assert!(source.read().await.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_bad_rows() -> Result<()> {
+    // the first and the last rows are malformed
+    let csv = "x,a\n1,b\n2,c\nyy,d\n";
+    let ndjson = "{\"a\": \"x\"}\n{\"a\": 1, \"b\": \"b\"}\nnot json\n{\"a\": 2, \"b\": \"c\"}\n";
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | b |",
+        "| 2 | c |",
+        "+---+---+",
+    ];
+
+    let bad_rows = BadRows::create(2);
+    let mut source = CsvSource::try_create(csv.as_bytes(), test_schema(), false, b',', b'\n', 10)?
+        .with_bad_rows(bad_rows.clone());
+    assert_blocks_eq(expected.clone(), &read_all(&mut source).await?);
+    assert_eq!(bad_rows.count(), 2);
+    assert!(bad_rows.first_error().unwrap().contains("line 0"));
+
+    let bad_rows = BadRows::create(2);
+    let mut source =
+        NdJsonSource::create(ndjson.as_bytes(), test_schema(), 10).with_bad_rows(bad_rows.clone());
+    assert_blocks_eq(expected, &read_all(&mut source).await?);
+    assert_eq!(bad_rows.count(), 2);
+
+    // one malformed row too many
+    let bad_rows = BadRows::create(1);
+    let mut source = CsvSource::try_create(csv.as_bytes(), test_schema(), false, b',', b'\n', 10)?
+        .with_bad_rows(bad_rows.clone());
+    assert!(read_all(&mut source).await.is_err());
+    assert_eq!(bad_rows.count(), 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_match_by_column_name() -> Result<()> {
+    // the columns are re-ordered, with an extra column
+    let data = "c,B,a\ntrue,x,1\nfalse,y,2\n";
+    let mut source = CsvSource::try_create(data.as_bytes(), test_schema(), true, b',', b'\n', 10)?
+        .with_match_by_column_name(MatchByColumnName::CaseInsensitive);
+    assert_blocks_eq(
+        vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "+---+---+",
+        ],
+        &read_all(&mut source).await?,
+    );
+
+    // the missing columns are not in the blocks, they are filled by the consumers
+    let mut source = CsvSource::try_create(data.as_bytes(), test_schema(), true, b',', b'\n', 10)?
+        .with_match_by_column_name(MatchByColumnName::CaseSensitive);
+    let blocks = read_all(&mut source).await?;
+    assert_eq!(blocks[0].schema().fields().len(), 1);
+    assert_blocks_eq(
+        vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"],
+        &blocks,
+    );
+
+    // the header is needed
+    let mut source = CsvSource::try_create(data.as_bytes(), test_schema(), false, b',', b'\n', 10)?
+        .with_match_by_column_name(MatchByColumnName::CaseSensitive);
+    assert!(source.read().await.is_err());
+
+    assert!(MatchByColumnName::try_create("case_sensitive").is_ok());
+    assert!(MatchByColumnName::try_create("yes").is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_column_count_mismatch() -> Result<()> {
+    let data = "1,x\n2\n3,z,extra\n";
+
+    // the missing fields are NULL, the extra fields are ignored
+    let mut source = CsvSource::try_create(data.as_bytes(), test_schema(), false, b',', b'\n', 10)?;
+    assert_blocks_eq(
+        vec![
+            "+---+------+",
+            "| a | b    |",
+            "+---+------+",
+            "| 1 | x    |",
+            "| 2 | NULL |",
+            "| 3 | z    |",
+            "+---+------+",
+        ],
+        &read_all(&mut source).await?,
+    );
+
+    let mut source = CsvSource::try_create(data.as_bytes(), test_schema(), false, b',', b'\n', 10)?
+        .with_error_on_column_count_mismatch(true);
+    let err = source.read().await.unwrap_err();
+    assert!(err.message().contains("Number of fields in the file (1)"));
+
+    let bad_rows = BadRows::create(2);
+    let mut source = CsvSource::try_create(data.as_bytes(), test_schema(), false, b',', b'\n', 10)?
+        .with_error_on_column_count_mismatch(true)
+        .with_bad_rows(bad_rows.clone());
+    assert_blocks_eq(
+        vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "+---+---+",
+        ],
+        &read_all(&mut source).await?,
+    );
+    assert_eq!(bad_rows.count(), 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_factory_compression() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let local = Arc::new(Local::with_path(dir.path().to_path_buf()));
+
+    let mut compressed = vec![];
+    GzipEncoder::new("1,x\n2,y\n".as_bytes())
+        .read_to_end(&mut compressed)
+        .await?;
+    local.put("data.csv.gz", compressed.clone()).await?;
+    local.put("data", compressed).await?;
+
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | x |",
+        "| 2 | y |",
+        "+---+---+",
+    ];
+    for (path, compression) in [("data.csv.gz", None), ("data", Some("gzip"))] {
+        let mut options = HashMap::new();
+        if let Some(compression) = compression {
+            options.insert("compression".to_string(), compression.to_string());
+        }
+        let mut source = SourceFactory::try_get(SourceParams {
+            acc: local.clone(),
+            path,
+            format: "csv",
+            schema: test_schema(),
+            max_block_size: 10,
+            projection: vec![0, 1],
+            options: &options,
+            bad_rows: None,
+        })?;
+        assert_blocks_eq(expected.clone(), &read_all(source.as_mut()).await?);
+    }
+
+    let options = options_of("compression", "lzo");
+    let source = SourceFactory::try_get(SourceParams {
+        acc: local,
+        path: "data",
+        format: "csv",
+        schema: test_schema(),
+        max_block_size: 10,
+        projection: vec![0, 1],
+        options: &options,
+        bad_rows: None,
+    });
+    assert!(source.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_orc_source_not_orc_file() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let local = Arc::new(Local::with_path(dir.path().to_path_buf()));
+    local.put("data.orc", b"1,x\n2,y\n".to_vec()).await?;
+
+    let options = HashMap::new();
+    let mut source = SourceFactory::try_get(SourceParams {
+        acc: local.clone(),
+        path: "data.orc",
+        format: "ORC",
+        schema: test_schema(),
+        max_block_size: 10,
+        projection: vec![0, 1],
+        options: &options,
+        bad_rows: None,
+    })?;
+    let err = source.read().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::OrcError("").code());
+
+    assert!(OrcSource::infer_schema(local, "data.orc").await.is_err());
+    Ok(())
+}
+
+const AVRO_SCHEMA: &str = r#"
+{
+    "type": "record",
+    "name": "t",
+    "fields": [
+        {"name": "a", "type": "long"},
+        {"name": "b", "type": ["null", "string"]},
+        {"name": "price", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+        {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+    ]
+}
+"#;
+
+fn avro_records(schema: &Schema) -> Vec<Record> {
+    let rows = [
+        (1i64, Some("x"), vec![0x30, 0x39], 1640995200123i64),
+        (2i64, None, vec![0xff, 0x9c], 0i64),
+    ];
+    rows.into_iter()
+        .map(|(a, b, price, ts)| {
+            let mut record = Record::new(schema).unwrap();
+            record.put("a", a);
+            record.put("b", b);
+            record.put("price", Value::Decimal(Decimal::from(price)));
+            record.put("ts", Value::TimestampMillis(ts));
+            record
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_avro() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let local = Arc::new(Local::with_path(dir.path().to_path_buf()));
+
+    let avro_schema = Schema::parse_str(AVRO_SCHEMA).unwrap();
+    let mut writer = Writer::new(&avro_schema, Vec::new());
+    for record in avro_records(&avro_schema) {
+        writer.append(record).unwrap();
+    }
+    local.put("data.avro", writer.into_inner().unwrap()).await?;
+
+    // the messages framed by the schema registry
+    let mut messages = vec![];
+    for record in avro_records(&avro_schema) {
+        messages.extend_from_slice(&[0, 0, 0, 0, 1]);
+        messages.extend(avro_rs::to_avro_datum(&avro_schema, record).unwrap());
+    }
+    local.put("data.msg", messages).await?;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("ts", DataType::String, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("price", DataType::Float64, false),
+        DataField::new("a", DataType::Int64, false),
+    ]);
+    let expected = vec![
+        "+-------------------------+------+--------+---+",
+        "| ts                      | b    | price  | a |",
+        "+-------------------------+------+--------+---+",
+        "| 1970-01-01 00:00:00.000 | NULL | -1     | 2 |",
+        "| 2022-01-01 00:00:00.123 | x    | 123.45 | 1 |",
+        "+-------------------------+------+--------+---+",
+    ];
+    for path in ["data.avro", "data.msg"] {
+        let options = options_of("avro_schema", AVRO_SCHEMA);
+        let mut source = SourceFactory::try_get(SourceParams {
+            acc: local.clone(),
+            path,
+            format: "avro",
+            schema: schema.clone(),
+            max_block_size: 10,
+            projection: vec![0, 1, 2, 3],
+            options: &options,
+            bad_rows: None,
+        })?;
+        common_datablocks::assert_blocks_sorted_eq(
+            expected.clone(),
+            &read_all(source.as_mut()).await?,
+        );
+    }
+
+    // the writer schema is required by the framed messages
+    let options = HashMap::new();
+    let mut source = SourceFactory::try_get(SourceParams {
+        acc: local,
+        path: "data.msg",
+        format: "avro",
+        schema,
+        max_block_size: 10,
+        projection: vec![0, 1, 2, 3],
+        options: &options,
+        bad_rows: None,
+    })?;
+    let err = source.read().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AvroError("").code());
+    Ok(())
+}
+
+fn options_of(key: &str, value: &str) -> HashMap<String, String> {
+    let mut options = HashMap::new();
+    options.insert(key.to_string(), value.to_string());
+    options
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+
+use common_exception::Result;
+use common_streams::SpillDir;
+
+#[test]
+fn test_spill_dir() -> Result<()> {
+    let root = std::env::temp_dir().join("databend-test-spill-dir");
+    let dir = SpillDir::create(&root, "query-1", 8);
+    assert!(!dir.path().exists());
+
+    let mut file = dir.create_file()?;
+    assert!(dir.path().exists());
+    file.write_all(b"12345")?;
+    file.seek(SeekFrom::Start(0))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    assert_eq!(content, "12345");
+
+    // The quota is shared by the files of the query.
+    let mut other = dir.create_file()?;
+    assert!(other.write_all(b"6789").is_err());
+    other.write_all(b"678")?;
+    assert_eq!(dir.written_bytes(), 8);
+
+    // Removed once the query and its files are dropped.
+    let path = dir.path().to_path_buf();
+    drop(dir);
+    assert!(path.exists());
+    drop(file);
+    drop(other);
+    assert!(!path.exists());
+    Ok(())
+}
+
+#[test]
+fn test_spill_dir_cleanup() -> Result<()> {
+    let root = std::env::temp_dir().join("databend-test-spill-cleanup");
+    let dir = SpillDir::create(&root, "query-1", 0);
+    let file = dir.create_file()?;
+    // As if the process crashed.
+    std::mem::forget(file);
+    std::mem::forget(dir);
+
+    SpillDir::cleanup(&root)?;
+    assert_eq!(std::fs::read_dir(&root)?.count(), 0);
+    SpillDir::cleanup(root.join("not-exists"))?;
+    Ok(())
+}
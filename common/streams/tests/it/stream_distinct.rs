@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::TryStreamExt;
+
+fn test_spill_dir(query_id: &str) -> Arc<SpillDir> {
+    SpillDir::create(
+        std::env::temp_dir().join("databend-test-spill"),
+        query_id,
+        0,
+    )
+}
+
+fn test_blocks() -> (DataSchemaRef, Vec<DataBlock>) {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt8, false),
+        DataField::new("name", DataType::String, false),
+    ]);
+
+    let ids = vec![2u8, 2, 2, 3, 3];
+    let names = vec!["2-1", "2-1", "2-2", "3-1", "3-1"];
+    let block0 =
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(ids), Series::new(names)]);
+
+    let ids = vec![2u8, 3, 3, 4];
+    let names = vec!["2-2", "3-1", "3-2", "4-1"];
+    let block1 =
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(ids), Series::new(names)]);
+
+    (schema, vec![block0, block1])
+}
+
+#[tokio::test]
+async fn test_distinct_stream() -> Result<()> {
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 2  | 2-1  |",
+        "| 2  | 2-2  |",
+        "| 3  | 3-1  |",
+        "| 3  | 3-2  |",
+        "| 4  | 4-1  |",
+        "+----+------+",
+    ];
+
+    // In memory.
+    {
+        let (schema, blocks) = test_blocks();
+        let input = DataBlockStream::create(schema.clone(), None, blocks);
+        let stream = DistinctStream::try_create(
+            Box::pin(input),
+            schema,
+            None,
+            0,
+            test_spill_dir("distinct-in-memory"),
+        )?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 2);
+        assert_blocks_sorted_eq(expected.clone(), &result);
+    }
+
+    // Spilled after the first block.
+    {
+        let (schema, blocks) = test_blocks();
+        let input = DataBlockStream::create(schema.clone(), None, blocks);
+        let stream = DistinctStream::try_create(
+            Box::pin(input),
+            schema,
+            None,
+            1,
+            test_spill_dir("distinct-spilled"),
+        )?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_blocks_sorted_eq(expected, &result);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distinct_stream_with_limit() -> Result<()> {
+    let (schema, blocks) = test_blocks();
+    let input = DataBlockStream::create(schema.clone(), None, blocks);
+    let stream = DistinctStream::try_create(
+        Box::pin(input),
+        schema,
+        Some(3),
+        0,
+        test_spill_dir("distinct-limit"),
+    )?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    // The first block has 3 distinct rows already, the second one is not read.
+    assert_eq!(result.len(), 1);
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 2  | 2-1  |",
+        "| 2  | 2-2  |",
+        "| 3  | 3-1  |",
+        "+----+------+",
+    ];
+    assert_blocks_sorted_eq(expected, &result);
+
+    Ok(())
+}
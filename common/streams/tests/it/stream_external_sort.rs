@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::TryStreamExt;
+
+fn test_spill_dir(query_id: &str) -> Arc<SpillDir> {
+    SpillDir::create(
+        std::env::temp_dir().join("databend-test-spill"),
+        query_id,
+        0,
+    )
+}
+
+// The blocks are sorted by id, as the input of the sort merge.
+fn test_stream() -> SendableDataBlockStream {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("name", DataType::String, false),
+    ]);
+
+    let blocks = vec![vec![1u64, 4, 7, 8], vec![2, 3, 9], vec![0, 5, 6]]
+        .into_iter()
+        .map(|ids| {
+            let names = ids.iter().map(|id| format!("n-{}", id)).collect::<Vec<_>>();
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+            DataBlock::create_by_array(schema.clone(), vec![Series::new(ids), Series::new(names)])
+        })
+        .collect::<Vec<_>>();
+
+    Box::pin(DataBlockStream::create(schema, None, blocks))
+}
+
+fn sort_by_id() -> Vec<SortColumnDescription> {
+    vec![SortColumnDescription {
+        column_name: "id".to_string(),
+        asc: true,
+        nulls_first: false,
+    }]
+}
+
+#[tokio::test]
+async fn test_external_sort_stream() -> Result<()> {
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 0  | n-0  |",
+        "| 1  | n-1  |",
+        "| 2  | n-2  |",
+        "| 3  | n-3  |",
+        "| 4  | n-4  |",
+        "| 5  | n-5  |",
+        "| 6  | n-6  |",
+        "| 7  | n-7  |",
+        "| 8  | n-8  |",
+        "| 9  | n-9  |",
+        "+----+------+",
+    ];
+
+    // In memory.
+    {
+        let stream = ExternalSortStream::try_create(
+            test_stream(),
+            sort_by_id(),
+            None,
+            2,
+            0,
+            test_spill_dir("external-sort-in-memory"),
+        )?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 1);
+        assert_blocks_eq(expected.clone(), &result);
+    }
+
+    // Every block is spilled as a run of blocks of 2 rows.
+    {
+        let spill_dir = test_spill_dir("external-sort-spilled");
+        let stream = ExternalSortStream::try_create(
+            test_stream(),
+            sort_by_id(),
+            None,
+            2,
+            1,
+            spill_dir.clone(),
+        )?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert!(spill_dir.written_bytes() > 0);
+        assert_blocks_eq(expected, &result);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_external_sort_stream_with_limit() -> Result<()> {
+    let stream = ExternalSortStream::try_create(
+        test_stream(),
+        sort_by_id(),
+        Some(4),
+        2,
+        1,
+        test_spill_dir("external-sort-limit"),
+    )?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 0  | n-0  |",
+        "| 1  | n-1  |",
+        "| 2  | n-2  |",
+        "| 3  | n-3  |",
+        "+----+------+",
+    ];
+    assert_blocks_eq(expected, &result);
+
+    Ok(())
+}
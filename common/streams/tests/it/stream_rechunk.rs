@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::StreamExt;
+
+fn create_block(schema: &DataSchemaRef, start: i32, end: i32) -> DataBlock {
+    let ids = (start..end).collect::<Vec<i32>>();
+    DataBlock::create_by_array(schema.clone(), vec![Series::new(ids)])
+}
+
+#[tokio::test]
+async fn test_rechunk_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+
+    // Tiny, empty and huge blocks.
+    let blocks = vec![
+        create_block(&schema, 0, 3),
+        create_block(&schema, 3, 3),
+        create_block(&schema, 3, 5),
+        create_block(&schema, 5, 27),
+        create_block(&schema, 27, 30),
+    ];
+    let stream = DataBlockStream::create(schema, None, blocks);
+    let rechunk_stream = RechunkStream::new(Box::pin(stream), 8, 0);
+
+    let result = rechunk_stream
+        .map(|block| block.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    let rows = result.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![8, 8, 8, 6]);
+
+    let ids = result
+        .iter()
+        .flat_map(|block| (0..block.num_rows()).map(|i| block.column(0).try_get(i).unwrap()))
+        .collect::<Vec<_>>();
+    let expected = (0..30)
+        .map(|v| DataValue::Int32(Some(v)))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rechunk_stream_with_target_bytes() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+
+    let blocks = vec![create_block(&schema, 0, 100)];
+    let stream = DataBlockStream::create(schema, None, blocks);
+
+    // 4 bytes per row, so 40 bytes fit 10 rows.
+    let rechunk_stream = RechunkStream::new(Box::pin(stream), 1000, 40);
+    let result = rechunk_stream
+        .map(|block| block.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    assert!(result.len() > 1);
+    assert!(result.iter().all(|block| block.num_rows() <= 10));
+    assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 100);
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::StreamExt;
+
+#[tokio::test]
+async fn test_takestream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let block0 = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![0i32, 1, 2])]);
+    let block1 = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![3i32, 4, 5])]);
+    let stream = DataBlockStream::create(schema, None, vec![block0, block1]);
+
+    let mut take_stream = TakeStream::new(Box::pin(stream), 4);
+    let mut blocks = vec![];
+    while let Some(block) = take_stream.next().await {
+        blocks.push(block?);
+    }
+
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 0  |", //
+        "| 1  |", //
+        "| 2  |", //
+        "| 3  |", //
+        "+----+", //
+    ];
+    assert_blocks_eq(expected, &blocks);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_takestream_stops_once_taken() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let block = DataBlock::create_by_array(schema, vec![Series::new(vec![0i32, 1])]);
+    // The input never ends, e.g. the sources still reading.
+    let input = futures::stream::iter(vec![Ok(block)]).chain(futures::stream::pending());
+
+    let mut take_stream = TakeStream::new(Box::pin(input), 1);
+    assert_eq!(take_stream.next().await.unwrap()?.num_rows(), 1);
+    assert!(take_stream.next().await.is_none());
+
+    // Nothing is polled for LIMIT 0.
+    let mut take_stream =
+        TakeStream::new(Box::pin(futures::stream::pending::<Result<DataBlock>>()), 0);
+    assert!(take_stream.next().await.is_none());
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::Write;
+
+use chrono::SecondsFormat;
+use chrono::Utc;
+use serde_json::Map;
+use serde_json::Value;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::Event;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// A layer writing every event as one JSON object per line:
+///
+/// `{"timestamp":"2021-12-30T08:00:00.000000Z","level":"INFO","target":"databend_query","spans":["query"],"message":"...","key":"value"}`
+///
+/// The fields of the event are at the top level, the spans are the names of the spans the
+/// event is in, from the root.
+pub struct JsonFormattingLayer<W> {
+    make_writer: W,
+}
+
+impl<W> JsonFormattingLayer<W> {
+    pub fn new(make_writer: W) -> Self {
+        JsonFormattingLayer { make_writer }
+    }
+}
+
+impl<S, W> Layer<S> for JsonFormattingLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'a> MakeWriter<'a> + 'static,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+
+        let mut record = Map::new();
+        record.insert(
+            "timestamp".to_string(),
+            Value::from(Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true)),
+        );
+        record.insert(
+            "level".to_string(),
+            Value::from(metadata.level().to_string()),
+        );
+        record.insert("target".to_string(), Value::from(metadata.target()));
+        if let Some(scope) = ctx.event_scope(event) {
+            let spans = scope
+                .from_root()
+                .map(|span| Value::from(span.name()))
+                .collect::<Vec<_>>();
+            record.insert("spans".to_string(), Value::from(spans));
+        }
+
+        let mut visitor = JsonVisitor(&mut record);
+        event.record(&mut visitor);
+
+        if let Ok(mut line) = serde_json::to_vec(&record) {
+            line.push(b'\n');
+            let _ = self.make_writer.make_writer().write_all(&line);
+        }
+    }
+}
+
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl<'a> Visit for JsonVisitor<'a> {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            Value::from(format!("{:?}", value)),
+        );
+    }
+}
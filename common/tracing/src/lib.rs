@@ -12,13 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod json_formatter;
+mod log_retention;
 mod logging;
 mod panic_hook;
+mod redaction;
+mod size_rolling;
 mod tracing_to_jaeger;
 
+pub use json_formatter::JsonFormattingLayer;
+pub use log_retention::LogRetention;
+pub use log_retention::TimeRollingFileAppender;
+pub use logging::get_log_level;
 pub use logging::init_default_ut_tracing;
 pub use logging::init_global_tracing;
+pub use logging::set_log_level;
+pub use logging::LogFormat;
+pub use logging::LogOptions;
+pub use logging::LogRotation;
+pub use logging::TracingExporter;
+pub use logging::DATABEND_JAEGER_AGENT;
 pub use panic_hook::set_panic_hook;
+pub use redaction::redact;
+pub use redaction::set_redact_literals;
+pub use redaction::set_redact_pattern;
+pub use redaction::RedactMakeWriter;
+pub use size_rolling::SizeRollingFileAppender;
 pub use tracing;
 pub use tracing_futures;
 pub use tracing_to_jaeger::extract_remote_span_as_parent;
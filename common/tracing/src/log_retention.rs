@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use tracing_appender::rolling::RollingFileAppender;
+use tracing_appender::rolling::Rotation;
+
+const COMPRESSED_SUFFIX: &str = ".gz";
+
+/// Which of the rotated log files are kept, and whether they are compressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LogRetention {
+    /// Max number of the rotated files, 0 for unlimited.
+    pub max_files: usize,
+    /// Max total bytes of the rotated files, 0 for unlimited.
+    pub max_total_size: u64,
+    /// Gzip the rotated files into `{file}.gz`.
+    pub compress: bool,
+}
+
+impl LogRetention {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_files == 0 && self.max_total_size == 0 && !self.compress
+    }
+
+    /// Applies the retention to the rotated files `{dir}/{prefix}.*`, `active` is the name of the
+    /// file written to, which is left alone.
+    ///
+    /// The names of the rotated files end with the time of the rotation, so the oldest files,
+    /// first by name, are removed first.
+    pub fn apply(&self, dir: impl AsRef<Path>, prefix: &str, active: &str) -> io::Result<()> {
+        if self.is_unlimited() {
+            return Ok(());
+        }
+
+        let rotated_prefix = format!("{}.", prefix);
+        let mut rotated = vec![];
+        for entry in fs::read_dir(dir.as_ref())? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name != active && name.starts_with(&rotated_prefix) && entry.file_type()?.is_file() {
+                rotated.push(entry.path());
+            }
+        }
+
+        if self.compress {
+            for path in rotated.iter_mut() {
+                if !Self::is_compressed(path) {
+                    *path = Self::compress(path)?;
+                }
+            }
+        }
+
+        rotated.sort_by_key(|path| {
+            let name = path.to_string_lossy().to_string();
+            name.trim_end_matches(COMPRESSED_SUFFIX).to_string()
+        });
+        let mut files = 0;
+        let mut total_size = 0;
+        for path in rotated.iter().rev() {
+            files += 1;
+            total_size += fs::metadata(path)?.len();
+            if (self.max_files > 0 && files > self.max_files)
+                || (self.max_total_size > 0 && total_size > self.max_total_size)
+            {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_compressed(path: &Path) -> bool {
+        path.to_string_lossy().ends_with(COMPRESSED_SUFFIX)
+    }
+
+    /// Replaces the file by `{file}.gz`, a partial `.gz` of an interrupted compression is
+    /// overwritten.
+    fn compress(path: &Path) -> io::Result<PathBuf> {
+        let mut compressed = path.as_os_str().to_owned();
+        compressed.push(COMPRESSED_SUFFIX);
+        let compressed = PathBuf::from(compressed);
+
+        let mut encoder = GzEncoder::new(File::create(&compressed)?, Compression::default());
+        io::copy(&mut File::open(path)?, &mut encoder)?;
+        encoder.finish()?.sync_all()?;
+        fs::remove_file(path)?;
+        Ok(compressed)
+    }
+
+    /// Applies the retention from the writer of the logs, whose errors can not be logged.
+    pub(crate) fn apply_or_report(&self, dir: &Path, prefix: &str, active: &str) {
+        if let Err(cause) = self.apply(dir, prefix, active) {
+            eprintln!(
+                "Failed to apply the retention to the log files {:?}: {}",
+                dir.join(prefix),
+                cause
+            );
+        }
+    }
+}
+
+/// A [`RollingFileAppender`] by the hour or the day, which applies the [`LogRetention`] to the
+/// rotated files whenever a new file is started.
+///
+/// The files are named `{dir}/{prefix}.{yyyy-MM-dd-HH}` or `{dir}/{prefix}.{yyyy-MM-dd}` in UTC
+/// as the ones of the [`RollingFileAppender`].
+pub struct TimeRollingFileAppender {
+    inner: RollingFileAppender,
+    dir: PathBuf,
+    prefix: String,
+    date_format: &'static str,
+    period: String,
+    retention: LogRetention,
+}
+
+impl TimeRollingFileAppender {
+    /// `hourly` is false for the daily rotation.
+    pub fn new(
+        dir: impl AsRef<Path>,
+        prefix: &str,
+        hourly: bool,
+        retention: LogRetention,
+    ) -> TimeRollingFileAppender {
+        let (rotation, date_format) = match hourly {
+            true => (Rotation::HOURLY, "%Y-%m-%d-%H"),
+            false => (Rotation::DAILY, "%Y-%m-%d"),
+        };
+        TimeRollingFileAppender {
+            inner: RollingFileAppender::new(rotation, dir.as_ref(), prefix),
+            dir: dir.as_ref().to_path_buf(),
+            prefix: prefix.to_string(),
+            date_format,
+            // Empty, so that the files left by the last run are retained by the first write.
+            period: "".to_string(),
+            retention,
+        }
+    }
+}
+
+impl Write for TimeRollingFileAppender {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.retention.is_unlimited() {
+            let period = Utc::now().format(self.date_format).to_string();
+            if period != self.period {
+                let active = format!("{}.{}", self.prefix, period);
+                self.retention
+                    .apply_or_report(&self.dir, &self.prefix, &active);
+                self.period = period;
+            }
+        }
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::env;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::Once;
@@ -20,15 +22,166 @@ use std::sync::Once;
 use once_cell::sync::Lazy;
 use opentelemetry::global;
 use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace;
+use opentelemetry::sdk::Resource;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use tracing::Subscriber;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_appender::rolling::RollingFileAppender;
 use tracing_appender::rolling::Rotation;
 use tracing_bunyan_formatter::BunyanFormattingLayer;
 use tracing_bunyan_formatter::JsonStorageLayer;
-use tracing_subscriber::fmt::Layer;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::registry::Registry;
+use tracing_subscriber::reload;
 use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
+
+use crate::JsonFormattingLayer;
+use crate::LogRetention;
+use crate::RedactMakeWriter;
+use crate::SizeRollingFileAppender;
+use crate::TimeRollingFileAppender;
+
+/// The format of the logs written to stdout or to the log files.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogFormat {
+    /// Human readable lines.
+    Text,
+    /// One JSON object per line, see [`JsonFormattingLayer`].
+    Json,
+    /// The JSON lines of the bunyan format.
+    Bunyan,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            "bunyan" => Ok(LogFormat::Bunyan),
+            _ => Err(format!(
+                "Unknown log format '{}', must be one of text, json or bunyan",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogFormat::Text => write!(f, "text"),
+            LogFormat::Json => write!(f, "json"),
+            LogFormat::Bunyan => write!(f, "bunyan"),
+        }
+    }
+}
+
+/// When a new log file is started.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogRotation {
+    Hourly,
+    Daily,
+    /// Once the log file reaches the size in bytes, see [`SizeRollingFileAppender`].
+    Size(u64),
+    Never,
+}
+
+impl LogRotation {
+    /// Parses the rotation `hourly`, `daily`, `size` or `never`, `size_mb` is the size of a log
+    /// file with the `size` rotation.
+    pub fn create(rotation: &str, size_mb: u64) -> Result<Self, String> {
+        match rotation.to_lowercase().as_str() {
+            "hourly" => Ok(LogRotation::Hourly),
+            "daily" => Ok(LogRotation::Daily),
+            "never" => Ok(LogRotation::Never),
+            "size" if size_mb > 0 => Ok(LogRotation::Size(size_mb * 1024 * 1024)),
+            "size" => Err("The size of a log file must be greater than 0".to_string()),
+            _ => Err(format!(
+                "Unknown log rotation '{}', must be one of hourly, daily, size or never",
+                rotation
+            )),
+        }
+    }
+}
+
+/// The env of the address of the Jaeger agent the spans are sent to, e.g. `127.0.0.1:6831`.
+pub const DATABEND_JAEGER_AGENT: &str = "DATABEND_JAEGER_AGENT";
+
+const DEFAULT_JAEGER_AGENT: &str = "127.0.0.1:6831";
+const DEFAULT_OTLP_ENDPOINT: &str = "http://127.0.0.1:4317";
+
+/// Where the spans are exported to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TracingExporter {
+    /// The spans are not exported.
+    Off,
+    /// To the Jaeger agent at the address, over UDP.
+    Jaeger(String),
+    /// To the OpenTelemetry collector at the endpoint, by OTLP over gRPC.
+    Otlp(String),
+}
+
+impl TracingExporter {
+    /// Parses the exporter `off`, `jaeger` or `otlp`. Without an `endpoint` the spans go to the
+    /// address of `DATABEND_JAEGER_AGENT` or `127.0.0.1:6831` for Jaeger, and to
+    /// `http://127.0.0.1:4317` for OTLP.
+    pub fn create(exporter: &str, endpoint: &str) -> Result<Self, String> {
+        match exporter.to_lowercase().as_str() {
+            "off" | "" => Ok(TracingExporter::Off),
+            "jaeger" if endpoint.is_empty() => Ok(TracingExporter::Jaeger(
+                env::var(DATABEND_JAEGER_AGENT)
+                    .unwrap_or_else(|_| DEFAULT_JAEGER_AGENT.to_string()),
+            )),
+            "jaeger" => Ok(TracingExporter::Jaeger(endpoint.to_string())),
+            "otlp" if endpoint.is_empty() => {
+                Ok(TracingExporter::Otlp(DEFAULT_OTLP_ENDPOINT.to_string()))
+            }
+            "otlp" => Ok(TracingExporter::Otlp(endpoint.to_string())),
+            _ => Err(format!(
+                "Unknown tracing exporter '{}', must be one of off, jaeger or otlp",
+                exporter
+            )),
+        }
+    }
+
+    /// Jaeger if `DATABEND_JAEGER_AGENT` is set, off otherwise.
+    pub fn from_env() -> Self {
+        match env::var(DATABEND_JAEGER_AGENT) {
+            Ok(agent) if !agent.is_empty() => TracingExporter::Jaeger(agent),
+            _ => TracingExporter::Off,
+        }
+    }
+}
+
+/// How the logs are written and the spans are exported, see [`init_global_tracing`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogOptions {
+    pub stdout_format: LogFormat,
+    pub file_format: LogFormat,
+    pub rotation: LogRotation,
+    /// The retention of the rotated log files.
+    pub retention: LogRetention,
+    pub tracing_exporter: TracingExporter,
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        LogOptions {
+            stdout_format: LogFormat::Text,
+            file_format: LogFormat::Bunyan,
+            rotation: LogRotation::Hourly,
+            retention: LogRetention::default(),
+            tracing_exporter: TracingExporter::from_env(),
+        }
+    }
+}
 
 /// Init tracing for unittest.
 /// Write logs to file `unittest`.
@@ -37,59 +190,172 @@ pub fn init_default_ut_tracing() {
 
     START.call_once(|| {
         let mut g = GLOBAL_UT_LOG_GUARD.as_ref().lock().unwrap();
-        *g = Some(init_global_tracing("unittest", "_logs_unittest", "DEBUG"));
+        *g = Some(init_global_tracing(
+            "unittest",
+            "_logs_unittest",
+            "DEBUG",
+            &LogOptions::default(),
+        ));
     });
 }
 
 static GLOBAL_UT_LOG_GUARD: Lazy<Arc<Mutex<Option<Vec<WorkerGuard>>>>> =
     Lazy::new(|| Arc::new(Mutex::new(None)));
 
+/// The handle reloading the filter of the logs installed by [`init_global_tracing`].
+static LOG_FILTER_HANDLE: Lazy<Mutex<Option<reload::Handle<EnvFilter, Registry>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Changes the level of the logs at runtime, e.g. `DEBUG` or `info,databend_query=debug` as
+/// the directives of `RUST_LOG`.
+pub fn set_log_level(level: &str) -> Result<(), String> {
+    let filter =
+        EnvFilter::try_new(level).map_err(|e| format!("Invalid log level '{}': {}", level, e))?;
+    match LOG_FILTER_HANDLE.lock().unwrap().as_ref() {
+        None => Err("The logging is not initialized".to_string()),
+        Some(handle) => handle
+            .reload(filter)
+            .map_err(|e| format!("Failed to set the log level: {}", e)),
+    }
+}
+
+/// The current level of the logs, None if the logging is not initialized.
+pub fn get_log_level() -> Option<String> {
+    LOG_FILTER_HANDLE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|handle| handle.with_current(|filter| filter.to_string()).ok())
+}
+
 /// Init logging and tracing.
 ///
+/// The spans are only exported if `options.tracing_exporter` is not off.
 /// A local tracing collection(maybe for testing) can be done with a local jaeger server.
 /// To report tracing data and view it:
 ///   docker run -d -p6831:6831/udp -p6832:6832/udp -p16686:16686 jaegertracing/all-in-one:latest
-///   RUST_LOG=trace cargo test
+///   RUST_LOG=trace DATABEND_JAEGER_AGENT=127.0.0.1:6831 cargo test
 ///   open http://localhost:16686/
 ///
 /// To adjust batch sending delay, use `OTEL_BSP_SCHEDULE_DELAY`:
 /// RUST_LOG=trace OTEL_BSP_SCHEDULE_DELAY=1 cargo test
-///
-// TODO(xp): use DATABEND_JAEGER to assign jaeger server address.
-pub fn init_global_tracing(app_name: &str, dir: &str, level: &str) -> Vec<WorkerGuard> {
+pub fn init_global_tracing(
+    app_name: &str,
+    dir: &str,
+    level: &str,
+    options: &LogOptions,
+) -> Vec<WorkerGuard> {
     let mut guards = vec![];
 
     // Stdout layer.
     let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
-    let stdout_logging_layer = Layer::new().with_writer(stdout_writer);
+    let stdout_logging_layer = format_layer(
+        options.stdout_format,
+        app_name,
+        RedactMakeWriter::new(stdout_writer),
+        true,
+    );
     guards.push(stdout_guard);
 
-    // JSON log layer.
-    let rolling_appender = RollingFileAppender::new(Rotation::HOURLY, dir, app_name);
-    let (rolling_writer, rolling_writer_guard) = tracing_appender::non_blocking(rolling_appender);
-    let file_logging_layer = BunyanFormattingLayer::new(app_name.to_string(), rolling_writer);
+    // File log layer.
+    // The retention runs on the worker thread of the non-blocking writer, e.g. the compression
+    // of a rotated file does not block the logging.
+    let (rolling_writer, rolling_writer_guard) = match options.rotation {
+        LogRotation::Size(max_size) => tracing_appender::non_blocking(
+            SizeRollingFileAppender::new(dir, app_name, max_size)
+                .expect("create log file")
+                .with_retention(options.retention),
+        ),
+        LogRotation::Hourly => tracing_appender::non_blocking(TimeRollingFileAppender::new(
+            dir,
+            app_name,
+            true,
+            options.retention,
+        )),
+        LogRotation::Daily => tracing_appender::non_blocking(TimeRollingFileAppender::new(
+            dir,
+            app_name,
+            false,
+            options.retention,
+        )),
+        LogRotation::Never => {
+            tracing_appender::non_blocking(RollingFileAppender::new(Rotation::NEVER, dir, app_name))
+        }
+    };
+    let file_logging_layer = format_layer(
+        options.file_format,
+        app_name,
+        RedactMakeWriter::new(rolling_writer),
+        false,
+    );
     guards.push(rolling_writer_guard);
 
-    // Jaeger layer.
+    // Tracing layer.
     global::set_text_map_propagator(TraceContextPropagator::new());
-    let tracer = opentelemetry_jaeger::new_pipeline()
-        .with_service_name(app_name)
-        .install_batch(opentelemetry::runtime::Tokio)
-        .expect("install");
-    let jaeger_layer = Some(tracing_opentelemetry::layer().with_tracer(tracer));
+    let tracing_layer = match &options.tracing_exporter {
+        TracingExporter::Off => None,
+        TracingExporter::Jaeger(agent) => {
+            let tracer = opentelemetry_jaeger::new_pipeline()
+                .with_agent_endpoint(agent.as_str())
+                .with_service_name(app_name)
+                .install_batch(opentelemetry::runtime::Tokio)
+                .expect("install jaeger pipeline");
+            Some(tracing_opentelemetry::layer().with_tracer(tracer))
+        }
+        TracingExporter::Otlp(endpoint) => {
+            let tracer = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(endpoint.as_str()),
+                )
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", app_name.to_string()),
+                ])))
+                .install_batch(opentelemetry::runtime::Tokio)
+                .expect("install otlp pipeline");
+            Some(tracing_opentelemetry::layer().with_tracer(tracer))
+        }
+    };
 
     // Use env RUST_LOG to initialize log if present.
     // Otherwise use the specified level.
     let directives = env::var(EnvFilter::DEFAULT_ENV).unwrap_or_else(|_x| level.to_string());
-    let env_filter = EnvFilter::new(directives);
+    let (env_filter, handle) = reload::Layer::new(EnvFilter::new(directives));
+    *LOG_FILTER_HANDLE.lock().unwrap() = Some(handle);
     let subscriber = Registry::default()
         .with(env_filter)
         .with(JsonStorageLayer)
         .with(stdout_logging_layer)
         .with(file_logging_layer)
-        .with(jaeger_layer);
+        .with(tracing_layer);
     tracing::subscriber::set_global_default(subscriber)
         .expect("error setting global tracing subscriber");
 
     guards
 }
+
+fn format_layer<S, W>(
+    format: LogFormat,
+    app_name: &str,
+    make_writer: W,
+    ansi: bool,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    match format {
+        LogFormat::Text => Box::new(
+            tracing_subscriber::fmt::Layer::new()
+                .with_ansi(ansi)
+                .with_writer(make_writer),
+        ),
+        LogFormat::Json => Box::new(JsonFormattingLayer::new(make_writer)),
+        LogFormat::Bunyan => Box::new(BunyanFormattingLayer::new(
+            app_name.to_string(),
+            make_writer,
+        )),
+    }
+}
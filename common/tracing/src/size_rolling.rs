@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::Utc;
+
+use crate::LogRetention;
+
+/// A file appender rolling the log file by its size.
+///
+/// The logs are written to `{dir}/{prefix}`, once it would grow beyond `max_size` bytes it is
+/// renamed to `{dir}/{prefix}.{yyyy-MM-dd-HH-mm-ss.ffffff}` and a new file is started, the
+/// [`LogRetention`] is applied to the renamed files.
+pub struct SizeRollingFileAppender {
+    dir: PathBuf,
+    prefix: String,
+    path: PathBuf,
+    max_size: u64,
+    file: File,
+    size: u64,
+    retention: LogRetention,
+}
+
+impl SizeRollingFileAppender {
+    pub fn new(dir: impl AsRef<Path>, prefix: &str, max_size: u64) -> io::Result<Self> {
+        fs::create_dir_all(dir.as_ref())?;
+        let path = dir.as_ref().join(prefix);
+        let file = Self::open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(SizeRollingFileAppender {
+            dir: dir.as_ref().to_path_buf(),
+            prefix: prefix.to_string(),
+            path,
+            max_size,
+            file,
+            size,
+            retention: LogRetention::default(),
+        })
+    }
+
+    /// Applies the retention to the files left by the last run, and to the rolled files.
+    pub fn with_retention(self, retention: LogRetention) -> Self {
+        retention.apply_or_report(&self.dir, &self.prefix, &self.prefix);
+        SizeRollingFileAppender { retention, ..self }
+    }
+
+    fn open(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn roll(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let suffix = Utc::now().format("%Y-%m-%d-%H-%M-%S%.6f");
+        let mut rolled = self.path.clone().into_os_string();
+        rolled.push(format!(".{}", suffix));
+        fs::rename(&self.path, rolled)?;
+
+        self.file = Self::open(&self.path)?;
+        self.size = 0;
+        self.retention
+            .apply_or_report(&self.dir, &self.prefix, &self.prefix);
+        Ok(())
+    }
+}
+
+impl Write for SizeRollingFileAppender {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // A record larger than max_size still goes to a file of its own.
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            self.roll()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io::Write;
+use std::str::FromStr;
+
+use common_tracing::LogFormat;
+use common_tracing::LogRetention;
+use common_tracing::LogRotation;
+use common_tracing::SizeRollingFileAppender;
+use common_tracing::TracingExporter;
+
+#[test]
+fn test_log_options() {
+    assert_eq!(LogFormat::from_str("text"), Ok(LogFormat::Text));
+    assert_eq!(LogFormat::from_str("JSON"), Ok(LogFormat::Json));
+    assert_eq!(LogFormat::from_str("bunyan"), Ok(LogFormat::Bunyan));
+    assert!(LogFormat::from_str("xml").is_err());
+
+    assert_eq!(LogRotation::create("hourly", 0), Ok(LogRotation::Hourly));
+    assert_eq!(LogRotation::create("Daily", 0), Ok(LogRotation::Daily));
+    assert_eq!(LogRotation::create("never", 0), Ok(LogRotation::Never));
+    assert_eq!(
+        LogRotation::create("size", 2),
+        Ok(LogRotation::Size(2 * 1024 * 1024))
+    );
+    assert!(LogRotation::create("size", 0).is_err());
+    assert!(LogRotation::create("weekly", 0).is_err());
+
+    assert_eq!(TracingExporter::create("off", ""), Ok(TracingExporter::Off));
+    assert_eq!(
+        TracingExporter::create("Jaeger", "10.0.0.1:6831"),
+        Ok(TracingExporter::Jaeger("10.0.0.1:6831".to_string()))
+    );
+    assert_eq!(
+        TracingExporter::create("otlp", ""),
+        Ok(TracingExporter::Otlp("http://127.0.0.1:4317".to_string()))
+    );
+    assert_eq!(
+        TracingExporter::create("otlp", "http://collector:4317"),
+        Ok(TracingExporter::Otlp("http://collector:4317".to_string()))
+    );
+    assert!(TracingExporter::create("zipkin", "").is_err());
+}
+
+#[test]
+fn test_size_rolling_file_appender() {
+    let dir = std::env::temp_dir().join(format!("databend_size_rolling_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut appender = SizeRollingFileAppender::new(&dir, "test", 10).unwrap();
+    appender.write_all(b"123456\n").unwrap();
+    appender.write_all(b"abc\n").unwrap();
+    appender.write_all(b"0123456789abc\n").unwrap();
+    appender.flush().unwrap();
+
+    // every line goes to a new file, as the first two lines do not fit in 10 bytes
+    let mut contents = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect::<Vec<_>>();
+    contents.sort();
+    assert_eq!(contents, vec!["0123456789abc\n", "123456\n", "abc\n"]);
+    assert_eq!(
+        fs::read_to_string(dir.join("test")).unwrap(),
+        "0123456789abc\n"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_log_retention() {
+    let dir = std::env::temp_dir().join(format!("databend_log_retention_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for name in [
+        "test.2021-12-01-01",
+        "test.2021-12-01-02",
+        "test.2021-12-01-03",
+        "test.2021-12-01-04",
+        "test.2021-12-01-05",
+        "other.2021-12-01-01",
+    ] {
+        fs::write(dir.join(name), "0123456789").unwrap();
+    }
+    let names = || {
+        let mut names = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    };
+
+    // the active file is neither counted nor removed
+    let retention = LogRetention {
+        max_files: 3,
+        ..Default::default()
+    };
+    retention.apply(&dir, "test", "test.2021-12-01-05").unwrap();
+    assert_eq!(names(), vec![
+        "other.2021-12-01-01",
+        "test.2021-12-01-02",
+        "test.2021-12-01-03",
+        "test.2021-12-01-04",
+        "test.2021-12-01-05",
+    ]);
+
+    let retention = LogRetention {
+        max_total_size: 25,
+        ..Default::default()
+    };
+    retention.apply(&dir, "test", "test.2021-12-01-05").unwrap();
+    assert_eq!(names(), vec![
+        "other.2021-12-01-01",
+        "test.2021-12-01-03",
+        "test.2021-12-01-04",
+        "test.2021-12-01-05",
+    ]);
+
+    let retention = LogRetention {
+        max_files: 1,
+        compress: true,
+        ..Default::default()
+    };
+    retention.apply(&dir, "test", "test.2021-12-01-05").unwrap();
+    assert_eq!(names(), vec![
+        "other.2021-12-01-01",
+        "test.2021-12-01-04.gz",
+        "test.2021-12-01-05",
+    ]);
+    let compressed = fs::read(dir.join("test.2021-12-01-04.gz")).unwrap();
+    assert_eq!(&compressed[..2], &[0x1f, 0x8b]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_size_rolling_file_appender_retention() {
+    let dir = std::env::temp_dir().join(format!(
+        "databend_size_rolling_retention_{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+
+    let retention = LogRetention {
+        max_files: 1,
+        ..Default::default()
+    };
+    let mut appender = SizeRollingFileAppender::new(&dir, "test", 10)
+        .unwrap()
+        .with_retention(retention);
+    appender.write_all(b"123456\n").unwrap();
+    appender.write_all(b"abc\n").unwrap();
+    appender.write_all(b"0123456789abc\n").unwrap();
+    appender.flush().unwrap();
+
+    // the first line is removed with the second rotation
+    let mut contents = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect::<Vec<_>>();
+    contents.sort();
+    assert_eq!(contents, vec!["0123456789abc\n", "abc\n"]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -19,6 +19,7 @@ use common_grpc::GrpcClaim;
 use common_grpc::GrpcToken;
 use common_meta_grpc::MetaGrpcReadReq;
 use common_meta_grpc::MetaGrpcWriteReq;
+use common_meta_types::check_metacli_protocol_version;
 use common_meta_types::protobuf::meta_server::Meta;
 use common_meta_types::protobuf::GetReply;
 use common_meta_types::protobuf::GetRequest;
@@ -26,6 +27,7 @@ use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::META_PROTOCOL_VERSION;
 use common_tracing::tracing;
 use futures::StreamExt;
 use prost::Message;
@@ -84,7 +86,13 @@ impl Meta for MetaGrpcImpl {
             .await
             .ok_or_else(|| Status::internal("Error request next is None"))??;
 
-        let HandshakeRequest { payload, .. } = req;
+        let HandshakeRequest {
+            protocol_version,
+            payload,
+        } = req;
+        // refuse the clients that do not understand the meta data of this version
+        check_metacli_protocol_version(protocol_version)?;
+
         let auth = BasicAuth::decode(&*payload).map_err(|e| Status::internal(e.to_string()))?;
 
         let user = "root";
@@ -98,8 +106,8 @@ impl Meta for MetaGrpcImpl {
                 .map_err(|e| Status::internal(e.to_string()))?;
 
             let resp = HandshakeResponse {
+                protocol_version: META_PROTOCOL_VERSION,
                 payload: token.into_bytes(),
-                ..HandshakeResponse::default()
             };
             let output = futures::stream::once(async { Ok(resp) });
             Ok(Response::new(Box::pin(output)))
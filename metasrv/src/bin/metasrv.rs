@@ -22,6 +22,7 @@ use common_meta_sled_store::init_sled_db;
 use common_metrics::init_default_metrics_recorder;
 use common_tracing::init_global_tracing;
 use common_tracing::tracing;
+use common_tracing::LogOptions;
 use databend_meta::api::GrpcServer;
 use databend_meta::api::HttpService;
 use databend_meta::configs::Config;
@@ -36,6 +37,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         "databend-meta",
         conf.log_dir.as_str(),
         conf.log_level.as_str(),
+        &LogOptions::default(),
     );
 
     tracing::info!("{:?}", conf.clone());
@@ -20,11 +20,15 @@ use common_exception::ErrorCode;
 use common_meta_grpc::GetTableExtReq;
 use common_meta_types::AddResult;
 use common_meta_types::Change;
+use common_meta_types::Cmd::CommitTables;
 use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::ReplaceTable;
 use common_meta_types::Cmd::UpsertTableOptions;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -139,20 +143,28 @@ impl RequestHandler<CreateTableReq> for ActionHandler {
         let db_name = &req.db;
         let table_name = &req.table;
         let if_not_exists = req.if_not_exists;
+        let or_replace = req.or_replace;
 
         tracing::info!("create table: {:}: {:?}", &db_name, &table_name);
 
         let table_meta = req.table_meta;
 
-        let cr = LogEntry {
-            txid: None,
-            cmd: CreateTable {
+        let cmd = if or_replace {
+            ReplaceTable {
                 db_name: db_name.clone(),
                 table_name: table_name.clone(),
                 table_meta,
-            },
+            }
+        } else {
+            CreateTable {
+                db_name: db_name.clone(),
+                table_name: table_name.clone(),
+                table_meta,
+            }
         };
 
+        let cr = LogEntry { txid: None, cmd };
+
         let rst = self
             .meta_node
             .write(cr)
@@ -162,7 +174,7 @@ impl RequestHandler<CreateTableReq> for ActionHandler {
         let add_res: AddResult<TableMeta, u64> = rst.try_into()?;
 
         if let OkOrExist::Exists(_) = add_res.res {
-            if !if_not_exists {
+            if !if_not_exists && !or_replace {
                 return Err(ErrorCode::TableAlreadyExists(format!(
                     "table exists: {}",
                     table_name
@@ -289,3 +301,35 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<CommitTablesReq> for ActionHandler {
+    async fn handle(&self, req: CommitTablesReq) -> common_exception::Result<CommitTablesReply> {
+        if req.reqs.is_empty() {
+            return Ok(CommitTablesReply {});
+        }
+        let cr = LogEntry {
+            txid: None,
+            cmd: CommitTables(req),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let table_id = ch.ident.unwrap_or_default();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "table of id {} is changed, current version {}",
+                table_id, prev.seq,
+            )));
+        }
+
+        Ok(CommitTablesReply {})
+    }
+}
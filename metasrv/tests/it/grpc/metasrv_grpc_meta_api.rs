@@ -69,6 +69,18 @@ async fn test_meta_api_table_list() -> anyhow::Result<()> {
     MetaApiTestSuite {}.table_list(&client).await
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_table_create_or_replace() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = start_metasrv().await?;
+
+    let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx").await?;
+
+    MetaApiTestSuite {}.table_create_or_replace(&client).await
+}
+
 // TODO(xp): uncomment following tests when the function is ready
 // ------------------------------------------------------------
 
@@ -100,6 +112,7 @@ async fn test_meta_api_flight_get_database_meta_ddl_table() -> anyhow::Result<()
     // create-tbl operation will increases meta_version
     let plan = CreateTablePlan {
         if_not_exists: true,
+        or_replace: false,
         db: test_db.to_string(),
         table: "tbl1".to_string(),
         schema: schema.clone(),
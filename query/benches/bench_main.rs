@@ -19,6 +19,7 @@ mod suites;
 criterion_main! {
     suites::bench_aggregate_query_sql::benches,
     suites::bench_filter_query_sql::benches,
+    suites::bench_fuse_meta::benches,
     suites::bench_limit_query_sql::benches,
     suites::bench_sort_query_sql::benches,
 }
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::DataValue;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use databend_query::storages::fuse::meta;
+use databend_query::storages::fuse::meta::BlockLocation;
+use databend_query::storages::fuse::meta::BlockMeta;
+use databend_query::storages::fuse::meta::SegmentInfo;
+use databend_query::storages::fuse::meta::SEGMENT_FORMAT_VERSION;
+use databend_query::storages::index::ColumnStatistics;
+
+// A segment of a wide table: 1000 blocks of 100 columns.
+fn large_segment() -> SegmentInfo {
+    let col_stats = (0..100u32)
+        .map(|id| {
+            let stats = ColumnStatistics {
+                min: DataValue::Int64(Some(id as i64)),
+                max: DataValue::String(Some(format!("max value of column {}", id).into_bytes())),
+                null_count: 0,
+                in_memory_size: 8 * 10000,
+            };
+            (id, stats)
+        })
+        .collect::<HashMap<_, _>>();
+    let blocks = (0..1000)
+        .map(|i| BlockMeta {
+            row_count: 10000,
+            block_size: 800000,
+            file_size: 100000,
+            col_stats: col_stats.clone(),
+            location: BlockLocation {
+                path: format!("_b/{}.parquet", i),
+                meta_size: 0,
+            },
+            bloom_filter_location: None,
+        })
+        .collect();
+    SegmentInfo {
+        format_version: SEGMENT_FORMAT_VERSION,
+        blocks,
+        summary: Default::default(),
+        ndv_sketches: Default::default(),
+    }
+}
+
+fn criterion_benchmark_fuse_meta(c: &mut Criterion) {
+    let segment = large_segment();
+    let json = serde_json::to_vec(&segment).unwrap();
+    let binary = meta::encode(&segment).unwrap();
+
+    c.bench_function("decode json segment", |b| {
+        b.iter(|| meta::decode::<SegmentInfo>(&json).unwrap())
+    });
+    c.bench_function("decode binary segment", |b| {
+        b.iter(|| meta::decode::<SegmentInfo>(&binary).unwrap())
+    });
+    c.bench_function("encode binary segment", |b| {
+        b.iter(|| meta::encode(&segment).unwrap())
+    });
+}
+
+criterion_group!(benches, criterion_benchmark_fuse_meta);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_tracing::tracing;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::Json;
+
+use crate::audit::SettingChange;
+use crate::sessions::SessionManager;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct LogLevel {
+    /// The directives of the log filter, e.g. `DEBUG` or `info,databend_query=debug`.
+    pub level: String,
+}
+
+// GET /v1/log_level
+// the current log level of the node
+// return: LogLevel
+#[poem::handler]
+pub async fn get_log_level_handler() -> poem::Result<Json<LogLevel>> {
+    match common_tracing::get_log_level() {
+        Some(level) => Ok(Json(LogLevel { level })),
+        None => Err(poem::Error::from_string(
+            "The logging is not initialized",
+            StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+// PUT /v1/log_level
+// change the log level of the node at runtime, the same as `SET GLOBAL log_level`
+// request: LogLevel
+// return: LogLevel
+#[poem::handler]
+pub async fn set_log_level_handler(
+    sessions: Data<&Arc<SessionManager>>,
+    Json(req): Json<LogLevel>,
+) -> poem::Result<Json<LogLevel>> {
+    let old_level = common_tracing::get_log_level().unwrap_or_default();
+    common_tracing::set_log_level(&req.level)
+        .map_err(|e| poem::Error::from_string(e, StatusCode::BAD_REQUEST))?;
+    tracing::info!("The log level is set to {} by the http api", req.level);
+
+    let tenant_id = sessions.get_conf().query.tenant_id.clone();
+    let mut change = SettingChange::create(tenant_id, "log_level", old_level, &req.level);
+    change.handler_type = "HTTPAPI".to_string();
+    sessions.get_settings_history().append(change);
+    Ok(Json(req))
+}
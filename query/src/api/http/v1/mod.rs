@@ -15,4 +15,6 @@
 pub mod cluster;
 pub mod config;
 pub mod health;
+pub mod log_level;
 pub mod logs;
+pub mod users;
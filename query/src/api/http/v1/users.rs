@@ -0,0 +1,263 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordType;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+use common_planners::CreateUserPlan;
+use common_planners::DropUserPlan;
+use common_planners::GrantPrivilegePlan;
+use common_planners::PlanNode;
+use common_planners::RevokePrivilegePlan;
+use futures::TryStreamExt;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::IntoResponse;
+use poem::web::Json;
+use poem::web::Path;
+use poem::web::Query;
+use poem::Request;
+
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::get_client_host;
+use crate::servers::http::get_credential;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct CreateUserRequest {
+    pub name: String,
+    #[serde(default = "default_hostname")]
+    pub hostname: String,
+    /// Defaults to pbkdf2_sha256_password if a password is given, as `IDENTIFIED BY` does.
+    pub password_type: Option<PasswordType>,
+    #[serde(default)]
+    pub password: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct UserHostParams {
+    #[serde(default = "default_hostname")]
+    pub hostname: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct GrantRequest {
+    #[serde(default = "default_hostname")]
+    pub hostname: String,
+    pub privileges: Vec<UserPrivilegeType>,
+    pub on: GrantObject,
+}
+
+fn default_hostname() -> String {
+    String::from("%")
+}
+
+// POST /v1/users
+// create a user, the same as `CREATE USER`
+// request: CreateUserRequest
+// return: None
+#[poem::handler]
+pub async fn create_user_handler(
+    request: &Request,
+    sessions: Data<&Arc<SessionManager>>,
+    Json(req): Json<CreateUserRequest>,
+) -> poem::Result<impl IntoResponse> {
+    let session = admin_session(request, sessions.0).await?;
+    let password_type = match req.password_type {
+        Some(password_type) => password_type,
+        None if req.password.is_empty() => PasswordType::None,
+        None => PasswordType::Pbkdf2Sha256,
+    };
+    let plan = PlanNode::CreateUser(CreateUserPlan {
+        name: req.name,
+        password: Vec::from(req.password),
+        hostname: req.hostname,
+        password_type,
+    });
+    execute_plan(session, plan).await.map_err(to_http_error)?;
+    Ok(StatusCode::CREATED)
+}
+
+// DELETE /v1/users/:name?hostname=%
+// drop a user, the same as `DROP USER`
+// return: None
+#[poem::handler]
+pub async fn drop_user_handler(
+    request: &Request,
+    sessions: Data<&Arc<SessionManager>>,
+    Path(name): Path<String>,
+    Query(params): Query<UserHostParams>,
+) -> poem::Result<impl IntoResponse> {
+    let session = admin_session(request, sessions.0).await?;
+    let plan = PlanNode::DropUser(DropUserPlan {
+        if_exists: false,
+        name,
+        hostname: params.hostname,
+    });
+    execute_plan(session, plan).await.map_err(to_http_error)?;
+    Ok(StatusCode::OK)
+}
+
+// GET /v1/users/:name/grants?hostname=%
+// list the grants of a user, the same as `SHOW GRANTS FOR`
+// return: a list of the grants, e.g. "GRANT SELECT ON 'db1'.* TO 'u1'@'%'"
+#[poem::handler]
+pub async fn list_grants_handler(
+    request: &Request,
+    sessions: Data<&Arc<SessionManager>>,
+    Path(name): Path<String>,
+    Query(params): Query<UserHostParams>,
+) -> poem::Result<impl IntoResponse> {
+    let session = admin_session(request, sessions.0).await?;
+    let user_mgr = session.get_user_manager();
+    let user_info = user_mgr
+        .get_user(&name, &params.hostname)
+        .await
+        .map_err(to_http_error)?;
+    let grants = user_info
+        .grants
+        .entries()
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    Ok(Json(grants))
+}
+
+// POST /v1/users/:name/grants
+// grant privileges to a user, the same as `GRANT`
+// request: GrantRequest
+// return: None
+#[poem::handler]
+pub async fn grant_handler(
+    request: &Request,
+    sessions: Data<&Arc<SessionManager>>,
+    Path(name): Path<String>,
+    Json(req): Json<GrantRequest>,
+) -> poem::Result<impl IntoResponse> {
+    let session = admin_session(request, sessions.0).await?;
+    let plan = PlanNode::GrantPrivilege(GrantPrivilegePlan {
+        name,
+        hostname: req.hostname,
+        priv_types: privilege_set(&req.privileges),
+        on: req.on,
+    });
+    execute_plan(session, plan).await.map_err(to_http_error)?;
+    Ok(StatusCode::OK)
+}
+
+// DELETE /v1/users/:name/grants
+// revoke privileges from a user, the same as `REVOKE`
+// request: GrantRequest
+// return: None
+#[poem::handler]
+pub async fn revoke_handler(
+    request: &Request,
+    sessions: Data<&Arc<SessionManager>>,
+    Path(name): Path<String>,
+    Json(req): Json<GrantRequest>,
+) -> poem::Result<impl IntoResponse> {
+    let session = admin_session(request, sessions.0).await?;
+    let plan = PlanNode::RevokePrivilege(RevokePrivilegePlan {
+        username: name,
+        hostname: req.hostname,
+        priv_types: privilege_set(&req.privileges),
+        on: req.on,
+    });
+    execute_plan(session, plan).await.map_err(to_http_error)?;
+    Ok(StatusCode::OK)
+}
+
+fn privilege_set(privileges: &[UserPrivilegeType]) -> UserPrivilegeSet {
+    let mut set = UserPrivilegeSet::empty();
+    privileges.iter().for_each(|p| set.set_privilege(*p));
+    set
+}
+
+// The session of the user of the request, authenticated by its `Authorization` header: the
+// admin port is open to whoever reaches it, only the users with the GRANT or SUPER privilege on
+// *.* may manage the users.
+async fn admin_session(req: &Request, sessions: &Arc<SessionManager>) -> poem::Result<SessionRef> {
+    let unauthorized =
+        |cause: ErrorCode| poem::Error::from_string(cause.message(), StatusCode::UNAUTHORIZED);
+    let credential = get_credential(req)
+        .map_err(unauthorized)?
+        .ok_or_else(|| unauthorized(ErrorCode::AuthenticateFailure("No Authorization header")))?;
+
+    let session = sessions
+        .create_session("HTTPAdmin")
+        .map_err(to_http_error)?;
+    session.set_client_host(get_client_host(req));
+    session
+        .get_auth_manager()
+        .auth(&session, &credential)
+        .await
+        .map_err(unauthorized)?;
+
+    let user = session.get_current_user().map_err(unauthorized)?;
+    let user = session
+        .get_user_manager()
+        .get_user_with_roles(user, &session.get_active_roles())
+        .await
+        .map_err(to_http_error)?;
+    let privileged = [UserPrivilegeType::Grant, UserPrivilegeType::Super]
+        .iter()
+        .any(|p| {
+            user.grants
+                .verify_global_privilege(&user.name, &user.hostname, *p)
+        });
+    if !privileged {
+        return Err(poem::Error::from_string(
+            format!(
+                "Permission denied, '{}'@'{}' needs the GRANT or SUPER privilege on *.*",
+                user.name, user.hostname
+            ),
+            StatusCode::FORBIDDEN,
+        ));
+    }
+    Ok(session)
+}
+
+// Runs the plan through the interpreters as the user of the request, so the checks are the same
+// as the SQL statements'.
+async fn execute_plan(session: SessionRef, plan: PlanNode) -> Result<()> {
+    let ctx = session.create_context().await?;
+    let interpreter = InterpreterFactory::get(ctx, plan)?;
+    interpreter
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+fn to_http_error(cause: ErrorCode) -> poem::Error {
+    let status = match cause.code() {
+        c if c == ErrorCode::UnknownUserCode() => StatusCode::NOT_FOUND,
+        c if c == ErrorCode::UserAlreadyExistsCode() => StatusCode::CONFLICT,
+        c if c == ErrorCode::UnknownDatabaseCode()
+            || c == ErrorCode::UnknownTableCode()
+            || c == ErrorCode::IllegalGrantCode() =>
+        {
+            StatusCode::BAD_REQUEST
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    poem::Error::from_string(cause.message(), status)
+}
@@ -18,8 +18,10 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_tracing::tracing;
+use poem::delete;
 use poem::get;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
@@ -47,10 +49,29 @@ impl HttpService {
             .at("/v1/health", get(super::http::v1::health::health_handler))
             .at("/v1/config", get(super::http::v1::config::config_handler))
             .at("/v1/logs", get(super::http::v1::logs::logs_handler))
+            .at(
+                "/v1/log_level",
+                get(super::http::v1::log_level::get_log_level_handler)
+                    .put(super::http::v1::log_level::set_log_level_handler),
+            )
             .at(
                 "/v1/cluster/list",
                 get(super::http::v1::cluster::cluster_list_handler),
             )
+            .at(
+                "/v1/users",
+                post(super::http::v1::users::create_user_handler),
+            )
+            .at(
+                "/v1/users/:name",
+                delete(super::http::v1::users::drop_user_handler),
+            )
+            .at(
+                "/v1/users/:name/grants",
+                get(super::http::v1::users::list_grants_handler)
+                    .post(super::http::v1::users::grant_handler)
+                    .delete(super::http::v1::users::revoke_handler),
+            )
             .at(
                 "/debug/home",
                 get(super::http::debug::home::debug_home_handler),
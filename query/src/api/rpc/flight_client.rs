@@ -13,17 +13,29 @@
 // limitations under the License.
 
 use std::convert::TryInto;
+use std::sync::Arc;
 
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_arrow::arrow_format::flight::data::flight_descriptor::DescriptorType;
 use common_arrow::arrow_format::flight::data::Action;
 use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
 use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_base::tokio::time::Duration;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::Mutex;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::future;
+use futures::stream;
+use futures::Stream;
+use futures::StreamExt;
 use tonic::transport::channel::Channel;
 use tonic::Request;
 use tonic::Streaming;
@@ -53,6 +65,61 @@ impl FlightClient {
         Ok(Box::pin(FlightDataStream::from_remote(schema, inner)))
     }
 
+    /// Streams the input blocks to a Flight service by DoExchange, and receives the blocks
+    /// transformed by the service in the output schema. The first message is the schema of the
+    /// input, with the command for the service as the descriptor.
+    pub async fn exchange_stream(
+        &mut self,
+        command: &str,
+        input: SendableDataBlockStream,
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        timeout: u64,
+    ) -> Result<SendableDataBlockStream> {
+        let mut first = serialize_schema(&input_schema.to_arrow());
+        first.flight_descriptor = Some(FlightDescriptor {
+            r#type: DescriptorType::Cmd as i32,
+            cmd: command.as_bytes().to_vec(),
+            path: vec![],
+        });
+
+        // The request stream ends at the first error of the input, which is returned after
+        // the output of the service.
+        let input_error = Arc::new(Mutex::new(None));
+        let request_error = input_error.clone();
+        let options = WriteOptions { compression: None };
+        let batches = input
+            .map(move |block| -> Result<FlightData> {
+                let record_batch: RecordBatch = block?.try_into()?;
+                match serialize_batch(&record_batch, &options) {
+                    (dicts, values) if dicts.is_empty() => Ok(values),
+                    _ => Err(ErrorCode::UnImplement(
+                        "DatabendQuery does not implement dicts.",
+                    )),
+                }
+            })
+            .scan((), move |_, data| {
+                future::ready(match data {
+                    Ok(data) => Some(data),
+                    Err(error) => {
+                        *request_error.lock() = Some(error);
+                        None
+                    }
+                })
+            });
+
+        let request = stream::once(future::ready(first)).chain(batches);
+        let inner = self.do_exchange(request, timeout).await?;
+        // The messages without body, e.g. the schema of the output, carry no rows.
+        let inner = inner
+            .filter(|data| future::ready(!matches!(data, Ok(data) if data.data_body.is_empty())));
+        let input_error = stream::once(async move { input_error.lock().take() })
+            .filter_map(|error| future::ready(error.map(Err)));
+        Ok(Box::pin(
+            FlightDataStream::from_remote(output_schema, inner).chain(input_error),
+        ))
+    }
+
     pub async fn execute_action(&mut self, action: FlightAction, timeout: u64) -> Result<()> {
         self.do_action(action, timeout).await?;
         Ok(())
@@ -69,6 +136,21 @@ impl FlightClient {
         Ok(response.into_inner())
     }
 
+    // Execute do_exchange.
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn do_exchange(
+        &mut self,
+        request: impl Stream<Item = FlightData> + Send + 'static,
+        timeout: u64,
+    ) -> Result<Streaming<FlightData>> {
+        let request = Request::new(request);
+        let mut request = common_tracing::inject_span_to_tonic_request(request);
+        request.set_timeout(Duration::from_secs(timeout));
+
+        let response = self.inner.do_exchange(request).await?;
+        Ok(response.into_inner())
+    }
+
     // Execute do_action.
     #[tracing::instrument(level = "debug", skip_all)]
     async fn do_action(&mut self, action: FlightAction, timeout: u64) -> Result<Vec<u8>> {
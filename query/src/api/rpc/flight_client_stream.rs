@@ -24,7 +24,7 @@ use common_exception::ErrorCode;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::Stream;
 use tokio_stream::StreamExt;
-use tonic::Streaming;
+use tonic::Status;
 
 #[derive(Debug)]
 pub struct FlightDataStream();
@@ -33,7 +33,7 @@ impl FlightDataStream {
     #[inline]
     pub fn from_remote(
         schema: DataSchemaRef,
-        inner: Streaming<FlightData>,
+        inner: impl Stream<Item = Result<FlightData, Status>>,
     ) -> impl Stream<Item = Result<DataBlock, ErrorCode>> {
         inner.map(move |flight_data| -> Result<DataBlock, ErrorCode> {
             match flight_data {
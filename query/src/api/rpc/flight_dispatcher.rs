@@ -140,6 +140,7 @@ impl DatabendQueryFlightDispatcher {
         assert_eq!(action_sinks.len(), 1);
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let stage_span = stage_span(&action_query_id, &action_stage_id);
 
         let stream_name = format!("{}/{}", stage_name, action_sinks[0]);
         let tx_ref = self.streams.read().get(&stream_name).map(|x| x.tx.clone());
@@ -167,7 +168,7 @@ impl DatabendQueryFlightDispatcher {
                     }
                 };
             }
-            .instrument(Span::current()),
+            .instrument(stage_span),
         )?;
         Ok(())
     }
@@ -216,7 +217,9 @@ impl DatabendQueryFlightDispatcher {
 
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let stage_span = stage_span(&action_query_id, &action_stage_id);
 
+        let max_block_size = query_context.get_settings().get_max_block_size()? as usize;
         let flight_scatter = T::try_create(
             action.get_plan().schema(),
             action.get_scatter_expression(),
@@ -230,19 +233,49 @@ impl DatabendQueryFlightDispatcher {
 
                 let sinks_tx_ref = &sinks_tx;
                 let forward_blocks = async move {
+                    // The scattered blocks are small, they are re-chunked to max_block_size
+                    // rows for each sink before they are sent.
+                    let mut pending_blocks = vec![Vec::new(); sinks_tx_ref.len()];
+                    let mut pending_rows = vec![0; sinks_tx_ref.len()];
+
                     let mut abortable_stream = pipeline.execute().await?;
                     while let Some(item) = abortable_stream.next().await {
                         let forward_blocks = flight_scatter.execute(&item?)?;
 
                         assert_eq!(forward_blocks.len(), sinks_tx_ref.len());
 
-                        for (index, forward_block) in forward_blocks.iter().enumerate() {
-                            let tx: &Sender<Result<DataBlock>> = &sinks_tx_ref[index];
-                            tx.send(Ok(forward_block.clone()))
-                                .await
-                                .map_err_to_code(ErrorCode::LogicalError, || {
-                                    "Cannot push data when run_action"
-                                })?;
+                        for (index, forward_block) in forward_blocks.into_iter().enumerate() {
+                            if forward_block.is_empty() {
+                                continue;
+                            }
+
+                            pending_rows[index] += forward_block.num_rows();
+                            pending_blocks[index].push(forward_block);
+                            if pending_rows[index] < max_block_size {
+                                continue;
+                            }
+
+                            let mut blocks =
+                                DataBlock::rechunk_blocks(&pending_blocks[index], max_block_size)?;
+                            pending_blocks[index].clear();
+                            pending_rows[index] = 0;
+                            if let Some(last) = blocks.last() {
+                                if last.num_rows() < max_block_size {
+                                    pending_rows[index] = last.num_rows();
+                                    pending_blocks[index].push(blocks.pop().unwrap());
+                                }
+                            }
+
+                            for block in blocks {
+                                forward_block_to(&sinks_tx_ref[index], block).await?;
+                            }
+                        }
+                    }
+
+                    for (index, blocks) in pending_blocks.iter().enumerate() {
+                        if !blocks.is_empty() {
+                            let block = DataBlock::concat_blocks(blocks)?;
+                            forward_block_to(&sinks_tx_ref[index], block).await?;
                         }
                     }
 
@@ -258,7 +291,7 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             }
-            .instrument(Span::current()),
+            .instrument(stage_span),
         )?;
 
         Ok(())
@@ -291,6 +324,12 @@ impl DatabendQueryFlightDispatcher {
     }
 }
 
+// The span of executing a stage of the query, a child of the span of the action sent by the
+// coordinator, within the trace of the query.
+fn stage_span(query_id: &str, stage_id: &str) -> Span {
+    tracing::info_span!("flight_stage", query.id = query_id, stage.id = stage_id)
+}
+
 async fn wait_start(stage_name: String, stages_notify: Arc<RwLock<HashMap<String, Arc<Notify>>>>) {
     let notify = {
         let stages_notify = stages_notify.read();
@@ -301,3 +340,11 @@ async fn wait_start(stage_name: String, stages_notify: Arc<RwLock<HashMap<String
         notify.notified().await;
     }
 }
+
+async fn forward_block_to(tx: &Sender<Result<DataBlock>>, block: DataBlock) -> Result<()> {
+    tx.send(Ok(block))
+        .await
+        .map_err_to_code(ErrorCode::LogicalError, || {
+            "Cannot push data when run_action"
+        })
+}
@@ -31,6 +31,7 @@ use common_arrow::arrow_format::flight::data::SchemaResult;
 use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
 use common_tracing::tracing;
+use common_tracing::tracing_futures::Instrument;
 use tokio_stream::Stream;
 use tonic::Request;
 use tonic::Response as RawResponse;
@@ -99,7 +100,9 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoGetStream = FlightStream<FlightData>;
 
-    #[tracing::instrument(level = "debug", skip_all)]
+    // The spans of the requests from the other nodes are at the info level, so that they chain
+    // the trace of the query with the default log level.
+    #[tracing::instrument(level = "info", name = "flight_do_get", skip_all)]
     async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
         common_tracing::extract_remote_span_as_parent(&request);
         let ticket: FlightTicket = request.into_inner().try_into()?;
@@ -108,8 +111,11 @@ impl FlightService for DatabendQueryFlightService {
             FlightTicket::StreamTicket(steam_ticket) => {
                 let receiver = self.dispatcher.get_stream(&steam_ticket)?;
 
+                // The blocks are sent within the span, until the stream is drained.
+                let stream =
+                    FlightDataStream::create(receiver).instrument(tracing::Span::current());
                 Ok(RawResponse::new(
-                    Box::pin(FlightDataStream::create(receiver)) as FlightStream<FlightData>,
+                    Box::pin(stream) as FlightStream<FlightData>
                 ))
             }
         }
@@ -133,7 +139,7 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoActionStream = FlightStream<FlightResult>;
 
-    #[tracing::instrument(level = "debug", skip_all)]
+    #[tracing::instrument(level = "info", name = "flight_do_action", skip_all)]
     async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
         common_tracing::extract_remote_span_as_parent(&request);
 
@@ -0,0 +1,261 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_infallible::RwLock;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use serde::Serialize;
+
+use crate::configs::Config;
+
+// Upper bound of the in-memory events of a tenant, the retention alone may not be enough under
+// a DDL storm.
+const AUDIT_LOG_MAX_EVENTS: usize = 100000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditEventType {
+    Ddl,
+    Dml,
+    Dcl,
+    LoginSuccess,
+    LoginFailure,
+    PrivilegeDenied,
+}
+
+impl AuditEventType {
+    /// The audited event type of the plan, None if the plan is not audited.
+    pub fn from_plan(plan: &PlanNode) -> Option<AuditEventType> {
+        match plan {
+            PlanNode::CreateDatabase(_)
+            | PlanNode::DropDatabase(_)
+            | PlanNode::UndropDatabase(_)
+            | PlanNode::CreateTable(_)
+            | PlanNode::DropTable(_)
+            | PlanNode::UndropTable(_)
+            | PlanNode::TruncateTable(_)
+            | PlanNode::VacuumDropTable(_)
+            | PlanNode::VacuumTable(_)
+            | PlanNode::OptimizeTable(_)
+            | PlanNode::AnalyzeTable(_)
+            | PlanNode::CreateUserStage(_)
+            | PlanNode::DropUserStage(_)
+            | PlanNode::CreateUDF(_)
+            | PlanNode::DropUDF(_)
+            | PlanNode::AlterUDF(_)
+            | PlanNode::AlterReadOnly(_)
+            | PlanNode::AlterTableColumn(_)
+            | PlanNode::AlterTableOptions(_) => Some(AuditEventType::Ddl),
+            PlanNode::Insert(_)
+            | PlanNode::Delete(_)
+            | PlanNode::Copy(_)
+            | PlanNode::CopyIntoStage(_) => Some(AuditEventType::Dml),
+            PlanNode::CreateUser(_)
+            | PlanNode::AlterUser(_)
+            | PlanNode::DropUser(_)
+            | PlanNode::GrantPrivilege(_)
+            | PlanNode::RevokePrivilege(_)
+            | PlanNode::RevokeAllPrivileges(_)
+            | PlanNode::CreateRowAccessPolicy(_)
+            | PlanNode::DropRowAccessPolicy(_)
+            | PlanNode::CreateNetworkPolicy(_)
+            | PlanNode::DropNetworkPolicy(_)
+            | PlanNode::AlterUserNetworkPolicy(_)
+            | PlanNode::CreateRole(_)
+            | PlanNode::DropRole(_)
+            | PlanNode::GrantRole(_)
+            | PlanNode::RevokeRole(_)
+            | PlanNode::GrantRolePrivilege(_)
+            | PlanNode::RevokeRolePrivilege(_)
+            | PlanNode::CreateConnection(_)
+            | PlanNode::DropConnection(_)
+            | PlanNode::AlterOwner(_) => Some(AuditEventType::Dcl),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEventType::Ddl => "DDL",
+            AuditEventType::Dml => "DML",
+            AuditEventType::Dcl => "DCL",
+            AuditEventType::LoginSuccess => "LOGIN_SUCCESS",
+            AuditEventType::LoginFailure => "LOGIN_FAILURE",
+            AuditEventType::PrivilegeDenied => "PRIVILEGE_DENIED",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditEvent {
+    // Unix timestamp in milliseconds.
+    pub event_time: u64,
+    pub event_type: AuditEventType,
+    pub tenant_id: String,
+    pub handler_type: String,
+    pub session_id: String,
+    pub query_id: String,
+    pub user: String,
+    pub client_address: String,
+    // The plan name for statements, `Login` for authentications.
+    pub operation: String,
+    pub query_text: String,
+    pub success: bool,
+    pub exception_code: i32,
+    pub exception: String,
+}
+
+impl AuditEvent {
+    pub fn create(event_type: AuditEventType, tenant_id: impl Into<String>) -> AuditEvent {
+        AuditEvent {
+            event_time: Self::now_millis(),
+            event_type,
+            tenant_id: tenant_id.into(),
+            handler_type: "".to_string(),
+            session_id: "".to_string(),
+            query_id: "".to_string(),
+            user: "".to_string(),
+            client_address: "".to_string(),
+            operation: "".to_string(),
+            query_text: "".to_string(),
+            success: true,
+            exception_code: 0,
+            exception: "".to_string(),
+        }
+    }
+
+    pub fn with_error(mut self, error: &ErrorCode) -> AuditEvent {
+        self.success = false;
+        self.exception_code = error.code() as i32;
+        self.exception = error.message();
+        self
+    }
+
+    fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as u64
+    }
+}
+
+/// Keeps the audit events of each tenant for its retention, and appends them to
+/// `audit_log_file` as json lines if configured.
+///
+/// The retention of a tenant is `audit_log_retention_days` of the config, unless the tenant
+/// sets its own by `SET GLOBAL audit_log_retention_days`.
+pub struct AuditLog {
+    default_retention_days: u64,
+    retention_days: RwLock<HashMap<String, u64>>,
+    events: RwLock<HashMap<String, VecDeque<AuditEvent>>>,
+    sink: Option<Mutex<File>>,
+}
+
+impl AuditLog {
+    pub fn try_create(conf: &Config) -> Result<Arc<AuditLog>> {
+        let sink = match conf.query.audit_log_file.as_str() {
+            "" => None,
+            path => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|cause| {
+                        ErrorCode::InvalidConfig(format!(
+                            "Cannot open audit log file {}, cause: {}",
+                            path, cause
+                        ))
+                    })?;
+                Some(Mutex::new(file))
+            }
+        };
+
+        Ok(Arc::new(AuditLog {
+            default_retention_days: conf.query.audit_log_retention_days,
+            retention_days: RwLock::new(HashMap::new()),
+            events: RwLock::new(HashMap::new()),
+            sink,
+        }))
+    }
+
+    pub fn get_retention_days(&self, tenant_id: &str) -> u64 {
+        self.retention_days
+            .read()
+            .get(tenant_id)
+            .cloned()
+            .unwrap_or(self.default_retention_days)
+    }
+
+    pub fn set_retention_days(&self, tenant_id: &str, retention_days: u64) {
+        self.retention_days
+            .write()
+            .insert(tenant_id.to_string(), retention_days);
+    }
+
+    pub fn append(&self, event: AuditEvent) {
+        if let Some(sink) = &self.sink {
+            if let Err(cause) = Self::write_sink(sink, &event) {
+                tracing::warn!("Cannot write audit event to file, cause: {}", cause);
+            }
+        }
+
+        let tenant_id = event.tenant_id.clone();
+        let mut events = self.events.write();
+        let tenant_events = events.entry(tenant_id.clone()).or_default();
+        tenant_events.push_back(event);
+        self.evict(&tenant_id, tenant_events);
+    }
+
+    /// All the events of the tenant within its retention, oldest first.
+    pub fn events(&self, tenant_id: &str) -> Vec<AuditEvent> {
+        let mut events = self.events.write();
+        match events.get_mut(tenant_id) {
+            None => vec![],
+            Some(tenant_events) => {
+                self.evict(tenant_id, tenant_events);
+                tenant_events.iter().cloned().collect()
+            }
+        }
+    }
+
+    fn evict(&self, tenant_id: &str, events: &mut VecDeque<AuditEvent>) {
+        let retention_millis = self.get_retention_days(tenant_id) * 24 * 3600 * 1000;
+        let expire_before = AuditEvent::now_millis().saturating_sub(retention_millis);
+        while let Some(event) = events.front() {
+            if event.event_time >= expire_before && events.len() <= AUDIT_LOG_MAX_EVENTS {
+                break;
+            }
+            events.pop_front();
+        }
+    }
+
+    fn write_sink(sink: &Mutex<File>, event: &AuditEvent) -> Result<()> {
+        let mut line = serde_json::to_string(event)?;
+        line.push('\n');
+        sink.lock().write_all(line.as_bytes())?;
+        Ok(())
+    }
+}
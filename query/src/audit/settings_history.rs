@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_infallible::RwLock;
+use common_tracing::tracing;
+use serde::Serialize;
+
+use crate::configs::Config;
+
+// Upper bound of the in-memory changes, in case of a script setting the globals in a loop.
+const SETTINGS_HISTORY_MAX_CHANGES: usize = 10000;
+
+/// A change of a global setting, which affects every tenant of the node.
+#[derive(Clone, Debug, Serialize)]
+pub struct SettingChange {
+    // Unix timestamp in milliseconds.
+    pub event_time: u64,
+    pub tenant_id: String,
+    // The handler of the change, e.g. `MySQL`, or `HTTPAPI` for the admin api.
+    pub handler_type: String,
+    pub query_id: String,
+    pub user: String,
+    pub client_address: String,
+    pub name: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+impl SettingChange {
+    pub fn create(
+        tenant_id: impl Into<String>,
+        name: impl Into<String>,
+        old_value: impl Into<String>,
+        new_value: impl Into<String>,
+    ) -> SettingChange {
+        SettingChange {
+            event_time: Self::now_millis(),
+            tenant_id: tenant_id.into(),
+            handler_type: "".to_string(),
+            query_id: "".to_string(),
+            user: "".to_string(),
+            client_address: "".to_string(),
+            name: name.into(),
+            old_value: old_value.into(),
+            new_value: new_value.into(),
+        }
+    }
+
+    fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as u64
+    }
+}
+
+/// Keeps the changes of the global settings for `audit_log_retention_days`, they are also
+/// logged, so that they are kept with the logs of the node.
+pub struct SettingsHistory {
+    retention_millis: u64,
+    changes: RwLock<VecDeque<SettingChange>>,
+}
+
+impl SettingsHistory {
+    pub fn create(conf: &Config) -> Arc<SettingsHistory> {
+        Arc::new(SettingsHistory {
+            retention_millis: conf.query.audit_log_retention_days * 24 * 3600 * 1000,
+            changes: RwLock::new(VecDeque::new()),
+        })
+    }
+
+    pub fn append(&self, change: SettingChange) {
+        tracing::info!(
+            "The global setting {} is changed from {:?} to {:?} by {:?} from {:?}",
+            change.name,
+            change.old_value,
+            change.new_value,
+            change.user,
+            change.client_address
+        );
+
+        let mut changes = self.changes.write();
+        changes.push_back(change);
+        self.evict(&mut changes);
+    }
+
+    /// All the changes within the retention, oldest first.
+    pub fn changes(&self) -> Vec<SettingChange> {
+        let mut changes = self.changes.write();
+        self.evict(&mut changes);
+        changes.iter().cloned().collect()
+    }
+
+    fn evict(&self, changes: &mut VecDeque<SettingChange>) {
+        let expire_before = SettingChange::now_millis().saturating_sub(self.retention_millis);
+        while let Some(change) = changes.front() {
+            if change.event_time >= expire_before && changes.len() <= SETTINGS_HISTORY_MAX_CHANGES {
+                break;
+            }
+            changes.pop_front();
+        }
+    }
+}
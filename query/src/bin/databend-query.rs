@@ -15,14 +15,21 @@
 use std::sync::Arc;
 
 use common_base::RuntimeTracker;
+use common_exception::ErrorCode;
 use common_macros::databend_main;
 use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MetaCompatibility;
+use common_meta_types::META_PROTOCOL_VERSION;
 use common_metrics::init_default_metrics_recorder;
+use common_streams::SpillDir;
 use common_tracing::init_global_tracing;
 use common_tracing::set_panic_hook;
+use common_tracing::set_redact_literals;
+use common_tracing::set_redact_pattern;
 use common_tracing::tracing;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
+use databend_query::common::MetaClientProvider;
 use databend_query::configs::Config;
 use databend_query::metrics::MetricService;
 use databend_query::servers::ClickHouseHandler;
@@ -31,6 +38,8 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::sessions::SessionManager;
+use databend_query::storages::SnapshotExpirer;
+use databend_query::storages::StatisticsRefresher;
 
 #[databend_main]
 async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<()> {
@@ -49,7 +58,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
     conf = Config::load_from_env(&conf)?;
     conf.initial_dir()?;
 
-    if conf.meta.meta_address.is_empty() {
+    if conf.meta.is_embedded_meta() {
         MetaEmbedded::init_global_meta_store(conf.meta.meta_embedded_dir.clone()).await?;
     }
 
@@ -57,22 +66,56 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         "databend-query-{}@{}:{}",
         conf.query.cluster_id, conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    set_redact_literals(conf.log.log_redact_literals);
+    set_redact_pattern(&conf.log.log_redact_pattern).map_err(ErrorCode::InvalidConfig)?;
     //let _guards = init_tracing_with_file(
     let _guards = init_global_tracing(
         app_name.as_str(),
         conf.log.log_dir.as_str(),
         conf.log.log_level.as_str(),
+        &conf.log.log_options()?,
     );
 
     init_default_metrics_recorder();
 
     set_panic_hook();
     tracing::info!("{:?}", conf);
+
+    // The spill files of the queries of the last run, e.g. if it crashed.
+    SpillDir::cleanup(&conf.query.spill_dir)?;
     tracing::info!(
         "DatabendQuery v-{}",
         *databend_query::configs::DATABEND_COMMIT_VERSION,
     );
 
+    if conf.meta.is_embedded_meta() {
+        tracing::warn!(
+            "Standalone mode, meta data is stored in the embedded meta store at {:?}. \
+             It is meant for development, set meta_address to use a meta service cluster",
+            conf.meta.meta_embedded_dir,
+        );
+    } else {
+        tracing::info!("Meta service address: {}", conf.meta.meta_address);
+
+        // refuse to start with a meta service that does not understand this binary, or vice versa
+        let provider = MetaClientProvider::new(conf.meta.to_grpc_client_config());
+        let (ver, compatibility) = provider.check_compatibility().await?;
+        match compatibility {
+            MetaCompatibility::Newer => tracing::warn!(
+                "Meta service protocol version {} is newer than {} of this binary, \
+                 the meta data written by the newer query nodes may not be fully understood, \
+                 upgrade this query node",
+                ver,
+                META_PROTOCOL_VERSION,
+            ),
+            _ => tracing::info!(
+                "Meta service protocol version: {}, of this binary: {}",
+                ver,
+                META_PROTOCOL_VERSION,
+            ),
+        }
+    }
+
     let session_manager = SessionManager::from_conf(conf.clone()).await?;
     let mut shutdown_handle = ShutdownHandle::create(session_manager.clone());
 
@@ -164,8 +207,19 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    let mut statistics_refresher = StatisticsRefresher::create(session_manager.clone());
+    statistics_refresher.start();
+    let mut snapshot_expirer = SnapshotExpirer::create(session_manager.clone());
+    snapshot_expirer.start();
+
     tracing::info!("Ready for connections.");
     shutdown_handle.wait_for_termination_request().await;
+    if let Err(cause) = statistics_refresher.shutdown().await {
+        tracing::warn!("{}", cause);
+    }
+    if let Err(cause) = snapshot_expirer.shutdown().await {
+        tracing::warn!("{}", cause);
+    }
     tracing::info!("Shutdown server.");
     Ok(())
 }
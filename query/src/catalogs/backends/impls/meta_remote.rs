@@ -18,6 +18,8 @@ use std::time::Duration;
 
 use common_exception::Result;
 use common_meta_api::MetaApi;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -131,6 +133,11 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply> {
+        self.query_backend(move |cli| async move { cli.commit_tables(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReq;
@@ -31,6 +33,8 @@ use common_meta_types::UpsertTableOptionReq;
 use dyn_clone::DynClone;
 
 use crate::databases::Database;
+use crate::storages::StorageDescription;
+use crate::storages::StorageOperation;
 use crate::storages::Table;
 use crate::table_functions::TableArgs;
 use crate::table_functions::TableFunction;
@@ -103,6 +107,37 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    /// Upserts the options of several tables atomically, for committing a transaction.
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply>;
+
+    ///
+    /// Table engine
+    ///
+
+    // Get the descriptions of the registered table engines.
+    fn get_table_engines(&self) -> Vec<StorageDescription>;
+
+    // Check the engine of the table supports the operation, before it is run. The engines not
+    // registered, e.g. of the system tables, are left to the table.
+    fn check_table_supports(&self, table: &dyn Table, operation: StorageOperation) -> Result<()> {
+        let engine = table.engine().to_uppercase();
+        let description = self
+            .get_table_engines()
+            .into_iter()
+            .find(|description| description.engine_name == engine);
+        match description {
+            Some(description) if !description.supports(operation) => {
+                Err(ErrorCode::UnsupportedEngineOperation(format!(
+                    "Table engine {} does not support {}, table {}",
+                    engine,
+                    operation,
+                    table.name()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
     ///
     /// Table function
     ///
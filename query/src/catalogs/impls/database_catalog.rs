@@ -17,6 +17,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReq;
@@ -35,6 +37,7 @@ use crate::catalogs::impls::ImmutableCatalog;
 use crate::catalogs::impls::MutableCatalog;
 use crate::configs::Config;
 use crate::databases::Database;
+use crate::storages::StorageDescription;
 use crate::storages::Table;
 use crate::table_functions::TableArgs;
 use crate::table_functions::TableFunction;
@@ -196,6 +199,16 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.upsert_table_option(req).await
     }
 
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply> {
+        // the tables of the system database are never in a transaction
+        self.mutable_catalog.commit_tables(req).await
+    }
+
+    fn get_table_engines(&self) -> Vec<StorageDescription> {
+        // the engines of the system tables are not registered
+        self.mutable_catalog.get_table_engines()
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -17,6 +17,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReq;
@@ -36,6 +38,7 @@ use crate::catalogs::SYS_TBL_ID_BEGIN;
 use crate::configs::Config;
 use crate::databases::Database;
 use crate::databases::SystemDatabase;
+use crate::storages::StorageDescription;
 use crate::storages::Table;
 
 /// System Catalog contains ... all the system databases (no surprise :)
@@ -48,12 +51,12 @@ pub struct ImmutableCatalog {
 }
 
 impl ImmutableCatalog {
-    pub async fn try_create_with_config(_conf: &Config) -> Result<Self> {
+    pub async fn try_create_with_config(conf: &Config) -> Result<Self> {
         let system_table_id = SYS_TBL_ID_BEGIN;
 
         // The global db meta.
         let mut sys_db_meta = InMemoryMetas::create(system_table_id);
-        let sys_db = SystemDatabase::create(&mut sys_db_meta);
+        let sys_db = SystemDatabase::create(&mut sys_db_meta, conf);
 
         Ok(Self {
             sys_db: Arc::new(sys_db),
@@ -143,4 +146,15 @@ impl Catalog for ImmutableCatalog {
             req
         )))
     }
+
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply> {
+        Err(ErrorCode::UnImplement(format!(
+            "Commit tables not allowed for system database {:?}",
+            req
+        )))
+    }
+
+    fn get_table_engines(&self) -> Vec<StorageDescription> {
+        vec![]
+    }
 }
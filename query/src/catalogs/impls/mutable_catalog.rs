@@ -18,6 +18,8 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_api::MetaApi;
 use common_meta_embedded::MetaEmbedded;
+use common_meta_types::CommitTablesReply;
+use common_meta_types::CommitTablesReq;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReq;
@@ -47,6 +49,7 @@ use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
 use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
 use crate::storages::StorageFactory;
 use crate::storages::Table;
 
@@ -75,11 +78,12 @@ impl MutableCatalog {
     /// MetaEmbedded
     /// ```
     pub async fn try_create_with_config(conf: Config) -> Result<Self> {
-        let local_mode = conf.meta.meta_address.is_empty();
+        let local_mode = conf.meta.is_embedded_meta();
 
         let meta: Arc<dyn MetaApi> = if local_mode {
             tracing::info!("use embedded meta");
-            // TODO(xp): This can only be used for test: data will be removed when program quit.
+            // The global meta store is persisted in `meta_embedded_dir` when databend-query
+            // runs standalone, tests fall back to a temp store removed when program quit.
 
             let meta_embedded = MetaEmbedded::get_meta().await?;
             meta_embedded
@@ -225,4 +229,12 @@ impl Catalog for MutableCatalog {
     ) -> Result<UpsertTableOptionReply> {
         self.ctx.meta.upsert_table_option(req).await
     }
+
+    async fn commit_tables(&self, req: CommitTablesReq) -> Result<CommitTablesReply> {
+        self.ctx.meta.commit_tables(req).await
+    }
+
+    fn get_table_engines(&self) -> Vec<StorageDescription> {
+        self.ctx.storage_factory.get_storage_descriptors()
+    }
 }
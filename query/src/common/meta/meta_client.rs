@@ -19,6 +19,8 @@ use common_exception::Result;
 use common_meta_api::KVApi;
 use common_meta_grpc::MetaGrpcClient;
 use common_meta_grpc::MetaGrpcClientConf;
+use common_meta_types::check_metasrv_protocol_version;
+use common_meta_types::MetaCompatibility;
 
 // Since there is a pending dependency issue,
 // StoreApiProvider is temporarily moved from store-api-sdk
@@ -41,6 +43,15 @@ impl MetaClientProvider {
         Ok(Arc::new(client))
     }
 
+    /// Connects to the meta service to check its protocol version against this binary.
+    ///
+    /// It fails if either of them does not support the version of the other.
+    pub async fn check_compatibility(&self) -> Result<(u64, MetaCompatibility)> {
+        let client = self.try_get_meta_client().await?;
+        let ver = client.server_protocol_version();
+        Ok((ver, check_metasrv_protocol_version(ver)?))
+    }
+
     /// Get kv async client, operations trait defined in KVApi.
     pub async fn try_get_kv_client(&self) -> Result<Arc<dyn KVApi>> {
         let local = self.grpc_conf.meta_service_config.address.is_empty();
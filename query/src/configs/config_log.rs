@@ -12,7 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use clap::Args;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::LogFormat;
+use common_tracing::LogOptions;
+use common_tracing::LogRetention;
+use common_tracing::LogRotation;
+use common_tracing::TracingExporter;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -21,6 +30,17 @@ use crate::configs::Config;
 // Log env.
 pub const LOG_LEVEL: &str = "LOG_LEVEL";
 pub const LOG_DIR: &str = "LOG_DIR";
+pub const LOG_REDACT_LITERALS: &str = "LOG_REDACT_LITERALS";
+pub const LOG_REDACT_PATTERN: &str = "LOG_REDACT_PATTERN";
+pub const LOG_STDOUT_FORMAT: &str = "LOG_STDOUT_FORMAT";
+pub const LOG_FILE_FORMAT: &str = "LOG_FILE_FORMAT";
+pub const LOG_ROTATION: &str = "LOG_ROTATION";
+pub const LOG_ROTATION_SIZE_MB: &str = "LOG_ROTATION_SIZE_MB";
+pub const LOG_MAX_FILES: &str = "LOG_MAX_FILES";
+pub const LOG_MAX_TOTAL_SIZE_MB: &str = "LOG_MAX_TOTAL_SIZE_MB";
+pub const LOG_COMPRESS: &str = "LOG_COMPRESS";
+pub const LOG_TRACING_EXPORTER: &str = "LOG_TRACING_EXPORTER";
+pub const LOG_TRACING_ENDPOINT: &str = "LOG_TRACING_ENDPOINT";
 
 /// Log config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -34,6 +54,54 @@ pub struct LogConfig {
     /// Log file dir
     #[clap(required = false, long, env = LOG_DIR, default_value = "./_logs")]
     pub log_dir: String,
+
+    /// Mask all the string literals of the queries in the logs and query_log,
+    /// the credentials are always masked.
+    #[clap(long, env = LOG_REDACT_LITERALS)]
+    pub log_redact_literals: bool,
+
+    /// Mask the string literals matching the regex in the logs and query_log, e.g. the
+    /// credentials of the deployment unknown to the built-in patterns
+    #[clap(long, env = LOG_REDACT_PATTERN, default_value = "")]
+    pub log_redact_pattern: String,
+
+    /// Format of the logs written to stdout <text|json|bunyan>
+    #[clap(long, env = LOG_STDOUT_FORMAT, default_value = "text")]
+    pub log_stdout_format: String,
+
+    /// Format of the logs written to the log files <text|json|bunyan>
+    #[clap(long, env = LOG_FILE_FORMAT, default_value = "bunyan")]
+    pub log_file_format: String,
+
+    /// When a new log file is started <hourly|daily|size|never>
+    #[clap(long, env = LOG_ROTATION, default_value = "hourly")]
+    pub log_rotation: String,
+
+    /// Size of a log file in MB with the size rotation
+    #[clap(long, env = LOG_ROTATION_SIZE_MB, default_value = "512")]
+    pub log_rotation_size_mb: u64,
+
+    /// Max number of the rotated log files kept, the oldest ones are removed first, 0 for unlimited
+    #[clap(long, env = LOG_MAX_FILES, default_value = "0")]
+    pub log_max_files: u64,
+
+    /// Max total size of the rotated log files kept in MB, 0 for unlimited
+    #[clap(long, env = LOG_MAX_TOTAL_SIZE_MB, default_value = "0")]
+    pub log_max_total_size_mb: u64,
+
+    /// Gzip the rotated log files
+    #[clap(long, env = LOG_COMPRESS)]
+    pub log_compress: bool,
+
+    /// Where the tracing spans are exported to <off|jaeger|otlp>
+    #[clap(long, env = LOG_TRACING_EXPORTER, default_value = "off")]
+    pub log_tracing_exporter: String,
+
+    /// Address of the Jaeger agent, or endpoint of the OTLP gRPC collector.
+    /// Defaults to DATABEND_JAEGER_AGENT or 127.0.0.1:6831 for jaeger,
+    /// and http://127.0.0.1:4317 for otlp
+    #[clap(long, env = LOG_TRACING_ENDPOINT, default_value = "")]
+    pub log_tracing_endpoint: String,
 }
 
 impl Default for LogConfig {
@@ -41,6 +109,17 @@ impl Default for LogConfig {
         Self {
             log_level: "INFO".to_string(),
             log_dir: "./_logs".to_string(),
+            log_redact_literals: false,
+            log_redact_pattern: "".to_string(),
+            log_stdout_format: "text".to_string(),
+            log_file_format: "bunyan".to_string(),
+            log_rotation: "hourly".to_string(),
+            log_rotation_size_mb: 512,
+            log_max_files: 0,
+            log_max_total_size_mb: 0,
+            log_compress: false,
+            log_tracing_exporter: "off".to_string(),
+            log_tracing_endpoint: "".to_string(),
         }
     }
 }
@@ -49,5 +128,79 @@ impl LogConfig {
     pub fn load_from_env(mut_config: &mut Config) {
         env_helper!(mut_config, log, log_level, String, LOG_LEVEL);
         env_helper!(mut_config, log, log_dir, String, LOG_DIR);
+        env_helper!(
+            mut_config,
+            log,
+            log_redact_literals,
+            bool,
+            LOG_REDACT_LITERALS
+        );
+        env_helper!(
+            mut_config,
+            log,
+            log_redact_pattern,
+            String,
+            LOG_REDACT_PATTERN
+        );
+        env_helper!(
+            mut_config,
+            log,
+            log_stdout_format,
+            String,
+            LOG_STDOUT_FORMAT
+        );
+        env_helper!(mut_config, log, log_file_format, String, LOG_FILE_FORMAT);
+        env_helper!(mut_config, log, log_rotation, String, LOG_ROTATION);
+        env_helper!(
+            mut_config,
+            log,
+            log_rotation_size_mb,
+            u64,
+            LOG_ROTATION_SIZE_MB
+        );
+        env_helper!(mut_config, log, log_max_files, u64, LOG_MAX_FILES);
+        env_helper!(
+            mut_config,
+            log,
+            log_max_total_size_mb,
+            u64,
+            LOG_MAX_TOTAL_SIZE_MB
+        );
+        env_helper!(mut_config, log, log_compress, bool, LOG_COMPRESS);
+        env_helper!(
+            mut_config,
+            log,
+            log_tracing_exporter,
+            String,
+            LOG_TRACING_EXPORTER
+        );
+        env_helper!(
+            mut_config,
+            log,
+            log_tracing_endpoint,
+            String,
+            LOG_TRACING_ENDPOINT
+        );
+    }
+
+    pub fn log_options(&self) -> Result<LogOptions> {
+        Ok(LogOptions {
+            stdout_format: LogFormat::from_str(&self.log_stdout_format)
+                .map_err(ErrorCode::InvalidConfig)?,
+            file_format: LogFormat::from_str(&self.log_file_format)
+                .map_err(ErrorCode::InvalidConfig)?,
+            rotation: LogRotation::create(&self.log_rotation, self.log_rotation_size_mb)
+                .map_err(ErrorCode::InvalidConfig)?,
+            retention: LogRetention {
+                max_files: self.log_max_files as usize,
+                max_total_size: self.log_max_total_size_mb * 1024 * 1024,
+                compress: self.log_compress,
+            },
+            tracing_exporter: TracingExporter::create(
+                &self.log_tracing_exporter,
+                &self.log_tracing_endpoint,
+            )
+            .map_err(ErrorCode::InvalidConfig)?,
+        })
     }
 }
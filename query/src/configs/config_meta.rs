@@ -87,6 +87,13 @@ impl Default for MetaConfig {
 
 impl MetaConfig {
     pub fn load_from_env(mut_config: &mut Config) {
+        env_helper!(
+            mut_config,
+            meta,
+            meta_embedded_dir,
+            String,
+            META_EMBEDDED_DIR
+        );
         env_helper!(mut_config, meta, meta_address, String, META_ADDRESS);
         env_helper!(mut_config, meta, meta_username, String, META_USERNAME);
         env_helper!(mut_config, meta, meta_password, String, META_PASSWORD);
@@ -106,6 +113,12 @@ impl MetaConfig {
         );
     }
 
+    /// Without a `meta_address` the query node runs standalone,
+    /// with an in-process sled-backed meta store persisted in `meta_embedded_dir`.
+    pub fn is_embedded_meta(&self) -> bool {
+        self.meta_address.is_empty()
+    }
+
     pub fn is_tls_enabled(&self) -> bool {
         !self.rpc_tls_meta_server_root_ca_cert.is_empty()
             && !self.rpc_tls_meta_service_domain_name.is_empty()
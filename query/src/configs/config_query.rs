@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
 use clap::Args;
 use serde::Deserialize;
 use serde::Serialize;
@@ -34,10 +36,31 @@ pub const QUERY_HTTP_API_ADDRESS: &str = "QUERY_HTTP_API_ADDRESS";
 pub const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
 pub const QUERY_WAIT_TIMEOUT_MILLS: &str = "QUERY_WAIT_TIMEOUT_MILLS";
 pub const QUERY_MAX_QUERY_LOG_SIZE: &str = "QUERY_MAX_QUERY_LOG_SIZE";
+pub const QUERY_QUERY_LOG_TABLE: &str = "QUERY_QUERY_LOG_TABLE";
 pub const QUERY_TABLE_CACHE_ENABLED: &str = "QUERY_TABLE_CACHE_ENABLED";
 pub const QUERY_TABLE_MEMORY_CACHE_MB_SIZE: &str = "QUERY_TABLE_MEMORY_CACHE_MB_SIZE";
 pub const QUERY_TABLE_DISK_CACHE_ROOT: &str = "QUERY_TABLE_DISK_CACHE_ROOT";
 pub const QUERY_TABLE_DISK_CACHE_MB_SIZE: &str = "QUERY_TABLE_DISK_CACHE_MB_SIZE";
+pub const QUERY_JWT_KEY_URL: &str = "QUERY_JWT_KEY_URL";
+pub const QUERY_AUDIT_LOG_FILE: &str = "QUERY_AUDIT_LOG_FILE";
+pub const QUERY_AUDIT_LOG_RETENTION_DAYS: &str = "QUERY_AUDIT_LOG_RETENTION_DAYS";
+pub const QUERY_DROP_RETENTION_HOURS: &str = "QUERY_DROP_RETENTION_HOURS";
+pub const QUERY_HISTORY_RETENTION_HOURS: &str = "QUERY_HISTORY_RETENTION_HOURS";
+pub const QUERY_STATISTICS_REFRESH_INTERVAL_SECS: &str = "QUERY_STATISTICS_REFRESH_INTERVAL_SECS";
+pub const QUERY_SNAPSHOT_EXPIRY_INTERVAL_SECS: &str = "QUERY_SNAPSHOT_EXPIRY_INTERVAL_SECS";
+pub const QUERY_CONNECTION_ENCRYPTION_KEY: &str = "QUERY_CONNECTION_ENCRYPTION_KEY";
+pub const QUERY_USER_CACHE_TTL_SECS: &str = "QUERY_USER_CACHE_TTL_SECS";
+pub const QUERY_TABLE_CACHE_PARQUET_META_COUNT: &str = "QUERY_TABLE_CACHE_PARQUET_META_COUNT";
+pub const QUERY_TABLE_CACHE_SEGMENT_INFO_COUNT: &str = "QUERY_TABLE_CACHE_SEGMENT_INFO_COUNT";
+pub const QUERY_TABLE_BLOCK_CACHE_ROOT: &str = "QUERY_TABLE_BLOCK_CACHE_ROOT";
+pub const QUERY_TABLE_BLOCK_CACHE_MB_SIZE: &str = "QUERY_TABLE_BLOCK_CACHE_MB_SIZE";
+pub const QUERY_USER_PASSWORD_REHASH_ON_LOGIN: &str = "QUERY_USER_PASSWORD_REHASH_ON_LOGIN";
+pub const QUERY_TABLE_CHANGE_WEBHOOK_URL: &str = "QUERY_TABLE_CHANGE_WEBHOOK_URL";
+pub const QUERY_TABLE_UPLOAD_MAX_RETRIES: &str = "QUERY_TABLE_UPLOAD_MAX_RETRIES";
+pub const QUERY_TABLE_UPLOAD_RETRY_BACKOFF_MS: &str = "QUERY_TABLE_UPLOAD_RETRY_BACKOFF_MS";
+pub const QUERY_TABLE_UPLOAD_RETRY_MAX_BACKOFF_MS: &str = "QUERY_TABLE_UPLOAD_RETRY_MAX_BACKOFF_MS";
+pub const QUERY_TABLE_UPLOAD_MAX_CONCURRENCY: &str = "QUERY_TABLE_UPLOAD_MAX_CONCURRENCY";
+pub const QUERY_SPILL_DIR: &str = "QUERY_SPILL_DIR";
 
 const QUERY_HTTP_HANDLER_TLS_SERVER_CERT: &str = "QUERY_HTTP_HANDLER_TLS_SERVER_CERT";
 const QUERY_HTTP_HANDLER_TLS_SERVER_KEY: &str = "QUERY_HTTP_HANDLER_TLS_SERVER_KEY";
@@ -59,7 +82,7 @@ const QUERY_TABLE_ENGINE_MEMORY_ENABLED: &str = "QUERY_TABLE_ENGINE_MEMORY_ENABL
 const QUERY_DATABASE_ENGINE_GITHUB_ENABLED: &str = "QUERY_DATABASE_ENGINE_GITHUB_ENABLED";
 
 /// Query config group.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
+#[derive(Clone, PartialEq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct QueryConfig {
     /// Tenant id for get the information from the MetaStore
@@ -172,6 +195,11 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_MAX_QUERY_LOG_SIZE, default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// The table the finished queries are also written into, as `database.table`, created as a
+    /// fuse table if it does not exist. Empty to keep the query log in memory only.
+    #[clap(long, env = QUERY_QUERY_LOG_TABLE, default_value = "")]
+    pub query_log_table: String,
+
     /// Table Cached enabled
     #[clap(long, env = QUERY_TABLE_CACHE_ENABLED)]
     pub table_cache_enabled: bool,
@@ -187,6 +215,99 @@ pub struct QueryConfig {
     /// Table disk cache size (mb)
     #[clap(long, env = QUERY_TABLE_DISK_CACHE_MB_SIZE, default_value = "1024")]
     pub table_disk_cache_mb_size: u64,
+
+    /// The JWKS url used to verify the JWT in the `Authorization: Bearer` header of http handler.
+    /// JWT authentication is disabled if empty.
+    #[clap(long, env = QUERY_JWT_KEY_URL, default_value = "")]
+    pub jwt_key_url: String,
+
+    /// The append-only file the audit events are written to, empty disables the file sink.
+    #[clap(long, env = QUERY_AUDIT_LOG_FILE, default_value = "")]
+    pub audit_log_file: String,
+
+    /// How long the audit events of a tenant are kept in system.audit_log by default,
+    /// a tenant can set its own by `SET GLOBAL audit_log_retention_days`.
+    #[clap(long, env = QUERY_AUDIT_LOG_RETENTION_DAYS, default_value = "30")]
+    pub audit_log_retention_days: u64,
+
+    /// How long dropped databases and tables stay in the recycle bin before they can be vacuumed.
+    /// 0 disables the recycle bin, the data of a dropped table is purged immediately.
+    #[clap(long, env = QUERY_DROP_RETENTION_HOURS, default_value = "24")]
+    pub drop_retention_hours: u64,
+
+    /// How long the history of a table is kept by VACUUM TABLE, so that the table can
+    /// still be read as of any time within it.
+    #[clap(long, env = QUERY_HISTORY_RETENTION_HOURS, default_value = "24")]
+    pub history_retention_hours: u64,
+
+    /// How often the statistics of the tables committed since the last refresh are refreshed
+    /// in background, in seconds. 0 disables the refresh.
+    #[clap(long, env = QUERY_STATISTICS_REFRESH_INTERVAL_SECS, default_value = "0")]
+    pub statistics_refresh_interval_secs: u64,
+
+    /// How often the snapshots of the tables past their retention are expired in background,
+    /// in seconds. 0 disables the expiry, the history is then only removed by VACUUM TABLE.
+    #[clap(long, env = QUERY_SNAPSHOT_EXPIRY_INTERVAL_SECS, default_value = "0")]
+    pub snapshot_expiry_interval_secs: u64,
+
+    /// The key to encrypt the credentials of the connections stored in meta.
+    /// CONNECTION objects can not be created if it is empty.
+    #[clap(long, env = QUERY_CONNECTION_ENCRYPTION_KEY, default_value = "")]
+    pub connection_encryption_key: String,
+
+    /// How long the user infos and the row access policies fetched from meta are cached on
+    /// this node, in seconds. A change made on another node is seen here after it at the latest.
+    /// 0 disables the cache.
+    #[clap(long, env = QUERY_USER_CACHE_TTL_SECS, default_value = "5")]
+    pub user_cache_ttl_secs: u64,
+
+    /// Max number of the parquet footers cached by fuse tables, 0 disables the cache
+    #[clap(long, env = QUERY_TABLE_CACHE_PARQUET_META_COUNT, default_value = "10000")]
+    pub table_cache_parquet_meta_count: u64,
+
+    /// Max number of the deserialized segments cached by fuse tables, 0 disables the cache
+    #[clap(long, env = QUERY_TABLE_CACHE_SEGMENT_INFO_COUNT, default_value = "1000")]
+    pub table_cache_segment_info_count: u64,
+
+    /// The local folder the fuse table blocks fetched from the remote storage are cached in
+    #[clap(long, env = QUERY_TABLE_BLOCK_CACHE_ROOT, default_value = "_block_cache")]
+    pub table_block_cache_root: String,
+
+    /// Size of the local disk cache of fuse table blocks (mb), 0 disables the cache
+    #[clap(long, env = QUERY_TABLE_BLOCK_CACHE_MB_SIZE, default_value = "0")]
+    pub table_block_cache_mb_size: u64,
+
+    /// Rehash the plaintext_password of the users into pbkdf2_sha256_password once they login
+    /// with the plain password. The sha256_password is kept for the MySQL clients.
+    #[clap(long, env = QUERY_USER_PASSWORD_REHASH_ON_LOGIN)]
+    pub user_password_rehash_on_login: bool,
+
+    /// The url the changes committed to the fuse tables are posted to as JSON, with the table id,
+    /// the snapshot id and the row delta. Empty disables the notifications.
+    #[clap(long, env = QUERY_TABLE_CHANGE_WEBHOOK_URL, default_value = "")]
+    pub table_change_webhook_url: String,
+
+    /// How many times a failed upload of the blocks and segments of the fuse tables is retried
+    /// before the write fails, 0 disables the retries.
+    #[clap(long, env = QUERY_TABLE_UPLOAD_MAX_RETRIES, default_value = "5")]
+    pub table_upload_max_retries: u64,
+
+    /// The backoff before the first retry of an upload (ms), doubled by each retry, with jitter.
+    #[clap(long, env = QUERY_TABLE_UPLOAD_RETRY_BACKOFF_MS, default_value = "100")]
+    pub table_upload_retry_backoff_ms: u64,
+
+    /// The max backoff between the retries of an upload (ms).
+    #[clap(long, env = QUERY_TABLE_UPLOAD_RETRY_MAX_BACKOFF_MS, default_value = "10000")]
+    pub table_upload_retry_max_backoff_ms: u64,
+
+    /// Max number of the uploads of the fuse tables in flight on this node, 0 for unlimited.
+    #[clap(long, env = QUERY_TABLE_UPLOAD_MAX_CONCURRENCY, default_value = "0")]
+    pub table_upload_max_concurrency: u64,
+
+    /// The directory the queries spill to, each query in its own sub directory removed when the
+    /// query finishes. The leftovers of a crash are removed on the startup.
+    #[clap(long, env = QUERY_SPILL_DIR, default_value = "_spill")]
+    pub spill_dir: String,
 }
 
 impl Default for QueryConfig {
@@ -221,14 +342,151 @@ impl Default for QueryConfig {
             database_engine_github_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10000,
+            query_log_table: "".to_string(),
             table_cache_enabled: false,
             table_memory_cache_mb_size: 256,
             table_disk_cache_root: "_cache".to_string(),
             table_disk_cache_mb_size: 1024,
+            jwt_key_url: "".to_string(),
+            audit_log_file: "".to_string(),
+            audit_log_retention_days: 30,
+            drop_retention_hours: 24,
+            history_retention_hours: 24,
+            statistics_refresh_interval_secs: 0,
+            snapshot_expiry_interval_secs: 0,
+            connection_encryption_key: "".to_string(),
+            user_cache_ttl_secs: 5,
+            table_cache_parquet_meta_count: 10000,
+            table_cache_segment_info_count: 1000,
+            table_block_cache_root: "_block_cache".to_string(),
+            table_block_cache_mb_size: 0,
+            user_password_rehash_on_login: false,
+            table_change_webhook_url: "".to_string(),
+            table_upload_max_retries: 5,
+            table_upload_retry_backoff_ms: 100,
+            table_upload_retry_max_backoff_ms: 10000,
+            table_upload_max_concurrency: 0,
+            spill_dir: "_spill".to_string(),
         }
     }
 }
 
+// The connection_encryption_key is masked, the config is logged at startup.
+impl fmt::Debug for QueryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueryConfig")
+            .field("tenant_id", &self.tenant_id)
+            .field("cluster_id", &self.cluster_id)
+            .field("num_cpus", &self.num_cpus)
+            .field("mysql_handler_host", &self.mysql_handler_host)
+            .field("mysql_handler_port", &self.mysql_handler_port)
+            .field("max_active_sessions", &self.max_active_sessions)
+            .field("clickhouse_handler_host", &self.clickhouse_handler_host)
+            .field("clickhouse_handler_port", &self.clickhouse_handler_port)
+            .field("http_handler_host", &self.http_handler_host)
+            .field("http_handler_port", &self.http_handler_port)
+            .field("flight_api_address", &self.flight_api_address)
+            .field("http_api_address", &self.http_api_address)
+            .field("metric_api_address", &self.metric_api_address)
+            .field(
+                "http_handler_tls_server_cert",
+                &self.http_handler_tls_server_cert,
+            )
+            .field(
+                "http_handler_tls_server_key",
+                &self.http_handler_tls_server_key,
+            )
+            .field(
+                "http_handler_tls_server_root_ca_cert",
+                &self.http_handler_tls_server_root_ca_cert,
+            )
+            .field("api_tls_server_cert", &self.api_tls_server_cert)
+            .field("api_tls_server_key", &self.api_tls_server_key)
+            .field(
+                "api_tls_server_root_ca_cert",
+                &self.api_tls_server_root_ca_cert,
+            )
+            .field("rpc_tls_server_cert", &self.rpc_tls_server_cert)
+            .field("rpc_tls_server_key", &self.rpc_tls_server_key)
+            .field(
+                "rpc_tls_query_server_root_ca_cert",
+                &self.rpc_tls_query_server_root_ca_cert,
+            )
+            .field(
+                "rpc_tls_query_service_domain_name",
+                &self.rpc_tls_query_service_domain_name,
+            )
+            .field("table_engine_csv_enabled", &self.table_engine_csv_enabled)
+            .field(
+                "table_engine_parquet_enabled",
+                &self.table_engine_parquet_enabled,
+            )
+            .field(
+                "table_engine_memory_enabled",
+                &self.table_engine_memory_enabled,
+            )
+            .field(
+                "database_engine_github_enabled",
+                &self.database_engine_github_enabled,
+            )
+            .field("wait_timeout_mills", &self.wait_timeout_mills)
+            .field("max_query_log_size", &self.max_query_log_size)
+            .field("query_log_table", &self.query_log_table)
+            .field("table_cache_enabled", &self.table_cache_enabled)
+            .field(
+                "table_memory_cache_mb_size",
+                &self.table_memory_cache_mb_size,
+            )
+            .field("table_disk_cache_root", &self.table_disk_cache_root)
+            .field("table_disk_cache_mb_size", &self.table_disk_cache_mb_size)
+            .field("jwt_key_url", &self.jwt_key_url)
+            .field("audit_log_file", &self.audit_log_file)
+            .field("audit_log_retention_days", &self.audit_log_retention_days)
+            .field("drop_retention_hours", &self.drop_retention_hours)
+            .field("history_retention_hours", &self.history_retention_hours)
+            .field(
+                "statistics_refresh_interval_secs",
+                &self.statistics_refresh_interval_secs,
+            )
+            .field(
+                "snapshot_expiry_interval_secs",
+                &self.snapshot_expiry_interval_secs,
+            )
+            .field("connection_encryption_key", &"******")
+            .field("user_cache_ttl_secs", &self.user_cache_ttl_secs)
+            .field(
+                "table_cache_parquet_meta_count",
+                &self.table_cache_parquet_meta_count,
+            )
+            .field(
+                "table_cache_segment_info_count",
+                &self.table_cache_segment_info_count,
+            )
+            .field("table_block_cache_root", &self.table_block_cache_root)
+            .field("table_block_cache_mb_size", &self.table_block_cache_mb_size)
+            .field(
+                "user_password_rehash_on_login",
+                &self.user_password_rehash_on_login,
+            )
+            .field("table_change_webhook_url", &self.table_change_webhook_url)
+            .field("table_upload_max_retries", &self.table_upload_max_retries)
+            .field(
+                "table_upload_retry_backoff_ms",
+                &self.table_upload_retry_backoff_ms,
+            )
+            .field(
+                "table_upload_retry_max_backoff_ms",
+                &self.table_upload_retry_max_backoff_ms,
+            )
+            .field(
+                "table_upload_max_concurrency",
+                &self.table_upload_max_concurrency,
+            )
+            .field("spill_dir", &self.spill_dir)
+            .finish()
+    }
+}
+
 impl QueryConfig {
     pub fn load_from_env(mut_config: &mut Config) {
         env_helper!(mut_config, query, tenant_id, String, QUERY_TENANT_ID);
@@ -379,6 +637,13 @@ impl QueryConfig {
             usize,
             QUERY_MAX_QUERY_LOG_SIZE
         );
+        env_helper!(
+            mut_config,
+            query,
+            query_log_table,
+            String,
+            QUERY_QUERY_LOG_TABLE
+        );
         env_helper!(
             mut_config,
             query,
@@ -435,5 +700,133 @@ impl QueryConfig {
             u64,
             QUERY_TABLE_DISK_CACHE_MB_SIZE
         );
+        env_helper!(mut_config, query, jwt_key_url, String, QUERY_JWT_KEY_URL);
+        env_helper!(
+            mut_config,
+            query,
+            audit_log_file,
+            String,
+            QUERY_AUDIT_LOG_FILE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            audit_log_retention_days,
+            u64,
+            QUERY_AUDIT_LOG_RETENTION_DAYS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            drop_retention_hours,
+            u64,
+            QUERY_DROP_RETENTION_HOURS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            history_retention_hours,
+            u64,
+            QUERY_HISTORY_RETENTION_HOURS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            statistics_refresh_interval_secs,
+            u64,
+            QUERY_STATISTICS_REFRESH_INTERVAL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            snapshot_expiry_interval_secs,
+            u64,
+            QUERY_SNAPSHOT_EXPIRY_INTERVAL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            connection_encryption_key,
+            String,
+            QUERY_CONNECTION_ENCRYPTION_KEY
+        );
+        env_helper!(
+            mut_config,
+            query,
+            user_cache_ttl_secs,
+            u64,
+            QUERY_USER_CACHE_TTL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_cache_parquet_meta_count,
+            u64,
+            QUERY_TABLE_CACHE_PARQUET_META_COUNT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_cache_segment_info_count,
+            u64,
+            QUERY_TABLE_CACHE_SEGMENT_INFO_COUNT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_block_cache_root,
+            String,
+            QUERY_TABLE_BLOCK_CACHE_ROOT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_block_cache_mb_size,
+            u64,
+            QUERY_TABLE_BLOCK_CACHE_MB_SIZE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            user_password_rehash_on_login,
+            bool,
+            QUERY_USER_PASSWORD_REHASH_ON_LOGIN
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_change_webhook_url,
+            String,
+            QUERY_TABLE_CHANGE_WEBHOOK_URL
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_upload_max_retries,
+            u64,
+            QUERY_TABLE_UPLOAD_MAX_RETRIES
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_upload_retry_backoff_ms,
+            u64,
+            QUERY_TABLE_UPLOAD_RETRY_BACKOFF_MS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_upload_retry_max_backoff_ms,
+            u64,
+            QUERY_TABLE_UPLOAD_RETRY_MAX_BACKOFF_MS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_upload_max_concurrency,
+            u64,
+            QUERY_TABLE_UPLOAD_MAX_CONCURRENCY
+        );
+        env_helper!(mut_config, query, spill_dir, String, QUERY_SPILL_DIR);
     }
 }
@@ -19,6 +19,7 @@ use common_meta_types::DatabaseInfo;
 use common_meta_types::DatabaseMeta;
 
 use crate::catalogs::InMemoryMetas;
+use crate::configs::Config;
 use crate::databases::Database;
 use crate::storages::system;
 use crate::storages::Table;
@@ -29,7 +30,7 @@ pub struct SystemDatabase {
 }
 
 impl SystemDatabase {
-    pub fn create(sys_db_meta: &mut InMemoryMetas) -> Self {
+    pub fn create(sys_db_meta: &mut InMemoryMetas, conf: &Config) -> Self {
         let table_list: Vec<Arc<dyn Table>> = vec![
             Arc::new(system::OneTable::create(sys_db_meta.next_id())),
             Arc::new(system::FunctionsTable::create(sys_db_meta.next_id())),
@@ -45,7 +46,17 @@ impl SystemDatabase {
             Arc::new(system::MetricsTable::create(sys_db_meta.next_id())),
             Arc::new(system::ColumnsTable::create(sys_db_meta.next_id())),
             Arc::new(system::UsersTable::create(sys_db_meta.next_id())),
-            Arc::new(system::QueryLogTable::create(sys_db_meta.next_id())),
+            Arc::new(system::QueryLogTable::create(
+                sys_db_meta.next_id(),
+                conf.query.max_query_log_size,
+            )),
+            Arc::new(system::QueryProfileTable::create(sys_db_meta.next_id())),
+            Arc::new(system::AuditLogTable::create(sys_db_meta.next_id())),
+            Arc::new(system::SettingsHistoryTable::create(sys_db_meta.next_id())),
+            Arc::new(system::StorageUsageTable::create(sys_db_meta.next_id())),
+            Arc::new(system::ColumnStatisticsTable::create(sys_db_meta.next_id())),
+            Arc::new(system::BuildOptionsTable::create(sys_db_meta.next_id())),
+            Arc::new(system::EnginesTable::create(sys_db_meta.next_id())),
         ];
 
         for tbl in table_list.into_iter() {
@@ -0,0 +1,203 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DateTimeOutput;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+/// How the rows are written as CSV, told by the options of `COPY INTO <location>` and of the
+/// HTTP downloads. The names of the options are case insensitive:
+///
+/// - `csv_header`: `1` to write the names of the columns first.
+/// - `field_delimitor`, `record_delimitor`: the first char of each is used.
+/// - `line_ending`: `lf` or `crlf`, overrides `record_delimitor`.
+/// - `field_enclosure`: the char the fields are quoted with, `"` by default.
+/// - `enclose_all`: `1` to quote all the fields, instead of the ones that need to be only.
+/// - `decimal_separator`: the char the fractional part of the floats is separated by.
+/// - `null_display`: the text of NULLs, empty by default.
+/// - `bom`: `1` to start with the UTF-8 byte order mark, so that Excel tells the encoding.
+///
+/// The DateTime values are rendered as told by `datetime_output`, which is not an option but
+/// taken from the settings of the session by the callers that have one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvOutputOptions {
+    pub header: bool,
+    pub field_delimiter: u8,
+    pub record_delimiter: Vec<u8>,
+    pub field_enclosure: u8,
+    pub enclose_all: bool,
+    pub decimal_separator: u8,
+    pub null_display: String,
+    pub bom: bool,
+    pub datetime_output: DateTimeOutput,
+}
+
+impl Default for CsvOutputOptions {
+    fn default() -> Self {
+        CsvOutputOptions {
+            header: false,
+            field_delimiter: b',',
+            record_delimiter: vec![b'\n'],
+            field_enclosure: b'"',
+            enclose_all: false,
+            decimal_separator: b'.',
+            null_display: String::new(),
+            bom: false,
+            datetime_output: DateTimeOutput::default(),
+        }
+    }
+}
+
+impl CsvOutputOptions {
+    pub fn from_options(options: &HashMap<String, String>) -> Result<Self> {
+        let option = |name: &str| {
+            options
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        };
+        let flag = |name: &str| option(name).map(|v| v == "1").unwrap_or(false);
+        let char = |name: &str, default: u8| {
+            option(name)
+                .and_then(|v| v.as_bytes().first().cloned())
+                .unwrap_or(default)
+        };
+
+        let record_delimiter = match option("line_ending") {
+            None => vec![char("record_delimitor", b'\n')],
+            Some(v) if v.eq_ignore_ascii_case("lf") => vec![b'\n'],
+            Some(v) if v.eq_ignore_ascii_case("crlf") => vec![b'\r', b'\n'],
+            Some(v) => {
+                return Err(ErrorCode::BadOption(format!(
+                    "line_ending must be one of lf, crlf, but got {}",
+                    v
+                )))
+            }
+        };
+
+        Ok(CsvOutputOptions {
+            header: flag("csv_header"),
+            field_delimiter: char("field_delimitor", b','),
+            record_delimiter,
+            field_enclosure: char("field_enclosure", b'"'),
+            enclose_all: flag("enclose_all"),
+            decimal_separator: char("decimal_separator", b'.'),
+            null_display: option("null_display").unwrap_or_default().to_string(),
+            bom: flag("bom"),
+            datetime_output: DateTimeOutput::default(),
+        })
+    }
+}
+
+/// Writes the blocks of the schema as CSV.
+pub struct CsvOutputFormat {
+    schema: DataSchemaRef,
+    options: CsvOutputOptions,
+}
+
+impl CsvOutputFormat {
+    pub fn create(schema: DataSchemaRef, options: CsvOutputOptions) -> Self {
+        CsvOutputFormat { schema, options }
+    }
+
+    /// The start of a file, the byte order mark and the header, as told by the options.
+    pub fn serialize_prefix(&self, buf: &mut Vec<u8>) {
+        if self.options.bom {
+            buf.extend_from_slice(UTF8_BOM);
+        }
+        if self.options.header {
+            let names = self.schema.fields().iter().map(|f| f.name().as_str());
+            self.write_record(names.map(Some), buf);
+        }
+    }
+
+    pub fn serialize_block(&self, block: &DataBlock, buf: &mut Vec<u8>) -> Result<()> {
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(block.columns())
+            .map(|(f, column)| {
+                let mut values = f
+                    .data_type()
+                    .create_serializer_with(&self.options.datetime_output)
+                    .serialize_column(column)?;
+                if f.data_type().is_floating() && self.options.decimal_separator != b'.' {
+                    let separator = (self.options.decimal_separator as char).to_string();
+                    for value in values.iter_mut() {
+                        *value = value.replace('.', &separator);
+                    }
+                }
+                Ok((column.to_array()?, values))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for row in 0..block.num_rows() {
+            let values = columns.iter().map(|(series, values)| {
+                if series.is_null(row) {
+                    None
+                } else {
+                    Some(values[row].as_str())
+                }
+            });
+            self.write_record(values, buf);
+        }
+        Ok(())
+    }
+
+    fn write_record<'a>(&self, values: impl Iterator<Item = Option<&'a str>>, buf: &mut Vec<u8>) {
+        for (i, value) in values.enumerate() {
+            if i > 0 {
+                buf.push(self.options.field_delimiter);
+            }
+            match value {
+                Some(value) => self.write_field(value, buf),
+                None => buf.extend_from_slice(self.options.null_display.as_bytes()),
+            }
+        }
+        buf.extend_from_slice(&self.options.record_delimiter);
+    }
+
+    // The fields holding the delimiters, the enclosure or line breaks are quoted.
+    fn write_field(&self, value: &str, buf: &mut Vec<u8>) {
+        let enclosure = self.options.field_enclosure;
+        let quoted = self.options.enclose_all
+            || value.bytes().any(|b| {
+                b == self.options.field_delimiter
+                    || self.options.record_delimiter.contains(&b)
+                    || b == enclosure
+                    || b == b'\n'
+                    || b == b'\r'
+            });
+        if !quoted {
+            buf.extend_from_slice(value.as_bytes());
+            return;
+        }
+
+        buf.push(enclosure);
+        for b in value.bytes() {
+            if b == enclosure {
+                buf.push(enclosure);
+            }
+            buf.push(b);
+        }
+        buf.push(enclosure);
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AlterOwnerPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::interpreter_common::ownership_object_exists_or_err;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct AlterOwnerInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterOwnerPlan,
+}
+
+impl AlterOwnerInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterOwnerPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(AlterOwnerInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterOwnerInterpreter {
+    fn name(&self) -> &str {
+        "AlterOwnerInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+        ownership_object_exists_or_err(&self.ctx, &plan.object).await?;
+
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr
+            .get_user(&plan.owner.username, &plan.owner.hostname)
+            .await?;
+
+        // The owner, or a user with GRANT on *.*, can transfer the ownership.
+        // An object without owner can only be taken over by the latter.
+        let user = self.ctx.get_current_user_with_roles().await?;
+        match user_mgr.get_object_owner(&plan.object).await? {
+            Some(_) => {
+                user_mgr
+                    .verify_ownership(&plan.object, Some(&user), UserPrivilegeType::Grant)
+                    .await?
+            }
+            None => {
+                if !user.grants.verify_global_privilege(
+                    &user.name,
+                    &user.hostname,
+                    UserPrivilegeType::Grant,
+                ) {
+                    return Err(ErrorCode::PermissionDenied(format!(
+                        "Permission denied, {} has no owner, '{}'@'{}' needs to have {} privilege on *.*",
+                        plan.object, user.name, user.hostname, UserPrivilegeType::Grant
+                    )));
+                }
+            }
+        }
+
+        user_mgr
+            .grant_ownership(&plan.object, &plan.owner, false)
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
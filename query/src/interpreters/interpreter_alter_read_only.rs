@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::OwnershipObject;
+use common_meta_types::ReadOnlyObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AlterReadOnlyPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::interpreter_common::grant_object_exists_or_err;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct AlterReadOnlyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterReadOnlyPlan,
+}
+
+impl AlterReadOnlyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterReadOnlyPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(AlterReadOnlyInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterReadOnlyInterpreter {
+    fn name(&self) -> &str {
+        "AlterReadOnlyInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let user = self.ctx.get_current_user_with_roles().await?;
+
+        // The maintenance mode needs SUPER on *.*, a database or a table needs to be owned.
+        match &plan.object {
+            ReadOnlyObject::Cluster => {
+                if !user.grants.verify_global_privilege(
+                    &user.name,
+                    &user.hostname,
+                    UserPrivilegeType::Super,
+                ) {
+                    return Err(ErrorCode::PermissionDenied(format!(
+                        "Permission denied, '{}'@'{}' needs to have {} privilege on *.*",
+                        user.name,
+                        user.hostname,
+                        UserPrivilegeType::Super
+                    )));
+                }
+            }
+            ReadOnlyObject::Database(db) => {
+                grant_object_exists_or_err(&self.ctx, &GrantObject::Database(db.clone())).await?;
+                let object = OwnershipObject::Database(db.clone());
+                user_mgr
+                    .verify_ownership(&object, Some(&user), UserPrivilegeType::Alter)
+                    .await?;
+            }
+            ReadOnlyObject::Table(db, table) => {
+                let object = GrantObject::Table(db.clone(), table.clone());
+                grant_object_exists_or_err(&self.ctx, &object).await?;
+                let object = OwnershipObject::Table(db.clone(), table.clone());
+                user_mgr
+                    .verify_ownership(&object, Some(&user), UserPrivilegeType::Alter)
+                    .await?;
+            }
+        }
+
+        user_mgr.set_read_only(&plan.object, plan.read_only).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_meta_types::GrantObject;
+use common_meta_types::OwnershipObject;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
@@ -43,8 +44,47 @@ pub async fn grant_object_exists_or_err(
                 )));
             }
         }
+        GrantObject::Connection(connection_name) => {
+            let user_mgr = ctx.get_sessions_manager().get_user_manager();
+            user_mgr.get_encrypted_connection(connection_name).await?;
+        }
+        GrantObject::Stage(stage_name) => {
+            let user_mgr = ctx.get_sessions_manager().get_user_manager();
+            user_mgr.get_stage(stage_name).await?;
+        }
+        GrantObject::UDF(udf_name) => {
+            let user_mgr = ctx.get_sessions_manager().get_user_manager();
+            user_mgr.get_udf(udf_name).await?;
+        }
         GrantObject::Global => (),
     }
 
     Ok(())
 }
+
+pub async fn ownership_object_exists_or_err(
+    ctx: &Arc<QueryContext>,
+    object: &OwnershipObject,
+) -> Result<()> {
+    match &object {
+        OwnershipObject::Database(database_name) => {
+            grant_object_exists_or_err(ctx, &GrantObject::Database(database_name.clone())).await
+        }
+        OwnershipObject::Table(database_name, table_name) => {
+            let object = GrantObject::Table(database_name.clone(), table_name.clone());
+            grant_object_exists_or_err(ctx, &object).await
+        }
+        OwnershipObject::Stage(stage_name) => {
+            let object = GrantObject::Stage(stage_name.clone());
+            grant_object_exists_or_err(ctx, &object).await
+        }
+        OwnershipObject::UDF(udf_name) => {
+            let object = GrantObject::UDF(udf_name.clone());
+            grant_object_exists_or_err(ctx, &object).await
+        }
+        OwnershipObject::Connection(connection_name) => {
+            let object = GrantObject::Connection(connection_name.clone());
+            grant_object_exists_or_err(ctx, &object).await
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_planners::CreateConnectionPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct CreateConnectionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateConnectionPlan,
+}
+
+impl CreateConnectionInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: CreateConnectionPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CreateConnectionInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateConnectionInterpreter {
+    fn name(&self) -> &str {
+        "CreateConnectionInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let object = OwnershipObject::Connection(plan.connection.name.clone());
+        let add_connection = user_mgr.add_connection(plan.connection).await;
+        match add_connection {
+            Err(e)
+                if plan.if_not_exists && e.code() == ErrorCode::ConnectionAlreadyExistsCode() => {}
+            res => {
+                res?;
+            }
+        }
+
+        if let Ok(user) = self.ctx.get_current_user() {
+            user_mgr
+                .grant_ownership(&object, &user.identity(), plan.if_not_exists)
+                .await?;
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -12,27 +12,62 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_dal::DataAccessor;
 use common_dal::S3;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::Compression;
+use common_meta_types::FileFormat;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserStageInfo;
 use common_planners::CopyPlan;
+use common_streams::BadRows;
 use common_streams::DataBlockStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_streams::SourceFactory;
 use common_streams::SourceParams;
 use common_streams::SourceStream;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_until;
 use nom::IResult;
 
+use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::transforms::AddOnStream;
 use crate::sessions::QueryContext;
+use crate::storages::StorageOperation;
+use crate::storages::Table;
+
+/// The files of an internal stage are kept in the storage of the query, under `stage/<name>/`.
+const INTERNAL_STAGE_PREFIX: &str = "stage";
+
+const LOADED: &str = "LOADED";
+const PARTIALLY_LOADED: &str = "PARTIALLY_LOADED";
+const LOAD_FAILED: &str = "LOAD_FAILED";
+
+/// What to do with a file having malformed rows, set by the option `on_error`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OnError {
+    /// Fail the statement, the default
+    Abort,
+    /// Skip the malformed rows, the file fails once it has more than `max_errors` of them
+    Continue,
+    /// Skip the file, the other files are still loaded
+    SkipFile,
+}
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -43,6 +78,79 @@ impl CopyInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CopyPlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(CopyInterpreter { ctx, plan }))
     }
+
+    fn on_error(options: &HashMap<String, String>) -> Result<(OnError, usize)> {
+        let option = |name: &str| {
+            options
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.to_lowercase())
+        };
+
+        let on_error = match option("on_error").as_deref() {
+            None | Some("abort") => OnError::Abort,
+            Some("continue") => OnError::Continue,
+            Some("skip_file") => OnError::SkipFile,
+            Some(other) => {
+                return Err(ErrorCode::BadOption(format!(
+                    "Unknown on_error: {}, expecting one of abort, continue, skip_file",
+                    other
+                )))
+            }
+        };
+        let max_errors = match option("max_errors") {
+            None => usize::MAX,
+            Some(v) => v.parse::<usize>().map_err(|_| {
+                ErrorCode::BadOption(format!("max_errors must be a number, but got {}", v))
+            })?,
+        };
+        Ok((on_error, max_errors))
+    }
+
+    // Append the rows of a file, the blocks written are committed along with the other files.
+    async fn load_file(
+        &self,
+        table: &Arc<dyn Table>,
+        acc: Arc<dyn DataAccessor>,
+        path: &str,
+        options: &HashMap<String, String>,
+        bad_rows: Option<Arc<BadRows>>,
+        rows_loaded: Arc<AtomicUsize>,
+    ) -> Result<Vec<DataBlock>> {
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let source_params = SourceParams {
+            acc,
+            path,
+            format: self.plan.format.as_str(),
+            schema: self.plan.schema.clone(),
+            max_block_size,
+            projection: (0..self.plan.schema.fields().len()).collect(),
+            options,
+            bad_rows,
+        };
+        let source_stream = SourceStream::new(SourceFactory::try_get(source_params)?);
+        let input_stream = source_stream.execute().await?.map(move |block| {
+            if let Ok(block) = &block {
+                rows_loaded.fetch_add(block.num_rows(), Ordering::Relaxed);
+            }
+            block
+        });
+        // The columns not loaded from the files are filled with their defaults, the sources
+        // matching the columns by name may load fewer columns than the plan.
+        let input_stream =
+            AddOnStream::try_create_by_first_block(Box::pin(input_stream), table.schema()).await?;
+
+        let progress_stream = Box::pin(ProgressStream::try_create(
+            input_stream,
+            self.ctx.get_scan_progress(),
+        )?);
+
+        table
+            .append_data(self.ctx.clone(), progress_stream)
+            .await?
+            .try_collect()
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -59,6 +167,13 @@ impl Interpreter for CopyInterpreter {
             .ctx
             .get_table(&self.plan.db_name, &self.plan.tbl_name)
             .await?;
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr
+            .verify_writable(&self.plan.db_name, &self.plan.tbl_name)
+            .await?;
+        self.ctx
+            .get_catalog()
+            .check_table_supports(table.as_ref(), StorageOperation::Append)?;
 
         let location = self.plan.location.clone();
         let c = extract_stage_location(location.as_str());
@@ -69,49 +184,223 @@ impl Interpreter for CopyInterpreter {
         }
         let (stage, path) = c.unwrap();
 
-        let acc = get_dal_by_stage(self.ctx.clone(), stage)?;
-        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
-        let source_params = SourceParams {
-            acc,
-            path,
-            format: self.plan.format.as_str(),
-            schema: self.plan.schema.clone(),
-            max_block_size,
-            projection: (0..self.plan.schema().fields().len()).collect(),
-            options: &self.plan.options,
+        let connection = self
+            .plan
+            .options
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("connection"));
+        let (acc, stage_info) = match connection {
+            Some((_, name)) => (get_dal_by_connection(self.ctx.clone(), name).await?, None),
+            None => match verify_stage_usage(self.ctx.clone(), stage).await? {
+                Some(info) => (
+                    get_dal_by_stage_info(self.ctx.clone(), &info).await?,
+                    Some(info),
+                ),
+                None => (get_dal_by_stage(self.ctx.clone(), stage)?, None),
+            },
         };
-        let source_stream = SourceStream::new(SourceFactory::try_get(source_params)?);
-        let input_stream = source_stream.execute().await?;
-        let progress_stream = Box::pin(ProgressStream::try_create(
-            input_stream,
-            self.ctx.get_scan_progress(),
-        )?);
 
-        let r = table
-            .append_data(self.ctx.clone(), progress_stream)
-            .await?
-            .try_collect()
-            .await?;
-        table.commit(self.ctx.clone(), r, false).await?;
+        // the options of the statement override the file format of the stage
+        let (root, options) = match &stage_info {
+            Some(info) => (
+                stage_root(info),
+                with_stage_file_format(&self.plan.options, &info.file_format),
+            ),
+            None => (String::new(), self.plan.options.clone()),
+        };
+        let path = format!("{}{}", root, path.trim_start_matches('/'));
+        let files = if path.ends_with('/') {
+            acc.list(&path).await?
+        } else {
+            vec![path]
+        };
+        let (on_error, max_errors) = Self::on_error(&options)?;
+
+        let mut operations = vec![];
+        let mut report = CopyReport::default();
+        for file in files {
+            let name = file.strip_prefix(&root).unwrap_or(&file).to_string();
+            let bad_rows = match on_error {
+                OnError::Continue => Some(BadRows::create(max_errors)),
+                _ => None,
+            };
+            let rows_loaded = Arc::new(AtomicUsize::new(0));
+
+            let loaded = self
+                .load_file(
+                    &table,
+                    acc.clone(),
+                    &file,
+                    &options,
+                    bad_rows.clone(),
+                    rows_loaded.clone(),
+                )
+                .await;
+            let errors_seen = bad_rows.as_ref().map(|b| b.count()).unwrap_or(0);
+            match loaded {
+                Ok(r) => {
+                    operations.extend(r);
+                    let status = if errors_seen == 0 {
+                        LOADED
+                    } else {
+                        PARTIALLY_LOADED
+                    };
+                    let first_error = bad_rows.and_then(|b| b.first_error());
+                    let rows = rows_loaded.load(Ordering::Relaxed);
+                    report.push(name, status, rows, errors_seen, first_error);
+                }
+                Err(e) if on_error == OnError::Abort => {
+                    return Err(e.add_message_back(format!(", file: {}", name)));
+                }
+                Err(e) => {
+                    // the blocks of the file written so far are left behind, never committed
+                    let first_error = bad_rows
+                        .and_then(|b| b.first_error())
+                        .unwrap_or_else(|| e.message());
+                    report.push(name, LOAD_FAILED, 0, errors_seen.max(1), Some(first_error));
+                }
+            }
+        }
 
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+        if !operations.is_empty() {
+            table.commit(self.ctx.clone(), operations, false).await?;
+        }
+
+        let schema = self.plan.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(report.files),
+            Series::new(report.statuses),
+            Series::new(report.rows_loaded),
+            Series::new(report.errors_seen),
+            Series::new(report.first_errors),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
     }
 }
 
-/// @my_ext_stage/tutorials/sample.csv -> stage: my_ext_stage,  location: /tutorials/sample.csv
-fn extract_stage_location(path: &str) -> IResult<&str, &str> {
+#[derive(Default)]
+struct CopyReport {
+    files: Vec<Vec<u8>>,
+    statuses: Vec<&'static [u8]>,
+    rows_loaded: Vec<u64>,
+    errors_seen: Vec<u64>,
+    first_errors: Vec<Option<Vec<u8>>>,
+}
+
+impl CopyReport {
+    fn push(
+        &mut self,
+        file: String,
+        status: &'static str,
+        rows_loaded: usize,
+        errors_seen: usize,
+        first_error: Option<String>,
+    ) {
+        self.files.push(file.into_bytes());
+        self.statuses.push(status.as_bytes());
+        self.rows_loaded.push(rows_loaded as u64);
+        self.errors_seen.push(errors_seen as u64);
+        self.first_errors.push(first_error.map(|e| e.into_bytes()));
+    }
+}
+
+// The options of the statement not given are taken from the file format of the stage.
+fn with_stage_file_format(
+    options: &HashMap<String, String>,
+    file_format: &FileFormat,
+) -> HashMap<String, String> {
+    let mut options = options.clone();
+    let mut set_default = |name: &str, value: String| {
+        if !options.keys().any(|k| k.eq_ignore_ascii_case(name)) {
+            options.insert(name.to_string(), value);
+        }
+    };
+
+    set_default("field_delimitor", file_format.field_delimiter.clone());
+    set_default("record_delimitor", file_format.record_delimiter.clone());
+    if file_format.csv_header {
+        set_default("csv_header", "1".to_string());
+    }
+    // the files are decompressed by their extensions unless told
+    let compression = match &file_format.compression {
+        Compression::Auto | Compression::None => "auto".to_string(),
+        other => format!("{:?}", other).to_lowercase(),
+    };
+    set_default("compression", compression);
+    options
+}
+
+/// The root of the files of a stage in its storage, ends with `/` unless empty.
+pub(crate) fn stage_root(stage: &UserStageInfo) -> String {
+    if stage.stage_params.url.is_empty() {
+        format!("{}/{}/", INTERNAL_STAGE_PREFIX, stage.stage_name)
+    } else {
+        String::new()
+    }
+}
+
+pub(crate) fn extract_stage_location(path: &str) -> IResult<&str, &str> {
     let (path, _) = tag("@")(path)?;
     let (path, stage) = take_until("/")(path)?;
     Ok((stage, path))
 }
 
+/// The data accessor of the stage of a location `@stage/path`, with the path in it.
+pub(crate) async fn stage_location_dal(
+    ctx: Arc<QueryContext>,
+    location: &str,
+) -> Result<(Arc<dyn DataAccessor>, String)> {
+    let (stage, path) = extract_stage_location(location)
+        .map_err(|_| ErrorCode::BadOption("Cannot convert value to stage and path"))?;
+    match verify_stage_usage(ctx.clone(), stage).await? {
+        Some(info) => {
+            let path = format!("{}{}", stage_root(&info), path.trim_start_matches('/'));
+            Ok((get_dal_by_stage_info(ctx, &info).await?, path))
+        }
+        None => Ok((get_dal_by_stage(ctx, stage)?, path.to_string())),
+    }
+}
+
+// Only the stages created in meta are access-controlled, the others are still
+// read with the storage config. Returns the stage if it is created in meta.
+pub(crate) async fn verify_stage_usage(
+    ctx: Arc<QueryContext>,
+    stage_name: &str,
+) -> Result<Option<UserStageInfo>> {
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    match user_mgr.get_stage(stage_name).await {
+        Ok(stage) => {
+            let user = ctx.get_current_user_with_roles().await.ok();
+            let object = OwnershipObject::Stage(stage_name.to_string());
+            user_mgr.verify_usage(&object, user.as_ref()).await?;
+            Ok(Some(stage))
+        }
+        Err(e) if e.code() == ErrorCode::UnknownStageCode() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// The stages created with a connection are accessed with the credentials of the connection.
+pub(crate) async fn get_dal_by_stage_info(
+    ctx: Arc<QueryContext>,
+    stage: &UserStageInfo,
+) -> Result<Arc<dyn DataAccessor>> {
+    if stage.stage_params.url.is_empty() {
+        // internal stage
+        return ctx.get_data_accessor();
+    }
+    match &stage.stage_params.connection {
+        Some(connection) => get_dal_by_connection(ctx, connection).await,
+        None => get_dal_by_stage(ctx, &stage.stage_name),
+    }
+}
+
 //  this is mock implementation from env
 //  todo: support get the stage config from metadata
-fn get_dal_by_stage(ctx: Arc<QueryContext>, _stage_name: &str) -> Result<Arc<dyn DataAccessor>> {
+pub(crate) fn get_dal_by_stage(
+    ctx: Arc<QueryContext>,
+    _stage_name: &str,
+) -> Result<Arc<dyn DataAccessor>> {
     let conf = ctx.get_config().storage.s3;
 
     Ok(Arc::new(S3::try_create(
@@ -123,3 +412,39 @@ fn get_dal_by_stage(ctx: Arc<QueryContext>, _stage_name: &str) -> Result<Arc<dyn
         conf.enable_pod_iam_policy,
     )?))
 }
+
+// The params missing from the connection, e.g. the bucket, fall back to the storage config.
+pub(crate) async fn get_dal_by_connection(
+    ctx: Arc<QueryContext>,
+    name: &str,
+) -> Result<Arc<dyn DataAccessor>> {
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    let user = ctx.get_current_user_with_roles().await.ok();
+    user_mgr
+        .verify_connection_usage(name, user.as_ref())
+        .await?;
+
+    let connection = user_mgr.get_connection(name).await?;
+    if connection.storage_type != "s3" {
+        return Err(ErrorCode::UnImplement(format!(
+            "Storage type '{}' of connection {} is not supported by COPY yet",
+            connection.storage_type, name
+        )));
+    }
+
+    let conf = ctx.get_config().storage.s3;
+    let option = |options: &BTreeMap<String, String>, key: &str, default: &str| {
+        options
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    };
+    Ok(Arc::new(S3::try_create(
+        &option(&connection.params, "region", &conf.region),
+        &option(&connection.params, "endpoint_url", &conf.endpoint_url),
+        &option(&connection.params, "bucket", &conf.bucket),
+        &option(&connection.credentials, "access_key_id", ""),
+        &option(&connection.credentials, "secret_access_key", ""),
+        false,
+    )?))
+}
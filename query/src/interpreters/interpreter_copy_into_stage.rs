@@ -0,0 +1,283 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CopyIntoStagePlan;
+use common_planners::PlanNode;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
+
+use crate::formats::CsvOutputFormat;
+use crate::formats::CsvOutputOptions;
+use crate::interpreters::interpreter_copy::extract_stage_location;
+use crate::interpreters::interpreter_copy::get_dal_by_stage_info;
+use crate::interpreters::interpreter_copy::stage_root;
+use crate::interpreters::interpreter_copy::verify_stage_usage;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::write_blocks;
+use crate::storages::fuse::io::BlockCompression;
+
+/// The size of the files written by default, the size of a parquet file is told by the size
+/// of its blocks in memory, before encoding and compression.
+const DEFAULT_MAX_FILE_SIZE: usize = 64 * 1024 * 1024;
+
+pub struct CopyIntoStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CopyIntoStagePlan,
+}
+
+impl CopyIntoStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CopyIntoStagePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CopyIntoStageInterpreter { ctx, plan }))
+    }
+
+    fn option(&self, name: &str) -> Option<&String> {
+        self.plan
+            .options
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    fn max_file_size(&self) -> Result<usize> {
+        match self.option("max_file_size") {
+            None => Ok(DEFAULT_MAX_FILE_SIZE),
+            Some(v) => match v.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(size),
+                _ => Err(ErrorCode::BadOption(format!(
+                    "max_file_size must be a positive number, but got {}",
+                    v
+                ))),
+            },
+        }
+    }
+
+    fn file_writer(&self, schema: DataSchemaRef) -> Result<FileWriter> {
+        match self.plan.format.to_lowercase().as_str() {
+            "csv" => Ok(FileWriter::Csv(CsvWriter {
+                format: CsvOutputFormat::create(
+                    schema,
+                    CsvOutputOptions::from_options(&self.plan.options)?,
+                ),
+                buffer: vec![],
+                rows: 0,
+            })),
+            "parquet" => Ok(FileWriter::Parquet(ParquetWriter {
+                arrow_schema: schema.to_arrow(),
+                blocks: vec![],
+                bytes: 0,
+                rows: 0,
+            })),
+            other => Err(ErrorCode::InvalidSourceFormat(format!(
+                "Unsupported format: {}, expecting one of parquet, csv",
+                other
+            ))),
+        }
+    }
+
+    async fn execute_query(&self) -> Result<SendableDataBlockStream> {
+        match self.plan.query.as_ref() {
+            PlanNode::Select(select) => {
+                let interpreter = SelectInterpreter::try_create(self.ctx.clone(), select.clone())?;
+                interpreter.execute(None).await
+            }
+            other => Err(ErrorCode::UnknownTypeOfQuery(format!(
+                "Unsupported query plan for COPY INTO stage, {}",
+                other.name()
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CopyIntoStageInterpreter {
+    fn name(&self) -> &str {
+        "CopyIntoStageInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let (stage, path) = match extract_stage_location(self.plan.location.as_str()) {
+            Ok(v) => v,
+            Err(_) => {
+                return Err(ErrorCode::BadOption(
+                    "Cannot convert value to stage and path",
+                ))
+            }
+        };
+
+        // the files are written to a stage only, the stage must exist
+        let stage_info = verify_stage_usage(self.ctx.clone(), stage)
+            .await?
+            .ok_or_else(|| ErrorCode::UnknownStage(format!("Stage {} does not exist", stage)))?;
+        let acc = get_dal_by_stage_info(self.ctx.clone(), &stage_info).await?;
+        let root = stage_root(&stage_info);
+        let dir = format!("{}/", path.trim_matches('/'));
+        let dir = dir.trim_start_matches('/');
+
+        let max_file_size = self.max_file_size()?;
+        let mut writer = self.file_writer(self.plan.query.schema())?;
+        let mut manifest = Manifest::default();
+        let mut stream = self.execute_query().await?;
+        while let Some(block) = stream.next().await {
+            writer.append(block?)?;
+            if writer.bytes() >= max_file_size {
+                let name = format!("{}data_{}_{}", dir, self.ctx.get_id(), manifest.len());
+                writer.flush(&acc, &root, name, &mut manifest).await?;
+            }
+        }
+        // an empty file is still written if there is no file yet, the schema is told by it
+        if writer.rows() > 0 || manifest.len() == 0 {
+            let name = format!("{}data_{}_{}", dir, self.ctx.get_id(), manifest.len());
+            writer.flush(&acc, &root, name, &mut manifest).await?;
+        }
+
+        let schema = self.plan.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(manifest.files),
+            Series::new(manifest.row_counts),
+            Series::new(manifest.sizes),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
+
+#[derive(Default)]
+struct Manifest {
+    files: Vec<Vec<u8>>,
+    row_counts: Vec<u64>,
+    sizes: Vec<u64>,
+}
+
+impl Manifest {
+    fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    fn push(&mut self, file: String, rows: usize, size: usize) {
+        self.files.push(file.into_bytes());
+        self.row_counts.push(rows as u64);
+        self.sizes.push(size as u64);
+    }
+}
+
+enum FileWriter {
+    Csv(CsvWriter),
+    Parquet(ParquetWriter),
+}
+
+impl FileWriter {
+    fn append(&mut self, block: DataBlock) -> Result<()> {
+        match self {
+            FileWriter::Csv(w) => w.append(&block),
+            FileWriter::Parquet(w) => {
+                w.rows += block.num_rows();
+                w.bytes += block.memory_size();
+                w.blocks.push(block);
+                Ok(())
+            }
+        }
+    }
+
+    fn bytes(&self) -> usize {
+        match self {
+            FileWriter::Csv(w) => w.buffer.len(),
+            FileWriter::Parquet(w) => w.bytes,
+        }
+    }
+
+    fn rows(&self) -> usize {
+        match self {
+            FileWriter::Csv(w) => w.rows,
+            FileWriter::Parquet(w) => w.rows,
+        }
+    }
+
+    // Write the rows appended so far into the file `name`, with the extension of the format,
+    // under the root of the stage.
+    async fn flush(
+        &mut self,
+        acc: &Arc<dyn DataAccessor>,
+        root: &str,
+        name: String,
+        manifest: &mut Manifest,
+    ) -> Result<()> {
+        match self {
+            FileWriter::Csv(w) => {
+                let name = format!("{}.csv", name);
+                let content = std::mem::take(&mut w.buffer);
+                let size = content.len();
+                acc.put(&format!("{}{}", root, name), content).await?;
+                manifest.push(name, std::mem::take(&mut w.rows), size);
+            }
+            FileWriter::Parquet(w) => {
+                let name = format!("{}.parquet", name);
+                let blocks = std::mem::take(&mut w.blocks);
+                let location = format!("{}{}", root, name);
+                let size = write_blocks(
+                    &w.arrow_schema,
+                    blocks,
+                    acc,
+                    &location,
+                    &BlockCompression::default(),
+                )
+                .await?;
+                manifest.push(name, std::mem::take(&mut w.rows), size as usize);
+                w.bytes = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct ParquetWriter {
+    arrow_schema: ArrowSchema,
+    blocks: Vec<DataBlock>,
+    // the size of the blocks in memory
+    bytes: usize,
+    rows: usize,
+}
+
+struct CsvWriter {
+    format: CsvOutputFormat,
+    buffer: Vec<u8>,
+    rows: usize,
+}
+
+impl CsvWriter {
+    fn append(&mut self, block: &DataBlock) -> Result<()> {
+        // every file starts with the header, if any
+        if self.buffer.is_empty() {
+            self.format.serialize_prefix(&mut self.buffer);
+        }
+        self.format.serialize_block(block, &mut self.buffer)?;
+        self.rows += block.num_rows();
+        Ok(())
+    }
+}
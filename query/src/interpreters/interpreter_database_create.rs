@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::OwnershipObject;
 use common_planners::CreateDatabasePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -48,9 +49,19 @@ impl Interpreter for CreateDatabaseInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr.verify_database_writable(&self.plan.db).await?;
+
         let catalog = self.ctx.get_catalog();
         catalog.create_database(self.plan.clone().into()).await?;
 
+        if let Ok(user) = self.ctx.get_current_user() {
+            let object = OwnershipObject::Database(self.plan.db.clone());
+            user_mgr
+                .grant_ownership(&object, &user.identity(), self.plan.if_not_exists)
+                .await?;
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -15,6 +15,11 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::DroppedDatabase;
+use common_meta_types::DroppedTable;
+use common_meta_types::OwnershipObject;
+use common_meta_types::ReadOnlyObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::DropDatabasePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -45,8 +50,24 @@ impl Interpreter for DropDatabaseInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let object = OwnershipObject::Database(self.plan.db.clone());
+        let user = self.ctx.get_current_user_with_roles().await?;
+        user_mgr
+            .verify_ownership(&object, Some(&user), UserPrivilegeType::Drop)
+            .await?;
+        user_mgr.verify_database_writable(&self.plan.db).await?;
+
         let catalog = self.ctx.get_catalog();
+        let dropped = self.dropped_database().await?;
         catalog.drop_database(self.plan.clone().into()).await?;
+        user_mgr.drop_object_owner(&object).await?;
+        let object = ReadOnlyObject::Database(self.plan.db.clone());
+        user_mgr.drop_read_only(&object).await?;
+
+        if let Some(dropped) = dropped {
+            user_mgr.add_dropped_database(dropped).await?;
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -55,3 +76,38 @@ impl Interpreter for DropDatabaseInterpreter {
         )))
     }
 }
+
+impl DropDatabaseInterpreter {
+    // Snapshot the database and its tables for the recycle bin, None if the recycle bin
+    // is disabled or there is nothing to drop.
+    async fn dropped_database(&self) -> Result<Option<DroppedDatabase>> {
+        let db_name = self.plan.db.as_str();
+        let catalog = self.ctx.get_catalog();
+        if self.ctx.get_config().query.drop_retention_hours == 0
+            || !catalog.exists_database(db_name).await?
+        {
+            return Ok(None);
+        }
+
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let mut tables = vec![];
+        for table in catalog.list_tables(db_name).await? {
+            let object = OwnershipObject::Table(db_name.to_string(), table.name().to_string());
+            let owner = user_mgr.get_object_owner(&object).await?;
+            tables.push(DroppedTable::new(
+                db_name,
+                table.get_table_info().clone(),
+                owner,
+            ));
+        }
+
+        let database = catalog.get_database(db_name).await?;
+        let object = OwnershipObject::Database(db_name.to_string());
+        Ok(Some(DroppedDatabase::new(
+            db_name,
+            database.get_db_info().meta.clone(),
+            tables,
+            user_mgr.get_object_owner(&object).await?,
+        )))
+    }
+}
@@ -71,6 +71,12 @@ impl Interpreter for DescribeStageInterpreter {
         property_values.push(params.url.clone());
         property_defaults.push(default_stage.stage_params.url.clone());
 
+        parent_properties.push("stage_params");
+        properties.push("connection");
+        property_types.push("String");
+        property_values.push(params.connection.clone().unwrap_or_default());
+        property_defaults.push(String::new());
+
         // credentials
         parent_properties.push("credentials");
         properties.push("access_key_id");
@@ -81,7 +87,8 @@ impl Interpreter for DescribeStageInterpreter {
         parent_properties.push("credentials");
         properties.push("secret_access_key");
         property_types.push("String");
-        property_values.push(params.credentials.secret_access_key.clone());
+        // The secret is never shown, only whether it is set.
+        property_values.push(mask_secret(&params.credentials.secret_access_key));
         property_defaults.push(
             default_stage
                 .stage_params
@@ -140,3 +147,11 @@ impl Interpreter for DescribeStageInterpreter {
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
     }
 }
+
+fn mask_secret(secret: &str) -> String {
+    if secret.is_empty() {
+        String::new()
+    } else {
+        "******".to_string()
+    }
+}
@@ -13,14 +13,18 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
 
 use crate::interpreters::plan_schedulers;
 use crate::interpreters::Interpreter;
@@ -50,6 +54,8 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Estimate => self.explain_estimate(),
+            ExplainType::Analyze => self.explain_analyze().await,
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -110,4 +116,70 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create_by_array(schema, vec![formatted_pipeline]))
     }
+
+    // The partitions are pruned while the plan is built, nothing is read here.
+    fn explain_estimate(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let plan = plan_schedulers::apply_plan_rewrite(
+            Optimizers::create(self.ctx.clone()),
+            &self.explain.input,
+        )?;
+
+        let mut collector = ReadSourceCollector { sources: vec![] };
+        collector.visit_plan_node(&plan)?;
+
+        let sources = &collector.sources;
+        let tables = sources.iter().map(|s| s.table_info.desc.as_bytes());
+        let partitions = sources.iter().map(|s| s.parts.len() as u64);
+        let rows = sources.iter().map(|s| s.statistics.read_rows as u64);
+        let bytes = sources.iter().map(|s| s.statistics.read_bytes as u64);
+        let exact = sources.iter().map(|s| s.statistics.is_exact);
+        Ok(DataBlock::create_by_array(schema, vec![
+            Series::new(tables.collect::<Vec<_>>()),
+            Series::new(partitions.collect::<Vec<_>>()),
+            Series::new(rows.collect::<Vec<_>>()),
+            Series::new(bytes.collect::<Vec<_>>()),
+            Series::new(exact.collect::<Vec<_>>()),
+        ]))
+    }
+
+    // The query runs on this node, its result is discarded.
+    async fn explain_analyze(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let optimizer = Optimizers::without_scatters(self.ctx.clone());
+        let plan = plan_schedulers::apply_plan_rewrite(optimizer, &self.explain.input)?;
+
+        let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
+        let pipeline = pipeline_builder.build(&plan)?;
+
+        // The executed pipeline may be merged into one way, the pipes share the stats.
+        let start = Instant::now();
+        let mut stream = pipeline.clone().execute().await?;
+        let mut rows = 0;
+        while let Some(block) = stream.next().await {
+            rows += block?.num_rows();
+        }
+        let elapsed = start.elapsed();
+
+        let mut lines = vec![format!("Result rows: {}, elapsed: {:?}", rows, elapsed)];
+        lines.extend(
+            format!("{}", pipeline.display_analyze())
+                .lines()
+                .map(|s| s.to_string()),
+        );
+        let formatted_pipeline =
+            Series::new(lines.iter().map(|s| s.as_bytes()).collect::<Vec<_>>());
+        Ok(DataBlock::create_by_array(schema, vec![formatted_pipeline]))
+    }
+}
+
+struct ReadSourceCollector {
+    sources: Vec<ReadDataSourcePlan>,
+}
+
+impl PlanVisitor for ReadSourceCollector {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        self.sources.push(plan.clone());
+        Ok(())
+    }
 }
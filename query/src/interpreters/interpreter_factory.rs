@@ -21,34 +21,63 @@ use common_planners::PlanNode;
 use super::DescribeStageInterpreter;
 use crate::interpreters::interpreter_stage_drop::DropStageInterpreter;
 use crate::interpreters::interpreter_table_optimize::OptimizeTableInterpreter;
+use crate::interpreters::AlterOwnerInterpreter;
+use crate::interpreters::AlterReadOnlyInterpreter;
+use crate::interpreters::AlterTableColumnInterpreter;
+use crate::interpreters::AlterTableOptionsInterpreter;
 use crate::interpreters::AlterUDFInterpreter;
 use crate::interpreters::AlterUserInterpreter;
+use crate::interpreters::AlterUserNetworkPolicyInterpreter;
+use crate::interpreters::AnalyzeTableInterpreter;
 use crate::interpreters::CopyInterpreter;
+use crate::interpreters::CopyIntoStageInterpreter;
 use crate::interpreters::CreatStageInterpreter;
 use crate::interpreters::CreatUDFInterpreter;
+use crate::interpreters::CreateConnectionInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateNetworkPolicyInterpreter;
+use crate::interpreters::CreateRoleInterpreter;
+use crate::interpreters::CreateRowAccessPolicyInterpreter;
 use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::CreateUserInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
+use crate::interpreters::DropConnectionInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::DropNetworkPolicyInterpreter;
+use crate::interpreters::DropRoleInterpreter;
+use crate::interpreters::DropRowAccessPolicyInterpreter;
 use crate::interpreters::DropTableInterpreter;
 use crate::interpreters::DropUDFInterpreter;
 use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::ExplainInterpreter;
+use crate::interpreters::ExportTableInterpreter;
 use crate::interpreters::GrantPrivilegeInterpreter;
+use crate::interpreters::GrantRoleInterpreter;
+use crate::interpreters::GrantRolePrivilegeInterpreter;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
+use crate::interpreters::RevokeAllPrivilegesInterpreter;
 use crate::interpreters::RevokePrivilegeInterpreter;
+use crate::interpreters::RevokeRoleInterpreter;
+use crate::interpreters::RevokeRolePrivilegeInterpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::interpreters::SetRoleInterpreter;
+use crate::interpreters::SetSecondaryRolesInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateDatabaseInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
 use crate::interpreters::ShowGrantsInterpreter;
 use crate::interpreters::ShowUDFInterpreter;
+use crate::interpreters::TransactionInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UndropDatabaseInterpreter;
+use crate::interpreters::UndropTableInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
+use crate::interpreters::VacuumDropTableInterpreter;
+use crate::interpreters::VacuumTableInterpreter;
 use crate::sessions::QueryContext;
 
 pub struct InterpreterFactory;
@@ -61,12 +90,26 @@ impl InterpreterFactory {
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateDatabase(v) => CreateDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::DropDatabase(v) => DropDatabaseInterpreter::try_create(ctx_clone, v),
+            PlanNode::UndropDatabase(v) => UndropDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::UndropTable(v) => UndropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::VacuumDropTable(v) => VacuumDropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::VacuumTable(v) => VacuumTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::AnalyzeTable(v) => AnalyzeTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::AlterTableColumn(v) => AlterTableColumnInterpreter::try_create(ctx_clone, v),
+            PlanNode::AlterTableOptions(v) => {
+                AlterTableOptionsInterpreter::try_create(ctx_clone, v)
+            }
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx_clone, v),
             PlanNode::OptimizeTable(v) => OptimizeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx_clone, v),
+            PlanNode::SetRole(v) => SetRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::SetSecondaryRoles(v) => {
+                SetSecondaryRolesInterpreter::try_create(ctx_clone, v)
+            }
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx_clone, v),
             PlanNode::Insert(v) => InsertInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowCreateTable(v) => ShowCreateTableInterpreter::try_create(ctx_clone, v),
@@ -76,7 +119,12 @@ impl InterpreterFactory {
             PlanNode::DropUser(v) => DropUserInterpreter::try_create(ctx_clone, v),
             PlanNode::GrantPrivilege(v) => GrantPrivilegeInterpreter::try_create(ctx_clone, v),
             PlanNode::RevokePrivilege(v) => RevokePrivilegeInterpreter::try_create(ctx_clone, v),
+            PlanNode::RevokeAllPrivileges(v) => {
+                RevokeAllPrivilegesInterpreter::try_create(ctx_clone, v)
+            }
             PlanNode::Copy(v) => CopyInterpreter::try_create(ctx_clone, v),
+            PlanNode::CopyIntoStage(v) => CopyIntoStageInterpreter::try_create(ctx_clone, v),
+            PlanNode::ExportTable(v) => ExportTableInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateUserStage(v) => CreatStageInterpreter::try_create(ctx_clone, v),
             PlanNode::DropUserStage(v) => DropStageInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowGrants(v) => ShowGrantsInterpreter::try_create(ctx_clone, v),
@@ -88,6 +136,36 @@ impl InterpreterFactory {
             PlanNode::DropUDF(v) => DropUDFInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowUDF(v) => ShowUDFInterpreter::try_create(ctx_clone, v),
             PlanNode::AlterUDF(v) => AlterUDFInterpreter::try_create(ctx_clone, v),
+            PlanNode::CreateRowAccessPolicy(v) => {
+                CreateRowAccessPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::DropRowAccessPolicy(v) => {
+                DropRowAccessPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::AlterOwner(v) => AlterOwnerInterpreter::try_create(ctx_clone, v),
+            PlanNode::AlterReadOnly(v) => AlterReadOnlyInterpreter::try_create(ctx_clone, v),
+            PlanNode::CreateNetworkPolicy(v) => {
+                CreateNetworkPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::DropNetworkPolicy(v) => {
+                DropNetworkPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::AlterUserNetworkPolicy(v) => {
+                AlterUserNetworkPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::CreateRole(v) => CreateRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropRole(v) => DropRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::GrantRole(v) => GrantRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::RevokeRole(v) => RevokeRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::GrantRolePrivilege(v) => {
+                GrantRolePrivilegeInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::RevokeRolePrivilege(v) => {
+                RevokeRolePrivilegeInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::CreateConnection(v) => CreateConnectionInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropConnection(v) => DropConnectionInterpreter::try_create(ctx_clone, v),
+            PlanNode::Transaction(v) => TransactionInterpreter::try_create(ctx_clone, v),
             _ => Result::Err(ErrorCode::UnknownTypeOfQuery(format!(
                 "Can't get the interpreter by plan:{}",
                 plan.name()
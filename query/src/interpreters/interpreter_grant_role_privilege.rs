@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::GrantRolePrivilegePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::interpreter_common::grant_object_exists_or_err;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct GrantRolePrivilegeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: GrantRolePrivilegePlan,
+}
+
+impl GrantRolePrivilegeInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: GrantRolePrivilegePlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(GrantRolePrivilegeInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for GrantRolePrivilegeInterpreter {
+    fn name(&self) -> &str {
+        "GrantRolePrivilegeInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+
+        plan.on.validate_privileges(plan.priv_types)?;
+        grant_object_exists_or_err(&self.ctx, &plan.on).await?;
+
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr
+            .grant_role_privileges(&plan.role_name, plan.on, plan.priv_types)
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
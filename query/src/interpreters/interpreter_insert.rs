@@ -23,12 +23,14 @@ use common_streams::SendableDataBlockStream;
 use futures::TryStreamExt;
 
 use super::interpreter_insert_with_stream::SendableWithSchema;
+use crate::catalogs::Catalog;
 use crate::interpreters::interpreter_insert_with_stream::InsertWithStream;
 use crate::interpreters::plan_schedulers::InsertWithPlan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::transforms::AddOnStream;
 use crate::sessions::QueryContext;
+use crate::storages::StorageOperation;
 
 pub struct InsertInterpreter {
     ctx: Arc<QueryContext>,
@@ -56,6 +58,13 @@ impl Interpreter for InsertInterpreter {
             .ctx
             .get_table(&plan.database_name, &plan.table_name)
             .await?;
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr
+            .verify_writable(&plan.database_name, &plan.table_name)
+            .await?;
+        self.ctx
+            .get_catalog()
+            .check_table_supports(table.as_ref(), StorageOperation::Append)?;
 
         let need_fill_missing_columns = table.schema() != self.plan.schema();
 
@@ -76,6 +85,29 @@ impl Interpreter for InsertInterpreter {
                     stream
                 };
 
+                // the small inserts are buffered and committed together, as one block, but
+                // not the ones of a transaction, which are committed by COMMIT
+                let settings = self.ctx.get_settings();
+                if !self.plan.overwrite
+                    && !self.ctx.in_transaction()
+                    && settings.get_enable_async_insert()? != 0
+                {
+                    let queue = self.ctx.get_sessions_manager().get_async_insert_queue();
+                    queue
+                        .insert(
+                            self.ctx.clone(),
+                            &plan.database_name,
+                            &plan.table_name,
+                            stream.try_collect().await?,
+                        )
+                        .await?;
+                    return Ok(Box::pin(DataBlockStream::create(
+                        self.plan.schema(),
+                        None,
+                        vec![],
+                    )));
+                }
+
                 let with_stream = InsertWithStream::new(&self.ctx, &table);
                 with_stream.append_stream(stream).await
             }
@@ -107,6 +139,13 @@ impl Interpreter for InsertInterpreter {
             )
             .await?;
 
+        // the streams read by the insert are consumed, along with the transaction if any
+        for req in self.ctx.take_stream_offsets() {
+            if let Some(req) = self.ctx.stage_table_change(req) {
+                self.ctx.get_catalog().upsert_table_option(req).await?;
+            }
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::sync::oneshot;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_streams::DataBlockStream;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+
+/// Accumulates the small inserts of a table and writes them as one block, once the buffered
+/// ones reach `async_insert_max_data_size` bytes, or `async_insert_busy_timeout_ms` after the
+/// first of them, so that the high frequency writers do not commit a snapshot per insert.
+///
+/// An insert is acknowledged after the block it is buffered in is committed, the failure of
+/// the commit is the failure of every insert of the block.
+#[derive(Default)]
+pub struct AsyncInsertQueue {
+    pending: Mutex<HashMap<(String, String), PendingInserts>>,
+    next_batch_id: AtomicU64,
+}
+
+struct PendingInserts {
+    batch_id: u64,
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+    waiters: Vec<oneshot::Sender<Result<()>>>,
+}
+
+impl AsyncInsertQueue {
+    pub fn create() -> Arc<AsyncInsertQueue> {
+        Arc::new(AsyncInsertQueue::default())
+    }
+
+    /// Buffers the blocks to insert into the table, which are of the schema of the table, and
+    /// waits until they are committed.
+    pub async fn insert(
+        self: &Arc<Self>,
+        ctx: Arc<QueryContext>,
+        database: &str,
+        table: &str,
+        blocks: Vec<DataBlock>,
+    ) -> Result<()> {
+        let settings = ctx.get_settings();
+        let max_data_size = settings.get_async_insert_max_data_size()? as usize;
+        let busy_timeout = Duration::from_millis(settings.get_async_insert_busy_timeout_ms()?);
+
+        let key = (database.to_string(), table.to_string());
+        let (tx, rx) = oneshot::channel();
+        let (full, new_batch) = {
+            let mut pending = self.pending.lock();
+            let inserts = pending
+                .entry(key.clone())
+                .or_insert_with(|| PendingInserts {
+                    batch_id: self.next_batch_id.fetch_add(1, Ordering::Relaxed),
+                    blocks: vec![],
+                    bytes: 0,
+                    waiters: vec![],
+                });
+            let new_batch = match inserts.waiters.is_empty() {
+                true => Some(inserts.batch_id),
+                false => None,
+            };
+            inserts.bytes += blocks.iter().map(|b| b.memory_size()).sum::<usize>();
+            inserts.blocks.extend(blocks);
+            inserts.waiters.push(tx);
+            match inserts.bytes >= max_data_size {
+                true => (pending.remove(&key), None),
+                false => (None, new_batch),
+            }
+        };
+
+        if let Some(inserts) = full {
+            Self::flush(ctx, key, inserts).await;
+        } else if let Some(batch_id) = new_batch {
+            // flushes the batch when it times out, unless it has been flushed as full meanwhile
+            let queue = self.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(busy_timeout).await;
+                let inserts = {
+                    let mut pending = queue.pending.lock();
+                    match pending.get(&key) {
+                        Some(inserts) if inserts.batch_id == batch_id => pending.remove(&key),
+                        _ => None,
+                    }
+                };
+                if let Some(inserts) = inserts {
+                    Self::flush(ctx, key, inserts).await;
+                }
+            });
+        }
+
+        rx.await.map_err(|_| {
+            ErrorCode::TokioError("The buffered insert is dropped before it is written")
+        })?
+    }
+
+    async fn flush(ctx: Arc<QueryContext>, key: (String, String), inserts: PendingInserts) {
+        let num_inserts = inserts.waiters.len();
+        let result = Self::write(ctx, &key.0, &key.1, inserts.blocks).await;
+        match &result {
+            Ok(_) => tracing::debug!(
+                "{} inserts into {}.{} are written as one block",
+                num_inserts,
+                key.0,
+                key.1
+            ),
+            Err(cause) => tracing::warn!(
+                "Cannot write the {} inserts into {}.{}, cause {:?}",
+                num_inserts,
+                key.0,
+                key.1,
+                cause
+            ),
+        }
+        for waiter in inserts.waiters {
+            // the insert may have been killed meanwhile
+            let _ = waiter.send(result.clone());
+        }
+    }
+
+    async fn write(
+        ctx: Arc<QueryContext>,
+        database: &str,
+        table: &str,
+        blocks: Vec<DataBlock>,
+    ) -> Result<()> {
+        // the latest version of the table, the inserts may have been buffered for a while
+        let table = ctx.get_catalog().get_table(database, table).await?;
+        let block = DataBlock::concat_blocks(&blocks)?;
+        if block.schema() != &table.schema() {
+            return Err(ErrorCode::LogicalError(format!(
+                "The schema of table {}.{} is changed while the inserts are buffered",
+                database,
+                table.name()
+            )));
+        }
+
+        let stream = Box::pin(DataBlockStream::create(table.schema(), None, vec![block]));
+        let append_logs = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx, append_logs.try_collect().await?, false)
+            .await
+    }
+}
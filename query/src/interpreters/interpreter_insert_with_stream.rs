@@ -16,12 +16,15 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::data_type_physical;
 use common_datavalues::prelude::Series;
 use common_datavalues::prelude::SeriesFrom;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::Result;
 use common_planners::Expression;
 use common_streams::ProgressStream;
@@ -61,6 +64,10 @@ pub trait SendableWithSchema {
 
 impl SendableWithSchema for &[Vec<Expression>] {
     fn to_stream(self, schema: Arc<DataSchema>) -> Result<SendableDataBlockStream> {
+        if let Some(block) = literals_to_block(self, &schema)? {
+            return Ok(Box::pin(futures::stream::iter(vec![Ok(block)])));
+        }
+
         let dummy = DataSchemaRefExt::create(vec![DataField::new("dummy", DataType::UInt8, false)]);
         let one_row_block = DataBlock::create_by_array(dummy.clone(), vec![Series::new(vec![1u8])]);
         let blocks = self
@@ -84,3 +91,38 @@ impl SendableWithSchema for &[Vec<Expression>] {
         Ok(stream)
     }
 }
+
+/// Build the block column by column if every value is a literal already in the physical type of its column,
+/// instead of evaluating the rows one by one.
+fn literals_to_block(
+    rows: &[Vec<Expression>],
+    schema: &DataSchemaRef,
+) -> Result<Option<DataBlock>> {
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    let mut columns = Vec::with_capacity(schema.fields().len());
+    for (col, field) in schema.fields().iter().enumerate() {
+        let physical_type = data_type_physical(field.data_type().clone());
+        let mut values = Vec::with_capacity(rows.len());
+        for exprs in rows {
+            match &exprs[col] {
+                Expression::Alias(_, expr) => match expr.as_ref() {
+                    Expression::Literal { value, .. } if value.data_type() == physical_type => {
+                        values.push(value.clone())
+                    }
+                    _ => return Ok(None),
+                },
+                _ => return Ok(None),
+            }
+        }
+
+        match DataValue::try_into_data_array(&values, field.data_type()) {
+            Ok(column) => columns.push(column),
+            Err(_) => return Ok(None),
+        }
+    }
+
+    Ok(Some(DataBlock::create_by_array(schema.clone(), columns)))
+}
@@ -14,11 +14,18 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::Mutex;
 use common_planners::PlanNode;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use common_tracing::tracing::Span;
+use common_tracing::tracing_futures::Instrument;
+use futures::StreamExt;
 
+use crate::audit::AuditEventType;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::interpreters::InterpreterQueryLog;
@@ -27,17 +34,43 @@ use crate::sessions::QueryContext;
 pub struct InterceptorInterpreter {
     ctx: Arc<QueryContext>,
     inner: InterpreterPtr,
+    plan: PlanNode,
     query_log: InterpreterQueryLog,
+    // The first error of the result stream, written into the query log at the finish.
+    failure: Arc<Mutex<Option<ErrorCode>>>,
+    // The root span of the trace of the query, the actions sent to the other nodes carry it.
+    span: Span,
 }
 
 impl InterceptorInterpreter {
     pub fn create(ctx: Arc<QueryContext>, inner: InterpreterPtr, plan: PlanNode) -> Self {
+        let span = tracing::info_span!(
+            "query",
+            query.id = ctx.get_id().as_str(),
+            query.kind = plan.name()
+        );
         InterceptorInterpreter {
             ctx: ctx.clone(),
             inner,
+            plan: plan.clone(),
             query_log: InterpreterQueryLog::create(ctx, plan),
+            failure: Arc::new(Mutex::new(None)),
+            span,
         }
     }
+
+    fn audit(&self, failure: Option<&ErrorCode>) {
+        let event_type = match failure {
+            Some(e) if e.code() == ErrorCode::PermissionDenied("").code() => {
+                AuditEventType::PrivilegeDenied
+            }
+            _ => match AuditEventType::from_plan(&self.plan) {
+                Some(event_type) => event_type,
+                None => return,
+            },
+        };
+        self.ctx.audit(event_type, self.plan.name(), failure);
+    }
 }
 
 #[async_trait::async_trait]
@@ -50,9 +83,39 @@ impl Interpreter for InterceptorInterpreter {
         &self,
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let result_stream = self.inner.execute(input_stream).await?;
+        let result_stream = match self
+            .inner
+            .execute(input_stream)
+            .instrument(self.span.clone())
+            .await
+        {
+            Ok(result_stream) => {
+                self.audit(None);
+                result_stream
+            }
+            Err(cause) => {
+                self.audit(Some(&cause));
+                // The handlers do not finish the queries failing to execute.
+                let _ = self
+                    .query_log
+                    .log_error(&cause)
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.log_error.error: {:?}", e));
+                return Err(cause);
+            }
+        };
+
+        let failure = self.failure.clone();
+        let result_stream = result_stream
+            .map(move |block| {
+                if let Err(cause) = &block {
+                    failure.lock().get_or_insert_with(|| cause.clone());
+                }
+                block
+            })
+            .instrument(self.span.clone());
         let metric_stream =
-            ProgressStream::try_create(result_stream, self.ctx.get_result_progress())?;
+            ProgressStream::try_create(Box::pin(result_stream), self.ctx.get_result_progress())?;
         Ok(Box::pin(metric_stream))
     }
 
@@ -61,6 +124,16 @@ impl Interpreter for InterceptorInterpreter {
     }
 
     async fn finish(&self) -> Result<()> {
-        self.query_log.log_finish().await
+        let failure = self.failure.lock().take();
+        match failure {
+            Some(cause) => {
+                // A privilege denied while reading the result, e.g. on a table read by the query.
+                if cause.code() == ErrorCode::PermissionDenied("").code() {
+                    self.audit(Some(&cause));
+                }
+                self.query_log.log_error(&cause).await
+            }
+            None => self.query_log.log_finish().await,
+        }
     }
 }
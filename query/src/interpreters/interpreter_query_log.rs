@@ -13,15 +13,23 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use common_base::tokio;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::SeriesFrom;
 use common_datavalues::series::Series;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CreateTableReq;
+use common_meta_types::TableMeta;
 use common_planners::PlanNode;
+use common_tracing::redact;
+use common_tracing::tracing;
 
+use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 
 #[derive(Clone, Copy)]
@@ -50,6 +58,7 @@ pub struct LogEvent {
     pub query_text: String,
     pub event_date: i32,
     pub event_time: u64,
+    pub query_duration_ms: u64,
 
     // Schema.
     pub current_database: String,
@@ -70,6 +79,7 @@ pub struct LogEvent {
     pub result_bytes: u64,
     pub cpu_usage: u32,
     pub memory_usage: u64,
+    pub peak_memory_usage: u64,
 
     // Client.
     pub client_info: String,
@@ -87,14 +97,24 @@ pub struct LogEvent {
     pub extra: String,
 }
 
+/// Writes the start, and the finish or the error, of a query into `system.query_log`, a ring
+/// buffer of the latest `max_query_log_size` records.
+///
+/// The finish and the error records are also appended to the `query_log_table` of the config
+/// if any, which is created as a fuse table on the first write.
 pub struct InterpreterQueryLog {
     ctx: Arc<QueryContext>,
     plan: PlanNode,
+    start: Instant,
 }
 
 impl InterpreterQueryLog {
     pub fn create(ctx: Arc<QueryContext>, plan: PlanNode) -> Self {
-        InterpreterQueryLog { ctx, plan }
+        InterpreterQueryLog {
+            ctx,
+            plan,
+            start: Instant::now(),
+        }
     }
 
     async fn write_log(&self, event: &LogEvent) -> Result<()> {
@@ -117,6 +137,7 @@ impl InterpreterQueryLog {
             Series::new(vec![event.query_text.as_str()]),
             Series::new(vec![event.event_date as i32]),
             Series::new(vec![event.event_time as u64]),
+            Series::new(vec![event.query_duration_ms]),
             // Schema.
             Series::new(vec![event.current_database.as_str()]),
             Series::new(vec![event.databases.as_str()]),
@@ -135,6 +156,7 @@ impl InterpreterQueryLog {
             Series::new(vec![event.result_bytes as u64]),
             Series::new(vec![event.cpu_usage]),
             Series::new(vec![event.memory_usage as u64]),
+            Series::new(vec![event.peak_memory_usage]),
             // Client.
             Series::new(vec![event.client_info.as_str()]),
             Series::new(vec![event.client_address.as_str()]),
@@ -147,15 +169,70 @@ impl InterpreterQueryLog {
             // Extra.
             Series::new(vec![event.extra.as_str()]),
         ]);
-        let blocks = vec![Ok(block)];
+        let blocks = vec![Ok(block.clone())];
         let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(blocks);
         let _ = query_log
             .append_data(self.ctx.clone(), Box::pin(input_stream))
             .await?;
 
+        if !matches!(event.log_type, LogType::Start) {
+            self.write_persistent_log(block);
+        }
         Ok(())
     }
 
+    // Buffered by the async insert queue, in the background not to delay the response.
+    fn write_persistent_log(&self, block: DataBlock) {
+        let name = self.ctx.get_config().query.query_log_table;
+        if name.is_empty() {
+            return;
+        }
+
+        let ctx = self.ctx.clone();
+        tokio::spawn(async move {
+            if let Err(cause) = Self::append_persistent_log(ctx, &name, block).await {
+                tracing::warn!(
+                    "Cannot write the query log into {}, cause {:?}",
+                    name,
+                    cause
+                );
+            }
+        });
+    }
+
+    async fn append_persistent_log(
+        ctx: Arc<QueryContext>,
+        name: &str,
+        block: DataBlock,
+    ) -> Result<()> {
+        let (database, table) = name.split_once('.').ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "The query_log_table must be database.table, but got {}",
+                name
+            ))
+        })?;
+
+        let catalog = ctx.get_catalog();
+        if catalog.get_table(database, table).await.is_err() {
+            catalog
+                .create_table(CreateTableReq {
+                    if_not_exists: true,
+                    or_replace: false,
+                    db: database.to_string(),
+                    table: table.to_string(),
+                    table_meta: TableMeta {
+                        schema: block.schema().clone(),
+                        engine: "FUSE".to_string(),
+                        ..Default::default()
+                    },
+                })
+                .await?;
+        }
+
+        let queue = ctx.get_sessions_manager().get_async_insert_queue();
+        queue.insert(ctx, database, table, vec![block]).await
+    }
+
     pub async fn log_start(&self) -> Result<()> {
         // User.
         let handler_type = self.ctx.get_session().get_type();
@@ -169,7 +246,7 @@ impl InterpreterQueryLog {
         // Query.
         let query_id = self.ctx.get_id();
         let query_kind = self.plan.name().to_string();
-        let query_text = self.ctx.get_query_str();
+        let query_text = redact(&self.ctx.get_query_str());
         // Schema.
         let current_database = self.ctx.get_current_database();
 
@@ -209,6 +286,7 @@ impl InterpreterQueryLog {
             query_text,
             event_date,
             event_time,
+            query_duration_ms: 0,
             current_database,
             databases: "".to_string(),
             tables: "".to_string(),
@@ -225,6 +303,7 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            peak_memory_usage: 0,
             client_info: "".to_string(),
             client_address,
 
@@ -239,6 +318,14 @@ impl InterpreterQueryLog {
     }
 
     pub async fn log_finish(&self) -> Result<()> {
+        self.log_end(None).await
+    }
+
+    pub async fn log_error(&self, cause: &ErrorCode) -> Result<()> {
+        self.log_end(Some(cause)).await
+    }
+
+    async fn log_end(&self, failure: Option<&ErrorCode>) -> Result<()> {
         // User.
         let handler_type = self.ctx.get_session().get_type();
         let tenant_id = self.ctx.get_config().query.tenant_id;
@@ -251,7 +338,7 @@ impl InterpreterQueryLog {
         // Query.
         let query_id = self.ctx.get_id();
         let query_kind = self.plan.name().to_string();
-        let query_text = self.ctx.get_query_str();
+        let query_text = redact(&self.ctx.get_query_str());
 
         // Stats.
         let now = SystemTime::now();
@@ -270,6 +357,8 @@ impl InterpreterQueryLog {
         let scan_seek_cost_ms = dal_metrics.read_seek_cost_ms as u64;
         let cpu_usage = self.ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = self.ctx.get_session().get_memory_usage() as u64;
+        let peak_memory_usage = self.ctx.get_memory_tracker().get_peak_memory_usage() as u64;
+        let query_duration_ms = self.start.elapsed().as_millis() as u64;
 
         // Result.
         let result_rows = self.ctx.get_result_progress_value().read_rows as u64;
@@ -281,8 +370,21 @@ impl InterpreterQueryLog {
         // Schema.
         let current_database = self.ctx.get_current_database();
 
+        // Extra, the aggregated scan metrics.
+        let scan_profile = self.ctx.get_query_profile().get_scan_profile();
+        let extra = match scan_profile.parts {
+            0 => "".to_string(),
+            _ => serde_json::json!({ "scan_profile": scan_profile }).to_string(),
+        };
+
+        // Exception.
+        let (log_type, exception_code, exception) = match failure {
+            None => (LogType::Finish, 0, "".to_string()),
+            Some(cause) => (LogType::Error, cause.code() as i32, cause.message()),
+        };
+
         let log_event = LogEvent {
-            log_type: LogType::Finish,
+            log_type,
             handler_type,
             tenant_id,
             cluster_id,
@@ -294,6 +396,7 @@ impl InterpreterQueryLog {
             query_text,
             event_date,
             event_time,
+            query_duration_ms,
             databases: "".to_string(),
             tables: "".to_string(),
             columns: "".to_string(),
@@ -309,17 +412,62 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            peak_memory_usage,
             client_info: "".to_string(),
             client_address,
             current_database,
 
-            exception_code: 0,
-            exception: "".to_string(),
+            exception_code,
+            exception,
             stack_trace: "".to_string(),
             server_version: "".to_string(),
-            extra: "".to_string(),
+            extra,
         };
 
-        self.write_log(&log_event).await
+        self.write_log(&log_event).await?;
+        self.write_profile().await
+    }
+
+    // The scan metrics of the parts read by the query, if any.
+    async fn write_profile(&self) -> Result<()> {
+        let part_scans = self.ctx.get_query_profile().get_part_scans();
+        if part_scans.is_empty() {
+            return Ok(());
+        }
+
+        let query_profile = self.ctx.get_table("system", "query_profile").await?;
+        let schema = query_profile.get_table_info().meta.schema.clone();
+
+        let query_id = self.ctx.get_id();
+        let block = DataBlock::create_by_array(schema, vec![
+            Series::new(vec![query_id.as_str(); part_scans.len()]),
+            Series::new(
+                part_scans
+                    .iter()
+                    .map(|p| p.part.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(part_scans.iter().map(|p| p.read_bytes).collect::<Vec<_>>()),
+            Series::new(
+                part_scans
+                    .iter()
+                    .map(|p| p.read_cost_us)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(
+                part_scans
+                    .iter()
+                    .map(|p| p.decode_cost_us)
+                    .collect::<Vec<_>>(),
+            ),
+            Series::new(part_scans.iter().map(|p| p.cache_hit).collect::<Vec<_>>()),
+        ]);
+        let blocks = vec![Ok(block)];
+        let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(blocks);
+        let _ = query_profile
+            .append_data(self.ctx.clone(), Box::pin(input_stream))
+            .await?;
+
+        Ok(())
     }
 }
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::RevokeAllPrivilegesPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct RevokeAllPrivilegesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RevokeAllPrivilegesPlan,
+}
+
+impl RevokeAllPrivilegesInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: RevokeAllPrivilegesPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(RevokeAllPrivilegesInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RevokeAllPrivilegesInterpreter {
+    fn name(&self) -> &str {
+        "RevokeAllPrivilegesInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+
+        // TODO: check privilege on granting
+
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr
+            .revoke_all_user_privileges(&plan.username, &plan.hostname)
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -18,12 +18,15 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
+use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
 use crate::interpreters::plan_schedulers;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::optimizers::EmptyResultOptimizer;
+use crate::optimizers::Optimizer;
 use crate::optimizers::Optimizers;
 use crate::sessions::QueryContext;
 
@@ -38,10 +41,11 @@ impl SelectInterpreter {
     }
 
     fn rewrite_plan(&self) -> Result<PlanNode> {
-        plan_schedulers::apply_plan_rewrite(
+        let plan = plan_schedulers::apply_plan_rewrite(
             Optimizers::create(self.ctx.clone()),
             &self.select.input,
-        )
+        )?;
+        EmptyResultOptimizer::create(self.ctx.clone()).optimize(&plan)
     }
 }
 
@@ -62,6 +66,14 @@ impl Interpreter for SelectInterpreter {
     ) -> Result<SendableDataBlockStream> {
         // TODO: maybe panic?
         let optimized_plan = self.rewrite_plan()?;
+        if let PlanNode::Empty(plan) = &optimized_plan {
+            // Known to be empty at plan time, no pipeline is built for it.
+            return Ok(Box::pin(DataBlockStream::create(
+                plan.schema(),
+                None,
+                vec![],
+            )));
+        }
         plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
     }
 }
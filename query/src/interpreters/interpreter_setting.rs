@@ -17,11 +17,18 @@ use std::sync::Arc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_datavalues::DateTimeOutput;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::OverflowMode;
+use common_meta_types::UserPrivilegeType;
 use common_planners::SettingPlan;
+use common_planners::VarValue;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 
+use crate::audit::SettingChange;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
@@ -35,6 +42,83 @@ impl SettingInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, set: SettingPlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(SettingInterpreter { ctx, set }))
     }
+
+    /// `SET GLOBAL` changes the node, which needs SUPER on *.*: the log_level, and the
+    /// audit_log_retention_days of the tenant. The changes are recorded into
+    /// `system.settings_history`.
+    async fn set_global(&self, var: &VarValue) -> Result<()> {
+        let user = self.ctx.get_current_user_with_roles().await?;
+        if !user.grants.verify_global_privilege(
+            &user.name,
+            &user.hostname,
+            UserPrivilegeType::Super,
+        ) {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, '{}'@'{}' needs to have {} privilege on *.*",
+                user.name,
+                user.hostname,
+                UserPrivilegeType::Super
+            )));
+        }
+
+        match var.variable.to_lowercase().as_str() {
+            "log_level" => {
+                let old_value = common_tracing::get_log_level().unwrap_or_default();
+                common_tracing::set_log_level(&var.value).map_err(ErrorCode::BadArguments)?;
+                tracing::info!("The log level is set to {} by SET GLOBAL", var.value);
+                self.record_change("log_level", old_value, &var.value);
+                Ok(())
+            }
+            "audit_log_retention_days" => {
+                let retention_days: u64 = var.value.parse().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid audit_log_retention_days: {:?}, expect a number of days",
+                        var.value
+                    ))
+                })?;
+                let tenant_id = self.ctx.get_config().query.tenant_id;
+                let audit_log = self.ctx.get_sessions_manager().get_audit_log();
+                let old_value = audit_log.get_retention_days(&tenant_id);
+                audit_log.set_retention_days(&tenant_id, retention_days);
+                self.record_change(
+                    "audit_log_retention_days",
+                    old_value.to_string(),
+                    &var.value,
+                );
+                Ok(())
+            }
+            _ => Err(ErrorCode::UnknownVariable(format!(
+                "Unknown global variable: {:?}, only log_level and audit_log_retention_days \
+                can be set globally",
+                var.variable
+            ))),
+        }
+    }
+
+    fn record_change(&self, name: &str, old_value: String, new_value: &str) {
+        let mut change = SettingChange::create(
+            self.ctx.get_config().query.tenant_id,
+            name,
+            old_value,
+            new_value,
+        );
+        change.handler_type = self.ctx.get_session().get_type();
+        change.query_id = self.ctx.get_id();
+        change.user = self
+            .ctx
+            .get_current_user()
+            .map(|user| user.name)
+            .unwrap_or_default();
+        change.client_address = self
+            .ctx
+            .get_client_address()
+            .map(|addr| addr.to_string())
+            .unwrap_or_default();
+        self.ctx
+            .get_sessions_manager()
+            .get_settings_history()
+            .append(change);
+    }
 }
 
 #[async_trait::async_trait]
@@ -49,6 +133,11 @@ impl Interpreter for SettingInterpreter {
     ) -> Result<SendableDataBlockStream> {
         let plan = self.set.clone();
         for var in plan.vars {
+            if var.is_global {
+                self.set_global(&var).await?;
+                continue;
+            }
+
             match var.variable.to_lowercase().as_str() {
                 // To be compatible with some drivers
                 "sql_mode" | "autocommit" => {}
@@ -56,6 +145,33 @@ impl Interpreter for SettingInterpreter {
                     let threads: u64 = var.value.parse()?;
                     self.ctx.get_settings().set_max_threads(threads)?;
                 }
+                "time_zone" => {
+                    DateTimeOutput::parse_time_zone(&var.value)?;
+                    self.ctx.get_settings().set_time_zone(var.value)?;
+                }
+                "datetime_output_format" => {
+                    let settings = self.ctx.get_settings();
+                    DateTimeOutput::try_create(&settings.get_time_zone()?, &var.value)?;
+                    settings.set_datetime_output_format(var.value.to_lowercase())?;
+                }
+                "numeric_overflow_mode" => {
+                    OverflowMode::try_create(&var.value)?;
+                    self.ctx
+                        .get_settings()
+                        .set_numeric_overflow_mode(var.value.to_lowercase())?;
+                }
+                "div_precision_increment" => {
+                    let precision: u64 = var.value.parse()?;
+                    if precision > 30 {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "div_precision_increment must be at most 30, got {}",
+                            precision
+                        )));
+                    }
+                    self.ctx
+                        .get_settings()
+                        .set_div_precision_increment(precision)?;
+                }
                 _ => {
                     self.ctx
                         .get_settings()
@@ -68,6 +68,11 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
                 info.push_str(&engine);
             }
         }
+        let mut options = db.options().iter().collect::<Vec<_>>();
+        options.sort();
+        for (k, v) in options {
+            info.push_str(&format!(" {}='{}'", k.to_uppercase(), v));
+        }
         let schema = self.plan.schema();
         let block = DataBlock::create_by_array(schema.clone(), vec![
             Series::new(vec![name.as_bytes()]),
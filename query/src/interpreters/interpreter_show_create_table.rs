@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::ShowCreateTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -58,16 +59,21 @@ impl Interpreter for ShowCreateTableInterpreter {
 
         let mut table_info = format!("CREATE TABLE `{}` (\n", name);
         for field in schema.fields().iter() {
-            let column = format!("  `{}` {},\n", field.name(), field.data_type());
+            let mut column = format!("  `{}` {}", field.name(), field.data_type());
+            if let Some(default_expr) = field.default_expr() {
+                let default_expr: Expression = serde_json::from_slice(default_expr)?;
+                column.push_str(format!(" DEFAULT {}", default_expr.column_name()).as_str());
+            }
+            column.push_str(",\n");
             table_info.push_str(column.as_str());
         }
         let table_engine = format!(") ENGINE={}", engine);
         table_info.push_str(table_engine.as_str());
         table_info.push_str(
             table
-                .options()
+                .user_options()
                 .iter()
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                .map(|(k, v)| format!(" {}='{}'", k, v))
                 .collect::<Vec<_>>()
                 .join("")
                 .as_str(),
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::CreateUserStagePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -50,6 +52,31 @@ impl Interpreter for CreatStageInterpreter {
         let plan = self.plan.clone();
         let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
         let user_stage = plan.user_stage_info;
+        let object = OwnershipObject::Stage(user_stage.stage_name.clone());
+        let user = self.ctx.get_current_user_with_roles().await.ok();
+        // The stage may only reference a connection its creator is allowed to use.
+        if let Some(connection) = &user_stage.stage_params.connection {
+            user_mgr
+                .verify_connection_usage(connection, user.as_ref())
+                .await?;
+        }
+        if plan.or_replace {
+            user_mgr
+                .verify_ownership(&object, user.as_ref(), UserPrivilegeType::Drop)
+                .await?;
+            user_mgr.replace_stage(user_stage).await?;
+            if let Some(user) = user {
+                user_mgr
+                    .grant_ownership(&object, &user.identity(), false)
+                    .await?;
+            }
+            return Ok(Box::pin(DataBlockStream::create(
+                self.plan.schema(),
+                None,
+                vec![],
+            )));
+        }
+
         let create_stage = user_mgr.add_stage(user_stage).await;
         if plan.if_not_exists {
             create_stage.or_else(|e| {
@@ -64,6 +91,12 @@ impl Interpreter for CreatStageInterpreter {
             create_stage?;
         }
 
+        if let Some(user) = user {
+            user_mgr
+                .grant_ownership(&object, &user.identity(), plan.if_not_exists)
+                .await?;
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AlterTableColumnPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct AlterTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableColumnPlan,
+}
+
+impl AlterTableColumnInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: AlterTableColumnPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(AlterTableColumnInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableColumnInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        let table = self.ctx.get_table(&plan.db, &plan.table).await?;
+
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let object = OwnershipObject::Table(plan.db.clone(), plan.table.clone());
+        let user = self.ctx.get_current_user_with_roles().await.ok();
+        user_mgr
+            .verify_ownership(&object, user.as_ref(), UserPrivilegeType::Alter)
+            .await?;
+        user_mgr.verify_writable(&plan.db, &plan.table).await?;
+
+        table
+            .alter_column(self.ctx.clone(), &plan.operation)
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
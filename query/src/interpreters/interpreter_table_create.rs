@@ -17,6 +17,9 @@ use std::sync::Arc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::Result;
+use common_meta_types::DroppedTable;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::CreateTablePlan;
 use common_planners::InsertInputSource;
 use common_planners::InsertPlan;
@@ -51,6 +54,26 @@ impl Interpreter for CreateTableInterpreter {
         &self,
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        if self.plan.or_replace {
+            // Replacing a table drops the existing one, which requires the same rights as DROP,
+            // and creates a new one in the database, which requires the CREATE privilege on it.
+            let user = self.ctx.get_current_user_with_roles().await?;
+            user_mgr
+                .verify_ownership(
+                    &self.ownership_object(),
+                    Some(&user),
+                    UserPrivilegeType::Drop,
+                )
+                .await?;
+            user_mgr
+                .verify_database_create(&self.plan.db, &user)
+                .await?;
+        }
+        user_mgr
+            .verify_writable(&self.plan.db, &self.plan.table)
+            .await?;
+
         match &self.plan.as_select {
             Some(select_plan_node) => {
                 self.create_table_as_select(input_stream, select_plan_node.clone())
@@ -70,7 +93,7 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog();
 
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.plan.clone().into()).await?;
+        self.create_or_replace_table().await?;
         let table = catalog.get_table(&self.plan.db, &self.plan.table).await?;
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
@@ -110,8 +133,7 @@ impl CreateTableInterpreter {
     }
 
     async fn create_table(&self) -> Result<SendableDataBlockStream> {
-        let catalog = self.ctx.get_catalog();
-        catalog.create_table(self.plan.clone().into()).await?;
+        self.create_or_replace_table().await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -119,4 +141,51 @@ impl CreateTableInterpreter {
             vec![],
         )))
     }
+
+    async fn create_or_replace_table(&self) -> Result<()> {
+        let catalog = self.ctx.get_catalog();
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let (replaced, owner) = if self.plan.or_replace {
+            let tbl = catalog
+                .get_table(&self.plan.db, &self.plan.table)
+                .await
+                .ok();
+            let owner = user_mgr.get_object_owner(&self.ownership_object()).await?;
+            (tbl, owner)
+        } else {
+            (None, None)
+        };
+
+        catalog.create_table(self.plan.clone().into()).await?;
+        self.grant_ownership().await?;
+
+        // The replaced table is swapped out in meta, its data is moved to the recycle bin,
+        // or purged, as a dropped table's.
+        if let Some(tbl) = replaced {
+            if self.ctx.get_config().query.drop_retention_hours > 0 {
+                let dropped = DroppedTable::new(&self.plan.db, tbl.get_table_info().clone(), owner);
+                user_mgr.add_dropped_table(dropped).await?;
+            } else {
+                let keep_last_snapshot = false;
+                tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn ownership_object(&self) -> OwnershipObject {
+        OwnershipObject::Table(self.plan.db.clone(), self.plan.table.clone())
+    }
+
+    async fn grant_ownership(&self) -> Result<()> {
+        if let Ok(user) = self.ctx.get_current_user() {
+            let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+            // A replaced table gets a new owner, an existing table is kept as it is.
+            let if_absent = self.plan.if_not_exists && !self.plan.or_replace;
+            user_mgr
+                .grant_ownership(&self.ownership_object(), &user.identity(), if_absent)
+                .await?;
+        }
+        Ok(())
+    }
 }
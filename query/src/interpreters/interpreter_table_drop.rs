@@ -15,6 +15,10 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::DroppedTable;
+use common_meta_types::OwnershipObject;
+use common_meta_types::ReadOnlyObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::DropTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -49,14 +53,31 @@ impl Interpreter for DropTableInterpreter {
         let tbl_name = self.plan.table.as_str();
         let tbl = self.ctx.get_table(db_name, tbl_name).await.ok();
 
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let object = OwnershipObject::Table(db_name.to_string(), tbl_name.to_string());
+        let user = self.ctx.get_current_user_with_roles().await?;
+        user_mgr
+            .verify_ownership(&object, Some(&user), UserPrivilegeType::Drop)
+            .await?;
+        user_mgr.verify_writable(db_name, tbl_name).await?;
+
+        let owner = user_mgr.get_object_owner(&object).await?;
         let catalog = self.ctx.get_catalog();
         catalog.drop_table(self.plan.clone().into()).await?;
+        user_mgr.drop_object_owner(&object).await?;
+        let object = ReadOnlyObject::Table(db_name.to_string(), tbl_name.to_string());
+        user_mgr.drop_read_only(&object).await?;
 
         // `drop_table` throws several types of exceptions
-        // thus `optimize` operation is executed after it.
+        // thus the table is moved to the recycle bin, or purged, after it.
         if let Some(tbl) = tbl {
-            let keep_last_snapshot = false;
-            tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
+            if self.ctx.get_config().query.drop_retention_hours > 0 {
+                let dropped = DroppedTable::new(db_name, tbl.get_table_info().clone(), owner);
+                user_mgr.add_dropped_table(dropped).await?;
+            } else {
+                let keep_last_snapshot = false;
+                tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
+            }
         }
 
         Ok(Box::pin(DataBlockStream::create(
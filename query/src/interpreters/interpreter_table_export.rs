@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::ExportTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::interpreter_copy::extract_stage_location;
+use crate::interpreters::interpreter_copy::get_dal_by_stage_info;
+use crate::interpreters::interpreter_copy::stage_root;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+
+pub struct ExportTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExportTablePlan,
+}
+
+impl ExportTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExportTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(ExportTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExportTableInterpreter {
+    fn name(&self) -> &str {
+        "ExportTableInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        let (stage, path) = match extract_stage_location(plan.location.as_str()) {
+            Ok(v) => v,
+            Err(_) => {
+                return Err(ErrorCode::BadOption(
+                    "Cannot convert value to stage and path",
+                ))
+            }
+        };
+
+        let table = self.ctx.get_table(&plan.db_name, &plan.tbl_name).await?;
+        let fuse_table = table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "expecting fuse table, but got table of engine type: {}",
+                table.get_table_info().meta.engine
+            ))
+        })?;
+
+        let manifest = fuse_table
+            .do_export_manifest(self.ctx.clone(), &plan.db_name, plan.snapshot_id.as_deref())
+            .await?;
+
+        // the stage must exist, the manifest is written to it
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let stage_info = user_mgr.get_stage(stage).await?;
+
+        let manifest_path = format!(
+            "{}/{}_manifest_{}.json",
+            path.trim_end_matches('/'),
+            plan.tbl_name,
+            manifest.snapshot_id.as_deref().unwrap_or("empty")
+        );
+        let acc = get_dal_by_stage_info(self.ctx.clone(), &stage_info).await?;
+        let stage_path = format!(
+            "{}{}",
+            stage_root(&stage_info),
+            manifest_path.trim_start_matches('/')
+        );
+        acc.put(&stage_path, serde_json::to_vec_pretty(&manifest)?)
+            .await?;
+
+        let schema = plan.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![format!("@{}{}", stage, manifest_path).into_bytes()]),
+            Series::new(vec![manifest.snapshot_id.map(|id| id.into_bytes())]),
+            Series::new(vec![manifest.files.len() as u64]),
+            Series::new(vec![manifest.row_count]),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
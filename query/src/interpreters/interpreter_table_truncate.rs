@@ -19,9 +19,11 @@ use common_planners::TruncateTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::StorageOperation;
 
 pub struct TruncateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -47,6 +49,11 @@ impl Interpreter for TruncateTableInterpreter {
         let db_name = self.plan.db.as_str();
         let tbl_name = self.plan.table.as_str();
         let tbl = self.ctx.get_table(db_name, tbl_name).await?;
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        user_mgr.verify_writable(db_name, tbl_name).await?;
+        self.ctx
+            .get_catalog()
+            .check_table_supports(tbl.as_ref(), StorageOperation::Truncate)?;
         tbl.truncate(self.ctx.clone(), self.plan.clone()).await?;
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::CreateTableReq;
+use common_meta_types::OwnershipObject;
+use common_planners::UndropTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct UndropTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: UndropTablePlan,
+}
+
+impl UndropTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: UndropTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(UndropTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for UndropTableInterpreter {
+    fn name(&self) -> &str {
+        "UndropTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let db_name = self.plan.db.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        // The most recently dropped table with this name wins.
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let dropped = user_mgr
+            .get_dropped_tables()
+            .await?
+            .into_iter()
+            .filter(|t| t.db == db_name && t.name() == tbl_name)
+            .last()
+            .ok_or_else(|| {
+                ErrorCode::UnknownDroppedObject(format!(
+                    "Unknown dropped table '{}'.'{}'",
+                    db_name, tbl_name
+                ))
+            })?;
+
+        let catalog = self.ctx.get_catalog();
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: false,
+                or_replace: false,
+                db: db_name.to_string(),
+                table: tbl_name.to_string(),
+                table_meta: dropped.table_info.meta.clone(),
+            })
+            .await?;
+        user_mgr.remove_dropped_table(&dropped).await?;
+
+        if let Some(owner) = &dropped.owner {
+            let object = OwnershipObject::Table(db_name.to_string(), tbl_name.to_string());
+            user_mgr.grant_ownership(&object, owner, false).await?;
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::VacuumTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::history_retention_hours;
+use crate::storages::retain_since;
+
+pub struct VacuumTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumTablePlan,
+}
+
+impl VacuumTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(VacuumTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumTableInterpreter {
+    fn name(&self) -> &str {
+        "VacuumTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        let table = self.ctx.get_table(&plan.database, &plan.table).await?;
+        if !plan.dry_run {
+            let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+            user_mgr
+                .verify_writable(&plan.database, &plan.table)
+                .await?;
+        }
+
+        let retain_hours = match plan.retain_hours {
+            Some(hours) => hours,
+            None => {
+                let database = self.ctx.get_catalog().get_database(&plan.database).await?;
+                let conf = self.ctx.get_config();
+                history_retention_hours(&conf, database.as_ref(), table.as_ref())?
+            }
+        };
+        let retain_since = retain_since(retain_hours);
+        let stats = table
+            .vacuum(self.ctx.clone(), retain_since, plan.dry_run)
+            .await?;
+
+        let block = DataBlock::create_by_array(plan.schema(), vec![
+            Series::new(vec![stats.snapshots]),
+            Series::new(vec![stats.segments]),
+            Series::new(vec![stats.blocks]),
+            Series::new(vec![stats.block_bytes]),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            plan.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_types::DroppedTable;
+use common_planners::VacuumDropTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct VacuumDropTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumDropTablePlan,
+}
+
+impl VacuumDropTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumDropTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(VacuumDropTableInterpreter { ctx, plan }))
+    }
+
+    // Removes all the data files of a dropped table, its metadata is already gone.
+    async fn purge(&self, table: &DroppedTable) -> Result<()> {
+        let catalog = self.ctx.get_catalog();
+        let tbl = catalog.get_table_by_info(&table.table_info)?;
+        let keep_last_snapshot = false;
+        tbl.optimize(self.ctx.clone(), keep_last_snapshot).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumDropTableInterpreter {
+    fn name(&self) -> &str {
+        "VacuumDropTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let retain_hours = match self.plan.retain_hours {
+            Some(hours) => hours,
+            None => self.ctx.get_config().query.drop_retention_hours,
+        };
+        let expire_on = Utc::now() - Duration::hours(retain_hours as i64);
+
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        for table in user_mgr.get_dropped_tables().await? {
+            if table.dropped_on <= expire_on {
+                self.purge(&table).await?;
+                user_mgr.remove_dropped_table(&table).await?;
+            }
+        }
+
+        for database in user_mgr.get_dropped_databases().await? {
+            if database.dropped_on <= expire_on {
+                for table in &database.tables {
+                    self.purge(table).await?;
+                }
+                user_mgr.remove_dropped_database(&database).await?;
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_exception::Result;
+use common_planners::TransactionAction;
+use common_planners::TransactionPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct TransactionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: TransactionPlan,
+}
+
+impl TransactionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: TransactionPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(TransactionInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for TransactionInterpreter {
+    fn name(&self) -> &str {
+        "TransactionInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        match self.plan.action {
+            TransactionAction::Begin => self.ctx.begin_transaction()?,
+            // COMMIT and ROLLBACK out of a transaction do nothing
+            TransactionAction::Commit => {
+                if let Some(transaction) = self.ctx.take_transaction() {
+                    let catalog = self.ctx.get_catalog();
+                    catalog.commit_tables(transaction.into_commit_req()).await?;
+                }
+            }
+            // the snapshots written in the transaction are left to VACUUM
+            TransactionAction::Rollback => {
+                if self.ctx.take_transaction().is_some() {
+                    tracing::debug!("transaction of query {} rolled back", self.ctx.get_id());
+                }
+            }
+        }
+
+        let schema = Arc::new(DataSchema::empty());
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+    }
+}
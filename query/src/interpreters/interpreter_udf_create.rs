@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::CreateUDFPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -50,6 +52,25 @@ impl Interpreter for CreatUDFInterpreter {
         let plan = self.plan.clone();
         let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
         let udf = plan.udf;
+        let object = OwnershipObject::UDF(udf.name.clone());
+        let user = self.ctx.get_current_user_with_roles().await.ok();
+        if plan.or_replace {
+            user_mgr
+                .verify_ownership(&object, user.as_ref(), UserPrivilegeType::Drop)
+                .await?;
+            user_mgr.replace_udf(udf).await?;
+            if let Some(user) = user {
+                user_mgr
+                    .grant_ownership(&object, &user.identity(), false)
+                    .await?;
+            }
+            return Ok(Box::pin(DataBlockStream::create(
+                self.plan.schema(),
+                None,
+                vec![],
+            )));
+        }
+
         let create_udf = user_mgr.add_udf(udf).await;
         if plan.if_not_exists {
             create_udf.or_else(|e| {
@@ -64,6 +85,12 @@ impl Interpreter for CreatUDFInterpreter {
             create_udf?;
         }
 
+        if let Some(user) = user {
+            user_mgr
+                .grant_ownership(&object, &user.identity(), plan.if_not_exists)
+                .await?;
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
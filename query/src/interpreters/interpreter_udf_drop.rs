@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::DropUDFPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -49,9 +51,16 @@ impl Interpreter for DropUDFInterpreter {
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
         let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let object = OwnershipObject::UDF(plan.name.clone());
+        let user = self.ctx.get_current_user_with_roles().await.ok();
+        user_mgr
+            .verify_ownership(&object, user.as_ref(), UserPrivilegeType::Drop)
+            .await?;
+
         user_mgr
             .drop_udf(plan.name.as_str(), plan.if_exists)
             .await?;
+        user_mgr.drop_object_owner(&object).await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -23,6 +23,7 @@ use common_tracing::tracing;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::users::encode_password;
 
 #[derive(Debug)]
 pub struct AlterUserInterpreter {
@@ -54,8 +55,8 @@ impl Interpreter for AlterUserInterpreter {
             .update_user(
                 plan.name.as_str(),
                 plan.hostname.as_str(),
-                Some(plan.new_password_type),
-                Some(plan.new_password),
+                Some(plan.new_password_type.clone()),
+                Some(encode_password(&plan.new_password_type, &plan.new_password)),
             )
             .await?;
 
@@ -26,6 +26,7 @@ use common_tracing::tracing;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::users::encode_password;
 
 #[derive(Debug)]
 pub struct CreateUserInterpreter {
@@ -55,10 +56,12 @@ impl Interpreter for CreateUserInterpreter {
         let user_info = UserInfo {
             name: plan.name,
             hostname: plan.hostname,
-            password: plan.password,
+            password: encode_password(&plan.password_type, &plan.password),
             password_type: plan.password_type,
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
+            network_policy: None,
+            roles: vec![],
         };
         user_mgr.add_user(user_info).await?;
 
@@ -13,32 +13,61 @@
 // limitations under the License.
 
 mod interpreter;
+mod interpreter_alter_owner;
+mod interpreter_alter_read_only;
 mod interpreter_common;
+mod interpreter_connection_create;
+mod interpreter_connection_drop;
 mod interpreter_copy;
+mod interpreter_copy_into_stage;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_database_undrop;
+mod interpreter_delete;
 mod interpreter_describe_stage;
 mod interpreter_describe_table;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_grant_privilege;
+mod interpreter_grant_role;
+mod interpreter_grant_role_privilege;
 mod interpreter_insert;
+mod interpreter_insert_async;
 mod interpreter_insert_with_stream;
 mod interpreter_interceptor;
 mod interpreter_kill;
+mod interpreter_network_policy_create;
+mod interpreter_network_policy_drop;
 mod interpreter_query_log;
+mod interpreter_revoke_all_privileges;
 mod interpreter_revoke_privilege;
+mod interpreter_revoke_role;
+mod interpreter_revoke_role_privilege;
+mod interpreter_role_create;
+mod interpreter_role_drop;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
+mod interpreter_set_role;
+mod interpreter_set_secondary_roles;
 mod interpreter_setting;
 mod interpreter_show_create_database;
 mod interpreter_show_create_table;
 mod interpreter_show_grants;
 mod interpreter_stage_create;
 mod interpreter_stage_drop;
+mod interpreter_table_alter_column;
+mod interpreter_table_alter_options;
+mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_drop;
+mod interpreter_table_export;
 mod interpreter_table_optimize;
 mod interpreter_table_truncate;
+mod interpreter_table_undrop;
+mod interpreter_table_vacuum;
+mod interpreter_table_vacuum_drop;
+mod interpreter_transaction;
 mod interpreter_udf_alter;
 mod interpreter_udf_create;
 mod interpreter_udf_drop;
@@ -47,35 +76,66 @@ mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
+mod interpreter_user_network_policy_alter;
 mod plan_schedulers;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_alter_owner::AlterOwnerInterpreter;
+pub use interpreter_alter_read_only::AlterReadOnlyInterpreter;
+pub use interpreter_connection_create::CreateConnectionInterpreter;
+pub use interpreter_connection_drop::DropConnectionInterpreter;
+pub(crate) use interpreter_copy::stage_location_dal;
 pub use interpreter_copy::CopyInterpreter;
+pub use interpreter_copy_into_stage::CopyIntoStageInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_database_undrop::UndropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_describe_stage::DescribeStageInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_grant_privilege::GrantPrivilegeInterpreter;
+pub use interpreter_grant_role::GrantRoleInterpreter;
+pub use interpreter_grant_role_privilege::GrantRolePrivilegeInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_insert_async::AsyncInsertQueue;
 pub use interpreter_interceptor::InterceptorInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
 pub use interpreter_query_log::LogEvent;
 pub use interpreter_query_log::LogType;
+pub use interpreter_revoke_all_privileges::RevokeAllPrivilegesInterpreter;
 pub use interpreter_revoke_privilege::RevokePrivilegeInterpreter;
+pub use interpreter_revoke_role::RevokeRoleInterpreter;
+pub use interpreter_revoke_role_privilege::RevokeRolePrivilegeInterpreter;
+pub use interpreter_role_create::CreateRoleInterpreter;
+pub use interpreter_role_drop::DropRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_set_role::SetRoleInterpreter;
+pub use interpreter_set_secondary_roles::SetSecondaryRolesInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_database::ShowCreateDatabaseInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_stage_create::CreatStageInterpreter;
 pub use interpreter_stage_drop::DropStageInterpreter;
+pub use interpreter_table_alter_column::AlterTableColumnInterpreter;
+pub use interpreter_table_alter_options::AlterTableOptionsInterpreter;
+pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_export::ExportTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
+pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_table_vacuum_drop::VacuumDropTableInterpreter;
+pub use interpreter_transaction::TransactionInterpreter;
 pub use interpreter_udf_alter::AlterUDFInterpreter;
 pub use interpreter_udf_create::CreatUDFInterpreter;
 pub use interpreter_udf_drop::DropUDFInterpreter;
@@ -84,4 +144,5 @@ pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
+pub use interpreter_user_network_policy_alter::AlterUserNetworkPolicyInterpreter;
 pub use plan_schedulers::PlanScheduler;
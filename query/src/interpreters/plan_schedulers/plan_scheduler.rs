@@ -23,12 +23,14 @@ use common_meta_types::NodeInfo;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::DistinctPlan;
 use common_planners::EmptyPlan;
 use common_planners::Expression;
 use common_planners::ExpressionPlan;
 use common_planners::Expressions;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::Partitions;
@@ -302,6 +304,8 @@ impl PlanScheduler {
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
+            PlanNode::Distinct(plan) => self.visit_distinct(plan, tasks),
+            PlanNode::Join(plan) => self.visit_join(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
             PlanNode::Sink(plan) => self.visit_sink(plan, tasks),
             PlanNode::Select(plan) => self.visit_select(plan, tasks),
@@ -780,6 +784,55 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_distinct(&mut self, plan: &DistinctPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_distinct(),
+            RunningMode::Standalone => self.visit_local_distinct(),
+        };
+        Ok(())
+    }
+
+    fn visit_local_distinct(&mut self) {
+        self.nodes_plan[self.local_pos] = PlanNode::Distinct(DistinctPlan {
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_distinct(&mut self) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Distinct(DistinctPlan {
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
+    // The hash join runs on the local node only, both of its sides are scheduled there.
+    fn visit_join(&mut self, plan: &JoinPlan, tasks: &mut Tasks) -> Result<()> {
+        let left = self.visit_local_join_input(plan.left.as_ref(), tasks)?;
+        let right = self.visit_local_join_input(plan.right.as_ref(), tasks)?;
+        self.nodes_plan[self.local_pos] = PlanNode::Join(JoinPlan {
+            left,
+            right,
+            ..plan.clone()
+        });
+        Ok(())
+    }
+
+    fn visit_local_join_input(
+        &mut self,
+        input: &PlanNode,
+        tasks: &mut Tasks,
+    ) -> Result<Arc<PlanNode>> {
+        self.visit_plan_node(input, tasks)?;
+        match self.running_mode {
+            RunningMode::Standalone => Ok(Arc::new(self.nodes_plan[self.local_pos].clone())),
+            RunningMode::Cluster => Err(ErrorCode::UnImplement(
+                "Cannot join the tables read by the cluster yet",
+            )),
+        }
+    }
+
     fn visit_data_source(&mut self, plan: &ReadDataSourcePlan, _: &mut Tasks) -> Result<()> {
         let table = self.query_context.build_table_from_source_plan(plan)?;
 
@@ -17,11 +17,13 @@
 #![feature(arbitrary_self_types)]
 
 pub mod api;
+pub mod audit;
 pub mod catalogs;
 pub mod clusters;
 pub mod common;
 pub mod configs;
 pub mod databases;
+pub mod formats;
 pub mod functions;
 pub mod interpreters;
 pub mod metrics;
@@ -14,7 +14,10 @@
 
 mod metrics;
 mod optimizer;
+mod optimizer_approx_count_distinct;
 mod optimizer_constant_folding;
+mod optimizer_distinct_aggregate;
+mod optimizer_empty_result;
 mod optimizer_expression_transform;
 mod optimizer_scatters;
 mod optimizer_statistics_exact;
@@ -23,7 +26,10 @@ mod utils;
 
 pub use optimizer::Optimizer;
 pub use optimizer::Optimizers;
+pub use optimizer_approx_count_distinct::ApproxCountDistinctOptimizer;
 pub use optimizer_constant_folding::ConstantFoldingOptimizer;
+pub use optimizer_distinct_aggregate::DistinctAggregateOptimizer;
+pub use optimizer_empty_result::EmptyResultOptimizer;
 pub use optimizer_expression_transform::ExprTransformOptimizer;
 pub use optimizer_scatters::ScattersOptimizer;
 pub use optimizer_statistics_exact::StatisticsExactOptimizer;
@@ -20,7 +20,9 @@ use common_tracing::tracing;
 use metrics::histogram;
 
 use crate::optimizers::optimizer_scatters::ScattersOptimizer;
+use crate::optimizers::ApproxCountDistinctOptimizer;
 use crate::optimizers::ConstantFoldingOptimizer;
+use crate::optimizers::DistinctAggregateOptimizer;
 use crate::optimizers::ExprTransformOptimizer;
 use crate::optimizers::StatisticsExactOptimizer;
 use crate::optimizers::TopNPushDownOptimizer;
@@ -50,7 +52,9 @@ impl Optimizers {
                 Box::new(ConstantFoldingOptimizer::create(ctx.clone())),
                 Box::new(ExprTransformOptimizer::create(ctx.clone())),
                 Box::new(TopNPushDownOptimizer::create(ctx.clone())),
-                Box::new(StatisticsExactOptimizer::create(ctx)),
+                Box::new(StatisticsExactOptimizer::create(ctx.clone())),
+                Box::new(ApproxCountDistinctOptimizer::create(ctx.clone())),
+                Box::new(DistinctAggregateOptimizer::create(ctx)),
             ],
         }
     }
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::AggregatorFinalPlan;
+use common_planners::Expression;
+use common_planners::PlanBuilder;
+use common_planners::PlanNode;
+use common_planners::PlanRewriter;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Replaces the exact `count(DISTINCT x)` by `approx_count_distinct(x)` once the aggregation
+/// reads more than `approx_count_distinct_min_rows` rows, as estimated by the statistics of the
+/// tables. The rows read bound the number of the distinct values.
+///
+/// The results keep their names, the plan is annotated by an `Approximate COUNT DISTINCT`
+/// expression, e.g. in EXPLAIN.
+pub struct ApproxCountDistinctOptimizer {
+    ctx: Arc<QueryContext>,
+}
+
+struct ApproxCountDistinctImpl {
+    min_rows: usize,
+}
+
+struct ReadRowsVisitor {
+    rows: usize,
+}
+
+impl PlanVisitor for ReadRowsVisitor {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        self.rows += plan.statistics.read_rows;
+        Ok(())
+    }
+}
+
+impl ApproxCountDistinctImpl {
+    fn is_count_distinct(expr: &Expression) -> bool {
+        matches!(expr, Expression::AggregateFunction { op, distinct: true, params, .. }
+            if params.is_empty() && op.to_lowercase() == "count")
+    }
+
+    fn approximate(expr: &Expression) -> Expression {
+        match expr {
+            Expression::AggregateFunction { args, .. } if Self::is_count_distinct(expr) => {
+                Expression::AggregateFunction {
+                    op: "approx_count_distinct".to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: args.clone(),
+                }
+            }
+            _ => expr.clone(),
+        }
+    }
+
+    fn estimated_rows(plan: &PlanNode) -> Result<usize> {
+        let mut visitor = ReadRowsVisitor { rows: 0 };
+        visitor.visit_plan_node(plan)?;
+        Ok(visitor.rows)
+    }
+}
+
+impl PlanRewriter for ApproxCountDistinctImpl {
+    fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
+        let input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let rows = Self::estimated_rows(&input)?;
+        let partial = match &input {
+            PlanNode::AggregatorPartial(partial)
+                if rows > self.min_rows && plan.aggr_expr.iter().any(Self::is_count_distinct) =>
+            {
+                partial
+            }
+            _ => {
+                return Ok(PlanNode::AggregatorFinal(AggregatorFinalPlan {
+                    input: Arc::new(input),
+                    ..plan.clone()
+                }));
+            }
+        };
+
+        let aggr_expr = plan
+            .aggr_expr
+            .iter()
+            .map(Self::approximate)
+            .collect::<Vec<_>>();
+
+        // The results are renamed back, e.g. approx_count_distinct(x) as count(distinct x).
+        let annotation = plan
+            .aggr_expr
+            .iter()
+            .zip(aggr_expr.iter())
+            .filter(|(expr, approximate)| expr != approximate)
+            .map(|(expr, approximate)| {
+                Expression::Column(approximate.column_name()).alias(&expr.column_name())
+            })
+            .collect::<Vec<_>>();
+        let projection = plan
+            .aggr_expr
+            .iter()
+            .chain(plan.group_expr.iter())
+            .map(|expr| Expression::Column(expr.column_name()))
+            .collect::<Vec<_>>();
+
+        PlanBuilder::from(partial.input.as_ref())
+            .aggregate_partial(&aggr_expr, &partial.group_expr)?
+            .aggregate_final(
+                plan.schema_before_group_by.clone(),
+                &aggr_expr,
+                &plan.group_expr,
+            )?
+            .expression(&annotation, "Approximate COUNT DISTINCT")?
+            .project(&projection)?
+            .build()
+    }
+}
+
+impl Optimizer for ApproxCountDistinctOptimizer {
+    fn name(&self) -> &str {
+        "ApproxCountDistinct"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let min_rows = self
+            .ctx
+            .get_settings()
+            .get_approx_count_distinct_min_rows()?;
+        if min_rows == 0 {
+            return Ok(plan.clone());
+        }
+
+        let mut visitor = ApproxCountDistinctImpl {
+            min_rows: min_rows as usize,
+        };
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl ApproxCountDistinctOptimizer {
+    pub fn create(ctx: Arc<QueryContext>) -> Self {
+        ApproxCountDistinctOptimizer { ctx }
+    }
+}
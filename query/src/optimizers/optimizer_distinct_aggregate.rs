@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_planners::AggregatorFinalPlan;
+use common_planners::AggregatorPartialPlan;
+use common_planners::Expression;
+use common_planners::PlanBuilder;
+use common_planners::PlanNode;
+use common_planners::PlanRewriter;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// The aggregate functions whose DISTINCT is the function of the distinct values.
+const DISTINCT_AGGREGATES: [&str; 5] = ["count", "sum", "avg", "min", "max"];
+
+/// Computes the DISTINCT aggregates of one argument by grouping by the argument first:
+///
+/// `SELECT g, count(DISTINCT x), sum(DISTINCT x) FROM t GROUP BY g`
+///
+/// is computed as
+///
+/// `SELECT g, count(x), sum(x) FROM (SELECT g, x FROM t GROUP BY g, x) GROUP BY g`
+///
+/// Otherwise every group keeps its distinct values in a hash set, in a single thread at the
+/// final aggregation. The group by deduplicates the values in parallel, and in the cluster.
+///
+/// The aggregates with different arguments, or mixed with the aggregates without DISTINCT, still
+/// use the hash sets, as do the nullable arguments, of which the NULL values are ignored.
+pub struct DistinctAggregateOptimizer {
+    ctx: Arc<QueryContext>,
+}
+
+struct DistinctAggregateImpl;
+
+impl DistinctAggregateImpl {
+    /// The argument of the DISTINCT aggregates if they can be rewritten.
+    fn distinct_argument(
+        aggr_expr: &[Expression],
+        schema: &DataSchemaRef,
+    ) -> Result<Option<Expression>> {
+        let mut argument = None;
+        for expr in aggr_expr {
+            match expr {
+                Expression::AggregateFunction {
+                    op,
+                    distinct: true,
+                    params,
+                    args,
+                } if params.is_empty() && args.len() == 1 && Self::is_supported(op) => {
+                    match &argument {
+                        None => argument = Some(args[0].clone()),
+                        Some(arg) if arg == &args[0] => {}
+                        Some(_) => return Ok(None),
+                    }
+                }
+                _ => return Ok(None),
+            }
+        }
+
+        match argument {
+            Some(arg) if !arg.to_data_field(schema)?.is_nullable() => Ok(Some(arg)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Keeps the variants of the numeric_overflow_mode, e.g. checked_sum.
+    fn is_supported(op: &str) -> bool {
+        let op = op.to_lowercase();
+        let name = op.rsplit('_').next().unwrap_or_default();
+        DISTINCT_AGGREGATES.contains(&name)
+    }
+
+    fn rewrite_distinct(
+        plan: &AggregatorFinalPlan,
+        input: &PlanNode,
+        argument: Expression,
+    ) -> Result<PlanNode> {
+        let mut distinct_group_expr = plan.group_expr.clone();
+        if !distinct_group_expr
+            .iter()
+            .any(|expr| expr.column_name() == argument.column_name())
+        {
+            distinct_group_expr.push(argument.clone());
+        }
+
+        let distinct_plan = PlanBuilder::from(input)
+            .aggregate_partial(&[], &distinct_group_expr)?
+            .aggregate_final(input.schema(), &[], &distinct_group_expr)?
+            .build()?;
+
+        let group_expr = plan
+            .group_expr
+            .iter()
+            .map(|expr| Expression::Column(expr.column_name()))
+            .collect::<Vec<_>>();
+        let aggr_expr = plan
+            .aggr_expr
+            .iter()
+            .map(|expr| match expr {
+                Expression::AggregateFunction { op, params, .. } => Expression::AggregateFunction {
+                    op: op.clone(),
+                    distinct: false,
+                    params: params.clone(),
+                    args: vec![Expression::Column(argument.column_name())],
+                },
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        // The columns are renamed back, e.g. count(x) as count(distinct x).
+        let mut projection = plan
+            .aggr_expr
+            .iter()
+            .zip(aggr_expr.iter())
+            .map(|(expr, rewritten)| {
+                Expression::Column(rewritten.column_name()).alias(&expr.column_name())
+            })
+            .collect::<Vec<_>>();
+        projection.extend(group_expr.iter().cloned());
+
+        PlanBuilder::from(&distinct_plan)
+            .aggregate_partial(&aggr_expr, &group_expr)?
+            .aggregate_final(distinct_plan.schema(), &aggr_expr, &group_expr)?
+            .project(&projection)?
+            .build()
+    }
+}
+
+impl PlanRewriter for DistinctAggregateImpl {
+    fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
+        let partial = match plan.input.as_ref() {
+            PlanNode::AggregatorPartial(partial) => partial,
+            other => {
+                let input = self.rewrite_plan_node(other)?;
+                return Ok(PlanNode::AggregatorFinal(AggregatorFinalPlan {
+                    input: Arc::new(input),
+                    ..plan.clone()
+                }));
+            }
+        };
+
+        let input = self.rewrite_plan_node(partial.input.as_ref())?;
+        match Self::distinct_argument(&plan.aggr_expr, &input.schema())? {
+            Some(argument) => Self::rewrite_distinct(plan, &input, argument),
+            None => Ok(PlanNode::AggregatorFinal(AggregatorFinalPlan {
+                input: Arc::new(PlanNode::AggregatorPartial(AggregatorPartialPlan {
+                    input: Arc::new(input),
+                    ..partial.clone()
+                })),
+                ..plan.clone()
+            })),
+        }
+    }
+}
+
+impl Optimizer for DistinctAggregateOptimizer {
+    fn name(&self) -> &str {
+        "DistinctAggregate"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        if self
+            .ctx
+            .get_settings()
+            .get_enable_distinct_aggregate_rewrite()?
+            == 0
+        {
+            return Ok(plan.clone());
+        }
+
+        let mut visitor = DistinctAggregateImpl;
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl DistinctAggregateOptimizer {
+    pub fn create(ctx: Arc<QueryContext>) -> Self {
+        DistinctAggregateOptimizer { ctx }
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::EmptyPlan;
+use common_planners::JoinType;
+use common_planners::PlanNode;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Replaces a plan whose result is known to be empty at plan time by an [`EmptyPlan`] of the
+/// same schema, so that no pipeline is built nor scheduled for it.
+///
+/// The result is empty once every table read has no partitions left, e.g. all of them are
+/// pruned, or the scan is skipped by the `ExprTransform` optimizer for `WHERE false` and
+/// `LIMIT 0`. The aggregations without GROUP BY still return one row, so they are kept.
+///
+/// It runs after the other optimizers, on the plan about to be executed, so that EXPLAIN still
+/// shows the plan of the tables read.
+pub struct EmptyResultOptimizer {}
+
+impl EmptyResultOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> Self {
+        EmptyResultOptimizer {}
+    }
+
+    fn is_empty_result(plan: &PlanNode) -> bool {
+        match plan {
+            PlanNode::ReadSource(plan) => plan.parts.is_empty(),
+            PlanNode::Limit(plan) => plan.n == Some(0) || Self::is_empty_result(&plan.input),
+            PlanNode::AggregatorFinal(plan) => {
+                !plan.group_expr.is_empty() && Self::is_empty_result(&plan.input)
+            }
+            PlanNode::AggregatorPartial(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::Filter(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::Having(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::Expression(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::Projection(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::Sort(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::LimitBy(plan) => Self::is_empty_result(&plan.input),
+            PlanNode::Distinct(plan) => Self::is_empty_result(&plan.input),
+            // An outer side keeps its rows even without any match on the other side.
+            PlanNode::Join(plan) => match plan.join_type {
+                JoinType::Inner => {
+                    Self::is_empty_result(&plan.left) || Self::is_empty_result(&plan.right)
+                }
+                JoinType::Left => Self::is_empty_result(&plan.left),
+                JoinType::Right => Self::is_empty_result(&plan.right),
+                JoinType::Full => {
+                    Self::is_empty_result(&plan.left) && Self::is_empty_result(&plan.right)
+                }
+            },
+            // The stages of the cluster only move the rows between the nodes.
+            PlanNode::Stage(plan) => Self::is_empty_result(&plan.input),
+            _ => false,
+        }
+    }
+}
+
+impl Optimizer for EmptyResultOptimizer {
+    fn name(&self) -> &str {
+        "EmptyResult"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        match Self::is_empty_result(plan) {
+            true => Ok(PlanNode::Empty(EmptyPlan::create_with_schema(
+                plan.schema(),
+            ))),
+            false => Ok(plan.clone()),
+        }
+    }
+}
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::DistinctPlan;
 use common_planners::Expression;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
@@ -174,6 +175,27 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_distinct(&mut self) -> Result<PlanNode> {
+        // Distinct we convergent it in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Cluster distinct input is None.")),
+            Some(input) => Self::convergent_shuffle_stage_builder(input)
+                .distinct()?
+                .build(),
+        }
+    }
+
+    fn standalone_distinct(&mut self) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Standalone distinct input is None.",
+            )),
+            Some(input) => PlanBuilder::from(input.as_ref()).distinct()?.build(),
+        }
+    }
+
     fn convergent_shuffle_stage_builder(input: Arc<PlanNode>) -> PlanBuilder {
         PlanBuilder::from(&PlanNode::Stage(StagePlan {
             kind: StageKind::Convergent,
@@ -278,6 +300,15 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_distinct(),
+            RunningMode::Standalone => self.standalone_distinct(),
+        }
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         let t = self.ctx.build_table_from_source_plan(plan)?;
 
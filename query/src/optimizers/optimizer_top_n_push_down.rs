@@ -77,6 +77,31 @@ impl PlanRewriter for TopNPushDownImpl {
         }
     }
 
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        // The rows below distinct may be duplicated, the top n of them is not enough.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input).distinct()?.build()
+    }
+
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // The top n of the joined rows may come from any rows of either side.
+        self.limit = None;
+        self.order_by = vec![];
+
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_plan_node(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                &new_right,
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+            )?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let current_limit = self.limit;
         let current_order_by = self.order_by.clone();
@@ -110,10 +135,9 @@ impl PlanRewriter for TopNPushDownImpl {
                     };
 
                     Some(Extras {
-                        projection: extras.projection.clone(),
-                        filters: extras.filters.clone(),
                         limit: Some(new_limit),
                         order_by: self.get_sort_columns(plan.schema())?,
+                        ..extras.clone()
                     })
                 }
                 None => {
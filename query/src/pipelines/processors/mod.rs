@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod metrics;
 mod pipe;
 mod pipeline;
 mod pipeline_builder;
@@ -20,6 +21,7 @@ mod pipeline_walker;
 mod processor;
 mod processor_empty;
 mod processor_merge;
+mod processor_metered;
 mod processor_mixed;
 
 pub use pipe::Pipe;
@@ -29,4 +31,7 @@ pub use processor::FormatterSettings;
 pub use processor::Processor;
 pub use processor_empty::EmptyProcessor;
 pub use processor_merge::MergeProcessor;
+pub use processor_metered::MeteredProcessor;
+pub use processor_metered::ProcessorStats;
 pub use processor_mixed::MixedProcessor;
+pub use processor_mixed::MixedScatter;
@@ -15,15 +15,35 @@
 use std::sync::Arc;
 
 use crate::pipelines::processors::Processor;
+use crate::pipelines::processors::ProcessorStats;
 
 #[derive(Clone)]
 pub struct Pipe {
     processors: Vec<Arc<dyn Processor>>,
+    stats: Option<Arc<ProcessorStats>>,
 }
 
 impl Pipe {
     pub fn create() -> Self {
-        Pipe { processors: vec![] }
+        Pipe {
+            processors: vec![],
+            stats: None,
+        }
+    }
+
+    /// A pipe of the [`MeteredProcessor`]s sharing the stats.
+    ///
+    /// [`MeteredProcessor`]: crate::pipelines::processors::MeteredProcessor
+    pub fn create_metered(stats: Arc<ProcessorStats>) -> Self {
+        Pipe {
+            processors: vec![],
+            stats: Some(stats),
+        }
+    }
+
+    /// The stats of the processors, None if they are not metered.
+    pub fn stats(&self) -> Option<Arc<ProcessorStats>> {
+        self.stats.clone()
     }
 
     pub fn nums(&self) -> usize {
@@ -21,10 +21,14 @@ use common_tracing::tracing;
 
 use super::MixedProcessor;
 use crate::pipelines::processors::MergeProcessor;
+use crate::pipelines::processors::MeteredProcessor;
+use crate::pipelines::processors::MixedScatter;
 use crate::pipelines::processors::Pipe;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::processors::ProcessorStats;
 use crate::sessions::QueryContext;
 
+#[derive(Clone)]
 pub struct Pipeline {
     ctx: Arc<QueryContext>,
     pipes: Vec<Pipe>,
@@ -62,11 +66,17 @@ impl Pipeline {
 
     pub fn add_source(&mut self, source: Arc<dyn Processor>) -> Result<()> {
         if self.pipes.first().is_none() {
-            let mut first = Pipe::create();
-            first.add(source);
-            self.pipes.push(first);
-        } else {
-            self.pipes[0].add(source);
+            let stats = Arc::new(ProcessorStats::default());
+            self.pipes.push(Pipe::create_metered(stats));
+        }
+        let first = &mut self.pipes[0];
+        match first.stats() {
+            Some(stats) => first.add(Arc::new(MeteredProcessor::create(
+                source,
+                stats,
+                self.ctx.get_memory_tracker(),
+            ))),
+            None => first.add(source),
         }
         Ok(())
     }
@@ -84,11 +94,16 @@ impl Pipeline {
         f: impl Fn() -> Result<Box<dyn Processor>>,
     ) -> Result<()> {
         let last_pipe = self.last_pipe()?;
-        let mut new_pipe = Pipe::create();
+        let stats = Arc::new(ProcessorStats::default());
+        let mut new_pipe = Pipe::create_metered(stats.clone());
         for x in last_pipe.processors() {
             let mut p = f()?;
             p.connect_to(x.clone())?;
-            new_pipe.add(Arc::from(p));
+            new_pipe.add(Arc::new(MeteredProcessor::create(
+                Arc::from(p),
+                stats.clone(),
+                self.ctx.get_memory_tracker(),
+            )));
         }
         self.pipes.push(new_pipe);
         Ok(())
@@ -135,7 +150,19 @@ impl Pipeline {
             return Ok(());
         }
 
-        let mut processor = MixedProcessor::create(self.ctx.clone(), n);
+        let processor = MixedProcessor::create(self.ctx.clone(), n);
+        self.add_mixed_processor(processor, n)
+    }
+
+    /// Mixed M processors into N processes as `mixed_processor`, the rows of each block are
+    /// scattered to the N processes by `scatter`, even if M is N.
+    pub fn scatter_processor(&mut self, n: usize, scatter: MixedScatter) -> Result<()> {
+        let processor = MixedProcessor::create_with_scatter(self.ctx.clone(), n, scatter);
+        self.add_mixed_processor(processor, n)
+    }
+
+    fn add_mixed_processor(&mut self, mut processor: MixedProcessor, n: usize) -> Result<()> {
+        let last_pipe = self.last_pipe()?;
         for x in last_pipe.processors() {
             processor.connect_to(x)?;
         }
@@ -18,9 +18,11 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::DistinctPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanNode;
@@ -38,11 +40,16 @@ use crate::api::FlightTicket;
 use crate::pipelines::processors::Pipeline;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
+use crate::pipelines::transforms::BlockRechunkTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
+use crate::pipelines::transforms::DistinctTransform;
 use crate::pipelines::transforms::ExpressionTransform;
+use crate::pipelines::transforms::GraceHashJoin;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
+use crate::pipelines::transforms::HashJoinTransform;
 use crate::pipelines::transforms::HavingTransform;
+use crate::pipelines::transforms::JoinParams;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
 use crate::pipelines::transforms::ProjectionTransform;
@@ -94,6 +101,8 @@ impl PipelineBuilder {
             PlanNode::Sort(node) => self.visit_sort(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
+            PlanNode::Distinct(node) => self.visit_distinct(node),
+            PlanNode::Join(node) => self.visit_join(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             PlanNode::Sink(node) => self.visit_sink(node),
@@ -174,13 +183,21 @@ impl PipelineBuilder {
                 )?))
             })?;
         } else {
+            let settings = self.ctx.get_settings();
+            let pass_through_min_rows = settings.get_group_by_pass_through_min_rows()? as usize;
+            let pass_through_ratio = settings.get_group_by_pass_through_ratio()? as usize;
+            let max_memory_bytes = settings.get_group_by_max_memory_bytes()? as usize;
             pipeline.add_simple_transform(|| {
-                Ok(Box::new(GroupByPartialTransform::create(
-                    node.schema(),
-                    node.input.schema(),
-                    node.aggr_expr.clone(),
-                    node.group_expr.clone(),
-                )))
+                Ok(Box::new(
+                    GroupByPartialTransform::create(
+                        node.schema(),
+                        node.input.schema(),
+                        node.aggr_expr.clone(),
+                        node.group_expr.clone(),
+                    )
+                    .with_pass_through(pass_through_min_rows, pass_through_ratio)
+                    .with_max_memory_bytes(max_memory_bytes),
+                ))
             })?;
         }
         Ok(pipeline)
@@ -188,7 +205,15 @@ impl PipelineBuilder {
 
     fn visit_aggregator_final(&mut self, node: &AggregatorFinalPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
-        pipeline.merge_processor()?;
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+
+        if node.group_expr.is_empty() || max_threads <= 1 {
+            pipeline.merge_processor()?;
+        } else {
+            // Each group is merged by one of the final transforms, by the hash of its key.
+            let scatter = GroupByFinalTransform::scatter_by_group_key(node.aggr_expr.len());
+            pipeline.scatter_processor(max_threads, scatter)?;
+        }
 
         if node.group_expr.is_empty() {
             pipeline.add_simple_transform(|| {
@@ -199,17 +224,23 @@ impl PipelineBuilder {
                 )?))
             })?;
         } else {
-            let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+            let settings = self.ctx.get_settings();
+            let max_block_size = settings.get_max_block_size()? as usize;
+            let max_memory_bytes = settings.get_group_by_max_memory_bytes()? as usize;
+            let spill_dir = self.ctx.get_spill_dir()?;
             pipeline.add_simple_transform(|| {
-                Ok(Box::new(GroupByFinalTransform::create(
-                    node.schema(),
-                    max_block_size,
-                    node.schema_before_group_by.clone(),
-                    node.aggr_expr.clone(),
-                    node.group_expr.clone(),
-                )))
+                Ok(Box::new(
+                    GroupByFinalTransform::create(
+                        node.schema(),
+                        max_block_size,
+                        node.schema_before_group_by.clone(),
+                        node.aggr_expr.clone(),
+                        node.group_expr.clone(),
+                    )
+                    .with_spill(max_memory_bytes, spill_dir.clone()),
+                ))
             })?;
-            pipeline.mixed_processor(self.ctx.get_settings().get_max_threads()? as usize)?;
+            pipeline.mixed_processor(max_threads)?;
         }
         Ok(pipeline)
     }
@@ -222,6 +253,7 @@ impl PipelineBuilder {
                 node.predicate.clone(),
             )?))
         })?;
+        self.add_block_rechunk_transform(&mut pipeline)?;
         Ok(pipeline)
     }
 
@@ -233,9 +265,27 @@ impl PipelineBuilder {
                 node.predicate.clone(),
             )?))
         })?;
+        self.add_block_rechunk_transform(&mut pipeline)?;
         Ok(pipeline)
     }
 
+    /// Normalize the blocks left by the filters, which may be tiny, before the next stage.
+    fn add_block_rechunk_transform(&self, pipeline: &mut Pipeline) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        if settings.get_enable_block_rechunk()? == 0 {
+            return Ok(());
+        }
+
+        let max_block_size = settings.get_max_block_size()? as usize;
+        let max_block_bytes = settings.get_max_block_bytes()? as usize;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(BlockRechunkTransform::try_create(
+                max_block_size,
+                max_block_bytes,
+            )?))
+        })
+    }
+
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
 
@@ -243,27 +293,40 @@ impl PipelineBuilder {
         // 'select * from numbers(100) order by number desc limit 10 offset 5', the
         // sort pipeline should return at least 15 rows.
         let rows_limit = self.limit.map(|limit| limit + self.offset);
+        let settings = self.ctx.get_settings();
+        let max_block_size = settings.get_max_block_size()? as usize;
+        let max_memory_bytes = settings.get_sort_max_memory_bytes()? as usize;
+        let spill_dir = self.ctx.get_spill_dir()?;
+
+        // the input may be in the order already, e.g. the numbers table by number: the sort is
+        // skipped if it is read in one stream, or else the sorted blocks are only merged
+        let input_order = plan.input.output_order();
+        let input_sorted = input_order.satisfies(&plan.order_by, &plan.schema());
+        if input_sorted && input_order.total && pipeline.last_pipe()?.nums() == 1 {
+            return Ok(pipeline);
+        }
 
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
         // processor 3: block ---> sort_stream
-        pipeline.add_simple_transform(|| {
-            Ok(Box::new(SortPartialTransform::try_create(
-                plan.schema(),
-                plan.order_by.clone(),
-                rows_limit,
-            )?))
-        })?;
+        if !input_sorted {
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(SortPartialTransform::try_create(
+                    plan.schema(),
+                    plan.order_by.clone(),
+                    rows_limit,
+                )?))
+            })?;
+        }
 
         // processor 1: [sorted blocks ...] ---> merge to one sorted block
         // processor 2: [sorted blocks ...] ---> merge to one sorted block
         // processor 3: [sorted blocks ...] ---> merge to one sorted block
         pipeline.add_simple_transform(|| {
-            Ok(Box::new(SortMergeTransform::try_create(
-                plan.schema(),
-                plan.order_by.clone(),
-                rows_limit,
-            )?))
+            Ok(Box::new(
+                SortMergeTransform::try_create(plan.schema(), plan.order_by.clone(), rows_limit)?
+                    .with_spill(max_block_size, max_memory_bytes, spill_dir.clone()),
+            ))
         })?;
 
         // processor1 sorted block --
@@ -274,11 +337,18 @@ impl PipelineBuilder {
         if pipeline.last_pipe()?.nums() > 1 {
             pipeline.merge_processor()?;
             pipeline.add_simple_transform(|| {
-                Ok(Box::new(SortMergeTransform::try_create(
-                    plan.schema(),
-                    plan.order_by.clone(),
-                    rows_limit,
-                )?))
+                Ok(Box::new(
+                    SortMergeTransform::try_create(
+                        plan.schema(),
+                        plan.order_by.clone(),
+                        rows_limit,
+                    )?
+                    .with_spill(
+                        max_block_size,
+                        max_memory_bytes,
+                        spill_dir.clone(),
+                    ),
+                ))
             })?;
         }
         Ok(pipeline)
@@ -308,6 +378,55 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_distinct(&mut self, node: &DistinctPlan) -> Result<Pipeline> {
+        // The limit is for the distinct rows, the rows below may be duplicated.
+        let rows_limit = self.limit.take().map(|limit| limit + self.offset);
+        let settings = self.ctx.get_settings();
+        let max_memory_bytes = settings.get_distinct_max_memory_bytes()? as usize;
+        let spill_dir = self.ctx.get_spill_dir()?;
+
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(DistinctTransform::create(
+                node.schema(),
+                rows_limit,
+                max_memory_bytes,
+                spill_dir.clone(),
+            )))
+        })?;
+        Ok(pipeline)
+    }
+
+    fn visit_join(&mut self, node: &JoinPlan) -> Result<Pipeline> {
+        // The limit is for the joined rows, neither side may be cut by it.
+        self.limit = None;
+        self.offset = 0;
+        let settings = self.ctx.get_settings();
+        let max_memory_bytes = settings.get_join_max_memory_bytes()? as usize;
+        let spill_dir = self.ctx.get_spill_dir()?;
+
+        // The build side is read by a context of its own, as the subqueries are, since the
+        // partitions of its tables are bound to the context.
+        let build_ctx = QueryContext::new(self.ctx.clone());
+        let mut build_pipeline = PipelineBuilder::create(build_ctx).build(&node.right)?;
+        build_pipeline.merge_processor()?;
+        let build = build_pipeline.last_pipe()?.first();
+
+        // The processors of the probe side share the join, the table built once is probed by
+        // all of them in parallel.
+        let mut pipeline = self.visit(&*node.left)?;
+        let join = GraceHashJoin::create(
+            JoinParams::create(node),
+            build,
+            pipeline.nums(),
+            max_memory_bytes,
+            spill_dir,
+        );
+        pipeline.add_simple_transform(|| Ok(Box::new(HashJoinTransform::create(join.clone()))))?;
+        Ok(pipeline)
+    }
+
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<Pipeline> {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
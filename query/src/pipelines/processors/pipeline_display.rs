@@ -19,7 +19,17 @@ use crate::pipelines::processors::Pipeline;
 
 impl Pipeline {
     pub fn display_indent(&self) -> impl fmt::Display + '_ {
-        struct Wrapper<'a>(&'a Pipeline);
+        self.display_indent_with_stats(false)
+    }
+
+    /// The pipes annotated with the output and the busy time of their processors, once the
+    /// pipeline is executed.
+    pub fn display_analyze(&self) -> impl fmt::Display + '_ {
+        self.display_indent_with_stats(true)
+    }
+
+    fn display_indent_with_stats(&self, with_stats: bool) -> impl fmt::Display + '_ {
+        struct Wrapper<'a>(&'a Pipeline, bool);
         impl<'a> fmt::Display for Wrapper<'a> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let mut indent = 0;
@@ -122,13 +132,23 @@ impl Pipeline {
                         }
                     }
 
+                    if let (true, Some(stats)) = (self.1, pipe.stats()) {
+                        write!(
+                            f,
+                            " (rows: {}, bytes: {}, busy: {:?})",
+                            stats.rows(),
+                            stats.bytes(),
+                            stats.busy_time()
+                        )?;
+                    }
+
                     index += 1;
                     Result::<bool, fmt::Error>::Ok(true)
                 })?;
                 Ok(())
             }
         }
-        Wrapper(self)
+        Wrapper(self, with_stats)
     }
 
     pub fn display_graphviz(&self) -> impl fmt::Display + '_ {
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use common_base::tokio;
 use common_base::tokio::sync::mpsc;
 use common_base::TrySpawn;
 use common_datablocks::DataBlock;
@@ -67,7 +68,18 @@ impl MergeProcessor {
                         Ok(stream) => stream,
                     };
 
-                    while let Some(item) = stream.next().await {
+                    loop {
+                        let item = tokio::select! {
+                            item = stream.next() => item,
+                            // The result is known, e.g. by a LIMIT, the parts being read
+                            // are dropped instead of waiting for them to be sent.
+                            _ = sender.closed() => return,
+                        };
+                        let item = match item {
+                            None => return,
+                            Some(item) => item,
+                        };
+
                         match item {
                             Ok(item) => {
                                 if let Err(error) = sender.send(Ok(item)).await {
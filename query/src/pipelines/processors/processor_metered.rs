@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::MemoryTrackedFuture;
+use common_base::MemoryTracker;
+use common_base::ThreadTracker;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+use metrics::counter;
+use metrics::histogram;
+
+use super::metrics::METRIC_PROCESSOR_BUSY_TIME;
+use super::metrics::METRIC_PROCESSOR_OUTPUT_ROWS;
+use crate::pipelines::processors::Processor;
+
+thread_local! {
+    // The nanoseconds of polling the streams nested in the poll of a metered stream, on
+    // the thread polling it.
+    static NESTED_POLL_NANOS: Cell<u64> = Cell::new(0);
+}
+
+/// The output and the busy time of the processors of a pipe, e.g. for EXPLAIN ANALYZE.
+#[derive(Debug, Default)]
+pub struct ProcessorStats {
+    rows: AtomicU64,
+    bytes: AtomicU64,
+    busy_nanos: AtomicU64,
+}
+
+impl ProcessorStats {
+    pub fn rows(&self) -> u64 {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    /// The memory size of the output blocks.
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    /// The sum of the busy time of the processors, which run in parallel.
+    pub fn busy_time(&self) -> Duration {
+        Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed))
+    }
+}
+
+/// Records the busy time and the output rows of a processor, as the prometheus metrics
+/// labeled by the name of the processor, and into the stats of its pipe.
+///
+/// The memory allocated by the processor is accounted to the memory tracker of the query,
+/// whose limit is checked after each output block.
+pub struct MeteredProcessor {
+    name: String,
+    inner: Arc<dyn Processor>,
+    stats: Arc<ProcessorStats>,
+    memory_tracker: Arc<MemoryTracker>,
+}
+
+impl MeteredProcessor {
+    pub fn create(
+        inner: Arc<dyn Processor>,
+        stats: Arc<ProcessorStats>,
+        memory_tracker: Arc<MemoryTracker>,
+    ) -> Self {
+        MeteredProcessor {
+            name: inner.name().to_string(),
+            inner,
+            stats,
+            memory_tracker,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for MeteredProcessor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        // The processors are connected before they are metered.
+        Result::Err(ErrorCode::IllegalTransformConnectionState(
+            "Cannot call MeteredProcessor connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        self.inner.inputs()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.inner.as_any()
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        // Some processors consume their inputs in execute, e.g. to build the hash tables.
+        let execute = self.inner.execute();
+        let input = MemoryTrackedFuture::create(self.memory_tracker.clone(), execute).await?;
+        Ok(Box::pin(MeteredStream {
+            name: self.name.clone(),
+            input,
+            busy: Duration::default(),
+            stats: self.stats.clone(),
+            memory_tracker: self.memory_tracker.clone(),
+        }))
+    }
+}
+
+struct MeteredStream {
+    name: String,
+    input: SendableDataBlockStream,
+    busy: Duration,
+    stats: Arc<ProcessorStats>,
+    memory_tracker: Arc<MemoryTracker>,
+}
+
+impl Stream for MeteredStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // The time of polling the inputs is the busy time of the processors of the inputs.
+        let outer = NESTED_POLL_NANOS.with(|nanos| nanos.replace(0));
+        let start = Instant::now();
+        let attached = ThreadTracker::attach(self.memory_tracker.clone());
+        let poll = self.input.poll_next_unpin(ctx);
+        drop(attached);
+        let elapsed = start.elapsed().as_nanos() as u64;
+        let nested = NESTED_POLL_NANOS.with(|nanos| nanos.replace(outer + elapsed));
+        let busy_nanos = elapsed.saturating_sub(nested);
+        self.busy += Duration::from_nanos(busy_nanos);
+        self.stats
+            .busy_nanos
+            .fetch_add(busy_nanos, Ordering::Relaxed);
+
+        if let Poll::Ready(Some(Ok(block))) = &poll {
+            counter!(METRIC_PROCESSOR_OUTPUT_ROWS, block.num_rows() as u64, "processor" => self.name.clone());
+            self.stats
+                .rows
+                .fetch_add(block.num_rows() as u64, Ordering::Relaxed);
+            self.stats
+                .bytes
+                .fetch_add(block.memory_size() as u64, Ordering::Relaxed);
+
+            if let Err(cause) = self.memory_tracker.check_limit() {
+                return Poll::Ready(Some(Err(cause)));
+            }
+        }
+        poll
+    }
+}
+
+impl Drop for MeteredStream {
+    fn drop(&mut self) {
+        histogram!(METRIC_PROCESSOR_BUSY_TIME, self.busy, "processor" => self.name.clone());
+    }
+}
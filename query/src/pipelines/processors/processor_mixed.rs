@@ -33,10 +33,15 @@ use crate::pipelines::processors::processor_merge::MergeProcessor;
 use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
 
+/// Splits a block into the blocks of each of the N outputs, e.g. by the hash of a key so that
+/// the rows of a key always go to the same output.
+pub type MixedScatter = Arc<dyn Fn(&DataBlock, usize) -> Result<Vec<DataBlock>> + Send + Sync>;
+
 // M inputs--> N outputs Mixed processor
 struct MixedWorker {
     ctx: Arc<QueryContext>,
     n: usize,
+    scatter: Option<MixedScatter>,
     shared_num: AtomicUsize,
     started: AtomicBool,
     receivers: Vec<Option<mpsc::Receiver<Result<DataBlock>>>>,
@@ -60,13 +65,29 @@ impl MixedWorker {
         }
 
         let mut stream = self.merger.merge()?;
+        let scatter = self.scatter.clone();
         self.ctx.try_spawn(async move {
             let index = AtomicUsize::new(0);
             while let Some(item) = stream.next().await {
-                let i = index.fetch_add(1, Ordering::Relaxed) % outputs_len;
-                // TODO: USE try_reserve when the channel is blocking
-                if let Err(error) = senders[i].send(item).await {
-                    tracing::error!("Mixed processor cannot push data: {}", error);
+                let items = match (&scatter, item) {
+                    (Some(scatter), Ok(block)) => match scatter(&block, outputs_len) {
+                        Ok(blocks) => blocks.into_iter().map(Ok).enumerate().collect(),
+                        Err(cause) => vec![(0, Err(cause))],
+                    },
+                    (_, item) => {
+                        let i = index.fetch_add(1, Ordering::Relaxed) % outputs_len;
+                        vec![(i, item)]
+                    }
+                };
+
+                for (i, item) in items {
+                    if matches!(&item, Ok(block) if block.is_empty()) {
+                        continue;
+                    }
+                    // TODO: USE try_reserve when the channel is blocking
+                    if let Err(error) = senders[i].send(item).await {
+                        tracing::error!("Mixed processor cannot push data: {}", error);
+                    }
                 }
             }
         })?;
@@ -83,9 +104,19 @@ pub struct MixedProcessor {
 
 impl MixedProcessor {
     pub fn create(ctx: Arc<QueryContext>, n: usize) -> Self {
+        Self::create_impl(ctx, n, None)
+    }
+
+    /// The blocks are scattered to the outputs instead of round-robin.
+    pub fn create_with_scatter(ctx: Arc<QueryContext>, n: usize, scatter: MixedScatter) -> Self {
+        Self::create_impl(ctx, n, Some(scatter))
+    }
+
+    fn create_impl(ctx: Arc<QueryContext>, n: usize, scatter: Option<MixedScatter>) -> Self {
         let worker = MixedWorker {
             ctx: ctx.clone(),
             n,
+            scatter,
             started: AtomicBool::new(false),
             shared_num: AtomicUsize::new(0),
             receivers: vec![],
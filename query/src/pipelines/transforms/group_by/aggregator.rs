@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bumpalo::Bump;
+use common_base::MemoryTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datavalues::arrays::StringArrayBuilder;
@@ -38,27 +40,55 @@ use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
 pub struct Aggregator<Method: HashMethod> {
     method: Method,
     params: AggregatorParamsRef,
+    // Give up the aggregation when the groups reach pass_through_ratio percent of the rows,
+    // checked after pass_through_min_rows rows, 0 for never.
+    pass_through_min_rows: usize,
+    pass_through_ratio: usize,
+    // Give up the aggregation as well when the groups take more bytes, 0 for never.
+    max_memory_bytes: usize,
 }
 
 impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
     pub fn create(method: Method, params: AggregatorParamsRef) -> Aggregator<Method> {
-        Aggregator { method, params }
+        Aggregator {
+            method,
+            params,
+            pass_through_min_rows: 0,
+            pass_through_ratio: 100,
+            max_memory_bytes: 0,
+        }
+    }
+
+    pub fn with_pass_through(mut self, min_rows: usize, ratio: usize) -> Aggregator<Method> {
+        self.pass_through_min_rows = min_rows;
+        self.pass_through_ratio = ratio;
+        self
+    }
+
+    pub fn with_max_memory_bytes(mut self, max_memory_bytes: usize) -> Aggregator<Method> {
+        self.max_memory_bytes = max_memory_bytes;
+        self
     }
 
     // If we set it to inline(performance degradation).
     // Because it will make other internal functions to no inline
+    //
+    // Returns the rest of the stream as well if the aggregation hardly reduces the rows, or
+    // the groups take more than max_memory_bytes, which should be passed through, see
+    // `pass_through`.
     #[inline(never)]
     pub async fn aggregate(
         &self,
         group_cols: Vec<String>,
         mut stream: SendableDataBlockStream,
-    ) -> Result<Method::State> {
+    ) -> Result<(Method::State, Option<SendableDataBlockStream>)> {
         // This may be confusing
         // It will help us improve performance ~10% when we declare local references for them.
         let hash_method = &self.method;
         let aggregator_params = self.params.as_ref();
 
         let mut state = hash_method.aggregate_state();
+        let mut rows = 0;
 
         match aggregator_params.aggregate_functions.is_empty() {
             true => {
@@ -69,6 +99,12 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
                     let group_columns = Self::group_columns(&group_cols, &block)?;
                     let group_keys = hash_method.build_keys(&group_columns, block.num_rows())?;
                     self.lookup_key(group_keys, &mut state);
+
+                    rows += block.num_rows();
+                    MemoryTracker::check_current_limit()?;
+                    if self.should_pass_through(rows, &state) {
+                        return Ok((state, Some(stream)));
+                    }
                 }
             }
             false => {
@@ -81,11 +117,71 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
                     let places = self.lookup_state(group_keys, &mut state);
                     Self::execute(aggregator_params, &block, &places)?;
+
+                    rows += block.num_rows();
+                    MemoryTracker::check_current_limit()?;
+                    if self.should_pass_through(rows, &state) {
+                        return Ok((state, Some(stream)));
+                    }
+                }
+            }
+        }
+
+        Ok((state, None))
+    }
+
+    #[inline(always)]
+    fn should_pass_through(&self, rows: usize, state: &Method::State) -> bool {
+        let groups = state.len();
+        (self.pass_through_min_rows != 0
+            && rows >= self.pass_through_min_rows
+            && groups * 100 >= rows * self.pass_through_ratio)
+            || (self.max_memory_bytes != 0 && state.allocated_bytes() > self.max_memory_bytes)
+    }
+
+    /// Convert the block to the partial result without aggregation: every row gets its own
+    /// state, the final group by merges the states of the same key.
+    pub fn pass_through(
+        &self,
+        group_cols: &[String],
+        block: &DataBlock,
+        schema: DataSchemaRef,
+    ) -> Result<DataBlock> {
+        let rows = block.num_rows();
+        let group_columns = Self::group_columns(group_cols, block)?;
+        let group_keys = self.method.build_keys(&group_columns, rows)?;
+
+        let aggregator_params = self.params.as_ref();
+        let funcs = &aggregator_params.aggregate_functions;
+        let offsets_aggregate_states = &aggregator_params.offsets_aggregate_states;
+
+        // The states are serialized into the block, they only live as long as this call.
+        let area = Bump::new();
+        let places: StateAddrs = (0..rows)
+            .map(|_| {
+                let place: StateAddr = area.alloc_layout(aggregator_params.layout).into();
+                for (idx, func) in funcs.iter().enumerate() {
+                    func.init_state(place.next(offsets_aggregate_states[idx]));
                 }
+                place
+            })
+            .collect();
+        Self::execute(aggregator_params, block, &places)?;
+
+        let mut columns: Vec<Series> = Vec::with_capacity(schema.fields().len());
+        let mut bytes = BytesMut::new();
+        for (idx, func) in funcs.iter().enumerate() {
+            let mut builder = StringArrayBuilder::with_capacity(rows * 4);
+            for place in places.iter() {
+                func.serialize(place.next(offsets_aggregate_states[idx]), &mut bytes)?;
+                builder.append_value(&bytes[..]);
+                bytes.clear();
             }
+            columns.push(builder.finish().into_series());
         }
 
-        Ok(state)
+        columns.push(self.method.keys_array(&group_keys));
+        Ok(DataBlock::create_by_array(schema, columns))
     }
 
     #[inline(always)]
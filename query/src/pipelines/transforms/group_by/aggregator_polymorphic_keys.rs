@@ -21,6 +21,8 @@ use common_datablocks::HashMethodKeysU8;
 use common_datablocks::HashMethodSerializer;
 use common_datavalues::arrays::PrimitiveArrayBuilder;
 use common_datavalues::arrays::StringArrayBuilder;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
 
 use crate::common::HashTable;
 use crate::pipelines::transforms::group_by::aggregator_keys_builder::FixedKeysArrayBuilder;
@@ -65,6 +67,11 @@ use crate::pipelines::transforms::group_by::AggregatorState;
 //             inner_builder: StringArrayBuilder::with_capacity(capacity),
 //         }
 //     }
+//
+//     fn keys_array(&self, keys: &[Vec<u8>]) -> Series {
+//         let keys = keys.iter().map(|key| key.as_slice()).collect::<Vec<_>>();
+//         Series::new(keys)
+//     }
 // }
 //
 pub trait PolymorphicKeysHelper<Method: HashMethod> {
@@ -73,6 +80,9 @@ pub trait PolymorphicKeysHelper<Method: HashMethod> {
 
     type ArrayBuilder: KeysArrayBuilder<<Self::State as AggregatorState<Method>>::Key>;
     fn state_array_builder(&self, capacity: usize) -> Self::ArrayBuilder;
+
+    /// Build the group by key column of the rows passed through without aggregation
+    fn keys_array(&self, keys: &[Method::HashKey]) -> Series;
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU8> for HashMethodKeysU8 {
@@ -87,6 +97,10 @@ impl PolymorphicKeysHelper<HashMethodKeysU8> for HashMethodKeysU8 {
             inner_builder: PrimitiveArrayBuilder::<u8>::with_capacity(capacity),
         }
     }
+
+    fn keys_array(&self, keys: &[u8]) -> Series {
+        Series::new(keys)
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU16> for HashMethodKeysU16 {
@@ -101,6 +115,10 @@ impl PolymorphicKeysHelper<HashMethodKeysU16> for HashMethodKeysU16 {
             inner_builder: PrimitiveArrayBuilder::<u16>::with_capacity(capacity),
         }
     }
+
+    fn keys_array(&self, keys: &[u16]) -> Series {
+        Series::new(keys)
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU32> for HashMethodKeysU32 {
@@ -118,6 +136,10 @@ impl PolymorphicKeysHelper<HashMethodKeysU32> for HashMethodKeysU32 {
             inner_builder: PrimitiveArrayBuilder::<u32>::with_capacity(capacity),
         }
     }
+
+    fn keys_array(&self, keys: &[u32]) -> Series {
+        Series::new(keys)
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU64> for HashMethodKeysU64 {
@@ -135,6 +157,10 @@ impl PolymorphicKeysHelper<HashMethodKeysU64> for HashMethodKeysU64 {
             inner_builder: PrimitiveArrayBuilder::<u64>::with_capacity(capacity),
         }
     }
+
+    fn keys_array(&self, keys: &[u64]) -> Series {
+        Series::new(keys)
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodSerializer> for HashMethodSerializer {
@@ -153,4 +179,9 @@ impl PolymorphicKeysHelper<HashMethodSerializer> for HashMethodSerializer {
             inner_builder: StringArrayBuilder::with_capacity(capacity),
         }
     }
+
+    fn keys_array(&self, keys: &[Vec<u8>]) -> Series {
+        let keys = keys.iter().map(|key| key.as_slice()).collect::<Vec<_>>();
+        Series::new(keys)
+    }
 }
@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_streams::SpillDir;
+use common_streams::SpillFile;
+
+const SPILL_PARTITIONS: usize = 16;
+
+/// A group key of the final group by, written into the spilled files as its bytes.
+pub trait SpillKey: Hash + Eq + Sized {
+    fn write_to(&self, writer: &mut impl Write) -> Result<()>;
+
+    /// None once the reader is drained.
+    fn read_from(reader: &mut impl Read) -> Result<Option<Self>>;
+
+    /// The bytes of the key out of the hash table.
+    fn heap_bytes(&self) -> usize {
+        0
+    }
+}
+
+impl SpillKey for Vec<u8> {
+    fn write_to(&self, writer: &mut impl Write) -> Result<()> {
+        write_bytes(writer, self)
+    }
+
+    fn read_from(reader: &mut impl Read) -> Result<Option<Self>> {
+        read_bytes(reader)
+    }
+
+    fn heap_bytes(&self) -> usize {
+        self.capacity()
+    }
+}
+
+macro_rules! impl_fixed_spill_key {
+    ($($ty: ty),*) => {
+        $(
+            impl SpillKey for $ty {
+                fn write_to(&self, writer: &mut impl Write) -> Result<()> {
+                    writer.write_all(&self.to_le_bytes())?;
+                    Ok(())
+                }
+
+                fn read_from(reader: &mut impl Read) -> Result<Option<Self>> {
+                    let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+                    match read_exact_or_eof(reader, &mut bytes)? {
+                        true => Ok(Some(<$ty>::from_le_bytes(bytes))),
+                        false => Ok(None),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_spill_key!(u8, u16, u32, u64);
+
+/// The serialized states of the groups spilled by the final group by, partitioned by the hash
+/// of their keys, so that each partition is merged apart from the others.
+pub struct SpilledGroups<K: SpillKey> {
+    partitions: Vec<BufWriter<SpillFile>>,
+    states: usize,
+    _key: PhantomData<K>,
+}
+
+impl<K: SpillKey> SpilledGroups<K> {
+    /// `states` is the number of the aggregate functions, whose states follow each key.
+    pub fn try_create(spill_dir: &Arc<SpillDir>, states: usize) -> Result<Self> {
+        let partitions = (0..SPILL_PARTITIONS)
+            .map(|_| Ok(BufWriter::new(spill_dir.create_file()?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SpilledGroups {
+            partitions,
+            states,
+            _key: PhantomData,
+        })
+    }
+
+    pub fn push(&mut self, key: &K, states: &[&[u8]]) -> Result<()> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let writer = &mut self.partitions[(hasher.finish() % SPILL_PARTITIONS as u64) as usize];
+
+        key.write_to(writer)?;
+        for state in states {
+            write_bytes(writer, state)?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<Vec<SpilledGroupsReader<K>>> {
+        let states = self.states;
+        self.partitions
+            .into_iter()
+            .map(|writer| {
+                let mut file = writer.into_inner().map_err(|e| e.into_error())?;
+                file.seek(SeekFrom::Start(0))?;
+                Ok(SpilledGroupsReader {
+                    reader: BufReader::new(file),
+                    states,
+                    _key: PhantomData,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reads back the groups of a spilled partition, a key may be read more than once.
+pub struct SpilledGroupsReader<K: SpillKey> {
+    reader: BufReader<SpillFile>,
+    states: usize,
+    _key: PhantomData<K>,
+}
+
+impl<K: SpillKey> SpilledGroupsReader<K> {
+    pub fn next_group(&mut self) -> Result<Option<(K, Vec<Vec<u8>>)>> {
+        let key = match K::read_from(&mut self.reader)? {
+            None => return Ok(None),
+            Some(key) => key,
+        };
+
+        let mut states = Vec::with_capacity(self.states);
+        for _ in 0..self.states {
+            match read_bytes(&mut self.reader)? {
+                None => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+                Some(state) => states.push(state),
+            }
+        }
+        Ok(Some((key, states)))
+    }
+}
+
+fn write_bytes(writer: &mut impl Write, bytes: &[u8]) -> Result<()> {
+    writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+fn read_bytes(reader: &mut impl Read) -> Result<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    if !read_exact_or_eof(reader, &mut len)? {
+        return Ok(None);
+    }
+
+    let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(Some(bytes))
+}
+
+// False at the end of the reader.
+fn read_exact_or_eof(reader: &mut impl Read, buf: &mut [u8]) -> Result<bool> {
+    match reader.read_exact(buf) {
+        Ok(_) => Ok(true),
+        Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(cause) => Err(cause.into()),
+    }
+}
@@ -16,6 +16,7 @@ mod aggregator;
 mod aggregator_keys_builder;
 mod aggregator_params;
 mod aggregator_polymorphic_keys;
+mod aggregator_spill;
 mod aggregator_state;
 mod aggregator_state_entity;
 mod aggregator_state_iterator;
@@ -25,4 +26,7 @@ pub use aggregator::Aggregator;
 pub use aggregator_params::AggregatorParams;
 pub use aggregator_params::AggregatorParamsRef;
 pub use aggregator_polymorphic_keys::PolymorphicKeysHelper;
+pub use aggregator_spill::SpillKey;
+pub use aggregator_spill::SpilledGroups;
+pub use aggregator_spill::SpilledGroupsReader;
 pub use aggregator_state::AggregatorState;
@@ -0,0 +1,251 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::tokio::sync::OnceCell;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_streams::SpillDir;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::hash_join::build_rows_with_nulls;
+use crate::pipelines::transforms::hash_join::probe_rows_with_nulls;
+use crate::pipelines::transforms::hash_join::JoinHashTable;
+use crate::pipelines::transforms::hash_join::JoinParamsRef;
+use crate::pipelines::transforms::hash_join::SpilledJoinSide;
+use crate::pipelines::transforms::hash_join::SpilledPartition;
+
+/// The hash join of the build side read first, then the probe side, shared by the processors of
+/// the probe side: the build side is read once, by the first of them to run, and they all probe
+/// the same table.
+///
+/// The build side is kept in memory while it is under `max_memory_bytes`, 0 means no limit, and
+/// each probe block is joined as it comes. Beyond, the build side and then the probe side are
+/// spilled into partitions by the hash of their keys, and the partitions are joined one by one
+/// at the end, each of them in memory.
+pub struct GraceHashJoin {
+    params: JoinParamsRef,
+    max_memory_bytes: usize,
+    spill_dir: Arc<SpillDir>,
+
+    build: Arc<dyn Processor>,
+    built: OnceCell<Result<BuiltSide>>,
+    // The probe processors which have not finished yet, the last one joins the rows left.
+    probing: AtomicUsize,
+}
+
+enum BuiltSide {
+    Memory(JoinHashTable),
+    Spilled {
+        // Taken by the last probe processor to join the partitions.
+        sides: Mutex<Option<(SpilledJoinSide, SpilledJoinSide)>>,
+        // The build rows with a null key, only kept by the right and full joins.
+        nulls: Vec<DataBlock>,
+    },
+}
+
+impl GraceHashJoin {
+    /// `build` is the processor of the right input of the join, `probes` the number of the
+    /// processors of the left one.
+    pub fn create(
+        params: JoinParamsRef,
+        build: Arc<dyn Processor>,
+        probes: usize,
+        max_memory_bytes: usize,
+        spill_dir: Arc<SpillDir>,
+    ) -> Arc<GraceHashJoin> {
+        Arc::new(GraceHashJoin {
+            params,
+            max_memory_bytes,
+            spill_dir,
+            build,
+            built: OnceCell::new(),
+            probing: AtomicUsize::new(probes),
+        })
+    }
+
+    pub fn build_processor(&self) -> Arc<dyn Processor> {
+        self.build.clone()
+    }
+
+    /// Joins a block of the probe side, or spills it once the build side is spilled.
+    pub async fn probe(&self, block: DataBlock) -> Result<Option<DataBlock>> {
+        let sides = match self.built().await? {
+            BuiltSide::Memory(hash_table) => return hash_table.probe(&block),
+            BuiltSide::Spilled { sides, .. } => sides,
+        };
+
+        let nulls = match sides.lock().as_mut() {
+            Some((_, probe)) => probe.push(&block)?,
+            None => unreachable!("the probe side is spilled until all the processors finish"),
+        };
+        match nulls {
+            Some(nulls) if self.params.join_type.keeps_left() => {
+                Ok(Some(probe_rows_with_nulls(&self.params, &nulls)?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Ends the probe side of a processor. The rows left once all of them are done go to the
+    /// last one: the unmatched rows of the build side, or the joined partitions.
+    pub async fn finish_probe(&self) -> Result<Option<JoinRemainder>> {
+        let built = self.built().await?;
+        if self.probing.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return Ok(None);
+        }
+
+        let mut remainder = JoinRemainder {
+            params: self.params.clone(),
+            blocks: VecDeque::new(),
+            partitions: VecDeque::new(),
+            partition: None,
+        };
+        match built {
+            BuiltSide::Memory(hash_table) => {
+                remainder.blocks.extend(hash_table.unmatched()?);
+            }
+            BuiltSide::Spilled { sides, nulls } => {
+                if !nulls.is_empty() {
+                    let nulls = DataBlock::concat_blocks(nulls)?;
+                    let block = build_rows_with_nulls(&self.params, &nulls)?;
+                    remainder.blocks.push_back(block);
+                }
+
+                let (build, probe) = sides.lock().take().expect("the last probe processor");
+                let build = build.finish(self.params.build_schema.clone())?;
+                let probe = probe.finish(self.params.probe_schema.clone())?;
+                remainder.partitions = build.into_iter().zip(probe).collect();
+            }
+        }
+        Ok(Some(remainder))
+    }
+
+    // The first caller reads the build side, the others wait for it.
+    async fn built(&self) -> Result<&BuiltSide> {
+        let built = self.built.get_or_init(|| self.read_build()).await;
+        built.as_ref().map_err(|cause| cause.clone())
+    }
+
+    async fn read_build(&self) -> Result<BuiltSide> {
+        let mut stream = self.build.execute().await?;
+        let mut blocks = vec![];
+        let mut bytes = 0;
+        let mut spilled = None;
+        let mut nulls = vec![];
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if let Some(spilled) = &mut spilled {
+                self.spill_build(spilled, &block, &mut nulls)?;
+                continue;
+            }
+
+            bytes += block.memory_size();
+            blocks.push(block);
+            if self.max_memory_bytes == 0 || bytes <= self.max_memory_bytes {
+                continue;
+            }
+
+            tracing::debug!(
+                "Spill the build side of the hash join beyond {} bytes",
+                self.max_memory_bytes
+            );
+            let keys = self.params.build_keys.clone();
+            let mut build = SpilledJoinSide::try_create(&self.spill_dir, keys)?;
+            for block in std::mem::take(&mut blocks) {
+                self.spill_build(&mut build, &block, &mut nulls)?;
+            }
+            spilled = Some(build);
+        }
+
+        match spilled {
+            None => Ok(BuiltSide::Memory(JoinHashTable::try_create(
+                self.params.clone(),
+                &blocks,
+            )?)),
+            Some(build) => {
+                let keys = self.params.probe_keys.clone();
+                let probe = SpilledJoinSide::try_create(&self.spill_dir, keys)?;
+                Ok(BuiltSide::Spilled {
+                    sides: Mutex::new(Some((build, probe))),
+                    nulls,
+                })
+            }
+        }
+    }
+
+    // The rows of the build side with a null key are only kept by the right and full joins.
+    fn spill_build(
+        &self,
+        spilled: &mut SpilledJoinSide,
+        block: &DataBlock,
+        nulls: &mut Vec<DataBlock>,
+    ) -> Result<()> {
+        if let Some(block) = spilled.push(block)? {
+            if self.params.join_type.keeps_right() {
+                nulls.push(block);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The rows of the join left once the probe side is done, returned to the last probe processor.
+pub struct JoinRemainder {
+    params: JoinParamsRef,
+    blocks: VecDeque<DataBlock>,
+    partitions: VecDeque<(SpilledPartition, SpilledPartition)>,
+    partition: Option<(JoinHashTable, SpilledPartition)>,
+}
+
+impl JoinRemainder {
+    /// The next block of the rows left, None at the end.
+    pub fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        if let Some(block) = self.blocks.pop_front() {
+            return Ok(Some(block));
+        }
+
+        loop {
+            if let Some((hash_table, probe)) = &mut self.partition {
+                while let Some(block) = probe.next_block()? {
+                    if let Some(joined) = hash_table.probe(&block)? {
+                        return Ok(Some(joined));
+                    }
+                }
+
+                let unmatched = hash_table.unmatched()?;
+                self.partition = None;
+                if unmatched.is_some() {
+                    return Ok(unmatched);
+                }
+            }
+
+            match self.partitions.pop_front() {
+                None => return Ok(None),
+                Some((mut build, probe)) => {
+                    let blocks = build.read_all()?;
+                    let hash_table = JoinHashTable::try_create(self.params.clone(), &blocks)?;
+                    self.partition = Some((hash_table, probe));
+                }
+            }
+        }
+    }
+}
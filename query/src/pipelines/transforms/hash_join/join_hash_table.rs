@@ -0,0 +1,203 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::compute::take;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::pipelines::transforms::hash_join::JoinParams;
+use crate::pipelines::transforms::hash_join::JoinParamsRef;
+
+/// The serialized keys of the rows of a block, with whether each row has no null key: a row
+/// with a null key matches no other row.
+pub fn join_keys(block: &DataBlock, keys: &[String]) -> Result<(Vec<Vec<u8>>, Vec<bool>)> {
+    let columns = keys
+        .iter()
+        .map(|key| block.try_column_by_name(key))
+        .collect::<Result<Vec<_>>>()?;
+    let hash_keys = HashMethodSerializer::default().build_keys(&columns, block.num_rows())?;
+
+    let arrays = columns
+        .iter()
+        .map(|column| column.to_array())
+        .collect::<Result<Vec<_>>>()?;
+    let valid = (0..block.num_rows())
+        .map(|row| arrays.iter().all(|array| !array.is_null(row)))
+        .collect();
+    Ok((hash_keys, valid))
+}
+
+/// The rows of the build side joined to nulls on the probe side, e.g. the unmatched rows of a
+/// right join.
+pub fn build_rows_with_nulls(params: &JoinParams, build: &DataBlock) -> Result<DataBlock> {
+    let rows = build.num_rows();
+    let mut columns = params
+        .probe_schema
+        .fields()
+        .iter()
+        .map(|field| DataValue::from(field.data_type()).to_series_with_size(rows))
+        .collect::<Result<Vec<_>>>()?;
+    for column in build.columns() {
+        columns.push(column.to_array()?);
+    }
+    Ok(DataBlock::create_by_array(params.schema.clone(), columns))
+}
+
+/// The rows of the probe side joined to nulls on the build side, e.g. the unmatched rows of a
+/// left join.
+pub fn probe_rows_with_nulls(params: &JoinParams, probe: &DataBlock) -> Result<DataBlock> {
+    let rows = probe.num_rows();
+    let mut columns = probe
+        .columns()
+        .iter()
+        .map(|column| column.to_array())
+        .collect::<Result<Vec<_>>>()?;
+    for field in params.build_schema.fields() {
+        columns.push(DataValue::from(field.data_type()).to_series_with_size(rows)?);
+    }
+    Ok(DataBlock::create_by_array(params.schema.clone(), columns))
+}
+
+/// The build side of the hash join in memory: its rows by their keys, and the rows matched by
+/// the probed rows so far. It is probed by all the processors of the probe side at once.
+pub struct JoinHashTable {
+    params: JoinParamsRef,
+    block: DataBlock,
+    rows: HashMap<Vec<u8>, Vec<u32>>,
+    matched: Vec<AtomicBool>,
+}
+
+impl JoinHashTable {
+    pub fn try_create(params: JoinParamsRef, blocks: &[DataBlock]) -> Result<Self> {
+        let block = match blocks.is_empty() {
+            true => DataBlock::empty_with_schema(params.build_schema.clone()),
+            false => DataBlock::concat_blocks(blocks)?,
+        };
+
+        let (keys, valid) = join_keys(&block, &params.build_keys)?;
+        let mut rows = HashMap::<Vec<u8>, Vec<u32>>::new();
+        for (row, (key, valid)) in keys.into_iter().zip(valid).enumerate() {
+            if valid {
+                rows.entry(key).or_default().push(row as u32);
+            }
+        }
+
+        Ok(JoinHashTable {
+            matched: (0..block.num_rows())
+                .map(|_| AtomicBool::new(false))
+                .collect(),
+            params,
+            block,
+            rows,
+        })
+    }
+
+    /// Joins the rows of a probe block to their matches, None if no row is left.
+    pub fn probe(&self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let (keys, valid) = join_keys(block, &self.params.probe_keys)?;
+        let keeps_left = self.params.join_type.keeps_left();
+
+        let mut probe_indices = Vec::with_capacity(block.num_rows());
+        let mut build_indices = Vec::with_capacity(block.num_rows());
+        for (row, (key, valid)) in keys.iter().zip(valid).enumerate() {
+            let matches = match valid {
+                true => self.rows.get(key),
+                false => None,
+            };
+
+            match matches {
+                Some(matches) => {
+                    for build_row in matches {
+                        probe_indices.push(row as u32);
+                        build_indices.push(Some(*build_row));
+                        self.matched[*build_row as usize].store(true, Ordering::Relaxed);
+                    }
+                }
+                None if keeps_left => {
+                    probe_indices.push(row as u32);
+                    build_indices.push(None);
+                }
+                None => {}
+            }
+        }
+
+        if probe_indices.is_empty() {
+            return Ok(None);
+        }
+
+        let probe = DataBlock::block_take_by_indices(block, &[], &probe_indices)?;
+        let mut columns = probe
+            .columns()
+            .iter()
+            .map(|column| column.to_array())
+            .collect::<Result<Vec<_>>>()?;
+        columns.extend(self.take_build_rows(&build_indices)?);
+        Ok(Some(DataBlock::create_by_array(
+            self.params.schema.clone(),
+            columns,
+        )))
+    }
+
+    /// The build rows matched by no probed row, only kept by the right and full joins.
+    pub fn unmatched(&self) -> Result<Option<DataBlock>> {
+        if !self.params.join_type.keeps_right() {
+            return Ok(None);
+        }
+
+        let indices = self
+            .matched
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| !matched.load(Ordering::Relaxed))
+            .map(|(row, _)| row as u32)
+            .collect::<Vec<_>>();
+        if indices.is_empty() {
+            return Ok(None);
+        }
+
+        let build = DataBlock::block_take_by_indices(&self.block, &[], &indices)?;
+        Ok(Some(build_rows_with_nulls(&self.params, &build)?))
+    }
+
+    // None takes a null, for the probed rows without a match.
+    fn take_build_rows(&self, indices: &[Option<u32>]) -> Result<Vec<Series>> {
+        let fields = self.params.build_schema.fields();
+        if self.block.num_rows() == 0 {
+            return fields
+                .iter()
+                .map(|field| DataValue::from(field.data_type()).to_series_with_size(indices.len()))
+                .collect();
+        }
+
+        let indices = DFUInt32Array::new_from_opt_slice(indices);
+        self.block
+            .columns()
+            .iter()
+            .map(|column| {
+                let array = column.to_array()?.get_array_ref();
+                let taken: ArrayRef = Arc::from(take::take(array.as_ref(), indices.inner())?);
+                Ok(taken.into_series())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_planners::JoinPlan;
+use common_planners::JoinType;
+
+/// The probe side is the left input of the join, the build side the right one.
+pub struct JoinParams {
+    pub join_type: JoinType,
+    pub probe_keys: Vec<String>,
+    pub build_keys: Vec<String>,
+    pub probe_schema: DataSchemaRef,
+    pub build_schema: DataSchemaRef,
+    pub schema: DataSchemaRef,
+}
+
+pub type JoinParamsRef = Arc<JoinParams>;
+
+impl JoinParams {
+    pub fn create(plan: &JoinPlan) -> JoinParamsRef {
+        Arc::new(JoinParams {
+            join_type: plan.join_type,
+            probe_keys: plan.left_keys.iter().map(|key| key.column_name()).collect(),
+            build_keys: plan
+                .right_keys
+                .iter()
+                .map(|key| key.column_name())
+                .collect(),
+            probe_schema: plan.left.schema(),
+            build_schema: plan.right.schema(),
+            schema: plan.schema(),
+        })
+    }
+}
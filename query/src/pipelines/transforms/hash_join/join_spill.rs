@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::SpillDir;
+use common_streams::SpillFile;
+
+use crate::pipelines::transforms::hash_join::join_keys;
+
+const SPILL_PARTITIONS: usize = 16;
+
+/// The rows of one side of the hash join spilled into partitions by the hash of their keys: the
+/// rows of both sides with the same key fall into the same partition, joined apart from the
+/// others.
+pub struct SpilledJoinSide {
+    keys: Vec<String>,
+    partitions: Vec<BufWriter<SpillFile>>,
+}
+
+impl SpilledJoinSide {
+    pub fn try_create(spill_dir: &Arc<SpillDir>, keys: Vec<String>) -> Result<Self> {
+        let partitions = (0..SPILL_PARTITIONS)
+            .map(|_| Ok(BufWriter::new(spill_dir.create_file()?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SpilledJoinSide { keys, partitions })
+    }
+
+    /// Spills the rows of the block, but the ones with a null key, which match no row: they are
+    /// returned instead.
+    pub fn push(&mut self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let (keys, valid) = join_keys(block, &self.keys)?;
+
+        let mut indices = vec![Vec::new(); SPILL_PARTITIONS];
+        let mut nulls = Vec::new();
+        for (row, (key, valid)) in keys.iter().zip(valid).enumerate() {
+            match valid {
+                true => {
+                    let mut hasher = DefaultHasher::new();
+                    key.hash(&mut hasher);
+                    let partition = (hasher.finish() % SPILL_PARTITIONS as u64) as usize;
+                    indices[partition].push(row as u32);
+                }
+                false => nulls.push(row as u32),
+            }
+        }
+
+        for (writer, indices) in self.partitions.iter_mut().zip(indices.iter()) {
+            if !indices.is_empty() {
+                let rows = DataBlock::block_take_by_indices(block, &[], indices)?;
+                write_block(writer, &rows)?;
+            }
+        }
+
+        match nulls.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(DataBlock::block_take_by_indices(block, &[], &nulls)?)),
+        }
+    }
+
+    pub fn finish(self, schema: DataSchemaRef) -> Result<Vec<SpilledPartition>> {
+        self.partitions
+            .into_iter()
+            .map(|writer| {
+                let mut file = writer.into_inner().map_err(|e| e.into_error())?;
+                file.seek(SeekFrom::Start(0))?;
+                Ok(SpilledPartition {
+                    reader: BufReader::new(file),
+                    schema: schema.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reads back the blocks of a spilled partition.
+pub struct SpilledPartition {
+    reader: BufReader<SpillFile>,
+    schema: DataSchemaRef,
+}
+
+impl SpilledPartition {
+    pub fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        let mut len = [0u8; 4];
+        match self.reader.read_exact(&mut len) {
+            Ok(_) => {}
+            Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(cause) => return Err(cause.into()),
+        }
+
+        let rows = u32::from_le_bytes(len) as usize;
+        let mut keys = Vec::with_capacity(rows);
+        for _ in 0..rows {
+            self.reader.read_exact(&mut len)?;
+            let mut key = vec![0u8; u32::from_le_bytes(len) as usize];
+            self.reader.read_exact(&mut key)?;
+            keys.push(key);
+        }
+
+        let columns =
+            HashMethodSerializer::default().de_group_columns(keys, self.schema.fields())?;
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            columns,
+        )))
+    }
+
+    pub fn read_all(&mut self) -> Result<Vec<DataBlock>> {
+        let mut blocks = Vec::new();
+        while let Some(block) = self.next_block()? {
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+}
+
+/// The rows of a block are written as their serialized values, each one prefixed by its length,
+/// after the number of the rows.
+fn write_block(writer: &mut BufWriter<SpillFile>, block: &DataBlock) -> Result<()> {
+    let columns = block.columns().iter().collect::<Vec<_>>();
+    let rows = HashMethodSerializer::default().build_keys(&columns, block.num_rows())?;
+
+    writer.write_all(&(rows.len() as u32).to_le_bytes())?;
+    for row in rows {
+        writer.write_all(&(row.len() as u32).to_le_bytes())?;
+        writer.write_all(&row)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod grace_hash_join;
+mod join_hash_table;
+mod join_params;
+mod join_spill;
+
+pub use grace_hash_join::GraceHashJoin;
+pub use grace_hash_join::JoinRemainder;
+pub use join_hash_table::build_rows_with_nulls;
+pub use join_hash_table::join_keys;
+pub use join_hash_table::probe_rows_with_nulls;
+pub use join_hash_table::JoinHashTable;
+pub use join_params::JoinParams;
+pub use join_params::JoinParamsRef;
+pub use join_spill::SpilledJoinSide;
+pub use join_spill::SpilledPartition;
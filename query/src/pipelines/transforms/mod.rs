@@ -14,12 +14,15 @@
 
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
+mod transform_block_rechunk;
 mod transform_create_sets;
+mod transform_distinct;
 mod transform_expression;
 mod transform_expression_executor;
 mod transform_filter;
 mod transform_group_by_final;
 mod transform_group_by_partial;
+mod transform_hash_join;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
@@ -29,20 +32,26 @@ mod transform_sort_partial;
 mod transform_source;
 
 mod group_by;
+mod hash_join;
 mod streams;
 mod transform_sink;
 
+pub use hash_join::GraceHashJoin;
+pub use hash_join::JoinParams;
 pub use streams::AddOnStream;
 pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
+pub use transform_block_rechunk::BlockRechunkTransform;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
+pub use transform_distinct::DistinctTransform;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression_executor::ExpressionExecutor;
 pub use transform_filter::HavingTransform;
 pub use transform_filter::WhereTransform;
 pub use transform_group_by_final::GroupByFinalTransform;
 pub use transform_group_by_partial::GroupByPartialTransform;
+pub use transform_hash_join::HashJoinTransform;
 pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
 pub use transform_projection::ProjectionTransform;
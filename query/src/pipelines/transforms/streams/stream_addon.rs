@@ -89,6 +89,29 @@ impl AddOnStream {
         })
     }
 
+    /// Fills the columns missing from the blocks of a source reading a subset of the columns,
+    /// e.g. matching the columns of a file by name, as told by the schema of the first block.
+    pub async fn try_create_by_first_block(
+        mut input: SendableDataBlockStream,
+        output_schema: DataSchemaRef,
+    ) -> Result<SendableDataBlockStream> {
+        let first = match input.next().await {
+            None => return Ok(input),
+            Some(first) => first?,
+        };
+        let input_schema = first.schema().clone();
+        let input: SendableDataBlockStream =
+            Box::pin(futures::stream::iter(vec![Ok(first)]).chain(input));
+        if input_schema == output_schema {
+            return Ok(input);
+        }
+        Ok(Box::pin(Self::try_create(
+            input,
+            input_schema,
+            output_schema,
+        )?))
+    }
+
     #[inline]
     fn add_missing_column(&self, mut block: DataBlock) -> Result<DataBlock> {
         let num_rows = block.num_rows();
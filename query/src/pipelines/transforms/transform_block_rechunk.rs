@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_streams::RechunkStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+/// Merges the tiny blocks and splits the huge ones of the input into blocks of
+/// `target_rows` rows, lowered to keep each block within `target_bytes` bytes.
+pub struct BlockRechunkTransform {
+    target_rows: usize,
+    target_bytes: usize,
+    input: Arc<dyn Processor>,
+}
+
+impl BlockRechunkTransform {
+    pub fn try_create(target_rows: usize, target_bytes: usize) -> Result<Self> {
+        Ok(BlockRechunkTransform {
+            target_rows,
+            target_bytes,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for BlockRechunkTransform {
+    fn name(&self) -> &str {
+        "BlockRechunkTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "block_rechunk_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+        let input_stream = self.input.execute().await?;
+        Ok(Box::pin(RechunkStream::new(
+            input_stream,
+            self.target_rows,
+            self.target_bytes,
+        )))
+    }
+}
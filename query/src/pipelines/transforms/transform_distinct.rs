@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::DistinctStream;
+use common_streams::SendableDataBlockStream;
+use common_streams::SpillDir;
+use common_tracing::tracing;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+pub struct DistinctTransform {
+    input: Arc<dyn Processor>,
+    schema: DataSchemaRef,
+    limit: Option<usize>,
+    max_memory_bytes: usize,
+    spill_dir: Arc<SpillDir>,
+}
+
+impl DistinctTransform {
+    /// `limit` is the number of distinct rows the query needs at most, the offset included.
+    pub fn create(
+        schema: DataSchemaRef,
+        limit: Option<usize>,
+        max_memory_bytes: usize,
+        spill_dir: Arc<SpillDir>,
+    ) -> Self {
+        Self {
+            input: Arc::new(EmptyProcessor::create()),
+            schema,
+            limit,
+            max_memory_bytes,
+            spill_dir,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for DistinctTransform {
+    fn name(&self) -> &str {
+        "DistinctTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "distinct_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        Ok(Box::pin(DistinctStream::try_create(
+            self.input.execute().await?,
+            self.schema.clone(),
+            self.limit,
+            self.max_memory_bytes,
+            self.spill_dir.clone(),
+        )?))
+    }
+}
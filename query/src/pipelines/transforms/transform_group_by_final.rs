@@ -12,28 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::alloc::Layout;
 use std::any::Any;
 use std::borrow::BorrowMut;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::sync::Arc;
 use std::time::Instant;
 
 use bumpalo::Bump;
+use common_base::MemoryTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethodKind;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::aggregates::get_layout_offsets;
+use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::aggregates::StateAddr;
-use common_infallible::RwLock;
+use common_io::prelude::BytesMut;
 use common_planners::Expression;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_streams::SpillDir;
 use common_tracing::tracing;
 use futures::stream::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::MixedScatter;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::SpillKey;
+use crate::pipelines::transforms::group_by::SpilledGroups;
+
+type GroupStates<K> = HashMap<K, usize, ahash::RandomState>;
 
 pub struct GroupByFinalTransform {
     max_block_size: usize,
@@ -42,6 +53,8 @@ pub struct GroupByFinalTransform {
     schema: DataSchemaRef,
     schema_before_group_by: DataSchemaRef,
     input: Arc<dyn Processor>,
+    max_memory_bytes: usize,
+    spill_dir: Option<Arc<SpillDir>>,
 }
 
 impl GroupByFinalTransform {
@@ -59,8 +72,136 @@ impl GroupByFinalTransform {
             schema,
             schema_before_group_by,
             input: Arc::new(EmptyProcessor::create()),
+            max_memory_bytes: 0,
+            spill_dir: None,
+        }
+    }
+
+    /// Spill the groups into the files of `spill_dir` once they take more than
+    /// `max_memory_bytes` bytes, 0 for never. The groups are partitioned by the hash of their
+    /// keys, and merged partition by partition once the input is drained.
+    pub fn with_spill(mut self, max_memory_bytes: usize, spill_dir: Arc<SpillDir>) -> Self {
+        self.max_memory_bytes = max_memory_bytes;
+        self.spill_dir = Some(spill_dir);
+        self
+    }
+
+    /// Scatters the blocks of the partial states by the hash of their group keys, so that the
+    /// final transforms merge the states of disjoint groups in parallel.
+    pub fn scatter_by_group_key(aggr_funcs_len: usize) -> MixedScatter {
+        Arc::new(move |block: &DataBlock, n: usize| {
+            // The keys follow the states, see GroupByPartialTransform.
+            let keys = block.column(aggr_funcs_len).to_array()?;
+            let hashes = keys.vec_hash(DFHasher::SipHasher(DefaultHasher::new()))?;
+            let indices = hashes
+                .into_no_null_iter()
+                .map(|hash| *hash % n as u64)
+                .collect::<Vec<_>>();
+            DataBlock::scatter_block(block, &Series::new(indices).into(), n)
+        })
+    }
+}
+
+/// Merges the serialized states of a group into its states, which are allocated in the arena
+/// if the group is new. Returns whether the group is new.
+fn merge_group<K: Hash + Eq>(
+    groups: &mut GroupStates<K>,
+    arena: &Bump,
+    funcs: &[AggregateFunctionRef],
+    layout: Layout,
+    offsets_aggregate_states: &[usize],
+    group_key: K,
+    states: &[&[u8]],
+) -> Result<bool> {
+    match groups.get(&group_key) {
+        None => {
+            if funcs.is_empty() {
+                groups.insert(group_key, 0usize);
+            } else {
+                let place: StateAddr = arena.alloc_layout(layout).into();
+                for (idx, func) in funcs.iter().enumerate() {
+                    let arg_place = place.next(offsets_aggregate_states[idx]);
+
+                    let mut data = states[idx];
+                    func.init_state(arg_place);
+                    func.deserialize(arg_place, &mut data)?;
+                }
+                groups.insert(group_key, place.addr());
+            }
+            Ok(true)
+        }
+        Some(place) => {
+            let place: StateAddr = (*place).into();
+
+            for (idx, func) in funcs.iter().enumerate() {
+                let arg_place = place.next(offsets_aggregate_states[idx]);
+
+                let mut data = states[idx];
+                let temp = arena.alloc_layout(func.state_layout());
+                let temp_addr = temp.into();
+
+                func.init_state(temp_addr);
+                func.deserialize(temp_addr, &mut data)?;
+                func.merge(arg_place, temp_addr)?;
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// Serializes the states of the groups into the spilled partitions, the groups are left empty.
+fn spill_groups<K: SpillKey>(
+    groups: &mut GroupStates<K>,
+    spilled: &mut SpilledGroups<K>,
+    funcs: &[AggregateFunctionRef],
+    offsets_aggregate_states: &[usize],
+) -> Result<()> {
+    let mut buffers = vec![BytesMut::new(); funcs.len()];
+    for (key, place) in std::mem::take(groups) {
+        let place: StateAddr = place.into();
+        for (idx, func) in funcs.iter().enumerate() {
+            buffers[idx].clear();
+            func.serialize(place.next(offsets_aggregate_states[idx]), &mut buffers[idx])?;
+        }
+
+        let states = buffers.iter().map(|b| &b[..]).collect::<Vec<_>>();
+        spilled.push(&key, &states)?;
+    }
+    Ok(())
+}
+
+/// The keys of the groups and the results of the aggregate functions, in the same order.
+fn finalize_groups<K: Clone>(
+    groups: &GroupStates<K>,
+    funcs: &[AggregateFunctionRef],
+    offsets_aggregate_states: &[usize],
+) -> Result<(Vec<K>, Vec<Series>)> {
+    let mut aggr_values: Vec<Box<dyn MutableArrayBuilder>> = {
+        let mut values = vec![];
+        for func in funcs {
+            let array = create_mutable_array(func.return_type()?);
+            values.push(array)
+        }
+        values
+    };
+
+    let mut keys = Vec::with_capacity(groups.len());
+    for (key, place) in groups.iter() {
+        keys.push(key.clone());
+
+        let place: StateAddr = (*place).into();
+        for (idx, func) in funcs.iter().enumerate() {
+            let arg_place = place.next(offsets_aggregate_states[idx]);
+            let array: &mut dyn MutableArrayBuilder = aggr_values[idx].borrow_mut();
+            func.merge_result(arg_place, array)?;
         }
     }
+
+    let columns = aggr_values
+        .into_iter()
+        .map(|mut array| array.as_series())
+        .collect();
+    Ok((keys, columns))
 }
 
 #[async_trait::async_trait]
@@ -91,7 +232,6 @@ impl Processor for GroupByFinalTransform {
             .map(|x| x.to_aggregate_function(&self.schema_before_group_by))
             .collect::<Result<Vec<_>>>()?;
         let aggr_funcs_len = funcs.len();
-        let group_expr_len = self.group_exprs.len();
 
         let group_cols = self
             .group_exprs
@@ -106,21 +246,24 @@ impl Processor for GroupByFinalTransform {
             .collect::<Result<Vec<_>>>()?;
 
         let start = Instant::now();
-        let arena = Bump::new();
+        let mut arena = Bump::new();
 
         let mut stream = self.input.execute().await?;
         let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
         let method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
 
         let (layout, offsets_aggregate_states) = unsafe { get_layout_offsets(&funcs) };
+        let schema = self.schema.clone();
+        let max_block_size = self.max_block_size;
 
         macro_rules! apply {
-            ($hash_method: ident, $key_array_type: ty, $downcast_fn: ident, $group_func_table: ty) => {{
-                type GroupFuncTable = $group_func_table;
-                let groups_locker = GroupFuncTable::default();
+            ($hash_method: ident, $key_array_type: ty, $downcast_fn: ident, $key_type: ty) => {{
+                let mut groups = GroupStates::<$key_type>::default();
+                let mut keys_bytes = 0;
+                let mut spilled: Option<SpilledGroups<$key_type>> = None;
 
                 while let Some(block) = stream.next().await {
-                    let mut groups = groups_locker.write();
+                    MemoryTracker::check_current_limit()?;
                     let block = block?;
 
                     let key_array = block.column(aggr_funcs_len).to_array()?;
@@ -137,91 +280,122 @@ impl Processor for GroupByFinalTransform {
                         states_binary_arrays.push(aggr_array);
                     }
 
+                    let mut states = Vec::with_capacity(aggr_funcs_len);
+
                     for row in 0..block.num_rows() {
                         let group_key = $hash_method.get_key(&key_array, row);
-                        match groups.get(&group_key) {
+                        states.clear();
+                        states.extend(states_binary_arrays.iter().map(|a| a.value(row)));
+
+                        match spilled.as_mut() {
+                            Some(spilled) => spilled.push(&group_key, &states)?,
                             None => {
-                                if aggr_funcs_len == 0 {
-                                    groups.insert(group_key, 0usize);
-                                } else {
-                                    let place: StateAddr = arena.alloc_layout(layout).into();
-                                    for (idx, func) in funcs.iter().enumerate() {
-                                        let arg_place = place.next(offsets_aggregate_states[idx]);
-
-                                        let mut data = states_binary_arrays[idx].value(row);
-                                        func.init_state(arg_place);
-                                        func.deserialize(arg_place, &mut data)?;
-                                    }
-                                    groups.insert(group_key, place.addr());
+                                let key_bytes = group_key.heap_bytes();
+                                if merge_group(
+                                    &mut groups,
+                                    &arena,
+                                    &funcs,
+                                    layout,
+                                    &offsets_aggregate_states,
+                                    group_key,
+                                    &states,
+                                )? {
+                                    keys_bytes += key_bytes;
                                 }
                             }
-                            Some(place) => {
-                                let place: StateAddr = (*place).into();
-
-                                for (idx, func) in funcs.iter().enumerate() {
-                                    let arg_place = place.next(offsets_aggregate_states[idx]);
-
-                                    let mut data = states_binary_arrays[idx].value(row);
-                                    let temp = arena.alloc_layout(funcs[idx].state_layout());
-                                    let temp_addr = temp.into();
+                        }
+                    }
 
-                                    funcs[idx].init_state(temp_addr);
-                                    func.deserialize(temp_addr, &mut data)?;
-                                    func.merge(arg_place, temp_addr)?;
-                                }
-                            }
-                        };
+                    let groups_bytes = arena.allocated_bytes()
+                        + keys_bytes
+                        + groups.capacity() * std::mem::size_of::<($key_type, usize)>();
+                    if let Some(spill_dir) = &self.spill_dir {
+                        if spilled.is_none()
+                            && self.max_memory_bytes != 0
+                            && groups_bytes > self.max_memory_bytes
+                        {
+                            tracing::debug!(
+                                "Group by final spills {} groups of {} bytes",
+                                groups.len(),
+                                groups_bytes
+                            );
+                            let mut spilled_groups =
+                                SpilledGroups::try_create(spill_dir, aggr_funcs_len)?;
+                            spill_groups(
+                                &mut groups,
+                                &mut spilled_groups,
+                                &funcs,
+                                &offsets_aggregate_states,
+                            )?;
+                            arena.reset();
+                            keys_bytes = 0;
+                            spilled = Some(spilled_groups);
+                        }
                     }
                 }
                 let delta = start.elapsed();
                 tracing::debug!("Group by final cost: {:?}", delta);
 
-                // Collect the merge states.
-                let groups = groups_locker.read();
-
-                let mut aggr_values: Vec<Box<dyn MutableArrayBuilder>> = {
-                    let mut values = vec![];
-                    for func in &funcs {
-                        let array = create_mutable_array(func.return_type()?);
-                        values.push(array)
+                let spilled = match spilled {
+                    None => {
+                        // Collect the merge states.
+                        let (keys, mut columns) =
+                            finalize_groups(&groups, &funcs, &offsets_aggregate_states)?;
+                        columns.extend_from_slice(
+                            &$hash_method.de_group_columns(keys, &group_fields)?,
+                        );
+
+                        let mut blocks = vec![];
+                        if !columns.is_empty() {
+                            let block = DataBlock::create_by_array(schema.clone(), columns);
+                            blocks = DataBlock::split_block_by_size(&block, max_block_size)?;
+                        }
+
+                        return Ok(Box::pin(DataBlockStream::create(schema, None, blocks)));
                     }
-                    values
+                    Some(spilled) => spilled,
                 };
 
-                let mut keys = Vec::with_capacity(groups.len());
-                for (key, place) in groups.iter() {
-                    keys.push(key.clone());
-
-                    let place: StateAddr = (*place).into();
-                    for (idx, func) in funcs.iter().enumerate() {
-                        let arg_place = place.next(offsets_aggregate_states[idx]);
-                        let array: &mut dyn MutableArrayBuilder = aggr_values[idx].borrow_mut();
-                        func.merge_result(arg_place, array)?;
-                    }
-                }
-
-                // Build final state block.
-                let mut columns: Vec<Series> = Vec::with_capacity(aggr_funcs_len + group_expr_len);
-                for mut array in aggr_values {
-                    columns.push(array.as_series());
-                }
-
-                {
-                    let group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
-                    columns.extend_from_slice(&group_columns);
-                }
-
-                let mut blocks = vec![];
-                if !columns.is_empty() {
-                    let block = DataBlock::create_by_array(self.schema.clone(), columns);
-                    blocks = DataBlock::split_block_by_size(&block, self.max_block_size)?;
-                }
-
-                Ok(Box::pin(DataBlockStream::create(
-                    self.schema.clone(),
-                    None,
-                    blocks,
-                )))
+                // The groups of the partitions are disjoint, each one is merged and emitted
+                // before the next one is read.
+                let partitions = spilled.finish()?;
+                let merged = futures::stream::iter(partitions).map(move |mut partition| {
+                    let mut merge = || -> Result<Vec<DataBlock>> {
+                        let arena = Bump::new();
+                        let mut groups = GroupStates::<$key_type>::default();
+                        while let Some((group_key, states)) = partition.next_group()? {
+                            let states = states.iter().map(|s| &s[..]).collect::<Vec<_>>();
+                            merge_group(
+                                &mut groups,
+                                &arena,
+                                &funcs,
+                                layout,
+                                &offsets_aggregate_states,
+                                group_key,
+                                &states,
+                            )?;
+                        }
+                        MemoryTracker::check_current_limit()?;
+
+                        if groups.is_empty() {
+                            return Ok(vec![]);
+                        }
+                        let (keys, mut columns) =
+                            finalize_groups(&groups, &funcs, &offsets_aggregate_states)?;
+                        columns.extend_from_slice(
+                            &$hash_method.de_group_columns(keys, &group_fields)?,
+                        );
+                        let block = DataBlock::create_by_array(schema.clone(), columns);
+                        DataBlock::split_block_by_size(&block, max_block_size)
+                    };
+
+                    let blocks = match merge() {
+                        Ok(blocks) => blocks.into_iter().map(Ok).collect::<Vec<_>>(),
+                        Err(cause) => vec![Err(cause)],
+                    };
+                    futures::stream::iter(blocks)
+                });
+                Ok(Box::pin(merged.flatten()))
             }};
         }
 
@@ -229,19 +403,19 @@ impl Processor for GroupByFinalTransform {
             ($method: ident, $apply: ident) => {{
                 match $method {
                     HashMethodKind::Serializer(hash_method) => {
-                        apply! { hash_method,  &DFStringArray, string, RwLock<HashMap<Vec<u8>, usize, ahash::RandomState>>}
+                        apply! { hash_method,  &DFStringArray, string, Vec<u8>}
                     }
                     HashMethodKind::KeysU8(hash_method) => {
-                        apply! { hash_method , &DFUInt8Array, u8, RwLock<HashMap<u8, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt8Array, u8, u8 }
                     }
                     HashMethodKind::KeysU16(hash_method) => {
-                        apply! { hash_method , &DFUInt16Array, u16, RwLock<HashMap<u16, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt16Array, u16, u16 }
                     }
                     HashMethodKind::KeysU32(hash_method) => {
-                        apply! { hash_method , &DFUInt32Array, u32, RwLock<HashMap<u32, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt32Array, u32, u32 }
                     }
                     HashMethodKind::KeysU64(hash_method) => {
-                        apply! { hash_method , &DFUInt64Array, u64, RwLock<HashMap<u64, usize, ahash::RandomState>> }
+                        apply! { hash_method , &DFUInt64Array, u64, u64 }
                     }
                 }
             }};
@@ -24,11 +24,13 @@ use common_exception::Result;
 use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::pipelines::transforms::group_by::Aggregator;
 use crate::pipelines::transforms::group_by::AggregatorParams;
+use crate::pipelines::transforms::group_by::AggregatorState;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
 
 pub struct GroupByPartialTransform {
@@ -38,6 +40,10 @@ pub struct GroupByPartialTransform {
     schema: DataSchemaRef,
     schema_before_group_by: DataSchemaRef,
     input: Arc<dyn Processor>,
+
+    pass_through_min_rows: usize,
+    pass_through_ratio: usize,
+    max_memory_bytes: usize,
 }
 
 impl GroupByPartialTransform {
@@ -53,9 +59,27 @@ impl GroupByPartialTransform {
             schema,
             schema_before_group_by,
             input: Arc::new(EmptyProcessor::create()),
+            pass_through_min_rows: 0,
+            pass_through_ratio: 100,
+            max_memory_bytes: 0,
         }
     }
 
+    /// Pass the rows through to the final group by without aggregation once the groups
+    /// reach `ratio` percent of at least `min_rows` aggregated rows, 0 `min_rows` for never.
+    pub fn with_pass_through(mut self, min_rows: usize, ratio: usize) -> Self {
+        self.pass_through_min_rows = min_rows;
+        self.pass_through_ratio = ratio;
+        self
+    }
+
+    /// Pass the rows through as well once the groups take more than `max_memory_bytes` bytes,
+    /// so that the final group by bounds the memory of the groups instead, 0 for never.
+    pub fn with_max_memory_bytes(mut self, max_memory_bytes: usize) -> Self {
+        self.max_memory_bytes = max_memory_bytes;
+        self
+    }
+
     fn extract_group_columns(&self) -> Vec<String> {
         self.group_exprs
             .iter()
@@ -64,11 +88,14 @@ impl GroupByPartialTransform {
     }
 
     #[inline]
-    async fn aggregate<Method: HashMethod + PolymorphicKeysHelper<Method>>(
+    async fn aggregate<Method>(
         &self,
         method: Method,
         group_cols: Vec<String>,
-    ) -> Result<SendableDataBlockStream> {
+    ) -> Result<SendableDataBlockStream>
+    where
+        Method: HashMethod + PolymorphicKeysHelper<Method> + Send + Sync + 'static,
+    {
         let start = Instant::now();
 
         let stream = self.input.execute().await?;
@@ -76,14 +103,33 @@ impl GroupByPartialTransform {
         let schema = self.schema_before_group_by.clone();
         let aggregator_params = AggregatorParams::try_create(schema, aggr_exprs)?;
 
-        let aggregator = Aggregator::create(method, aggregator_params);
-        let state = aggregator.aggregate(group_cols, stream).await?;
+        let aggregator = Aggregator::create(method, aggregator_params)
+            .with_pass_through(self.pass_through_min_rows, self.pass_through_ratio)
+            .with_max_memory_bytes(self.max_memory_bytes);
+        let (state, rest) = aggregator.aggregate(group_cols.clone(), stream).await?;
 
         let delta = start.elapsed();
         tracing::debug!("Group by partial cost: {:?}", delta);
 
         let finalized_schema = self.schema.clone();
-        aggregator.aggregate_finalized(&state, finalized_schema)
+        let finalized = aggregator.aggregate_finalized(&state, finalized_schema.clone())?;
+
+        match rest {
+            None => Ok(finalized),
+            Some(rest) => {
+                tracing::debug!(
+                    "Group by partial passes through the rest rows after {} groups",
+                    state.len()
+                );
+
+                // The groups are emitted already, release them before reading the rest.
+                drop(state);
+                let passed = rest.map(move |block| {
+                    aggregator.pass_through(&group_cols, &block?, finalized_schema.clone())
+                });
+                Ok(Box::pin(finalized.chain(passed)))
+            }
+        }
     }
 }
 
@@ -125,6 +171,9 @@ impl Processor for GroupByPartialTransform {
     ///  3, 1 -> state1
     ///  4, 2 -> state2
     /// 1.2)  serialize the state to the output block
+    /// 2) If the groups are nearly as many as the rows, or take too much memory, the rest blocks
+    ///  are passed through: every row is serialized with its own state, the final group by
+    ///  merges them.
     #[tracing::instrument(level = "debug", name = "group_by_partial_execute", skip(self))]
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("execute...");
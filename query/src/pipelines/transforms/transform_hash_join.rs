@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_stream::stream;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::hash_join::GraceHashJoin;
+
+/// Joins the blocks of its input, a processor of the probe side, to the rows of the build side,
+/// which is read in full first. The join is shared by the processors of the probe side.
+pub struct HashJoinTransform {
+    input: Arc<dyn Processor>,
+    join: Arc<GraceHashJoin>,
+}
+
+impl HashJoinTransform {
+    pub fn create(join: Arc<GraceHashJoin>) -> Self {
+        HashJoinTransform {
+            input: Arc::new(EmptyProcessor::create()),
+            join,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HashJoinTransform {
+    fn name(&self) -> &str {
+        "HashJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone(), self.join.build_processor()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "hash_join_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let mut probe_stream = self.input.execute().await?;
+        let join = self.join.clone();
+
+        let stream = stream! {
+            while let Some(block) = probe_stream.next().await {
+                let joined = match block {
+                    Ok(block) => join.probe(block).await,
+                    Err(cause) => Err(cause),
+                };
+                match joined {
+                    Ok(None) => {}
+                    Ok(Some(block)) => yield Ok(block),
+                    Err(cause) => {
+                        yield Err(cause);
+                        return;
+                    }
+                }
+            }
+
+            let mut remainder = match join.finish_probe().await {
+                Ok(None) => return,
+                Ok(Some(remainder)) => remainder,
+                Err(cause) => {
+                    yield Err(cause);
+                    return;
+                }
+            };
+            loop {
+                match remainder.next_block() {
+                    Ok(None) => break,
+                    Ok(Some(block)) => yield Ok(block),
+                    Err(cause) => {
+                        yield Err(cause);
+                        break;
+                    }
+                }
+            }
+        };
+        Ok(Box::pin(stream))
+    }
+}
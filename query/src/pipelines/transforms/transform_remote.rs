@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use common_tracing::tracing_futures::Instrument;
 
 use crate::api::FlightClient;
 use crate::api::FlightTicket;
@@ -91,11 +92,19 @@ impl Processor for RemoteTransform {
         let data_schema = self.schema.clone();
         let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
 
+        // The do_get of the remote node is a child of the span, which lasts until the
+        // stream is drained.
+        let span = tracing::info_span!("exchange_receive", node = self.fetch_node_name.as_str());
         let fetch_ticket = self.ticket.clone();
-        let mut flight_client = self.flight_client().await?;
-        let fetch_stream = flight_client
-            .fetch_stream(fetch_ticket, data_schema, timeout)
-            .await?;
+        let fetch_stream = async {
+            let mut flight_client = self.flight_client().await?;
+            flight_client
+                .fetch_stream(fetch_ticket, data_schema, timeout)
+                .await
+        }
+        .instrument(span.clone())
+        .await?;
+        let fetch_stream = Box::pin(fetch_stream.instrument(span));
         Ok(Box::pin(self.ctx.try_create_abortable(fetch_stream)?))
     }
 }
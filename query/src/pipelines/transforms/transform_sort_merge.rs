@@ -22,7 +22,9 @@ use common_exception::Result;
 use common_planners::Expression;
 use common_streams::CorrectWithSchemaStream;
 use common_streams::DataBlockStream;
+use common_streams::ExternalSortStream;
 use common_streams::SendableDataBlockStream;
+use common_streams::SpillDir;
 use common_tracing::tracing;
 use futures::StreamExt;
 
@@ -35,6 +37,7 @@ pub struct SortMergeTransform {
     exprs: Vec<Expression>,
     limit: Option<usize>,
     input: Arc<dyn Processor>,
+    spill: Option<(usize, usize, Arc<SpillDir>)>,
 }
 
 impl SortMergeTransform {
@@ -48,8 +51,22 @@ impl SortMergeTransform {
             exprs,
             limit,
             input: Arc::new(EmptyProcessor::create()),
+            spill: None,
         })
     }
+
+    /// Spill the blocks into the files of `spill_dir` as sorted runs of blocks of
+    /// `block_size` rows once they take more than `max_memory_bytes` bytes, 0 for never. The
+    /// runs are merged once the input is drained.
+    pub fn with_spill(
+        mut self,
+        block_size: usize,
+        max_memory_bytes: usize,
+        spill_dir: Arc<SpillDir>,
+    ) -> Self {
+        self.spill = Some((block_size, max_memory_bytes, spill_dir));
+        self
+    }
 }
 
 #[async_trait]
@@ -76,11 +93,44 @@ impl Processor for SortMergeTransform {
         tracing::debug!("execute...");
 
         let sort_columns_descriptions = get_sort_descriptions(&self.schema, &self.exprs)?;
-        let mut blocks = vec![];
         let mut stream = self.input.execute().await?;
 
+        if let Some((block_size, max_memory_bytes, spill_dir)) = &self.spill {
+            let sorted = ExternalSortStream::try_create(
+                stream,
+                sort_columns_descriptions,
+                self.limit,
+                *block_size,
+                *max_memory_bytes,
+                spill_dir.clone(),
+            )?;
+            return Ok(Box::pin(CorrectWithSchemaStream::new(
+                Box::pin(sorted),
+                self.schema.clone(),
+            )));
+        }
+
+        let mut blocks = vec![];
+
+        let mut rows = 0;
         while let Some(block) = stream.next().await {
-            blocks.push(block?);
+            let block = block?;
+            rows += block.num_rows();
+            blocks.push(block);
+
+            // With a limit, e.g. the top k nearest vectors, only the top rows so far are kept,
+            // merged once the blocks hold twice of them.
+            if let Some(limit) = self.limit {
+                if blocks.len() > 1 && rows >= limit.saturating_mul(2) {
+                    let top = DataBlock::merge_sort_blocks(
+                        &blocks,
+                        &sort_columns_descriptions,
+                        self.limit,
+                    )?;
+                    rows = top.num_rows();
+                    blocks = vec![top];
+                }
+            }
         }
 
         let results = match blocks.len() {
@@ -22,6 +22,7 @@ use common_streams::CorrectWithSchemaStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use common_tracing::tracing_futures::Instrument;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
@@ -75,9 +76,11 @@ impl Processor for SourceTransform {
         let desc = self.source_plan.table_info.desc.clone();
         tracing::debug!("execute, table:{:#} ...", desc);
 
-        Ok(Box::pin(CorrectWithSchemaStream::new(
-            self.read_table().await?,
-            self.source_plan.schema(),
-        )))
+        // The span of reading the partitions lasts until the stream is drained.
+        let span = tracing::info_span!("read_partitions", table = desc.as_str());
+        let stream = self.read_table().instrument(span.clone()).await?;
+        Ok(Box::pin(
+            CorrectWithSchemaStream::new(stream, self.source_plan.schema()).instrument(span),
+        ))
     }
 }
@@ -18,6 +18,7 @@ use std::time::Instant;
 use common_clickhouse_srv::connection::Connection;
 use common_clickhouse_srv::CHContext;
 use common_clickhouse_srv::ClickHouseSession;
+use common_exception::ErrorCode;
 use common_tracing::tracing;
 use metrics::histogram;
 
@@ -46,7 +47,8 @@ impl ClickHouseSession for InteractiveWorker {
     ) -> common_clickhouse_srv::errors::Result<()> {
         let start = Instant::now();
 
-        let mut query_writer = QueryWriter::create(ctx.client_revision, conn);
+        let settings = self.session.get_settings();
+        let mut query_writer = QueryWriter::create(ctx.client_revision, conn, settings);
 
         let session = self.session.clone();
         let get_query_result = InteractiveWorkerBase::do_query(ctx, session);
@@ -112,12 +114,23 @@ impl ClickHouseSession for InteractiveWorker {
                 ),
                 Err(err) => (Err(err), None),
             };
+            let authed = match (authed, &user_info) {
+                (Ok(true), Some(user_info)) => user_manager
+                    .verify_network_policy(user_info, client_addr)
+                    .await
+                    .map(|_| true),
+                (authed, _) => authed,
+            };
             match authed {
-                Ok(res) => {
-                    if res {
-                        self.session.set_current_user(user_info.unwrap());
-                    }
-                    res
+                Ok(true) => {
+                    self.session.set_current_user(user_info.unwrap());
+                    self.session.audit_login(user, client_addr, None);
+                    true
+                }
+                Ok(false) => {
+                    let failure = ErrorCode::AuthenticateFailure("wrong password");
+                    self.session.audit_login(user, client_addr, Some(&failure));
+                    false
                 }
                 Err(failure) => {
                     tracing::error!(
@@ -129,6 +142,7 @@ impl ClickHouseSession for InteractiveWorker {
                         client_addr,
                         failure
                     );
+                    self.session.audit_login(user, client_addr, Some(&failure));
                     false
                 }
             }
@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::LargeBinaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_clickhouse_srv::binary::Encoder;
+use common_clickhouse_srv::types::column::BoxColumnData;
+use common_clickhouse_srv::types::column::ColumnData;
+use common_clickhouse_srv::types::DateTimeType;
+use common_clickhouse_srv::types::Either;
+use common_clickhouse_srv::types::SqlType;
+use common_clickhouse_srv::types::Value;
+use common_clickhouse_srv::types::ValueRef;
+use common_datavalues::DataType;
+use common_datavalues::DateTimeOutput;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// A ClickHouse column over the arrow array of a result column. The block is encoded from the
+/// arrow buffers in place, the values are not copied into the Vec-backed columns of clickhouse-srv.
+#[derive(Clone)]
+pub struct ArrowColumnData {
+    values: ArrowValues,
+    // The nullable columns are written with their null map.
+    nullable: bool,
+}
+
+/// The arrow array of a column, downcast to the array of its type. The arrays share the buffers
+/// of the block, cloning them is cheap.
+#[derive(Clone)]
+enum ArrowValues {
+    Boolean(BooleanArray),
+    Int8(PrimitiveArray<i8>),
+    Int16(PrimitiveArray<i16>),
+    Int32(PrimitiveArray<i32>),
+    Int64(PrimitiveArray<i64>),
+    UInt8(PrimitiveArray<u8>),
+    UInt16(PrimitiveArray<u16>),
+    UInt32(PrimitiveArray<u32>),
+    UInt64(PrimitiveArray<u64>),
+    Float32(PrimitiveArray<f32>),
+    Float64(PrimitiveArray<f64>),
+    // The days since epoch, a ClickHouse Date is 16 bits.
+    Date16(PrimitiveArray<u16>),
+    Date32(PrimitiveArray<i32>),
+    // Written as a DateTime if it has a time zone to be rendered in, else as the seconds since
+    // epoch. The value is the seconds since epoch in both cases.
+    DateTime32(PrimitiveArray<u32>, bool),
+    Interval(PrimitiveArray<i64>),
+    String(LargeBinaryArray),
+}
+
+impl ArrowColumnData {
+    pub fn try_create(
+        data_type: &DataType,
+        nullable: bool,
+        array: &ArrayRef,
+        datetime_output: &DateTimeOutput,
+    ) -> Result<ArrowColumnData> {
+        let values = match data_type {
+            DataType::Boolean => ArrowValues::Boolean(downcast(array)?),
+            DataType::Int8 => ArrowValues::Int8(downcast(array)?),
+            DataType::Int16 => ArrowValues::Int16(downcast(array)?),
+            DataType::Int32 => ArrowValues::Int32(downcast(array)?),
+            DataType::Int64 => ArrowValues::Int64(downcast(array)?),
+            DataType::UInt8 => ArrowValues::UInt8(downcast(array)?),
+            DataType::UInt16 => ArrowValues::UInt16(downcast(array)?),
+            DataType::UInt32 => ArrowValues::UInt32(downcast(array)?),
+            DataType::UInt64 => ArrowValues::UInt64(downcast(array)?),
+            DataType::Float32 => ArrowValues::Float32(downcast(array)?),
+            DataType::Float64 => ArrowValues::Float64(downcast(array)?),
+            DataType::Date16 => ArrowValues::Date16(downcast(array)?),
+            DataType::Date32 => ArrowValues::Date32(downcast(array)?),
+            DataType::DateTime32(tz) => {
+                let with_tz = datetime_output.time_zone(tz).is_some();
+                ArrowValues::DateTime32(downcast(array)?, with_tz)
+            }
+            DataType::Interval(_) => ArrowValues::Interval(downcast(array)?),
+            DataType::String => ArrowValues::String(downcast(array)?),
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unsupported column type:{:?}",
+                    data_type
+                )));
+            }
+        };
+        Ok(ArrowColumnData { values, nullable })
+    }
+}
+
+impl ColumnData for ArrowColumnData {
+    fn sql_type(&self) -> SqlType {
+        let sql_type = self.values.sql_type();
+        match self.nullable {
+            true => SqlType::Nullable(sql_type.into()),
+            false => sql_type,
+        }
+    }
+
+    fn save(&self, encoder: &mut Encoder, start: usize, end: usize) {
+        if self.nullable {
+            let array = self.values.array();
+            for row in start..end {
+                encoder.write(array.is_null(row) as u8);
+            }
+        }
+        self.values.save(encoder, start, end);
+    }
+
+    fn len(&self) -> usize {
+        self.values.array().len()
+    }
+
+    fn push(&mut self, _value: Value) {
+        unimplemented!("the arrow arrays of a result column are immutable")
+    }
+
+    fn at(&self, index: usize) -> ValueRef {
+        if !self.nullable {
+            return self.values.at(index);
+        }
+
+        match self.values.array().is_null(index) {
+            true => ValueRef::Nullable(Either::Left(self.values.sql_type().into())),
+            false => ValueRef::Nullable(Either::Right(Box::new(self.values.at(index)))),
+        }
+    }
+
+    fn clone_instance(&self) -> BoxColumnData {
+        Box::new(self.clone())
+    }
+}
+
+impl ArrowValues {
+    fn array(&self) -> &dyn Array {
+        match self {
+            ArrowValues::Boolean(array) => array,
+            ArrowValues::Int8(array) => array,
+            ArrowValues::Int16(array) => array,
+            ArrowValues::Int32(array) => array,
+            ArrowValues::Int64(array) => array,
+            ArrowValues::UInt8(array) => array,
+            ArrowValues::UInt16(array) => array,
+            ArrowValues::UInt32(array) => array,
+            ArrowValues::UInt64(array) => array,
+            ArrowValues::Float32(array) => array,
+            ArrowValues::Float64(array) => array,
+            ArrowValues::Date16(array) => array,
+            ArrowValues::Date32(array) => array,
+            ArrowValues::DateTime32(array, _) => array,
+            ArrowValues::Interval(array) => array,
+            ArrowValues::String(array) => array,
+        }
+    }
+
+    fn sql_type(&self) -> SqlType {
+        match self {
+            ArrowValues::Boolean(_) => SqlType::UInt8,
+            ArrowValues::Int8(_) => SqlType::Int8,
+            ArrowValues::Int16(_) => SqlType::Int16,
+            ArrowValues::Int32(_) => SqlType::Int32,
+            ArrowValues::Int64(_) => SqlType::Int64,
+            ArrowValues::UInt8(_) => SqlType::UInt8,
+            ArrowValues::UInt16(_) => SqlType::UInt16,
+            ArrowValues::UInt32(_) => SqlType::UInt32,
+            ArrowValues::UInt64(_) => SqlType::UInt64,
+            ArrowValues::Float32(_) => SqlType::Float32,
+            ArrowValues::Float64(_) => SqlType::Float64,
+            ArrowValues::Date16(_) | ArrowValues::Date32(_) => SqlType::Date,
+            ArrowValues::DateTime32(_, true) => SqlType::DateTime(DateTimeType::DateTime32),
+            ArrowValues::DateTime32(_, false) => SqlType::UInt32,
+            ArrowValues::Interval(_) => SqlType::Int64,
+            ArrowValues::String(_) => SqlType::String,
+        }
+    }
+
+    // Write the values of the rows, the values of the null rows are written as they are in the
+    // arrow buffers, ClickHouse skips them by the null map.
+    fn save(&self, encoder: &mut Encoder, start: usize, end: usize) {
+        match self {
+            ArrowValues::Boolean(array) => {
+                (start..end).for_each(|row| encoder.write(array.value(row) as u8))
+            }
+            ArrowValues::Int8(array) => array.write_to(encoder, start, end),
+            ArrowValues::Int16(array) => array.write_to(encoder, start, end),
+            ArrowValues::Int32(array) => array.write_to(encoder, start, end),
+            ArrowValues::Int64(array) => array.write_to(encoder, start, end),
+            ArrowValues::UInt8(array) => array.write_to(encoder, start, end),
+            ArrowValues::UInt16(array) => array.write_to(encoder, start, end),
+            ArrowValues::UInt32(array) => array.write_to(encoder, start, end),
+            ArrowValues::UInt64(array) => array.write_to(encoder, start, end),
+            ArrowValues::Float32(array) => array.write_to(encoder, start, end),
+            ArrowValues::Float64(array) => array.write_to(encoder, start, end),
+            ArrowValues::Date16(array) => array.write_to(encoder, start, end),
+            ArrowValues::Date32(array) => array.values()[start..end]
+                .iter()
+                .for_each(|v| encoder.write(*v as u16)),
+            ArrowValues::DateTime32(array, _) => array.write_to(encoder, start, end),
+            ArrowValues::Interval(array) => array.write_to(encoder, start, end),
+            ArrowValues::String(array) => {
+                (start..end).for_each(|row| encoder.byte_string(array.value(row)))
+            }
+        }
+    }
+
+    fn at(&self, index: usize) -> ValueRef {
+        let utc: Tz = "UTC".parse().unwrap();
+        match self {
+            ArrowValues::Boolean(array) => ValueRef::UInt8(array.value(index) as u8),
+            ArrowValues::Int8(array) => ValueRef::Int8(array.value(index)),
+            ArrowValues::Int16(array) => ValueRef::Int16(array.value(index)),
+            ArrowValues::Int32(array) => ValueRef::Int32(array.value(index)),
+            ArrowValues::Int64(array) => ValueRef::Int64(array.value(index)),
+            ArrowValues::UInt8(array) => ValueRef::UInt8(array.value(index)),
+            ArrowValues::UInt16(array) => ValueRef::UInt16(array.value(index)),
+            ArrowValues::UInt32(array) => ValueRef::UInt32(array.value(index)),
+            ArrowValues::UInt64(array) => ValueRef::UInt64(array.value(index)),
+            ArrowValues::Float32(array) => ValueRef::Float32(array.value(index)),
+            ArrowValues::Float64(array) => ValueRef::Float64(array.value(index)),
+            ArrowValues::Date16(array) => ValueRef::Date(array.value(index), utc),
+            ArrowValues::Date32(array) => ValueRef::Date(array.value(index) as u16, utc),
+            ArrowValues::DateTime32(array, true) => ValueRef::DateTime(array.value(index), utc),
+            ArrowValues::DateTime32(array, false) => ValueRef::UInt32(array.value(index)),
+            ArrowValues::Interval(array) => ValueRef::Int64(array.value(index)),
+            ArrowValues::String(array) => ValueRef::String(array.value(index)),
+        }
+    }
+}
+
+// Writes the values of the rows of a primitive array as they are in its buffer.
+trait WriteValues {
+    fn write_to(&self, encoder: &mut Encoder, start: usize, end: usize);
+}
+
+macro_rules! impl_write_values {
+    ($($t:ty),*) => {
+        $(
+            impl WriteValues for PrimitiveArray<$t> {
+                fn write_to(&self, encoder: &mut Encoder, start: usize, end: usize) {
+                    self.values()[start..end].iter().for_each(|v| encoder.write(*v));
+                }
+            }
+        )*
+    };
+}
+
+impl_write_values!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+fn downcast<T: Clone + 'static>(array: &ArrayRef) -> Result<T> {
+    match array.as_any().downcast_ref::<T>() {
+        Some(array) => Ok(array.clone()),
+        None => Err(ErrorCode::BadDataValueType(format!(
+            "Unexpected arrow array type:{:?}",
+            array.data_type()
+        ))),
+    }
+}
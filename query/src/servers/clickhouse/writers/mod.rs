@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod arrow_column;
 mod query_writer;
 
 pub use query_writer::from_clickhouse_block;
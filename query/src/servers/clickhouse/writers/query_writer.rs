@@ -13,10 +13,8 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::sync::Arc;
 
-use chrono::Date;
-use chrono::DateTime;
-use chrono_tz::Tz;
 use common_base::ProgressValues;
 use common_clickhouse_srv::connection::Connection;
 use common_clickhouse_srv::errors::Error as CHError;
@@ -38,17 +36,21 @@ use futures::channel::mpsc::Receiver;
 use futures::StreamExt;
 
 use crate::servers::clickhouse::interactive_worker_base::BlockItem;
+use crate::servers::clickhouse::writers::arrow_column::ArrowColumnData;
+use crate::sessions::Settings;
 
 pub struct QueryWriter<'a> {
     client_version: u64,
     conn: &'a mut Connection,
+    settings: Arc<Settings>,
 }
 
 impl<'a> QueryWriter<'a> {
-    pub fn create(version: u64, conn: &'a mut Connection) -> QueryWriter {
+    pub fn create(version: u64, conn: &'a mut Connection, settings: Arc<Settings>) -> QueryWriter {
         QueryWriter {
             conn,
             client_version: version,
+            settings,
         }
     }
 
@@ -92,7 +94,9 @@ impl<'a> QueryWriter<'a> {
     }
 
     async fn write_block(&mut self, block: DataBlock) -> Result<()> {
-        let block = to_clickhouse_block(block)?;
+        // read for each block, the query may have changed the settings of the session
+        let datetime_output = self.settings.get_datetime_output()?;
+        let block = to_clickhouse_block(block, &datetime_output)?;
 
         match self.conn.write_block(&block).await {
             Ok(_) => Ok(()),
@@ -138,7 +142,7 @@ pub fn from_clickhouse_err(res: common_clickhouse_srv::errors::Error) -> ErrorCo
     ErrorCode::LogicalError(format!("clickhouse-srv expception: {:?}", res))
 }
 
-pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
+pub fn to_clickhouse_block(block: DataBlock, datetime_output: &DateTimeOutput) -> Result<Block> {
     let mut result = Block::new();
     if block.num_columns() == 0 {
         return Ok(result);
@@ -150,7 +154,7 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
         let name = field.name();
         result.append_column(column::new_column(
             name,
-            to_clickhouse_column(field, &column)?,
+            to_clickhouse_column(field, &column, datetime_output)?,
         ));
     }
     Ok(result)
@@ -259,184 +263,29 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
     Ok(DataBlock::create_by_array(schema, arrays))
 }
 
-fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnData> {
-    let is_nullable = field.is_nullable();
-    let utc: Tz = "UTC".parse().unwrap();
-    let result = match is_nullable {
-        true => match field.data_type() {
-            DataType::Int8 => Vec::column_from::<ArcColumnWrapper>(column.i8()?.collect_values()),
-            DataType::Int16 => Vec::column_from::<ArcColumnWrapper>(column.i16()?.collect_values()),
-            DataType::Int32 => Vec::column_from::<ArcColumnWrapper>(column.i32()?.collect_values()),
-            DataType::Int64 => Vec::column_from::<ArcColumnWrapper>(column.i64()?.collect_values()),
-            DataType::UInt8 => Vec::column_from::<ArcColumnWrapper>(column.u8()?.collect_values()),
-            DataType::UInt16 => {
-                Vec::column_from::<ArcColumnWrapper>(column.u16()?.collect_values())
-            }
-            DataType::Date16 => {
-                let c: Vec<Option<Date<Tz>>> = column
-                    .u16()?
-                    .into_iter()
-                    .map(|x| x.map(|v| v.to_date(&utc)))
-                    .collect();
-                Vec::column_from::<ArcColumnWrapper>(c)
-            }
-            DataType::UInt32 => {
-                Vec::column_from::<ArcColumnWrapper>(column.u32()?.collect_values())
-            }
-            DataType::Date32 => {
-                let c: Vec<Option<Date<Tz>>> = column
-                    .i32()?
-                    .into_iter()
-                    .map(|x| x.map(|v| v.to_date(&utc)))
-                    .collect();
-                Vec::column_from::<ArcColumnWrapper>(c)
-            }
-            DataType::DateTime32(tz) => {
-                let tz = tz.clone();
-                let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                let tz: Tz = tz.parse().unwrap();
-
-                let c: Vec<Option<DateTime<Tz>>> = column
-                    .u32()?
-                    .into_iter()
-                    .map(|x| x.map(|v| v.to_date_time(&tz)))
-                    .collect();
-
-                Vec::column_from::<ArcColumnWrapper>(c)
-            }
-            DataType::UInt64 => {
-                Vec::column_from::<ArcColumnWrapper>(column.u64()?.collect_values())
-            }
-            DataType::Float32 => {
-                Vec::column_from::<ArcColumnWrapper>(column.f32()?.collect_values())
-            }
-            DataType::Float64 => {
-                Vec::column_from::<ArcColumnWrapper>(column.f64()?.collect_values())
-            }
-            DataType::String => {
-                Vec::column_from::<ArcColumnWrapper>(column.string()?.collect_values())
-            }
-            DataType::Boolean => {
-                let v: Vec<Option<u8>> = column
-                    .bool()?
-                    .into_iter()
-                    .map(|f| f.map(|v| v as u8))
-                    .collect();
-
-                Vec::column_from::<ArcColumnWrapper>(v)
-            }
-            DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
-                fields
-                    .iter()
-                    .zip(column.tuple()?.inner().values().iter())
-                    .map(|(f, v)| {
-                        let series = v.clone().into_series();
-                        to_clickhouse_column(f, &series)
-                    })
-                    .collect::<Result<Vec<_>>>()?,
-            ),
-            _ => {
-                return Err(ErrorCode::BadDataValueType(format!(
-                    "Unsupported column type:{:?}",
-                    column.data_type()
-                )));
-            }
-        },
-        false => match field.data_type() {
-            DataType::Int8 => Vec::column_from::<ArcColumnWrapper>(
-                column.i8()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Int16 => Vec::column_from::<ArcColumnWrapper>(
-                column.i16()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Int32 => Vec::column_from::<ArcColumnWrapper>(
-                column.i32()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Int64 => Vec::column_from::<ArcColumnWrapper>(
-                column.i64()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::UInt8 => Vec::column_from::<ArcColumnWrapper>(
-                column.u8()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::UInt16 => Vec::column_from::<ArcColumnWrapper>(
-                column.u16()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Date16 => {
-                let c: Vec<Date<Tz>> = column
-                    .u16()?
-                    .into_no_null_iter()
-                    .map(|v| v.to_date(&utc))
-                    .collect();
-
-                Vec::column_from::<ArcColumnWrapper>(c)
-            }
-            DataType::UInt32 => Vec::column_from::<ArcColumnWrapper>(
-                column.u32()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Date32 => {
-                let c: Vec<Date<Tz>> = column
-                    .i32()?
-                    .into_no_null_iter()
-                    .map(|v| v.to_date(&utc))
-                    .collect();
-
-                Vec::column_from::<ArcColumnWrapper>(c)
-            }
-            DataType::DateTime32(tz) => {
-                let tz = tz.clone();
-                let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                let tz: Tz = tz.parse().unwrap();
-
-                let c: Vec<DateTime<Tz>> = column
-                    .u32()?
-                    .into_no_null_iter()
-                    .map(|v| v.to_date_time(&tz))
-                    .collect();
-
-                Vec::column_from::<ArcColumnWrapper>(c)
-            }
-
-            DataType::UInt64 => Vec::column_from::<ArcColumnWrapper>(
-                column.u64()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Float32 => Vec::column_from::<ArcColumnWrapper>(
-                column.f32()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Float64 => Vec::column_from::<ArcColumnWrapper>(
-                column.f64()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::String => {
-                let vs: Vec<&[u8]> = column.string()?.into_no_null_iter().collect();
-                Vec::column_from::<ArcColumnWrapper>(vs)
-            }
-            DataType::Boolean => {
-                let vs: Vec<u8> = column
-                    .bool()?
-                    .into_no_null_iter()
-                    .map(|c| c as u8)
-                    .collect();
-                Vec::column_from::<ArcColumnWrapper>(vs)
-            }
-            DataType::Interval(_) => Vec::column_from::<ArcColumnWrapper>(
-                column.i64()?.inner().values().as_slice().to_vec(),
-            ),
-            DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
-                fields
-                    .iter()
-                    .zip(column.tuple()?.inner().values().iter())
-                    .map(|(f, v)| {
-                        let series = v.clone().into_series();
-                        to_clickhouse_column(f, &series)
-                    })
-                    .collect::<Result<Vec<_>>>()?,
-            ),
-            _ => {
-                return Err(ErrorCode::BadDataValueType(format!(
-                    "Unsupported column type:{:?}",
-                    column.data_type()
-                )));
-            }
-        },
-    };
-    Ok(result)
+// The struct columns are the tuples of the columns of their fields, the others are written from
+// their arrow arrays in place.
+fn to_clickhouse_column(
+    field: &DataField,
+    column: &Series,
+    datetime_output: &DateTimeOutput,
+) -> Result<ArcColumnData> {
+    match field.data_type() {
+        DataType::Struct(fields) => Ok(Vec::column_from::<ArcColumnWrapper>(
+            fields
+                .iter()
+                .zip(column.tuple()?.inner().values().iter())
+                .map(|(f, v)| {
+                    let series = v.clone().into_series();
+                    to_clickhouse_column(f, &series, datetime_output)
+                })
+                .collect::<Result<Vec<_>>>()?,
+        )),
+        data_type => {
+            let array = column.get_array_ref();
+            let nullable = field.is_nullable();
+            let column = ArrowColumnData::try_create(data_type, nullable, &array, datetime_output)?;
+            Ok(Arc::new(column))
+        }
+    }
 }
@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_tracing::tracing;
 use poem::get;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::put;
 use poem::Endpoint;
 use poem::EndpointExt;
@@ -27,9 +28,12 @@ use poem::Route;
 
 use crate::common::service::HttpShutdownHandler;
 use crate::configs::Config;
+use crate::servers::http::middleware::HTTPSessionMiddleware;
+use crate::servers::http::v1::download;
 use crate::servers::http::v1::query_route;
 use crate::servers::http::v1::statement_router;
 use crate::servers::http::v1::streaming_load;
+use crate::servers::http::v1::upload_to_stage;
 use crate::servers::Server;
 use crate::sessions::SessionManager;
 
@@ -50,8 +54,8 @@ impl HttpHandler {
     pub fn usage(sock: SocketAddr) -> String {
         format!(
             r#" examples:
-curl --request POST '{:?}/v1/statement/' --header 'Content-Type: text/plain' --data-raw 'SELECT avg(number) FROM numbers(100000000)'
-curl --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' --data-raw '{{"sql": "SELECT avg(number) FROM numbers(100000000)"}}'"#,
+curl -u root: --request POST '{:?}/v1/statement/' --header 'Content-Type: text/plain' --data-raw 'SELECT avg(number) FROM numbers(100000000)'
+curl -u root: --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' --data-raw '{{"sql": "SELECT avg(number) FROM numbers(100000000)"}}'"#,
             sock, sock
         )
     }
@@ -65,6 +69,9 @@ curl --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' -
             .nest("/v1/statement", statement_router())
             .nest("/v1/query", query_route())
             .at("/v1/streaming_load", put(streaming_load))
+            .at("/v1/upload_to_stage", put(upload_to_stage))
+            .at("/v1/download", post(download))
+            .with(HTTPSessionMiddleware)
             .data(self.session_manager.clone())
             .boxed()
     }
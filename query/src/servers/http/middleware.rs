@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use headers::authorization::Basic;
+use headers::authorization::Bearer;
+use headers::authorization::Credentials;
+use poem::error::Result as PoemResult;
+use poem::http::header::AUTHORIZATION;
+use poem::http::StatusCode;
+use poem::Endpoint;
+use poem::IntoResponse;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+
+use crate::users::auth::Credential;
+
+/// Extract the credential of `Authorization` header into the request extensions.
+/// The credential is verified when the session of the request is created.
+pub struct HTTPSessionMiddleware;
+
+impl<E: Endpoint> Middleware<E> for HTTPSessionMiddleware {
+    type Output = HTTPSessionEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        HTTPSessionEndpoint { ep }
+    }
+}
+
+pub struct HTTPSessionEndpoint<E> {
+    ep: E,
+}
+
+pub fn get_credential(req: &Request) -> Result<Option<Credential>> {
+    let std_auth_headers: Vec<_> = req.headers().get_all(AUTHORIZATION).iter().collect();
+    match std_auth_headers.len() {
+        0 => Ok(None),
+        1 => {
+            let value = std_auth_headers[0];
+            if value.as_bytes().starts_with(b"Basic ") {
+                match Basic::decode(value) {
+                    Some(basic) => Ok(Some(Credential::Password {
+                        name: basic.username().to_string(),
+                        password: Some(basic.password().as_bytes().to_vec()),
+                        hostname: None,
+                    })),
+                    None => Err(ErrorCode::AuthenticateFailure("bad Basic auth header")),
+                }
+            } else if value.as_bytes().starts_with(b"Bearer ") {
+                match Bearer::decode(value) {
+                    Some(bearer) => Ok(Some(Credential::Jwt {
+                        token: bearer.token().to_string(),
+                    })),
+                    None => Err(ErrorCode::AuthenticateFailure("bad Bearer auth header")),
+                }
+            } else {
+                Err(ErrorCode::AuthenticateFailure("bad auth header"))
+            }
+        }
+        _ => Err(ErrorCode::AuthenticateFailure(
+            "multiple authorization headers detected",
+        )),
+    }
+}
+
+/// The credential extracted by `HTTPSessionMiddleware`, the handlers creating a session
+/// refuse the requests without one.
+pub fn require_credential(req: &Request) -> PoemResult<&Credential> {
+    req.extensions().get::<Credential>().ok_or_else(|| {
+        poem::Error::from_string("No Authorization header", StatusCode::UNAUTHORIZED)
+    })
+}
+
+/// The peer address of the request, HTTP sessions are not attached to a connection,
+/// so the handlers set it on the session for the network policy and the audit log.
+pub fn get_client_host(req: &Request) -> Option<SocketAddr> {
+    req.remote_addr().as_socket_addr().cloned()
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, mut req: Request) -> PoemResult<Self::Output> {
+        match get_credential(&req) {
+            Ok(Some(credential)) => {
+                req.extensions_mut().insert(credential);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                return Err(poem::Error::from_string(
+                    err.message(),
+                    StatusCode::UNAUTHORIZED,
+                ));
+            }
+        }
+        self.ep.call(req).await.map(|v| v.into_response())
+    }
+}
@@ -13,6 +13,12 @@
 // limitations under the License.
 
 mod http_services;
+mod middleware;
 pub mod v1;
 
 pub use http_services::HttpHandler;
+pub use middleware::get_client_host;
+pub use middleware::get_credential;
+pub use middleware::require_credential;
+pub use middleware::HTTPSessionEndpoint;
+pub use middleware::HTTPSessionMiddleware;
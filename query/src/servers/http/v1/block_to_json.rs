@@ -20,6 +20,9 @@ use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::DFPrimitiveType;
 use common_datavalues::DataType;
+use common_datavalues::DateConverter;
+use common_datavalues::DateTimeOutput;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Serialize;
@@ -92,11 +95,40 @@ where
         .collect()
 }
 
+// The DateTime values in the time zone, or the seconds since epoch if there is none.
+fn datetime_array_to_json(array: &DFPrimitiveArray<u32>, tz: &Option<Tz>) -> Vec<JsonValue> {
+    match tz {
+        Some(tz) => array
+            .into_iter()
+            .map(|o| o.map(|x| x.to_date_time(tz).format(TIME_FMT).to_string()))
+            .map(to_json_value)
+            .collect(),
+        None => primitive_array_to_json(array),
+    }
+}
+
+fn datetime_array_to_json_not_null(
+    array: &DFPrimitiveArray<u32>,
+    tz: &Option<Tz>,
+) -> Vec<JsonValue> {
+    match tz {
+        Some(tz) => array
+            .into_no_null_iter()
+            .map(|x| x.to_date_time(tz).format(TIME_FMT).to_string())
+            .map(to_json_value)
+            .collect(),
+        None => primitive_array_to_json_not_null(array),
+    }
+}
+
 fn bad_type(data_type: &DataType) -> ErrorCode {
     ErrorCode::BadDataValueType(format!("Unsupported column type:{:?}", data_type))
 }
 
-pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
+pub fn block_to_json(
+    block: &DataBlock,
+    datetime_output: &DateTimeOutput,
+) -> Result<Vec<Vec<JsonValue>>> {
     let mut col_table = Vec::new();
     let columns_size = block.columns().len();
     for col_index in 0..columns_size {
@@ -127,8 +159,9 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::Boolean => series.bool()?.into_iter().map(to_json_value).collect(),
                 DataType::Date16 => date_array_to_string_array(series.u16()?, DATE_FMT),
                 DataType::Date32 => date_array_to_string_array(series.i32()?, DATE_FMT),
-                // TODO(youngsofun): add time zone?
-                DataType::DateTime32(_) => date_array_to_string_array(series.i32()?, TIME_FMT),
+                DataType::DateTime32(tz) => {
+                    datetime_array_to_json(series.u32()?, &datetime_output.time_zone(tz))
+                }
                 // TODO(youngsofun): support other DataType
                 _ => return Err(bad_type(data_type)),
             },
@@ -156,8 +189,8 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                     .collect(),
                 DataType::Date16 => date_array_to_string_array_not_null(series.u16()?, DATE_FMT),
                 DataType::Date32 => date_array_to_string_array_not_null(series.i32()?, DATE_FMT),
-                DataType::DateTime32(_) => {
-                    date_array_to_string_array_not_null(series.i32()?, TIME_FMT)
+                DataType::DateTime32(tz) => {
+                    datetime_array_to_json_not_null(series.u32()?, &datetime_output.time_zone(tz))
                 }
                 _ => return Err(bad_type(data_type)),
             },
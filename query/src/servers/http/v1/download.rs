@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::Query;
+use poem::Request;
+use poem::Response;
+
+use crate::formats::CsvOutputFormat;
+use crate::formats::CsvOutputOptions;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::get_client_host;
+use crate::servers::http::require_credential;
+use crate::sessions::SessionManager;
+use crate::sql::PlanParser;
+
+/// Runs the SQL of the body and responds its result as a CSV file, written as told by the
+/// query parameters, which are the CSV options of `COPY INTO <location>`, e.g.
+/// `/v1/download?csv_header=1&bom=1&line_ending=crlf` for Excel.
+///
+/// The result is collected in memory before responded, `COPY INTO <location>` is meant for the
+/// large ones.
+#[poem::handler]
+pub async fn download(
+    req: &Request,
+    sql: String,
+    Query(options): Query<HashMap<String, String>>,
+    sessions_extension: Data<&Arc<SessionManager>>,
+) -> PoemResult<Response> {
+    let session_manager = sessions_extension.0;
+    let session = session_manager
+        .create_session("Download")
+        .map_err(InternalServerError)?;
+    session.set_client_host(get_client_host(req));
+    // Auth.
+    let credential = require_credential(req)?;
+    session
+        .get_auth_manager()
+        .auth(&session, credential)
+        .await
+        .map_err(|e| poem::Error::from_string(e.message(), StatusCode::UNAUTHORIZED))?;
+
+    let context = session
+        .create_context()
+        .await
+        .map_err(InternalServerError)?;
+
+    let bad_request = |message: String| poem::Error::from_string(message, StatusCode::BAD_REQUEST);
+    match options.get("format") {
+        Some(format) if !format.eq_ignore_ascii_case("csv") => {
+            return Err(bad_request(format!(
+                "Download only supports csv format, but got {}",
+                format
+            )));
+        }
+        _ => {}
+    }
+    let mut options =
+        CsvOutputOptions::from_options(&options).map_err(|e| bad_request(e.message()))?;
+    options.datetime_output = context
+        .get_settings()
+        .get_datetime_output()
+        .map_err(InternalServerError)?;
+
+    let plan = PlanParser::parse(&sql, context.clone())
+        .await
+        .map_err(|e| bad_request(e.message()))?;
+    context.attach_query_str(&sql);
+    let format = CsvOutputFormat::create(plan.schema(), options);
+    let interpreter =
+        InterpreterFactory::get(context.clone(), plan).map_err(InternalServerError)?;
+    let blocks = interpreter
+        .execute(None)
+        .await
+        .map_err(InternalServerError)?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(InternalServerError)?;
+
+    let mut body = vec![];
+    format.serialize_prefix(&mut body);
+    for block in blocks.iter() {
+        format
+            .serialize_block(block, &mut body)
+            .map_err(InternalServerError)?;
+    }
+
+    Ok(Response::builder()
+        .content_type("text/csv; charset=utf-8")
+        .header(
+            "Content-Disposition",
+            format!("attachment; filename=\"{}.csv\"", context.get_id()),
+        )
+        .body(body))
+}
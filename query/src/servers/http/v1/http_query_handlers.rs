@@ -31,10 +31,14 @@ use poem::web::Json;
 use poem::web::Path;
 use poem::web::Query;
 use poem::IntoResponse;
+use poem::Request;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::servers::http::get_client_host;
+use crate::servers::http::require_credential;
+use crate::servers::http::v1::query::ColumnStats;
 use crate::servers::http::v1::query::ExecuteStateName;
 use crate::servers::http::v1::query::HttpQuery;
 use crate::servers::http::v1::query::HttpQueryRequest;
@@ -92,16 +96,20 @@ pub struct QueryResponse {
     // just call it after client not use it anymore, not care about the server-side behavior
     pub final_uri: Option<String>,
     pub next_uri: Option<String>,
+    // With `column_stats` in the request, of the rows of the pages sent so far.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column_stats: Option<Vec<ColumnStats>>,
 }
 
 impl QueryResponse {
     pub(crate) fn from_internal(id: String, r: HttpQueryResponseInternal) -> QueryResponse {
-        let (data, next_url) = match &r.data {
+        let (data, next_url, column_stats) = match &r.data {
             Some(d) => (
                 d.page.data.clone(),
                 d.next_page_no.map(|n| make_page_uri(&id, n)),
+                d.column_stats.clone(),
             ),
-            None => (Arc::new(vec![]), None),
+            None => (Arc::new(vec![]), None, None),
         };
         let columns = r.initial_state.as_ref().and_then(|v| v.schema.clone());
         let stats = QueryStats {
@@ -118,6 +126,7 @@ impl QueryResponse {
             stats_uri: Some(make_state_uri(&id)),
             final_uri: Some(make_final_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
+            column_stats,
         }
     }
 
@@ -132,6 +141,7 @@ impl QueryResponse {
             stats_uri: None,
             final_uri: None,
             error: Some(QueryError::from_error_code(err)),
+            column_stats: None,
         }
     }
 }
@@ -217,6 +227,7 @@ async fn query_page_handler(
 
 #[poem::handler]
 pub(crate) async fn query_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Query(params): Query<PageParams>,
     Json(req): Json<HttpQueryRequest>,
@@ -225,7 +236,16 @@ pub(crate) async fn query_handler(
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
-    let query = HttpQuery::try_create(query_id.clone(), req, session_manager).await;
+    let credential = require_credential(request)?;
+    let client_host = get_client_host(request);
+    let query = HttpQuery::try_create(
+        query_id.clone(),
+        req,
+        session_manager,
+        credential,
+        client_host,
+    )
+    .await;
 
     match query {
         Ok(query) => {
@@ -20,7 +20,8 @@ use common_base::ProgressValues;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
 use common_streams::CsvSource;
-use common_streams::Source;
+use common_streams::MatchByColumnName;
+use common_streams::SourceStream;
 use common_tracing::tracing;
 use futures::StreamExt;
 use poem::error::InternalServerError;
@@ -34,6 +35,9 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::interpreters::InterpreterFactory;
+use crate::pipelines::transforms::AddOnStream;
+use crate::servers::http::get_client_host;
+use crate::servers::http::require_credential;
 use crate::sessions::SessionManager;
 use crate::sql::PlanParser;
 
@@ -55,15 +59,14 @@ pub async fn streaming_load(
     let session = session_manager
         .create_session("Streaming load")
         .map_err(InternalServerError)?;
+    session.set_client_host(get_client_host(req));
     // Auth.
-    let user_name = "root";
-    let user_manager = session.get_user_manager();
-    // TODO: list user's grant list and check client address
-    let user_info = user_manager
-        .get_user(user_name, "%")
+    let credential = require_credential(req)?;
+    session
+        .get_auth_manager()
+        .auth(&session, credential)
         .await
-        .map_err(InternalServerError)?;
-    session.set_current_user(user_info);
+        .map_err(|e| poem::Error::from_string(e.message(), StatusCode::UNAUTHORIZED))?;
 
     let context = session
         .create_context()
@@ -102,6 +105,21 @@ pub async fn streaming_load(
         })
         .unwrap_or(b'\n');
 
+    let match_by_column_name = MatchByColumnName::try_create(
+        req.headers()
+            .get("match_by_column_name")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("none"),
+    )
+    .map_err(|e| poem::Error::from_string(e.message(), StatusCode::BAD_REQUEST))?;
+
+    let error_on_column_count_mismatch = req
+        .headers()
+        .get("error_on_column_count_mismatch")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+        .unwrap_or(false);
+
     let plan = PlanParser::parse(insert_sql, context.clone())
         .await
         .map_err(InternalServerError)?;
@@ -152,15 +170,31 @@ pub async fn streaming_load(
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
             let reader = field.into_async_read();
-            let mut source = CsvSource::try_create(reader.compat(), plan.schema(), csv_header, field_delimitor, record_delimitor, max_block_size)?;
-
-            loop {
-                let block = source.read().await;
-                match block {
-                    Ok(None) => break,
-                    Ok(Some(b)) =>  yield(Ok(b)),
-                    Err(e) => yield(Err(e)),
+            let source = CsvSource::try_create(reader.compat(), plan.schema(), csv_header, field_delimitor, record_delimitor, max_block_size)
+                .map(|source| source
+                    .with_match_by_column_name(match_by_column_name)
+                    .with_error_on_column_count_mismatch(error_on_column_count_mismatch));
+            let source = match source {
+                Ok(source) => source,
+                Err(e) => {
+                    yield(Err(e));
+                    break;
+                }
+            };
+
+            // The files may have different columns when matched by name, each is filled alone.
+            let blocks = SourceStream::new(Box::new(source)).execute().await;
+            let blocks = match blocks {
+                Ok(blocks) => AddOnStream::try_create_by_first_block(blocks, plan.schema()).await,
+                Err(e) => Err(e),
+            };
+            match blocks {
+                Ok(mut blocks) => {
+                    while let Some(block) = blocks.next().await {
+                        yield(block);
+                    }
                 }
+                Err(e) => yield(Err(e)),
             }
         }
     };
@@ -13,14 +13,17 @@
 // limitations under the License.
 
 pub mod block_to_json;
+mod download;
 mod http_query_handlers;
 mod load;
 mod query;
 mod statement;
+mod upload_to_stage;
 
 pub(crate) use block_to_json::block_to_json;
 pub(crate) use block_to_json::JsonBlock;
 pub(crate) use block_to_json::JsonBlockRef;
+pub use download::download;
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
@@ -29,8 +32,11 @@ pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub use load::streaming_load;
 pub use load::LoadResponse;
+pub use query::ColumnStats;
 pub use query::ExecuteStateName;
 pub use query::HttpQueryHandle;
 pub use query::HttpQueryManager;
 pub use statement::statement_handler;
 pub use statement::statement_router;
+pub use upload_to_stage::upload_to_stage;
+pub use upload_to_stage::UploadToStageResponse;
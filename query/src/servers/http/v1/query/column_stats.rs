@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::servers::http::v1::block_to_json;
+
+/// The statistics of a column of the result, formatted as the values of the column.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    pub name: String,
+    // NULL if all the values are NULL, or the type has no order.
+    pub min: JsonValue,
+    pub max: JsonValue,
+    pub null_count: usize,
+}
+
+/// The min, max and the number of NULLs of each column of the rows of the result sent so far,
+/// so that the clients need not aggregate the result again, e.g. to set the axis of a chart.
+pub struct ResultStats {
+    schema: DataSchemaRef,
+    min: Vec<DataValue>,
+    max: Vec<DataValue>,
+    null_count: Vec<usize>,
+}
+
+impl ResultStats {
+    pub fn new(schema: DataSchemaRef) -> ResultStats {
+        let nulls = schema
+            .fields()
+            .iter()
+            .map(|f| DataValue::from(f.data_type()))
+            .collect::<Vec<_>>();
+        ResultStats {
+            min: nulls.clone(),
+            max: nulls,
+            null_count: vec![0; schema.fields().len()],
+            schema,
+        }
+    }
+
+    pub fn update(&mut self, block: &DataBlock) -> Result<()> {
+        for index in 0..self.null_count.len() {
+            let series = block.column(index).to_array()?;
+            self.null_count[index] += series.null_count();
+            // The types without order, e.g. Struct, keep NULL.
+            if let (Ok(min), Ok(max)) = (series.min(), series.max()) {
+                self.min[index] = Self::merge(&self.min[index], min, |s| s.min())?;
+                self.max[index] = Self::merge(&self.max[index], max, |s| s.max())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn merge<F>(current: &DataValue, value: DataValue, agg: F) -> Result<DataValue>
+    where F: Fn(&Series) -> Result<DataValue> {
+        if current.is_null() {
+            return Ok(value);
+        }
+        if value.is_null() {
+            return Ok(current.clone());
+        }
+        let column = DataColumnCommon::concat(&[
+            DataColumn::Constant(current.clone(), 1),
+            DataColumn::Constant(value, 1),
+        ])?;
+        agg(&column.to_array()?)
+    }
+
+    pub fn to_json(&self, datetime_output: &DateTimeOutput) -> Result<Vec<ColumnStats>> {
+        if self.null_count.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // The values are formatted as the rows of the result, in the nullable columns.
+        let fields = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| DataField::new(f.name(), f.data_type().clone(), true))
+            .collect::<Vec<_>>();
+        let schema = DataSchemaRefExt::create(fields);
+        let to_row = |values: &[DataValue]| -> Result<Vec<JsonValue>> {
+            let columns = values
+                .iter()
+                .map(|v| DataColumn::Constant(v.clone(), 1))
+                .collect::<Vec<_>>();
+            let rows = block_to_json(&DataBlock::create(schema.clone(), columns), datetime_output)?;
+            Ok(rows.into_iter().next().unwrap_or_default())
+        };
+        let min = to_row(&self.min)?;
+        let max = to_row(&self.max)?;
+
+        Ok(self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| ColumnStats {
+                name: field.name().to_string(),
+                min: min[index].clone(),
+                max: max[index].clone(),
+                null_count: self.null_count[index],
+            })
+            .collect())
+    }
+}
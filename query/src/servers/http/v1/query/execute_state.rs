@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -23,6 +25,7 @@ use common_base::ProgressValues;
 use common_base::TrySpawn;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DateTimeOutput;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
@@ -37,18 +40,23 @@ use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::users::auth::Credential;
 
 #[derive(Deserialize, Debug)]
 pub struct HttpQueryRequest {
     #[serde(default)]
     pub session: HttpSessionConf,
     pub sql: String,
+    /// Adds the min, max and the number of NULLs of each column of the result into the responses.
+    #[serde(default)]
+    pub column_stats: bool,
 }
 
 #[derive(Deserialize, Debug, Default)]
 pub struct HttpSessionConf {
     pub database: Option<String>,
-    pub user: Option<String>,
+    /// The settings of the session of the query, e.g. `time_zone`.
+    pub settings: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
@@ -149,21 +157,29 @@ impl ExecuteState {
     pub(crate) async fn try_create(
         request: &HttpQueryRequest,
         session_manager: &Arc<SessionManager>,
+        credential: &Credential,
+        client_host: Option<SocketAddr>,
         block_tx: mpsc::Sender<DataBlock>,
-    ) -> Result<(ExecutorRef, DataSchemaRef)> {
+    ) -> Result<(ExecutorRef, DataSchemaRef, DateTimeOutput)> {
         let sql = &request.sql;
         let session = session_manager.create_session("http-statement")?;
+        session.set_client_host(client_host);
         let context = session.create_context().await?;
         if let Some(db) = &request.session.database {
             context.set_current_database(db.clone()).await?;
         };
         context.attach_query_str(sql);
-        let default_user = "root".to_string();
-        let user_name = request.session.user.as_ref().unwrap_or(&default_user);
-        let user_manager = session.get_user_manager();
-        // TODO: list user's grant list and check client address
-        let user_info = user_manager.get_user(user_name, "%").await?;
-        session.set_current_user(user_info);
+        let auth_manager = session.get_auth_manager();
+        auth_manager.auth(&session, credential).await?;
+
+        if let Some(settings) = &request.session.settings {
+            for (name, value) in settings {
+                session
+                    .get_settings()
+                    .update_settings(name, value.clone())?;
+            }
+        }
+        let datetime_output = session.get_settings().get_datetime_output()?;
 
         let plan = PlanParser::parse(sql, context.clone()).await?;
         let schema = plan.schema();
@@ -219,6 +235,6 @@ impl ExecuteState {
                 tracing::debug!("drop block sender!");
             })?;
 
-        Ok((executor_clone, schema))
+        Ok((executor_clone, schema, datetime_output))
     }
 }
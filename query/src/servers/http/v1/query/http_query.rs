@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use common_base::tokio::sync::mpsc;
@@ -30,6 +31,7 @@ use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::ResultDataManager;
 use crate::servers::http::v1::query::Wait;
 use crate::sessions::SessionManager;
+use crate::users::auth::Credential;
 
 pub struct ResponseInitialState {
     pub schema: Option<DataSchemaRef>,
@@ -63,12 +65,20 @@ impl HttpQuery {
         id: String,
         request: HttpQueryRequest,
         session_manager: &Arc<SessionManager>,
+        credential: &Credential,
+        client_host: Option<SocketAddr>,
     ) -> Result<HttpQueryRef> {
         //TODO(youngsofun): support config/set channel size
         let (block_tx, block_rx) = mpsc::channel(10);
 
-        let (state, schema) = ExecuteState::try_create(&request, session_manager, block_tx).await?;
-        let data = Arc::new(TokioMutex::new(ResultDataManager::new(schema, block_rx)));
+        let (state, schema, datetime_output) =
+            ExecuteState::try_create(&request, session_manager, credential, client_host, block_tx)
+                .await?;
+        let mut data = ResultDataManager::new(schema, datetime_output, block_rx);
+        if request.column_stats {
+            data = data.with_column_stats();
+        }
+        let data = Arc::new(TokioMutex::new(data));
         let query = HttpQuery {
             id,
             request,
@@ -129,6 +139,7 @@ impl HttpQuery {
         let response = ResponseData {
             page,
             next_page_no: data.next_page_no(),
+            column_stats: data.column_stats()?,
         };
         Ok(response)
     }
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_stats;
 mod execute_state;
 mod http_query;
 mod http_query_manager;
 mod result_data_manager;
 
+pub use column_stats::ColumnStats;
+pub(crate) use column_stats::ResultStats;
 pub(crate) use execute_state::ExecuteState;
 pub use execute_state::ExecuteStateName;
 pub(crate) use execute_state::Executor;
@@ -20,11 +20,14 @@ use common_base::tokio::sync::mpsc;
 use common_base::tokio::sync::mpsc::error::TryRecvError;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DateTimeOutput;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
 
 use crate::servers::http::v1::block_to_json;
+use crate::servers::http::v1::query::ColumnStats;
+use crate::servers::http::v1::query::ResultStats;
 use crate::servers::http::v1::JsonBlock;
 use crate::servers::http::v1::JsonBlockRef;
 
@@ -46,29 +49,51 @@ pub struct Page {
 pub struct ResponseData {
     pub page: Page,
     pub next_page_no: Option<usize>,
+    pub column_stats: Option<Vec<ColumnStats>>,
 }
 
 pub struct ResultDataManager {
     pub(crate) schema: DataSchemaRef,
+    datetime_output: DateTimeOutput,
     total_rows: usize,
     total_pages: usize,
     last_page: Option<Page>,
     pub(crate) block_rx: mpsc::Receiver<DataBlock>,
     end: bool,
+    stats: Option<ResultStats>,
 }
 
 impl ResultDataManager {
-    pub fn new(schema: DataSchemaRef, block_rx: mpsc::Receiver<DataBlock>) -> ResultDataManager {
+    pub fn new(
+        schema: DataSchemaRef,
+        datetime_output: DateTimeOutput,
+        block_rx: mpsc::Receiver<DataBlock>,
+    ) -> ResultDataManager {
         ResultDataManager {
             schema,
+            datetime_output,
             block_rx,
             total_rows: 0,
             last_page: None,
             total_pages: 0,
             end: false,
+            stats: None,
         }
     }
 
+    pub fn with_column_stats(mut self) -> Self {
+        self.stats = Some(ResultStats::new(self.schema.clone()));
+        self
+    }
+
+    /// The statistics of the columns of the rows of the pages collected so far.
+    pub fn column_stats(&self) -> Result<Option<Vec<ColumnStats>>> {
+        self.stats
+            .as_ref()
+            .map(|stats| stats.to_json(&self.datetime_output))
+            .transpose()
+    }
+
     pub fn next_page_no(&mut self) -> Option<usize> {
         if self.end {
             None
@@ -136,7 +161,12 @@ impl ResultDataManager {
             match ResultDataManager::receive(block_rx, tp).await {
                 Ok(block) => {
                     rows += block.num_rows();
-                    results.push(block_to_json(&block).unwrap());
+                    if let Some(stats) = self.stats.as_mut() {
+                        if let Err(e) = stats.update(&block) {
+                            tracing::warn!("fail to update the column stats: {}", e);
+                        }
+                    }
+                    results.push(block_to_json(&block, &self.datetime_output).unwrap());
                     // TODO(youngsofun):  set it in post if needed
                     if rows >= TARGET_ROWS_PER_PAGE {
                         break;
@@ -21,9 +21,12 @@ use poem::web::Data;
 use poem::web::Json;
 use poem::web::Query;
 use poem::Endpoint;
+use poem::Request;
 use poem::Route;
 use serde::Deserialize;
 
+use crate::servers::http::get_client_host;
+use crate::servers::http::require_credential;
 use crate::servers::http::v1::query::HttpQuery;
 use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::servers::http::v1::query::HttpSessionConf;
@@ -34,11 +37,11 @@ use crate::sessions::SessionManager;
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
     db: Option<String>,
-    user: Option<String>,
 }
 
 #[poem::handler]
 pub async fn statement_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     sql: String,
     Query(params): Query<StatementHandlerParams>,
@@ -48,10 +51,23 @@ pub async fn statement_handler(
     let query_id = http_query_manager.next_query_id();
     let session = HttpSessionConf {
         database: params.db.filter(|x| !x.is_empty()),
-        user: params.user,
+        settings: None,
     };
-    let req = HttpQueryRequest { sql, session };
-    let query = HttpQuery::try_create(query_id.clone(), req, session_manager).await;
+    let req = HttpQueryRequest {
+        sql,
+        session,
+        column_stats: false,
+    };
+    let credential = require_credential(request)?;
+    let client_host = get_client_host(request);
+    let query = HttpQuery::try_create(
+        query_id.clone(),
+        req,
+        session_manager,
+        credential,
+        client_host,
+    )
+    .await;
 
     match query {
         Ok(query) => {
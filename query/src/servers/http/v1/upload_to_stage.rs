@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::Json;
+use poem::web::Multipart;
+use poem::Request;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::interpreters::stage_location_dal;
+use crate::servers::http::get_client_host;
+use crate::servers::http::require_credential;
+use crate::sessions::SessionManager;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UploadToStageResponse {
+    pub id: String,
+    pub stage_name: String,
+    pub state: String,
+    /// The paths of the files uploaded, relative to the stage.
+    pub files: Vec<String>,
+}
+
+/// Puts the files of the multipart body to the stage named by the `stage_name` header, under
+/// the optional `relative_path` header, by their file names.
+#[poem::handler]
+pub async fn upload_to_stage(
+    req: &Request,
+    mut multipart: Multipart,
+    sessions_extension: Data<&Arc<SessionManager>>,
+) -> PoemResult<Json<UploadToStageResponse>> {
+    let session_manager = sessions_extension.0;
+    let session = session_manager
+        .create_session("Upload to stage")
+        .map_err(InternalServerError)?;
+    session.set_client_host(get_client_host(req));
+    // Auth.
+    let credential = require_credential(req)?;
+    session
+        .get_auth_manager()
+        .auth(&session, credential)
+        .await
+        .map_err(|e| poem::Error::from_string(e.message(), StatusCode::UNAUTHORIZED))?;
+
+    let context = session
+        .create_context()
+        .await
+        .map_err(InternalServerError)?;
+
+    let stage_name = req
+        .headers()
+        .get("stage_name")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim_start_matches('@'))
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| {
+            poem::Error::from_string("Missing header stage_name", StatusCode::BAD_REQUEST)
+        })?
+        .to_string();
+    let relative_path = req
+        .headers()
+        .get("relative_path")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim_matches('/'))
+        .filter(|v| !v.is_empty())
+        .map(|v| format!("{}/", v))
+        .unwrap_or_default();
+    // Only the stages created, the locations of an unknown stage fall back to the storage.
+    session
+        .get_user_manager()
+        .get_stage(&stage_name)
+        .await
+        .map_err(|e| poem::Error::from_string(e.message(), StatusCode::BAD_REQUEST))?;
+
+    let mut files = vec![];
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let file_name = match field.file_name() {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => {
+                return Err(poem::Error::from_string(
+                    "Missing the file name of a part",
+                    StatusCode::BAD_REQUEST,
+                ))
+            }
+        };
+        let file = format!("{}{}", relative_path, file_name);
+        let location = format!("@{}/{}", stage_name, file);
+        let (accessor, path) = stage_location_dal(context.clone(), &location)
+            .await
+            .map_err(InternalServerError)?;
+        let bytes = field.bytes().await.map_err(InternalServerError)?;
+        accessor
+            .put(&path, bytes)
+            .await
+            .map_err(InternalServerError)?;
+        files.push(file);
+    }
+
+    Ok(Json(UploadToStageResponse {
+        id: context.get_id(),
+        stage_name,
+        state: "SUCCESS".to_string(),
+        files,
+    }))
+}
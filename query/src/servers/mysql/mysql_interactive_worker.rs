@@ -21,6 +21,8 @@ use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::*;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 use metrics::histogram;
@@ -31,14 +33,18 @@ use msql_srv::ParamParser;
 use msql_srv::QueryResultWriter;
 use msql_srv::StatementMetaWriter;
 use rand::RngCore;
+use sha2::Digest;
+use sha2::Sha256;
 use tokio_stream::StreamExt;
 
 use crate::interpreters::InterpreterFactory;
 use crate::servers::mysql::writers::DFInitResultWriter;
+use crate::servers::mysql::writers::DFQueryResult;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::users::verify_caching_sha2_scramble;
 use crate::users::CertifiedInfo;
 
 struct InteractiveWorkerBase<W: std::io::Write> {
@@ -69,8 +75,19 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
         "mysql_native_password"
     }
 
-    fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+    fn auth_plugin_for_username(&self, user: &[u8]) -> &str {
+        let username = String::from_utf8_lossy(user);
+        let user_manager = self.session.get_user_manager();
+        let password_type = user_manager.get_cached_password_type(&username, &self.client_addr);
+
+        // The callback is sync, the user is looked up in the cache only. The users not cached
+        // are asked for caching_sha2_password, the plugin of the default sha256 passwords. The
+        // salted hashes would need the plain password, which is never asked for: the handler
+        // has no TLS, the password would cross the connection in clear.
+        match password_type {
+            Some(PasswordType::DoubleSha1) => "mysql_native_password",
+            _ => "caching_sha2_password",
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -90,7 +107,16 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
         let authenticate = self.base.authenticate(auth_plugin, salt, info);
         futures::executor::block_on(async move {
             match authenticate.await {
-                Ok(res) => res,
+                Ok(true) => {
+                    self.session.audit_login(&username, &self.client_addr, None);
+                    true
+                }
+                Ok(false) => {
+                    let failure = ErrorCode::AuthenticateFailure("wrong password");
+                    self.session
+                        .audit_login(&username, &self.client_addr, Some(&failure));
+                    false
+                }
                 Err(failure) => {
                     tracing::error!(
                         "MySQL handler authenticate failed, \
@@ -101,6 +127,8 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
                         self.client_addr,
                         failure
                     );
+                    self.session
+                        .audit_login(&username, &self.client_addr, Some(&failure));
                     false
                 }
             }
@@ -210,20 +238,35 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         let address = &info.user_client_address;
 
         let user_manager = self.session.get_user_manager();
-        // TODO: list user's grant list and check client address
-        let user_info = user_manager.get_user(user_name, "%").await?;
+        let user_info = user_manager
+            .get_user_with_client_address(user_name, address)
+            .await?;
+        if user_info.password_type == PasswordType::Pbkdf2Sha256 {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "The password of user '{}'@'{}' is salted, it cannot be verified over the MySQL \
+                protocol without TLS, login by the HTTP or ClickHouse handler instead",
+                user_info.name, user_info.hostname
+            )));
+        }
 
         let input = &info.user_password;
         let saved = &user_info.password;
-        let encode_password = Self::encoding_password(auth_plugin, salt, input, saved)?;
-
-        let authed = user_manager
-            .auth_user(
-                user_info.clone(),
-                CertifiedInfo::create(user_name, encode_password, address),
-            )
-            .await?;
+        let authed = match auth_plugin {
+            "caching_sha2_password" => Self::verify_caching_sha2(&user_info, salt, input),
+            _ => {
+                let encode_password = Self::encoding_password(auth_plugin, salt, input, saved)?;
+                user_manager
+                    .auth_user(
+                        user_info.clone(),
+                        CertifiedInfo::create(user_name, encode_password, address),
+                    )
+                    .await?
+            }
+        };
         if authed {
+            user_manager
+                .verify_network_policy(&user_info, address)
+                .await?;
             self.session.set_current_user(user_info);
         }
 
@@ -254,10 +297,26 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
                 }
                 Ok(s)
             }
+            // The plain password would cross the connection in clear, the handler has no TLS.
+            "mysql_clear_password" => Err(ErrorCode::AuthenticateFailure(
+                "mysql_clear_password is refused without TLS",
+            )),
             _ => Ok(input.to_vec()),
         }
     }
 
+    fn verify_caching_sha2(user_info: &UserInfo, salt: &[u8], input: &[u8]) -> bool {
+        match user_info.password_type {
+            PasswordType::None => true,
+            PasswordType::Sha256 => verify_caching_sha2_scramble(&user_info.password, salt, input),
+            PasswordType::PlainText => {
+                let digest = Sha256::digest(&user_info.password);
+                verify_caching_sha2_scramble(&digest, salt, input)
+            }
+            _ => false,
+        }
+    }
+
     fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
         writer.error(
             ErrorKind::ER_UNKNOWN_ERROR,
@@ -282,7 +341,7 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     fn do_close(&mut self, _: u32) {}
 
     #[tracing::instrument(level = "debug", skip(self))]
-    async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
+    async fn do_query(&mut self, query: &str) -> Result<DFQueryResult> {
         tracing::debug!("{}", query);
 
         let context = self.session.create_context().await?;
@@ -303,7 +362,10 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
                 ))),
                 Err(error_code) => {
                     if hint_error_code == error_code.code() {
-                        Ok((vec![DataBlock::empty()], String::from("")))
+                        Ok(DFQueryResult::create(
+                            vec![DataBlock::empty()],
+                            String::from(""),
+                        ))
                     } else {
                         let actual_code = error_code.code();
                         Err(error_code.add_message(format!(
@@ -320,10 +382,11 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     async fn exec_query(
         plan: Result<PlanNode>,
         context: &Arc<QueryContext>,
-    ) -> Result<(Vec<DataBlock>, String)> {
+    ) -> Result<DFQueryResult> {
         let instant = Instant::now();
 
-        let interpreter = InterpreterFactory::get(context.clone(), plan?)?;
+        let plan = plan?;
+        let interpreter = InterpreterFactory::get(context.clone(), plan.clone())?;
         // Write start query log.
         let _ = interpreter
             .start()
@@ -342,7 +405,12 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
             .finish()
             .await
             .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
-        query_result.map(|data| (data, Self::extra_info(context, instant)))
+        let datetime_output = context.get_settings().get_datetime_output()?;
+        query_result.map(|data| {
+            DFQueryResult::create(data, Self::extra_info(context, instant))
+                .with_plan(&plan)
+                .with_datetime_output(datetime_output)
+        })
     }
 
     fn extra_info(context: &Arc<QueryContext>, instant: Instant) -> String {
@@ -12,20 +12,104 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use chrono_tz::Tz;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::LargeBinaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
 use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
-use common_datavalues::DataValue;
 use common_datavalues::DateConverter;
+use common_datavalues::DateTimeOutput;
+use common_datavalues::TypeSerializer;
 use common_exception::exception::ABORT_QUERY;
 use common_exception::exception::ABORT_SESSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_planners::ReadDataSourcePlan;
 use common_tracing::tracing;
 use msql_srv::*;
 
+/// The blocks of a query result, with what is needed for the metadata of the result set.
+pub struct DFQueryResult {
+    pub blocks: Vec<DataBlock>,
+    pub extra_info: String,
+    /// The tables of the result columns read from a table as they are, by the names of the
+    /// columns in the result.
+    pub column_tables: HashMap<String, String>,
+    /// How the DateTime values are rendered, told by the settings of the session.
+    pub datetime_output: DateTimeOutput,
+}
+
+impl DFQueryResult {
+    pub fn create(blocks: Vec<DataBlock>, extra_info: String) -> DFQueryResult {
+        DFQueryResult {
+            blocks,
+            extra_info,
+            column_tables: HashMap::new(),
+            datetime_output: DateTimeOutput::default(),
+        }
+    }
+
+    pub fn with_datetime_output(mut self, datetime_output: DateTimeOutput) -> DFQueryResult {
+        self.datetime_output = datetime_output;
+        self
+    }
+
+    /// Finds the result columns projected as they are from the only table read by the query,
+    /// like MySQL, which reports no table for the expressions.
+    pub fn with_plan(mut self, plan: &PlanNode) -> DFQueryResult {
+        fn collect(
+            plan: &PlanNode,
+            exprs: &mut Option<Vec<Expression>>,
+            sources: &mut Vec<ReadDataSourcePlan>,
+        ) {
+            match plan {
+                PlanNode::Projection(v) if exprs.is_none() => *exprs = Some(v.expr.clone()),
+                PlanNode::ReadSource(v) => sources.push(v.clone()),
+                _ => {}
+            }
+            for input in plan.inputs() {
+                collect(&input, exprs, sources);
+            }
+        }
+
+        let mut exprs = None;
+        let mut sources = vec![];
+        collect(plan, &mut exprs, &mut sources);
+
+        // the table functions, e.g. numbers(10), are not tables of the clients
+        let (source, exprs) = match (sources.as_slice(), exprs) {
+            ([source], Some(exprs)) if source.tbl_args.is_none() => (source, exprs),
+            _ => return self,
+        };
+        let schema = source.table_info.schema();
+        for expr in exprs {
+            let (name, column) = match &expr {
+                Expression::Column(column) => (column, column),
+                Expression::Alias(alias, inner) => match inner.as_ref() {
+                    Expression::Column(column) => (alias, column),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if schema.has_field(column) {
+                self.column_tables
+                    .insert(name.clone(), source.table_info.name.clone());
+            }
+        }
+        self
+    }
+}
+
 pub struct DFQueryResultWriter<'a, W: std::io::Write> {
     inner: Option<QueryResultWriter<'a, W>>,
 }
@@ -35,21 +119,24 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
         DFQueryResultWriter::<'a, W> { inner: Some(inner) }
     }
 
-    pub fn write(&mut self, query_result: Result<(Vec<DataBlock>, String)>) -> Result<()> {
+    pub fn write(&mut self, query_result: Result<DFQueryResult>) -> Result<()> {
         if let Some(writer) = self.inner.take() {
             match query_result {
-                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer)?,
+                Ok(query_result) => Self::ok(query_result, writer)?,
                 Err(error) => Self::err(&error, writer)?,
             }
         }
         Ok(())
     }
 
-    fn ok(
-        blocks: Vec<DataBlock>,
-        extra_info: String,
-        dataset_writer: QueryResultWriter<'a, W>,
-    ) -> Result<()> {
+    fn ok(query_result: DFQueryResult, dataset_writer: QueryResultWriter<'a, W>) -> Result<()> {
+        let DFQueryResult {
+            blocks,
+            extra_info,
+            column_tables,
+            datetime_output,
+        } = query_result;
+
         // XXX: num_columns == 0 may is error?
         let default_response = OkResponse {
             info: extra_info,
@@ -61,140 +148,115 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
             return Ok(());
         }
 
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
+        let epoch = datetime_output == DateTimeOutput::Epoch;
+        let convert_field_type = |field: &DataField| -> Result<ColumnType> {
             match field.data_type() {
-                DataType::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
+                DataType::Int8 | DataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+                DataType::Int16 | DataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+                DataType::Int32 | DataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+                DataType::Int64 | DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
                 DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+                DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+                // the string columns of the result sets are VAR_STRING, as in MySQL
+                DataType::String => Ok(ColumnType::MYSQL_TYPE_VAR_STRING),
+                // BOOLEAN is TINYINT(1) in MySQL
+                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_TINY),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
+                // the seconds since epoch, as UNIX_TIMESTAMP() of MySQL
+                DataType::DateTime32(_) if epoch => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+                DataType::DateTime64(_, _) if epoch => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
                 DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
                 DataType::DateTime64(_, _) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VAR_STRING),
                 _ => Err(ErrorCode::UnImplement(format!(
                     "Unsupported column type:{:?}",
                     field.data_type()
                 ))),
             }
-        }
+        };
+
+        // the flags MySQL sets on the columns of the types, which the drivers map types by
+        let convert_field_flags = |field: &DataField| -> ColumnFlags {
+            let data_type = field.data_type();
+            let mut flags = ColumnFlags::empty();
+            if !field.is_nullable() {
+                flags |= ColumnFlags::NOT_NULL_FLAG;
+            }
+            if data_type.is_numeric() || data_type == &DataType::Boolean {
+                flags |= ColumnFlags::NUM_FLAG | ColumnFlags::BINARY_FLAG;
+            }
+            if data_type.is_unsigned_integer() {
+                flags |= ColumnFlags::UNSIGNED_FLAG;
+            }
+            if data_type.is_date_or_date_time() {
+                flags |= ColumnFlags::BINARY_FLAG;
+            }
+            if epoch
+                && matches!(
+                    data_type,
+                    DataType::DateTime32(_) | DataType::DateTime64(_, _)
+                )
+            {
+                flags |= ColumnFlags::NUM_FLAG | ColumnFlags::UNSIGNED_FLAG;
+            }
+            flags
+        };
 
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
+        let make_column_from_field = |field: &DataField| -> Result<Column> {
             convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
+                table: column_tables.get(field.name()).cloned().unwrap_or_default(),
                 column: field.name().to_string(),
                 coltype: column_type,
-                colflags: ColumnFlags::empty(),
+                colflags: convert_field_flags(field),
             })
-        }
+        };
 
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
+        let convert_schema = |schema: &DataSchemaRef| -> Result<Vec<Column>> {
             schema.fields().iter().map(make_column_from_field).collect()
-        }
+        };
 
         let block = blocks[0].clone();
-        let utc: Tz = "UTC".parse().unwrap();
         match convert_schema(block.schema()) {
             Err(error) => Self::err(&error, dataset_writer),
             Ok(columns) => {
-                let columns_size = block.num_columns();
                 let mut row_writer = dataset_writer.start(&columns)?;
 
                 for block in &blocks {
-                    let rows_size = block.column(0).len();
-                    for row_index in 0..rows_size {
-                        for col_index in 0..columns_size {
-                            let val = block.column(col_index).try_get(row_index)?;
-                            if val.is_null() {
+                    // Values are read from the arrow arrays directly, without a DataValue per cell.
+                    // The arrays are downcast once per block, not per cell.
+                    let series = block
+                        .columns()
+                        .iter()
+                        .map(|column| column.to_array())
+                        .collect::<Result<Vec<_>>>()?;
+                    let arrow_arrays = series
+                        .iter()
+                        .map(|array| array.get_array_ref())
+                        .collect::<Vec<_>>();
+                    let column_values = block
+                        .schema()
+                        .fields()
+                        .iter()
+                        .zip(series.iter().zip(arrow_arrays.iter()))
+                        .map(|(field, (series, array))| {
+                            ColumnValues::try_create(
+                                field.data_type(),
+                                series,
+                                array,
+                                &datetime_output,
+                            )
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    for row_index in 0..block.num_rows() {
+                        for (array, values) in arrow_arrays.iter().zip(column_values.iter()) {
+                            if array.is_null(row_index) {
                                 row_writer.write_col(None::<u8>)?;
                                 continue;
                             }
-                            let data_type = block.schema().fields()[col_index].data_type();
-                            match (data_type, val.clone()) {
-                                (DataType::Boolean, DataValue::Boolean(Some(v))) => {
-                                    row_writer.write_col(v as i8)?
-                                }
-                                (DataType::Int8, DataValue::Int8(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Int16, DataValue::Int16(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Int32, DataValue::Int32(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Int64, DataValue::Int64(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt8, DataValue::UInt8(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt16, DataValue::UInt16(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt32, DataValue::UInt32(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt64, DataValue::UInt64(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Float32, DataValue::Float32(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Float64, DataValue::Float64(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Date16, DataValue::UInt16(Some(v))) => {
-                                    row_writer.write_col(v.to_date(&utc).naive_local())?
-                                }
-                                (DataType::Date32, DataValue::Int32(Some(v))) => {
-                                    row_writer.write_col(v.to_date(&utc).naive_local())?
-                                }
-                                (DataType::DateTime32(tz), DataValue::UInt32(Some(v))) => {
-                                    let tz = tz.clone();
-                                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                                    let tz: Tz = tz.parse().unwrap();
-                                    row_writer.write_col(v.to_date_time(&tz).naive_local())?
-                                }
-                                (
-                                    DataType::DateTime64(precision, tz),
-                                    DataValue::UInt64(Some(v)),
-                                ) => {
-                                    let tz = tz.clone();
-                                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                                    let tz: Tz = tz.parse().unwrap();
-                                    let fmt = format!("%Y-%m-%d %H:%M:%S%.{}f", precision);
-
-                                    row_writer.write_col(
-                                        v.to_date_time64(precision, &tz)
-                                            .naive_local()
-                                            .format(fmt.as_str())
-                                            .to_string(),
-                                    )?
-                                }
-                                (DataType::String, DataValue::String(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Struct(_), DataValue::Struct(_)) => {
-                                    let serializer = data_type.create_serializer();
-                                    row_writer.write_col(serializer.serialize_value(&val)?)?
-                                }
-                                (_, v) => {
-                                    return Err(ErrorCode::BadDataValueType(format!(
-                                        "Unsupported column type:{:?}, expected type in schema: {:?}",
-                                        v.data_type(), data_type
-                                    )));
-                                }
-                            }
+                            values.write_col(&mut row_writer, row_index)?;
                         }
                         row_writer.end_row()?;
                     }
@@ -220,3 +282,151 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
         Ok(())
     }
 }
+
+fn downcast<T: 'static>(array: &ArrayRef) -> Result<&T> {
+    array.as_any().downcast_ref::<T>().ok_or_else(|| {
+        ErrorCode::BadDataValueType(format!(
+            "Unexpected arrow array type:{:?}",
+            array.data_type()
+        ))
+    })
+}
+
+// The seconds of a DateTime64 value since epoch, with the fraction of its precision.
+fn epoch_with_fraction(v: u64, precision: u32) -> String {
+    if precision == 0 {
+        return v.to_string();
+    }
+    let base = 10u64.pow(precision);
+    format!(
+        "{}.{:0width$}",
+        v / base,
+        v % base,
+        width = precision as usize
+    )
+}
+
+/// The values of a result column in a block, its arrow array downcast to the array of its type.
+enum ColumnValues<'b> {
+    Null,
+    Boolean(&'b BooleanArray),
+    Int8(&'b PrimitiveArray<i8>),
+    Int16(&'b PrimitiveArray<i16>),
+    Int32(&'b PrimitiveArray<i32>),
+    Int64(&'b PrimitiveArray<i64>),
+    UInt8(&'b PrimitiveArray<u8>),
+    UInt16(&'b PrimitiveArray<u16>),
+    UInt32(&'b PrimitiveArray<u32>),
+    UInt64(&'b PrimitiveArray<u64>),
+    Float32(&'b PrimitiveArray<f32>),
+    Float64(&'b PrimitiveArray<f64>),
+    Date16(&'b PrimitiveArray<u16>),
+    Date32(&'b PrimitiveArray<i32>),
+    // The time zone the values are rendered in, none for the seconds since epoch.
+    DateTime32(&'b PrimitiveArray<u32>, Option<Tz>),
+    DateTime64(&'b PrimitiveArray<u64>, u32, Option<Tz>),
+    String(&'b LargeBinaryArray),
+    // The struct values are still serialized from their DataValue.
+    Struct(&'b Series, Box<dyn TypeSerializer>),
+}
+
+impl<'b> ColumnValues<'b> {
+    fn try_create(
+        data_type: &DataType,
+        series: &'b Series,
+        array: &'b ArrayRef,
+        datetime_output: &DateTimeOutput,
+    ) -> Result<ColumnValues<'b>> {
+        Ok(match data_type {
+            DataType::Null => ColumnValues::Null,
+            DataType::Boolean => ColumnValues::Boolean(downcast(array)?),
+            DataType::Int8 => ColumnValues::Int8(downcast(array)?),
+            DataType::Int16 => ColumnValues::Int16(downcast(array)?),
+            DataType::Int32 => ColumnValues::Int32(downcast(array)?),
+            DataType::Int64 => ColumnValues::Int64(downcast(array)?),
+            DataType::UInt8 => ColumnValues::UInt8(downcast(array)?),
+            DataType::UInt16 => ColumnValues::UInt16(downcast(array)?),
+            DataType::UInt32 => ColumnValues::UInt32(downcast(array)?),
+            DataType::UInt64 => ColumnValues::UInt64(downcast(array)?),
+            DataType::Float32 => ColumnValues::Float32(downcast(array)?),
+            DataType::Float64 => ColumnValues::Float64(downcast(array)?),
+            DataType::Date16 => ColumnValues::Date16(downcast(array)?),
+            DataType::Date32 => ColumnValues::Date32(downcast(array)?),
+            DataType::DateTime32(tz) => {
+                ColumnValues::DateTime32(downcast(array)?, datetime_output.time_zone(tz))
+            }
+            DataType::DateTime64(precision, tz) => ColumnValues::DateTime64(
+                downcast(array)?,
+                *precision,
+                datetime_output.time_zone(tz),
+            ),
+            DataType::String => ColumnValues::String(downcast(array)?),
+            DataType::Struct(_) => {
+                ColumnValues::Struct(series, data_type.create_serializer_with(datetime_output))
+            }
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unsupported column type:{:?}",
+                    data_type
+                )));
+            }
+        })
+    }
+
+    // Write the value of the row, which is not null.
+    fn write_col<W: std::io::Write>(
+        &self,
+        row_writer: &mut RowWriter<W>,
+        row_index: usize,
+    ) -> Result<()> {
+        let utc = Tz::UTC;
+        match self {
+            ColumnValues::Null => row_writer.write_col(None::<u8>)?,
+            ColumnValues::Boolean(array) => row_writer.write_col(array.value(row_index) as i8)?,
+            ColumnValues::Int8(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Int16(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Int32(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Int64(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::UInt8(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::UInt16(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::UInt32(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::UInt64(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Float32(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Float64(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Date16(array) => {
+                row_writer.write_col(array.value(row_index).to_date(&utc).naive_local())?
+            }
+            ColumnValues::Date32(array) => {
+                row_writer.write_col(array.value(row_index).to_date(&utc).naive_local())?
+            }
+            ColumnValues::DateTime32(array, tz) => {
+                let v = array.value(row_index);
+                match tz {
+                    Some(tz) => row_writer.write_col(v.to_date_time(tz).naive_local())?,
+                    None => row_writer.write_col(v as u64)?,
+                }
+            }
+            ColumnValues::DateTime64(array, precision, tz) => {
+                let v = array.value(row_index);
+                match tz {
+                    Some(tz) => {
+                        let fmt = format!("%Y-%m-%d %H:%M:%S%.{}f", precision);
+                        row_writer.write_col(
+                            v.to_date_time64(precision, tz)
+                                .naive_local()
+                                .format(fmt.as_str())
+                                .to_string(),
+                        )?
+                    }
+                    None => row_writer.write_col(epoch_with_fraction(v, *precision))?,
+                }
+            }
+            ColumnValues::String(array) => row_writer.write_col(array.value(row_index))?,
+            ColumnValues::Struct(series, serializer) => {
+                let val = series.try_get(row_index)?;
+                row_writer.write_col(serializer.serialize_value(&val)?)?
+            }
+        }
+        Ok(())
+    }
+}
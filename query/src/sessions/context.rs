@@ -21,6 +21,7 @@ use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
 
 use common_base::tokio::task::JoinHandle;
+use common_base::MemoryTracker;
 use common_base::Progress;
 use common_base::ProgressValues;
 use common_base::Runtime;
@@ -35,7 +36,9 @@ use common_dal::StorageScheme;
 use common_dal::S3;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::udfs::UDFDefinition;
 use common_infallible::RwLock;
+use common_meta_types::UpsertTableOptionReq;
 use common_meta_types::UserInfo;
 use common_planners::Part;
 use common_planners::Partitions;
@@ -44,8 +47,12 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_streams::AbortStream;
 use common_streams::SendableDataBlockStream;
+use common_streams::SpillDir;
+use common_tracing::redact;
 use common_tracing::tracing;
 
+use crate::audit::AuditEvent;
+use crate::audit::AuditEventType;
 use crate::catalogs::Catalog;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
@@ -53,9 +60,15 @@ use crate::configs::AzureStorageBlobConfig;
 use crate::configs::Config;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueryProfile;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::Settings;
+use crate::sessions::Transaction;
+use crate::storages::fuse::cache::BlockDataCache;
+use crate::storages::fuse::cache::ParquetMetaCache;
+use crate::storages::fuse::cache::SegmentInfoCache;
+use crate::storages::fuse::io::UploadDataAccessor;
 use crate::storages::Table;
 
 pub struct QueryContext {
@@ -185,6 +198,31 @@ impl QueryContext {
         self.shared.get_table(database, table).await
     }
 
+    /// Fetch the tables read by a query at one version of the catalog, the later calls of
+    /// `get_table` in the same query return them, see `QueryContextShared::pin_tables`.
+    pub async fn pin_tables(&self, tables: &[(String, String)]) -> Result<()> {
+        self.shared.pin_tables(tables).await
+    }
+
+    pub fn begin_transaction(&self) -> Result<()> {
+        self.shared.session.begin_transaction()
+    }
+
+    /// Ends the transaction of the session, see `Session::take_transaction`.
+    pub fn take_transaction(&self) -> Option<Transaction> {
+        self.shared.session.take_transaction()
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.shared.in_transaction()
+    }
+
+    /// Stages the change of a table if the session is in a transaction, otherwise the change
+    /// is given back to be committed by the caller.
+    pub fn stage_table_change(&self, req: UpsertTableOptionReq) -> Option<UpsertTableOptionReq> {
+        self.shared.stage_table_change(req)
+    }
+
     pub fn get_id(&self) -> String {
         self.shared.init_query_id.as_ref().read().clone()
     }
@@ -203,6 +241,31 @@ impl QueryContext {
         self.shared.get_current_user()
     }
 
+    pub fn get_current_role(&self) -> Option<String> {
+        self.shared.get_current_role()
+    }
+
+    pub fn set_current_role(&self, role: String) -> Result<()> {
+        self.shared.set_current_role(role)
+    }
+
+    pub fn set_secondary_roles_all(&self, all: bool) {
+        self.shared.set_secondary_roles_all(all)
+    }
+
+    pub fn get_active_roles(&self) -> Vec<String> {
+        self.shared.get_active_roles()
+    }
+
+    // Get the current user with the privileges of the active roles of the session, which is
+    // the user the privilege checks are made against.
+    pub async fn get_current_user_with_roles(&self) -> Result<UserInfo> {
+        let user = self.get_current_user()?;
+        let roles = self.get_active_roles();
+        let user_mgr = self.get_sessions_manager().get_user_manager();
+        user_mgr.get_user_with_roles(user, &roles).await
+    }
+
     pub async fn set_current_database(&self, new_database_name: String) -> Result<()> {
         let catalog = self.get_catalog();
         match catalog.get_database(&new_database_name).await {
@@ -280,11 +343,70 @@ impl QueryContext {
         )))
     }
 
+    /// Get the data accessor the fuse tables upload their blocks and segments by, which
+    /// retries the failed uploads and limits the uploads in flight of this node.
+    pub fn get_upload_data_accessor(&self) -> Result<Arc<dyn DataAccessor>> {
+        Ok(Arc::new(UploadDataAccessor::new(
+            self.shared.get_upload_policy(),
+            self.get_data_accessor()?,
+        )))
+    }
+
     /// Get the data accessor metrics.
     pub fn get_dal_metrics(&self) -> DalMetrics {
         self.shared.dal_ctx.get_metrics()
     }
 
+    /// Get the profile of the query, e.g. the scan metrics of the parts.
+    pub fn get_query_profile(&self) -> Arc<QueryProfile> {
+        self.shared.query_profile.clone()
+    }
+
+    /// Get the tracker of the memory allocated by the query, limited by `max_memory_usage`.
+    pub fn get_memory_tracker(&self) -> Arc<MemoryTracker> {
+        self.shared.memory_tracker.clone()
+    }
+
+    /// Get the directory the query spills to, created on the first spill and removed with the
+    /// query.
+    pub fn get_spill_dir(&self) -> Result<Arc<SpillDir>> {
+        let mut spill_dir = self.shared.spill_dir.write();
+        if let Some(spill_dir) = spill_dir.as_ref() {
+            return Ok(spill_dir.clone());
+        }
+
+        let created = SpillDir::create(
+            &self.get_config().query.spill_dir,
+            &self.get_id(),
+            self.get_settings().get_spill_quota_bytes()?,
+        );
+        *spill_dir = Some(created.clone());
+        Ok(created)
+    }
+
+    /// Define a function in the scope of the query, by `WITH FUNCTION`.
+    pub fn add_temp_function(&self, name: &str, definition: UDFDefinition) {
+        let mut temp_functions = self.shared.temp_functions.write();
+        temp_functions.insert(name.to_lowercase(), definition);
+    }
+
+    pub fn get_temp_function(&self, name: &str) -> Option<UDFDefinition> {
+        let temp_functions = self.shared.temp_functions.read();
+        temp_functions.get(&name.to_lowercase()).cloned()
+    }
+
+    /// Record the offset a stream is advanced to, once the changes it returned to the query
+    /// are consumed by an INSERT.
+    pub fn set_stream_offset(&self, stream_id: u64, req: UpsertTableOptionReq) {
+        let mut stream_offsets = self.shared.stream_offsets.write();
+        stream_offsets.insert(stream_id, req);
+    }
+
+    pub fn take_stream_offsets(&self) -> Vec<UpsertTableOptionReq> {
+        let mut stream_offsets = self.shared.stream_offsets.write();
+        stream_offsets.drain().map(|(_, req)| req).collect()
+    }
+
     /// Get the session running query.
     pub fn get_query_str(&self) -> String {
         self.shared.get_query_str()
@@ -295,10 +417,53 @@ impl QueryContext {
         self.shared.session.mutable_state.get_client_host()
     }
 
+    /// Record an audit event of the running query, the operation is the plan name.
+    pub fn audit(
+        self: &Arc<Self>,
+        event_type: AuditEventType,
+        operation: &str,
+        failure: Option<&ErrorCode>,
+    ) {
+        let session = self.get_session();
+        let mut event = AuditEvent::create(event_type, self.get_config().query.tenant_id);
+        event.handler_type = session.get_type();
+        event.session_id = session.get_id();
+        event.query_id = self.get_id();
+        event.user = self
+            .get_current_user()
+            .map(|user| user.name)
+            .unwrap_or_default();
+        event.client_address = self
+            .get_client_address()
+            .map(|addr| addr.to_string())
+            .unwrap_or_default();
+        event.operation = operation.to_string();
+        event.query_text = redact(&self.get_query_str());
+        if let Some(failure) = failure {
+            event = event.with_error(failure);
+        }
+        self.get_sessions_manager().get_audit_log().append(event);
+    }
+
     // Get table cache
     pub fn get_table_cache(&self) -> Arc<Option<Box<dyn StorageCache>>> {
         self.shared.get_table_cache()
     }
+
+    // Get the cache of the parquet footers of the fuse table blocks
+    pub fn get_parquet_meta_cache(&self) -> Arc<Option<ParquetMetaCache>> {
+        self.shared.get_parquet_meta_cache()
+    }
+
+    // Get the cache of the deserialized segments of the fuse tables
+    pub fn get_segment_info_cache(&self) -> Arc<Option<SegmentInfoCache>> {
+        self.shared.get_segment_info_cache()
+    }
+
+    // Get the local disk cache of the fuse table blocks
+    pub fn get_block_data_cache(&self) -> Option<Arc<BlockDataCache>> {
+        self.shared.get_block_data_cache()
+    }
 }
 
 impl TrySpawn for QueryContext {
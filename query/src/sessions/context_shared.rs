@@ -17,16 +17,20 @@ use std::collections::HashMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
+use common_base::MemoryTracker;
 use common_base::Progress;
 use common_base::Runtime;
 use common_cache::storage::StorageCache;
 use common_dal::DalContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::udfs::UDFDefinition;
 use common_infallible::Mutex;
 use common_infallible::RwLock;
+use common_meta_types::UpsertTableOptionReq;
 use common_meta_types::UserInfo;
 use common_planners::PlanNode;
+use common_streams::SpillDir;
 use futures::future::AbortHandle;
 use uuid::Uuid;
 
@@ -35,12 +39,21 @@ use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
 use crate::configs::Config;
 use crate::servers::http::v1::HttpQueryHandle;
+use crate::sessions::QueryProfile;
 use crate::sessions::Session;
 use crate::sessions::Settings;
+use crate::storages::fuse::cache::BlockDataCache;
+use crate::storages::fuse::cache::ParquetMetaCache;
+use crate::storages::fuse::cache::SegmentInfoCache;
+use crate::storages::fuse::io::UploadPolicy;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String);
 
+/// How many times the tables of a query are read again, if one of them is changed
+/// while they are read.
+const PIN_TABLES_MAX_RETRIES: usize = 5;
+
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
 ///     USE database_1;
@@ -66,6 +79,12 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
+    pub(in crate::sessions) query_profile: Arc<QueryProfile>,
+    pub(in crate::sessions) spill_dir: Arc<RwLock<Option<Arc<SpillDir>>>>,
+    pub(in crate::sessions) memory_tracker: Arc<MemoryTracker>,
+    pub(in crate::sessions) temp_functions: Arc<RwLock<HashMap<String, UDFDefinition>>>,
+    // The offsets of the streams read by the query, keyed by the ids of the streams.
+    pub(in crate::sessions) stream_offsets: Arc<RwLock<HashMap<u64, UpsertTableOptionReq>>>,
 }
 
 impl QueryContextShared {
@@ -74,6 +93,10 @@ impl QueryContextShared {
         session: Arc<Session>,
         cluster_cache: Arc<Cluster>,
     ) -> Result<Arc<QueryContextShared>> {
+        // The memory of the query is also accounted to the runtime creating it, e.g. of a handler.
+        let memory_tracker = MemoryTracker::create(MemoryTracker::current());
+        memory_tracker.set_limit(session.get_settings().get_max_memory_usage()? as i64);
+
         Ok(Arc::new(QueryContextShared {
             conf,
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
@@ -90,6 +113,11 @@ impl QueryContextShared {
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
+            query_profile: Arc::new(Default::default()),
+            spill_dir: Arc::new(RwLock::new(None)),
+            memory_tracker,
+            temp_functions: Arc::new(RwLock::new(HashMap::new())),
+            stream_offsets: Arc::new(RwLock::new(HashMap::new())),
         }))
     }
 
@@ -124,6 +152,22 @@ impl QueryContextShared {
         self.session.get_current_user()
     }
 
+    pub fn get_current_role(&self) -> Option<String> {
+        self.session.get_current_role()
+    }
+
+    pub fn set_current_role(&self, role: String) -> Result<()> {
+        self.session.set_current_role(role)
+    }
+
+    pub fn set_secondary_roles_all(&self, all: bool) {
+        self.session.set_secondary_roles_all(all)
+    }
+
+    pub fn get_active_roles(&self) -> Vec<String> {
+        self.session.get_active_roles()
+    }
+
     pub fn get_settings(&self) -> Arc<Settings> {
         self.session.get_settings()
     }
@@ -151,7 +195,8 @@ impl QueryContextShared {
 
     async fn get_table_to_cache(&self, database: &str, table: &str) -> Result<Arc<dyn Table>> {
         let catalog = self.get_catalog();
-        let cache_table = catalog.get_table(database, table).await?;
+        let cache_table =
+            self.with_staged_changes(&catalog, catalog.get_table(database, table).await?)?;
 
         let table_meta_key = (database.to_string(), table.to_string());
         let mut tables_refs = self.tables_refs.lock();
@@ -162,6 +207,97 @@ impl QueryContextShared {
         }
     }
 
+    /// Resolve the tables at one version of the catalog, so that a query reading several tables
+    /// never sees a table before a concurrent change and another one after it.
+    ///
+    /// The tables are read twice, the versions unchanged between the two reads tell a moment
+    /// all the tables were at. Or they are read again, up to `PIN_TABLES_MAX_RETRIES` times.
+    /// The tables already resolved in the query are kept, the tables failed to resolve are left
+    /// to `get_table`, which reports the errors.
+    pub async fn pin_tables(&self, tables: &[DatabaseAndTable]) -> Result<()> {
+        let tables = {
+            let tables_refs = self.tables_refs.lock();
+            tables
+                .iter()
+                .filter(|table| !tables_refs.contains_key(*table))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        // a single table is always at one version
+        if tables.len() < 2 {
+            return Ok(());
+        }
+
+        let catalog = self.get_catalog();
+        let mut resolved = Self::resolve_tables(&catalog, &tables).await;
+        for _ in 0..PIN_TABLES_MAX_RETRIES {
+            let again = Self::resolve_tables(&catalog, &tables).await;
+            let unchanged = resolved
+                .iter()
+                .zip(again.iter())
+                .all(|(a, b)| match (a, b) {
+                    (Some(a), Some(b)) => a.get_table_info().ident == b.get_table_info().ident,
+                    (None, None) => true,
+                    _ => false,
+                });
+
+            if unchanged {
+                let mut tables_refs = self.tables_refs.lock();
+                for (key, table) in tables.into_iter().zip(resolved.into_iter()) {
+                    if let Some(table) = table {
+                        let table = self.with_staged_changes(&catalog, table)?;
+                        tables_refs.entry(key).or_insert(table);
+                    }
+                }
+                return Ok(());
+            }
+
+            resolved = again;
+        }
+
+        let names = tables
+            .iter()
+            .map(|(db, tbl)| format!("{}.{}", db, tbl))
+            .collect::<Vec<_>>();
+        Err(ErrorCode::TableVersionMissMatch(format!(
+            "Cannot read the tables {} at one version, they are changed concurrently, please retry",
+            names.join(", ")
+        )))
+    }
+
+    /// The table as seen by the transaction of the session, if any. The version of the table
+    /// is kept, which the staged changes are committed against.
+    fn with_staged_changes(
+        &self,
+        catalog: &DatabaseCatalog,
+        table: Arc<dyn Table>,
+    ) -> Result<Arc<dyn Table>> {
+        match self.session.apply_staged_changes(table.get_table_info()) {
+            Some(table_info) => catalog.get_table_by_info(&table_info),
+            None => Ok(table),
+        }
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.session.in_transaction()
+    }
+
+    pub fn stage_table_change(&self, req: UpsertTableOptionReq) -> Option<UpsertTableOptionReq> {
+        self.session.stage_table_change(req)
+    }
+
+    async fn resolve_tables(
+        catalog: &DatabaseCatalog,
+        tables: &[DatabaseAndTable],
+    ) -> Vec<Option<Arc<dyn Table>>> {
+        let mut resolved = Vec::with_capacity(tables.len());
+        for (database, table) in tables {
+            resolved.push(catalog.get_table(database, table).await.ok());
+        }
+        resolved
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -171,7 +307,10 @@ impl QueryContextShared {
             None => {
                 let settings = self.get_settings();
                 let max_threads = settings.get_max_threads()? as usize;
-                let runtime = Arc::new(Runtime::with_worker_threads(max_threads)?);
+                let runtime = Arc::new(Runtime::with_memory_tracker(
+                    max_threads,
+                    self.memory_tracker.clone(),
+                )?);
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
@@ -206,6 +345,22 @@ impl QueryContextShared {
     pub fn get_table_cache(&self) -> Arc<Option<Box<dyn StorageCache>>> {
         self.session.sessions.get_table_cache()
     }
+
+    pub fn get_parquet_meta_cache(&self) -> Arc<Option<ParquetMetaCache>> {
+        self.session.sessions.get_parquet_meta_cache()
+    }
+
+    pub fn get_segment_info_cache(&self) -> Arc<Option<SegmentInfoCache>> {
+        self.session.sessions.get_segment_info_cache()
+    }
+
+    pub fn get_block_data_cache(&self) -> Option<Arc<BlockDataCache>> {
+        self.session.sessions.get_block_data_cache()
+    }
+
+    pub fn get_upload_policy(&self) -> Arc<UploadPolicy> {
+        self.session.sessions.get_upload_policy()
+    }
 }
 
 impl Session {
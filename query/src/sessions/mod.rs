@@ -18,6 +18,7 @@ mod macros;
 mod context;
 mod context_shared;
 mod metrics;
+mod query_profile;
 mod session;
 mod session_info;
 mod session_ref;
@@ -26,12 +27,17 @@ mod session_status;
 mod sessions;
 mod sessions_info;
 mod settings;
+mod transaction;
 
 pub use context::QueryContext;
 pub use context_shared::QueryContextShared;
+pub use query_profile::PartScanMetrics;
+pub use query_profile::QueryProfile;
+pub use query_profile::ScanProfile;
 pub use session::Session;
 pub use session_info::ProcessInfo;
 pub use session_ref::SessionRef;
 pub use session_status::MutableStatus;
 pub use sessions::SessionManager;
 pub use settings::Settings;
+pub use transaction::Transaction;
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_infallible::RwLock;
+use serde::Serialize;
+
+/// The metrics of reading a part of a table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartScanMetrics {
+    pub part: String,
+    // The compressed bytes of the projected columns.
+    pub read_bytes: u64,
+    // From the start of the read to the decoded block, the decoding included.
+    pub read_cost_us: u64,
+    pub decode_cost_us: u64,
+    // Whether the part was in the local block cache before the read.
+    pub cache_hit: bool,
+}
+
+/// The aggregated scan metrics of a query.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ScanProfile {
+    pub parts: u64,
+    pub read_bytes: u64,
+    pub read_cost_us: u64,
+    pub decode_cost_us: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub slowest_part: String,
+    pub slowest_part_cost_us: u64,
+}
+
+/// The profile of a query, collected while it runs.
+#[derive(Default)]
+pub struct QueryProfile {
+    part_scans: RwLock<Vec<PartScanMetrics>>,
+}
+
+impl QueryProfile {
+    pub fn add_part_scan(&self, metrics: PartScanMetrics) {
+        self.part_scans.write().push(metrics);
+    }
+
+    pub fn get_part_scans(&self) -> Vec<PartScanMetrics> {
+        self.part_scans.read().clone()
+    }
+
+    pub fn get_scan_profile(&self) -> ScanProfile {
+        let mut profile = ScanProfile::default();
+        for part in self.part_scans.read().iter() {
+            profile.parts += 1;
+            profile.read_bytes += part.read_bytes;
+            profile.read_cost_us += part.read_cost_us;
+            profile.decode_cost_us += part.decode_cost_us;
+            match part.cache_hit {
+                true => profile.cache_hits += 1,
+                false => profile.cache_misses += 1,
+            }
+            if profile.slowest_part.is_empty() || part.read_cost_us > profile.slowest_part_cost_us {
+                profile.slowest_part = part.part.clone();
+                profile.slowest_part_cost_us = part.read_cost_us;
+            }
+        }
+        profile
+    }
+}
@@ -20,9 +20,13 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_macros::MallocSizeOf;
 use common_mem_allocator::malloc_size;
+use common_meta_types::TableInfo;
+use common_meta_types::UpsertTableOptionReq;
 use common_meta_types::UserInfo;
 use futures::channel::*;
 
+use crate::audit::AuditEvent;
+use crate::audit::AuditEventType;
 use crate::catalogs::DatabaseCatalog;
 use crate::configs::Config;
 use crate::sessions::context_shared::QueryContextShared;
@@ -30,6 +34,8 @@ use crate::sessions::MutableStatus;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::Settings;
+use crate::sessions::Transaction;
+use crate::users::auth::AuthMgr;
 use crate::users::UserApiProvider;
 
 #[derive(Clone, MallocSizeOf)]
@@ -142,6 +148,14 @@ impl Session {
         });
     }
 
+    pub fn set_client_host(self: &Arc<Self>, host: Option<SocketAddr>) {
+        self.mutable_state.set_client_host(host);
+    }
+
+    pub fn get_client_host(self: &Arc<Self>) -> Option<SocketAddr> {
+        self.mutable_state.get_client_host()
+    }
+
     pub fn set_current_database(self: &Arc<Self>, database_name: String) {
         self.mutable_state.set_current_database(database_name);
     }
@@ -150,16 +164,94 @@ impl Session {
         self.mutable_state.get_current_database()
     }
 
+    pub fn begin_transaction(self: &Arc<Self>) -> Result<()> {
+        let mut transaction = self.mutable_state.get_transaction().write();
+        if transaction.is_some() {
+            return Err(ErrorCode::TransactionError(
+                "A transaction is already in progress",
+            ));
+        }
+        *transaction = Some(Transaction::default());
+        Ok(())
+    }
+
+    /// Ends the transaction in progress, the staged changes are committed or discarded by
+    /// the caller.
+    pub fn take_transaction(self: &Arc<Self>) -> Option<Transaction> {
+        let mut transaction = self.mutable_state.get_transaction().write();
+        transaction.take()
+    }
+
+    pub fn in_transaction(self: &Arc<Self>) -> bool {
+        self.mutable_state.get_transaction().read().is_some()
+    }
+
+    /// Stages the change of a table in the transaction in progress, or gives it back if
+    /// there is none.
+    pub fn stage_table_change(
+        self: &Arc<Self>,
+        req: UpsertTableOptionReq,
+    ) -> Option<UpsertTableOptionReq> {
+        let mut transaction = self.mutable_state.get_transaction().write();
+        match transaction.as_mut() {
+            Some(transaction) => {
+                transaction.stage(req);
+                None
+            }
+            None => Some(req),
+        }
+    }
+
+    pub fn apply_staged_changes(self: &Arc<Self>, table_info: &TableInfo) -> Option<TableInfo> {
+        let transaction = self.mutable_state.get_transaction().read();
+        transaction.as_ref()?.apply_staged(table_info)
+    }
+
     pub fn get_current_user(self: &Arc<Self>) -> Result<UserInfo> {
         self.mutable_state
             .get_current_user()
             .ok_or_else(|| ErrorCode::AuthenticateFailure("unauthenticated"))
     }
 
+    // Set the current user after authentication, the roles granted to the user become
+    // the roles of the session.
     pub fn set_current_user(self: &Arc<Self>, user: UserInfo) {
+        self.mutable_state.set_auth_roles(user.roles.clone());
         self.mutable_state.set_current_user(user)
     }
 
+    pub fn get_auth_roles(self: &Arc<Self>) -> Vec<String> {
+        self.mutable_state.get_auth_roles()
+    }
+
+    pub fn set_auth_roles(self: &Arc<Self>, roles: Vec<String>) {
+        self.mutable_state.set_auth_roles(roles)
+    }
+
+    pub fn get_current_role(self: &Arc<Self>) -> Option<String> {
+        self.mutable_state.get_current_role()
+    }
+
+    // Switch the current role, the role must be one of the roles of the session.
+    pub fn set_current_role(self: &Arc<Self>, role: String) -> Result<()> {
+        if !self.get_auth_roles().contains(&role) {
+            return Err(ErrorCode::RoleNotGranted(format!(
+                "Role '{}' is not granted to the user of the session",
+                role
+            )));
+        }
+        self.mutable_state.set_current_role(Some(role));
+        Ok(())
+    }
+
+    pub fn set_secondary_roles_all(self: &Arc<Self>, all: bool) {
+        self.mutable_state.set_secondary_roles_all(all)
+    }
+
+    pub fn get_active_roles(self: &Arc<Self>) -> Vec<String> {
+        self.mutable_state.get_active_roles()
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.mutable_state.get_settings()
     }
@@ -176,6 +268,33 @@ impl Session {
         self.sessions.get_user_manager()
     }
 
+    pub fn get_auth_manager(self: &Arc<Self>) -> Arc<AuthMgr> {
+        self.sessions.get_auth_manager()
+    }
+
+    /// Record the login attempt of the session into the audit log.
+    pub fn audit_login(
+        self: &Arc<Self>,
+        user: &str,
+        client_address: &str,
+        failure: Option<&ErrorCode>,
+    ) {
+        let event_type = match failure {
+            None => AuditEventType::LoginSuccess,
+            Some(_) => AuditEventType::LoginFailure,
+        };
+        let mut event = AuditEvent::create(event_type, &self.config.query.tenant_id);
+        event.handler_type = self.get_type();
+        event.session_id = self.get_id();
+        event.user = user.to_string();
+        event.client_address = client_address.to_string();
+        event.operation = "Login".to_string();
+        if let Some(failure) = failure {
+            event = event.with_error(failure);
+        }
+        self.sessions.get_audit_log().append(event);
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         malloc_size(self)
     }
@@ -47,11 +47,7 @@ impl Session {
         let mut memory_usage = 0;
 
         if let Some(shared) = &status.get_context_shared() {
-            if let Ok(runtime) = shared.try_get_runtime() {
-                let runtime_tracker = runtime.get_tracker();
-                let runtime_memory_tracker = runtime_tracker.get_memory_tracker();
-                memory_usage = runtime_memory_tracker.get_memory_usage();
-            }
+            memory_usage = shared.memory_tracker.get_memory_usage();
         }
 
         ProcessInfo {
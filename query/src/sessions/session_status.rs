@@ -25,6 +25,7 @@ use futures::channel::oneshot::Sender;
 
 use crate::sessions::context_shared::QueryContextShared;
 use crate::sessions::Settings;
+use crate::sessions::Transaction;
 
 #[derive(MallocSizeOf)]
 pub struct MutableStatus {
@@ -34,11 +35,18 @@ pub struct MutableStatus {
     #[ignore_malloc_size_of = "insignificant"]
     current_user: RwLock<Option<UserInfo>>,
     #[ignore_malloc_size_of = "insignificant"]
+    auth_roles: RwLock<Vec<String>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    current_role: RwLock<Option<String>>,
+    secondary_roles_all: AtomicBool,
+    #[ignore_malloc_size_of = "insignificant"]
     client_host: RwLock<Option<SocketAddr>>,
     #[ignore_malloc_size_of = "insignificant"]
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     #[ignore_malloc_size_of = "insignificant"]
     context_shared: RwLock<Option<Arc<QueryContextShared>>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    transaction: RwLock<Option<Transaction>>,
 }
 
 impl MutableStatus {
@@ -46,11 +54,15 @@ impl MutableStatus {
         Ok(MutableStatus {
             abort: Default::default(),
             current_user: Default::default(),
+            auth_roles: Default::default(),
+            current_role: Default::default(),
+            secondary_roles_all: Default::default(),
             client_host: Default::default(),
             current_database: RwLock::new("default".to_string()),
             session_settings: RwLock::new(Settings::try_create()?.as_ref().clone()),
             io_shutdown_tx: Default::default(),
             context_shared: Default::default(),
+            transaction: Default::default(),
         })
     }
 
@@ -88,6 +100,58 @@ impl MutableStatus {
         *lock = Some(user);
     }
 
+    // Get the roles of the session: the roles granted to the user, and the roles granted
+    // by the authenticator
+    pub fn get_auth_roles(&self) -> Vec<String> {
+        let lock = self.auth_roles.read();
+        lock.clone()
+    }
+
+    // Set the roles of the session, e.g. the roles granted to the user merged with the roles
+    // claim of a jwt, the first one of them becomes the current role.
+    pub fn set_auth_roles(&self, roles: Vec<String>) {
+        let mut lock = self.auth_roles.write();
+        *self.current_role.write() = roles.first().cloned();
+        *lock = roles;
+    }
+
+    // Get the primary role of the session, set by SET ROLE
+    pub fn get_current_role(&self) -> Option<String> {
+        let lock = self.current_role.read();
+        lock.clone()
+    }
+
+    pub fn set_current_role(&self, role: Option<String>) {
+        let mut lock = self.current_role.write();
+        *lock = role;
+    }
+
+    // Whether all the other granted roles are active too, set by SET SECONDARY ROLES
+    pub fn get_secondary_roles_all(&self) -> bool {
+        self.secondary_roles_all.load(Ordering::Relaxed)
+    }
+
+    pub fn set_secondary_roles_all(&self, all: bool) {
+        self.secondary_roles_all.store(all, Ordering::Relaxed);
+    }
+
+    // Get the active roles of the session: the current role, and with secondary roles ALL,
+    // the other roles of the session. Their privileges are granted to the current user in
+    // the privilege checks.
+    pub fn get_active_roles(&self) -> Vec<String> {
+        let current_role = self.get_current_role();
+        let mut roles: Vec<String> = current_role.iter().cloned().collect();
+        if self.get_secondary_roles_all() {
+            let auth_roles = self.get_auth_roles();
+            roles.extend(
+                auth_roles
+                    .into_iter()
+                    .filter(|role| Some(role) != current_role.as_ref()),
+            );
+        }
+        roles
+    }
+
     pub fn get_settings(&self) -> Arc<Settings> {
         let lock = self.session_settings.read();
         Arc::new(lock.clone())
@@ -134,4 +198,8 @@ impl MutableStatus {
         let mut lock = self.context_shared.write();
         lock.take()
     }
+
+    pub fn get_transaction(&self) -> &RwLock<Option<Transaction>> {
+        &self.transaction
+    }
 }
@@ -31,15 +31,23 @@ use common_tracing::tracing;
 use futures::future::Either;
 use futures::StreamExt;
 
+use crate::audit::AuditLog;
+use crate::audit::SettingsHistory;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::configs::config_storage::StorageType;
 use crate::configs::Config;
+use crate::interpreters::AsyncInsertQueue;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
+use crate::storages::fuse::cache::BlockDataCache;
 use crate::storages::fuse::cache::LocalCache;
 use crate::storages::fuse::cache::LocalCacheConfig;
+use crate::storages::fuse::cache::ParquetMetaCache;
+use crate::storages::fuse::cache::SegmentInfoCache;
+use crate::storages::fuse::io::UploadPolicy;
+use crate::users::auth::AuthMgr;
 use crate::users::UserApiProvider;
 
 pub struct SessionManager {
@@ -47,11 +55,19 @@ pub struct SessionManager {
     pub(in crate::sessions) discovery: Arc<ClusterDiscovery>,
     pub(in crate::sessions) catalog: Arc<DatabaseCatalog>,
     pub(in crate::sessions) user: Arc<UserApiProvider>,
+    pub(in crate::sessions) auth_manager: Arc<AuthMgr>,
+    pub(in crate::sessions) audit_log: Arc<AuditLog>,
+    pub(in crate::sessions) settings_history: Arc<SettingsHistory>,
     pub(in crate::sessions) http_query_manager: Arc<HttpQueryManager>,
+    pub(in crate::sessions) async_insert_queue: Arc<AsyncInsertQueue>,
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     pub(in crate::sessions) table_cache: Arc<Option<Box<dyn StorageCache>>>,
+    pub(in crate::sessions) parquet_meta_cache: Arc<Option<ParquetMetaCache>>,
+    pub(in crate::sessions) segment_info_cache: Arc<Option<SegmentInfoCache>>,
+    pub(in crate::sessions) block_data_cache: Option<Arc<BlockDataCache>>,
+    pub(in crate::sessions) upload_policy: Arc<UploadPolicy>,
 }
 
 impl SessionManager {
@@ -72,6 +88,28 @@ impl SessionManager {
             Arc::new(None)
         };
 
+        let parquet_meta_cache = match conf.query.table_cache_parquet_meta_count {
+            0 => Arc::new(None),
+            capacity => Arc::new(Some(ParquetMetaCache::create(capacity))),
+        };
+
+        let segment_info_cache = match conf.query.table_cache_segment_info_count {
+            0 => Arc::new(None),
+            capacity => Arc::new(Some(SegmentInfoCache::create(capacity))),
+        };
+
+        // Blocks on the local disk storage are not worth caching on the same disk again.
+        let block_data_cache = match conf.query.table_block_cache_mb_size {
+            0 => None,
+            _ if storage_type == StorageType::Disk => None,
+            size_mb => Some(Arc::new(BlockDataCache::create(
+                &conf.query.table_block_cache_root,
+                size_mb,
+            )?)),
+        };
+
+        let upload_policy = Arc::new(UploadPolicy::create(&conf));
+
         let catalog = Arc::new(DatabaseCatalog::try_create_with_config(conf.clone()).await?);
 
         // Cluster discovery.
@@ -80,6 +118,9 @@ impl SessionManager {
         // User manager and init the default users.
         let user = UserApiProvider::create_global(conf.clone()).await?;
         user.load_udfs(conf.clone()).await?;
+        let auth_manager = AuthMgr::create(conf.clone(), user.clone());
+        let audit_log = AuditLog::try_create(&conf)?;
+        let settings_history = SettingsHistory::create(&conf);
 
         let http_query_manager = HttpQueryManager::create_global(conf.clone()).await?;
 
@@ -89,10 +130,18 @@ impl SessionManager {
             conf,
             discovery,
             user,
+            auth_manager,
+            audit_log,
+            settings_history,
             http_query_manager,
+            async_insert_queue: AsyncInsertQueue::create(),
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
             table_cache,
+            parquet_meta_cache,
+            segment_info_cache,
+            block_data_cache,
+            upload_policy,
         }))
     }
 
@@ -113,6 +162,22 @@ impl SessionManager {
         self.user.clone()
     }
 
+    pub fn get_auth_manager(self: &Arc<Self>) -> Arc<AuthMgr> {
+        self.auth_manager.clone()
+    }
+
+    pub fn get_audit_log(self: &Arc<Self>) -> Arc<AuditLog> {
+        self.audit_log.clone()
+    }
+
+    pub fn get_settings_history(self: &Arc<Self>) -> Arc<SettingsHistory> {
+        self.settings_history.clone()
+    }
+
+    pub fn get_async_insert_queue(self: &Arc<Self>) -> Arc<AsyncInsertQueue> {
+        self.async_insert_queue.clone()
+    }
+
     pub fn get_catalog(self: &Arc<Self>) -> Arc<DatabaseCatalog> {
         self.catalog.clone()
     }
@@ -121,6 +186,22 @@ impl SessionManager {
         self.table_cache.clone()
     }
 
+    pub fn get_parquet_meta_cache(self: &Arc<Self>) -> Arc<Option<ParquetMetaCache>> {
+        self.parquet_meta_cache.clone()
+    }
+
+    pub fn get_segment_info_cache(self: &Arc<Self>) -> Arc<Option<SegmentInfoCache>> {
+        self.segment_info_cache.clone()
+    }
+
+    pub fn get_block_data_cache(self: &Arc<Self>) -> Option<Arc<BlockDataCache>> {
+        self.block_data_cache.clone()
+    }
+
+    pub fn get_upload_policy(self: &Arc<Self>) -> Arc<UploadPolicy> {
+        self.upload_policy.clone()
+    }
+
     pub fn create_session(self: &Arc<Self>, typ: impl Into<String>) -> Result<SessionRef> {
         let mut sessions = self.active_sessions.write();
         match sessions.len() == self.max_sessions {
@@ -15,9 +15,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues::prelude::DateTimeOutput;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::DivisionByZeroMode;
+use common_functions::scalars::OverflowMode;
 use common_infallible::RwLock;
 use common_macros::MallocSizeOf;
 #[derive(Clone, Debug, MallocSizeOf)]
@@ -28,12 +31,33 @@ pub struct Settings {
 impl Settings {
     apply_macros! { apply_getter_setter_settings, apply_initial_settings, apply_update_settings,
         ("max_block_size", u64, 10000, "Maximum block size for reading"),
+        ("max_block_bytes", u64, 64 * 1024 * 1024, "Maximum block size in bytes for the re-chunked blocks between pipeline stages, 0 means no limit"),
+        ("enable_block_rechunk", u64, 0, "Re-chunk the blocks to max_block_size rows and max_block_bytes bytes after filtering. 1 for enable, 0 for disable"),
         ("max_threads", u64, 16, "The maximum number of threads to execute the request. By default, it is determined automatically."),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds"),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query."),
         ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query."),
         ("parallel_read_threads", u64, 1, "The maximum number of parallelism for reading data. By default, it is 1."),
-        ("storage_read_buffer_size", u64, 1024 * 1024, "The size of buffer in bytes for buffered reader of dal, default value is 1MB")
+        ("storage_read_buffer_size", u64, 1024 * 1024, "The size of buffer in bytes for buffered reader of dal, default value is 1MB"),
+        ("max_segment_reads", u64, 16, "The maximum number of segments of a fuse table read concurrently when planning the reads of the table"),
+        ("group_by_pass_through_min_rows", u64, 100000, "Minimum rows the partial group by aggregates before it may pass the rows through to the final group by, 0 for disable"),
+        ("group_by_pass_through_ratio", u64, 90, "The partial group by passes the rows through once the number of groups reaches this percentage of the aggregated rows"),
+        ("distinct_max_memory_bytes", u64, 1024 * 1024 * 1024, "Maximum bytes of the rows DISTINCT keeps in memory before spilling them to disk, 0 means no limit"),
+        ("group_by_max_memory_bytes", u64, 1024 * 1024 * 1024, "Maximum bytes of the groups each GROUP BY transform keeps in memory, beyond which the partial ones pass the rows through and the final ones spill them to disk, 0 means no limit"),
+        ("sort_max_memory_bytes", u64, 1024 * 1024 * 1024, "Maximum bytes of the blocks each ORDER BY transform keeps in memory, beyond which they are spilled to disk as sorted runs merged at the end, 0 means no limit"),
+        ("join_max_memory_bytes", u64, 1024 * 1024 * 1024, "Maximum bytes of the build side each hash join keeps in memory, beyond which both sides are spilled to disk in partitions joined one by one, 0 means no limit"),
+        ("max_memory_usage", u64, 0, "Maximum bytes of the memory a query allocates, the query fails beyond, 0 means no limit"),
+        ("spill_quota_bytes", u64, 0, "Maximum bytes a query spills to the disk, e.g. by DISTINCT, before it fails, 0 means no limit"),
+        ("enable_distinct_aggregate_rewrite", u64, 1, "Compute the DISTINCT aggregates of one argument, e.g. count(DISTINCT x), by grouping by the argument first instead of keeping a hash set per group. 1 for enable, 0 for disable"),
+        ("approx_count_distinct_min_rows", u64, 0, "Replace COUNT(DISTINCT ...) by the approximate approx_count_distinct once the aggregation reads more than this estimated rows, 0 for disable"),
+        ("enable_async_insert", u64, 0, "Buffer the INSERT ... VALUES of a table and write them together as one block. 1 for enable, 0 for disable"),
+        ("async_insert_max_data_size", u64, 1024 * 1024, "The buffered inserts of a table are written once they reach this size in bytes"),
+        ("async_insert_busy_timeout_ms", u64, 200, "The buffered inserts of a table are written at most this milliseconds after the first of them"),
+        ("time_zone", String, "UTC", "The time zone the DateTime values of the results are rendered in, a name of the tz database, e.g. Asia/Shanghai"),
+        ("datetime_output_format", String, "time_zone", "How the DateTime values of the results are rendered: time_zone for the session time_zone, utc, or epoch for the seconds since 1970-01-01 00:00:00 UTC"),
+        ("numeric_overflow_mode", String, "wrap", "What +, -, * and sum do when an integer result overflows: wrap around, error, saturate to the bounds of the type, or promote to Float64"),
+        ("div_precision_increment", u64, 0, "The number of decimal digits the results of / are rounded to, at most 30, 0 keeps the full precision of Float64"),
+        ("error_on_division_by_zero", u64, 0, "Fail the query when /, div or % has a zero divisor. 1 for enable, 0 for a NULL result and a warning in the log")
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -47,6 +71,23 @@ impl Settings {
         Ok(settings)
     }
 
+    /// How the DateTime values of the results are rendered, resolved from `time_zone` and `datetime_output_format`.
+    pub fn get_datetime_output(&self) -> Result<DateTimeOutput> {
+        DateTimeOutput::try_create(&self.get_time_zone()?, &self.get_datetime_output_format()?)
+    }
+
+    /// What the integer arithmetic does on overflow, resolved from `numeric_overflow_mode`.
+    pub fn get_overflow_mode(&self) -> Result<OverflowMode> {
+        OverflowMode::try_create(&self.get_numeric_overflow_mode()?)
+    }
+
+    /// What /, div and % do on a zero divisor, resolved from `error_on_division_by_zero`.
+    pub fn get_division_by_zero_mode(&self) -> Result<DivisionByZeroMode> {
+        Ok(DivisionByZeroMode::create(
+            self.get_error_on_division_by_zero()? != 0,
+        ))
+    }
+
     pub fn iter(&self) -> SettingsIterator {
         SettingsIterator {
             settings: self.inner.get_settings(),
@@ -230,7 +271,7 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_update_string(&self, key: &'static str, val: &str) -> Result<()> {
+    pub fn try_update_string(&self, key: &'static str, val: String) -> Result<()> {
         let mut settings = self.settings.write();
         let setting_val = settings
             .get(key)
@@ -238,7 +279,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             let v = DataValue::Struct(vec![
-                DataValue::String(Some(val.as_bytes().to_vec())),
+                DataValue::String(Some(val.into_bytes())),
                 values[1].clone(),
                 values[2].clone(),
             ]);
@@ -248,7 +289,7 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_get_string(&self, key: &str) -> Result<Vec<u8>> {
+    pub fn try_get_string(&self, key: &str) -> Result<String> {
         let settings = self.settings.read();
         let setting_val = settings
             .get(key)
@@ -256,7 +297,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             if let DataValue::String(Some(result)) = values[0].clone() {
-                return Ok(result);
+                return Ok(String::from_utf8(result)?);
             }
         }
 
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_meta_types::CommitTablesReq;
+use common_meta_types::TableInfo;
+use common_meta_types::UpsertTableOptionReq;
+
+/// The changes of the tables made in a transaction of a session, from BEGIN to COMMIT.
+///
+/// The new snapshots of the fuse tables are written as usual, but the options pointing the
+/// tables to them are only staged here, and are committed to the meta server at once by
+/// COMMIT, which fails if any of the tables has been changed by others meanwhile. The staged
+/// changes are seen by the statements of the transaction only.
+#[derive(Default)]
+pub struct Transaction {
+    tables: HashMap<u64, UpsertTableOptionReq>,
+}
+
+impl Transaction {
+    /// Stages the change of a table, on top of the ones staged before, which were made to the
+    /// same version of the table.
+    pub fn stage(&mut self, req: UpsertTableOptionReq) {
+        match self.tables.get_mut(&req.table_id) {
+            Some(staged) => {
+                staged.options.extend(req.options);
+                if req.schema.is_some() {
+                    staged.schema = req.schema;
+                }
+            }
+            None => {
+                self.tables.insert(req.table_id, req);
+            }
+        }
+    }
+
+    /// The table as seen in the transaction, with its staged changes applied.
+    pub fn apply_staged(&self, table_info: &TableInfo) -> Option<TableInfo> {
+        let staged = self.tables.get(&table_info.ident.table_id)?;
+        let mut table_info = table_info.clone();
+        if let Some(schema) = &staged.schema {
+            table_info.meta.schema = schema.clone();
+        }
+        for (key, value) in &staged.options {
+            match value {
+                Some(value) => table_info.meta.options.insert(key.clone(), value.clone()),
+                None => table_info.meta.options.remove(key),
+            };
+        }
+        Some(table_info)
+    }
+
+    pub fn into_commit_req(self) -> CommitTablesReq {
+        CommitTablesReq {
+            reqs: self.tables.into_values().collect(),
+        }
+    }
+}
@@ -114,6 +114,7 @@ impl Executor {
             description: "".to_string(),
             tbl_args: table.table_args(),
             push_downs: None,
+            read_order: table.read_order(),
         };
 
         // Bind plan partitions to context.
@@ -22,10 +22,12 @@ use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
 
+use crate::audit::AuditEventType;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::QueryAnalyzeState;
+use crate::sql::statements::QueryJoinRelation;
 use crate::sql::statements::QueryRelation;
 use crate::sql::DfHint;
 use crate::sql::DfParser;
@@ -57,7 +59,19 @@ impl PlanParser {
             return Err(ErrorCode::SyntaxException("Only support single query"));
         }
 
-        match statements[0].analyze(ctx.clone()).await? {
+        let analyzed = match statements[0].analyze(ctx.clone()).await {
+            Ok(analyzed) => analyzed,
+            Err(cause) => {
+                // The privileges checked while analyzing, e.g. the usage of the UDFs, fail
+                // before any interpreter is created, so they are audited here.
+                if cause.code() == ErrorCode::PermissionDenied("").code() {
+                    ctx.audit(AuditEventType::PrivilegeDenied, "Analyze", Some(&cause));
+                }
+                return Err(cause);
+            }
+        };
+
+        match analyzed {
             AnalyzedResult::SimpleQuery(plan) => Ok(*plan),
             AnalyzedResult::SelectQuery(data) => Self::build_query_plan(&data),
             AnalyzedResult::ExplainQuery((typ, data)) => {
@@ -78,7 +92,8 @@ impl PlanParser {
         let having = Self::build_having_plan(before_order, data)?;
         let order_by = Self::build_order_by_plan(having, data)?;
         let projection = Self::build_projection_plan(order_by, data)?;
-        let limit = Self::build_limit_plan(projection, data)?;
+        let distinct = Self::build_distinct_plan(projection, data)?;
+        let limit = Self::build_limit_plan(distinct, data)?;
 
         Ok(PlanNode::Select(SelectPlan {
             input: Arc::new(limit),
@@ -86,10 +101,34 @@ impl PlanParser {
     }
 
     fn build_from_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
-        match &data.relation {
+        Self::build_relation_plan(&data.relation)
+    }
+
+    fn build_relation_plan(relation: &QueryRelation) -> Result<PlanNode> {
+        match relation {
             QueryRelation::None => Err(ErrorCode::LogicalError("Not from in select query")),
             QueryRelation::Nested(data) => Self::build_query_plan(data),
             QueryRelation::FromTable(plan) => Ok(PlanNode::ReadSource(plan.as_ref().clone())),
+            QueryRelation::Join(join) => Self::build_join_plan(join),
+        }
+    }
+
+    fn build_join_plan(join: &QueryJoinRelation) -> Result<PlanNode> {
+        let left = Self::build_join_side_plan(&join.left, &join.left_projection)?;
+        let right = Self::build_join_side_plan(&join.right, &join.right_projection)?;
+        PlanBuilder::from(&left)
+            .join(&right, join.join_type, &join.left_keys, &join.right_keys)?
+            .build()
+    }
+
+    fn build_join_side_plan(
+        relation: &QueryRelation,
+        projection: &[Expression],
+    ) -> Result<PlanNode> {
+        let plan = Self::build_relation_plan(relation)?;
+        match projection.is_empty() {
+            true => Ok(plan),
+            false => PlanBuilder::from(&plan).project(projection)?.build(),
         }
     }
 
@@ -186,6 +225,13 @@ impl PlanParser {
             .build()
     }
 
+    fn build_distinct_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match data.distinct {
+            false => Ok(plan),
+            true => PlanBuilder::from(&plan).distinct()?.build(),
+        }
+    }
+
     fn build_limit_plan(input: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         match (&data.limit, &data.offset) {
             (None, None) => Ok(input),
@@ -15,6 +15,7 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::time::Instant;
@@ -30,6 +31,7 @@ use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
 use common_planners::ExplainType;
 use common_planners::Optimization;
+use common_planners::TransactionAction;
 use metrics::histogram;
 use serde::Deserialize;
 use sqlparser::ast::BinaryOperator;
@@ -37,6 +39,7 @@ use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::Statement;
 use sqlparser::ast::TableConstraint;
 use sqlparser::ast::Value;
@@ -52,32 +55,63 @@ use sqlparser::tokenizer::Whitespace;
 use sqlparser::tokenizer::Word;
 
 use super::statements::DfCopy;
+use super::statements::DfCopyIntoStage;
 use super::statements::DfDescribeStage;
+use crate::sql::statements::CopyIntoStageSource;
+use crate::sql::statements::DfAlterColumnOperation;
+use crate::sql::statements::DfAlterOwner;
+use crate::sql::statements::DfAlterOwnerObject;
+use crate::sql::statements::DfAlterReadOnly;
+use crate::sql::statements::DfAlterTableColumn;
+use crate::sql::statements::DfAlterTableOptions;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfAlterUser;
+use crate::sql::statements::DfAlterUserNetworkPolicy;
+use crate::sql::statements::DfAnalyzeTable;
+use crate::sql::statements::DfCreateConnection;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateNetworkPolicy;
+use crate::sql::statements::DfCreateRole;
+use crate::sql::statements::DfCreateRowAccessPolicy;
 use crate::sql::statements::DfCreateStage;
+use crate::sql::statements::DfCreateStream;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
+use crate::sql::statements::DfDropConnection;
 use crate::sql::statements::DfDropDatabase;
+use crate::sql::statements::DfDropNetworkPolicy;
+use crate::sql::statements::DfDropRole;
+use crate::sql::statements::DfDropRowAccessPolicy;
 use crate::sql::statements::DfDropStage;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
 use crate::sql::statements::DfExplain;
+use crate::sql::statements::DfExportTable;
 use crate::sql::statements::DfGrantObject;
+use crate::sql::statements::DfGrantRole;
+use crate::sql::statements::DfGrantRolePrivilege;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfOptimizeTable;
+use crate::sql::statements::DfQueryFunction;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfReadOnlyObject;
+use crate::sql::statements::DfRevokeAllStatement;
+use crate::sql::statements::DfRevokeRole;
+use crate::sql::statements::DfRevokeRolePrivilege;
 use crate::sql::statements::DfRevokeStatement;
+use crate::sql::statements::DfSetRole;
+use crate::sql::statements::DfSetSecondaryRoles;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
 use crate::sql::statements::DfShowCreateTable;
 use crate::sql::statements::DfShowDatabases;
+use crate::sql::statements::DfShowEngines;
 use crate::sql::statements::DfShowFunctions;
 use crate::sql::statements::DfShowGrants;
 use crate::sql::statements::DfShowMetrics;
@@ -86,8 +120,13 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUDF;
 use crate::sql::statements::DfShowUsers;
+use crate::sql::statements::DfTransaction;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropDatabase;
+use crate::sql::statements::DfUndropTable;
 use crate::sql::statements::DfUseDatabase;
+use crate::sql::statements::DfVacuumDropTable;
+use crate::sql::statements::DfVacuumTable;
 use crate::sql::DfHint;
 use crate::sql::DfStatement;
 
@@ -113,13 +152,178 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = Self::strip_time_travel_keyword(tokenizer.tokenize()?);
+        let tokens = Self::rewrite_table_sample(tokens);
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
         })
     }
 
+    // `FROM t AT (SNAPSHOT => 'id')` is parsed as the table arguments `FROM t (SNAPSHOT => 'id')`,
+    // the AT keyword unknown to sqlparser is dropped.
+    fn strip_time_travel_keyword(tokens: Vec<Token>) -> Vec<Token> {
+        let is_word = |token: &Token, value: &str| match token {
+            Token::Word(w) => w.quote_style.is_none() && w.value.eq_ignore_ascii_case(value),
+            _ => false,
+        };
+
+        let mut stripped = Vec::with_capacity(tokens.len());
+        for (i, token) in tokens.iter().enumerate() {
+            if is_word(token, "AT") {
+                let mut next = tokens[i + 1..]
+                    .iter()
+                    .filter(|t| !matches!(t, Token::Whitespace(_)));
+                if next.next() == Some(&Token::LParen)
+                    && next
+                        .next()
+                        .map_or(false, |t| is_word(t, "SNAPSHOT") || is_word(t, "TIMESTAMP"))
+                {
+                    continue;
+                }
+            }
+            stripped.push(token.clone());
+        }
+        stripped
+    }
+
+    // `FROM t SAMPLE [BLOCK | SYSTEM | ROW | BERNOULLI] (p [PERCENT])`, also written `SAMPLE p%`
+    // or with TABLESAMPLE, is parsed as the table arguments `FROM t (SAMPLE_BLOCK => p)` or
+    // `FROM t (SAMPLE_ROW => p)`, appended to the time travel arguments if any. The blocks are
+    // sampled by default.
+    fn rewrite_table_sample(tokens: Vec<Token>) -> Vec<Token> {
+        let mut rewritten: Vec<Token> = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+        while i < tokens.len() {
+            if Self::is_unquoted_word(&tokens[i], "SAMPLE")
+                || Self::is_unquoted_word(&tokens[i], "TABLESAMPLE")
+            {
+                if let Some((name, percent, end)) = Self::sample_clause(&tokens, i + 1) {
+                    if Self::append_table_arg(&mut rewritten, name, percent) {
+                        i = end;
+                        continue;
+                    }
+                }
+            }
+            rewritten.push(tokens[i].clone());
+            i += 1;
+        }
+        rewritten
+    }
+
+    fn is_unquoted_word(token: &Token, value: &str) -> bool {
+        match token {
+            Token::Word(w) => w.quote_style.is_none() && w.value.eq_ignore_ascii_case(value),
+            _ => false,
+        }
+    }
+
+    // The argument name and the percent of the sample clause starting at `start`, with the
+    // position past the clause.
+    fn sample_clause(tokens: &[Token], start: usize) -> Option<(&'static str, String, usize)> {
+        let next = |from: usize| {
+            (from..tokens.len()).find(|i| !matches!(tokens[*i], Token::Whitespace(_)))
+        };
+
+        let mut pos = next(start)?;
+        let mut name = "SAMPLE_BLOCK";
+        if Self::is_unquoted_word(&tokens[pos], "BLOCK")
+            || Self::is_unquoted_word(&tokens[pos], "SYSTEM")
+        {
+            pos = next(pos + 1)?;
+        } else if Self::is_unquoted_word(&tokens[pos], "ROW")
+            || Self::is_unquoted_word(&tokens[pos], "BERNOULLI")
+        {
+            name = "SAMPLE_ROW";
+            pos = next(pos + 1)?;
+        }
+
+        let parenthesized = tokens[pos] == Token::LParen;
+        if parenthesized {
+            pos = next(pos + 1)?;
+        }
+        let percent = match &tokens[pos] {
+            Token::Number(n, _) => n.clone(),
+            _ => return None,
+        };
+        let mut end = pos + 1;
+        let unit = next(end);
+        match unit {
+            Some(p) if tokens[p] == Token::Mod || Self::is_unquoted_word(&tokens[p], "PERCENT") => {
+                end = p + 1;
+            }
+            _ if !parenthesized => return None,
+            _ => {}
+        }
+        if parenthesized {
+            let p = next(end)?;
+            if tokens[p] != Token::RParen {
+                return None;
+            }
+            end = p + 1;
+        }
+        Some((name, percent, end))
+    }
+
+    // Appends the argument to the table just before, which is a plain table name or a table with
+    // the time travel arguments.
+    fn append_table_arg(tokens: &mut Vec<Token>, name: &str, value: String) -> bool {
+        let last = match tokens
+            .iter()
+            .rposition(|t| !matches!(t, Token::Whitespace(_)))
+        {
+            Some(last) => last,
+            None => return false,
+        };
+        let arg = vec![
+            Token::make_word(name, None),
+            Token::RArrow,
+            Token::Number(value, false),
+            Token::RParen,
+        ];
+        let plain_table = matches!(
+            &tokens[last],
+            Token::Word(w) if w.keyword == Keyword::NoKeyword || w.quote_style.is_some()
+        );
+        if plain_table {
+            tokens.push(Token::LParen);
+            tokens.extend(arg);
+            return true;
+        }
+        if tokens[last] != Token::RParen {
+            return false;
+        }
+
+        let mut depth = 0;
+        let open = (0..=last).rev().find(|i| {
+            match tokens[*i] {
+                Token::RParen => depth += 1,
+                Token::LParen => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        });
+        let inner = tokens[open.map_or(last, |i| i + 1)..last]
+            .iter()
+            .filter(|t| !matches!(t, Token::Whitespace(_)))
+            .take(2)
+            .collect::<Vec<_>>();
+        let time_travel = match inner.as_slice() {
+            [first, arrow] => {
+                (Self::is_unquoted_word(first, "SNAPSHOT")
+                    || Self::is_unquoted_word(first, "TIMESTAMP"))
+                    && **arrow == Token::RArrow
+            }
+            _ => false,
+        };
+        if time_travel {
+            tokens.truncate(last);
+            tokens.push(Token::Comma);
+            tokens.extend(arg);
+        }
+        time_travel
+    }
+
     /// Parse a SQL statement and produce a set of statements with dialect
     pub fn parse_sql(sql: &str) -> Result<(Vec<DfStatement>, Vec<DfHint>), ErrorCode> {
         let dialect = &GenericDialect {};
@@ -172,6 +376,29 @@ impl<'a> DfParser<'a> {
         Ok((stmts, hints))
     }
 
+    /// Parse a standalone SQL expression, e.g. the predicate of a row access policy.
+    pub fn parse_expr(sql: &str) -> Result<Expr, ErrorCode> {
+        let mut parser = DfParser::new(sql)?;
+        let expr = parser.parser.parse_expr()?;
+        match parser.parser.peek_token() {
+            Token::EOF => Ok(expr),
+            unexpected => Ok(parser.expected("end of expression", unexpected)?),
+        }
+    }
+
+    /// Parse standalone column definitions separated by commas, e.g. `id UInt64, score Float64`.
+    pub fn parse_column_defs(sql: &str) -> Result<Vec<ColumnDef>, ErrorCode> {
+        let mut parser = DfParser::new(sql)?;
+        let mut columns = vec![parser.parse_column_def()?];
+        while parser.parser.consume_token(&Token::Comma) {
+            columns.push(parser.parse_column_def()?);
+        }
+        match parser.parser.peek_token() {
+            Token::EOF => Ok(columns),
+            unexpected => Ok(parser.expected("end of column definitions", unexpected)?),
+        }
+    }
+
     /// Report unexpected token
     fn expected<T>(&self, expected: &str, found: Token) -> Result<T, ParserError> {
         parser_err!(format!("Expected {}, found: {}", expected, found))
@@ -214,6 +441,8 @@ impl<'a> DfParser<'a> {
                             self.parse_show_databases()
                         } else if self.consume_token("SETTINGS") {
                             Ok(DfStatement::ShowSettings(DfShowSettings))
+                        } else if self.consume_token("ENGINES") {
+                            Ok(DfStatement::ShowEngines(DfShowEngines))
                         } else if self.consume_token("CREATE") {
                             self.parse_show_create()
                         } else if self.consume_token("PROCESSLIST") {
@@ -235,6 +464,7 @@ impl<'a> DfParser<'a> {
                     Keyword::TRUNCATE => self.parse_truncate(),
                     Keyword::SET => self.parse_set(),
                     Keyword::INSERT => self.parse_insert(),
+                    Keyword::DELETE => self.parse_delete(),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => self.parse_query(),
                     Keyword::GRANT => {
                         self.parser.next_token();
@@ -248,11 +478,22 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_copy()
                     }
+                    Keyword::ANALYZE => {
+                        self.parser.next_token();
+                        self.parse_analyze()
+                    }
+                    Keyword::BEGIN | Keyword::START | Keyword::COMMIT | Keyword::ROLLBACK => {
+                        self.parser.next_token();
+                        self.parse_transaction(w.keyword)
+                    }
                     Keyword::NoKeyword => match w.value.to_uppercase().as_str() {
                         // Use database
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
                         "OPTIMIZE" => self.parse_optimize(),
+                        "UNDROP" => self.parse_undrop(),
+                        "VACUUM" => self.parse_vacuum(),
+                        "EXPORT" => self.parse_export_table(),
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => self.expected("an SQL statement", Token::Word(w)),
@@ -265,14 +506,62 @@ impl<'a> DfParser<'a> {
 
     fn parse_query(&mut self) -> Result<DfStatement, ParserError> {
         // self.parser.prev_token();
+        let functions = self.parse_query_functions()?;
         let native_query = self.parser.parse_query()?;
-        Ok(DfStatement::Query(Box::new(DfQueryStatement::try_from(
-            native_query,
-        )?)))
+        let mut query = DfQueryStatement::try_from(native_query)?;
+        query.functions = functions;
+        Ok(DfStatement::Query(Box::new(query)))
+    }
+
+    // WITH FUNCTION f(x) AS (x * 2) [, FUNCTION g(x, y) AS (x + y)] SELECT ...
+    fn parse_query_functions(&mut self) -> Result<Vec<DfQueryFunction>, ParserError> {
+        let mut functions: Vec<DfQueryFunction> = vec![];
+        if !self.parser.parse_keyword(Keyword::WITH) {
+            return Ok(functions);
+        }
+        if !self.consume_token("FUNCTION") {
+            // a common table expression, left to the query parser
+            self.parser.prev_token();
+            return Ok(functions);
+        }
+
+        loop {
+            let name = self.parser.parse_identifier()?.value;
+            if functions.iter().any(|f| f.name.eq_ignore_ascii_case(&name)) {
+                return parser_err!(format!("Function {} is defined more than once", name));
+            }
+
+            let parameters = self.parse_udf_parameters()?;
+            self.parser.expect_keyword(Keyword::AS)?;
+            self.parser.expect_token(&Token::LParen)?;
+            let definition = self.parser.parse_expr()?;
+            self.parser.expect_token(&Token::RParen)?;
+            functions.push(DfQueryFunction {
+                name,
+                parameters,
+                definition,
+            });
+
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+            if !self.consume_token("FUNCTION") {
+                return self.expected("FUNCTION", self.parser.peek_token());
+            }
+        }
+        Ok(functions)
     }
 
     fn parse_set(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.next_token();
+        if self.consume_token("ROLE") {
+            return self.parse_set_role();
+        }
+        if self.consume_token("SECONDARY") {
+            return self.parse_set_secondary_roles();
+        }
+        let global = self.consume_token("GLOBAL");
+
         match self.parser.parse_set()? {
             Statement::SetVariable {
                 local,
@@ -281,6 +570,7 @@ impl<'a> DfParser<'a> {
                 value,
             } => Ok(DfStatement::SetVariable(DfSetVariable {
                 local,
+                global,
                 hivevar,
                 variable,
                 value,
@@ -289,6 +579,23 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // syntax: "SET ROLE role_name", with SET ROLE consumed.
+    fn parse_set_role(&mut self) -> Result<DfStatement, ParserError> {
+        let role_name = self.parser.parse_literal_string()?;
+        Ok(DfStatement::SetRole(DfSetRole { role_name }))
+    }
+
+    // syntax: "SET SECONDARY ROLES {ALL | NONE}", with SET SECONDARY consumed.
+    fn parse_set_secondary_roles(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("ROLES")?;
+        let all = match self.parser.next_token() {
+            Token::Word(w) if w.keyword == Keyword::ALL => true,
+            Token::Word(w) if w.value.to_uppercase() == "NONE" => false,
+            unexpected => return self.expected("ALL or NONE", unexpected),
+        };
+        Ok(DfStatement::SetSecondaryRoles(DfSetSecondaryRoles { all }))
+    }
+
     fn parse_insert(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.next_token();
         match self.parser.parse_insert()? {
@@ -317,6 +624,20 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    fn parse_delete(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "DELETE FROM t [WHERE expr]"
+        self.parser.next_token();
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let name = self.parser.parse_object_name()?;
+        let selection = if self.parser.parse_keyword(Keyword::WHERE) {
+            Some(self.parser.parse_expr()?)
+        } else {
+            None
+        };
+
+        Ok(DfStatement::Delete(DfDeleteStatement { name, selection }))
+    }
+
     /// Parse an SQL EXPLAIN statement.
     pub fn parse_explain(&mut self) -> Result<DfStatement, ParserError> {
         // Parser is at the token immediately after EXPLAIN
@@ -331,6 +652,14 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "ESTIMATE" => {
+                    self.parser.next_token();
+                    ExplainType::Estimate
+                }
+                "ANALYZE" => {
+                    self.parser.next_token();
+                    ExplainType::Analyze
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
@@ -530,17 +859,30 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_create(&mut self) -> Result<DfStatement, ParserError> {
+        let or_replace = self.parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
         match self.parser.next_token() {
             Token::Word(w) => {
                 //TODO:make stage to sql parser keyword
                 if w.value.to_uppercase() == "STAGE" {
-                    self.parse_create_stage()
+                    self.parse_create_stage(or_replace)
+                } else if w.value.to_uppercase() == "ROW" && !or_replace {
+                    self.parse_create_row_access_policy()
+                } else if w.value.to_uppercase() == "NETWORK" && !or_replace {
+                    self.parse_create_network_policy()
+                } else if w.value.to_uppercase() == "CONNECTION" && !or_replace {
+                    self.parse_create_connection()
+                } else if w.value.to_uppercase() == "STREAM" && !or_replace {
+                    self.parse_create_stream()
+                } else if w.value.to_uppercase() == "ROLE" && !or_replace {
+                    self.parse_create_role()
                 } else {
                     match w.keyword {
-                        Keyword::TABLE => self.parse_create_table(),
-                        Keyword::DATABASE => self.parse_create_database(),
-                        Keyword::USER => self.parse_create_user(),
-                        Keyword::FUNCTION => self.parse_create_udf(),
+                        Keyword::TABLE => self.parse_create_table(or_replace),
+                        Keyword::FUNCTION => self.parse_create_udf(or_replace),
+                        Keyword::DATABASE if !or_replace => self.parse_create_database(),
+                        Keyword::USER if !or_replace => self.parse_create_user(),
+                        _ if or_replace => self
+                            .expected("TABLE, FUNCTION or STAGE after OR REPLACE", Token::Word(w)),
                         _ => self.expected("create statement", Token::Word(w)),
                     }
                 }
@@ -549,13 +891,65 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    fn parse_if_not_exists_or_replace(&mut self, or_replace: bool) -> Result<bool, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        if if_not_exists && or_replace {
+            return parser_err!("OR REPLACE and IF NOT EXISTS cannot be used together");
+        }
+        Ok(if_not_exists)
+    }
+
     fn parse_alter(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
-            Token::Word(w) => match w.keyword {
-                Keyword::USER => self.parse_alter_user(),
-                Keyword::FUNCTION => self.parse_alter_udf(),
-                _ => self.expected("keyword USER or FUNCTION", Token::Word(w)),
-            },
+            Token::Word(w) => {
+                //TODO:make stage to sql parser keyword
+                if w.value.to_uppercase() == "STAGE" {
+                    let stage_name = self.parser.parse_literal_string()?;
+                    return self.parse_alter_owner(DfAlterOwnerObject::Stage(stage_name));
+                }
+
+                if w.value.to_uppercase() == "CLUSTER" {
+                    self.parser.expect_keyword(Keyword::SET)?;
+                    return self.parse_alter_read_only(DfReadOnlyObject::Cluster);
+                }
+
+                match w.keyword {
+                    Keyword::USER => self.parse_alter_user(),
+                    Keyword::FUNCTION => self.parse_alter_udf(),
+                    Keyword::DATABASE => {
+                        let db_name = self.parser.parse_object_name()?;
+                        match self.parser.parse_keyword(Keyword::SET) {
+                            true => self.parse_alter_read_only(DfReadOnlyObject::Database(db_name)),
+                            false => self.parse_alter_owner(DfAlterOwnerObject::Database(db_name)),
+                        }
+                    }
+                    Keyword::TABLE => {
+                        let table_name = self.parser.parse_object_name()?;
+                        if self.parser.parse_keyword(Keyword::SET) {
+                            return match self.parser.peek_token() {
+                                Token::Word(w) if w.value.to_uppercase() == "READ_ONLY" => {
+                                    self.parse_alter_read_only(DfReadOnlyObject::Table(table_name))
+                                }
+                                _ => self.parse_alter_table_options(table_name),
+                            };
+                        }
+                        match self.parser.parse_one_of_keywords(&[
+                            Keyword::ADD,
+                            Keyword::DROP,
+                            Keyword::RENAME,
+                        ]) {
+                            Some(keyword) => self.parse_alter_table_column(table_name, keyword),
+                            None => self.parse_alter_owner(DfAlterOwnerObject::Table(table_name)),
+                        }
+                    }
+                    _ => self.expected(
+                        "keyword USER, FUNCTION, DATABASE, TABLE, STAGE or CLUSTER",
+                        Token::Word(w),
+                    ),
+                }
+            }
             unexpected => self.expected("alter statement", unexpected),
         }
     }
@@ -566,13 +960,14 @@ impl<'a> DfParser<'a> {
                 .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let name = self.parser.parse_object_name()?;
         let (engine, engine_options) = self.parse_database_engine()?;
+        let options = self.parse_options()?;
 
         let create = DfCreateDatabase {
             if_not_exists,
             name,
             engine,
             engine_options,
-            options: HashMap::new(),
+            options,
         };
 
         Ok(DfStatement::CreateDatabase(create))
@@ -597,6 +992,17 @@ impl<'a> DfParser<'a> {
             Token::Word(w) => {
                 if w.value.to_uppercase() == "STAGE" {
                     self.parse_drop_stage()
+                } else if w.value.to_uppercase() == "ROW" {
+                    self.parse_drop_row_access_policy()
+                } else if w.value.to_uppercase() == "NETWORK" {
+                    self.parse_drop_network_policy()
+                } else if w.value.to_uppercase() == "CONNECTION" {
+                    self.parse_drop_connection()
+                } else if w.value.to_uppercase() == "ROLE" {
+                    self.parse_drop_role()
+                } else if w.value.to_uppercase() == "STREAM" {
+                    // a stream is a table of the STREAM engine
+                    self.parse_drop_table()
                 } else {
                     match w.keyword {
                         Keyword::DATABASE => self.parse_drop_database(),
@@ -637,6 +1043,25 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropTable(drop))
     }
 
+    /// Create stream, on a table.
+    fn parse_create_stream(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_object_name()?;
+        self.parser
+            .expect_keywords(&[Keyword::ON, Keyword::TABLE])?;
+        let table = self.parser.parse_object_name()?;
+
+        let create = DfCreateStream {
+            if_not_exists,
+            name,
+            table,
+        };
+
+        Ok(DfStatement::CreateStream(create))
+    }
+
     // Parse 'use database' db name.
     fn parse_use_database(&mut self) -> Result<DfStatement, ParserError> {
         if !self.consume_token("USE") {
@@ -647,6 +1072,31 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::UseDatabase(DfUseDatabase { name }))
     }
 
+    // BEGIN [TRANSACTION | WORK], START TRANSACTION, COMMIT [WORK] or ROLLBACK [WORK]
+    fn parse_transaction(&mut self, keyword: Keyword) -> Result<DfStatement, ParserError> {
+        let action = match keyword {
+            Keyword::BEGIN => {
+                let _ = self
+                    .parser
+                    .parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
+                TransactionAction::Begin
+            }
+            Keyword::START => {
+                self.parser.expect_keyword(Keyword::TRANSACTION)?;
+                TransactionAction::Begin
+            }
+            Keyword::COMMIT => {
+                let _ = self.parser.parse_keyword(Keyword::WORK);
+                TransactionAction::Commit
+            }
+            _ => {
+                let _ = self.parser.parse_keyword(Keyword::WORK);
+                TransactionAction::Rollback
+            }
+        };
+        Ok(DfStatement::Transaction(DfTransaction { action }))
+    }
+
     // Parse 'KILL statement'.
     fn parse_kill<const KILL_QUERY: bool>(&mut self) -> Result<DfStatement, ParserError> {
         Ok(DfStatement::KillStatement(DfKillStatement {
@@ -708,6 +1158,31 @@ impl<'a> DfParser<'a> {
             String::from("")
         };
 
+        if !if_current_user {
+            if self.consume_token("SET") {
+                self.expect_token("NETWORK_POLICY")?;
+                self.parser.expect_token(&Token::Eq)?;
+                let network_policy = Some(self.parser.parse_literal_string()?);
+                return Ok(DfStatement::AlterUserNetworkPolicy(
+                    DfAlterUserNetworkPolicy {
+                        name,
+                        hostname,
+                        network_policy,
+                    },
+                ));
+            }
+            if self.consume_token("UNSET") {
+                self.expect_token("NETWORK_POLICY")?;
+                return Ok(DfStatement::AlterUserNetworkPolicy(
+                    DfAlterUserNetworkPolicy {
+                        name,
+                        hostname,
+                        network_policy: None,
+                    },
+                ));
+            }
+        }
+
         let (password_type, password) = self.get_auth_option()?;
 
         let alter = DfAlterUser {
@@ -751,7 +1226,8 @@ impl<'a> DfParser<'a> {
                     "plaintext_password" => PasswordType::PlainText,
                     "sha256_password" => PasswordType::Sha256,
                     "double_sha1_password" => PasswordType::DoubleSha1,
-                    unexpected => return parser_err!(format!("Expected auth type {}, found: {}", "'no_password'|'plaintext_password'|'sha256_password'|'double_sha1_password'", unexpected))
+                    "pbkdf2_sha256_password" => PasswordType::Pbkdf2Sha256,
+                    unexpected => return parser_err!(format!("Expected auth type {}, found: {}", "'no_password'|'plaintext_password'|'sha256_password'|'double_sha1_password'|'pbkdf2_sha256_password'", unexpected))
                 }
             } else {
                 PasswordType::Sha256
@@ -805,24 +1281,29 @@ impl<'a> DfParser<'a> {
         Ok(credentials)
     }
 
-    fn parse_create_stage(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    fn parse_create_stage(&mut self, or_replace: bool) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists_or_replace(or_replace)?;
         let name = self.parser.parse_literal_string()?;
+        // The stage is internal without URL, its files are kept in the storage of the query.
         let url = if self.consume_token("URL") {
             self.parser.expect_token(&Token::Eq)?;
-            self.parser.parse_literal_string()?
+            let url = self.parser.parse_literal_string()?;
+            if !url.to_uppercase().starts_with("S3") {
+                return parser_err!("Not supported storage");
+            }
+            Some(url)
         } else {
-            return parser_err!("Missing URL");
+            None
         };
 
-        if !url.to_uppercase().starts_with("S3") {
-            return parser_err!("Not supported storage");
-        }
-
-        let credentials = self.parse_stage_credentials()?;
-        let stage_params = StageParams::new(url.as_str(), credentials);
+        // The credentials are either inline or held by a connection, never both.
+        let stage_params = match url {
+            Some(url) => self.parse_external_stage_params(url)?,
+            None if self.consume_token("CREDENTIALS") || self.consume_token("CONNECTION") => {
+                return parser_err!("Missing URL, internal stages take no credentials");
+            }
+            None => StageParams::default(),
+        };
         let file_format = self.parse_stage_file_format()?;
 
         let comments = if self.consume_token("COMMENTS") {
@@ -834,6 +1315,7 @@ impl<'a> DfParser<'a> {
 
         let create = DfCreateStage {
             if_not_exists,
+            or_replace,
             stage_name: name,
             stage_params,
             file_format,
@@ -843,6 +1325,21 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateStage(create))
     }
 
+    fn parse_external_stage_params(&mut self, url: String) -> Result<StageParams, ParserError> {
+        let stage_params = if self.consume_token("CONNECTION") {
+            self.parser.expect_token(&Token::Eq)?;
+            let connection = self.parser.parse_literal_string()?;
+            if self.consume_token("CREDENTIALS") {
+                return parser_err!("CREDENTIALS can not be used with CONNECTION");
+            }
+            StageParams::with_connection(url.as_str(), connection.as_str())
+        } else {
+            let credentials = self.parse_stage_credentials()?;
+            StageParams::new(url.as_str(), credentials)
+        };
+        Ok(stage_params)
+    }
+
     fn parse_drop_stage(&mut self) -> Result<DfStatement, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let stage_name = self.parser.parse_literal_string()?;
@@ -854,6 +1351,207 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropStage(drop))
     }
 
+    fn parse_row_access_policy_keywords(&mut self) -> Result<(), ParserError> {
+        for keyword in ["ACCESS", "POLICY"] {
+            if !self.consume_token(keyword) {
+                return self.expected(keyword, self.parser.peek_token());
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_create_row_access_policy(&mut self) -> Result<DfStatement, ParserError> {
+        self.parse_row_access_policy_keywords()?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+
+        self.parser.expect_keyword(Keyword::ON)?;
+        let table = self.parser.parse_object_name()?;
+
+        let mut users = vec![];
+        let mut roles = vec![];
+        if self.parser.parse_keyword(Keyword::TO) {
+            loop {
+                if self.consume_token("ROLE") {
+                    roles.push(self.parser.parse_literal_string()?);
+                } else {
+                    let (username, hostname) = self.parse_user_identity()?;
+                    users.push(UserIdentity { username, hostname });
+                }
+                if !self.parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
+        }
+
+        self.parser.expect_keyword(Keyword::AS)?;
+        let predicate = self.parser.parse_expr()?;
+
+        let create = DfCreateRowAccessPolicy {
+            if_not_exists,
+            name,
+            table,
+            users,
+            roles,
+            predicate,
+        };
+
+        Ok(DfStatement::CreateRowAccessPolicy(create))
+    }
+
+    fn parse_drop_row_access_policy(&mut self) -> Result<DfStatement, ParserError> {
+        self.parse_row_access_policy_keywords()?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+
+        let drop = DfDropRowAccessPolicy { if_exists, name };
+        Ok(DfStatement::DropRowAccessPolicy(drop))
+    }
+
+    // syntax: "CREATE NETWORK POLICY [IF NOT EXISTS] 'name'
+    //   [ALLOWED_IP_LIST = ('ip' [, ...])] [BLOCKED_IP_LIST = ('ip' [, ...])] [COMMENT = 'comment']"
+    fn parse_create_network_policy(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("POLICY")?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+
+        let mut create = DfCreateNetworkPolicy {
+            if_not_exists,
+            name,
+            allowed_ip_list: vec![],
+            blocked_ip_list: vec![],
+            comment: String::from(""),
+        };
+        loop {
+            if self.consume_token("ALLOWED_IP_LIST") {
+                create.allowed_ip_list = self.parse_ip_list()?;
+            } else if self.consume_token("BLOCKED_IP_LIST") {
+                create.blocked_ip_list = self.parse_ip_list()?;
+            } else if self.consume_token("COMMENT") {
+                self.parser.expect_token(&Token::Eq)?;
+                create.comment = self.parser.parse_literal_string()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(DfStatement::CreateNetworkPolicy(create))
+    }
+
+    fn parse_ip_list(&mut self) -> Result<Vec<String>, ParserError> {
+        self.parser.expect_token(&Token::Eq)?;
+        self.parser.expect_token(&Token::LParen)?;
+        let mut ip_list = vec![];
+        if !self.parser.consume_token(&Token::RParen) {
+            loop {
+                ip_list.push(self.parser.parse_literal_string()?);
+                if !self.parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
+            self.parser.expect_token(&Token::RParen)?;
+        }
+        Ok(ip_list)
+    }
+
+    fn parse_drop_network_policy(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("POLICY")?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+
+        let drop = DfDropNetworkPolicy { if_exists, name };
+        Ok(DfStatement::DropNetworkPolicy(drop))
+    }
+
+    // syntax: "CREATE ROLE [IF NOT EXISTS] 'name'", with CREATE ROLE consumed.
+    fn parse_create_role(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let role_name = self.parser.parse_literal_string()?;
+
+        let create = DfCreateRole {
+            if_not_exists,
+            role_name,
+        };
+        Ok(DfStatement::CreateRole(create))
+    }
+
+    // syntax: "DROP ROLE [IF EXISTS] 'name'", with DROP ROLE consumed.
+    fn parse_drop_role(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let role_name = self.parser.parse_literal_string()?;
+
+        let drop = DfDropRole {
+            if_exists,
+            role_name,
+        };
+        Ok(DfStatement::DropRole(drop))
+    }
+
+    // syntax: "CREATE CONNECTION [IF NOT EXISTS] 'name' STORAGE_TYPE = 's3'
+    // CREDENTIALS = (...) [PARAMS = (...)] [COMMENT = '...']", with CONNECTION consumed.
+    fn parse_create_connection(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+
+        let mut create = DfCreateConnection {
+            if_not_exists,
+            name,
+            storage_type: String::from(""),
+            params: BTreeMap::new(),
+            credentials: BTreeMap::new(),
+            comment: String::from(""),
+        };
+        loop {
+            if self.consume_token("STORAGE_TYPE") {
+                self.parser.expect_token(&Token::Eq)?;
+                create.storage_type = self.parser.parse_literal_string()?;
+            } else if self.consume_token("CREDENTIALS") {
+                create.credentials = self.parse_connection_options()?;
+            } else if self.consume_token("PARAMS") {
+                create.params = self.parse_connection_options()?;
+            } else if self.consume_token("COMMENT") {
+                self.parser.expect_token(&Token::Eq)?;
+                create.comment = self.parser.parse_literal_string()?;
+            } else {
+                break;
+            }
+        }
+
+        if create.storage_type.is_empty() {
+            return parser_err!("Missing STORAGE_TYPE");
+        }
+        Ok(DfStatement::CreateConnection(create))
+    }
+
+    // The option names are case insensitive, they are stored in lower case.
+    fn parse_connection_options(&mut self) -> Result<BTreeMap<String, String>, ParserError> {
+        self.parser.expect_token(&Token::Eq)?;
+        self.parser.expect_token(&Token::LParen)?;
+        let options = self.parse_options()?;
+        self.parser.expect_token(&Token::RParen)?;
+
+        Ok(options
+            .into_iter()
+            .map(|(k, v)| (k.to_lowercase(), v))
+            .collect())
+    }
+
+    fn parse_drop_connection(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+
+        let drop = DfDropConnection { if_exists, name };
+        Ok(DfStatement::DropConnection(drop))
+    }
+
     fn parse_udf_parameters(&mut self) -> Result<Vec<String>, ParserError> {
         let mut params = vec![];
         let mut found_right_paren = false;
@@ -942,10 +1640,8 @@ impl<'a> DfParser<'a> {
         }
     }
 
-    fn parse_create_udf(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    fn parse_create_udf(&mut self, or_replace: bool) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists_or_replace(or_replace)?;
 
         let udf_name = self.parser.parse_literal_string()?;
         self.parser.expect_keyword(Keyword::AS)?;
@@ -957,6 +1653,7 @@ impl<'a> DfParser<'a> {
         let description = self.parse_udf_desc(desc_token)?;
         let create_udf = DfCreateUDF {
             if_not_exists,
+            or_replace,
             udf_name,
             parameters,
             definition,
@@ -968,6 +1665,10 @@ impl<'a> DfParser<'a> {
 
     fn parse_alter_udf(&mut self) -> Result<DfStatement, ParserError> {
         let udf_name = self.parser.parse_literal_string()?;
+        if self.consume_token("OWNER") {
+            return self.parse_alter_owner_to(DfAlterOwnerObject::UDF(udf_name));
+        }
+
         let as_token = Token::make_keyword("AS");
         self.parser.expect_token(&as_token)?;
 
@@ -1004,10 +1705,8 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::ShowUDF(show_udf))
     }
 
-    fn parse_create_table(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    fn parse_create_table(&mut self, or_replace: bool) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists_or_replace(or_replace)?;
         let table_name = self.parser.parse_object_name()?;
 
         // Parse the table which we copy schema from. This is for create table like statement.
@@ -1042,6 +1741,7 @@ impl<'a> DfParser<'a> {
 
         let create = DfCreateTable {
             if_not_exists,
+            or_replace,
             name: table_name,
             columns,
             engine,
@@ -1167,6 +1867,9 @@ impl<'a> DfParser<'a> {
                         self.consume_token("PRIVILEGES");
                         break;
                     }
+                    _ if w.value.to_uppercase() == "USAGE" => {
+                        privileges.set_privilege(UserPrivilegeType::Usage)
+                    }
                     _ => return self.expected("privilege type", Token::Word(w)),
                 },
                 unexpected => return self.expected("privilege type", unexpected),
@@ -1179,6 +1882,20 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_grant(&mut self) -> Result<DfStatement, ParserError> {
+        // GRANT ROLE 'role' TO 'user'@'host'
+        if self.consume_token("ROLE") {
+            let role_name = self.parser.parse_literal_string()?;
+            if !self.parser.parse_keyword(Keyword::TO) {
+                return self.expected("keyword TO", self.parser.peek_token());
+            }
+            let (username, hostname) = self.parse_user_identity()?;
+            return Ok(DfStatement::GrantRole(DfGrantRole {
+                role_name,
+                username,
+                hostname,
+            }));
+        }
+
         let privileges = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
             return self.expected("keyword ON", self.parser.peek_token());
@@ -1187,6 +1904,15 @@ impl<'a> DfParser<'a> {
         if !self.parser.parse_keyword(Keyword::TO) {
             return self.expected("keyword TO", self.parser.peek_token());
         }
+        // GRANT privileges ON object TO ROLE 'role'
+        if self.consume_token("ROLE") {
+            let role_name = self.parser.parse_literal_string()?;
+            return Ok(DfStatement::GrantRolePrivilege(DfGrantRolePrivilege {
+                role_name,
+                priv_types: privileges,
+                on,
+            }));
+        }
         let (name, hostname) = self.parse_user_identity()?;
         let grant = DfGrantStatement {
             name,
@@ -1198,6 +1924,32 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_revoke(&mut self) -> Result<DfStatement, ParserError> {
+        // REVOKE ROLE 'role' FROM 'user'@'host'
+        if self.consume_token("ROLE") {
+            let role_name = self.parser.parse_literal_string()?;
+            if !self.parser.parse_keyword(Keyword::FROM) {
+                return self.expected("keyword FROM", self.parser.peek_token());
+            }
+            let (username, hostname) = self.parse_user_identity()?;
+            return Ok(DfStatement::RevokeRole(DfRevokeRole {
+                role_name,
+                username,
+                hostname,
+            }));
+        }
+
+        // REVOKE ALL [PRIVILEGES], GRANT OPTION FROM 'user'@'host'
+        if self.parser.parse_keyword(Keyword::ALL) {
+            let privileges = self.consume_token("PRIVILEGES");
+            if self.parser.consume_token(&Token::Comma) {
+                return self.parse_revoke_all();
+            }
+            if privileges {
+                self.parser.prev_token();
+            }
+            self.parser.prev_token();
+        }
+
         let privileges = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
             return self.expected("keyword ON", self.parser.peek_token());
@@ -1206,6 +1958,15 @@ impl<'a> DfParser<'a> {
         if !self.parser.parse_keyword(Keyword::FROM) {
             return self.expected("keyword FROM", self.parser.peek_token());
         }
+        // REVOKE privileges ON object FROM ROLE 'role'
+        if self.consume_token("ROLE") {
+            let role_name = self.parser.parse_literal_string()?;
+            return Ok(DfStatement::RevokeRolePrivilege(DfRevokeRolePrivilege {
+                role_name,
+                priv_types: privileges,
+                on,
+            }));
+        }
         let (username, hostname) = self.parse_user_identity()?;
         let revoke = DfRevokeStatement {
             username,
@@ -1216,6 +1977,113 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::RevokePrivilege(revoke))
     }
 
+    fn parse_revoke_all(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.parser.parse_keyword(Keyword::GRANT) {
+            return self.expected("keyword GRANT", self.parser.peek_token());
+        }
+        self.expect_token("OPTION")?;
+        if !self.parser.parse_keyword(Keyword::FROM) {
+            return self.expected("keyword FROM", self.parser.peek_token());
+        }
+        let (username, hostname) = self.parse_user_identity()?;
+        Ok(DfStatement::RevokeAllPrivileges(DfRevokeAllStatement {
+            username,
+            hostname,
+        }))
+    }
+
+    // ALTER {DATABASE | TABLE | STAGE | FUNCTION} <name> OWNER TO 'user'@'host'
+    fn parse_alter_owner(
+        &mut self,
+        object: DfAlterOwnerObject,
+    ) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("OWNER") {
+            return self.expected("keyword OWNER", self.parser.peek_token());
+        }
+        self.parse_alter_owner_to(object)
+    }
+
+    fn parse_alter_owner_to(
+        &mut self,
+        object: DfAlterOwnerObject,
+    ) -> Result<DfStatement, ParserError> {
+        if !self.parser.parse_keyword(Keyword::TO) {
+            return self.expected("keyword TO", self.parser.peek_token());
+        }
+        let (username, hostname) = self.parse_user_identity()?;
+        let alter = DfAlterOwner {
+            object,
+            owner: UserIdentity { username, hostname },
+        };
+        Ok(DfStatement::AlterOwner(alter))
+    }
+
+    // syntax: "ALTER {DATABASE | TABLE} name SET READ_ONLY = {TRUE | FALSE}"
+    // or "ALTER CLUSTER SET MAINTENANCE_MODE = {TRUE | FALSE}", with SET consumed.
+    fn parse_alter_read_only(
+        &mut self,
+        object: DfReadOnlyObject,
+    ) -> Result<DfStatement, ParserError> {
+        match object {
+            DfReadOnlyObject::Cluster => self.expect_token("MAINTENANCE_MODE")?,
+            _ => self.expect_token("READ_ONLY")?,
+        }
+        self.parser.expect_token(&Token::Eq)?;
+        let read_only = match self.parser.next_token() {
+            Token::Word(w) if w.keyword == Keyword::TRUE => true,
+            Token::Word(w) if w.keyword == Keyword::FALSE => false,
+            unexpected => return self.expected("TRUE or FALSE", unexpected),
+        };
+
+        Ok(DfStatement::AlterReadOnly(DfAlterReadOnly {
+            object,
+            read_only,
+        }))
+    }
+
+    // syntax: "ALTER TABLE name SET option = value [option = value ...]", with SET consumed.
+    fn parse_alter_table_options(&mut self, name: ObjectName) -> Result<DfStatement, ParserError> {
+        let options = self.parse_options()?;
+        if options.is_empty() {
+            let token = self.parser.peek_token();
+            return self.expected("table option", token);
+        }
+        Ok(DfStatement::AlterTableOptions(DfAlterTableOptions {
+            name,
+            options,
+        }))
+    }
+
+    // syntax: "ALTER TABLE name ADD [COLUMN] column_def", "ALTER TABLE name DROP [COLUMN] column"
+    // or "ALTER TABLE name RENAME COLUMN column TO new_column", with ADD, DROP or RENAME consumed.
+    fn parse_alter_table_column(
+        &mut self,
+        name: ObjectName,
+        keyword: Keyword,
+    ) -> Result<DfStatement, ParserError> {
+        let operation = match keyword {
+            Keyword::ADD => {
+                self.parser.parse_keyword(Keyword::COLUMN);
+                DfAlterColumnOperation::Add(self.parse_column_def()?)
+            }
+            Keyword::DROP => {
+                self.parser.parse_keyword(Keyword::COLUMN);
+                DfAlterColumnOperation::Drop(self.parser.parse_identifier()?)
+            }
+            _ => {
+                self.parser.expect_keyword(Keyword::COLUMN)?;
+                let from = self.parser.parse_identifier()?;
+                self.parser.expect_keyword(Keyword::TO)?;
+                let to = self.parser.parse_identifier()?;
+                DfAlterColumnOperation::Rename { from, to }
+            }
+        };
+        Ok(DfStatement::AlterTableColumn(DfAlterTableColumn {
+            name,
+            operation,
+        }))
+    }
+
     fn parse_user_identity(&mut self) -> Result<(String, String), ParserError> {
         let username = self.parser.parse_literal_string()?;
         let hostname = if self.consume_token("@") {
@@ -1229,6 +2097,24 @@ impl<'a> DfParser<'a> {
     /// Parse a possibly qualified, possibly quoted identifier or wild card, e.g.
     /// `*` or `myschema`.*. The sub string pattern like "db0%" is not in planned.
     fn parse_grant_object(&mut self) -> Result<DfGrantObject, ParserError> {
+        // CONNECTION 'name'
+        if self.consume_token("CONNECTION") {
+            let name = self.parser.parse_literal_string()?;
+            return Ok(DfGrantObject::Connection(name));
+        }
+
+        // STAGE 'name'
+        if self.consume_token("STAGE") {
+            let name = self.parser.parse_literal_string()?;
+            return Ok(DfGrantObject::Stage(name));
+        }
+
+        // FUNCTION 'name'
+        if self.parser.parse_keyword(Keyword::FUNCTION) {
+            let name = self.parser.parse_literal_string()?;
+            return Ok(DfGrantObject::UDF(name));
+        }
+
         let chunk0 = self.parse_grant_object_pattern_chunk()?;
         // "*" as current db or "table" with current db
         if !self.consume_token(".") {
@@ -1270,6 +2156,10 @@ impl<'a> DfParser<'a> {
     // from @my_ext_stage/tutorials/dataloading/contacts1.csv format CSV [options];
     fn parse_copy(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.expect_keyword(Keyword::INTO)?;
+        if let Token::SingleQuotedString(_) = self.parser.peek_token() {
+            return self.parse_copy_into_stage();
+        }
+
         let name = self.parser.parse_object_name()?;
         let columns = self
             .parser
@@ -1291,6 +2181,31 @@ impl<'a> DfParser<'a> {
         }))
     }
 
+    // copy into '@my_stage/unload/' from {mytable | (select ...)} format parquet max_file_size = 1048576
+    fn parse_copy_into_stage(&mut self) -> Result<DfStatement, ParserError> {
+        let location = self.parser.parse_literal_string()?;
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let source = if self.parser.consume_token(&Token::LParen) {
+            let native_query = self.parser.parse_query()?;
+            self.parser.expect_token(&Token::RParen)?;
+            CopyIntoStageSource::Query(Box::new(DfQueryStatement::try_from(native_query)?))
+        } else {
+            CopyIntoStageSource::Table(self.parser.parse_object_name()?)
+        };
+
+        self.parser.expect_keyword(Keyword::FORMAT)?;
+        let format = self.parser.next_token().to_string();
+
+        let options = self.parse_options()?;
+
+        Ok(DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location,
+            source,
+            format,
+            options,
+        }))
+    }
+
     fn parse_options(&mut self) -> Result<HashMap<String, String>, ParserError> {
         let mut options = HashMap::new();
         loop {
@@ -1336,6 +2251,102 @@ impl<'a> DfParser<'a> {
         }))
     }
 
+    fn parse_undrop(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "UNDROP {DATABASE | TABLE} name"
+        self.expect_token("UNDROP")?;
+        match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::TABLE => Ok(DfStatement::UndropTable(DfUndropTable {
+                    name: self.parser.parse_object_name()?,
+                })),
+                Keyword::DATABASE => Ok(DfStatement::UndropDatabase(DfUndropDatabase {
+                    name: self.parser.parse_object_name()?,
+                })),
+                _ => self.expected("one of DATABASE, TABLE", Token::Word(w)),
+            },
+            unexpected => self.expected("one of DATABASE, TABLE", unexpected),
+        }
+    }
+
+    fn parse_export_table(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "EXPORT TABLE name TO '@stage/path' [SNAPSHOT = 'snapshot_id']"
+        self.expect_token("EXPORT")?;
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::TO)?;
+        let location = self.parser.parse_literal_string()?;
+        let snapshot_id = if self.consume_token("SNAPSHOT") {
+            self.parser.expect_token(&Token::Eq)?;
+            Some(self.parser.parse_literal_string()?)
+        } else {
+            None
+        };
+
+        Ok(DfStatement::ExportTable(DfExportTable {
+            name,
+            location,
+            snapshot_id,
+        }))
+    }
+
+    fn parse_vacuum(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("VACUUM")?;
+        match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::DROP => self.parse_vacuum_drop_table(),
+                Keyword::TABLE => self.parse_vacuum_table(),
+                _ => self.expected("one of DROP, TABLE", Token::Word(w)),
+            },
+            unexpected => self.expected("one of DROP, TABLE", unexpected),
+        }
+    }
+
+    fn parse_vacuum_drop_table(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "VACUUM DROP TABLE [RETAIN n HOURS]"
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let retain_hours = self.parse_retain_hours()?;
+
+        Ok(DfStatement::VacuumDropTable(DfVacuumDropTable {
+            retain_hours,
+        }))
+    }
+
+    fn parse_vacuum_table(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "VACUUM TABLE t [RETAIN n HOURS] [DRY RUN]"
+        let name = self.parser.parse_object_name()?;
+        let retain_hours = self.parse_retain_hours()?;
+        let dry_run = if self.consume_token("DRY") {
+            self.expect_token("RUN")?;
+            true
+        } else {
+            false
+        };
+
+        Ok(DfStatement::VacuumTable(DfVacuumTable {
+            name,
+            retain_hours,
+            dry_run,
+        }))
+    }
+
+    fn parse_analyze(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "ANALYZE TABLE t"
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::AnalyzeTable(DfAnalyzeTable { name }))
+    }
+
+    fn parse_retain_hours(&mut self) -> Result<Option<u64>, ParserError> {
+        if self.consume_token("RETAIN") {
+            let hours = self.parser.parse_literal_uint()?;
+            self.expect_token("HOURS")?;
+            Ok(Some(hours))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn consume_token(&mut self, expected: &str) -> bool {
         if self.parser.peek_token().to_string().to_uppercase() == *expected.to_uppercase() {
             self.parser.next_token();
@@ -20,31 +20,57 @@ use nom::character::complete::multispace1;
 use nom::IResult;
 
 use super::statements::DfCopy;
+use super::statements::DfCopyIntoStage;
 use super::statements::DfDescribeStage;
+use crate::sql::statements::DfAlterOwner;
+use crate::sql::statements::DfAlterReadOnly;
+use crate::sql::statements::DfAlterTableColumn;
+use crate::sql::statements::DfAlterTableOptions;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfAlterUser;
+use crate::sql::statements::DfAlterUserNetworkPolicy;
+use crate::sql::statements::DfAnalyzeTable;
+use crate::sql::statements::DfCreateConnection;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateNetworkPolicy;
+use crate::sql::statements::DfCreateRole;
+use crate::sql::statements::DfCreateRowAccessPolicy;
 use crate::sql::statements::DfCreateStage;
+use crate::sql::statements::DfCreateStream;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
+use crate::sql::statements::DfDropConnection;
 use crate::sql::statements::DfDropDatabase;
+use crate::sql::statements::DfDropNetworkPolicy;
+use crate::sql::statements::DfDropRole;
+use crate::sql::statements::DfDropRowAccessPolicy;
 use crate::sql::statements::DfDropStage;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
 use crate::sql::statements::DfExplain;
+use crate::sql::statements::DfExportTable;
+use crate::sql::statements::DfGrantRole;
+use crate::sql::statements::DfGrantRolePrivilege;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfOptimizeTable;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRevokeAllStatement;
+use crate::sql::statements::DfRevokeRole;
+use crate::sql::statements::DfRevokeRolePrivilege;
 use crate::sql::statements::DfRevokeStatement;
+use crate::sql::statements::DfSetRole;
+use crate::sql::statements::DfSetSecondaryRoles;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
 use crate::sql::statements::DfShowCreateTable;
 use crate::sql::statements::DfShowDatabases;
+use crate::sql::statements::DfShowEngines;
 use crate::sql::statements::DfShowFunctions;
 use crate::sql::statements::DfShowGrants;
 use crate::sql::statements::DfShowMetrics;
@@ -53,8 +79,13 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUDF;
 use crate::sql::statements::DfShowUsers;
+use crate::sql::statements::DfTransaction;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropDatabase;
+use crate::sql::statements::DfUndropTable;
 use crate::sql::statements::DfUseDatabase;
+use crate::sql::statements::DfVacuumDropTable;
+use crate::sql::statements::DfVacuumTable;
 
 /// Tokens parsed by `DFParser` are converted into these values.
 #[derive(Debug, Clone, PartialEq)]
@@ -68,17 +99,26 @@ pub enum DfStatement {
     ShowCreateDatabase(DfShowCreateDatabase),
     CreateDatabase(DfCreateDatabase),
     DropDatabase(DfDropDatabase),
+    UndropDatabase(DfUndropDatabase),
     UseDatabase(DfUseDatabase),
 
     // Tables.
     ShowTables(DfShowTables),
     ShowCreateTable(DfShowCreateTable),
     CreateTable(DfCreateTable),
+    CreateStream(DfCreateStream),
     DescribeTable(DfDescribeTable),
     DescribeStage(DfDescribeStage),
     DropTable(DfDropTable),
+    AlterTableColumn(DfAlterTableColumn),
+    AlterTableOptions(DfAlterTableOptions),
+    UndropTable(DfUndropTable),
+    VacuumDropTable(DfVacuumDropTable),
+    VacuumTable(DfVacuumTable),
+    AnalyzeTable(DfAnalyzeTable),
     TruncateTable(DfTruncateTable),
     OptimizeTable(DfOptimizeTable),
+    ShowEngines(DfShowEngines),
 
     // Settings.
     ShowSettings(DfShowSettings),
@@ -97,10 +137,15 @@ pub enum DfStatement {
 
     // Set
     SetVariable(DfSetVariable),
+    SetRole(DfSetRole),
+    SetSecondaryRoles(DfSetSecondaryRoles),
 
     // Insert
     InsertQuery(DfInsertStatement),
 
+    // Delete
+    Delete(DfDeleteStatement),
+
     // User
     CreateUser(DfCreateUser),
     AlterUser(DfAlterUser),
@@ -109,10 +154,13 @@ pub enum DfStatement {
 
     // Copy
     Copy(DfCopy),
+    CopyIntoStage(DfCopyIntoStage),
+    ExportTable(DfExportTable),
 
     // Grant
     GrantPrivilege(DfGrantStatement),
     RevokePrivilege(DfRevokeStatement),
+    RevokeAllPrivileges(DfRevokeAllStatement),
     ShowGrants(DfShowGrants),
 
     // Stage
@@ -124,6 +172,36 @@ pub enum DfStatement {
     DropUDF(DfDropUDF),
     ShowUDF(DfShowUDF),
     AlterUDF(DfAlterUDF),
+
+    // Row access policy
+    CreateRowAccessPolicy(DfCreateRowAccessPolicy),
+    DropRowAccessPolicy(DfDropRowAccessPolicy),
+
+    // Ownership
+    AlterOwner(DfAlterOwner),
+
+    // Read-only and maintenance mode
+    AlterReadOnly(DfAlterReadOnly),
+
+    // Network policy
+    CreateNetworkPolicy(DfCreateNetworkPolicy),
+    DropNetworkPolicy(DfDropNetworkPolicy),
+    AlterUserNetworkPolicy(DfAlterUserNetworkPolicy),
+
+    // Role
+    CreateRole(DfCreateRole),
+    DropRole(DfDropRole),
+    GrantRole(DfGrantRole),
+    RevokeRole(DfRevokeRole),
+    GrantRolePrivilege(DfGrantRolePrivilege),
+    RevokeRolePrivilege(DfRevokeRolePrivilege),
+
+    // Connection
+    CreateConnection(DfCreateConnection),
+    DropConnection(DfDropConnection),
+
+    // Transaction
+    Transaction(DfTransaction),
 }
 
 /// Comment hints from SQL.
@@ -20,8 +20,13 @@ use common_ast::parser::expr::ExprVisitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::scalars::OverflowMode;
 use common_functions::udfs::UDFTransformer;
+use common_meta_types::OwnershipObject;
 use common_planners::Expression;
+use common_planners::PlanBuilder;
+use common_planners::PlanNode;
+use common_planners::SelectPlan;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::Query;
@@ -50,7 +55,9 @@ impl ExpressionAnalyzer {
         let mut stack = Vec::new();
 
         // Build RPN for expr. because async function unsupported recursion
-        for rpn_item in &ExprRPNBuilder::build(self.context.clone(), expr)? {
+        let (rpn, udfs) = ExprRPNBuilder::build(self.context.clone(), expr)?;
+        self.verify_udf_usage(&udfs).await?;
+        for rpn_item in &rpn {
             match rpn_item {
                 ExprRPNItem::Value(v) => Self::analyze_value(v, &mut stack)?,
                 ExprRPNItem::Identifier(v) => self.analyze_identifier(v, &mut stack)?,
@@ -72,6 +79,21 @@ impl ExpressionAnalyzer {
         }
     }
 
+    // The UDFs of the tenant can only be called by the users allowed to use them.
+    async fn verify_udf_usage(&self, udfs: &[String]) -> Result<()> {
+        if udfs.is_empty() {
+            return Ok(());
+        }
+
+        let user = self.context.get_current_user_with_roles().await.ok();
+        let user_mgr = self.context.get_sessions_manager().get_user_manager();
+        for name in udfs {
+            let object = OwnershipObject::UDF(name.clone());
+            user_mgr.verify_usage(&object, user.as_ref()).await?;
+        }
+        Ok(())
+    }
+
     fn analyze_value(value: &Value, args: &mut Vec<Expression>) -> Result<()> {
         args.push(ValueExprAnalyzer::analyze(value)?);
         Ok(())
@@ -95,7 +117,7 @@ impl ExpressionAnalyzer {
                 true => self.aggr_function(info, &arguments),
                 false => match info.kind {
                     OperatorKind::Unary => Self::unary_function(info, &arguments),
-                    OperatorKind::Binary => Self::binary_function(info, &arguments),
+                    OperatorKind::Binary => self.binary_function(info, &arguments),
                     OperatorKind::Other => self.other_function(info, &arguments),
                 },
             }?,
@@ -115,17 +137,56 @@ impl ExpressionAnalyzer {
         }
     }
 
-    fn binary_function(info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+    fn binary_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
         let op = info.name.clone();
         match args.len() < 2 {
             true => Err(ErrorCode::LogicalError(
                 "Binary operator must be two children.",
             )),
-            false => Ok(Expression::BinaryExpression {
-                op,
-                left: Box::new(args[0].to_owned()),
-                right: Box::new(args[1].to_owned()),
+            false => {
+                let expr = match self.arithmetic_function_name(&op)? {
+                    Some(name) => Expression::ScalarFunction {
+                        op: name,
+                        args: args.to_owned(),
+                    },
+                    None => Expression::BinaryExpression {
+                        op: op.clone(),
+                        left: Box::new(args[0].to_owned()),
+                        right: Box::new(args[1].to_owned()),
+                    },
+                };
+                self.round_division(&op, expr)
+            }
+        }
+    }
+
+    fn overflow_mode(&self) -> Result<OverflowMode> {
+        self.context.get_settings().get_overflow_mode()
+    }
+
+    /// The function computing the arithmetic function `name` in the numeric_overflow_mode and
+    /// error_on_division_by_zero of the session, e.g. checked_plus or checked_divide.
+    fn arithmetic_function_name(&self, name: &str) -> Result<Option<String>> {
+        let settings = self.context.get_settings();
+        Ok(
+            match settings.get_overflow_mode()?.arithmetic_function_name(name) {
+                Some(name) => Some(name),
+                None => settings
+                    .get_division_by_zero_mode()?
+                    .division_function_name(name),
+            },
+        )
+    }
+
+    /// Rounds the result of `/` to div_precision_increment decimal digits.
+    fn round_division(&self, name: &str, expr: Expression) -> Result<Expression> {
+        let precision = self.context.get_settings().get_div_precision_increment()?;
+        match name.to_lowercase().as_str() {
+            "/" | "divide" if precision > 0 => Ok(Expression::ScalarFunction {
+                op: "round".to_string(),
+                args: vec![expr, common_planners::lit(precision as i64)],
             }),
+            _ => Ok(expr),
         }
     }
 
@@ -135,12 +196,16 @@ impl ExpressionAnalyzer {
 
         match context_args.is_empty() {
             true => {
-                let op = info.name.clone();
+                let op = match self.arithmetic_function_name(&info.name)? {
+                    Some(op) if args.len() == 2 => op,
+                    _ => info.name.clone(),
+                };
                 let arguments = args.to_owned();
-                Ok(Expression::ScalarFunction {
+                let expr = Expression::ScalarFunction {
                     op,
                     args: arguments,
-                })
+                };
+                self.round_division(&info.name, expr)
             }
             false => {
                 let op = info.name.clone();
@@ -169,6 +234,11 @@ impl ExpressionAnalyzer {
             };
         }
 
+        let op = match self.overflow_mode()?.aggregate_function_name(&info.name) {
+            Some(op) => op,
+            None => info.name.clone(),
+        };
+
         if info.name.eq_ignore_ascii_case("count")
             && !args.is_empty()
             && matches!(args[0], Expression::Wildcard)
@@ -181,7 +251,7 @@ impl ExpressionAnalyzer {
             })
         } else {
             Ok(Expression::AggregateFunction {
-                op: info.name.clone(),
+                op,
                 distinct: info.distinct,
                 args: args.to_owned(),
                 params: parameters,
@@ -207,14 +277,30 @@ impl ExpressionAnalyzer {
     }
 
     async fn analyze_exists(&self, subquery: &Query, args: &mut Vec<Expression>) -> Result<()> {
-        let subquery = vec![self.analyze_subquery(subquery).await?];
+        // Only whether there is any row matters, the subquery stops at the first one.
+        let subquery = match self.analyze_subquery(subquery).await? {
+            Expression::Subquery { name, query_plan } => Expression::Subquery {
+                name,
+                query_plan: Arc::new(Self::first_row_plan(query_plan.as_ref())?),
+            },
+            other => other,
+        };
         args.push(Expression::ScalarFunction {
             op: "EXISTS".to_lowercase(),
-            args: subquery,
+            args: vec![subquery],
         });
         Ok(())
     }
 
+    fn first_row_plan(plan: &PlanNode) -> Result<PlanNode> {
+        match plan {
+            PlanNode::Select(select) => Ok(PlanNode::Select(SelectPlan {
+                input: Arc::new(Self::first_row_plan(select.input.as_ref())?),
+            })),
+            _ => PlanBuilder::from(plan).limit(1)?.build(),
+        }
+    }
+
     async fn analyze_subquery(&self, subquery: &Query) -> Result<Expression> {
         let statement = DfQueryStatement::try_from(subquery.clone())?;
 
@@ -376,16 +462,22 @@ impl ExprRPNItem {
 struct ExprRPNBuilder {
     rpn: Vec<ExprRPNItem>,
     context: Arc<QueryContext>,
+    // The UDFs of the tenant expanded into the RPN.
+    udfs: Vec<String>,
 }
 
 impl ExprRPNBuilder {
-    pub fn build(context: Arc<QueryContext>, expr: &Expr) -> Result<Vec<ExprRPNItem>> {
+    pub fn build(
+        context: Arc<QueryContext>,
+        expr: &Expr,
+    ) -> Result<(Vec<ExprRPNItem>, Vec<String>)> {
         let mut builder = ExprRPNBuilder {
             context,
             rpn: Vec::new(),
+            udfs: Vec::new(),
         };
         ExprTraverser::accept(expr, &mut builder)?;
-        Ok(builder.rpn)
+        Ok((builder.rpn, builder.udfs))
     }
 
     fn process_expr(&mut self, expr: &Expr) -> Result<()> {
@@ -493,10 +585,19 @@ impl ExprRPNBuilder {
 impl ExprVisitor for ExprRPNBuilder {
     fn pre_visit(&mut self, expr: &Expr) -> Result<Expr> {
         if let Expr::Function(function) = expr {
+            // the functions defined by the query shadow the ones of the tenant
+            if let Some(definition) = self.context.get_temp_function(&function.name.to_string()) {
+                return UDFTransformer::transform_with_definition(&definition, function);
+            }
+
             if let Ok(Some(transformed_expr)) = UDFTransformer::transform_function(
                 self.context.get_config().query.tenant_id.as_str(),
                 function,
             ) {
+                let name = function.name.to_string();
+                if !self.udfs.contains(&name) {
+                    self.udfs.push(name);
+                }
                 return Ok(transformed_expr);
             }
         }
@@ -21,6 +21,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::ExplainType;
 use common_planners::Expression;
+use common_planners::JoinType;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 
@@ -39,6 +40,20 @@ pub enum QueryRelation {
     None,
     FromTable(Box<ReadDataSourcePlan>),
     Nested(Box<QueryAnalyzeState>),
+    Join(Box<QueryJoinRelation>),
+}
+
+/// The equi-join of two relations. The columns of a side whose names are ambiguous in the
+/// join are renamed to their full names by its projection, empty if none is.
+#[derive(Clone)]
+pub struct QueryJoinRelation {
+    pub join_type: JoinType,
+    pub left: QueryRelation,
+    pub left_projection: Vec<Expression>,
+    pub left_keys: Vec<Expression>,
+    pub right: QueryRelation,
+    pub right_projection: Vec<Expression>,
+    pub right_keys: Vec<Expression>,
 }
 
 #[derive(Clone)]
@@ -54,6 +69,7 @@ pub struct QueryAnalyzeState {
     pub aggregate_expressions: Vec<Expression>,
     pub before_group_by_expressions: Vec<Expression>,
 
+    pub distinct: bool,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 
@@ -86,6 +102,7 @@ impl Default for QueryAnalyzeState {
             group_by_expressions: vec![],
             aggregate_expressions: vec![],
             before_group_by_expressions: vec![],
+            distinct: false,
             limit: None,
             offset: None,
             relation: QueryRelation::None,
@@ -133,6 +150,10 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("projection", &self.projection_expressions);
         }
 
+        if self.distinct {
+            debug_struct.field("distinct", &self.distinct);
+        }
+
         debug_struct.finish()
     }
 }
@@ -152,12 +173,19 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowCreateDatabase(v) => v.analyze(ctx).await,
             DfStatement::CreateDatabase(v) => v.analyze(ctx).await,
             DfStatement::DropDatabase(v) => v.analyze(ctx).await,
+            DfStatement::UndropDatabase(v) => v.analyze(ctx).await,
             DfStatement::CreateTable(v) => v.analyze(ctx).await,
+            DfStatement::CreateStream(v) => v.analyze(ctx).await,
             DfStatement::DescribeTable(v) => v.analyze(ctx).await,
             DfStatement::DescribeStage(v) => v.analyze(ctx).await,
             DfStatement::DropTable(v) => v.analyze(ctx).await,
+            DfStatement::UndropTable(v) => v.analyze(ctx).await,
+            DfStatement::VacuumDropTable(v) => v.analyze(ctx).await,
+            DfStatement::VacuumTable(v) => v.analyze(ctx).await,
+            DfStatement::AnalyzeTable(v) => v.analyze(ctx).await,
             DfStatement::TruncateTable(v) => v.analyze(ctx).await,
             DfStatement::OptimizeTable(v) => v.analyze(ctx).await,
+            DfStatement::ShowEngines(v) => v.analyze(ctx).await,
             DfStatement::UseDatabase(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateTable(v) => v.analyze(ctx).await,
             DfStatement::ShowTables(v) => v.analyze(ctx).await,
@@ -167,14 +195,20 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowGrants(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
+            DfStatement::Delete(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
+            DfStatement::SetRole(v) => v.analyze(ctx).await,
+            DfStatement::SetSecondaryRoles(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
             DfStatement::AlterUser(v) => v.analyze(ctx).await,
             DfStatement::ShowUsers(v) => v.analyze(ctx).await,
             DfStatement::GrantPrivilege(v) => v.analyze(ctx).await,
             DfStatement::RevokePrivilege(v) => v.analyze(ctx).await,
+            DfStatement::RevokeAllPrivileges(v) => v.analyze(ctx).await,
             DfStatement::DropUser(v) => v.analyze(ctx).await,
             DfStatement::Copy(v) => v.analyze(ctx).await,
+            DfStatement::CopyIntoStage(v) => v.analyze(ctx).await,
+            DfStatement::ExportTable(v) => v.analyze(ctx).await,
             DfStatement::CreateStage(v) => v.analyze(ctx).await,
             DfStatement::ShowFunctions(v) => v.analyze(ctx).await,
             DfStatement::DropStage(v) => v.analyze(ctx).await,
@@ -182,6 +216,24 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::DropUDF(v) => v.analyze(ctx).await,
             DfStatement::ShowUDF(v) => v.analyze(ctx).await,
             DfStatement::AlterUDF(v) => v.analyze(ctx).await,
+            DfStatement::CreateRowAccessPolicy(v) => v.analyze(ctx).await,
+            DfStatement::DropRowAccessPolicy(v) => v.analyze(ctx).await,
+            DfStatement::AlterOwner(v) => v.analyze(ctx).await,
+            DfStatement::AlterReadOnly(v) => v.analyze(ctx).await,
+            DfStatement::AlterTableColumn(v) => v.analyze(ctx).await,
+            DfStatement::AlterTableOptions(v) => v.analyze(ctx).await,
+            DfStatement::CreateNetworkPolicy(v) => v.analyze(ctx).await,
+            DfStatement::DropNetworkPolicy(v) => v.analyze(ctx).await,
+            DfStatement::AlterUserNetworkPolicy(v) => v.analyze(ctx).await,
+            DfStatement::CreateRole(v) => v.analyze(ctx).await,
+            DfStatement::DropRole(v) => v.analyze(ctx).await,
+            DfStatement::GrantRole(v) => v.analyze(ctx).await,
+            DfStatement::RevokeRole(v) => v.analyze(ctx).await,
+            DfStatement::GrantRolePrivilege(v) => v.analyze(ctx).await,
+            DfStatement::RevokeRolePrivilege(v) => v.analyze(ctx).await,
+            DfStatement::CreateConnection(v) => v.analyze(ctx).await,
+            DfStatement::DropConnection(v) => v.analyze(ctx).await,
+            DfStatement::Transaction(v) => v.analyze(ctx).await,
         }
     }
 }
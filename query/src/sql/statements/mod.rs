@@ -17,33 +17,59 @@ pub mod query;
 mod analyzer_expr;
 mod analyzer_statement;
 mod analyzer_value_expr;
+mod statement_alter_owner;
+mod statement_alter_read_only;
+mod statement_alter_table_column;
+mod statement_alter_table_options;
 mod statement_alter_udf;
 mod statement_alter_user;
+mod statement_alter_user_network_policy;
+mod statement_analyze_table;
 mod statement_copy;
+mod statement_copy_into_stage;
+mod statement_create_connection;
 mod statement_create_database;
+mod statement_create_network_policy;
+mod statement_create_role;
+mod statement_create_row_access_policy;
 mod statement_create_stage;
+mod statement_create_stream;
 mod statement_create_table;
 mod statement_create_udf;
 mod statement_create_user;
+mod statement_delete;
 mod statement_describe_stage;
 mod statement_describe_table;
+mod statement_drop_connection;
 mod statement_drop_database;
+mod statement_drop_network_policy;
+mod statement_drop_role;
+mod statement_drop_row_access_policy;
 mod statement_drop_stage;
 mod statement_drop_table;
 mod statement_drop_udf;
 mod statement_drop_user;
 mod statement_explain;
+mod statement_export_table;
 mod statement_grant;
+mod statement_grant_role;
+mod statement_grant_role_privilege;
 mod statement_insert;
 mod statement_kill;
 mod statement_optimize_table;
 mod statement_revoke;
+mod statement_revoke_all;
+mod statement_revoke_role;
+mod statement_revoke_role_privilege;
 mod statement_select;
 mod statement_select_convert;
+mod statement_set_role;
+mod statement_set_secondary_roles;
 mod statement_set_variable;
 mod statement_show_create_database;
 mod statement_show_create_table;
 mod statement_show_databases;
+mod statement_show_engines;
 mod statement_show_functions;
 mod statement_show_grants;
 mod statement_show_metrics;
@@ -52,41 +78,78 @@ mod statement_show_settings;
 mod statement_show_tables;
 mod statement_show_udf;
 mod statement_show_users;
+mod statement_transaction;
 mod statement_truncate_table;
+mod statement_undrop_database;
+mod statement_undrop_table;
 mod statement_use_database;
+mod statement_vacuum_drop_table;
+mod statement_vacuum_table;
 
 pub use analyzer_statement::AnalyzableStatement;
 pub use analyzer_statement::AnalyzedResult;
 pub use analyzer_statement::QueryAnalyzeState;
+pub use analyzer_statement::QueryJoinRelation;
 pub use analyzer_statement::QueryRelation;
 pub use query::QueryASTIR;
+pub use statement_alter_owner::DfAlterOwner;
+pub use statement_alter_owner::DfAlterOwnerObject;
+pub use statement_alter_read_only::DfAlterReadOnly;
+pub use statement_alter_read_only::DfReadOnlyObject;
+pub use statement_alter_table_column::DfAlterColumnOperation;
+pub use statement_alter_table_column::DfAlterTableColumn;
+pub use statement_alter_table_options::DfAlterTableOptions;
 pub use statement_alter_udf::DfAlterUDF;
 pub use statement_alter_user::DfAlterUser;
+pub use statement_alter_user_network_policy::DfAlterUserNetworkPolicy;
+pub use statement_analyze_table::DfAnalyzeTable;
 pub use statement_copy::DfCopy;
+pub use statement_copy_into_stage::CopyIntoStageSource;
+pub use statement_copy_into_stage::DfCopyIntoStage;
+pub use statement_create_connection::DfCreateConnection;
 pub use statement_create_database::DfCreateDatabase;
+pub use statement_create_network_policy::DfCreateNetworkPolicy;
+pub use statement_create_role::DfCreateRole;
+pub use statement_create_row_access_policy::DfCreateRowAccessPolicy;
 pub use statement_create_stage::DfCreateStage;
+pub use statement_create_stream::DfCreateStream;
 pub use statement_create_table::DfCreateTable;
 pub use statement_create_udf::DfCreateUDF;
 pub use statement_create_user::DfCreateUser;
+pub use statement_delete::DfDeleteStatement;
 pub use statement_describe_stage::DfDescribeStage;
 pub use statement_describe_table::DfDescribeTable;
+pub use statement_drop_connection::DfDropConnection;
 pub use statement_drop_database::DfDropDatabase;
+pub use statement_drop_network_policy::DfDropNetworkPolicy;
+pub use statement_drop_role::DfDropRole;
+pub use statement_drop_row_access_policy::DfDropRowAccessPolicy;
 pub use statement_drop_stage::DfDropStage;
 pub use statement_drop_table::DfDropTable;
 pub use statement_drop_udf::DfDropUDF;
 pub use statement_drop_user::DfDropUser;
 pub use statement_explain::DfExplain;
+pub use statement_export_table::DfExportTable;
 pub use statement_grant::DfGrantObject;
 pub use statement_grant::DfGrantStatement;
+pub use statement_grant_role::DfGrantRole;
+pub use statement_grant_role_privilege::DfGrantRolePrivilege;
 pub use statement_insert::DfInsertStatement;
 pub use statement_kill::DfKillStatement;
 pub use statement_optimize_table::DfOptimizeTable;
 pub use statement_revoke::DfRevokeStatement;
+pub use statement_revoke_all::DfRevokeAllStatement;
+pub use statement_revoke_role::DfRevokeRole;
+pub use statement_revoke_role_privilege::DfRevokeRolePrivilege;
+pub use statement_select::DfQueryFunction;
 pub use statement_select::DfQueryStatement;
+pub use statement_set_role::DfSetRole;
+pub use statement_set_secondary_roles::DfSetSecondaryRoles;
 pub use statement_set_variable::DfSetVariable;
 pub use statement_show_create_database::DfShowCreateDatabase;
 pub use statement_show_create_table::DfShowCreateTable;
 pub use statement_show_databases::DfShowDatabases;
+pub use statement_show_engines::DfShowEngines;
 pub use statement_show_functions::DfShowFunctions;
 pub use statement_show_grants::DfShowGrants;
 pub use statement_show_metrics::DfShowMetrics;
@@ -95,5 +158,10 @@ pub use statement_show_settings::DfShowSettings;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_udf::DfShowUDF;
 pub use statement_show_users::DfShowUsers;
+pub use statement_transaction::DfTransaction;
 pub use statement_truncate_table::DfTruncateTable;
+pub use statement_undrop_database::DfUndropDatabase;
+pub use statement_undrop_table::DfUndropTable;
 pub use statement_use_database::DfUseDatabase;
+pub use statement_vacuum_drop_table::DfVacuumDropTable;
+pub use statement_vacuum_table::DfVacuumTable;
@@ -16,15 +16,22 @@ mod query_ast_ir;
 mod query_collect_push_downs;
 mod query_normalizer;
 mod query_qualified_rewriter;
+mod query_row_access_policy_rewriter;
 mod query_schema_joined;
 mod query_schema_joined_analyzer;
+mod query_tables_collector;
 
 pub use query_ast_ir::QueryASTIR;
 pub use query_ast_ir::QueryASTIRVisitor;
 pub use query_collect_push_downs::QueryCollectPushDowns;
 pub use query_normalizer::QueryNormalizer;
 pub use query_qualified_rewriter::QualifiedRewriter;
+pub use query_row_access_policy_rewriter::RowAccessPolicyRewriter;
 pub use query_schema_joined::JoinedColumnDesc;
+pub use query_schema_joined::JoinedRelation;
 pub use query_schema_joined::JoinedSchema;
 pub use query_schema_joined::JoinedTableDesc;
+pub use query_schema_joined_analyzer::table_options;
 pub use query_schema_joined_analyzer::JoinedSchemaAnalyzer;
+pub use query_schema_joined_analyzer::TableOptions;
+pub use query_tables_collector::QueryTablesCollector;
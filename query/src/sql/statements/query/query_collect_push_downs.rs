@@ -55,19 +55,33 @@ impl QueryCollectPushDowns {
             require_filters: vec![],
         };
         QueryCollectPushDowns::visit(ir, &mut push_downs_data)?;
+        // The keys of the joins are read too.
+        for condition in schema.join_conditions_mut() {
+            QueryCollectPushDowns::visit_recursive_expr(condition, &mut push_downs_data)?;
+        }
         push_downs_data.collect_push_downs(schema)
     }
 
     fn collect_push_downs(mut self, schema: &mut JoinedSchema) -> Result<()> {
+        // The filter is applied to the joined rows, it is not pushed down to the tables.
+        if schema.get_tables_desc().len() > 1 {
+            self.require_filters.clear();
+        }
+
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
             let projection = self.collect_table_require_columns(table_desc);
+            // the sampling of the table, set by the analysis of its options
+            let sample = table_desc
+                .get_push_downs()
+                .and_then(|extras| extras.sample.clone());
 
             schema.set_table_push_downs(index, Extras {
                 projection: Some(projection),
                 filters: self.require_filters.clone(),
                 limit: None,
                 order_by: vec![],
+                sample,
             });
         }
 
@@ -75,9 +89,11 @@ impl QueryCollectPushDowns {
     }
 
     fn collect_table_require_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
-        match self.require_columns.is_empty() {
+        let projection = self.collect_table_projection_columns(table_desc);
+        // A joined table may have none of the columns, it is still read for its rows.
+        match projection.is_empty() {
             true => Self::collect_table_smallest_column(table_desc),
-            false => self.collect_table_projection_columns(table_desc),
+            false => projection,
         }
     }
 
@@ -87,6 +103,10 @@ impl QueryCollectPushDowns {
         let mut smallest_size = usize::MAX;
         let columns_desc = table_desc.get_columns_desc();
         for (column_index, column_desc) in columns_desc.iter().enumerate() {
+            if column_desc.is_virtual {
+                continue;
+            }
+
             if let Ok(bytes) = column_desc.data_type.numeric_byte_size() {
                 if smallest_size > bytes {
                     smallest_size = bytes;
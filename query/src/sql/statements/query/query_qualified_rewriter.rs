@@ -82,9 +82,28 @@ impl QualifiedRewriter {
         QualifiedRewriter::visit(ir, &mut rewriter)
     }
 
+    /// The columns of the conditions of the joins are resolved as the ones of the query.
+    pub fn rewrite_join_conditions(
+        schema: &mut JoinedSchema,
+        ctx: Arc<QueryContext>,
+    ) -> Result<()> {
+        let mut rewriter = QualifiedRewriter {
+            tables_schema: schema.clone(),
+            ctx,
+        };
+        for condition in schema.join_conditions_mut() {
+            QualifiedRewriter::visit_recursive_expr(condition, &mut rewriter)?;
+        }
+        Ok(())
+    }
+
     fn expand_wildcard(&self, columns_expression: &mut Vec<Expression>) {
         for table_desc in self.tables_schema.get_tables_desc() {
             for column_desc in table_desc.get_columns_desc() {
+                if column_desc.is_virtual {
+                    continue;
+                }
+
                 let name = column_desc.short_name.clone();
                 match column_desc.is_ambiguity {
                     true => {
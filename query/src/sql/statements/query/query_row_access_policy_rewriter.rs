@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+use sqlparser::ast::BinaryOperator;
+use sqlparser::ast::Expr;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::query::table_options;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfParser;
+
+/// Injects the predicates of the row access policies protecting the scanned table
+/// into the selection of the query, so that a user only sees the permitted rows.
+pub struct RowAccessPolicyRewriter;
+
+impl RowAccessPolicyRewriter {
+    /// Returns the protected query, or `None` if no policy applies to the query.
+    pub async fn rewrite(
+        ctx: Arc<QueryContext>,
+        query: &DfQueryStatement,
+    ) -> Result<Option<DfQueryStatement>> {
+        let (database, table) = match Self::scanned_table(&ctx, &query.from) {
+            None => {
+                Self::verify_joined_tables(&ctx, &query.from).await?;
+                return Ok(None);
+            }
+            Some(scanned_table) => scanned_table,
+        };
+
+        let policies = Self::applied_policies(&ctx, &database, &table).await?;
+        if policies.is_empty() {
+            return Ok(None);
+        }
+
+        let mut selection = query.selection.clone();
+        for policy in policies {
+            let predicate = DfParser::parse_expr(&policy.predicate).map_err(|cause| {
+                cause.add_message_back(format!(
+                    " (while in parse row access policy {})",
+                    policy.name
+                ))
+            })?;
+
+            selection = Some(match selection {
+                None => Expr::Nested(Box::new(predicate)),
+                Some(expr) => Expr::BinaryOp {
+                    left: Box::new(Expr::Nested(Box::new(expr))),
+                    op: BinaryOperator::And,
+                    right: Box::new(Expr::Nested(Box::new(predicate))),
+                },
+            });
+        }
+
+        let mut protected_query = query.clone();
+        protected_query.selection = selection;
+        Ok(Some(protected_query))
+    }
+
+    // The policies protecting the table which apply to the current user.
+    async fn applied_policies(
+        ctx: &QueryContext,
+        database: &str,
+        table: &str,
+    ) -> Result<Vec<RowAccessPolicy>> {
+        let user_mgr = ctx.get_sessions_manager().get_user_manager();
+        let policies = user_mgr
+            .get_row_access_policies()
+            .await?
+            .into_iter()
+            .filter(|policy| policy.is_protecting(database, table))
+            .collect::<Vec<_>>();
+
+        if policies.is_empty() {
+            return Ok(policies);
+        }
+
+        // A protected table is never readable without knowing who is reading.
+        let user = ctx.get_current_user()?;
+        let active_roles = ctx.get_active_roles();
+        Ok(policies
+            .into_iter()
+            .filter(|policy| policy.applies_to(&user.name, &user.hostname, &active_roles))
+            .collect())
+    }
+
+    // The predicates are not injected into the joins, a joined table the user may only read
+    // in part is refused instead of read in full.
+    async fn verify_joined_tables(ctx: &QueryContext, from: &[TableWithJoins]) -> Result<()> {
+        let mut tables = vec![];
+        for table_with_joins in from {
+            Self::joined_tables(ctx, table_with_joins, &mut tables);
+        }
+
+        for (database, table) in tables {
+            if !Self::applied_policies(ctx, &database, &table)
+                .await?
+                .is_empty()
+            {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, {}.{} is protected by a row access policy and cannot be joined",
+                    database, table
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn joined_tables(
+        ctx: &QueryContext,
+        table_with_joins: &TableWithJoins,
+        tables: &mut Vec<(String, String)>,
+    ) {
+        let factors = std::iter::once(&table_with_joins.relation)
+            .chain(table_with_joins.joins.iter().map(|join| &join.relation));
+        for factor in factors {
+            match factor {
+                TableFactor::Table { name, args, .. }
+                    if args.is_empty() || is_table_options(args) =>
+                {
+                    match name.0.len() {
+                        1 => tables.push((ctx.get_current_database(), name.0[0].value.clone())),
+                        2 => tables.push((name.0[0].value.clone(), name.0[1].value.clone())),
+                        _ => {}
+                    }
+                }
+                TableFactor::NestedJoin(joins) => Self::joined_tables(ctx, joins, tables),
+                _ => {}
+            }
+        }
+    }
+
+    // Only a plain scan of a single table can be protected, the subqueries are analyzed
+    // as queries of their own and rewritten there. The scan of a table at a point of its
+    // history, or of a sample of it, is protected as well.
+    fn scanned_table(ctx: &QueryContext, from: &[TableWithJoins]) -> Option<(String, String)> {
+        match from {
+            [TableWithJoins {
+                relation: TableFactor::Table { name, args, .. },
+                joins,
+            }] if joins.is_empty() && (args.is_empty() || is_table_options(args)) => {
+                match name.0.len() {
+                    1 => Some((ctx.get_current_database(), name.0[0].value.clone())),
+                    2 => Some((name.0[0].value.clone(), name.0[1].value.clone())),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+// Illegal options are still options, the query fails in the analysis of the table.
+fn is_table_options(args: &[FunctionArg]) -> bool {
+    !matches!(table_options(args), Ok(None))
+}
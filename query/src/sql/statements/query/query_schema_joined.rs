@@ -24,7 +24,9 @@ use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::JoinType;
 
 use crate::sql::statements::QueryAnalyzeState;
 use crate::storages::Table;
@@ -35,6 +37,47 @@ pub struct JoinedSchema {
     short_name_columns: HashMap<String, JoinedColumnDesc>,
     // Reference by full name, short name may be ambiguous.
     tables_long_name_columns: Vec<JoinedTableDesc>,
+    // How the tables are joined, a single table for no join.
+    relation: JoinedRelation,
+}
+
+/// The joins of the tables of a schema, by the positions of the tables.
+#[derive(Clone, Debug)]
+pub enum JoinedRelation {
+    Table(usize),
+    Join {
+        join_type: JoinType,
+        left: Box<JoinedRelation>,
+        right: Box<JoinedRelation>,
+        // The condition of `ON`, equalities of columns of both sides joined by `AND`.
+        condition: Expression,
+    },
+}
+
+impl JoinedRelation {
+    fn shift_tables(&mut self, offset: usize) {
+        match self {
+            JoinedRelation::Table(pos) => *pos += offset,
+            JoinedRelation::Join { left, right, .. } => {
+                left.shift_tables(offset);
+                right.shift_tables(offset);
+            }
+        }
+    }
+
+    fn collect_conditions<'a>(&'a mut self, conditions: &mut Vec<&'a mut Expression>) {
+        if let JoinedRelation::Join {
+            left,
+            right,
+            condition,
+            ..
+        } = self
+        {
+            left.collect_conditions(conditions);
+            right.collect_conditions(conditions);
+            conditions.push(condition);
+        }
+    }
 }
 
 impl JoinedSchema {
@@ -42,6 +85,7 @@ impl JoinedSchema {
         JoinedSchema {
             short_name_columns: HashMap::new(),
             tables_long_name_columns: Vec::new(),
+            relation: JoinedRelation::Table(0),
         }
     }
 
@@ -78,6 +122,7 @@ impl JoinedSchema {
         Ok(JoinedSchema {
             short_name_columns,
             tables_long_name_columns: vec![table_desc],
+            relation: JoinedRelation::Table(0),
         })
     }
 
@@ -100,6 +145,17 @@ impl JoinedSchema {
         self.tables_long_name_columns
     }
 
+    pub fn get_relation(&self) -> &JoinedRelation {
+        &self.relation
+    }
+
+    /// The conditions of the joins, innermost first.
+    pub fn join_conditions_mut(&mut self) -> Vec<&mut Expression> {
+        let mut conditions = vec![];
+        self.relation.collect_conditions(&mut conditions);
+        conditions
+    }
+
     pub fn to_data_schema(&self) -> DataSchemaRef {
         let mut fields = Vec::with_capacity(self.short_name_columns.len());
 
@@ -129,8 +185,66 @@ impl JoinedSchema {
         Arc::new(DataSchema::new(fields))
     }
 
-    pub fn join(&self, _joined_schema: &JoinedSchema) -> Result<Arc<JoinedSchema>> {
-        unimplemented!("")
+    /// Joins the tables of the schema to the ones of `right`. The columns of the side filled with
+    /// nulls become nullable, and the columns of both sides with the same name are ambiguous,
+    /// they are only referenced by their full names.
+    pub fn join(
+        self,
+        right: JoinedSchema,
+        join_type: JoinType,
+        condition: Expression,
+    ) -> Result<JoinedSchema> {
+        let mut tables_desc = self.tables_long_name_columns;
+        for table_desc in &right.tables_long_name_columns {
+            let name_parts = table_desc.get_name_parts();
+            if tables_desc.iter().any(|v| v.get_name_parts() == name_parts) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Not unique table/alias: '{}', alias the tables of the join",
+                    name_parts.join(".")
+                )));
+            }
+        }
+
+        let mut right_relation = right.relation;
+        right_relation.shift_tables(tables_desc.len());
+        let left_tables = tables_desc.len();
+        tables_desc.extend(right.tables_long_name_columns);
+
+        let mut names_count = HashMap::<String, usize>::new();
+        for table_desc in &tables_desc {
+            for column_desc in table_desc.get_columns_desc() {
+                *names_count
+                    .entry(column_desc.short_name.clone())
+                    .or_default() += 1;
+            }
+        }
+
+        let mut short_name_columns = HashMap::new();
+        for (pos, table_desc) in tables_desc.iter_mut().enumerate() {
+            let nullable = match pos < left_tables {
+                true => join_type.keeps_right(),
+                false => join_type.keeps_left(),
+            };
+
+            for column_desc in table_desc.get_columns_desc_mut() {
+                column_desc.nullable |= nullable;
+                column_desc.is_ambiguity = names_count[&column_desc.short_name] > 1;
+                if !column_desc.is_ambiguity {
+                    short_name_columns.insert(column_desc.short_name.clone(), column_desc.clone());
+                }
+            }
+        }
+
+        Ok(JoinedSchema {
+            short_name_columns,
+            tables_long_name_columns: tables_desc,
+            relation: JoinedRelation::Join {
+                join_type,
+                left: Box::new(self.relation),
+                right: Box::new(right_relation),
+                condition,
+            },
+        })
     }
 }
 
@@ -190,6 +304,13 @@ impl JoinedTableDesc {
             columns_desc.push(JoinedColumnDesc::from_field(data_field, false));
         }
 
+        // addressed by the indices following the ones of the columns of the table
+        for data_field in table.virtual_columns() {
+            let mut column_desc = JoinedColumnDesc::from_field(&data_field, false);
+            column_desc.is_virtual = true;
+            columns_desc.push(column_desc);
+        }
+
         JoinedTableDesc::Table {
             table,
             columns_desc,
@@ -226,6 +347,48 @@ impl JoinedTableDesc {
             JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
         }
     }
+
+    fn get_columns_desc_mut(&mut self) -> &mut [JoinedColumnDesc] {
+        match self {
+            JoinedTableDesc::Table { columns_desc, .. } => columns_desc,
+            JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
+        }
+    }
+
+    /// The columns of the schema of the table read, the ones ambiguous in the join renamed to
+    /// their full names. Empty if no column is renamed.
+    pub fn qualified_columns(&self, schema: &DataSchemaRef) -> Vec<Expression> {
+        let prefix = self.get_name_parts().join(".");
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        let mut renamed = false;
+        for field in schema.fields() {
+            let column = Expression::Column(field.name().clone());
+            let ambiguous = self
+                .get_columns_desc()
+                .iter()
+                .any(|v| v.is_ambiguity && &v.short_name == field.name());
+            match ambiguous {
+                true => {
+                    renamed = true;
+                    let name = format!("{}.{}", prefix, field.name());
+                    columns.push(Expression::Alias(name, Box::new(column)));
+                }
+                false => columns.push(column),
+            }
+        }
+
+        match renamed {
+            true => columns,
+            false => vec![],
+        }
+    }
+
+    pub fn get_push_downs(&self) -> Option<&Extras> {
+        match self {
+            JoinedTableDesc::Table { push_downs, .. } => push_downs.as_ref(),
+            JoinedTableDesc::Subquery { .. } => None,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -234,6 +397,8 @@ pub struct JoinedColumnDesc {
     pub data_type: DataType,
     pub nullable: bool,
     pub is_ambiguity: bool,
+    // Computed by the table read and left out of `*`.
+    pub is_virtual: bool,
 }
 
 impl JoinedColumnDesc {
@@ -243,6 +408,7 @@ impl JoinedColumnDesc {
             data_type: field.data_type().clone(),
             nullable: field.is_nullable(),
             is_ambiguity,
+            is_virtual: false,
         }
     }
 
@@ -252,6 +418,7 @@ impl JoinedColumnDesc {
             data_type,
             nullable,
             is_ambiguity: false,
+            is_virtual: false,
         }
     }
 }
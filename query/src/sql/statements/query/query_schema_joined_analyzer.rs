@@ -14,16 +14,24 @@
 
 use std::sync::Arc;
 
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Extras;
+use common_planners::JoinType;
+use common_planners::Sample;
+use sqlparser::ast::Expr;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
+use sqlparser::ast::JoinConstraint;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Value;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
@@ -32,6 +40,8 @@ use crate::sql::statements::query::query_schema_joined::JoinedSchema;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
+use crate::storages::NavigationPoint;
+use crate::storages::StorageOperation;
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -49,8 +59,20 @@ impl JoinedSchemaAnalyzer {
         let rpn = RelationRPNBuilder::build(&query.from)?;
         for rpn_item in &rpn {
             match rpn_item {
-                RelationRPNItem::Join(_) => {
-                    return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet."));
+                RelationRPNItem::Join(operator) => {
+                    let right = analyzed_tables.pop();
+                    let left = analyzed_tables.pop();
+                    match (left, right) {
+                        (Some(left), Some(right)) => {
+                            let schema = self.join(left, right, operator);
+                            analyzed_tables.push(schema.await?);
+                        }
+                        _ => {
+                            return Err(ErrorCode::LogicalError(
+                                "Logical error: this is relation rpn bug.",
+                            ));
+                        }
+                    }
                 }
                 RelationRPNItem::Table(v) => {
                     let schema = self.table(v);
@@ -76,6 +98,37 @@ impl JoinedSchemaAnalyzer {
         Ok(analyzed_tables.remove(0))
     }
 
+    // Only the equi-joins are planned, to the hash join.
+    async fn join(
+        &self,
+        left: JoinedSchema,
+        right: JoinedSchema,
+        operator: &JoinOperator,
+    ) -> Result<JoinedSchema> {
+        let (join_type, constraint) = match operator {
+            JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+            JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+            JoinOperator::RightOuter(constraint) => (JoinType::Right, constraint),
+            JoinOperator::FullOuter(constraint) => (JoinType::Full, constraint),
+            _ => {
+                return Err(ErrorCode::UnImplement(
+                    "Unimplemented cross join, the tables must be joined ON the equalities of their columns",
+                ));
+            }
+        };
+
+        match constraint {
+            JoinConstraint::On(expr) => {
+                let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+                let condition = analyzer.analyze(expr).await?;
+                left.join(right, join_type, condition)
+            }
+            _ => Err(ErrorCode::UnImplement(
+                "Unimplemented join without ON, USING and NATURAL joins are unsupported",
+            )),
+        }
+    }
+
     async fn subquery(&self, v: &DerivedRPNItem) -> Result<JoinedSchema> {
         let subquery = &(*v.subquery);
         let subquery = DfQueryStatement::try_from(subquery.clone())?;
@@ -96,18 +149,40 @@ impl JoinedSchemaAnalyzer {
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
-        let read_table = self.ctx.get_table(&database, &table).await?;
+        let mut read_table = self.ctx.get_table(&database, &table).await?;
+        if let Some(point) = &item.navigation {
+            self.ctx
+                .get_catalog()
+                .check_table_supports(read_table.as_ref(), StorageOperation::TimeTravel)?;
+            read_table = read_table.navigate_to(self.ctx.clone(), point).await?;
+        }
+        if item.sample.is_some() && !read_table.support_sampling() {
+            return Err(ErrorCode::UnImplement(format!(
+                "The table {}.{} of engine {} cannot be sampled",
+                database,
+                table,
+                read_table.engine()
+            )));
+        }
 
-        match &item.alias {
+        let mut schema = match &item.alias {
             None => {
                 let name_prefix = vec![database, table];
-                JoinedSchema::from_table(read_table, name_prefix)
+                JoinedSchema::from_table(read_table, name_prefix)?
             }
             Some(table_alias) => {
                 let name_prefix = vec![table_alias.name.value.clone()];
-                JoinedSchema::from_table(read_table, name_prefix)
+                JoinedSchema::from_table(read_table, name_prefix)?
             }
+        };
+        // The sampling is kept by the push downs collected later.
+        if let Some(sample) = &item.sample {
+            schema.set_table_push_downs(0, Extras {
+                sample: Some(sample.clone()),
+                ..Extras::default()
+            });
         }
+        Ok(schema)
     }
 
     async fn table_function(&self, item: &TableFunctionRPNItem) -> Result<JoinedSchema> {
@@ -151,9 +226,106 @@ impl JoinedSchemaAnalyzer {
     }
 }
 
+/// The options of a table given as its arguments: the time travel point of
+/// `t AT (SNAPSHOT => 'id' | TIMESTAMP => 'yyyy-mm-dd hh:mm:ss')` and the sampling of
+/// `t SAMPLE [BLOCK | ROW] (percent)`, which the parser rewrites to `SAMPLE_BLOCK => percent`
+/// or `SAMPLE_ROW => percent`.
+#[derive(Default)]
+pub struct TableOptions {
+    pub navigation: Option<NavigationPoint>,
+    pub sample: Option<Sample>,
+}
+
+/// The table options of the arguments, None if the arguments are of a table function.
+pub fn table_options(args: &[FunctionArg]) -> Result<Option<TableOptions>> {
+    let mut named_args = Vec::with_capacity(args.len());
+    for arg in args {
+        match arg {
+            FunctionArg::Named { name, arg } => match name.value.to_uppercase().as_str() {
+                name @ ("SNAPSHOT" | "TIMESTAMP" | "SAMPLE_BLOCK" | "SAMPLE_ROW") => {
+                    named_args.push((name.to_string(), arg))
+                }
+                _ => return Ok(None),
+            },
+            FunctionArg::Unnamed(_) => return Ok(None),
+        }
+    }
+    if named_args.is_empty() {
+        return Ok(None);
+    }
+
+    let mut options = TableOptions::default();
+    for (name, arg) in named_args {
+        if name.starts_with("SAMPLE") {
+            if options.sample.is_some() {
+                return Err(ErrorCode::SyntaxException(
+                    "A table can be sampled only once",
+                ));
+            }
+            options.sample = Some(sample(&name, arg)?);
+        } else {
+            if options.navigation.is_some() {
+                return Err(ErrorCode::SyntaxException(
+                    "A table can travel to only one point",
+                ));
+            }
+            options.navigation = Some(navigation_point(&name, arg)?);
+        }
+    }
+    Ok(Some(options))
+}
+
+fn navigation_point(name: &str, arg: &Expr) -> Result<NavigationPoint> {
+    let value = match arg {
+        Expr::Value(Value::SingleQuotedString(value)) => value,
+        _ => {
+            return Err(ErrorCode::SyntaxException(format!(
+                "The {} to travel to must be a string literal",
+                name
+            )))
+        }
+    };
+    match name {
+        "SNAPSHOT" => Ok(NavigationPoint::SnapshotID(value.clone())),
+        _ => Ok(NavigationPoint::TimePoint(parse_timestamp(value)?)),
+    }
+}
+
+fn sample(name: &str, arg: &Expr) -> Result<Sample> {
+    let percent = match arg {
+        Expr::Value(Value::Number(n, _)) => n.parse::<f64>().ok(),
+        _ => None,
+    };
+    match percent {
+        Some(p) if p > 0.0 && p <= 100.0 => match name {
+            "SAMPLE_BLOCK" => Ok(Sample::Block(p)),
+            _ => Ok(Sample::Row(p)),
+        },
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The percent to sample must be a number in (0, 100], but got {}",
+            arg
+        ))),
+    }
+}
+
+// The timestamp is in UTC, the fraction of second is optional.
+fn parse_timestamp(value: &str) -> Result<u64> {
+    let datetime = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|d| d.and_hms(0, 0, 0)))
+        .map_err(|cause| {
+            ErrorCode::BadArguments(format!(
+                "Cannot parse the timestamp '{}' to travel to: {}",
+                value, cause
+            ))
+        })?;
+    Ok(datetime.timestamp_millis() as u64)
+}
+
 struct TableRPNItem {
     name: ObjectName,
     alias: Option<TableAlias>,
+    navigation: Option<NavigationPoint>,
+    sample: Option<Sample>,
 }
 
 struct DerivedRPNItem {
@@ -193,6 +365,8 @@ impl RelationRPNBuilder {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: ObjectName(vec![Ident::new("system"), Ident::new("one")]),
             alias: None,
+            navigation: None,
+            sample: None,
         }));
     }
 
@@ -240,8 +414,11 @@ impl RelationRPNBuilder {
                 }
 
                 match args.is_empty() {
-                    true => self.visit_table(name, alias),
-                    false => self.visit_table_function(name, args, alias),
+                    true => self.visit_table(name, alias, TableOptions::default()),
+                    false => match table_options(args)? {
+                        Some(options) => self.visit_table(name, alias, options),
+                        None => self.visit_table_function(name, args, alias),
+                    },
                 }
             }
             TableFactor::Derived {
@@ -266,10 +443,17 @@ impl RelationRPNBuilder {
         }
     }
 
-    fn visit_table(&mut self, name: &ObjectName, alias: &Option<TableAlias>) -> Result<()> {
+    fn visit_table(
+        &mut self,
+        name: &ObjectName,
+        alias: &Option<TableAlias>,
+        options: TableOptions,
+    ) -> Result<()> {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: name.clone(),
             alias: alias.clone(),
+            navigation: options.navigation,
+            sample: options.sample,
         }));
         Ok(())
     }
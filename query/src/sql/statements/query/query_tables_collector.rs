@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::parser::expr::ExprTraverser;
+use common_ast::parser::expr::ExprVisitor;
+use common_exception::Result;
+use sqlparser::ast::Expr;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+use sqlparser::ast::Select;
+use sqlparser::ast::SelectItem;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+
+use crate::sql::statements::query::table_options;
+use crate::sql::statements::DfQueryStatement;
+
+/// Collect the tables a query reads, in the FROM clauses, the derived tables and the
+/// subqueries of the expressions, so that they are resolved at once before the analysis.
+/// The statements it fails to walk are left to the analysis, which reports the errors.
+pub struct QueryTablesCollector {
+    current_database: String,
+    tables: Vec<(String, String)>,
+}
+
+impl QueryTablesCollector {
+    pub fn collect(current_database: String, query: &DfQueryStatement) -> Vec<(String, String)> {
+        let mut collector = QueryTablesCollector {
+            current_database,
+            tables: vec![],
+        };
+
+        collector.visit_from(&query.from);
+        collector.visit_projection(&query.projection);
+        collector.visit_exprs(query.selection.iter().chain(query.having.iter()));
+        collector.visit_exprs(query.group_by.iter());
+        collector.visit_exprs(query.order_by.iter().map(|item| &item.expr));
+        collector.tables
+    }
+
+    fn visit_query(&mut self, query: &Query) {
+        self.visit_set_expr(&query.body);
+    }
+
+    fn visit_set_expr(&mut self, expr: &SetExpr) {
+        match expr {
+            SetExpr::Select(select) => self.visit_select(select),
+            SetExpr::Query(query) => self.visit_query(query),
+            SetExpr::SetOperation { left, right, .. } => {
+                self.visit_set_expr(left);
+                self.visit_set_expr(right);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_select(&mut self, select: &Select) {
+        self.visit_from(&select.from);
+        self.visit_projection(&select.projection);
+        self.visit_exprs(select.selection.iter().chain(select.having.iter()));
+    }
+
+    fn visit_from(&mut self, from: &[TableWithJoins]) {
+        for table_with_joins in from {
+            self.visit_joins(table_with_joins);
+        }
+    }
+
+    fn visit_joins(&mut self, table_with_joins: &TableWithJoins) {
+        self.visit_table_factor(&table_with_joins.relation);
+        for join in &table_with_joins.joins {
+            self.visit_table_factor(&join.relation);
+        }
+    }
+
+    fn visit_table_factor(&mut self, factor: &TableFactor) {
+        match factor {
+            // the tables of the table functions are not known here
+            TableFactor::Table { name, args, .. }
+                if args.is_empty() || matches!(table_options(args), Ok(Some(_))) =>
+            {
+                self.visit_table(name)
+            }
+            TableFactor::Derived { subquery, .. } => self.visit_query(subquery),
+            TableFactor::NestedJoin(joins) => self.visit_joins(joins),
+            _ => {}
+        }
+    }
+
+    fn visit_table(&mut self, name: &ObjectName) {
+        let table = match name.0.as_slice() {
+            [table] => (self.current_database.clone(), table.value.clone()),
+            [database, table] => (database.value.clone(), table.value.clone()),
+            _ => return,
+        };
+
+        if !self.tables.contains(&table) {
+            self.tables.push(table);
+        }
+    }
+
+    fn visit_projection(&mut self, projection: &[SelectItem]) {
+        let exprs = projection.iter().filter_map(|item| match item {
+            SelectItem::UnnamedExpr(expr) => Some(expr),
+            SelectItem::ExprWithAlias { expr, .. } => Some(expr),
+            _ => None,
+        });
+        self.visit_exprs(exprs);
+    }
+
+    fn visit_exprs<'a>(&mut self, exprs: impl Iterator<Item = &'a Expr>) {
+        for expr in exprs {
+            // an unsupported expression fails the analysis later
+            let _ = ExprTraverser::accept(expr, self);
+        }
+    }
+}
+
+impl ExprVisitor for QueryTablesCollector {
+    fn visit_exists(&mut self, subquery: &Query) -> Result<()> {
+        self.visit_query(subquery);
+        Ok(())
+    }
+
+    fn visit_subquery(&mut self, subquery: &Query) -> Result<()> {
+        self.visit_query(subquery);
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserIdentity;
+use common_planners::AlterOwnerPlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterOwnerObject {
+    Database(ObjectName),
+    Table(ObjectName),
+    Stage(String),
+    UDF(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterOwner {
+    pub object: DfAlterOwnerObject,
+    pub owner: UserIdentity,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterOwner {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let object = self.resolve_object(ctx)?;
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterOwner(
+            AlterOwnerPlan {
+                object,
+                owner: self.owner.clone(),
+            },
+        ))))
+    }
+}
+
+impl DfAlterOwner {
+    fn resolve_object(&self, ctx: Arc<QueryContext>) -> Result<OwnershipObject> {
+        match &self.object {
+            DfAlterOwnerObject::Database(ObjectName(idents)) => match idents.len() {
+                1 => Ok(OwnershipObject::Database(idents[0].value.clone())),
+                _ => Err(ErrorCode::SyntaxException(
+                    "Alter database name must be `db`",
+                )),
+            },
+            DfAlterOwnerObject::Table(ObjectName(idents)) => match idents.len() {
+                1 => Ok(OwnershipObject::Table(
+                    ctx.get_current_database(),
+                    idents[0].value.clone(),
+                )),
+                2 => Ok(OwnershipObject::Table(
+                    idents[0].value.clone(),
+                    idents[1].value.clone(),
+                )),
+                _ => Err(ErrorCode::SyntaxException(
+                    "Alter table name must be [`db`].`table`",
+                )),
+            },
+            DfAlterOwnerObject::Stage(name) => Ok(OwnershipObject::Stage(name.clone())),
+            DfAlterOwnerObject::UDF(name) => Ok(OwnershipObject::UDF(name.clone())),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ReadOnlyObject;
+use common_planners::AlterReadOnlyPlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfReadOnlyObject {
+    Cluster,
+    Database(ObjectName),
+    Table(ObjectName),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterReadOnly {
+    pub object: DfReadOnlyObject,
+    pub read_only: bool,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterReadOnly {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let object = self.resolve_object(ctx)?;
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::AlterReadOnly(AlterReadOnlyPlan {
+                object,
+                read_only: self.read_only,
+            }),
+        )))
+    }
+}
+
+impl DfAlterReadOnly {
+    fn resolve_object(&self, ctx: Arc<QueryContext>) -> Result<ReadOnlyObject> {
+        match &self.object {
+            DfReadOnlyObject::Cluster => Ok(ReadOnlyObject::Cluster),
+            DfReadOnlyObject::Database(ObjectName(idents)) => match idents.len() {
+                1 => Ok(ReadOnlyObject::Database(idents[0].value.clone())),
+                _ => Err(ErrorCode::SyntaxException(
+                    "Alter database name must be `db`",
+                )),
+            },
+            DfReadOnlyObject::Table(ObjectName(idents)) => match idents.len() {
+                1 => Ok(ReadOnlyObject::Table(
+                    ctx.get_current_database(),
+                    idents[0].value.clone(),
+                )),
+                2 => Ok(ReadOnlyObject::Table(
+                    idents[0].value.clone(),
+                    idents[1].value.clone(),
+                )),
+                _ => Err(ErrorCode::SyntaxException(
+                    "Alter table name must be [`db`].`table`",
+                )),
+            },
+        }
+    }
+}
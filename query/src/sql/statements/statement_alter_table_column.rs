@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterColumnOperation;
+use common_planners::AlterTableColumnPlan;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_planners::RequireColumnsVisitor;
+use common_tracing::tracing;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use super::analyzer_expr::ExpressionAnalyzer;
+use super::statement_create_table::column_field;
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterColumnOperation {
+    Add(ColumnDef),
+    Drop(Ident),
+    Rename { from: Ident, to: Ident },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTableColumn {
+    pub name: ObjectName,
+    pub operation: DfAlterColumnOperation,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterTableColumn {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = self.resolve_table(ctx.clone())?;
+        let operation = match &self.operation {
+            DfAlterColumnOperation::Add(column) => {
+                let expr_analyzer = ExpressionAnalyzer::create(ctx);
+                let field = column_field(&expr_analyzer, column).await?;
+                // The rows written before are filled with the default as they are read, where
+                // the other columns may not be read.
+                if let Some(default_expr) = field.default_expr() {
+                    let expr: Expression = serde_json::from_slice(default_expr)?;
+                    if !RequireColumnsVisitor::collect_columns_from_expr(&expr)?.is_empty() {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "The default of the added column {} can not refer to the other columns",
+                            field.name()
+                        )));
+                    }
+                }
+                AlterColumnOperation::Add(field)
+            }
+            DfAlterColumnOperation::Drop(column) => {
+                AlterColumnOperation::Drop(column.value.clone())
+            }
+            DfAlterColumnOperation::Rename { from, to } => AlterColumnOperation::Rename {
+                from: from.value.clone(),
+                to: to.value.clone(),
+            },
+        };
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::AlterTableColumn(AlterTableColumnPlan {
+                db,
+                table,
+                operation,
+            }),
+        )))
+    }
+}
+
+impl DfAlterTableColumn {
+    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let ObjectName(idents) = &self.name;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Alter table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
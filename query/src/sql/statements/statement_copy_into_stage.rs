@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::CopyIntoStagePlan;
+use common_planners::PlanNode;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopyIntoStageSource {
+    Table(ObjectName),
+    Query(Box<DfQueryStatement>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCopyIntoStage {
+    pub location: String,
+    pub source: CopyIntoStageSource,
+    pub format: String,
+    pub options: HashMap<String, String>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCopyIntoStage {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let query = match &self.source {
+            CopyIntoStageSource::Table(name) => {
+                PlanParser::parse(&format!("SELECT * FROM {}", name), ctx).await?
+            }
+            CopyIntoStageSource::Query(query) => {
+                let statements = vec![DfStatement::Query(query.clone())];
+                PlanParser::build_plan(statements, ctx).await?
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CopyIntoStage(CopyIntoStagePlan {
+                location: self.location.clone(),
+                query: Box::new(query),
+                format: self.format.clone(),
+                options: self.options.clone(),
+            }),
+        )))
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+use common_planners::CreateConnectionPlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateConnection {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub storage_type: String,
+    pub params: BTreeMap<String, String>,
+    pub credentials: BTreeMap<String, String>,
+    pub comment: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateConnection {
+    #[tracing::instrument(level = "debug", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
+    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let connection = ConnectionInfo::new(
+            &self.name,
+            &self.storage_type,
+            self.params.clone(),
+            self.credentials.clone(),
+            &self.comment,
+        );
+        connection.validate()?;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateConnection(CreateConnectionPlan {
+                if_not_exists: self.if_not_exists,
+                connection,
+            }),
+        )))
+    }
+}
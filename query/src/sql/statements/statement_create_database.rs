@@ -26,6 +26,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateDatabase {
@@ -67,7 +68,31 @@ impl DfCreateDatabase {
         Ok(DatabaseMeta {
             engine: self.engine.clone(),
             engine_options: self.engine_options.clone(),
-            options: self.options.clone(),
+            options: self.database_options()?,
         })
     }
+
+    // The only option of a database is the default retention of the history of its tables.
+    fn database_options(&self) -> Result<HashMap<String, String>> {
+        let mut options = HashMap::with_capacity(self.options.len());
+        for (name, value) in &self.options {
+            if !name.eq_ignore_ascii_case(TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+                return Err(ErrorCode::BadOption(format!(
+                    "Unknown database option {}, expect {}",
+                    name, TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS
+                )));
+            }
+            let days = value.trim().parse::<u64>().map_err(|_| {
+                ErrorCode::BadOption(format!(
+                    "Invalid value '{}' of database option {}, expect an unsigned integer",
+                    value, TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS
+                ))
+            })?;
+            options.insert(
+                TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS.to_string(),
+                days.to_string(),
+            );
+        }
+        Ok(options)
+    }
 }
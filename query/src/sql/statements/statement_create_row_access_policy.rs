@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::UserIdentity;
+use common_planners::CreateRowAccessPolicyPlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateRowAccessPolicy {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub table: ObjectName,
+    pub users: Vec<UserIdentity>,
+    pub roles: Vec<String>,
+    pub predicate: Expr,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateRowAccessPolicy {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (database, table) = self.resolve_table(ctx.clone())?;
+
+        // The protected table must exist, and the predicate must be a valid expression.
+        ctx.get_table(&database, &table).await?;
+        let expression_analyzer = ExpressionAnalyzer::create(ctx.clone());
+        expression_analyzer.analyze(&self.predicate).await?;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateRowAccessPolicy(CreateRowAccessPolicyPlan {
+                if_not_exists: self.if_not_exists,
+                policy: RowAccessPolicy::new(
+                    &self.name,
+                    &database,
+                    &table,
+                    &self.predicate.to_string(),
+                    self.users.clone(),
+                    self.roles.clone(),
+                ),
+            }),
+        )))
+    }
+}
+
+impl DfCreateRowAccessPolicy {
+    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let idents = &self.table.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Protected table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Protected table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
@@ -29,6 +29,7 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateStage {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     pub stage_name: String,
     pub stage_params: StageParams,
     pub file_format: FileFormat,
@@ -42,6 +43,7 @@ impl AnalyzableStatement for DfCreateStage {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateUserStage(CreateUserStagePlan {
                 if_not_exists: self.if_not_exists,
+                or_replace: self.or_replace,
                 user_stage_info: UserStageInfo::new(
                     self.stage_name.as_str(),
                     self.comments.as_str(),
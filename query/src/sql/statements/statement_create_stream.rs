@@ -0,0 +1,81 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableMeta;
+use common_planners::CreateTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::storages::stream::StreamTable;
+use crate::storages::stream::STREAM_ENGINE;
+
+/// `CREATE STREAM [IF NOT EXISTS] [db.]stream ON TABLE [db.]table`, a table of the STREAM
+/// engine returning the rows appended to the table from now on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateStream {
+    pub if_not_exists: bool,
+    pub name: ObjectName,
+    pub table: ObjectName,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateStream {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, stream) = Self::resolve_name(ctx.clone(), &self.name)?;
+        let (table_db, table_name) = Self::resolve_name(ctx.clone(), &self.table)?;
+        let table = ctx.get_table(&table_db, &table_name).await?;
+
+        let table_meta = TableMeta {
+            schema: table.schema(),
+            engine: STREAM_ENGINE.to_string(),
+            options: StreamTable::create_options(&table_db, table.as_ref())?,
+            ..Default::default()
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateTable(CreateTablePlan {
+                if_not_exists: self.if_not_exists,
+                or_replace: false,
+                db,
+                table: stream,
+                table_meta,
+                as_select: None,
+            }),
+        )))
+    }
+}
+
+impl DfCreateStream {
+    fn resolve_name(ctx: Arc<QueryContext>, name: &ObjectName) -> Result<(String, String)> {
+        let idents = &name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Stream or table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Stream and table names must be [`db`].`name`",
+            )),
+        }
+    }
+}
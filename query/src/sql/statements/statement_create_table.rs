@@ -36,10 +36,13 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::external::ExternalTable;
+use crate::storages::fuse::table_options::validate_table_options;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     /// Table name
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
@@ -85,9 +88,16 @@ impl AnalyzableStatement for DfCreateTable {
             None => None,
         };
 
+        // The options of fuse tables are checked against the final schema, the ones of the
+        // other engines are left to the engines.
+        if table_meta.engine.eq_ignore_ascii_case("FUSE") {
+            table_meta.options = validate_table_options(&table_meta.options, &table_meta.schema)?;
+        }
+
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateTable(CreateTablePlan {
                 if_not_exists,
+                or_replace: self.or_replace,
                 db,
                 table,
                 table_meta,
@@ -134,33 +144,41 @@ impl DfCreateTable {
                 let origin_table = ctx.get_table(&origin_db_name, &origin_table_name).await?;
                 Ok(origin_table.schema())
             }
+            // The schema of an external table is inferred from its files, if no column is given.
+            None if self.columns.is_empty() && self.engine.eq_ignore_ascii_case("EXTERNAL") => {
+                ExternalTable::infer_schema(ctx, &self.options).await
+            }
             None => {
                 let expr_analyzer = ExpressionAnalyzer::create(ctx);
                 let mut fields = Vec::with_capacity(self.columns.len());
-
                 for column in &self.columns {
-                    let mut nullable = true;
-                    let mut default_expr = None;
-                    for opt in &column.options {
-                        match &opt.option {
-                            ColumnOption::NotNull => {
-                                nullable = false;
-                            }
-                            ColumnOption::Default(expr) => {
-                                let expr = expr_analyzer.analyze(expr).await?;
-                                default_expr = Some(serde_json::to_vec(&expr)?);
-                            }
-                            _ => {}
-                        }
-                    }
-                    let field = SQLCommon::make_data_type(&column.data_type).map(|data_type| {
-                        DataField::new(&column.name.value, data_type, nullable)
-                            .with_default_expr(default_expr)
-                    })?;
-                    fields.push(field);
+                    fields.push(column_field(&expr_analyzer, column).await?);
                 }
                 Ok(DataSchemaRefExt::create(fields))
             }
         }
     }
 }
+
+/// The field of a column definition, with its nullability and default.
+pub(crate) async fn column_field(
+    expr_analyzer: &ExpressionAnalyzer,
+    column: &ColumnDef,
+) -> Result<DataField> {
+    let mut nullable = true;
+    let mut default_expr = None;
+    for opt in &column.options {
+        match &opt.option {
+            ColumnOption::NotNull => {
+                nullable = false;
+            }
+            ColumnOption::Default(expr) => {
+                let expr = expr_analyzer.analyze(expr).await?;
+                default_expr = Some(serde_json::to_vec(&expr)?);
+            }
+            _ => {}
+        }
+    }
+    let data_type = SQLCommon::make_data_type(&column.data_type)?;
+    Ok(DataField::new(&column.name.value, data_type, nullable).with_default_expr(default_expr))
+}
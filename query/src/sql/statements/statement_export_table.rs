@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::ExportTablePlan;
+use common_planners::PlanNode;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfExportTable {
+    pub name: ObjectName,
+    pub location: String,
+    pub snapshot_id: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfExportTable {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let mut db_name = ctx.get_current_database();
+        let mut tbl_name = self.name.0[0].value.clone();
+
+        if self.name.0.len() > 1 {
+            db_name = tbl_name;
+            tbl_name = self.name.0[1].value.clone();
+        }
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::ExportTable(ExportTablePlan {
+                db_name,
+                tbl_name,
+                location: self.location.clone(),
+                snapshot_id: self.snapshot_id.clone(),
+            }),
+        )))
+    }
+}
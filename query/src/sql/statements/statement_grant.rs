@@ -38,6 +38,9 @@ pub enum DfGrantObject {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    Connection(String),
+    Stage(String),
+    UDF(String),
 }
 
 impl DfGrantObject {
@@ -56,6 +59,11 @@ impl DfGrantObject {
                     .unwrap_or_else(|| ctx.get_current_database());
                 GrantObject::Database(database_name)
             }
+            DfGrantObject::Connection(connection_name) => {
+                GrantObject::Connection(connection_name.clone())
+            }
+            DfGrantObject::Stage(stage_name) => GrantObject::Stage(stage_name.clone()),
+            DfGrantObject::UDF(udf_name) => GrantObject::UDF(udf_name.clone()),
         }
     }
 }
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
@@ -29,6 +32,8 @@ use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 use sqlparser::ast::SetExpr;
 use sqlparser::ast::SqliteOnConflict;
+use sqlparser::ast::UnaryOperator;
+use sqlparser::ast::Value;
 use sqlparser::ast::Values;
 
 use crate::sessions::QueryContext;
@@ -70,13 +75,16 @@ impl AnalyzableStatement for DfInsertStatement {
         let (database_name, table_name) = self.resolve_table(&ctx)?;
         let write_table = ctx.get_table(&database_name, &table_name).await?;
         let table_id = write_table.get_id();
-        let schema = self.insert_schema(write_table)?;
+        let schema = self.insert_schema(&table_name, write_table)?;
 
         let input_source = match &self.source {
             None => self.analyze_insert_without_source().await,
             Some(source) => match &source.body {
                 SetExpr::Values(v) => self.analyze_insert_values(ctx.clone(), v, &schema).await,
-                SetExpr::Select(_) => self.analyze_insert_select(ctx.clone(), source).await,
+                SetExpr::Select(_) => {
+                    self.analyze_insert_select(ctx.clone(), source, &schema)
+                        .await
+                }
                 _ => Err(ErrorCode::SyntaxException(
                     "Insert must be have values or select source.",
                 )),
@@ -138,29 +146,145 @@ impl DfInsertStatement {
     ) -> Result<InsertInputSource> {
         tracing::debug!("{:?}", values);
 
+        for (row, value) in values.0.iter().enumerate() {
+            if value.len() != schema.fields().len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Number of values in row {} ({}) does not match number of insert columns ({})",
+                    row + 1,
+                    value.len(),
+                    schema.fields().len()
+                )));
+            }
+        }
+
+        // Fast path: batches of pure literals skip the expression analyzer.
+        if let Some(value_exprs) = Self::analyze_literal_values(values, schema)? {
+            return Ok(InsertInputSource::Expressions(value_exprs));
+        }
+
         let expression_analyzer = ExpressionAnalyzer::create(ctx);
         let mut value_exprs = Vec::with_capacity(values.0.len());
-        for value in &values.0 {
+        for (row, value) in values.0.iter().enumerate() {
             let mut exprs = Vec::with_capacity(value.len());
             for (i, v) in value.iter().enumerate() {
-                let expr = expression_analyzer.analyze(v).await?;
-                let expr = if &expr.to_data_type(schema)? != schema.field(i).data_type() {
+                let field = schema.field(i);
+                let analyzed = match expression_analyzer.analyze(v).await {
+                    Ok(expr) => expr.to_data_type(schema).map(|data_type| (expr, data_type)),
+                    Err(cause) => Err(cause),
+                };
+                let (expr, data_type) = analyzed.map_err(|cause| {
+                    cause.add_message_back(format!(
+                        " (while in analyze value of column `{}` at row {})",
+                        field.name(),
+                        row + 1
+                    ))
+                })?;
+                let expr = if &data_type != field.data_type() {
                     Expression::Cast {
                         expr: Box::new(expr),
-                        data_type: schema.field(i).data_type().clone(),
+                        data_type: field.data_type().clone(),
                     }
                 } else {
                     expr
                 };
+                exprs.push(Expression::Alias(field.name().to_string(), Box::new(expr)));
+            }
+            value_exprs.push(exprs);
+        }
+
+        Ok(InsertInputSource::Expressions(value_exprs))
+    }
+
+    /// Parse the literal texts with the deserializers of the insert columns.
+    /// Returns None if any value needs to be evaluated as an expression.
+    fn analyze_literal_values(
+        values: &Values,
+        schema: &DataSchemaRef,
+    ) -> Result<Option<Vec<Vec<Expression>>>> {
+        let rows = values.0.len();
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for (col, field) in schema.fields().iter().enumerate() {
+            let mut deserializer = match field.data_type().create_deserializer(rows) {
+                Ok(deserializer) => deserializer,
+                Err(_) => return Ok(None),
+            };
+
+            for (row, value) in values.0.iter().enumerate() {
+                match Self::literal_text(&value[col], field.data_type()) {
+                    None => return Ok(None),
+                    Some(LiteralText::Null) => deserializer.de_null(),
+                    Some(LiteralText::Text(text)) => {
+                        deserializer.de_text(text.as_bytes()).map_err(|cause| {
+                            cause.add_message_back(format!(
+                                " (while in parse value of column `{}` at row {})",
+                                field.name(),
+                                row + 1
+                            ))
+                        })?
+                    }
+                }
+            }
+            columns.push(deserializer.finish_to_series());
+        }
+
+        let mut value_exprs = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let mut exprs = Vec::with_capacity(columns.len());
+            for (column, field) in columns.iter().zip(schema.fields()) {
+                let literal = Expression::Literal {
+                    value: column.try_get(row)?,
+                    column_name: None,
+                    data_type: field.data_type().clone(),
+                };
                 exprs.push(Expression::Alias(
-                    schema.field(i).name().to_string(),
-                    Box::new(expr),
+                    field.name().to_string(),
+                    Box::new(literal),
                 ));
             }
             value_exprs.push(exprs);
         }
 
-        Ok(InsertInputSource::Expressions(value_exprs))
+        Ok(Some(value_exprs))
+    }
+
+    /// The text of the value if it is a literal the deserializer of `data_type` reads
+    /// the same way as casting it, e.g. numbers for numeric columns and strings for string and date columns.
+    fn literal_text<'a>(expr: &'a Expr, data_type: &DataType) -> Option<LiteralText<'a>> {
+        match expr {
+            Expr::Value(Value::Null) => Some(LiteralText::Null),
+            Expr::Value(Value::Boolean(v)) if data_type == &DataType::Boolean => {
+                Some(LiteralText::Text(Cow::Borrowed(if *v {
+                    "true"
+                } else {
+                    "false"
+                })))
+            }
+            Expr::Value(Value::Number(v, _)) if Self::is_number_literal(v, data_type) => {
+                Some(LiteralText::Text(Cow::Borrowed(v)))
+            }
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            } if data_type.is_signed_numeric() => match expr.as_ref() {
+                Expr::Value(Value::Number(v, _)) if Self::is_number_literal(v, data_type) => {
+                    Some(LiteralText::Text(Cow::Owned(format!("-{}", v))))
+                }
+                _ => None,
+            },
+            Expr::Value(Value::SingleQuotedString(v))
+                if data_type.is_string() || data_type.is_date_or_date_time() =>
+            {
+                Some(LiteralText::Text(Cow::Borrowed(v)))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_number_literal(v: &str, data_type: &DataType) -> bool {
+        match data_type {
+            t if t.is_integer() => v.bytes().all(|b| b.is_ascii_digit()),
+            t => t.is_floating(),
+        }
     }
 
     async fn analyze_insert_without_source(&self) -> Result<InsertInputSource> {
@@ -174,26 +298,65 @@ impl DfInsertStatement {
         &self,
         ctx: Arc<QueryContext>,
         source: &Query,
+        schema: &DataSchemaRef,
     ) -> Result<InsertInputSource> {
         let statement = DfQueryStatement::try_from(source.clone())?;
         let select_plan =
             PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await?;
+
+        let select_fields = select_plan.schema().fields().len();
+        if select_fields < schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Insert select returns {} columns, but no value is given for column `{}`",
+                select_fields,
+                schema.field(select_fields).name()
+            )));
+        }
+        if select_fields > schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Insert select returns {} columns, but only {} columns are inserted",
+                select_fields,
+                schema.fields().len()
+            )));
+        }
+
         Ok(InsertInputSource::SelectPlan(Box::new(select_plan)))
     }
 
-    fn insert_schema(&self, read_table: Arc<dyn Table>) -> Result<DataSchemaRef> {
-        match self.columns.is_empty() {
-            true => Ok(read_table.schema()),
-            false => {
-                let schema = read_table.schema();
-                let fields = self
-                    .columns
-                    .iter()
-                    .map(|ident| schema.field_with_name(&ident.value).map(|v| v.clone()))
-                    .collect::<Result<Vec<_>>>()?;
-
-                Ok(DataSchemaRefExt::create(fields))
+    /// The schema of the data to be inserted, in the order of the column list.
+    /// Table columns that are not listed are filled with their defaults at insert time.
+    fn insert_schema(&self, table_name: &str, read_table: Arc<dyn Table>) -> Result<DataSchemaRef> {
+        let schema = read_table.schema();
+        if self.columns.is_empty() {
+            return Ok(schema);
+        }
+
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut seen = HashSet::with_capacity(self.columns.len());
+        for ident in &self.columns {
+            let name = &ident.value;
+            if !seen.insert(name.as_str()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column `{}` specified more than once in insert column list",
+                    name
+                )));
             }
+
+            if !schema.has_field(name) {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Unknown column `{}` in table `{}`",
+                    name, table_name
+                )));
+            }
+
+            fields.push(schema.field_with_name(name)?.clone());
         }
+
+        Ok(DataSchemaRefExt::create(fields))
     }
 }
+
+enum LiteralText<'a> {
+    Null,
+    Text(Cow<'a, str>),
+}
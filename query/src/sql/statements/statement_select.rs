@@ -15,9 +15,13 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::udfs::UDFDefinition;
+use common_functions::udfs::UDFFactory;
+use common_functions::udfs::UDFParser;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::find_aggregate_exprs;
 use common_planners::find_aggregate_exprs_in_expr;
@@ -32,6 +36,7 @@ use sqlparser::ast::TableWithJoins;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_statement::QueryAnalyzeState;
+use crate::sql::statements::query::JoinedRelation;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedSchemaAnalyzer;
 use crate::sql::statements::query::JoinedTableDesc;
@@ -39,13 +44,17 @@ use crate::sql::statements::query::QualifiedRewriter;
 use crate::sql::statements::query::QueryASTIR;
 use crate::sql::statements::query::QueryCollectPushDowns;
 use crate::sql::statements::query::QueryNormalizer;
+use crate::sql::statements::query::QueryTablesCollector;
+use crate::sql::statements::query::RowAccessPolicyRewriter;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::QueryJoinRelation;
 use crate::sql::statements::QueryRelation;
 use crate::storages::ToReadDataSourcePlan;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfQueryStatement {
+    pub distinct: bool,
     pub from: Vec<TableWithJoins>,
     pub projection: Vec<SelectItem>,
     pub selection: Option<Expr>,
@@ -54,23 +63,42 @@ pub struct DfQueryStatement {
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Offset>,
+    /// The functions defined by `WITH FUNCTION`, in the scope of the query only.
+    pub functions: Vec<DfQueryFunction>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfQueryFunction {
+    pub name: String,
+    pub parameters: Vec<String>,
+    pub definition: Expr,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfQueryStatement {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        self.define_functions(&ctx)?;
+
+        let protected_query = RowAccessPolicyRewriter::rewrite(ctx.clone(), self).await?;
+        let query = protected_query.as_ref().unwrap_or(self);
+
+        let tables = QueryTablesCollector::collect(ctx.get_current_database(), query);
+        ctx.pin_tables(&tables).await?;
+
         let analyzer = JoinedSchemaAnalyzer::create(ctx.clone());
-        let mut joined_schema = analyzer.analyze(self).await?;
+        let mut joined_schema = analyzer.analyze(query).await?;
 
-        let mut ir = QueryNormalizer::normalize(ctx.clone(), self).await?;
+        let mut ir = QueryNormalizer::normalize(ctx.clone(), query).await?;
 
         QualifiedRewriter::rewrite(&joined_schema, ctx.clone(), &mut ir)?;
+        QualifiedRewriter::rewrite_join_conditions(&mut joined_schema, ctx.clone())?;
 
         QueryCollectPushDowns::collect_extras(&mut ir, &mut joined_schema)?;
 
-        let analyze_state = self.analyze_query(ir).await?;
-        self.check_and_finalize(joined_schema, analyze_state, ctx)
+        let analyze_state = query.analyze_query(ir).await?;
+        query
+            .check_and_finalize(joined_schema, analyze_state, ctx)
             .await
     }
 }
@@ -80,6 +108,7 @@ impl DfQueryStatement {
         let limit = ir.limit;
         let offset = ir.offset;
         let mut analyze_state = QueryAnalyzeState {
+            distinct: self.distinct,
             limit,
             offset,
             ..Default::default()
@@ -192,6 +221,33 @@ impl DfQueryStatement {
 }
 
 impl DfQueryStatement {
+    // The functions are expanded as the expressions are analyzed, the same as the UDFs,
+    // nothing is written to the meta.
+    fn define_functions(&self, ctx: &QueryContext) -> Result<()> {
+        let tenant = ctx.get_config().query.tenant_id;
+        for function in &self.functions {
+            if UDFFactory::is_builtin_function(&function.name) {
+                return Err(ErrorCode::RegisterUDFError(format!(
+                    "Can not define builtin functions: {}",
+                    function.name
+                )));
+            }
+
+            let mut parser = UDFParser::default();
+            parser.verify_definition_expr(
+                &tenant,
+                &function.name,
+                &function.parameters,
+                &function.definition,
+            )?;
+            ctx.add_temp_function(
+                &function.name,
+                UDFDefinition::new(function.parameters.clone(), function.definition.clone()),
+            );
+        }
+        Ok(())
+    }
+
     pub async fn check_and_finalize(
         &self,
         schema: JoinedSchema,
@@ -201,29 +257,123 @@ impl DfQueryStatement {
         let dry_run_res = Self::verify_with_dry_run(&schema, &state)?;
         state.finalize_schema = dry_run_res.schema().clone();
 
-        let mut tables_desc = schema.take_tables_desc();
-
-        if tables_desc.len() != 1 {
-            return Err(ErrorCode::UnImplement("Select join unimplemented yet."));
+        let relation = schema.get_relation().clone();
+        let mut tables = Vec::with_capacity(schema.get_tables_desc().len());
+        for table_desc in schema.take_tables_desc() {
+            tables.push(Some(Self::table_relation(table_desc, &ctx).await?));
         }
 
-        match tables_desc.remove(0) {
+        state.relation = Self::join_relation(&relation, &mut tables)?.relation;
+        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    }
+
+    async fn table_relation(
+        table_desc: JoinedTableDesc,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<RelationSide> {
+        let (relation, schema) = match &table_desc {
             JoinedTableDesc::Table {
                 table, push_downs, ..
             } => {
-                let source_plan = table.read_plan(ctx.clone(), push_downs).await?;
-                state.relation = QueryRelation::FromTable(Box::new(source_plan));
+                let source_plan = table.read_plan(ctx.clone(), push_downs.clone()).await?;
+                let schema = source_plan.schema();
+                (QueryRelation::FromTable(Box::new(source_plan)), schema)
             }
             JoinedTableDesc::Subquery {
                 state: subquery_state,
                 ..
             } => {
                 // TODO: maybe need reanalyze subquery.
-                state.relation = QueryRelation::Nested(subquery_state);
+                let schema = subquery_state.finalize_schema.clone();
+                (QueryRelation::Nested(subquery_state.clone()), schema)
+            }
+        };
+
+        let projection = table_desc.qualified_columns(&schema);
+        Ok(RelationSide::create(relation, projection, &schema))
+    }
+
+    fn join_relation(
+        relation: &JoinedRelation,
+        tables: &mut [Option<RelationSide>],
+    ) -> Result<RelationSide> {
+        match relation {
+            JoinedRelation::Table(pos) => tables[*pos].take().ok_or_else(|| {
+                ErrorCode::LogicalError("Logical error: the table is joined twice, it's a bug.")
+            }),
+            JoinedRelation::Join {
+                join_type,
+                left,
+                right,
+                condition,
+            } => {
+                let left = Self::join_relation(left, tables)?;
+                let right = Self::join_relation(right, tables)?;
+
+                let mut keys = (vec![], vec![]);
+                Self::join_keys(condition, &left.columns, &right.columns, &mut keys)?;
+
+                let mut columns = left.columns;
+                columns.extend(right.columns);
+                let join = QueryJoinRelation {
+                    join_type: *join_type,
+                    left: left.relation,
+                    left_projection: left.projection,
+                    left_keys: keys.0,
+                    right: right.relation,
+                    right_projection: right.projection,
+                    right_keys: keys.1,
+                };
+                Ok(RelationSide {
+                    relation: QueryRelation::Join(Box::new(join)),
+                    projection: vec![],
+                    columns,
+                })
             }
         }
+    }
 
-        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    // The condition of a join is a conjunction of equalities of a column of each side, the keys
+    // of the hash join.
+    fn join_keys(
+        condition: &Expression,
+        left_columns: &[String],
+        right_columns: &[String],
+        keys: &mut (Vec<Expression>, Vec<Expression>),
+    ) -> Result<()> {
+        match condition {
+            Expression::BinaryExpression { op, left, right } if op.eq_ignore_ascii_case("and") => {
+                Self::join_keys(left, left_columns, right_columns, keys)?;
+                Self::join_keys(right, left_columns, right_columns, keys)
+            }
+            Expression::BinaryExpression { op, left, right } if op == "=" => {
+                match (left.as_ref(), right.as_ref()) {
+                    (Expression::Column(l), Expression::Column(r))
+                        if left_columns.contains(l) && right_columns.contains(r) =>
+                    {
+                        keys.0.push(left.as_ref().clone());
+                        keys.1.push(right.as_ref().clone());
+                        Ok(())
+                    }
+                    (Expression::Column(l), Expression::Column(r))
+                        if left_columns.contains(r) && right_columns.contains(l) =>
+                    {
+                        keys.0.push(right.as_ref().clone());
+                        keys.1.push(left.as_ref().clone());
+                        Ok(())
+                    }
+                    _ => Err(Self::unsupported_join_condition(condition)),
+                }
+            }
+            _ => Err(Self::unsupported_join_condition(condition)),
+        }
+    }
+
+    fn unsupported_join_condition(condition: &Expression) -> ErrorCode {
+        ErrorCode::UnImplement(format!(
+            "Unsupported join condition {:?}, only the equalities of a column of each side joined by AND are supported",
+            condition
+        ))
     }
 
     fn verify_with_dry_run(schema: &JoinedSchema, state: &QueryAnalyzeState) -> Result<DataBlock> {
@@ -342,3 +492,29 @@ impl DfQueryStatement {
         )))
     }
 }
+
+/// A relation of the FROM of a query and the names of its columns, the columns of a table
+/// ambiguous in the join renamed by its projection.
+struct RelationSide {
+    relation: QueryRelation,
+    projection: Vec<Expression>,
+    columns: Vec<String>,
+}
+
+impl RelationSide {
+    fn create(
+        relation: QueryRelation,
+        projection: Vec<Expression>,
+        schema: &DataSchemaRef,
+    ) -> Self {
+        let columns = match projection.is_empty() {
+            true => schema.fields().iter().map(|f| f.name().clone()).collect(),
+            false => projection.iter().map(|expr| expr.column_name()).collect(),
+        };
+        RelationSide {
+            relation,
+            projection,
+            columns,
+        }
+    }
+}
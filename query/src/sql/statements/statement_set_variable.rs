@@ -22,6 +22,7 @@ use common_planners::VarValue;
 use common_tracing::tracing;
 use sqlparser::ast::Ident;
 use sqlparser::ast::SetVariableValue;
+use sqlparser::ast::Value;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
@@ -30,6 +31,8 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfSetVariable {
     pub local: bool,
+    /// `SET GLOBAL`, of the node instead of the session.
+    pub global: bool,
     pub hivevar: bool,
     pub variable: Ident,
     pub value: Vec<SetVariableValue>,
@@ -54,11 +57,16 @@ impl AnalyzableStatement for DfSetVariable {
 }
 
 impl DfSetVariable {
-    fn mapping_set_var(variable: String, value: &SetVariableValue) -> VarValue {
+    fn mapping_set_var(variable: String, is_global: bool, value: &SetVariableValue) -> VarValue {
         VarValue {
+            is_global,
             variable,
             value: match value {
                 sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
+                // the quotes are not a part of the value, e.g. SET time_zone = 'Asia/Shanghai'
+                sqlparser::ast::SetVariableValue::Literal(Value::SingleQuotedString(v)) => {
+                    v.clone()
+                }
                 sqlparser::ast::SetVariableValue::Literal(v) => v.to_string(),
             },
         }
@@ -68,7 +76,7 @@ impl DfSetVariable {
         let variable = self.variable.value.clone();
         self.value
             .iter()
-            .map(|value| DfSetVariable::mapping_set_var(variable.clone(), value))
+            .map(|value| DfSetVariable::mapping_set_var(variable.clone(), self.global, value))
             .collect()
     }
 }
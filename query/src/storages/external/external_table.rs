@@ -0,0 +1,233 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::read_metadata_async;
+use common_dal::DataAccessor;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_planners::Extras;
+use common_planners::Part;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::OrcSource;
+use common_streams::ParquetSource;
+use common_streams::SendableDataBlockStream;
+use common_streams::Source;
+use futures::StreamExt;
+
+use crate::interpreters::stage_location_dal;
+use crate::sessions::QueryContext;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+/// The stage location `@stage/path/` of the files of an external table.
+pub const EXTERNAL_TBL_OPT_KEY_LOCATION: &str = "location";
+/// The format of the files, `parquet` (the default) or `orc`.
+pub const EXTERNAL_TBL_OPT_KEY_FORMAT: &str = "format";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FileFormat {
+    Parquet,
+    Orc,
+}
+
+impl FileFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            FileFormat::Parquet => ".parquet",
+            FileFormat::Orc => ".orc",
+        }
+    }
+}
+
+/// A read-only table over the Parquet or ORC files under a stage location, the files are
+/// listed whenever the table is read. The schema is inferred from the first file at creation
+/// if no column is given, all the files are expected to have the columns of the table.
+pub struct ExternalTable {
+    table_info: TableInfo,
+    format: FileFormat,
+}
+
+impl ExternalTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        Self::location(table_info.options())?;
+        let format = Self::format(table_info.options())?;
+        Ok(Box::new(Self { table_info, format }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "EXTERNAL".to_string(),
+            comment: "Read-only tables of the files under a stage location".to_string(),
+            supports_append: false,
+            supports_truncate: false,
+            supports_delete: false,
+            supports_time_travel: false,
+        }
+    }
+
+    fn option<'a>(options: &'a HashMap<String, String>, key: &str) -> Option<&'a String> {
+        options
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v)
+    }
+
+    fn location(options: &HashMap<String, String>) -> Result<&String> {
+        Self::option(options, EXTERNAL_TBL_OPT_KEY_LOCATION).ok_or_else(|| {
+            ErrorCode::BadOption("External table requires the LOCATION of its files")
+        })
+    }
+
+    fn format(options: &HashMap<String, String>) -> Result<FileFormat> {
+        match Self::option(options, EXTERNAL_TBL_OPT_KEY_FORMAT) {
+            None => Ok(FileFormat::Parquet),
+            Some(v) if v.eq_ignore_ascii_case("parquet") => Ok(FileFormat::Parquet),
+            Some(v) if v.eq_ignore_ascii_case("orc") => Ok(FileFormat::Orc),
+            Some(v) => Err(ErrorCode::BadOption(format!(
+                "Unsupported format {} of external table, expect parquet or orc",
+                v
+            ))),
+        }
+    }
+
+    /// The schema of the first file under the location given by the options.
+    pub async fn infer_schema(
+        ctx: Arc<QueryContext>,
+        options: &HashMap<String, String>,
+    ) -> Result<DataSchemaRef> {
+        let format = Self::format(options)?;
+        let (acc, files) = Self::list_files(ctx, Self::location(options)?, format).await?;
+        let file = files.first().ok_or_else(|| {
+            ErrorCode::BadOption(format!(
+                "Cannot infer the schema, no {:?} file in the location",
+                format
+            ))
+        })?;
+
+        if format == FileFormat::Orc {
+            return Ok(Arc::new(OrcSource::infer_schema(acc, file).await?));
+        }
+
+        let mut reader = acc.get_input_stream(file, None)?;
+        let metadata = read_metadata_async(&mut reader)
+            .await
+            .map_err(|e| ErrorCode::ParquetError(format!("{}, file {}", e, file)))?;
+        let arrow_schema =
+            infer_schema(&metadata).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        Ok(Arc::new(DataSchema::from(arrow_schema)))
+    }
+
+    async fn list_files(
+        ctx: Arc<QueryContext>,
+        location: &str,
+        format: FileFormat,
+    ) -> Result<(Arc<dyn DataAccessor>, Vec<String>)> {
+        let (acc, path) = stage_location_dal(ctx, location).await?;
+        let files = if path.ends_with('/') {
+            acc.list(&path)
+                .await?
+                .into_iter()
+                .filter(|f| f.ends_with(format.extension()))
+                .collect()
+        } else {
+            vec![path]
+        };
+        Ok((acc, files))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    // A partition for each file.
+    async fn read_partitions(
+        &self,
+        ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let location = Self::location(self.table_info.options())?;
+        let (_, files) = Self::list_files(ctx, location, self.format).await?;
+        let parts = files
+            .into_iter()
+            .map(|name| Part { name, version: 0 })
+            .collect();
+        Ok((Statistics::default(), parts))
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let schema = self.table_info.schema();
+        let projection = match &plan.push_downs {
+            Some(Extras {
+                projection: Some(prj),
+                ..
+            }) => prj.clone(),
+            _ => (0..schema.fields().len()).collect::<Vec<usize>>(),
+        };
+
+        let location = Self::location(self.table_info.options())?;
+        let (acc, _) = stage_location_dal(ctx.clone(), location).await?;
+
+        let ctx_clone = ctx.clone();
+        let parts = std::iter::from_fn(move || match ctx_clone.clone().try_get_partitions(1) {
+            Err(_) => None,
+            Ok(parts) if parts.is_empty() => None,
+            Ok(parts) => Some(parts),
+        })
+        .flatten();
+
+        // the row groups or stripes of a file are read one by one
+        let format = self.format;
+        let stream = futures::stream::iter(parts)
+            .map(move |part| {
+                let (acc, schema, projection) = (acc.clone(), schema.clone(), projection.clone());
+                let source: Box<dyn Source> = match format {
+                    FileFormat::Parquet => {
+                        Box::new(ParquetSource::new(acc, part.name, schema, projection))
+                    }
+                    FileFormat::Orc => Box::new(OrcSource::new(acc, part.name, schema, projection)),
+                };
+                futures::stream::try_unfold(source, |mut source| async move {
+                    Ok(source.read().await?.map(|block| (block, source)))
+                })
+            })
+            .flatten();
+        Ok(Box::pin(stream))
+    }
+}
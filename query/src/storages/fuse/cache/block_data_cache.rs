@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_compat::CompatExt;
+use common_base::tokio;
+use common_cache::basic::LruDiskCache;
+use common_dal::DataAccessor;
+use common_dal::InputStream;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_tracing::tracing;
+use common_tracing::tracing::debug_span;
+use common_tracing::tracing::Instrument;
+use futures::Stream;
+
+/// LRU cache of the fuse table blocks on the local disk, keyed by the block location.
+///
+/// A block is downloaded as a whole the first time it is read, the later reads of its
+/// column chunks are served from the local copy. Blocks are immutable once written,
+/// so a cached block never goes stale.
+pub struct BlockDataCache {
+    cache: Mutex<LruDiskCache>,
+}
+
+impl BlockDataCache {
+    pub fn create(root: &str, size_mb: u64) -> Result<Self> {
+        let cache = LruDiskCache::new(root.to_owned(), size_mb * 1024 * 1024)?;
+        Ok(Self {
+            cache: Mutex::new(cache),
+        })
+    }
+
+    /// Downloads the block at `location` into the cache, if it is not cached yet.
+    ///
+    /// Blocks larger than the whole cache are not cached, and failing to write the local
+    /// copy is not an error: the reads of the block just go to the remote storage.
+    pub async fn fetch(&self, da: &dyn DataAccessor, location: &str) -> Result<()> {
+        if self.cache.lock().contains_key(location) {
+            return Ok(());
+        }
+
+        let data = da
+            .read(location)
+            .instrument(debug_span!("block_data_cache_fetch"))
+            .await?;
+
+        let mut cache = self.cache.lock();
+        if cache.contains_key(location) || !cache.can_store(data.len() as u64) {
+            return Ok(());
+        }
+        if let Err(e) = cache.insert_bytes(location, &data) {
+            tracing::warn!("fail to cache block {} on local disk, {}", location, e);
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, location: &str) -> bool {
+        self.cache.lock().contains_key(location)
+    }
+
+    /// Opens the local copy of the block at `location`, if there is one.
+    pub fn open(&self, location: &str) -> Option<std::fs::File> {
+        let mut cache = self.cache.lock();
+        match cache.contains_key(location) {
+            true => cache.get_file(location).ok(),
+            false => None,
+        }
+    }
+
+    /// Drops the local copy of the block at `location`, if there is one.
+    pub fn evict(&self, location: &str) {
+        if let Err(e) = self.cache.lock().remove(location) {
+            tracing::warn!("fail to evict block {} from local disk, {}", location, e);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Total size of the cached blocks in bytes.
+    pub fn size(&self) -> u64 {
+        self.cache.lock().size()
+    }
+}
+
+/// A data accessor which serves the blocks cached by the [`BlockDataCache`] from the
+/// local disk, and everything else from the inner accessor.
+pub struct CachedDataAccessor {
+    cache: Arc<BlockDataCache>,
+    inner: Arc<dyn DataAccessor>,
+}
+
+impl CachedDataAccessor {
+    pub fn new(cache: Arc<BlockDataCache>, inner: Arc<dyn DataAccessor>) -> Self {
+        Self { cache, inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl DataAccessor for CachedDataAccessor {
+    fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream> {
+        match self.cache.open(path) {
+            Some(file) => Ok(Box::new(tokio::fs::File::from_std(file).compat())),
+            None => self.inner.get_input_stream(path, stream_len),
+        }
+    }
+
+    async fn put(&self, path: &str, content: Vec<u8>) -> Result<()> {
+        self.inner.put(path, content).await
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        input_stream: Box<
+            dyn Stream<Item = std::result::Result<bytes::Bytes, std::io::Error>>
+                + Send
+                + Unpin
+                + 'static,
+        >,
+        stream_len: usize,
+    ) -> Result<()> {
+        self.inner.put_stream(path, input_stream, stream_len).await
+    }
+
+    async fn remove(&self, path: &str) -> Result<()> {
+        self.cache.evict(path);
+        self.inner.remove(path).await
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        self.inner.list(prefix).await
+    }
+}
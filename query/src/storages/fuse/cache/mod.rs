@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_data_cache;
 pub mod local_cache;
+mod parquet_meta_cache;
+mod segment_info_cache;
+pub use block_data_cache::BlockDataCache;
+pub use block_data_cache::CachedDataAccessor;
 pub use local_cache::LocalCache;
 pub use local_cache::LocalCacheConfig;
+pub use parquet_meta_cache::ParquetMetaCache;
+pub use segment_info_cache::SegmentInfoCache;
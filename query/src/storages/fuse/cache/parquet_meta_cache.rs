@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::io::parquet::read::read_metadata_async;
+use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
+use common_cache::basic::Cache;
+use common_cache::basic::LruCache;
+use common_dal::DataAccessor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_tracing::tracing::debug_span;
+use common_tracing::tracing::Instrument;
+
+/// LRU cache of the parquet footers of the blocks, keyed by the block location.
+///
+/// Blocks are immutable once written, so a cached footer never goes stale.
+pub struct ParquetMetaCache {
+    cache: Mutex<LruCache<String, FileMetaData>>,
+}
+
+impl ParquetMetaCache {
+    pub fn create(capacity: u64) -> Self {
+        Self {
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    pub async fn read(
+        &self,
+        da: &dyn DataAccessor,
+        location: &str,
+        len: Option<u64>,
+    ) -> Result<FileMetaData> {
+        if let Some(metadata) = self.cache.lock().get(location) {
+            return Ok(metadata.clone());
+        }
+
+        let mut reader = da.get_input_stream(location, len)?;
+        let metadata = read_metadata_async(&mut reader)
+            .instrument(debug_span!("parquet_meta_cache_read_meta"))
+            .await
+            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        self.cache.lock().put(location.to_owned(), metadata.clone());
+        Ok(metadata)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
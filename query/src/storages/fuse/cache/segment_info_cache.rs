@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_cache::basic::Cache;
+use common_cache::basic::LruCache;
+use common_cache::storage::StorageCache;
+use common_dal::DataAccessor;
+use common_exception::Result;
+use common_infallible::Mutex;
+
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::meta::SegmentInfo;
+
+/// LRU cache of the deserialized segments, keyed by the segment location.
+///
+/// Segments are immutable once written, so a cached segment never goes stale.
+pub struct SegmentInfoCache {
+    cache: Mutex<LruCache<String, Arc<SegmentInfo>>>,
+}
+
+impl SegmentInfoCache {
+    pub fn create(capacity: u64) -> Self {
+        Self {
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// The segment at `location`, read through the table cache if it is not cached yet.
+    pub async fn read(
+        &self,
+        da: &dyn DataAccessor,
+        location: &str,
+        table_cache: Arc<Option<Box<dyn StorageCache>>>,
+    ) -> Result<Arc<SegmentInfo>> {
+        if let Some(segment) = self.cache.lock().get(location) {
+            return Ok(segment.clone());
+        }
+
+        let segment = Arc::new(SegmentReader::read(da, location, table_cache).await?);
+        self.cache.lock().put(location.to_owned(), segment.clone());
+        Ok(segment)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -16,9 +16,31 @@
 pub const TBL_OPT_KEY_SNAPSHOT_LOC: &str = "SNAPSHOT_LOC";
 pub const TBL_OPT_KEY_CHUNK_BLOCK_NUM: &str = "CHUNK_BLOCK_NUM";
 pub const TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "BLOCK_SIZE_THRESHOLD";
+// comma separated names of the columns to build bloom filters on
+pub const TBL_OPT_KEY_BLOOM_FILTER_COLUMNS: &str = "BLOOM_FILTER_COLUMNS";
+// the compression codec of the blocks, and the ones of some columns as `<column>:<codec>,...`
+pub const TBL_OPT_KEY_COMPRESSION: &str = "COMPRESSION";
+pub const TBL_OPT_KEY_COLUMN_COMPRESSION: &str = "COLUMN_COMPRESSION";
+// counters of the latest snapshot, updated together with SNAPSHOT_LOC at each commit
+pub const TBL_OPT_KEY_ROW_COUNT: &str = "ROW_COUNT";
+pub const TBL_OPT_KEY_DATA_SIZE: &str = "DATA_SIZE";
+pub const TBL_OPT_KEY_DATA_SIZE_COMPRESSED: &str = "DATA_SIZE_COMPRESSED";
+// location of the column statistics of the latest ANALYZE TABLE
+pub const TBL_OPT_KEY_STATISTICS_LOC: &str = "STATISTICS_LOC";
+pub const TBL_OPT_KEY_DROPPED_COLUMNS: &str = "DROPPED_COLUMNS";
+pub const TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "DATA_RETENTION_TIME_IN_DAYS";
+pub const TBL_OPT_KEY_CHANGE_TRACKING: &str = "CHANGE_TRACKING";
+pub const TBL_OPT_KEY_COMMENT: &str = "COMMENT";
+// the virtual columns of the scans, unless shadowed by the columns of the table
+pub const FUSE_VIRTUAL_COLUMN_BLOCK_LOCATION: &str = "_block_location";
+pub const FUSE_VIRTUAL_COLUMN_ROW_NUMBER: &str = "_row_number";
+pub const FUSE_VIRTUAL_COLUMN_SNAPSHOT_ID: &str = "_snapshot_id";
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
+pub const FUSE_TBL_BLOOM_FILTER_PREFIX: &str = "_bf";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
+pub const FUSE_TBL_STATISTICS_PREFIX: &str = "_st";
 
 pub const DEFAULT_CHUNK_BLOCK_NUM: usize = 1000;
 pub const DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD: usize = 100 * 1024 * 1024;
+pub const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.01;
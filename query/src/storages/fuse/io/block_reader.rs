@@ -0,0 +1,129 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_arrow::arrow::io::parquet::read::read_metadata_async;
+use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::ParquetSource;
+use common_streams::Source;
+use futures::StreamExt;
+
+use crate::pipelines::transforms::AddOnStream;
+
+/// Reads the blocks of a fuse table in the current schema of the table: the columns are
+/// located by their positions in the physical schema, and the ones added after a block was
+/// written are filled with their defaults.
+pub struct BlockReader {
+    physical_schema: DataSchemaRef,
+    projection: Vec<usize>,
+    output_schema: DataSchemaRef,
+    read_buffer_size: u64,
+}
+
+pub struct BlockReadMetrics {
+    pub read_bytes: u64,
+    pub decode_cost: Duration,
+}
+
+impl BlockReader {
+    /// `projection` is of the positions in `physical_schema`, `output_schema` is of the
+    /// columns projected, in the current schema of the table.
+    pub fn create(
+        physical_schema: DataSchemaRef,
+        projection: Vec<usize>,
+        output_schema: DataSchemaRef,
+        read_buffer_size: u64,
+    ) -> Self {
+        Self {
+            physical_schema,
+            projection,
+            output_schema,
+            read_buffer_size,
+        }
+    }
+
+    pub async fn read(
+        &self,
+        data_accessor: Arc<dyn DataAccessor>,
+        location: &str,
+        file_len: u64,
+        metadata: Option<FileMetaData>,
+    ) -> Result<(DataBlock, BlockReadMetrics)> {
+        // the metadata is handed over to the source, which would read it otherwise
+        let metadata = match metadata {
+            Some(metadata) => metadata,
+            None => {
+                let mut reader = data_accessor.get_input_stream(location, Some(file_len))?;
+                read_metadata_async(&mut reader)
+                    .await
+                    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?
+            }
+        };
+
+        // one column is read at least, which the rows of the filled columns are counted by
+        let num_columns = metadata.schema().num_columns();
+        let mut projection = self
+            .projection
+            .iter()
+            .copied()
+            .filter(|position| *position < num_columns)
+            .collect::<Vec<_>>();
+        if projection.is_empty() && num_columns > 0 {
+            projection.push(0);
+        }
+        let missing = projection != self.projection;
+
+        let mut source = ParquetSource::with_hints(
+            data_accessor,
+            location.to_owned(),
+            self.physical_schema.clone(),
+            projection.clone(),
+            Some(metadata),
+            Some(file_len),
+            Some(self.read_buffer_size),
+        );
+        let block = match source.read().await? {
+            Some(block) => block,
+            None => {
+                DataBlock::empty_with_schema(Arc::new(self.physical_schema.project(projection)))
+            }
+        };
+        let metrics = BlockReadMetrics {
+            read_bytes: source.read_bytes(),
+            decode_cost: source.decode_cost(),
+        };
+
+        if !missing {
+            return Ok((block, metrics));
+        }
+        let input_schema = block.schema().clone();
+        let input = Box::pin(futures::stream::once(async { Ok::<_, ErrorCode>(block) }));
+        let mut stream = AddOnStream::try_create(input, input_schema, self.output_schema.clone())?;
+        match stream.next().await {
+            Some(block) => Ok((block?, metrics)),
+            None => Ok((
+                DataBlock::empty_with_schema(self.output_schema.clone()),
+                metrics,
+            )),
+        }
+    }
+}
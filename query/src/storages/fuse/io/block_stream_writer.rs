@@ -26,10 +26,16 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 
 use super::block_writer;
+use super::block_writer::BlockCompression;
 use crate::storages::fuse::io::locations::gen_block_location;
+use crate::storages::fuse::io::locations::gen_bloom_filter_location;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::SEGMENT_FORMAT_VERSION;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
+use crate::storages::fuse::DEFAULT_BLOOM_FILTER_FPP;
+use crate::storages::index::BlockBloomFilter;
+use crate::storages::index::BloomFilter;
 
 pub type SegmentInfoStream =
     std::pin::Pin<Box<dyn futures::stream::Stream<Item = Result<SegmentInfo>> + Send>>;
@@ -43,6 +49,8 @@ impl BlockStreamWriter {
         data_schema: Arc<DataSchema>,
         chunk_block_num: usize,
         block_size_threshold: usize,
+        bloom_filter_columns: Vec<String>,
+        compression: BlockCompression,
     ) -> SegmentInfoStream {
         let s = stream! {
             // filter out empty blocks
@@ -56,7 +64,7 @@ impl BlockStreamWriter {
                 match item.map_err(|TryChunksError(_, e)| e) {
                     Err(e) => yield(Err(e)),
                     Ok(blocks) => {
-                        let seg = Self::generate_segment(data_accessor.clone(), data_schema.clone(), blocks, block_size_threshold).await;
+                        let seg = Self::generate_segment(data_accessor.clone(), data_schema.clone(), blocks, block_size_threshold, &bloom_filter_columns, &compression).await;
                         yield(seg);
                     }
                 }
@@ -70,6 +78,8 @@ impl BlockStreamWriter {
         data_schema: Arc<DataSchema>,
         blocks: Vec<DataBlock>,
         block_size_threshold: usize,
+        bloom_filter_columns: &[String],
+        compression: &BlockCompression,
     ) -> Result<SegmentInfo> {
         // re-shape the blocks
         let blocks = Self::reshape_blocks(blocks, block_size_threshold)?;
@@ -79,14 +89,18 @@ impl BlockStreamWriter {
             let partial_acc = acc.begin(&block)?;
             let schema = block.schema().to_arrow();
             let location = gen_block_location();
+            let bloom_filter_location =
+                Self::write_bloom_filter(&data_accessor, &block, bloom_filter_columns).await?;
             let file_size =
-                block_writer::write_block(&schema, block, &data_accessor, &location).await?;
-            acc = partial_acc.end(file_size, location);
+                block_writer::write_block(&schema, block, &data_accessor, &location, compression)
+                    .await?;
+            acc = partial_acc.end(file_size, location, bloom_filter_location);
         }
 
         // summary and generate a segment
         let summary = acc.summary(data_schema.as_ref())?;
         let seg = SegmentInfo {
+            format_version: SEGMENT_FORMAT_VERSION,
             blocks: acc.blocks_metas,
             summary: Statistics {
                 row_count: acc.summary_row_count,
@@ -95,10 +109,37 @@ impl BlockStreamWriter {
                 compressed_byte_size: acc.file_size,
                 col_stats: summary,
             },
+            ndv_sketches: acc.ndv_sketches,
         };
         Ok(seg)
     }
 
+    // The bloom filters of a block are written to an object of their own, next to the block.
+    async fn write_bloom_filter(
+        data_accessor: &Arc<dyn DataAccessor>,
+        block: &DataBlock,
+        bloom_filter_columns: &[String],
+    ) -> Result<Option<String>> {
+        let mut filters = BlockBloomFilter::new();
+        for name in bloom_filter_columns {
+            // columns dropped from the schema are ignored
+            if let Ok(idx) = block.schema().index_of(name) {
+                let mut filter = BloomFilter::with_rate(block.num_rows(), DEFAULT_BLOOM_FILTER_FPP);
+                filter.add_column(block.column(idx))?;
+                filters.insert(idx as u32, filter);
+            }
+        }
+
+        if filters.is_empty() {
+            return Ok(None);
+        }
+
+        let location = gen_bloom_filter_location();
+        let bytes = serde_json::to_vec(&filters)?;
+        data_accessor.put(&location, bytes).await?;
+        Ok(Some(location))
+    }
+
     // A simple strategy of merging small blocks into larger ones:
     // for each n successive data blocks in `blocks`, if the sum of their `memory_size` exceeds
     //   `block_size_threshold`, they will be merged into one larger block.
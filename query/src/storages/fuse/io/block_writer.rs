@@ -13,8 +13,11 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
+
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::error::ArrowError;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_arrow::arrow::io::parquet::write::*;
 use common_arrow::arrow::record_batch::RecordBatch;
@@ -24,32 +27,141 @@ use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_COMPRESSION;
+use crate::storages::fuse::TBL_OPT_KEY_COMPRESSION;
+
+/// The compression codecs of the columns of the blocks, given by the table options
+/// `COMPRESSION` (of all the columns, lz4 by default) and `COLUMN_COMPRESSION` (of some
+/// columns, e.g. `'payload:zstd,id:none'`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockCompression {
+    default: Compression,
+    columns: HashMap<String, Compression>,
+}
+
+impl Default for BlockCompression {
+    fn default() -> Self {
+        BlockCompression {
+            default: Compression::Lz4, // let's begin with lz4
+            columns: HashMap::new(),
+        }
+    }
+}
+
+impl BlockCompression {
+    pub fn try_create(options: &HashMap<String, String>) -> Result<Self> {
+        let mut compression = BlockCompression::default();
+        if let Some(codec) = options.get(TBL_OPT_KEY_COMPRESSION) {
+            compression.default = Self::parse_codec(codec)?;
+        }
+        if let Some(columns) = options.get(TBL_OPT_KEY_COLUMN_COMPRESSION) {
+            for column in columns
+                .split(',')
+                .map(|c| c.trim())
+                .filter(|c| !c.is_empty())
+            {
+                let (name, codec) = column.split_once(':').ok_or_else(|| {
+                    ErrorCode::BadOption(format!(
+                        "Invalid column compression '{}', expect <column>:<codec>",
+                        column
+                    ))
+                })?;
+                let codec = Self::parse_codec(codec)?;
+                compression.columns.insert(name.trim().to_string(), codec);
+            }
+        }
+        Ok(compression)
+    }
+
+    fn parse_codec(codec: &str) -> Result<Compression> {
+        match codec.trim().to_lowercase().as_str() {
+            "lz4" => Ok(Compression::Lz4),
+            "zstd" => Ok(Compression::Zstd),
+            "snappy" => Ok(Compression::Snappy),
+            "none" => Ok(Compression::Uncompressed),
+            other => Err(ErrorCode::BadOption(format!(
+                "Unknown compression '{}', expect one of lz4, zstd, snappy, none",
+                other
+            ))),
+        }
+    }
+
+    pub fn of_column(&self, name: &str) -> Compression {
+        self.columns.get(name).cloned().unwrap_or(self.default)
+    }
+}
+
 pub async fn write_block(
     arrow_schema: &ArrowSchema,
     block: DataBlock,
     data_accessor: impl AsRef<dyn DataAccessor>,
     location: &str,
+    compression: &BlockCompression,
+) -> Result<u64> {
+    write_blocks(
+        arrow_schema,
+        vec![block],
+        data_accessor,
+        location,
+        compression,
+    )
+    .await
+}
+
+/// Write the blocks into one parquet file, each block in a row group of its own.
+pub async fn write_blocks(
+    arrow_schema: &ArrowSchema,
+    blocks: Vec<DataBlock>,
+    data_accessor: impl AsRef<dyn DataAccessor>,
+    location: &str,
+    compression: &BlockCompression,
 ) -> Result<u64> {
     let data_accessor = data_accessor.as_ref();
     let options = WriteOptions {
         write_statistics: true,
-        compression: Compression::Lz4, // let's begin with lz4
+        compression: compression.default,
         version: Version::V2,
     };
-    let batch = RecordBatch::try_from(block)?;
+    let batches = blocks
+        .into_iter()
+        .map(RecordBatch::try_from)
+        .collect::<Result<Vec<_>>>()?;
     let encodings: Vec<_> = arrow_schema
         .fields()
         .iter()
         .map(|f| col_encoding(&f.data_type))
         .collect();
+    let codecs: Vec<_> = arrow_schema
+        .fields()
+        .iter()
+        .map(|f| compression.of_column(f.name()))
+        .collect();
 
-    let iter = vec![Ok(batch)];
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), arrow_schema, options, encodings)?;
-    let parquet_schema = row_groups.parquet_schema().clone();
-
-    // PutObject in S3 need to know the content-length in advance
-    // multipart upload may intimidate this, but let's fit things together first
-    // see issue #xxx
+    // the pages of each column are compressed by the codec of the column
+    let parquet_schema = to_parquet_schema(arrow_schema)?;
+    let descriptors = parquet_schema.columns().to_vec();
+    let row_groups = batches.into_iter().map(|batch| {
+        let columns = batch
+            .columns()
+            .to_vec()
+            .into_iter()
+            .zip(descriptors.clone())
+            .zip(encodings.clone())
+            .zip(codecs.clone())
+            .map(move |(((array, descriptor), encoding), codec)| {
+                let options = WriteOptions {
+                    compression: codec,
+                    ..options
+                };
+                array_to_pages(array, descriptor, options, encoding).map(move |pages| {
+                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                    let compressed_pages =
+                        Compressor::new(encoded_pages, codec, vec![]).map_err(ArrowError::from);
+                    DynStreamingIterator::new(compressed_pages)
+                })
+            });
+        Ok::<_, ArrowError>(DynIter::new(columns))
+    });
 
     use bytes::BufMut;
     // we need a configuration of block size threshold here
@@ -65,12 +177,10 @@ pub async fn write_block(
     )
     .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
+    // the whole object is put at once (PutObject in S3 needs to know the content-length in
+    // advance), so that a throttled upload can be retried
     let parquet = writer.into_inner();
-    let stream_len = parquet.len();
-    let stream = futures::stream::once(async move { Ok(bytes::Bytes::from(parquet)) });
-    data_accessor
-        .put_stream(location, Box::new(Box::pin(stream)), stream_len)
-        .await?;
+    data_accessor.put(location, parquet).await?;
 
     Ok(len)
 }
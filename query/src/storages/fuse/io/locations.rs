@@ -16,19 +16,31 @@
 use uuid::Uuid;
 
 use crate::storages::fuse::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::storages::fuse::constants::FUSE_TBL_BLOOM_FILTER_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::storages::fuse::constants::FUSE_TBL_STATISTICS_PREFIX;
 
 pub fn gen_block_location() -> String {
     let part_uuid = Uuid::new_v4().to_simple().to_string() + ".parquet";
     format!("{}/{}", FUSE_TBL_BLOCK_PREFIX, part_uuid)
 }
 
+pub fn gen_bloom_filter_location() -> String {
+    let filter_uuid = Uuid::new_v4().to_simple().to_string() + ".bloom";
+    format!("{}/{}", FUSE_TBL_BLOOM_FILTER_PREFIX, filter_uuid)
+}
+
 pub fn gen_segment_info_location() -> String {
     let segment_uuid = Uuid::new_v4().to_simple().to_string();
     format!("{}/{}", FUSE_TBL_SEGMENT_PREFIX, segment_uuid)
 }
 
+pub fn gen_statistics_location() -> String {
+    let statistics_uuid = Uuid::new_v4().to_simple().to_string();
+    format!("{}/{}", FUSE_TBL_STATISTICS_PREFIX, statistics_uuid)
+}
+
 pub fn snapshot_location(id: &Uuid) -> String {
     format!("{}/{}", FUSE_TBL_SNAPSHOT_PREFIX, id.to_simple())
 }
@@ -18,27 +18,49 @@ use common_cache::storage::StorageCache;
 use common_dal::DataAccessor;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use futures::StreamExt;
+use futures::TryStreamExt;
 use serde::de::DeserializeOwned;
 
+use crate::storages::fuse::cache::SegmentInfoCache;
 use crate::storages::fuse::io::snapshot_location;
+use crate::storages::fuse::meta;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::meta::Versioned;
+use crate::storages::index::BlockBloomFilter;
+
+async fn read_bytes(
+    da: &dyn DataAccessor,
+    loc: &str,
+    cache: Arc<Option<Box<dyn StorageCache>>>,
+) -> Result<Vec<u8>> {
+    if let Some(cache) = &*cache {
+        cache.get(loc, da).await
+    } else {
+        da.read(loc).await
+    }
+}
 
 async fn read_obj<T: DeserializeOwned>(
     da: &dyn DataAccessor,
     loc: impl AsRef<str>,
     cache: Arc<Option<Box<dyn StorageCache>>>,
 ) -> Result<T> {
-    let loc = loc.as_ref();
-    let bytes = if let Some(cache) = &*cache {
-        cache.get(loc, da).await?
-    } else {
-        da.read(loc).await?
-    };
+    let bytes = read_bytes(da, loc.as_ref(), cache).await?;
     let r = serde_json::from_slice::<T>(&bytes)?;
     Ok(r)
 }
 
+async fn read_versioned<T: Versioned>(
+    da: &dyn DataAccessor,
+    loc: impl AsRef<str>,
+    cache: Arc<Option<Box<dyn StorageCache>>>,
+) -> Result<T> {
+    let bytes = read_bytes(da, loc.as_ref(), cache).await?;
+    meta::decode(&bytes)
+}
+
 pub struct SnapshotReader {}
 
 impl SnapshotReader {
@@ -47,7 +69,7 @@ impl SnapshotReader {
         loc: impl AsRef<str>,
         cache: Arc<Option<Box<dyn StorageCache>>>,
     ) -> Result<TableSnapshot> {
-        let snapshot: TableSnapshot = read_obj(da, loc, cache).await?;
+        let snapshot: TableSnapshot = read_versioned(da, loc, cache).await?;
         Ok(snapshot)
     }
 
@@ -85,7 +107,45 @@ impl SegmentReader {
         loc: impl AsRef<str>,
         cache: Arc<Option<Box<dyn StorageCache>>>,
     ) -> Result<SegmentInfo> {
-        let segment_info: SegmentInfo = read_obj(da, loc, cache).await?;
+        let segment_info: SegmentInfo = read_versioned(da, loc, cache).await?;
         Ok(segment_info)
     }
+
+    /// Reads the segments at most `max_concurrency` at a time, in the order of `locations`.
+    /// The deserialized segments are kept by the segment cache, if there is one.
+    pub async fn read_segments(
+        da: &dyn DataAccessor,
+        locations: &[String],
+        cache: Arc<Option<Box<dyn StorageCache>>>,
+        segment_cache: Arc<Option<SegmentInfoCache>>,
+        max_concurrency: usize,
+    ) -> Result<Vec<Arc<SegmentInfo>>> {
+        futures::stream::iter(locations)
+            .map(|loc| {
+                let cache = cache.clone();
+                let segment_cache = segment_cache.clone();
+                async move {
+                    match &*segment_cache {
+                        Some(segment_cache) => segment_cache.read(da, loc, cache).await,
+                        None => Ok(Arc::new(Self::read(da, loc, cache).await?)),
+                    }
+                }
+            })
+            .buffered(max_concurrency.max(1))
+            .try_collect()
+            .await
+    }
+}
+
+pub struct BloomFilterReader {}
+
+impl BloomFilterReader {
+    pub async fn read(
+        da: &dyn DataAccessor,
+        loc: impl AsRef<str>,
+        cache: Arc<Option<Box<dyn StorageCache>>>,
+    ) -> Result<BlockBloomFilter> {
+        let filter: BlockBloomFilter = read_obj(da, loc, cache).await?;
+        Ok(filter)
+    }
 }
@@ -12,14 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_reader;
 mod block_stream_writer;
 mod block_writer;
 mod locations;
 mod meta_reader;
+mod upload;
 
+pub use block_reader::BlockReadMetrics;
+pub use block_reader::BlockReader;
 pub use block_stream_writer::BlockStreamWriter;
 pub use block_stream_writer::SegmentInfoStream;
+pub use block_writer::write_blocks;
+pub use block_writer::BlockCompression;
 pub use locations::gen_segment_info_location;
+pub use locations::gen_statistics_location;
 pub use locations::snapshot_location;
+pub use meta_reader::BloomFilterReader;
 pub use meta_reader::SegmentReader;
 pub use meta_reader::SnapshotReader;
+pub use upload::UploadDataAccessor;
+pub use upload::UploadPolicy;
@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::sync::Semaphore;
+use common_base::tokio::sync::SemaphorePermit;
+use common_dal::DataAccessor;
+use common_dal::InputStream;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metrics::label_counter;
+use common_tracing::tracing;
+use futures::Stream;
+use rand::Rng;
+
+use crate::configs::Config;
+
+const UPLOAD_RETRY_COUNT: &str = "fuse_upload_retry_count";
+const UPLOAD_FAILURE_COUNT: &str = "fuse_upload_failure_count";
+
+/// How the uploads of the fuse tables are retried and throttled, shared by the queries of a node.
+///
+/// Object stores throttle the bursts of writes, so a failed upload is retried with exponential
+/// backoff and jitter instead of failing the write, and at most `table_upload_max_concurrency`
+/// uploads are in flight. The retries are counted by the `fuse_upload_retry_count` metric.
+pub struct UploadPolicy {
+    max_retries: u64,
+    backoff: Duration,
+    max_backoff: Duration,
+    permits: Option<Semaphore>,
+    tenant_id: String,
+    cluster_id: String,
+}
+
+impl UploadPolicy {
+    pub fn create(conf: &Config) -> Self {
+        let permits = match conf.query.table_upload_max_concurrency {
+            0 => None,
+            n => Some(Semaphore::new(n as usize)),
+        };
+        UploadPolicy {
+            max_retries: conf.query.table_upload_max_retries,
+            backoff: Duration::from_millis(conf.query.table_upload_retry_backoff_ms),
+            max_backoff: Duration::from_millis(conf.query.table_upload_retry_max_backoff_ms),
+            permits,
+            tenant_id: conf.query.tenant_id.clone(),
+            cluster_id: conf.query.cluster_id.clone(),
+        }
+    }
+
+    /// The backoff before the n-th retry (from 1): the initial backoff doubled by each retry,
+    /// capped by the max backoff, of which a random half is added as jitter so that the
+    /// throttled writers do not retry all at once.
+    pub fn backoff(&self, retry: u64) -> Duration {
+        let exp = 1u32 << (retry.saturating_sub(1).min(31) as u32);
+        let backoff = self.backoff.saturating_mul(exp).min(self.max_backoff);
+        let millis = backoff.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(millis / 2..=millis))
+    }
+
+    async fn acquire(&self) -> Result<Option<SemaphorePermit<'_>>> {
+        match &self.permits {
+            None => Ok(None),
+            Some(permits) => permits
+                .acquire()
+                .await
+                .map(Some)
+                .map_err(|e| ErrorCode::UnexpectedError(e.to_string())),
+        }
+    }
+
+    /// Runs the upload `f` of `location`, retrying it on failure.
+    pub async fn upload<F, Fut>(&self, location: &str, f: F) -> Result<()>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let mut retry = 0;
+        loop {
+            // the permit is not held while backing off
+            let res = {
+                let _permit = self.acquire().await?;
+                f().await
+            };
+
+            match res {
+                Ok(_) => return Ok(()),
+                Err(e) if retry < self.max_retries => {
+                    retry += 1;
+                    let backoff = self.backoff(retry);
+                    tracing::warn!(
+                        "upload of {} failed, retry {}/{} in {:?}, {}",
+                        location,
+                        retry,
+                        self.max_retries,
+                        backoff,
+                        e
+                    );
+                    label_counter(UPLOAD_RETRY_COUNT, &self.tenant_id, &self.cluster_id);
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(e) => {
+                    label_counter(UPLOAD_FAILURE_COUNT, &self.tenant_id, &self.cluster_id);
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
+
+/// A data accessor which uploads by the [`UploadPolicy`], and reads from the inner accessor.
+///
+/// A stream can only be consumed once, so `put_stream` is throttled but not retried.
+pub struct UploadDataAccessor {
+    policy: Arc<UploadPolicy>,
+    inner: Arc<dyn DataAccessor>,
+}
+
+impl UploadDataAccessor {
+    pub fn new(policy: Arc<UploadPolicy>, inner: Arc<dyn DataAccessor>) -> Self {
+        Self { policy, inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl DataAccessor for UploadDataAccessor {
+    fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream> {
+        self.inner.get_input_stream(path, stream_len)
+    }
+
+    async fn put(&self, path: &str, content: Vec<u8>) -> Result<()> {
+        self.policy
+            .upload(path, || self.inner.put(path, content.clone()))
+            .await
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        input_stream: Box<
+            dyn Stream<Item = std::result::Result<bytes::Bytes, std::io::Error>>
+                + Send
+                + Unpin
+                + 'static,
+        >,
+        stream_len: usize,
+    ) -> Result<()> {
+        let _permit = self.policy.acquire().await?;
+        self.inner.put_stream(path, input_stream, stream_len).await
+    }
+
+    async fn remove(&self, path: &str) -> Result<()> {
+        self.inner.remove(path).await
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        self.inner.list(prefix).await
+    }
+}
@@ -27,6 +27,9 @@ pub struct BlockMeta {
     pub file_size: u64,
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub location: BlockLocation,
+    /// Location of the bloom filters of the block, absent if the table has no bloom filter columns
+    #[serde(default)]
+    pub bloom_filter_location: Option<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
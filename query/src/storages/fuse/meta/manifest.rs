@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const MANIFEST_FORMAT_VERSION: u32 = 1;
+
+/// Manifest of a table snapshot, it lists the parquet files and the schema of the snapshot,
+/// so that external query engines (Spark, Trino, ...) can read the data without the server.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TableManifest {
+    pub format_version: u32,
+    pub database: String,
+    pub table: String,
+    /// None if the table has no snapshot yet
+    pub snapshot_id: Option<String>,
+    pub schema: Vec<ManifestColumn>,
+    pub row_count: u64,
+    pub files: Vec<ManifestFile>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ManifestColumn {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ManifestFile {
+    /// Full path of the parquet file, prefixed by the root of the storage
+    pub path: String,
+    pub row_count: u64,
+    pub file_size: u64,
+}
@@ -14,14 +14,27 @@
 //
 
 mod block;
+mod manifest;
 mod segment;
 mod snapshot;
+mod versioned;
 
 pub use block::BlockLocation;
 pub use block::BlockMeta;
+pub use manifest::ManifestColumn;
+pub use manifest::ManifestFile;
+pub use manifest::TableManifest;
+pub use manifest::MANIFEST_FORMAT_VERSION;
 pub use segment::SegmentInfo;
+pub use segment::SEGMENT_FORMAT_VERSION;
 pub use snapshot::ColumnId;
 pub use snapshot::Location;
 pub use snapshot::SnapshotId;
 pub use snapshot::Statistics;
 pub use snapshot::TableSnapshot;
+pub use snapshot::SNAPSHOT_FORMAT_VERSION;
+pub use versioned::decode;
+pub use versioned::encode;
+pub use versioned::MetaEncoding;
+pub use versioned::Versioned;
+pub use versioned::META_BINARY_MAGIC;
@@ -13,17 +13,37 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
+
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::Versioned;
+use crate::storages::index::NdvSketch;
+
+pub const SEGMENT_FORMAT_VERSION: u32 = 1;
 
 /// A segment comprised of one or more blocks
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct SegmentInfo {
-    // TODO format_version
-    // pub format_version: u32,
+    /// Version of the format the segment is written in, which covers the block metas as well.
+    /// 0 for the segments written before it is kept
+    #[serde(default)]
+    pub format_version: u32,
+
     /// blocks belong to this segment
     pub blocks: Vec<BlockMeta>,
 
     /// summary statistics
     pub summary: Statistics,
+
+    /// Sketches of the distinct values of the columns, absent for the segments written
+    /// before they are kept
+    #[serde(default)]
+    pub ndv_sketches: HashMap<ColumnId, NdvSketch>,
+}
+
+impl Versioned for SegmentInfo {
+    const KIND: &'static str = "segment";
+    const FORMAT_VERSION: u32 = SEGMENT_FORMAT_VERSION;
 }
@@ -12,32 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
+use chrono::Utc;
 use common_base::uuid;
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use uuid::Uuid;
 
+use crate::storages::fuse::meta::Versioned;
 use crate::storages::index::ColumnStatistics;
 
 pub type ColumnId = u32;
 pub type SnapshotId = Uuid; // TODO String might be better
 pub type Location = String;
 
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TableSnapshot {
-    // TODO format_version
-    // pub format_version: u32,
+    /// Version of the format the snapshot is written in, 0 for the snapshots written before
+    /// it is kept
+    #[serde(default)]
+    pub format_version: u32,
+
     /// id of snapshot
     pub snapshot_id: SnapshotId,
 
     pub prev_snapshot_id: Option<SnapshotId>,
 
+    /// The time the snapshot is committed, in unix milliseconds.
+    /// None for the snapshots committed before the timestamp is kept.
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+
     /// For each snapshot, we keep a schema for it (in case of schema evolution)
     pub schema: DataSchema,
 
+    /// The columns dropped from the schema by the time of the snapshot, by the positions the
+    /// blocks keep them at. Empty for the snapshots committed before any column is dropped
+    #[serde(default)]
+    pub dropped_columns: BTreeMap<ColumnId, DataField>,
+
     /// Summary Statistics
     pub summary: Statistics,
 
@@ -48,7 +67,16 @@ pub struct TableSnapshot {
     pub segments: Vec<Location>,
 }
 
+impl Versioned for TableSnapshot {
+    const KIND: &'static str = "snapshot";
+    const FORMAT_VERSION: u32 = SNAPSHOT_FORMAT_VERSION;
+}
+
 impl TableSnapshot {
+    pub fn now_timestamp() -> Option<u64> {
+        Some(Utc::now().timestamp_millis() as u64)
+    }
+
     #[allow(dead_code)]
     #[must_use]
     pub fn append_segment(mut self, location: Location) -> TableSnapshot {
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Leading bytes of the meta objects in the binary encoding: msgpack compressed by zstd.
+/// The objects without it are JSON, as written by the older versions.
+pub const META_BINARY_MAGIC: &[u8] = b"DFM\x01";
+
+const META_ZSTD_LEVEL: i32 = 3;
+
+/// Encoding of a meta object, told by its leading bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetaEncoding {
+    Json,
+    MsgPack,
+}
+
+impl MetaEncoding {
+    pub fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        match self {
+            MetaEncoding::Json => Ok(serde_json::from_slice(bytes)?),
+            MetaEncoding::MsgPack => rmp_serde::from_slice(bytes).map_err(|e| {
+                ErrorCode::BadBytes(format!("Cannot decode the msgpack of meta, cause: {}", e))
+            }),
+        }
+    }
+}
+
+/// The meta objects of the fuse tables (snapshots and segments) carry the version of their
+/// on-disk format.
+///
+/// Adding fields (with a `#[serde(default)]`) does not change the format version: the readers
+/// ignore the fields they do not know. The version is bumped only if the layout changes in a way
+/// the older readers would decode wrongly, and the readers refuse the objects written in a newer
+/// format, instead of misreading them.
+pub trait Versioned: DeserializeOwned {
+    /// Name of the object kind, for the error messages.
+    const KIND: &'static str;
+
+    /// Format version of the objects written by this build.
+    const FORMAT_VERSION: u32;
+
+    /// Decodes an object written in an older format `version`. The objects written before the
+    /// version is kept are of version 0.
+    ///
+    /// By default, the current layout is able to read the older ones.
+    fn decode_legacy(_version: u32, encoding: MetaEncoding, bytes: &[u8]) -> Result<Self> {
+        encoding.deserialize(bytes)
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionProbe {
+    #[serde(default)]
+    format_version: u32,
+}
+
+/// Encodes a meta object in the binary encoding. The structs are kept as maps of the field
+/// names, so that the fields can be added as with JSON.
+pub fn encode<T: Serialize>(obj: &T) -> Result<Vec<u8>> {
+    let msgpack = rmp_serde::to_vec_named(obj).map_err(|e| {
+        ErrorCode::BadBytes(format!("Cannot encode the msgpack of meta, cause: {}", e))
+    })?;
+    let mut bytes = META_BINARY_MAGIC.to_vec();
+    bytes.extend(zstd::encode_all(msgpack.as_slice(), META_ZSTD_LEVEL)?);
+    Ok(bytes)
+}
+
+/// Decodes a meta object of either encoding, dispatching on its format version.
+pub fn decode<T: Versioned>(bytes: &[u8]) -> Result<T> {
+    match bytes.strip_prefix(META_BINARY_MAGIC) {
+        Some(compressed) => {
+            let msgpack = zstd::decode_all(compressed)?;
+            decode_versioned(MetaEncoding::MsgPack, &msgpack)
+        }
+        None => decode_versioned(MetaEncoding::Json, bytes),
+    }
+}
+
+fn decode_versioned<T: Versioned>(encoding: MetaEncoding, bytes: &[u8]) -> Result<T> {
+    let probe: VersionProbe = encoding.deserialize(bytes)?;
+    match probe.format_version {
+        v if v == T::FORMAT_VERSION => encoding.deserialize(bytes),
+        v if v < T::FORMAT_VERSION => T::decode_legacy(v, encoding, bytes),
+        v => Err(ErrorCode::UnknownFormatVersion(format!(
+            "{} of format version {} is written by a newer version, the supported version is up to {}",
+            T::KIND,
+            v,
+            T::FORMAT_VERSION
+        ))),
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The bytes of the parquet blocks read from the storage, or from the local block cache.
+pub static METRIC_FUSE_READ_PARQUET_BYTES: &str = "fuse.read_parquet_bytes";
+pub static METRIC_FUSE_BLOCK_CACHE_HITS: &str = "fuse.block_cache_hits";
+pub static METRIC_FUSE_BLOCK_CACHE_MISSES: &str = "fuse.block_cache_misses";
+pub static METRIC_FUSE_PRUNING_BLOCKS: &str = "fuse.pruning_blocks";
+pub static METRIC_FUSE_PRUNING_KEPT_BLOCKS: &str = "fuse.pruning_kept_blocks";
+// The ratio of the blocks pruned of each scan, 0 if none is pruned.
+pub static METRIC_FUSE_PRUNE_RATIO: &str = "fuse.prune_ratio";
@@ -16,13 +16,22 @@ pub mod cache;
 mod constants;
 pub mod io;
 pub mod meta;
+mod metrics;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
 mod table;
 mod table_functions;
+pub mod table_options;
 
 pub use constants::*;
 pub use table::FuseTable;
+pub use table_functions::FuseBlocksTable;
 pub use table_functions::FuseHistoryTable;
+pub use table_functions::FuseSegmentsTable;
+pub use table_functions::FuseSnapshotDiffTable;
+pub use table_functions::FUSE_FUNC_BLOCKS;
 pub use table_functions::FUSE_FUNC_HIST;
+pub use table_functions::FUSE_FUNC_SEGMENTS;
+pub use table_functions::FUSE_FUNC_SNAPSHOTS;
+pub use table_functions::FUSE_FUNC_SNAPSHOT_DIFF;
@@ -0,0 +1,195 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::AlterColumnOperation;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::transforms::AddOnStream;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
+use crate::storages::fuse::TBL_OPT_KEY_DROPPED_COLUMNS;
+
+impl FuseTable {
+    // The schema evolves without re-writing any block:
+    //
+    // - an added column is appended to the blocks written after it, the blocks written before
+    //   have it filled with its default as they are read
+    // - a dropped column keeps its position in the blocks, the ones written after it have it
+    //   filled with NULLs (or the zero values), so that the positions of the other columns,
+    //   which the column statistics and the bloom filters are keyed by, never change
+    // - a renamed column is only renamed in the schema, the columns are read by position
+    pub async fn do_alter_column(
+        &self,
+        ctx: Arc<QueryContext>,
+        operation: &AlterColumnOperation,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let mut fields = schema.fields().clone();
+        let mut options = HashMap::new();
+        match operation {
+            AlterColumnOperation::Add(field) => {
+                if schema.has_field(field.name()) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column {} already exists in table {}",
+                        field.name(),
+                        self.name()
+                    )));
+                }
+                fields.push(field.clone());
+            }
+            AlterColumnOperation::Drop(name) => {
+                let index = self.index_of_column(name)?;
+                if fields.len() == 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can not drop the only column {} of table {}",
+                        name,
+                        self.name()
+                    )));
+                }
+                let position = self.physical_projection(&[index])?[0];
+                let mut dropped = self.dropped_columns()?;
+                dropped.insert(position as ColumnId, fields.remove(index));
+                options.insert(
+                    TBL_OPT_KEY_DROPPED_COLUMNS.to_string(),
+                    Some(serde_json::to_string(&dropped)?),
+                );
+            }
+            AlterColumnOperation::Rename { from, to } => {
+                let index = self.index_of_column(from)?;
+                if schema.has_field(to) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column {} already exists in table {}",
+                        to,
+                        self.name()
+                    )));
+                }
+                let field = &fields[index];
+                fields[index] = DataField::new(to, field.data_type().clone(), field.is_nullable())
+                    .with_default_expr(field.default_expr().cloned());
+
+                let bloom_filter_columns = self.bloom_filter_columns();
+                if bloom_filter_columns.contains(from) {
+                    let renamed = bloom_filter_columns
+                        .into_iter()
+                        .map(|c| if &c == from { to.clone() } else { c })
+                        .collect::<Vec<_>>();
+                    options.insert(
+                        TBL_OPT_KEY_BLOOM_FILTER_COLUMNS.to_string(),
+                        Some(renamed.join(",")),
+                    );
+                }
+            }
+        }
+
+        // fails if the table has been changed meanwhile
+        let req = UpsertTableOptionReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.version),
+            options,
+            schema: Some(Arc::new(DataSchema::new_from(
+                fields,
+                schema.meta().clone(),
+            ))),
+        };
+        ctx.get_catalog().upsert_table_option(req).await?;
+        Ok(())
+    }
+
+    fn index_of_column(&self, name: &str) -> Result<usize> {
+        self.table_info.schema().index_of(name).map_err(|_| {
+            ErrorCode::UnknownColumn(format!("Unknown column {} of table {}", name, self.name()))
+        })
+    }
+
+    /// The columns dropped from the table, by their positions in the blocks.
+    pub(crate) fn dropped_columns(&self) -> Result<BTreeMap<ColumnId, DataField>> {
+        match self.table_info.options().get(TBL_OPT_KEY_DROPPED_COLUMNS) {
+            Some(v) => Ok(serde_json::from_str(v)?),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    /// The schema the blocks are written in: the columns of the table, with the dropped ones
+    /// kept at their positions, under names which the queries can not refer to.
+    pub(crate) fn physical_schema(&self) -> Result<DataSchemaRef> {
+        let schema = self.table_info.schema();
+        let dropped = self.dropped_columns()?;
+        if dropped.is_empty() {
+            return Ok(schema);
+        }
+
+        let mut columns = schema.fields().iter();
+        let fields = (0..schema.fields().len() + dropped.len())
+            .map(|position| match dropped.get(&(position as ColumnId)) {
+                Some(f) => DataField::new(
+                    &format!("__dropped_column_{}", position),
+                    f.data_type().clone(),
+                    f.is_nullable(),
+                ),
+                None => columns.next().cloned().unwrap(),
+            })
+            .collect::<Vec<_>>();
+        Ok(Arc::new(DataSchema::new_from(
+            fields,
+            schema.meta().clone(),
+        )))
+    }
+
+    /// The positions in the blocks of the columns of the table at the given indices.
+    pub(crate) fn physical_projection(&self, projection: &[usize]) -> Result<Vec<usize>> {
+        let dropped = self.dropped_columns()?;
+        Ok(projection
+            .iter()
+            .map(|index| {
+                dropped.keys().fold(*index, |position, dropped_position| {
+                    match (*dropped_position as usize) <= position {
+                        true => position + 1,
+                        false => position,
+                    }
+                })
+            })
+            .collect())
+    }
+
+    /// The blocks to be written, of the schema of the table, with the dropped columns filled.
+    pub(crate) fn physical_block_stream(
+        &self,
+        stream: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        let schema = self.table_info.schema();
+        let physical_schema = self.physical_schema()?;
+        if physical_schema == schema {
+            return Ok(stream);
+        }
+        Ok(Box::pin(AddOnStream::try_create(
+            stream,
+            schema,
+            physical_schema,
+        )?))
+    }
+}
@@ -0,0 +1,186 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::UpsertTableOptionReq;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::gen_statistics_location;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_STATISTICS_LOC;
+use crate::storages::index::NdvSketch;
+use crate::storages::AnalyzedColumnStatistics;
+use crate::storages::AnalyzedStatistics;
+
+/// The rows sampled for the histograms, evenly spread over the table.
+const HISTOGRAM_SAMPLE_ROWS: u64 = 100_000;
+const HISTOGRAM_BUCKETS: usize = 16;
+
+impl FuseTable {
+    // All the blocks of the latest snapshot are read: the NDVs and the null counts are of all
+    // the rows, while the histograms are built of the rows sampled at a fixed stride.
+    //
+    // The statistics are written to a file of their own, which the table option STATISTICS_LOC
+    // points to, the file of the previous statistics is removed once the option is updated.
+    pub async fn do_analyze(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        let snapshot = self.read_table_snapshot(ctx.as_ref()).await?;
+        let schema = self.table_info.schema();
+        let mut sketches = vec![NdvSketch::new(); schema.fields().len()];
+        let mut null_counts = vec![0u64; schema.fields().len()];
+        let mut samples = vec![];
+
+        let da = ctx.get_data_accessor()?;
+        if let Some(snapshot) = &snapshot {
+            let read_buffer_size = ctx.get_settings().get_storage_read_buffer_size()?;
+            let block_reader = self.full_block_reader(read_buffer_size)?;
+            let stride = (snapshot.summary.row_count / HISTOGRAM_SAMPLE_ROWS).max(1);
+            let mut rows_read = 0u64;
+            for location in &snapshot.segments {
+                let segment =
+                    SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?;
+                for block_meta in &segment.blocks {
+                    let (block, _) = block_reader
+                        .read(
+                            da.clone(),
+                            &block_meta.location.path,
+                            block_meta.file_size,
+                            None,
+                        )
+                        .await?;
+                    for (i, column) in block.columns().iter().enumerate() {
+                        sketches[i].add_column(column)?;
+                        null_counts[i] += column.to_array()?.null_count() as u64;
+                    }
+
+                    let rows = block.num_rows() as u64;
+                    let first = (stride - rows_read % stride) % stride;
+                    let indices = (first..rows)
+                        .step_by(stride as usize)
+                        .map(|i| i as u32)
+                        .collect::<Vec<_>>();
+                    if !indices.is_empty() {
+                        samples.push(DataBlock::block_take_by_indices(&block, &[], &indices)?);
+                    }
+                    rows_read += rows;
+                }
+            }
+        }
+
+        let sample = if samples.is_empty() {
+            DataBlock::empty_with_schema(schema.clone())
+        } else {
+            DataBlock::concat_blocks(&samples)?
+        };
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for (i, field) in schema.fields().iter().enumerate() {
+            columns.push(AnalyzedColumnStatistics {
+                name: field.name().clone(),
+                ndv: sketches[i].estimate(),
+                null_count: null_counts[i],
+                histogram_bounds: Self::histogram_bounds(&sample, field)?,
+            });
+        }
+
+        let statistics = AnalyzedStatistics {
+            snapshot: self.snapshot_loc(),
+            num_rows: snapshot.map(|s| s.summary.row_count).unwrap_or(0),
+            columns,
+        };
+        let location = gen_statistics_location();
+        da.put(&location, serde_json::to_vec(&statistics)?).await?;
+
+        // fails if the table has been changed meanwhile
+        let req = UpsertTableOptionReq::new(
+            &self.table_info.ident,
+            TBL_OPT_KEY_STATISTICS_LOC,
+            location.clone(),
+        );
+        if let Err(cause) = ctx.get_catalog().upsert_table_option(req).await {
+            da.remove(&location).await?;
+            return Err(cause);
+        }
+
+        if let Some(prev) = self.statistics_loc() {
+            if let Err(cause) = da.remove(&prev).await {
+                tracing::warn!("fail to remove the statistics {}, {}", prev, cause);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn read_analyzed_statistics(
+        &self,
+        ctx: &QueryContext,
+    ) -> Result<Option<AnalyzedStatistics>> {
+        match self.statistics_loc() {
+            Some(location) => {
+                let da = ctx.get_data_accessor()?;
+                let bytes = da.read(&location).await?;
+                Ok(Some(serde_json::from_slice(&bytes)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn statistics_loc(&self) -> Option<String> {
+        self.table_info
+            .options()
+            .get(TBL_OPT_KEY_STATISTICS_LOC)
+            .cloned()
+    }
+
+    // The upper bounds of equi-height buckets of the non null values of the sample, only the
+    // numbers, strings and dates are bucketed.
+    fn histogram_bounds(sample: &DataBlock, field: &DataField) -> Result<Vec<DataValue>> {
+        let data_type = field.data_type();
+        let sortable =
+            data_type.is_numeric() || data_type.is_string() || data_type.is_date_or_date_time();
+        if !sortable || sample.num_rows() == 0 {
+            return Ok(vec![]);
+        }
+
+        let column = sample.try_column_by_name(field.name())?.clone();
+        let block = DataBlock::create(DataSchemaRefExt::create(vec![field.clone()]), vec![column]);
+        let sorted = DataBlock::sort_block(
+            &block,
+            &[SortColumnDescription {
+                column_name: field.name().clone(),
+                asc: true,
+                nulls_first: true,
+            }],
+            None,
+        )?;
+
+        let values = sorted.column(0).to_array()?;
+        let nulls = values.null_count();
+        let buckets = HISTOGRAM_BUCKETS.min(values.len() - nulls);
+        let mut bounds: Vec<DataValue> = Vec::with_capacity(buckets);
+        for bucket in 1..=buckets {
+            let bound = values.try_get(nulls + (values.len() - nulls) * bucket / buckets - 1)?;
+            if bounds.last() != Some(&bound) {
+                bounds.push(bound);
+            }
+        }
+        Ok(bounds)
+    }
+}
@@ -23,12 +23,15 @@ use futures::StreamExt;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockCompression;
 use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::meta;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
 use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
 use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
 use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
 
 pub type AppendOperationLogEntryStream =
@@ -47,14 +50,19 @@ impl FuseTable {
             DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
         );
 
-        let da = ctx.get_data_accessor()?;
+        let bloom_filter_columns = self.bloom_filter_columns();
+        let compression = self.block_compression()?;
+
+        let da = ctx.get_upload_data_accessor()?;
 
         let mut segment_stream = BlockStreamWriter::write_block_stream(
             da.clone(),
-            stream,
-            self.table_info.schema().clone(),
+            self.physical_block_stream(stream)?,
+            self.physical_schema()?,
             chunk_block_num,
             block_size_threshold,
+            bloom_filter_columns,
+            compression,
         )
         .await;
 
@@ -63,7 +71,7 @@ impl FuseTable {
                 let log_entry_res = match segment {
                     Ok(seg) => {
                         let seg_loc = io::gen_segment_info_location();
-                        let bytes = serde_json::to_vec(&seg)?;
+                        let bytes = meta::encode(&seg)?;
                         da.put(&seg_loc, bytes).await?;
                         let log_entry = AppendOperationLogEntry::new(seg_loc, seg);
                         Ok(log_entry)
@@ -76,11 +84,28 @@ impl FuseTable {
         Ok(Box::pin(log_entries))
     }
 
-    fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
+    pub(super) fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
         self.table_info
             .options()
             .get(opt_key)
             .and_then(|s| s.parse::<T>().ok())
             .unwrap_or(default)
     }
+
+    pub(super) fn bloom_filter_columns(&self) -> Vec<String> {
+        self.table_info
+            .options()
+            .get(TBL_OPT_KEY_BLOOM_FILTER_COLUMNS)
+            .map(|s| {
+                s.split(',')
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(super) fn block_compression(&self) -> Result<BlockCompression> {
+        BlockCompression::try_create(self.table_info.options())
+    }
 }
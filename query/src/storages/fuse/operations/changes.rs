@@ -0,0 +1,115 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::io::SnapshotReader;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// The blocks of the table which are not in the snapshot at `since_snapshot_loc`, along
+    /// with the location of the snapshot of the table, `None` if the table is empty.
+    ///
+    /// The blocks are told apart by their locations, the rows of the blocks re-written since
+    /// then, e.g. by deletion and compaction, are in the blocks returned.
+    pub async fn blocks_added_since(
+        &self,
+        ctx: &QueryContext,
+        since_snapshot_loc: Option<&str>,
+    ) -> Result<(Vec<BlockMeta>, Option<String>)> {
+        let snapshot = match self.read_table_snapshot(ctx).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok((vec![], None)),
+        };
+        let snapshot_loc = self.snapshot_loc();
+        if snapshot_loc.as_deref() == since_snapshot_loc {
+            return Ok((vec![], snapshot_loc));
+        }
+
+        let da = ctx.get_data_accessor()?;
+        let since_segments = match since_snapshot_loc {
+            Some(loc) => {
+                SnapshotReader::read(da.as_ref(), loc, ctx.get_table_cache())
+                    .await
+                    .map_err(|e| {
+                        e.add_message_back(format!(
+                            " (snapshot {} of table {} may have been purged)",
+                            loc,
+                            self.name()
+                        ))
+                    })?
+                    .segments
+            }
+            None => vec![],
+        };
+
+        let added = subtract_locations(&snapshot.segments, &since_segments);
+        let removed = subtract_locations(&since_segments, &snapshot.segments);
+        let max_reads = ctx.get_settings().get_max_segment_reads()? as usize;
+        let mut segments = Vec::with_capacity(2);
+        for locations in [added, removed] {
+            let locations = locations.into_iter().cloned().collect::<Vec<_>>();
+            segments.push(
+                SegmentReader::read_segments(
+                    da.as_ref(),
+                    &locations,
+                    ctx.get_table_cache(),
+                    ctx.get_segment_info_cache(),
+                    max_reads,
+                )
+                .await?,
+            );
+        }
+
+        let blocks = subtract_blocks(&segments[0], &segments[1])
+            .into_iter()
+            .cloned()
+            .collect();
+        Ok((blocks, snapshot_loc))
+    }
+}
+
+/// The locations of `locations` not in `others`, in their order.
+pub(crate) fn subtract_locations<'a>(
+    locations: &'a [String],
+    others: &[String],
+) -> Vec<&'a String> {
+    let others: HashSet<&String> = others.iter().collect();
+    locations.iter().filter(|l| !others.contains(l)).collect()
+}
+
+/// The blocks of `segments` not in `others`, the blocks may be kept across segments, e.g. by
+/// compaction and deletion.
+pub(crate) fn subtract_blocks<'a, S: Borrow<SegmentInfo>, O: Borrow<SegmentInfo>>(
+    segments: &'a [S],
+    others: &[O],
+) -> Vec<&'a BlockMeta> {
+    let others: HashSet<&String> = others
+        .iter()
+        .flat_map(|s| s.borrow().blocks.iter().map(|b| &b.location.path))
+        .collect();
+    segments
+        .iter()
+        .flat_map(|s| s.borrow().blocks.iter())
+        .filter(|b| !others.contains(&b.location.path))
+        .collect()
+}
@@ -26,12 +26,17 @@ use uuid::Uuid;
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::meta;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::meta::SNAPSHOT_FORMAT_VERSION;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::operations::TableOperationLog;
 use crate::storages::fuse::statistics;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_SIZE;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_SIZE_COMPRESSED;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_COUNT;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 
 impl FuseTable {
@@ -45,41 +50,56 @@ impl FuseTable {
         // TODO OCC retry & resolves conflicts if applicable
 
         let prev = self.read_table_snapshot(ctx.as_ref()).await?;
+        let prev_row_count = prev.as_ref().map(|v| v.summary.row_count).unwrap_or(0);
         let new_snapshot = if overwrite {
-            let schema = self.table_info.meta.schema.as_ref().clone();
-            let (segments, summary) = Self::merge_append_operations(&schema, operation_log)?;
+            // the statistics are keyed by the positions of the columns in the blocks
+            let physical_schema = self.physical_schema()?;
+            let (segments, summary) =
+                Self::merge_append_operations(&physical_schema, operation_log)?;
             TableSnapshot {
+                format_version: SNAPSHOT_FORMAT_VERSION,
                 snapshot_id: Uuid::new_v4(),
                 prev_snapshot_id: prev.as_ref().map(|v| v.snapshot_id),
-                schema,
+                timestamp: TableSnapshot::now_timestamp(),
+                schema: self.table_info.meta.schema.as_ref().clone(),
+                dropped_columns: self.dropped_columns()?,
                 summary,
                 segments,
             }
         } else {
-            Self::merge_table_operations(self.table_info.meta.schema.as_ref(), prev, operation_log)?
+            self.merge_table_operations(prev, operation_log)?
         };
 
         let uuid = new_snapshot.snapshot_id;
         let snapshot_loc = io::snapshot_location(&uuid);
-        let bytes = serde_json::to_vec(&new_snapshot)?;
+        let bytes = meta::encode(&new_snapshot)?;
         let da = ctx.get_data_accessor()?;
         da.put(&snapshot_loc, bytes).await?;
 
-        self.commit_to_meta_server(ctx, snapshot_loc).await?;
+        let committed = self
+            .commit_to_meta_server(ctx.clone(), snapshot_loc, &new_snapshot.summary)
+            .await?;
+        // the changes staged in a transaction are not notified, they may never be committed
+        if committed.is_some() {
+            self.notify_commit(ctx.as_ref(), &new_snapshot, prev_row_count)
+                .await;
+        }
         Ok(())
     }
 
     fn merge_table_operations(
-        schema: &DataSchema,
+        &self,
         prev: Option<TableSnapshot>,
         ops: TableOperationLog,
     ) -> Result<TableSnapshot> {
+        let physical_schema = self.physical_schema()?;
+
         // 1. merge operations(appends, currently)
-        let (mut segs, stats) = Self::merge_append_operations(schema, ops)?;
+        let (mut segs, stats) = Self::merge_append_operations(&physical_schema, ops)?;
 
         // 2. merge stats with previous snapshot, if any
         let stats = if let Some(TableSnapshot { summary, .. }) = &prev {
-            statistics::merge_statistics(schema, &stats, summary)?
+            statistics::merge_statistics(&physical_schema, &stats, summary)?
         } else {
             stats
         };
@@ -91,33 +111,65 @@ impl FuseTable {
         };
 
         let new_snapshot = TableSnapshot {
+            format_version: SNAPSHOT_FORMAT_VERSION,
             snapshot_id: Uuid::new_v4(),
             prev_snapshot_id,
-            schema: schema.clone(),
+            timestamp: TableSnapshot::now_timestamp(),
+            schema: self.table_info.meta.schema.as_ref().clone(),
+            dropped_columns: self.dropped_columns()?,
             summary: stats,
             segments: segs,
         };
         Ok(new_snapshot)
     }
 
+    /// Points the table to the new snapshot, or stages the change if the session is in a
+    /// transaction, in which case `None` is returned.
     async fn commit_to_meta_server(
         &self,
         ctx: Arc<QueryContext>,
         new_snapshot_location: String,
-    ) -> Result<UpsertTableOptionReply> {
+        summary: &Statistics,
+    ) -> Result<Option<UpsertTableOptionReply>> {
         let table_id = self.table_info.ident.table_id;
         let table_version = self.table_info.ident.version;
-        let catalog = ctx.get_catalog();
-        catalog
-            .upsert_table_option(UpsertTableOptionReq::new(
-                &TableIdent {
-                    table_id,
-                    version: table_version,
-                },
-                TBL_OPT_KEY_SNAPSHOT_LOC,
-                new_snapshot_location,
-            ))
-            .await
+        let req = Self::snapshot_options_req(
+            &TableIdent {
+                table_id,
+                version: table_version,
+            },
+            new_snapshot_location,
+            summary,
+        );
+        match ctx.stage_table_change(req) {
+            None => Ok(None),
+            Some(req) => {
+                let catalog = ctx.get_catalog();
+                Ok(Some(catalog.upsert_table_option(req).await?))
+            }
+        }
+    }
+
+    // The snapshot location and the counters of the table are updated at once.
+    pub(crate) fn snapshot_options_req(
+        ident: &TableIdent,
+        snapshot_location: String,
+        summary: &Statistics,
+    ) -> UpsertTableOptionReq {
+        let mut req = UpsertTableOptionReq::new(ident, TBL_OPT_KEY_SNAPSHOT_LOC, snapshot_location);
+        req.options.insert(
+            TBL_OPT_KEY_ROW_COUNT.to_string(),
+            Some(summary.row_count.to_string()),
+        );
+        req.options.insert(
+            TBL_OPT_KEY_DATA_SIZE.to_string(),
+            Some(summary.uncompressed_byte_size.to_string()),
+        );
+        req.options.insert(
+            TBL_OPT_KEY_DATA_SIZE_COMPRESSED.to_string(),
+            Some(summary.compressed_byte_size.to_string()),
+        );
+        req
     }
 
     pub fn merge_append_operations(
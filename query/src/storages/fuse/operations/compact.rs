@@ -0,0 +1,204 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_stream::stream;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::meta;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::SEGMENT_FORMAT_VERSION;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+use crate::storages::index::NdvSketch;
+
+impl FuseTable {
+    // Merges the blocks smaller than the block size threshold into blocks of the threshold,
+    // and commits the re-written segments as a new snapshot.
+    //
+    // - segments of large blocks only, which are full, are kept as they are
+    // - the large blocks of the other segments are re-grouped into new segments, without
+    //   re-writing the blocks
+    // - the small blocks are read and merged into new blocks
+    //
+    // The blocks and segments replaced are still referenced by the previous snapshots,
+    // they are removed by the purge of the table history.
+    #[inline]
+    pub async fn do_compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let chunk_block_num = self.get_option(TBL_OPT_KEY_CHUNK_BLOCK_NUM, DEFAULT_CHUNK_BLOCK_NUM);
+        let block_size_threshold = self.get_option(
+            TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+            DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+        );
+        let is_small = |block: &BlockMeta| (block.block_size as usize) < block_size_threshold;
+
+        let da = ctx.get_upload_data_accessor()?;
+        let physical_schema = self.physical_schema()?;
+
+        let mut log_entries = Vec::with_capacity(snapshot.segments.len());
+        let mut large_blocks = vec![];
+        let mut small_blocks = vec![];
+        let mut rewritten_sketches = vec![];
+        for location in &snapshot.segments {
+            let segment = SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?;
+            if segment.blocks.len() >= chunk_block_num && !segment.blocks.iter().any(is_small) {
+                log_entries.push(AppendOperationLogEntry::new(location.clone(), segment));
+                continue;
+            }
+            rewritten_sketches.push(segment.ndv_sketches);
+            for block in segment.blocks {
+                match is_small(&block) {
+                    true => small_blocks.push(block),
+                    false => large_blocks.push(block),
+                }
+            }
+        }
+
+        // short cut, nothing to merge
+        let rewritten_segments = snapshot.segments.len() - log_entries.len();
+        if small_blocks.len() < 2 && rewritten_segments < 2 {
+            return Ok(());
+        }
+
+        let ndv_sketches = statistics::reduce_ndv_sketches(&rewritten_sketches);
+        for blocks in large_blocks.chunks(chunk_block_num) {
+            let segment =
+                Self::segment_of(blocks.to_vec(), physical_schema.as_ref(), &ndv_sketches)?;
+            log_entries.push(Self::write_segment(da.as_ref(), segment).await?);
+        }
+
+        let read_buffer_size = ctx.get_settings().get_storage_read_buffer_size()?;
+        let merged = Self::merge_blocks(
+            da.clone(),
+            self.full_block_reader(read_buffer_size)?,
+            small_blocks,
+            block_size_threshold,
+        );
+        let mut segment_stream = BlockStreamWriter::write_block_stream(
+            da.clone(),
+            self.physical_block_stream(merged)?,
+            physical_schema,
+            chunk_block_num,
+            block_size_threshold,
+            self.bloom_filter_columns(),
+            self.block_compression()?,
+        )
+        .await;
+        while let Some(segment) = segment_stream.next().await {
+            log_entries.push(Self::write_segment(da.as_ref(), segment?).await?);
+        }
+
+        // the snapshot is replaced as a whole, and fails to commit if the table has been
+        // changed meanwhile
+        self.do_commit(ctx, log_entries, true).await
+    }
+
+    // Reads the blocks one by one, and merges the successive ones into blocks of the threshold.
+    fn merge_blocks(
+        da: Arc<dyn DataAccessor>,
+        block_reader: BlockReader,
+        blocks: Vec<BlockMeta>,
+        block_size_threshold: usize,
+    ) -> SendableDataBlockStream {
+        let s = stream! {
+            let mut block_acc = vec![];
+            let mut block_size_acc = 0;
+            for block_meta in blocks {
+                let block = block_reader
+                    .read(da.clone(), &block_meta.location.path, block_meta.file_size, None)
+                    .await;
+                match block {
+                    Ok((block, _)) => {
+                        block_size_acc += block.memory_size();
+                        block_acc.push(block);
+                    }
+                    Err(e) => {
+                        yield(Err(e));
+                        return;
+                    }
+                }
+
+                if block_size_acc >= block_size_threshold {
+                    yield(DataBlock::concat_blocks(&block_acc));
+                    block_acc.clear();
+                    block_size_acc = 0;
+                }
+            }
+
+            if !block_acc.is_empty() {
+                yield(DataBlock::concat_blocks(&block_acc));
+            }
+        };
+        Box::pin(s)
+    }
+
+    // The blocks are not read, the sketches of the segments they come from are kept instead,
+    // which over-estimates the distinct values of the segment but not of the whole table.
+    pub(super) fn segment_of(
+        blocks: Vec<BlockMeta>,
+        schema: &DataSchema,
+        ndv_sketches: &HashMap<ColumnId, NdvSketch>,
+    ) -> Result<SegmentInfo> {
+        let col_stats = blocks.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+        let summary = Statistics {
+            row_count: blocks.iter().map(|b| b.row_count).sum(),
+            block_count: blocks.len() as u64,
+            uncompressed_byte_size: blocks.iter().map(|b| b.block_size).sum(),
+            compressed_byte_size: blocks.iter().map(|b| b.file_size).sum(),
+            col_stats: statistics::reduce_block_stats(&col_stats, schema)?,
+        };
+        Ok(SegmentInfo {
+            format_version: SEGMENT_FORMAT_VERSION,
+            blocks,
+            summary,
+            ndv_sketches: ndv_sketches.clone(),
+        })
+    }
+
+    pub(super) async fn write_segment(
+        da: &dyn DataAccessor,
+        segment: SegmentInfo,
+    ) -> Result<AppendOperationLogEntry> {
+        let location = io::gen_segment_info_location();
+        let bytes = meta::encode(&segment)?;
+        da.put(&location, bytes).await?;
+        Ok(AppendOperationLogEntry::new(location, segment))
+    }
+}
@@ -0,0 +1,228 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::Expression;
+use futures::StreamExt;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+use crate::storages::index::RangeFilter;
+
+impl FuseTable {
+    // Deletes the rows matching the predicate, and commits the re-written segments as a new
+    // snapshot.
+    //
+    // - segments and blocks which can not contain any matching row, according to their column
+    //   statistics, are kept as they are without being read
+    // - the other blocks are read and filtered, segments of no deleted rows are kept as well
+    // - the blocks of the segments which do have rows deleted are re-grouped into new segments,
+    //   only the blocks having rows deleted are re-written, without the deleted rows, as each
+    //   segment is done
+    //
+    // Like compaction, the data replaced is still referenced by the previous snapshots, and is
+    // only removed by the purge or the vacuum of the table history.
+    #[inline]
+    pub async fn do_delete(&self, ctx: Arc<QueryContext>, plan: &DeletePlan) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let predicate = match &plan.selection {
+            Some(predicate) => predicate,
+            // deletes all the rows, an empty snapshot is committed
+            None => return self.do_commit(ctx, vec![], true).await,
+        };
+
+        let chunk_block_num = self.get_option(TBL_OPT_KEY_CHUNK_BLOCK_NUM, DEFAULT_CHUNK_BLOCK_NUM);
+        let block_size_threshold = self.get_option(
+            TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+            DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+        );
+        let read_buffer_size = ctx.get_settings().get_storage_read_buffer_size()?;
+        let block_reader = self.full_block_reader(read_buffer_size)?;
+
+        let da = ctx.get_upload_data_accessor()?;
+        let schema = self.table_info.schema();
+        let physical_schema = self.physical_schema()?;
+        let range_filter = RangeFilter::try_create(predicate, physical_schema.clone())?;
+        let executor = Self::predicate_executor(&schema, predicate)?;
+
+        let mut log_entries = Vec::with_capacity(snapshot.segments.len());
+        let mut kept_blocks = vec![];
+        let mut rewritten_sketches = vec![];
+        let mut rewritten_segments = 0;
+        for location in &snapshot.segments {
+            let segment = SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?;
+            if !range_filter.eval(&segment.summary.col_stats)? {
+                log_entries.push(AppendOperationLogEntry::new(location.clone(), segment));
+                continue;
+            }
+
+            let mut untouched = Vec::with_capacity(segment.blocks.len());
+            let mut remaining = vec![];
+            for block_meta in &segment.blocks {
+                if !range_filter.eval(&block_meta.col_stats)? {
+                    untouched.push(block_meta.clone());
+                    continue;
+                }
+
+                let (block, _) = block_reader
+                    .read(
+                        da.clone(),
+                        &block_meta.location.path,
+                        block_meta.file_size,
+                        None,
+                    )
+                    .await?;
+                let block_after_delete = Self::delete_rows(&executor, &block)?;
+                if block_after_delete.num_rows() == block.num_rows() {
+                    untouched.push(block_meta.clone());
+                } else if block_after_delete.num_rows() > 0 {
+                    remaining.push(block_after_delete);
+                }
+            }
+
+            if untouched.len() == segment.blocks.len() {
+                log_entries.push(AppendOperationLogEntry::new(location.clone(), segment));
+                continue;
+            }
+
+            // the blocks having rows deleted are written segment by segment, so that only the
+            // re-written blocks of one segment are held in memory
+            kept_blocks.append(&mut untouched);
+            rewritten_sketches.push(segment.ndv_sketches);
+            rewritten_segments += 1;
+            if !remaining.is_empty() {
+                let mut entries = self
+                    .write_blocks(
+                        &ctx,
+                        remaining,
+                        physical_schema.clone(),
+                        chunk_block_num,
+                        block_size_threshold,
+                    )
+                    .await?;
+                log_entries.append(&mut entries);
+            }
+        }
+
+        // short cut, no rows deleted
+        if rewritten_segments == 0 {
+            return Ok(());
+        }
+
+        let ndv_sketches = statistics::reduce_ndv_sketches(&rewritten_sketches);
+        for blocks in kept_blocks.chunks(chunk_block_num) {
+            let segment =
+                Self::segment_of(blocks.to_vec(), physical_schema.as_ref(), &ndv_sketches)?;
+            log_entries.push(Self::write_segment(da.as_ref(), segment).await?);
+        }
+
+        // the snapshot is replaced as a whole, and fails to commit if the table has been
+        // changed meanwhile
+        self.do_commit(ctx, log_entries, true).await
+    }
+
+    // Writes the blocks into new blocks and segments.
+    async fn write_blocks(
+        &self,
+        ctx: &QueryContext,
+        blocks: Vec<DataBlock>,
+        physical_schema: DataSchemaRef,
+        chunk_block_num: usize,
+        block_size_threshold: usize,
+    ) -> Result<Vec<AppendOperationLogEntry>> {
+        let da = ctx.get_upload_data_accessor()?;
+        let blocks = futures::stream::iter(blocks.into_iter().map(Ok));
+        let mut segment_stream = BlockStreamWriter::write_block_stream(
+            da.clone(),
+            self.physical_block_stream(Box::pin(blocks))?,
+            physical_schema,
+            chunk_block_num,
+            block_size_threshold,
+            self.bloom_filter_columns(),
+            self.block_compression()?,
+        )
+        .await;
+
+        let mut log_entries = vec![];
+        while let Some(segment) = segment_stream.next().await {
+            log_entries.push(Self::write_segment(da.as_ref(), segment?).await?);
+        }
+        Ok(log_entries)
+    }
+
+    fn predicate_executor(
+        schema: &DataSchemaRef,
+        predicate: &Expression,
+    ) -> Result<ExpressionExecutor> {
+        let predicate_field = predicate.to_data_field(schema)?;
+        let predicate_schema = DataSchemaRefExt::create(vec![predicate_field]);
+        let executor = ExpressionExecutor::try_create(
+            "delete predicate executor",
+            schema.clone(),
+            predicate_schema,
+            vec![predicate.clone()],
+            false,
+        )?;
+        executor.validate()?;
+        Ok(executor)
+    }
+
+    // Keeps the rows of which the predicate is false or NULL.
+    fn delete_rows(executor: &ExpressionExecutor, block: &DataBlock) -> Result<DataBlock> {
+        let matched = executor.execute(block)?;
+        let matched = matched
+            .column(0)
+            .cast_with_type(&DataType::Boolean)?
+            .to_array()?;
+        let keep = matched
+            .bool()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v != Some(true))
+            .collect::<Vec<_>>();
+        DataBlock::filter_block(block, &DataColumn::from(Series::new(keep)))
+    }
+
+    // Reads all the columns of the blocks, in the current schema of the table.
+    pub(super) fn full_block_reader(&self, read_buffer_size: u64) -> Result<BlockReader> {
+        let schema = self.table_info.schema();
+        let projection = (0..schema.fields().len()).collect::<Vec<_>>();
+        Ok(BlockReader::create(
+            self.physical_schema()?,
+            self.physical_projection(&projection)?,
+            schema,
+            read_buffer_size,
+        ))
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_dal::StorageScheme;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::meta::ManifestColumn;
+use crate::storages::fuse::meta::ManifestFile;
+use crate::storages::fuse::meta::TableManifest;
+use crate::storages::fuse::meta::MANIFEST_FORMAT_VERSION;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+impl FuseTable {
+    /// Build the manifest of the given snapshot, or of the latest one if `snapshot_id` is None.
+    pub async fn do_export_manifest(
+        &self,
+        ctx: Arc<QueryContext>,
+        db_name: &str,
+        snapshot_id: Option<&str>,
+    ) -> Result<TableManifest> {
+        let snapshot = match snapshot_id {
+            None => self.read_table_snapshot(ctx.as_ref()).await?,
+            Some(id) => Some(self.find_snapshot(ctx.clone(), id).await?),
+        };
+
+        let mut manifest = TableManifest {
+            format_version: MANIFEST_FORMAT_VERSION,
+            database: db_name.to_string(),
+            table: self.name().to_string(),
+            snapshot_id: None,
+            schema: vec![],
+            row_count: 0,
+            files: vec![],
+        };
+
+        // the schema of the snapshot may differ from the current one, if the table evolved
+        let schema = match &snapshot {
+            Some(s) => s.schema.clone(),
+            None => self.schema().as_ref().clone(),
+        };
+        manifest.schema = schema
+            .fields()
+            .iter()
+            .map(|f| ManifestColumn {
+                name: f.name().clone(),
+                data_type: f.data_type().to_string(),
+                nullable: f.is_nullable(),
+            })
+            .collect();
+
+        if let Some(snapshot) = snapshot {
+            let root = storage_root(ctx.as_ref())?;
+            let da = ctx.get_data_accessor()?;
+            for loc in &snapshot.segments {
+                let segment = SegmentReader::read(da.as_ref(), loc, ctx.get_table_cache()).await?;
+                for block in segment.blocks {
+                    manifest.files.push(ManifestFile {
+                        path: format!("{}{}", root, block.location.path),
+                        row_count: block.row_count,
+                        file_size: block.file_size,
+                    });
+                }
+            }
+            manifest.snapshot_id = Some(snapshot.snapshot_id.to_simple().to_string());
+            manifest.row_count = snapshot.summary.row_count;
+        }
+
+        Ok(manifest)
+    }
+}
+
+// The root of the storage, which the block locations are relative to.
+fn storage_root(ctx: &QueryContext) -> Result<String> {
+    let storage_conf = &ctx.get_config().storage;
+    let root = match StorageScheme::from_str(&storage_conf.storage_type)? {
+        StorageScheme::S3 => format!("s3://{}/", storage_conf.s3.bucket),
+        StorageScheme::AzureStorageBlob => {
+            format!("azblob://{}/", storage_conf.azure_storage_blob.container)
+        }
+        StorageScheme::LocalFs => format!("{}/", storage_conf.disk.data_path),
+    };
+    Ok(root)
+}
@@ -12,15 +12,29 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod alter_column;
+mod analyze;
 mod append;
+mod changes;
 mod commit;
+mod compact;
+mod delete;
+mod export;
+mod navigate;
+mod notify;
 mod operation_log;
 mod optimize;
 mod part_info;
 mod read;
 mod read_plan;
+mod refresh;
+mod set_options;
 mod truncate;
+mod vacuum;
 
+pub(crate) use changes::subtract_blocks;
+pub(crate) use changes::subtract_locations;
+pub use notify::TableChangeEvent;
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
 pub use part_info::PartInfo;
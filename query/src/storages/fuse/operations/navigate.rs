@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::snapshot_location;
+use crate::storages::fuse::io::SnapshotReader;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_SIZE;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_SIZE_COMPRESSED;
+use crate::storages::fuse::TBL_OPT_KEY_DROPPED_COLUMNS;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_COUNT;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::NavigationPoint;
+use crate::storages::Table;
+
+impl FuseTable {
+    /// The table as of the given snapshot, it is planned against the segments and
+    /// the schema of that snapshot instead of the latest ones.
+    pub async fn navigate(
+        &self,
+        ctx: Arc<QueryContext>,
+        point: &NavigationPoint,
+    ) -> Result<FuseTable> {
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(id) => self.find_snapshot(ctx, id).await?,
+            NavigationPoint::TimePoint(millis) => self.find_snapshot_at(ctx, *millis).await?,
+        };
+
+        let mut table_info = self.table_info.clone();
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
+        let summary = &snapshot.summary;
+        for (key, value) in [
+            (
+                TBL_OPT_KEY_SNAPSHOT_LOC,
+                snapshot_location(&snapshot.snapshot_id),
+            ),
+            (TBL_OPT_KEY_ROW_COUNT, summary.row_count.to_string()),
+            (
+                TBL_OPT_KEY_DATA_SIZE,
+                summary.uncompressed_byte_size.to_string(),
+            ),
+            (
+                TBL_OPT_KEY_DATA_SIZE_COMPRESSED,
+                summary.compressed_byte_size.to_string(),
+            ),
+        ] {
+            table_info.meta.options.insert(key.to_string(), value);
+        }
+        // the blocks of the snapshot are laid out as the columns dropped by then tell
+        match snapshot.dropped_columns.is_empty() {
+            true => table_info.meta.options.remove(TBL_OPT_KEY_DROPPED_COLUMNS),
+            false => table_info.meta.options.insert(
+                TBL_OPT_KEY_DROPPED_COLUMNS.to_string(),
+                serde_json::to_string(&snapshot.dropped_columns)?,
+            ),
+        };
+        Ok(FuseTable { table_info })
+    }
+
+    pub(crate) async fn find_snapshot(
+        &self,
+        ctx: Arc<QueryContext>,
+        snapshot_id: &str,
+    ) -> Result<TableSnapshot> {
+        self.read_snapshot_history(ctx)
+            .await?
+            .into_iter()
+            .find(|s| s.snapshot_id.to_simple().to_string() == snapshot_id)
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Unknown snapshot {} of table {}",
+                    snapshot_id,
+                    self.name()
+                ))
+            })
+    }
+
+    // The history is ordered from the latest snapshot to the oldest one, the snapshots
+    // without timestamp are skipped.
+    async fn find_snapshot_at(&self, ctx: Arc<QueryContext>, millis: u64) -> Result<TableSnapshot> {
+        self.read_snapshot_history(ctx)
+            .await?
+            .into_iter()
+            .find(|s| matches!(s.timestamp, Some(t) if t <= millis))
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "No snapshot of table {} is committed at or before timestamp {}",
+                    self.name(),
+                    millis
+                ))
+            })
+    }
+
+    pub(crate) async fn read_snapshot_history(
+        &self,
+        ctx: Arc<QueryContext>,
+    ) -> Result<Vec<TableSnapshot>> {
+        let da = ctx.get_data_accessor()?;
+        let snapshot_loc = self.snapshot_loc();
+        SnapshotReader::read_snapshot_history(
+            da.as_ref(),
+            snapshot_loc.as_ref(),
+            ctx.get_table_cache(),
+        )
+        .await
+    }
+}
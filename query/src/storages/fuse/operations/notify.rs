@@ -0,0 +1,104 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use reqwest::header::CONTENT_TYPE;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+
+/// How long a commit waits for the webhook, it is not retried.
+const NOTIFY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The change committed to a fuse table, posted as JSON to `table_change_webhook_url`
+/// after each successful commit, so that the downstream jobs can be triggered.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableChangeEvent {
+    pub tenant: String,
+    /// `'db_name'.'table_name'` of the table committed to.
+    pub table: String,
+    pub table_id: u64,
+    pub snapshot_id: String,
+    pub prev_snapshot_id: Option<String>,
+    /// The rows of the new snapshot minus the rows of the previous one, negative
+    /// if rows are deleted.
+    pub row_delta: i64,
+    pub row_count: u64,
+    /// The time the snapshot is committed, in unix milliseconds.
+    pub timestamp: Option<u64>,
+}
+
+impl FuseTable {
+    // The notification is best effort, the commit is done already and never fails by it.
+    pub(crate) async fn notify_commit(
+        &self,
+        ctx: &QueryContext,
+        snapshot: &TableSnapshot,
+        prev_row_count: u64,
+    ) {
+        let config = ctx.get_config();
+        let url = config.query.table_change_webhook_url;
+        if url.is_empty() {
+            return;
+        }
+
+        let event = TableChangeEvent {
+            tenant: config.query.tenant_id,
+            table: self.table_info.desc.clone(),
+            table_id: self.table_info.ident.table_id,
+            snapshot_id: snapshot.snapshot_id.to_simple().to_string(),
+            prev_snapshot_id: snapshot.prev_snapshot_id.map(|v| v.to_simple().to_string()),
+            row_delta: snapshot.summary.row_count as i64 - prev_row_count as i64,
+            row_count: snapshot.summary.row_count,
+            timestamp: snapshot.timestamp,
+        };
+
+        if let Err(cause) = Self::post_event(&url, &event).await {
+            tracing::warn!(
+                "Failed to notify the commit of table {}, snapshot {}: {}",
+                event.table,
+                event.snapshot_id,
+                cause
+            );
+        }
+    }
+
+    async fn post_event(url: &str, event: &TableChangeEvent) -> Result<()> {
+        let body = serde_json::to_vec(event)?;
+        let client = reqwest::Client::builder()
+            .timeout(NOTIFY_TIMEOUT)
+            .build()
+            .map_err(|e| ErrorCode::NetworkRequestError(e.to_string()))?;
+        let response = client
+            .post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| ErrorCode::NetworkRequestError(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(ErrorCode::NetworkRequestError(format!(
+                "webhook responded {}",
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
@@ -96,7 +96,7 @@ impl FuseTable {
         Ok(())
     }
 
-    async fn blocks_of(
+    pub(crate) async fn blocks_of(
         &self,
         data_accessor: Arc<dyn DataAccessor>,
         locations: impl Iterator<Item = impl AsRef<str>>,
@@ -107,6 +107,9 @@ impl FuseTable {
             let res = SegmentReader::read(data_accessor.as_ref(), x, ctx.get_table_cache()).await?;
             for block_meta in res.blocks {
                 result.insert(block_meta.location.path);
+                if let Some(loc) = block_meta.bloom_filter_location {
+                    result.insert(loc);
+                }
             }
         }
         Ok(result)
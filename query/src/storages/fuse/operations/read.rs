@@ -14,20 +14,39 @@
 //
 
 use std::sync::Arc;
+use std::time::Instant;
 
-use common_datavalues::DataSchema;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_metrics::label_counter;
+use common_metrics::label_counter_with_val;
 use common_planners::Extras;
-use common_streams::ParquetSource;
+use common_planners::Sample;
 use common_streams::SendableDataBlockStream;
-use common_streams::Source;
+use common_tracing::tracing;
 use common_tracing::tracing_futures::Instrument;
 use futures::StreamExt;
+use rand::Rng;
 
 use super::part_info::PartInfo;
+use crate::sessions::PartScanMetrics;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::cache::CachedDataAccessor;
+use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::metrics::METRIC_FUSE_BLOCK_CACHE_HITS;
+use crate::storages::fuse::metrics::METRIC_FUSE_BLOCK_CACHE_MISSES;
+use crate::storages::fuse::metrics::METRIC_FUSE_READ_PARQUET_BYTES;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FUSE_VIRTUAL_COLUMN_BLOCK_LOCATION;
+use crate::storages::fuse::FUSE_VIRTUAL_COLUMN_ROW_NUMBER;
 
 impl FuseTable {
     #[inline]
@@ -48,6 +67,14 @@ impl FuseTable {
                 .collect::<Vec<usize>>()
         };
 
+        let row_sample = match push_downs {
+            Some(Extras {
+                sample: Some(Sample::Row(percent)),
+                ..
+            }) => Some(*percent),
+            _ => None,
+        };
+
         let bite_size = ctx.get_settings().get_parallel_read_threads()?;
         let ctx_clone = ctx.clone();
         let iter =
@@ -59,51 +86,219 @@ impl FuseTable {
                 },
             )
             .flatten();
+        // the virtual columns are filled once the stored ones are read, the first column of
+        // the table is read for the rows of a projection of only virtual columns
+        let num_columns = self.table_info.schema().fields().len();
+        let stored = projection
+            .iter()
+            .cloned()
+            .filter(|i| *i < num_columns)
+            .collect::<Vec<_>>();
+        let virtual_fill = match stored.len() == projection.len() {
+            true => None,
+            false => Some(Arc::new(
+                self.virtual_fill(ctx.as_ref(), &projection, &stored)
+                    .await?,
+            )),
+        };
+        let projection = match stored.is_empty() {
+            true => vec![0],
+            false => stored,
+        };
+
         let da = ctx.get_data_accessor()?;
-        let arrow_schema = self.table_info.schema().to_arrow();
-        let table_schema = Arc::new(DataSchema::from(arrow_schema));
+        let read_buffer_size = ctx.get_settings().get_storage_read_buffer_size()?;
+        let output_schema = Arc::new(self.table_info.schema().project(projection.clone()));
+        let block_reader = Arc::new(BlockReader::create(
+            self.physical_schema()?,
+            self.physical_projection(&projection)?,
+            output_schema,
+            read_buffer_size,
+        ));
 
         let part_stream = futures::stream::iter(iter);
-
-        let read_buffer_size = ctx.get_settings().get_storage_read_buffer_size()?;
+        let meta_cache = ctx.get_parquet_meta_cache();
+        let block_cache = ctx.get_block_data_cache();
+        let query_profile = ctx.get_query_profile();
+        let config = ctx.get_config();
+        let tenant = Arc::new(config.query.tenant_id.clone());
+        let cluster = Arc::new(config.query.cluster_id.clone());
         let stream = part_stream
             .map(move |part| {
-                let da = da.clone();
-                let table_schema = table_schema.clone();
-                let projection = projection.clone();
+                let remote_da = da.clone();
+                let block_cache = block_cache.clone();
+                let block_reader = block_reader.clone();
+                let meta_cache = meta_cache.clone();
+                let query_profile = query_profile.clone();
+                let virtual_fill = virtual_fill.clone();
+                let tenant = tenant.clone();
+                let cluster = cluster.clone();
                 async move {
+                    let start = Instant::now();
                     let part_info = PartInfo::decode(&part.name)?;
                     let part_location = part_info.location();
                     let part_len = part_info.length();
 
-                    let mut source = ParquetSource::with_hints(
-                        da,
-                        part_info.location().to_owned(),
-                        table_schema,
-                        projection,
-                        None, // TODO cache parquet meta
-                        Some(part_len),
-                        Some(read_buffer_size),
-                    );
-                    source
-                        .read()
+                    let cache_hit =
+                        matches!(&block_cache, Some(cache) if cache.contains(part_location));
+                    if block_cache.is_some() {
+                        let metric = match cache_hit {
+                            true => METRIC_FUSE_BLOCK_CACHE_HITS,
+                            false => METRIC_FUSE_BLOCK_CACHE_MISSES,
+                        };
+                        label_counter(metric, &tenant, &cluster);
+                    }
+                    let da: Arc<dyn DataAccessor> = match block_cache {
+                        Some(cache) => {
+                            cache.fetch(remote_da.as_ref(), part_location).await?;
+                            Arc::new(CachedDataAccessor::new(cache, remote_da))
+                        }
+                        None => remote_da,
+                    };
+
+                    let metadata = match meta_cache.as_ref() {
+                        Some(cache) => Some(
+                            cache
+                                .read(da.as_ref(), part_location, Some(part_len))
+                                .await?,
+                        ),
+                        None => None,
+                    };
+
+                    let (block, read_metrics) = block_reader
+                        .read(da, part_location, part_len, metadata)
                         .await
                         .map_err(|e| {
                             ErrorCode::ParquetError(format!(
                                 "fail to read block {}, {}",
                                 part_location, e
                             ))
-                        })?
-                        .ok_or_else(|| {
-                            ErrorCode::ParquetError(format!(
-                                "reader returns None for block {}",
-                                part_location,
-                            ))
-                        })
+                        })?;
+
+                    let metrics = PartScanMetrics {
+                        part: part_location.to_owned(),
+                        read_bytes: read_metrics.read_bytes,
+                        read_cost_us: start.elapsed().as_micros() as u64,
+                        decode_cost_us: read_metrics.decode_cost.as_micros() as u64,
+                        cache_hit,
+                    };
+                    tracing::debug!("read part {:?}", metrics);
+                    label_counter_with_val(
+                        METRIC_FUSE_READ_PARQUET_BYTES,
+                        metrics.read_bytes,
+                        &tenant,
+                        &cluster,
+                    );
+                    query_profile.add_part_scan(metrics);
+                    let block = match virtual_fill {
+                        Some(fill) => fill.apply(&block, part_location),
+                        None => block,
+                    };
+                    match row_sample {
+                        Some(percent) => Self::sample_rows(&block, percent),
+                        None => Ok(block),
+                    }
                 }
             })
             .buffer_unordered(bite_size as usize)
             .instrument(common_tracing::tracing::Span::current());
         Ok(Box::pin(stream))
     }
+
+    async fn virtual_fill(
+        &self,
+        ctx: &QueryContext,
+        projection: &[usize],
+        stored: &[usize],
+    ) -> Result<VirtualFill> {
+        let schema = self.table_info.schema();
+        let virtual_columns = self.virtual_columns();
+        let mut fields = Vec::with_capacity(projection.len());
+        let mut columns = Vec::with_capacity(projection.len());
+        for index in projection {
+            match stored.iter().position(|i| i == index) {
+                Some(position) => {
+                    fields.push(schema.field(*index).clone());
+                    columns.push(ScanColumn::Stored(position));
+                }
+                None => {
+                    let field = virtual_columns
+                        .get(*index - schema.fields().len())
+                        .ok_or_else(|| {
+                            ErrorCode::LogicalError(format!(
+                                "Logical error: no column of index {} in table {}",
+                                index, self.table_info.name
+                            ))
+                        })?;
+                    columns.push(match field.name().as_str() {
+                        FUSE_VIRTUAL_COLUMN_BLOCK_LOCATION => ScanColumn::BlockLocation,
+                        FUSE_VIRTUAL_COLUMN_ROW_NUMBER => ScanColumn::RowNumber,
+                        _ => ScanColumn::SnapshotId,
+                    });
+                    fields.push(field.clone());
+                }
+            }
+        }
+
+        // the table read is of a single snapshot, the one time traveled to if any
+        let snapshot_id = match columns.iter().any(|c| matches!(c, ScanColumn::SnapshotId)) {
+            true => self
+                .read_table_snapshot(ctx)
+                .await?
+                .map(|snapshot| snapshot.snapshot_id.to_simple().to_string())
+                .unwrap_or_default(),
+            false => String::new(),
+        };
+
+        Ok(VirtualFill {
+            schema: DataSchemaRefExt::create(fields),
+            columns,
+            snapshot_id,
+        })
+    }
+
+    // Keeps each row with the probability of the percent.
+    fn sample_rows(block: &DataBlock, percent: f64) -> Result<DataBlock> {
+        let mut rng = rand::thread_rng();
+        let indices = (0..block.num_rows() as u32)
+            .filter(|_| rng.gen::<f64>() * 100.0 < percent)
+            .collect::<Vec<_>>();
+        DataBlock::block_take_by_indices(block, &[], &indices)
+    }
+}
+
+enum ScanColumn {
+    // the position of the column in the blocks read
+    Stored(usize),
+    BlockLocation,
+    RowNumber,
+    SnapshotId,
+}
+
+// Assembles the blocks of a projection of virtual columns, out of the blocks read of its
+// stored columns. The row numbers are of the rows in the blocks, before any sampling.
+struct VirtualFill {
+    schema: DataSchemaRef,
+    columns: Vec<ScanColumn>,
+    snapshot_id: String,
+}
+
+impl VirtualFill {
+    fn apply(&self, block: &DataBlock, location: &str) -> DataBlock {
+        let num_rows = block.num_rows();
+        let string = |v: &str| DataColumn::Constant(DataValue::String(Some(v.into())), num_rows);
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| match column {
+                ScanColumn::Stored(position) => block.column(*position).clone(),
+                ScanColumn::BlockLocation => string(location),
+                ScanColumn::RowNumber => {
+                    DataColumn::Array(Series::new((0..num_rows as u64).collect::<Vec<_>>()))
+                }
+                ScanColumn::SnapshotId => string(&self.snapshot_id),
+            })
+            .collect();
+        DataBlock::create(self.schema.clone(), columns)
+    }
 }
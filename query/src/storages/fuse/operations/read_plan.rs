@@ -13,19 +13,27 @@
 //  limitations under the License.
 //
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_dal::DataAccessor;
+use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::Extras;
 use common_planners::Part;
 use common_planners::Partitions;
+use common_planners::Sample;
 use common_planners::Statistics;
+use rand::seq::index;
 
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io::SegmentReader;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::part_info::PartInfo;
 use crate::storages::fuse::pruning::apply_block_pruning;
+use crate::storages::fuse::statistics;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -39,16 +47,107 @@ impl FuseTable {
         match snapshot {
             Some(snapshot) => {
                 let da = ctx.get_data_accessor()?;
-                let schema = self.table_info.schema();
-                let block_metas =
-                    apply_block_pruning(&snapshot, schema, &push_downs, da, ctx.clone()).await?;
-                let (statistics, parts) = Self::to_partitions(&block_metas, push_downs);
+                // the column statistics are keyed by the positions of the columns in the blocks
+                let schema = self.physical_schema()?;
+                let block_metas = apply_block_pruning(
+                    &snapshot,
+                    schema.clone(),
+                    &push_downs,
+                    da.clone(),
+                    ctx.clone(),
+                )
+                .await?;
+                let block_metas = match push_downs.as_ref().and_then(|e| e.sample.as_ref()) {
+                    Some(Sample::Block(percent)) => Self::sample_blocks(block_metas, *percent),
+                    _ => block_metas,
+                };
+                let push_downs = match push_downs {
+                    Some(mut extras) => {
+                        if let Some(projection) = &extras.projection {
+                            // the virtual columns are not stored in the blocks
+                            let num_columns = self.table_info.schema().fields().len();
+                            let stored = projection
+                                .iter()
+                                .cloned()
+                                .filter(|i| *i < num_columns)
+                                .collect::<Vec<_>>();
+                            extras.projection = Some(self.physical_projection(&stored)?);
+                        }
+                        Some(extras)
+                    }
+                    None => None,
+                };
+                let (mut statistics, parts) = Self::to_partitions(&block_metas, push_downs);
+                // the statistics of ANALYZE TABLE are preferred, unless the table has been
+                // changed since then
+                match self.read_analyzed_statistics(ctx.as_ref()).await? {
+                    Some(analyzed) if analyzed.snapshot == self.snapshot_loc() => {
+                        for column in analyzed.columns {
+                            statistics
+                                .column_ndvs
+                                .insert(column.name.clone(), column.ndv);
+                            statistics
+                                .column_null_counts
+                                .insert(column.name, column.null_count);
+                        }
+                    }
+                    _ => {
+                        statistics.column_ndvs =
+                            Self::estimate_ndvs(ctx.as_ref(), &snapshot, &schema, da.as_ref())
+                                .await?;
+                    }
+                }
                 Ok((statistics, parts))
             }
             None => Ok((Statistics::default(), vec![])),
         }
     }
 
+    // Keeps the percent of the blocks picked at random, rounded up to keep at least one block of
+    // a table not empty, in the order of the table.
+    fn sample_blocks(block_metas: Vec<BlockMeta>, percent: f64) -> Vec<BlockMeta> {
+        let total = block_metas.len();
+        let amount = ((total as f64 * percent / 100.0).ceil() as usize).min(total);
+        if amount == total {
+            return block_metas;
+        }
+        let mut picked = index::sample(&mut rand::thread_rng(), total, amount).into_vec();
+        picked.sort_unstable();
+        picked.into_iter().map(|i| block_metas[i].clone()).collect()
+    }
+
+    // Merges the sketches of all the segments, the estimates are of the whole table, which
+    // bound the ones of the pruned blocks. The segments are read through the caches, as the
+    // pruning does, which has just read them.
+    async fn estimate_ndvs(
+        ctx: &QueryContext,
+        snapshot: &TableSnapshot,
+        schema: &DataSchemaRef,
+        da: &dyn DataAccessor,
+    ) -> Result<BTreeMap<String, u64>> {
+        let segments = SegmentReader::read_segments(
+            da,
+            &snapshot.segments,
+            ctx.get_table_cache(),
+            ctx.get_segment_info_cache(),
+            ctx.get_settings().get_max_segment_reads()? as usize,
+        )
+        .await?;
+        let sketches = segments
+            .iter()
+            .map(|segment| &segment.ndv_sketches)
+            .collect::<Vec<_>>();
+
+        Ok(statistics::reduce_ndv_sketches(&sketches)
+            .into_iter()
+            .filter(|(col_id, _)| (*col_id as usize) < schema.fields().len())
+            .map(|(col_id, sketch)| {
+                let name = schema.field(col_id as usize).name().clone();
+                (name, sketch.estimate())
+            })
+            .collect())
+    }
+
     pub fn to_partitions(
         blocks_metas: &[BlockMeta],
         push_downs: Option<Extras>,
@@ -0,0 +1,57 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::TableStatistics;
+
+impl FuseTable {
+    // The counters are missing or lag behind the snapshot if it is committed by a node
+    // not keeping them, they are re-computed from the summary of the current snapshot.
+    pub async fn do_refresh_statistics(&self, ctx: Arc<QueryContext>) -> Result<bool> {
+        let (location, snapshot) = match (
+            self.snapshot_loc(),
+            self.read_table_snapshot(ctx.as_ref()).await?,
+        ) {
+            (Some(location), Some(snapshot)) => (location, snapshot),
+            _ => return Ok(false),
+        };
+
+        let summary = &snapshot.summary;
+        let expected = TableStatistics {
+            num_rows: summary.row_count,
+            data_size: summary.uncompressed_byte_size,
+            data_size_compressed: summary.compressed_byte_size,
+        };
+        if self.counters().ok().flatten().as_ref() == Some(&expected) {
+            return Ok(false);
+        }
+
+        // fails if the table is committed meanwhile, which keeps the counters of the new snapshot
+        ctx.get_catalog()
+            .upsert_table_option(Self::snapshot_options_req(
+                &self.table_info.ident,
+                location,
+                summary,
+            ))
+            .await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,45 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpsertTableOptionReq;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::table_options::validate_table_options;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    pub async fn do_set_options(
+        &self,
+        ctx: Arc<QueryContext>,
+        options: &HashMap<String, String>,
+    ) -> Result<()> {
+        let options = validate_table_options(options, &self.table_info.schema())?;
+
+        // fails if the table has been changed meanwhile
+        let req = UpsertTableOptionReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.version),
+            options: options.into_iter().map(|(k, v)| (k, Some(v))).collect(),
+            schema: None,
+        };
+        ctx.get_catalog().upsert_table_option(req).await?;
+        Ok(())
+    }
+}
@@ -15,43 +15,50 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
-use common_meta_types::UpsertTableOptionReq;
 use common_planners::TruncateTablePlan;
-use uuid::Uuid;
+use common_tracing::tracing;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
-use crate::storages::fuse::io;
 use crate::storages::fuse::FuseTable;
-use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::Table;
 
 impl FuseTable {
+    // Truncates the table by committing an empty snapshot, the previous snapshots are kept as
+    // the history of the table, which is readable by time travel until it is expired by its
+    // retention, see `SnapshotExpirer`.
+    //
+    // With PURGE, the history before the truncation is vacuumed right after the commit, only
+    // the empty snapshot is left. It is removed after the commit, so that a failed commit
+    // leaves the table as it was.
     #[inline]
     pub async fn do_truncate(&self, ctx: Arc<QueryContext>, plan: TruncateTablePlan) -> Result<()> {
-        if let Some(prev_snapshot) = self.read_table_snapshot(ctx.as_ref()).await? {
-            let prev_id = prev_snapshot.snapshot_id;
-            let mut new_snapshot = prev_snapshot;
-            new_snapshot.segments = vec![];
-            new_snapshot.prev_snapshot_id = Some(prev_id);
-            new_snapshot.summary = Default::default();
-            new_snapshot.snapshot_id = Uuid::new_v4();
-            let new_snapshot_loc = io::snapshot_location(&new_snapshot.snapshot_id);
-            let da = ctx.get_data_accessor()?;
-            let bytes = serde_json::to_vec(&new_snapshot)?;
-            da.put(&new_snapshot_loc, bytes).await?;
-
-            if plan.purge {
-                let keep_last_snapshot = false;
-                self.do_optimize(ctx.clone(), keep_last_snapshot).await?
-            }
-            ctx.get_catalog()
-                .upsert_table_option(UpsertTableOptionReq::new(
-                    &self.table_info.ident,
-                    TBL_OPT_KEY_SNAPSHOT_LOC,
-                    new_snapshot_loc,
-                ))
-                .await?;
+        // the purged data could not be brought back by ROLLBACK
+        if ctx.in_transaction() {
+            return Err(ErrorCode::TransactionError(format!(
+                "Can not truncate table {} in a transaction",
+                self.name()
+            )));
+        }
+
+        // nothing to truncate, no side effects
+        if self.read_table_snapshot(ctx.as_ref()).await?.is_none() {
+            return Ok(());
+        }
+
+        self.do_commit(ctx.clone(), vec![], true).await?;
+
+        if plan.purge {
+            // the table is reloaded to see the empty snapshot, which is the only one retained
+            let table = ctx.get_catalog().get_table(&plan.db, &plan.table).await?;
+            let stats = table.vacuum(ctx.clone(), u64::MAX, false).await?;
+            tracing::info!(
+                "purge truncated table {}: {:?}",
+                self.table_info.desc,
+                stats
+            );
         }
 
         Ok(())
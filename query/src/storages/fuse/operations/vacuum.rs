@@ -0,0 +1,114 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::snapshot_location;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::VacuumStatistics;
+
+impl FuseTable {
+    // Only the files reachable from the snapshot history are collected, the blocks and segments
+    // written by the operations that failed to commit are not referenced by any snapshot.
+    pub async fn do_vacuum(
+        &self,
+        ctx: Arc<QueryContext>,
+        retain_since: u64,
+        dry_run: bool,
+    ) -> Result<VacuumStatistics> {
+        let da = ctx.get_data_accessor()?;
+        let snapshots = self.read_snapshot_history(ctx.clone()).await?;
+        let (retained, expired) = snapshots.split_at(Self::retained_len(&snapshots, retain_since));
+        if expired.is_empty() {
+            return Ok(VacuumStatistics::default());
+        }
+
+        let retained_segments: HashSet<&String> =
+            retained.iter().flat_map(|s| s.segments.iter()).collect();
+        let expired_segments: HashSet<&String> = expired
+            .iter()
+            .flat_map(|s| s.segments.iter())
+            .filter(|s| !retained_segments.contains(s))
+            .collect();
+
+        // a block may be shared by the segments of different snapshots, e.g. kept by compaction
+        let retained_blocks = self
+            .blocks_of(da.clone(), retained_segments.iter(), ctx.clone())
+            .await?;
+        let mut expired_blocks = HashSet::new();
+        let mut block_files = vec![];
+        let mut block_bytes = 0;
+        for location in &expired_segments {
+            let segment = SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?;
+            for block in segment.blocks {
+                let path = block.location.path;
+                if retained_blocks.contains(&path) || !expired_blocks.insert(path.clone()) {
+                    continue;
+                }
+                block_bytes += block.file_size;
+                block_files.push(path);
+                if let Some(bloom_filter_location) = block.bloom_filter_location {
+                    block_files.push(bloom_filter_location);
+                }
+            }
+        }
+
+        let stats = VacuumStatistics {
+            snapshots: expired.len() as u64,
+            segments: expired_segments.len() as u64,
+            blocks: expired_blocks.len() as u64,
+            block_bytes,
+        };
+        if dry_run {
+            return Ok(stats);
+        }
+
+        tracing::info!("vacuum table {}: {:?}", self.table_info.desc, stats);
+
+        // NOTE: not transactional, the files are removed from the leaves up and the snapshots
+        // from the oldest one, so that an interrupted vacuum leaves a readable history.
+        for location in block_files {
+            da.remove(&location).await?;
+        }
+        for location in expired_segments {
+            da.remove(location).await?;
+        }
+        for snapshot in expired.iter().rev() {
+            da.remove(&snapshot_location(&snapshot.snapshot_id)).await?;
+        }
+
+        Ok(stats)
+    }
+
+    // The history is ordered from the latest snapshot to the oldest one. The snapshots committed
+    // after `retain_since` are retained, along with the latest one committed at or before it,
+    // which is the table as of `retain_since`. The current snapshot is always retained.
+    fn retained_len(snapshots: &[TableSnapshot], retain_since: u64) -> usize {
+        match snapshots
+            .iter()
+            .position(|s| !matches!(s.timestamp, Some(t) if t > retain_since))
+        {
+            Some(pos) => pos + 1,
+            None => snapshots.len(),
+        }
+    }
+}
@@ -17,20 +17,28 @@ use std::sync::Arc;
 
 use common_dal::DataAccessor;
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_metrics::label_counter_with_val;
 use common_planners::Extras;
 use common_tracing::tracing;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use metrics::histogram;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io::snapshot_location;
+use crate::storages::fuse::io::BloomFilterReader;
 use crate::storages::fuse::io::SegmentReader;
 use crate::storages::fuse::io::SnapshotReader;
 use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::metrics::METRIC_FUSE_PRUNE_RATIO;
+use crate::storages::fuse::metrics::METRIC_FUSE_PRUNING_BLOCKS;
+use crate::storages::fuse::metrics::METRIC_FUSE_PRUNING_KEPT_BLOCKS;
 use crate::storages::index::BlockStatistics;
+use crate::storages::index::BloomFilterPredicate;
 use crate::storages::index::RangeFilter;
 
 pub struct BlockPruner {
@@ -53,13 +61,20 @@ impl BlockPruner {
         push_down: &Option<Extras>,
         ctx: Arc<QueryContext>,
     ) -> Result<Vec<BlockMeta>> {
-        let block_pred: Pred = match push_down {
-            Some(exprs) if !exprs.filters.is_empty() => {
-                // for the time being, we only handle the first expr
-                let verifiable_expression = RangeFilter::try_create(&exprs.filters[0], schema)?;
-                Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v))
+        let filter = push_down
+            .as_ref()
+            .and_then(|extras| extras.filters.iter().cloned().reduce(|l, r| l.and(r)));
+        let (block_pred, bloom_pred): (Pred, Option<BloomFilterPredicate>) = match filter {
+            Some(filter) => {
+                // the pushed down filters are a conjunction
+                let bloom_pred = BloomFilterPredicate::try_create(&filter, &schema)?;
+                let verifiable_expression = RangeFilter::try_create(&filter, schema)?;
+                (
+                    Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v)),
+                    bloom_pred,
+                )
             }
-            _ => Box::new(|_: &BlockStatistics| Ok(true)),
+            None => (Box::new(|_: &BlockStatistics| Ok(true)), None),
         };
 
         let snapshot = SnapshotReader::read(
@@ -68,42 +83,72 @@ impl BlockPruner {
             ctx.get_table_cache(),
         )
         .await?;
-        let segment_num = snapshot.segments.len();
-        let segment_locs = snapshot.segments;
-
-        if segment_locs.is_empty() {
+        if snapshot.segments.is_empty() {
             return Ok(vec![]);
         };
 
-        let res = futures::stream::iter(segment_locs)
-            .map(|seg_loc| async {
-                let segment_info = SegmentReader::read(
-                    self.data_accessor.as_ref(),
-                    seg_loc,
-                    ctx.get_table_cache(),
-                )
-                .await?;
-                Self::filter_segment(segment_info, &block_pred)
+        let segments = SegmentReader::read_segments(
+            self.data_accessor.as_ref(),
+            &snapshot.segments,
+            ctx.get_table_cache(),
+            ctx.get_segment_info_cache(),
+            ctx.get_settings().get_max_segment_reads()? as usize,
+        )
+        .await?;
+        let mut res = vec![];
+        for segment_info in segments.iter() {
+            res.extend(Self::filter_segment(segment_info, &block_pred)?);
+        }
+
+        match bloom_pred {
+            Some(bloom_pred) => self.filter_by_bloom_filter(res, &bloom_pred, ctx).await,
+            None => Ok(res),
+        }
+    }
+
+    // Only the blocks that survived the range filter have their bloom filters loaded.
+    async fn filter_by_bloom_filter(
+        &self,
+        blocks: Vec<BlockMeta>,
+        pred: &BloomFilterPredicate,
+        ctx: Arc<QueryContext>,
+    ) -> Result<Vec<BlockMeta>> {
+        let block_num = blocks.len();
+        let res = futures::stream::iter(blocks)
+            .map(|block_meta| {
+                let ctx = ctx.clone();
+                async move {
+                    let keep = match &block_meta.bloom_filter_location {
+                        Some(loc) => {
+                            let filter = BloomFilterReader::read(
+                                self.data_accessor.as_ref(),
+                                loc,
+                                ctx.get_table_cache(),
+                            )
+                            .await?;
+                            pred.eval(&filter)
+                        }
+                        None => true,
+                    };
+                    Ok::<_, ErrorCode>(keep.then(|| block_meta))
+                }
             })
-            // configuration of the max size of buffered futures
-            .buffered(std::cmp::min(10, segment_num))
+            .buffered(std::cmp::min(10, block_num.max(1)))
             .try_collect::<Vec<_>>()
-            .await?
-            .into_iter()
-            .flatten();
+            .await?;
 
-        Ok(res.collect())
+        Ok(res.into_iter().flatten().collect())
     }
 
     #[inline]
-    fn filter_segment(segment_info: SegmentInfo, pred: &Pred) -> Result<Vec<BlockMeta>> {
+    fn filter_segment(segment_info: &SegmentInfo, pred: &Pred) -> Result<Vec<BlockMeta>> {
         if pred(&segment_info.summary.col_stats)? {
             let block_num = segment_info.blocks.len();
-            segment_info.blocks.into_iter().try_fold(
+            segment_info.blocks.iter().try_fold(
                 Vec::with_capacity(block_num),
                 |mut acc, block_meta| {
                     if pred(&block_meta.col_stats)? {
-                        acc.push(block_meta)
+                        acc.push(block_meta.clone())
                     }
                     Ok(acc)
                 },
@@ -122,7 +167,21 @@ pub async fn apply_block_pruning(
     data_accessor: Arc<dyn DataAccessor>,
     ctx: Arc<QueryContext>,
 ) -> Result<Vec<BlockMeta>> {
-    BlockPruner::new(table_snapshot, data_accessor)
-        .apply(schema, push_down, ctx)
-        .await
+    let blocks = BlockPruner::new(table_snapshot, data_accessor)
+        .apply(schema, push_down, ctx.clone())
+        .await?;
+
+    let total = table_snapshot.summary.block_count;
+    if total > 0 {
+        let config = ctx.get_config();
+        let (tenant, cluster) = (&config.query.tenant_id, &config.query.cluster_id);
+        let kept = blocks.len() as u64;
+        label_counter_with_val(METRIC_FUSE_PRUNING_BLOCKS, total, tenant, cluster);
+        label_counter_with_val(METRIC_FUSE_PRUNING_KEPT_BLOCKS, kept, tenant, cluster);
+        histogram!(
+            METRIC_FUSE_PRUNE_RATIO,
+            1.0 - kept.min(total) as f64 / total as f64
+        );
+    }
+    Ok(blocks)
 }
@@ -24,11 +24,13 @@ use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
+use crate::storages::index::NdvSketch;
 
 #[derive(Default)]
 pub struct StatisticsAccumulator {
     pub blocks_metas: Vec<BlockMeta>,
     pub blocks_statistics: Vec<BlockStatistics>,
+    pub ndv_sketches: HashMap<ColumnId, NdvSketch>,
     pub summary_row_count: u64,
     pub summary_block_count: u64,
     pub in_memory_size: u64,
@@ -49,6 +51,12 @@ impl StatisticsAccumulator {
         self.in_memory_size += block_in_memory_size;
         let block_stats = Self::acc_columns(block)?;
         self.blocks_statistics.push(block_stats.clone());
+        for (idx, col) in block.columns().iter().enumerate() {
+            self.ndv_sketches
+                .entry(idx as ColumnId)
+                .or_default()
+                .add_column(col)?;
+        }
         Ok(PartiallyAccumulated {
             accumulator: self,
             block_row_count: block.num_rows() as u64,
@@ -110,7 +118,12 @@ pub struct PartiallyAccumulated {
 }
 
 impl PartiallyAccumulated {
-    pub fn end(mut self, file_size: u64, location: String) -> StatisticsAccumulator {
+    pub fn end(
+        mut self,
+        file_size: u64,
+        location: String,
+        bloom_filter_location: Option<String>,
+    ) -> StatisticsAccumulator {
         let mut stats = &mut self.accumulator;
         stats.file_size += file_size;
         let block_meta = BlockMeta {
@@ -122,6 +135,7 @@ impl PartiallyAccumulated {
             block_size: self.block_size,
             file_size,
             col_stats: self.block_column_statistics,
+            bloom_filter_location,
         };
         stats.blocks_metas.push(block_meta);
         self.accumulator
@@ -19,3 +19,4 @@ pub use accumulator::PartiallyAccumulated;
 pub use accumulator::StatisticsAccumulator;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_stats;
+pub use reducers::reduce_ndv_sketches;
@@ -24,16 +24,25 @@ use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
+use crate::storages::index::NdvSketch;
 
+/// Reduces the statistics of the columns, only the columns having statistics in every input
+/// are kept, as the blocks written before a column is added have none of it. The inputs of no
+/// statistics at all, e.g. of no blocks, are ignored.
 pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     stats: &[T],
     schema: &DataSchema,
 ) -> Result<BlockStatistics> {
+    let stats = stats
+        .iter()
+        .map(Borrow::<BlockStatistics>::borrow)
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>();
     let len = stats.len();
 
     // transpose Vec<HashMap<_,(_,_)>> to HashMap<_, (_, Vec<_>)>
     let col_stat_list = stats.iter().fold(HashMap::new(), |acc, item| {
-        item.borrow().iter().fold(
+        item.iter().fold(
             acc,
             |mut acc: HashMap<ColumnId, Vec<&ColumnStatistics>>, (col_id, stats)| {
                 let entry = acc.entry(*col_id);
@@ -52,6 +61,7 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
 
     col_stat_list
         .iter()
+        .filter(|(_, stats)| stats.len() == len)
         .try_fold(HashMap::with_capacity(len), |mut acc, (id, stats)| {
             let mut min_stats = Vec::with_capacity(stats.len());
             let mut max_stats = Vec::with_capacity(stats.len());
@@ -102,3 +112,26 @@ pub fn merge_statistics(schema: &DataSchema, l: &Statistics, r: &Statistics) ->
     };
     Ok(s)
 }
+
+/// Merges the sketches of the columns, only the columns having a sketch in every input are
+/// kept, as the others do not cover all the values.
+pub fn reduce_ndv_sketches<T: Borrow<HashMap<ColumnId, NdvSketch>>>(
+    sketches: &[T],
+) -> HashMap<ColumnId, NdvSketch> {
+    let (first, rest) = match sketches.split_first() {
+        Some(v) => v,
+        None => return HashMap::new(),
+    };
+
+    first
+        .borrow()
+        .iter()
+        .filter_map(|(col_id, sketch)| {
+            let mut merged = sketch.clone();
+            for other in rest {
+                merged.merge(other.borrow().get(col_id)?);
+            }
+            Some((*col_id, merged))
+        })
+        .collect()
+}
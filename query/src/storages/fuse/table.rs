@@ -15,12 +15,18 @@
 
 use std::any::Any;
 use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataType;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::AlterColumnOperation;
+use common_planners::DeletePlan;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
@@ -34,9 +40,21 @@ use crate::sessions::QueryContext;
 use crate::storages::fuse::io::SnapshotReader;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::table_options::user_table_options;
+use crate::storages::fuse::FUSE_VIRTUAL_COLUMN_BLOCK_LOCATION;
+use crate::storages::fuse::FUSE_VIRTUAL_COLUMN_ROW_NUMBER;
+use crate::storages::fuse::FUSE_VIRTUAL_COLUMN_SNAPSHOT_ID;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_SIZE;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_SIZE_COMPRESSED;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_COUNT;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::AnalyzedStatistics;
+use crate::storages::NavigationPoint;
 use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
 use crate::storages::Table;
+use crate::storages::TableStatistics;
+use crate::storages::VacuumStatistics;
 
 pub struct FuseTable {
     pub(crate) table_info: TableInfo,
@@ -46,6 +64,18 @@ impl FuseTable {
     pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
         Ok(Box::new(FuseTable { table_info }))
     }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "FUSE".to_string(),
+            comment: "The default engine, the blocks and snapshots are kept in the storage"
+                .to_string(),
+            supports_append: true,
+            supports_truncate: true,
+            supports_delete: true,
+            supports_time_travel: true,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -66,6 +96,35 @@ impl Table for FuseTable {
         true
     }
 
+    fn support_sampling(&self) -> bool {
+        true
+    }
+
+    fn virtual_columns(&self) -> Vec<DataField> {
+        let schema = self.table_info.schema();
+        [
+            (FUSE_VIRTUAL_COLUMN_BLOCK_LOCATION, DataType::String),
+            (FUSE_VIRTUAL_COLUMN_ROW_NUMBER, DataType::UInt64),
+            (FUSE_VIRTUAL_COLUMN_SNAPSHOT_ID, DataType::String),
+        ]
+        .into_iter()
+        .filter(|(name, _)| !schema.has_field(name))
+        .map(|(name, data_type)| DataField::new(name, data_type, false))
+        .collect()
+    }
+
+    fn user_options(&self) -> BTreeMap<String, String> {
+        user_table_options(self.table_info.options())
+    }
+
+    fn statistics(&self) -> Result<Option<TableStatistics>> {
+        self.counters()
+    }
+
+    async fn refresh_statistics(&self, ctx: Arc<QueryContext>) -> Result<bool> {
+        self.do_refresh_statistics(ctx).await
+    }
+
     #[tracing::instrument(level = "debug", name="fuse_table_read_partitions", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn read_partitions(
         &self,
@@ -121,9 +180,61 @@ impl Table for FuseTable {
         self.do_truncate(ctx, truncate_plan).await
     }
 
+    async fn delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        self.do_delete(ctx, &delete_plan).await
+    }
+
     async fn optimize(&self, ctx: Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
         self.do_optimize(ctx, keep_last_snapshot).await
     }
+
+    async fn compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        self.do_compact(ctx).await
+    }
+
+    async fn vacuum(
+        &self,
+        ctx: Arc<QueryContext>,
+        retain_since: u64,
+        dry_run: bool,
+    ) -> Result<VacuumStatistics> {
+        self.do_vacuum(ctx, retain_since, dry_run).await
+    }
+
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        self.do_analyze(ctx).await
+    }
+
+    async fn analyzed_statistics(
+        &self,
+        ctx: Arc<QueryContext>,
+    ) -> Result<Option<AnalyzedStatistics>> {
+        self.read_analyzed_statistics(ctx.as_ref()).await
+    }
+
+    async fn alter_column(
+        &self,
+        ctx: Arc<QueryContext>,
+        operation: &AlterColumnOperation,
+    ) -> Result<()> {
+        self.do_alter_column(ctx, operation).await
+    }
+
+    async fn set_options(
+        &self,
+        ctx: Arc<QueryContext>,
+        options: &HashMap<String, String>,
+    ) -> Result<()> {
+        self.do_set_options(ctx, options).await
+    }
+
+    async fn navigate_to(
+        &self,
+        ctx: Arc<QueryContext>,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        Ok(Arc::new(self.navigate(ctx, point).await?))
+    }
 }
 
 impl FuseTable {
@@ -134,6 +245,31 @@ impl FuseTable {
             .cloned()
     }
 
+    // The counters are committed along with the snapshot location, a table without snapshot
+    // is empty, while the snapshots committed before the counters were kept are unknown.
+    pub(crate) fn counters(&self) -> Result<Option<TableStatistics>> {
+        let options = self.table_info.options();
+        if !options.contains_key(TBL_OPT_KEY_SNAPSHOT_LOC) {
+            return Ok(Some(TableStatistics::default()));
+        }
+
+        let counter = |key: &str| options.get(key).map(|v| v.parse::<u64>()).transpose();
+        match (
+            counter(TBL_OPT_KEY_ROW_COUNT)?,
+            counter(TBL_OPT_KEY_DATA_SIZE)?,
+            counter(TBL_OPT_KEY_DATA_SIZE_COMPRESSED)?,
+        ) {
+            (Some(num_rows), Some(data_size), Some(data_size_compressed)) => {
+                Ok(Some(TableStatistics {
+                    num_rows,
+                    data_size,
+                    data_size_compressed,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     pub(crate) async fn read_table_snapshot(
         &self,
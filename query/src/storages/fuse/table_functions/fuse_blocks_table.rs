@@ -0,0 +1,207 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::table_functions::table_arg_util::parse_func_history_args;
+use crate::storages::fuse::table_functions::table_arg_util::string_literal;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const FUSE_FUNC_BLOCKS: &str = "fuse_blocks";
+
+/// The blocks of the current snapshot of a table, one row for each column of each block with
+/// the statistics of the column in the block, e.g. `SELECT * FROM fuse_blocks('db', 't')`.
+pub struct FuseBlocksTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseBlocksTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("segment_location", DataType::String, false),
+            DataField::new("block_location", DataType::String, false),
+            DataField::new("row_count", DataType::UInt64, false),
+            DataField::new("bytes_uncompressed", DataType::UInt64, false),
+            DataField::new("bytes_compressed", DataType::UInt64, false),
+            DataField::new("column_name", DataType::String, false),
+            // the min and max values of the column in the block, as text
+            DataField::new("min", DataType::String, false),
+            DataField::new("max", DataType::String, false),
+            DataField::new("null_count", DataType::UInt64, false),
+        ]);
+
+        let (arg_database_name, arg_table_name) = parse_func_history_args(&table_args)?;
+
+        let engine = FUSE_FUNC_BLOCKS.to_owned();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine,
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(FuseBlocksTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[derive(Default)]
+struct BlockRows {
+    segment_locations: Vec<Vec<u8>>,
+    block_locations: Vec<Vec<u8>>,
+    row_counts: Vec<u64>,
+    bytes_uncompressed: Vec<u64>,
+    bytes_compressed: Vec<u64>,
+    column_names: Vec<Vec<u8>>,
+    mins: Vec<Vec<u8>>,
+    maxs: Vec<Vec<u8>>,
+    null_counts: Vec<u64>,
+}
+
+#[async_trait::async_trait]
+impl Table for FuseBlocksTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let tbl = ctx
+            .get_catalog()
+            .get_table(
+                self.arg_database_name.as_str(),
+                self.arg_table_name.as_str(),
+            )
+            .await?;
+
+        let tbl = tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "expecting fuse table, but got table of engine type: {}",
+                tbl.get_table_info().meta.engine
+            ))
+        })?;
+
+        // the statistics are keyed by the positions of the columns in the blocks
+        let physical_schema = tbl.physical_schema()?;
+        let mut rows = BlockRows::default();
+        if let Some(snapshot) = tbl.read_table_snapshot(ctx.as_ref()).await? {
+            let da = ctx.get_data_accessor()?;
+            for location in snapshot.segments.iter() {
+                let segment =
+                    SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?;
+                for block in segment.blocks.iter() {
+                    let mut col_stats = block.col_stats.iter().collect::<Vec<_>>();
+                    col_stats.sort_by_key(|(id, _)| **id);
+                    for (id, stats) in col_stats {
+                        let column_name = match physical_schema.fields().get(*id as usize) {
+                            Some(field) => field.name().clone(),
+                            None => id.to_string(),
+                        };
+                        rows.segment_locations.push(location.clone().into_bytes());
+                        rows.block_locations
+                            .push(block.location.path.clone().into_bytes());
+                        rows.row_counts.push(block.row_count);
+                        rows.bytes_uncompressed.push(block.block_size);
+                        rows.bytes_compressed.push(block.file_size);
+                        rows.column_names.push(column_name.into_bytes());
+                        rows.mins.push(stats.min.to_string().into_bytes());
+                        rows.maxs.push(stats.max.to_string().into_bytes());
+                        rows.null_counts.push(stats.null_count);
+                    }
+                }
+            }
+        }
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(rows.segment_locations),
+            Series::new(rows.block_locations),
+            Series::new(rows.row_counts),
+            Series::new(rows.bytes_uncompressed),
+            Series::new(rows.bytes_compressed),
+            Series::new(rows.column_names),
+            Series::new(rows.mins),
+            Series::new(rows.maxs),
+            Series::new(rows.null_counts),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
+
+impl TableFunction for FuseBlocksTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
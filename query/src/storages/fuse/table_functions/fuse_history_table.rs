@@ -16,6 +16,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use chrono::NaiveDateTime;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::Series;
 use common_datavalues::prelude::SeriesFrom;
@@ -45,6 +46,8 @@ use crate::table_functions::TableArgs;
 use crate::table_functions::TableFunction;
 
 pub const FUSE_FUNC_HIST: &str = "fuse_history";
+/// The same as `fuse_history`, named along with `fuse_segments` and `fuse_blocks`.
+pub const FUSE_FUNC_SNAPSHOTS: &str = "fuse_snapshots";
 
 pub struct FuseHistoryTable {
     table_info: TableInfo,
@@ -67,6 +70,7 @@ impl FuseHistoryTable {
             DataField::new("row_count", DataType::UInt64, false),
             DataField::new("bytes_uncompressed", DataType::UInt64, false),
             DataField::new("bytes_compressed", DataType::UInt64, false),
+            DataField::new("timestamp", DataType::String, true),
         ]);
 
         let (arg_database_name, arg_table_name) = parse_func_history_args(&table_args)?;
@@ -100,6 +104,7 @@ impl FuseHistoryTable {
         let mut row_count: Vec<u64> = Vec::with_capacity(len);
         let mut compressed: Vec<u64> = Vec::with_capacity(len);
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
+        let mut timestamps: Vec<Option<Vec<u8>>> = Vec::with_capacity(len);
         for s in snapshots {
             snapshot_ids.push(s.snapshot_id.to_simple().to_string().into_bytes());
             prev_snapshot_ids.push(
@@ -111,6 +116,16 @@ impl FuseHistoryTable {
             row_count.push(s.summary.row_count);
             compressed.push(s.summary.compressed_byte_size);
             uncompressed.push(s.summary.uncompressed_byte_size);
+            // in the format accepted by `AT (TIMESTAMP => '...')`
+            timestamps.push(s.timestamp.map(|millis| {
+                NaiveDateTime::from_timestamp(
+                    (millis / 1000) as i64,
+                    (millis % 1000) as u32 * 1_000_000,
+                )
+                .format("%Y-%m-%d %H:%M:%S%.3f")
+                .to_string()
+                .into_bytes()
+            }));
         }
 
         DataBlock::create_by_array(self.table_info.schema(), vec![
@@ -121,6 +136,7 @@ impl FuseHistoryTable {
             Series::new(row_count),
             Series::new(uncompressed),
             Series::new(compressed),
+            Series::new(timestamps),
         ])
     }
 }
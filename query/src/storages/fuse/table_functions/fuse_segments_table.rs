@@ -0,0 +1,176 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::table_functions::table_arg_util::parse_func_history_args;
+use crate::storages::fuse::table_functions::table_arg_util::string_literal;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const FUSE_FUNC_SEGMENTS: &str = "fuse_segments";
+
+/// The segments of the current snapshot of a table, e.g. `SELECT * FROM fuse_segments('db', 't')`.
+pub struct FuseSegmentsTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl FuseSegmentsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("location", DataType::String, false),
+            DataField::new("format_version", DataType::UInt32, false),
+            DataField::new("block_count", DataType::UInt64, false),
+            DataField::new("row_count", DataType::UInt64, false),
+            DataField::new("bytes_uncompressed", DataType::UInt64, false),
+            DataField::new("bytes_compressed", DataType::UInt64, false),
+        ]);
+
+        let (arg_database_name, arg_table_name) = parse_func_history_args(&table_args)?;
+
+        let engine = FUSE_FUNC_SEGMENTS.to_owned();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine,
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(FuseSegmentsTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseSegmentsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let tbl = ctx
+            .get_catalog()
+            .get_table(
+                self.arg_database_name.as_str(),
+                self.arg_table_name.as_str(),
+            )
+            .await?;
+
+        let tbl = tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "expecting fuse table, but got table of engine type: {}",
+                tbl.get_table_info().meta.engine
+            ))
+        })?;
+
+        let mut locations = vec![];
+        let mut format_versions = vec![];
+        let mut block_counts = vec![];
+        let mut row_counts = vec![];
+        let mut uncompressed = vec![];
+        let mut compressed = vec![];
+        if let Some(snapshot) = tbl.read_table_snapshot(ctx.as_ref()).await? {
+            let da = ctx.get_data_accessor()?;
+            for location in snapshot.segments.iter() {
+                let segment =
+                    SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?;
+                locations.push(location.clone());
+                format_versions.push(segment.format_version);
+                block_counts.push(segment.summary.block_count);
+                row_counts.push(segment.summary.row_count);
+                uncompressed.push(segment.summary.uncompressed_byte_size);
+                compressed.push(segment.summary.compressed_byte_size);
+            }
+        }
+
+        let locations: Vec<&[u8]> = locations.iter().map(|s| s.as_bytes()).collect();
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(locations),
+            Series::new(format_versions),
+            Series::new(block_counts),
+            Series::new(row_counts),
+            Series::new(uncompressed),
+            Series::new(compressed),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
+
+impl TableFunction for FuseSegmentsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
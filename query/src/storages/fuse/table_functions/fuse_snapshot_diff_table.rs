@@ -0,0 +1,260 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::SegmentReader;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::operations::subtract_blocks;
+use crate::storages::fuse::operations::subtract_locations;
+use crate::storages::fuse::table_functions::table_arg_util::parse_func_snapshot_diff_args;
+use crate::storages::fuse::table_functions::table_arg_util::string_literal;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const FUSE_FUNC_SNAPSHOT_DIFF: &str = "fuse_snapshot_diff";
+
+/// The segments and blocks added and removed from one snapshot of a table to another one,
+/// e.g. `SELECT * FROM fuse_snapshot_diff('db', 't', 'from_snapshot_id', 'to_snapshot_id')`.
+pub struct FuseSnapshotDiffTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+    arg_from_snapshot_id: String,
+    arg_to_snapshot_id: String,
+}
+
+impl FuseSnapshotDiffTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let schema = DataSchemaRefExt::create(vec![
+            // "segment" or "block"
+            DataField::new("object_type", DataType::String, false),
+            // "added" or "removed"
+            DataField::new("change", DataType::String, false),
+            DataField::new("location", DataType::String, false),
+            DataField::new("row_count", DataType::UInt64, false),
+            DataField::new("bytes_compressed", DataType::UInt64, false),
+        ]);
+
+        let (arg_database_name, arg_table_name, arg_from_snapshot_id, arg_to_snapshot_id) =
+            parse_func_snapshot_diff_args(&table_args)?;
+
+        let engine = FUSE_FUNC_SNAPSHOT_DIFF.to_owned();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine,
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(FuseSnapshotDiffTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+            arg_from_snapshot_id,
+            arg_to_snapshot_id,
+        }))
+    }
+
+    async fn read_segments(ctx: &QueryContext, locations: &[&String]) -> Result<Vec<SegmentInfo>> {
+        let da = ctx.get_data_accessor()?;
+        let mut segments = Vec::with_capacity(locations.len());
+        for location in locations {
+            segments.push(SegmentReader::read(da.as_ref(), location, ctx.get_table_cache()).await?);
+        }
+        Ok(segments)
+    }
+}
+
+#[derive(Default)]
+struct DiffRows {
+    object_types: Vec<&'static str>,
+    changes: Vec<&'static str>,
+    locations: Vec<String>,
+    row_counts: Vec<u64>,
+    bytes_compressed: Vec<u64>,
+}
+
+impl DiffRows {
+    fn push(
+        &mut self,
+        object_type: &'static str,
+        change: &'static str,
+        location: &str,
+        rows: u64,
+        bytes: u64,
+    ) {
+        self.object_types.push(object_type);
+        self.changes.push(change);
+        self.locations.push(location.to_string());
+        self.row_counts.push(rows);
+        self.bytes_compressed.push(bytes);
+    }
+
+    fn push_segments(
+        &mut self,
+        change: &'static str,
+        locations: &[&String],
+        segments: &[SegmentInfo],
+    ) {
+        for (location, segment) in locations.iter().zip(segments.iter()) {
+            let summary = &segment.summary;
+            self.push(
+                "segment",
+                change,
+                location,
+                summary.row_count,
+                summary.compressed_byte_size,
+            );
+        }
+    }
+
+    fn push_blocks(&mut self, change: &'static str, blocks: &[&BlockMeta]) {
+        for block in blocks {
+            self.push(
+                "block",
+                change,
+                &block.location.path,
+                block.row_count,
+                block.file_size,
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FuseSnapshotDiffTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+            string_literal(self.arg_from_snapshot_id.as_str()),
+            string_literal(self.arg_to_snapshot_id.as_str()),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let tbl = ctx
+            .get_catalog()
+            .get_table(
+                self.arg_database_name.as_str(),
+                self.arg_table_name.as_str(),
+            )
+            .await?;
+
+        let tbl = tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "expecting fuse table, but got table of engine type: {}",
+                tbl.get_table_info().meta.engine
+            ))
+        })?;
+
+        let from = tbl
+            .find_snapshot(ctx.clone(), &self.arg_from_snapshot_id)
+            .await?;
+        let to = tbl
+            .find_snapshot(ctx.clone(), &self.arg_to_snapshot_id)
+            .await?;
+
+        // the segments of both the snapshots, and so their blocks, are left out
+        let added = subtract_locations(&to.segments, &from.segments);
+        let removed = subtract_locations(&from.segments, &to.segments);
+        let added_segments = Self::read_segments(ctx.as_ref(), &added).await?;
+        let removed_segments = Self::read_segments(ctx.as_ref(), &removed).await?;
+
+        let mut rows = DiffRows::default();
+        rows.push_segments("added", &added, &added_segments);
+        rows.push_segments("removed", &removed, &removed_segments);
+        rows.push_blocks(
+            "added",
+            &subtract_blocks(&added_segments, &removed_segments),
+        );
+        rows.push_blocks(
+            "removed",
+            &subtract_blocks(&removed_segments, &added_segments),
+        );
+
+        let object_types: Vec<&[u8]> = rows.object_types.iter().map(|s| s.as_bytes()).collect();
+        let changes: Vec<&[u8]> = rows.changes.iter().map(|s| s.as_bytes()).collect();
+        let locations: Vec<&[u8]> = rows.locations.iter().map(|s| s.as_bytes()).collect();
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(object_types),
+            Series::new(changes),
+            Series::new(locations),
+            Series::new(rows.row_counts),
+            Series::new(rows.bytes_compressed),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
+
+impl TableFunction for FuseSnapshotDiffTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
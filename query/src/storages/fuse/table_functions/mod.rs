@@ -13,8 +13,18 @@
 //  limitations under the License.
 //
 
+mod fuse_blocks_table;
 mod fuse_history_table;
+mod fuse_segments_table;
+mod fuse_snapshot_diff_table;
 mod table_arg_util;
 
+pub use fuse_blocks_table::FuseBlocksTable;
+pub use fuse_blocks_table::FUSE_FUNC_BLOCKS;
 pub use fuse_history_table::FuseHistoryTable;
 pub use fuse_history_table::FUSE_FUNC_HIST;
+pub use fuse_history_table::FUSE_FUNC_SNAPSHOTS;
+pub use fuse_segments_table::FuseSegmentsTable;
+pub use fuse_segments_table::FUSE_FUNC_SEGMENTS;
+pub use fuse_snapshot_diff_table::FuseSnapshotDiffTable;
+pub use fuse_snapshot_diff_table::FUSE_FUNC_SNAPSHOT_DIFF;
@@ -49,3 +49,21 @@ pub fn parse_func_history_args(table_args: &TableArgs) -> Result<(String, String
         ))),
     }
 }
+
+pub fn parse_func_snapshot_diff_args(
+    table_args: &TableArgs,
+) -> Result<(String, String, String, String)> {
+    match table_args {
+        Some(args) if args.len() == 4 => {
+            let db = string_value(&args[0])?;
+            let tbl = string_value(&args[1])?;
+            let from = string_value(&args[2])?;
+            let to = string_value(&args[3])?;
+            Ok((db, tbl, from, to))
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "expecting database name, table name and two snapshot ids (as four string literals), but got {:?}",
+            table_args
+        ))),
+    }
+}
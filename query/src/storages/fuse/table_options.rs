@@ -0,0 +1,182 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::io::BlockCompression;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
+use crate::storages::fuse::TBL_OPT_KEY_CHANGE_TRACKING;
+use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_COMPRESSION;
+use crate::storages::fuse::TBL_OPT_KEY_COMMENT;
+use crate::storages::fuse::TBL_OPT_KEY_COMPRESSION;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableOptionType {
+    UInt64,
+    Boolean,
+    String,
+    /// A compression codec, e.g. `zstd`
+    Codec,
+    /// The compression codecs of some columns, e.g. `payload:zstd,id:none`
+    ColumnCodecs,
+    /// Comma separated column names
+    Columns,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableOption {
+    pub name: &'static str,
+    pub option_type: TableOptionType,
+    pub description: &'static str,
+}
+
+/// The options of fuse tables which could be set by CREATE TABLE and ALTER TABLE ... SET. The
+/// other options of a table are kept by the engine itself, e.g. the location of its snapshot.
+pub static FUSE_TABLE_OPTIONS: &[TableOption] = &[
+    TableOption {
+        name: TBL_OPT_KEY_CHUNK_BLOCK_NUM,
+        option_type: TableOptionType::UInt64,
+        description: "The max number of blocks in a segment",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+        option_type: TableOptionType::UInt64,
+        description: "The max size in bytes of a block in memory",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_BLOOM_FILTER_COLUMNS,
+        option_type: TableOptionType::Columns,
+        description: "The columns to build bloom filters on",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_COMPRESSION,
+        option_type: TableOptionType::Codec,
+        description: "The compression codec of the blocks, lz4 by default",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_COLUMN_COMPRESSION,
+        option_type: TableOptionType::ColumnCodecs,
+        description: "The compression codecs of some columns",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS,
+        option_type: TableOptionType::UInt64,
+        description: "The days the replaced snapshots are kept for",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_CHANGE_TRACKING,
+        option_type: TableOptionType::Boolean,
+        description: "Whether the changes of the table are tracked",
+    },
+    TableOption {
+        name: TBL_OPT_KEY_COMMENT,
+        option_type: TableOptionType::String,
+        description: "The comment of the table",
+    },
+];
+
+pub fn get_table_option(name: &str) -> Option<&'static TableOption> {
+    FUSE_TABLE_OPTIONS
+        .iter()
+        .find(|option| option.name.eq_ignore_ascii_case(name))
+}
+
+/// Checks the options set by the user on a table of the schema, returns them under the
+/// upper case names which they are read by.
+pub fn validate_table_options(
+    options: &HashMap<String, String>,
+    schema: &DataSchema,
+) -> Result<HashMap<String, String>> {
+    let mut validated = HashMap::with_capacity(options.len());
+    for (name, value) in options {
+        let option = get_table_option(name).ok_or_else(|| {
+            ErrorCode::BadOption(format!(
+                "Unknown table option {}, expect one of {}",
+                name,
+                FUSE_TABLE_OPTIONS
+                    .iter()
+                    .map(|option| option.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })?;
+        let value = validate_value(option, value, schema)?;
+        validated.insert(option.name.to_string(), value);
+    }
+
+    // fails early on the unknown codecs, rather than at the first insertion
+    BlockCompression::try_create(&validated)?;
+    Ok(validated)
+}
+
+fn validate_value(option: &TableOption, value: &str, schema: &DataSchema) -> Result<String> {
+    let bad_value = |expect: &str| {
+        ErrorCode::BadOption(format!(
+            "Invalid value '{}' of table option {}, expect {}",
+            value, option.name, expect
+        ))
+    };
+    match option.option_type {
+        TableOptionType::UInt64 => value
+            .trim()
+            .parse::<u64>()
+            .map(|v| v.to_string())
+            .map_err(|_| bad_value("an unsigned integer")),
+        TableOptionType::Boolean => match value.trim().to_lowercase().as_str() {
+            v @ ("true" | "false") => Ok(v.to_string()),
+            _ => Err(bad_value("true or false")),
+        },
+        TableOptionType::Columns => {
+            let columns = value
+                .split(',')
+                .map(|c| c.trim())
+                .filter(|c| !c.is_empty())
+                .collect::<Vec<_>>();
+            for column in &columns {
+                if !schema.has_field(column) {
+                    return Err(bad_value("the columns of the table"));
+                }
+            }
+            Ok(columns.join(","))
+        }
+        TableOptionType::ColumnCodecs => {
+            for column in value.split(',').filter_map(|c| c.split_once(':')) {
+                if !schema.has_field(column.0.trim()) {
+                    return Err(bad_value("the codecs of the columns of the table"));
+                }
+            }
+            Ok(value.to_string())
+        }
+        TableOptionType::String | TableOptionType::Codec => Ok(value.to_string()),
+    }
+}
+
+/// The options of the table set by the user, sorted by their names, the ones kept by the
+/// engine itself are left out.
+pub fn user_table_options(options: &HashMap<String, String>) -> BTreeMap<String, String> {
+    options
+        .iter()
+        .filter(|(name, _)| get_table_option(name).is_some())
+        .map(|(name, value)| (name.to_uppercase(), value.clone()))
+        .collect()
+}
@@ -32,6 +32,7 @@ use crate::storages::github::RepoIssuesTable;
 use crate::storages::github::RepoPRsTable;
 use crate::storages::github::RepoTableOptions;
 use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
 use crate::storages::Table;
 
 pub enum GithubTableType {
@@ -66,6 +67,17 @@ impl GithubTable {
         }))
     }
 
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "GITHUB".to_string(),
+            comment: "Read-only tables of a Github repository".to_string(),
+            supports_append: false,
+            supports_truncate: false,
+            supports_delete: false,
+            supports_time_travel: false,
+        }
+    }
+
     fn get_table_type(&self) -> Result<GithubTableType> {
         match self.options.table_type.as_str() {
             "comments" => Ok(GithubTableType::Comments),
@@ -44,6 +44,7 @@ impl RepoCommentsTable {
         options.table_type = GithubTableType::Comments.to_string();
         let req = CreateTableReq {
             if_not_exists: false,
+            or_replace: false,
             db: options.owner.clone(),
             table: format!("{}_{}", options.repo.clone(), "comments"),
             table_meta: TableMeta {
@@ -48,6 +48,7 @@ impl RepoInfoTable {
         options.table_type = GithubTableType::Info.to_string();
         let req = CreateTableReq {
             if_not_exists: false,
+            or_replace: false,
             db: options.owner.clone(),
             table: options.repo.clone(),
             table_meta: TableMeta {
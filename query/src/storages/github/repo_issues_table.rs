@@ -52,6 +52,7 @@ impl RepoIssuesTable {
         options.table_type = GithubTableType::Issues.to_string();
         let req = CreateTableReq {
             if_not_exists: false,
+            or_replace: false,
             db: options.owner.clone(),
             table: format!("{}_{}", options.repo.clone(), "issues"),
             table_meta: TableMeta {
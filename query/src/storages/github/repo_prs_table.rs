@@ -51,6 +51,7 @@ impl RepoPRsTable {
         options.table_type = GithubTableType::PullRequests.to_string();
         let req = CreateTableReq {
             if_not_exists: false,
+            or_replace: false,
             db: options.owner.clone(),
             table: format!("{}_{}", options.repo.clone(), "prs"),
             table_meta: TableMeta {
@@ -0,0 +1,206 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::collections::HashMap;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+
+pub type BlockBloomFilter = HashMap<u32, BloomFilter>;
+
+/// A bloom filter over the values of a column.
+///
+/// The hash of a value only depends on its bytes, so the filters written by one
+/// version of the server can be probed by another one.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct BloomFilter {
+    num_hashes: u32,
+    bits: Vec<u64>,
+}
+
+impl BloomFilter {
+    /// Creates a filter sized for `num_items` values at the false positive rate `fpp`.
+    pub fn with_rate(num_items: usize, fpp: f64) -> Self {
+        let num_items = num_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-num_items * fpp.ln() / (ln2 * ln2)).ceil().max(64.0) as usize;
+        let num_hashes = ((num_bits as f64 / num_items) * ln2)
+            .round()
+            .clamp(1.0, 16.0);
+        Self {
+            num_hashes: num_hashes as u32,
+            bits: vec![0; (num_bits + 63) / 64],
+        }
+    }
+
+    pub fn num_bits(&self) -> u64 {
+        self.bits.len() as u64 * 64
+    }
+
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// NULLs and the values of unsupported types are not added.
+    pub fn add(&mut self, value: &DataValue) {
+        if let Some(hash) = hash_value(value) {
+            for pos in self.positions(hash) {
+                self.bits[(pos / 64) as usize] |= 1 << (pos % 64);
+            }
+        }
+    }
+
+    pub fn add_column(&mut self, column: &DataColumn) -> Result<()> {
+        match column {
+            DataColumn::Constant(value, _) => self.add(value),
+            DataColumn::Array(_) => column.to_values()?.iter().for_each(|v| self.add(v)),
+        }
+        Ok(())
+    }
+
+    /// Returns false only if the value is definitely not in the filter.
+    pub fn maybe_contains(&self, value: &DataValue) -> bool {
+        match hash_value(value) {
+            None => true,
+            Some(hash) => self
+                .positions(hash)
+                .all(|pos| self.bits[(pos / 64) as usize] & (1 << (pos % 64)) != 0),
+        }
+    }
+
+    // Kirsch-Mitzenmacher: the i-th position is h1 + i * h2.
+    fn positions(&self, hash: u64) -> impl Iterator<Item = u64> {
+        let num_bits = self.num_bits();
+        let h1 = hash;
+        let h2 = mix64(hash) | 1;
+        (0..self.num_hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+    }
+}
+
+/// The equality predicates `column = literal` of the conjunction of a filter,
+/// which let a block be skipped if its bloom filter does not contain the literal.
+#[derive(Debug, Clone)]
+pub struct BloomFilterPredicate {
+    equalities: Vec<(u32, DataValue)>,
+}
+
+impl BloomFilterPredicate {
+    /// Returns None if the expression has no usable equality predicate.
+    pub fn try_create(expr: &Expression, schema: &DataSchemaRef) -> Result<Option<Self>> {
+        let mut equalities = vec![];
+        collect_equalities(expr, schema, &mut equalities)?;
+        if equalities.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { equalities }))
+    }
+
+    /// Returns false if the block definitely has no row matching the predicate.
+    /// The columns without a bloom filter can not prune anything.
+    pub fn eval(&self, filters: &BlockBloomFilter) -> bool {
+        self.equalities
+            .iter()
+            .all(|(id, value)| match filters.get(id) {
+                Some(filter) => filter.maybe_contains(value),
+                None => true,
+            })
+    }
+}
+
+// Ignoring a conjunct only keeps more blocks, so any other shape of expression is skipped.
+fn collect_equalities(
+    expr: &Expression,
+    schema: &DataSchemaRef,
+    equalities: &mut Vec<(u32, DataValue)>,
+) -> Result<()> {
+    if let Expression::BinaryExpression { left, op, right } = expr {
+        match op.to_lowercase().as_str() {
+            "and" => {
+                collect_equalities(left, schema, equalities)?;
+                collect_equalities(right, schema, equalities)?;
+            }
+            "=" => match (left.as_ref(), right.as_ref()) {
+                (Expression::Column(name), Expression::Literal { value, .. })
+                | (Expression::Literal { value, .. }, Expression::Column(name)) => {
+                    if let Some(equality) = column_equality(name, value, schema) {
+                        equalities.push(equality);
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// The literal is cast to the type of the column, the filter holds the values of that type.
+fn column_equality(
+    name: &str,
+    value: &DataValue,
+    schema: &DataSchemaRef,
+) -> Option<(u32, DataValue)> {
+    let index = schema.index_of(name).ok()?;
+    let data_type = schema.field(index).data_type();
+    let value = value
+        .to_array()
+        .and_then(|s| s.cast_with_type(data_type))
+        .and_then(|s| s.try_get(0))
+        .ok()?;
+    if value.is_null() {
+        return None;
+    }
+    Some((index as u32, value))
+}
+
+pub(super) fn hash_value(value: &DataValue) -> Option<u64> {
+    match value {
+        DataValue::Boolean(Some(v)) => Some(fnv1a(&[*v as u8])),
+        DataValue::Int8(Some(v)) => Some(fnv1a(&(*v as i64).to_le_bytes())),
+        DataValue::Int16(Some(v)) => Some(fnv1a(&(*v as i64).to_le_bytes())),
+        DataValue::Int32(Some(v)) => Some(fnv1a(&(*v as i64).to_le_bytes())),
+        DataValue::Int64(Some(v)) => Some(fnv1a(&v.to_le_bytes())),
+        DataValue::UInt8(Some(v)) => Some(fnv1a(&(*v as u64).to_le_bytes())),
+        DataValue::UInt16(Some(v)) => Some(fnv1a(&(*v as u64).to_le_bytes())),
+        DataValue::UInt32(Some(v)) => Some(fnv1a(&(*v as u64).to_le_bytes())),
+        DataValue::UInt64(Some(v)) => Some(fnv1a(&v.to_le_bytes())),
+        DataValue::Float32(Some(v)) => hash_float(*v as f64),
+        DataValue::Float64(Some(v)) => hash_float(*v),
+        DataValue::String(Some(v)) => Some(fnv1a(v)),
+        _ => None,
+    }
+}
+
+fn hash_float(v: f64) -> Option<u64> {
+    // NaN equals nothing, and -0.0 equals 0.0.
+    match v {
+        v if v.is_nan() => None,
+        v if v == 0.0 => Some(fnv1a(&0.0f64.to_bits().to_le_bytes())),
+        v => Some(fnv1a(&v.to_bits().to_le_bytes())),
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub(super) fn mix64(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::storages::index::index_bloom::hash_value;
+use crate::storages::index::index_bloom::mix64;
+
+/// 2^10 registers, the standard error of the estimates is about 3%.
+const NDV_SKETCH_PRECISION: u32 = 10;
+
+/// A HyperLogLog sketch of the distinct values of a column.
+///
+/// Sketches merge into the sketch of the union of their values. The values are hashed like
+/// the bloom filters, so the sketches written by one version of the server can be merged by
+/// another one.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct NdvSketch {
+    registers: Vec<u8>,
+}
+
+impl Default for NdvSketch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NdvSketch {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; 1 << NDV_SKETCH_PRECISION],
+        }
+    }
+
+    /// NULLs and the values of unsupported types are not added.
+    pub fn add(&mut self, value: &DataValue) {
+        if let Some(hash) = hash_value(value) {
+            let hash = mix64(hash);
+            let index = (hash >> (64 - NDV_SKETCH_PRECISION)) as usize;
+            // the position of the first 1 bit of the remaining bits, bounded by the sentinel
+            let rest = (hash << NDV_SKETCH_PRECISION) | (1 << (NDV_SKETCH_PRECISION - 1));
+            let rank = rest.leading_zeros() as u8 + 1;
+            self.registers[index] = self.registers[index].max(rank);
+        }
+    }
+
+    pub fn add_column(&mut self, column: &DataColumn) -> Result<()> {
+        match column {
+            DataColumn::Constant(value, _) => self.add(value),
+            DataColumn::Array(_) => column.to_values()?.iter().for_each(|v| self.add(v)),
+        }
+        Ok(())
+    }
+
+    pub fn merge(&mut self, other: &NdvSketch) {
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(l, r)| *l = (*l).max(*r));
+    }
+
+    /// The estimated number of distinct values added.
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum = self
+            .registers
+            .iter()
+            .map(|r| 2f64.powi(-(*r as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        // linear counting for the small cardinalities
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
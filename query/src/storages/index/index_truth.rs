@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_planners::lit;
+use common_planners::Expression;
+
+/// The definite outcomes of a predicate on a row under the SQL three-valued logic.
+///
+/// A row on which the predicate is NULL is neither TRUE nor FALSE, it is filtered out like
+/// FALSE but negating it still gives NULL. So the rows that may make `NOT p` TRUE are
+/// exactly the rows that may make `p` FALSE, which is not the complement of those that may
+/// make `p` TRUE as soon as NULLs are involved.
+///
+/// The range filter builds, for a block, the expression telling whether the block may
+/// contain a row on which the predicate takes a given `Truth`, and prunes the block if it
+/// may not contain any row on which the predicate is TRUE.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Truth {
+    True,
+    False,
+}
+
+impl Truth {
+    pub fn negate(self) -> Truth {
+        match self {
+            Truth::True => Truth::False,
+            Truth::False => Truth::True,
+        }
+    }
+
+    /// Whether `left AND right` may take this truth, from whether its operands may take it.
+    pub fn and(self, left: Expression, right: Expression) -> Expression {
+        match self {
+            Truth::True => left.and(right),
+            Truth::False => left.or(right),
+        }
+    }
+
+    /// Whether `left OR right` may take this truth, from whether its operands may take it.
+    pub fn or(self, left: Expression, right: Expression) -> Expression {
+        match self {
+            Truth::True => left.or(right),
+            Truth::False => left.and(right),
+        }
+    }
+
+    /// Whether a literal predicate takes this truth, a NULL literal takes neither.
+    pub fn literal(self, value: &DataValue) -> Result<Expression> {
+        if value.is_null() {
+            return Ok(lit(false));
+        }
+
+        let value = value.as_bool()?;
+        Ok(lit(match self {
+            Truth::True => value,
+            Truth::False => !value,
+        }))
+    }
+
+    /// The operator of the atom which is TRUE on a row iff the atom of `op` takes this truth.
+    ///
+    /// The comparisons are NULL on a NULL operand whatever the operator, so the FALSE rows of
+    /// `col < 1` are the TRUE rows of `col >= 1`. `isnull` and `isnotnull` are never NULL.
+    pub fn atom_operator(self, op: &str) -> Option<&str> {
+        if self == Truth::True {
+            return Some(op);
+        }
+
+        match op {
+            "=" => Some("!="),
+            "!=" => Some("="),
+            "<" => Some(">="),
+            "<=" => Some(">"),
+            ">" => Some("<="),
+            ">=" => Some("<"),
+            "like" => Some("not like"),
+            "not like" => Some("like"),
+            "isnull" => Some("isnotnull"),
+            "isnotnull" => Some("isnull"),
+            _ => None,
+        }
+    }
+}
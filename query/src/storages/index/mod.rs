@@ -12,13 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod index_bloom;
 mod index_min_max;
+mod index_ndv;
 mod index_sparse;
+mod index_truth;
+mod range_analysis;
 pub mod range_filter;
 
+pub use index_bloom::BlockBloomFilter;
+pub use index_bloom::BloomFilter;
+pub use index_bloom::BloomFilterPredicate;
 pub use index_min_max::MinMaxIndex;
+pub use index_ndv::NdvSketch;
 pub use index_sparse::SparseIndex;
 pub use index_sparse::SparseIndexValue;
+pub use index_truth::Truth;
 pub use range_filter::BlockStatistics;
 pub use range_filter::ColumnStatistics;
 pub use range_filter::RangeFilter;
@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::lit;
+use common_planners::neg;
+use common_planners::Expression;
+
+use crate::storages::index::range_filter::column_stat_expr;
+use crate::storages::index::range_filter::StatColumns;
+use crate::storages::index::range_filter::StatType;
+
+/// The bounds of an expression over the rows of a block, as expressions of the statistics
+/// of the columns it reads.
+struct Interval {
+    min: Expression,
+    max: Expression,
+}
+
+/// Interval arithmetic on the min/max statistics, for the comparisons which are not a
+/// monotonic expression of one column against a constant, e.g. `a + b > 10`, `a - b <= c`
+/// or `a * 2 = b`.
+///
+/// The bounds are computed in Float64 so the arithmetic on them never overflows. Float64
+/// is exact for the integers up to 2^53 and rounds monotonically beyond, so the strict
+/// comparisons are relaxed to non-strict ones and `!=` is not handled, to never prune a
+/// block with a matching row.
+pub struct RangeAnalysis<'a> {
+    schema: &'a DataSchemaRef,
+    stat_columns: &'a mut StatColumns,
+}
+
+impl<'a> RangeAnalysis<'a> {
+    pub fn create(schema: &'a DataSchemaRef, stat_columns: &'a mut StatColumns) -> Self {
+        RangeAnalysis {
+            schema,
+            stat_columns,
+        }
+    }
+
+    /// Build the expression telling whether a block may contain a row on which
+    /// `left op right` is TRUE.
+    pub fn build(&mut self, op: &str, left: &Expression, right: &Expression) -> Result<Expression> {
+        let left = self.interval(left)?;
+        let right = self.interval(right)?;
+        match op {
+            "=" => Ok(left.min.lt_eq(right.max).and(left.max.gt_eq(right.min))),
+            "<" | "<=" => Ok(left.min.lt_eq(right.max)),
+            ">" | ">=" => Ok(left.max.gt_eq(right.min)),
+            other => Err(ErrorCode::UnknownException(format!(
+                "Cannot analyze the range of the operator: {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn interval(&mut self, expr: &Expression) -> Result<Interval> {
+        match expr {
+            Expression::Literal { value, .. } => {
+                let value = lit(value.as_f64()?);
+                Ok(Interval {
+                    min: value.clone(),
+                    max: value,
+                })
+            }
+            Expression::Column(name) => {
+                let field = self.schema.field_with_name(name)?;
+                if !field.data_type().is_numeric() {
+                    return Err(ErrorCode::UnknownException(format!(
+                        "Cannot analyze the range of the non numeric column: {}",
+                        name
+                    )));
+                }
+
+                let min = column_stat_expr(name, StatType::Min, self.schema, self.stat_columns)?;
+                let max = column_stat_expr(name, StatType::Max, self.schema, self.stat_columns)?;
+                Ok(Interval {
+                    min: to_float64(min),
+                    max: to_float64(max),
+                })
+            }
+            Expression::UnaryExpression { op, expr } if op.to_lowercase() == "negate" => {
+                let interval = self.interval(expr)?;
+                Ok(Interval {
+                    min: neg(interval.max),
+                    max: neg(interval.min),
+                })
+            }
+            Expression::BinaryExpression { op, left, right } => {
+                self.binary_interval(op.to_lowercase().as_str(), left, right)
+            }
+            Expression::ScalarFunction { op, args } if args.len() == 2 => {
+                self.binary_interval(op.to_lowercase().as_str(), &args[0], &args[1])
+            }
+            other => Err(ErrorCode::UnknownException(format!(
+                "Cannot analyze the range of the expression: {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn binary_interval(
+        &mut self,
+        op: &str,
+        left: &Expression,
+        right: &Expression,
+    ) -> Result<Interval> {
+        match op {
+            "+" | "plus" => {
+                let (left, right) = (self.interval(left)?, self.interval(right)?);
+                Ok(Interval {
+                    min: arithmetic("+", left.min, right.min),
+                    max: arithmetic("+", left.max, right.max),
+                })
+            }
+            "-" | "minus" => {
+                let (left, right) = (self.interval(left)?, self.interval(right)?);
+                Ok(Interval {
+                    min: arithmetic("-", left.min, right.max),
+                    max: arithmetic("-", left.max, right.min),
+                })
+            }
+            // Scaling by a constant, the bounds are swapped by a negative one.
+            "*" | "multiply" => match (literal_f64(left), literal_f64(right)) {
+                (_, Some(factor)) => self.scaled_interval(left, "*", factor),
+                (Some(factor), _) => self.scaled_interval(right, "*", factor),
+                _ => Err(ErrorCode::UnknownException(
+                    "Cannot analyze the range of the product of two columns",
+                )),
+            },
+            "/" | "divide" => match literal_f64(right) {
+                Some(divisor) if divisor.abs() > 0.0 => self.scaled_interval(left, "/", divisor),
+                _ => Err(ErrorCode::UnknownException(
+                    "Cannot analyze the range of the division by a non constant",
+                )),
+            },
+            other => Err(ErrorCode::UnknownException(format!(
+                "Cannot analyze the range of the operator: {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn scaled_interval(&mut self, expr: &Expression, op: &str, factor: f64) -> Result<Interval> {
+        let interval = self.interval(expr)?;
+        let (min, max) = match factor >= 0.0 {
+            true => (interval.min, interval.max),
+            false => (interval.max, interval.min),
+        };
+        Ok(Interval {
+            min: arithmetic(op, min, lit(factor)),
+            max: arithmetic(op, max, lit(factor)),
+        })
+    }
+}
+
+fn literal_f64(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::Literal { value, .. } if !value.is_null() => value.as_f64().ok(),
+        _ => None,
+    }
+}
+
+fn to_float64(expr: Expression) -> Expression {
+    Expression::Cast {
+        expr: Box::new(expr),
+        data_type: DataType::Float64,
+    }
+}
+
+fn arithmetic(op: &str, left: Expression, right: Expression) -> Expression {
+    Expression::create_binary_expression(op, vec![left, right])
+}
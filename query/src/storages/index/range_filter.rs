@@ -29,6 +29,8 @@ use common_planners::Expressions;
 use crate::optimizers::MonotonicityCheckVisitor;
 use crate::optimizers::RequireColumnsVisitor;
 use crate::pipelines::transforms::ExpressionExecutor;
+use crate::storages::index::range_analysis::RangeAnalysis;
+use crate::storages::index::Truth;
 
 pub type BlockStatistics = HashMap<u32, ColumnStatistics>;
 
@@ -77,22 +79,31 @@ impl RangeFilter {
     }
 
     pub fn eval(&self, stats: &BlockStatistics) -> Result<bool> {
+        // the blocks written before a column is added have no statistics of it
+        if self
+            .stat_columns
+            .iter()
+            .any(|c| !stats.contains_key(&c.column_id))
+        {
+            return Ok(true);
+        }
+
         let columns = self
             .stat_columns
             .iter()
             .map(|c| {
-                let stat = stats.get(&c.column_id).ok_or_else(|| {
-                    ErrorCode::UnknownException(format!(
-                        "Unable to get the colStats by ColumnId: {}",
-                        c.column_id
-                    ))
-                })?;
-                c.apply_stat_value(stat, self.origin.clone())?.to_array()
+                let stat = &stats[&c.column_id];
+                let value = c.apply_stat_value(stat, self.origin.clone())?;
+                match value.is_null() {
+                    true => value.to_array()?.cast_with_type(c.stat_field.data_type()),
+                    false => value.to_array(),
+                }
             })
             .collect::<Result<Vec<_>>>()?;
         let data_block = DataBlock::create_by_array(self.schema.clone(), columns);
         let executed_data_block = self.executor.execute(&data_block)?;
 
+        // NULL means the block has no row on which the predicate is TRUE.
         executed_data_block.column(0).try_get(0)?.as_bool()
     }
 }
@@ -103,22 +114,41 @@ pub fn build_verifiable_expr(
     expr: &Expression,
     schema: &DataSchemaRef,
     stat_columns: &mut StatColumns,
+) -> Expression {
+    build_verifiable_expr_of_truth(expr, Truth::True, schema, stat_columns)
+}
+
+/// Build the expression telling whether a block may contain a row on which `expr` takes
+/// `truth`, negations are pushed down by looking for the opposite truth of their operand.
+fn build_verifiable_expr_of_truth(
+    expr: &Expression,
+    truth: Truth,
+    schema: &DataSchemaRef,
+    stat_columns: &mut StatColumns,
 ) -> Expression {
     let unhandled = lit(true);
 
     let (exprs, op) = match expr {
-        Expression::Literal { .. } => return expr.clone(),
+        Expression::Literal { value, .. } => return truth.literal(value).unwrap_or(unhandled),
+        Expression::UnaryExpression { op, expr } if op.to_lowercase() == "not" => {
+            return build_verifiable_expr_of_truth(expr, truth.negate(), schema, stat_columns);
+        }
+        Expression::ScalarFunction { op, args }
+            if op.to_lowercase() == "not" && args.len() == 1 =>
+        {
+            return build_verifiable_expr_of_truth(&args[0], truth.negate(), schema, stat_columns);
+        }
         Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
-                let left = build_verifiable_expr(left, schema, stat_columns);
-                let right = build_verifiable_expr(right, schema, stat_columns);
-                return left.and(right);
+                let left = build_verifiable_expr_of_truth(left, truth, schema, stat_columns);
+                let right = build_verifiable_expr_of_truth(right, truth, schema, stat_columns);
+                return truth.and(left, right);
             }
             "or" => {
-                let left = build_verifiable_expr(left, schema, stat_columns);
-                let right = build_verifiable_expr(right, schema, stat_columns);
-                return left.or(right);
+                let left = build_verifiable_expr_of_truth(left, truth, schema, stat_columns);
+                let right = build_verifiable_expr_of_truth(right, truth, schema, stat_columns);
+                return truth.or(left, right);
             }
             _ => (
                 vec![left.as_ref().clone(), right.as_ref().clone()],
@@ -128,8 +158,24 @@ pub fn build_verifiable_expr(
         _ => return unhandled,
     };
 
-    VerifiableExprBuilder::try_create(exprs, op.to_lowercase().as_str(), schema, stat_columns)
-        .map_or(unhandled.clone(), |mut v| v.build().unwrap_or(unhandled))
+    let op = op.to_lowercase();
+    let op = match truth.atom_operator(op.as_str()) {
+        Some(op) => op,
+        None => return unhandled,
+    };
+
+    let verifiable_expr =
+        VerifiableExprBuilder::try_create(exprs.clone(), op, schema, stat_columns)
+            .and_then(|mut v| v.build());
+    match (verifiable_expr, exprs.as_slice()) {
+        (Ok(expr), _) => expr,
+        // Fallback to the interval arithmetic for the comparisons of several columns or of
+        // non monotonic expressions.
+        (Err(_), [left, right]) => RangeAnalysis::create(schema, stat_columns)
+            .build(op, left, right)
+            .unwrap_or(unhandled),
+        (Err(_), _) => unhandled,
+    }
 }
 
 fn inverse_operator(op: &str) -> Result<&str> {
@@ -147,7 +193,7 @@ fn inverse_operator(op: &str) -> Result<&str> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-enum StatType {
+pub(crate) enum StatType {
     Min,
     Max,
     Nulls,
@@ -186,7 +232,9 @@ impl StatColumn {
         } else {
             field.data_type().clone()
         };
-        let stat_field = DataField::new(column_new.as_str(), data_type, field.is_nullable());
+        // The min and max of a block whose values are all NULL are NULL.
+        let nullable = field.is_nullable() || !matches!(stat_type, StatType::Nulls);
+        let stat_field = DataField::new(column_new.as_str(), data_type, nullable);
 
         Self {
             column_id,
@@ -206,6 +254,11 @@ impl StatColumn {
             return Ok(DataValue::UInt64(Some(column_stats.null_count)));
         }
 
+        // All the values are NULL, so is any expression of them.
+        if column_stats.min.is_null() || column_stats.max.is_null() {
+            return Ok(DataValue::Null);
+        }
+
         let variable_left = Some(DataColumnWithField::new(
             DataColumn::Constant(column_stats.min.clone(), 1),
             self.column_field.clone(),
@@ -260,6 +313,39 @@ impl StatColumn {
 
 pub type StatColumns = Vec<StatColumn>;
 
+/// Register the stat column if it is not yet, and return the expression reading it.
+fn add_stat_column(stat_columns: &mut StatColumns, stat_col: StatColumn) -> Expression {
+    let name = stat_col.stat_field.name().to_owned();
+    if !stat_columns.iter().any(|c| {
+        c.column_id == stat_col.column_id
+            && c.stat_type == stat_col.stat_type
+            && c.stat_field.name() == &name
+    }) {
+        stat_columns.push(stat_col);
+    }
+    Expression::Column(name)
+}
+
+/// The expression reading the min or max of the column in a block.
+pub(crate) fn column_stat_expr(
+    column_name: &str,
+    stat_type: StatType,
+    schema: &DataSchemaRef,
+    stat_columns: &mut StatColumns,
+) -> Result<Expression> {
+    let (index, field) = schema
+        .column_with_name(column_name)
+        .ok_or_else(|| ErrorCode::UnknownException("Unable to find the column name"))?;
+    let stat_col = StatColumn::create(
+        index as u32,
+        field.clone(),
+        stat_type,
+        field,
+        Expression::Column(column_name.to_owned()),
+    );
+    Ok(add_stat_column(stat_columns, stat_col))
+}
+
 struct VerifiableExprBuilder<'a> {
     op: &'a str,
     args: Expressions,
@@ -457,14 +543,7 @@ impl<'a> VerifiableExprBuilder<'a> {
             &self.field,
             self.args[0].clone(),
         );
-        if !self.stat_columns.iter().any(|c| {
-            c.column_id == self.column_id
-                && c.stat_type == stat_type
-                && c.stat_field.name() == self.field.name()
-        }) {
-            self.stat_columns.push(stat_col.clone());
-        }
-        Ok(Expression::Column(stat_col.stat_field.name().to_owned()))
+        Ok(add_stat_column(self.stat_columns, stat_col))
     }
 
     fn min_column_expr(&mut self) -> Result<Expression> {
@@ -32,6 +32,7 @@ use common_streams::SendableDataBlockStream;
 use crate::sessions::QueryContext;
 use crate::storages::memory::MemoryTableStream;
 use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
 use crate::storages::Table;
 
 pub struct MemoryTable {
@@ -58,6 +59,17 @@ impl MemoryTable {
         let table = Self { table_info, blocks };
         Ok(Box::new(table))
     }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "MEMORY".to_string(),
+            comment: "The rows are kept in memory, lost when the server stops".to_string(),
+            supports_append: true,
+            supports_truncate: true,
+            supports_delete: false,
+            supports_time_travel: false,
+        }
+    }
 }
 
 #[async_trait::async_trait]
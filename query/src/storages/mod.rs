@@ -12,22 +12,48 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod external;
 pub mod fuse;
 pub mod github;
 pub mod index;
 pub mod memory;
 pub mod null;
+pub mod stream;
 pub mod system;
 
+mod snapshot_expirer;
+mod statistics_refresher;
 mod storage_context;
 mod storage_factory;
 mod storage_table;
 mod storage_table_read_plan;
 
+pub use fuse::FuseBlocksTable;
 pub use fuse::FuseHistoryTable;
+pub use fuse::FuseSegmentsTable;
+pub use fuse::FuseSnapshotDiffTable;
+pub use fuse::FUSE_FUNC_BLOCKS;
 pub use fuse::FUSE_FUNC_HIST;
+pub use fuse::FUSE_FUNC_SEGMENTS;
+pub use fuse::FUSE_FUNC_SNAPSHOTS;
+pub use fuse::FUSE_FUNC_SNAPSHOT_DIFF;
+pub use snapshot_expirer::expire_snapshots;
+pub use snapshot_expirer::history_retention_hours;
+pub use snapshot_expirer::retain_since;
+pub use snapshot_expirer::SnapshotExpirer;
+pub use statistics_refresher::CommitWatcher;
+pub use statistics_refresher::StatisticsRefresher;
 pub use storage_context::StorageContext;
+pub use storage_factory::Storage;
 pub use storage_factory::StorageCreator;
+pub use storage_factory::StorageDescription;
+pub use storage_factory::StorageDescriptor;
 pub use storage_factory::StorageFactory;
+pub use storage_factory::StorageOperation;
+pub use storage_table::AnalyzedColumnStatistics;
+pub use storage_table::AnalyzedStatistics;
+pub use storage_table::NavigationPoint;
 pub use storage_table::Table;
+pub use storage_table::TableStatistics;
+pub use storage_table::VacuumStatistics;
 pub use storage_table_read_plan::ToReadDataSourcePlan;
@@ -28,6 +28,7 @@ use futures::stream::StreamExt;
 
 use crate::sessions::QueryContext;
 use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
 use crate::storages::Table;
 
 pub struct NullTable {
@@ -38,6 +39,17 @@ impl NullTable {
     pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
         Ok(Box::new(Self { table_info }))
     }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "NULL".to_string(),
+            comment: "The rows inserted are discarded".to_string(),
+            supports_append: true,
+            supports_truncate: true,
+            supports_delete: false,
+            supports_time_travel: false,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,195 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::tokio;
+use common_base::tokio::sync::Notify;
+use common_base::tokio::task::JoinHandle;
+use common_base::tokio::time::sleep as tokio_async_sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use futures::future::select;
+use futures::future::Either;
+use futures::Future;
+
+use crate::catalogs::Catalog;
+use crate::configs::Config;
+use crate::databases::Database;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::storages::fuse::TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use crate::storages::Table;
+
+/// The hours the history of a table is retained for: the `DATA_RETENTION_TIME_IN_DAYS` of the
+/// table, or else the one of its database, or else `history_retention_hours`.
+pub fn history_retention_hours(
+    conf: &Config,
+    database: &dyn Database,
+    table: &dyn Table,
+) -> Result<u64> {
+    let days = match table.options().get(TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+        Some(days) => Some(days),
+        None => database
+            .options()
+            .get(TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS),
+    };
+    match days {
+        Some(days) => {
+            let days = days.parse::<u64>().map_err(|_| {
+                ErrorCode::BadOption(format!(
+                    "Invalid {} of table {}: {}",
+                    TBL_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS,
+                    table.get_table_info().desc,
+                    days
+                ))
+            })?;
+            Ok(days.saturating_mul(24))
+        }
+        None => Ok(conf.query.history_retention_hours),
+    }
+}
+
+/// The unix millis since which the history is retained, for the retention of the given hours.
+pub fn retain_since(retain_hours: u64) -> u64 {
+    (Utc::now().timestamp_millis() as u64).saturating_sub(retain_hours.saturating_mul(3_600_000))
+}
+
+/// Expires the snapshots of the tables past their retention in background, every
+/// `snapshot_expiry_interval_secs`, as VACUUM TABLE does.
+pub struct SnapshotExpirer {
+    sessions: Arc<SessionManager>,
+    interval: Duration,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Option<JoinHandle<()>>,
+}
+
+impl SnapshotExpirer {
+    pub fn create(sessions: Arc<SessionManager>) -> SnapshotExpirer {
+        let interval_secs = sessions.get_conf().query.snapshot_expiry_interval_secs;
+        SnapshotExpirer {
+            sessions,
+            interval: Duration::from_secs(interval_secs),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: None,
+        }
+    }
+
+    fn expire_loop(&self) -> impl Future<Output = ()> + 'static {
+        let sessions = self.sessions.clone();
+        let interval = self.interval;
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(interval);
+
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(failure) = Self::expire_round(&sessions).await {
+                            tracing::warn!("Cannot expire table snapshots, cause {:?}", failure);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn expire_round(sessions: &Arc<SessionManager>) -> Result<()> {
+        let session = sessions.create_session("SnapshotExpirer")?;
+        let ctx = session.create_context().await?;
+        let expired = expire_snapshots(ctx).await?;
+        if expired > 0 {
+            tracing::info!("{} table snapshots are expired", expired);
+        }
+        Ok(())
+    }
+
+    /// Nothing is started if the expiry is disabled.
+    pub fn start(&mut self) {
+        if !self.interval.is_zero() {
+            self.shutdown_handler = Some(tokio::spawn(self.expire_loop()));
+        }
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown snapshot expirer, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Vacuums the history of all the tables past their retention, returns the number of the
+/// snapshots removed. The tables of the engines keeping no history, and the read only ones,
+/// are left as they are.
+pub async fn expire_snapshots(ctx: Arc<QueryContext>) -> Result<u64> {
+    let catalog = ctx.get_catalog();
+    let conf = ctx.get_config();
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    let mut expired = 0;
+    for database in catalog.list_databases().await? {
+        for table in catalog.list_tables(database.name()).await? {
+            if user_mgr
+                .verify_writable(database.name(), table.name())
+                .await
+                .is_err()
+            {
+                continue;
+            }
+
+            let since =
+                history_retention_hours(&conf, database.as_ref(), table.as_ref()).map(retain_since);
+            let stats = match since {
+                Ok(since) => table.vacuum(ctx.clone(), since, false).await,
+                Err(cause) => Err(cause),
+            };
+            match stats {
+                Ok(stats) => expired += stats.snapshots,
+                Err(cause) if cause.code() == ErrorCode::UnImplement("").code() => {}
+                Err(cause) => {
+                    // retried in the next round
+                    tracing::warn!(
+                        "Cannot expire snapshots of table {}, cause {:?}",
+                        table.get_table_info().desc,
+                        cause
+                    );
+                }
+            }
+        }
+    }
+    Ok(expired)
+}
@@ -0,0 +1,163 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::sync::Notify;
+use common_base::tokio::task::JoinHandle;
+use common_base::tokio::time::sleep as tokio_async_sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MetaId;
+use common_tracing::tracing;
+use futures::future::select;
+use futures::future::Either;
+use futures::Future;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+
+/// Refreshes the statistics of the tables committed since the last round in background,
+/// every `statistics_refresh_interval_secs`.
+pub struct StatisticsRefresher {
+    sessions: Arc<SessionManager>,
+    interval: Duration,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Option<JoinHandle<()>>,
+}
+
+impl StatisticsRefresher {
+    pub fn create(sessions: Arc<SessionManager>) -> StatisticsRefresher {
+        let interval_secs = sessions.get_conf().query.statistics_refresh_interval_secs;
+        StatisticsRefresher {
+            sessions,
+            interval: Duration::from_secs(interval_secs),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: None,
+        }
+    }
+
+    fn refresh_loop(&self) -> impl Future<Output = ()> + 'static {
+        let sessions = self.sessions.clone();
+        let interval = self.interval;
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        async move {
+            let mut watcher = CommitWatcher::default();
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(interval);
+
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(failure) = Self::refresh_round(&sessions, &mut watcher).await {
+                            tracing::warn!("Cannot refresh table statistics, cause {:?}", failure);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn refresh_round(
+        sessions: &Arc<SessionManager>,
+        watcher: &mut CommitWatcher,
+    ) -> Result<()> {
+        let session = sessions.create_session("StatisticsRefresher")?;
+        let ctx = session.create_context().await?;
+        let refreshed = watcher.refresh(ctx).await?;
+        if refreshed > 0 {
+            tracing::info!("Statistics of {} tables are refreshed", refreshed);
+        }
+        Ok(())
+    }
+
+    /// Nothing is started if the refresh is disabled.
+    pub fn start(&mut self) {
+        if !self.interval.is_zero() {
+            self.shutdown_handler = Some(tokio::spawn(self.refresh_loop()));
+        }
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown statistics refresher, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tracks the versions of the tables, the version of a table changes at each commit.
+#[derive(Default)]
+pub struct CommitWatcher {
+    versions: HashMap<(String, MetaId), u64>,
+}
+
+impl CommitWatcher {
+    /// Refreshes the statistics of the tables committed since the last call (all the tables
+    /// at the first call), returns the number of the tables updated.
+    pub async fn refresh(&mut self, ctx: Arc<QueryContext>) -> Result<usize> {
+        let catalog = ctx.get_catalog();
+        let mut versions = HashMap::with_capacity(self.versions.len());
+        let mut refreshed = 0;
+        for database in catalog.list_databases().await? {
+            for table in catalog.list_tables(database.name()).await? {
+                let ident = &table.get_table_info().ident;
+                let key = (database.name().to_string(), ident.table_id);
+                if self.versions.get(&key) != Some(&ident.version) {
+                    match table.refresh_statistics(ctx.clone()).await {
+                        Ok(true) => refreshed += 1,
+                        Ok(false) => {}
+                        Err(cause) => {
+                            // retried in the next round
+                            tracing::warn!(
+                                "Cannot refresh statistics of table {}, cause {:?}",
+                                table.get_table_info().desc,
+                                cause
+                            );
+                            continue;
+                        }
+                    }
+                }
+                versions.insert(key, ident.version);
+            }
+        }
+
+        // the dropped tables are forgotten
+        self.versions = versions;
+        Ok(refreshed)
+    }
+}
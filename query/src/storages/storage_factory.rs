@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -21,10 +23,13 @@ use common_infallible::RwLock;
 use common_meta_types::TableInfo;
 
 use crate::configs::Config;
+use crate::storages::external::ExternalTable;
 use crate::storages::fuse::FuseTable;
 use crate::storages::github::GithubTable;
 use crate::storages::memory::MemoryTable;
 use crate::storages::null::NullTable;
+use crate::storages::stream::StreamTable;
+use crate::storages::stream::STREAM_ENGINE;
 use crate::storages::StorageContext;
 use crate::storages::Table;
 
@@ -42,44 +47,167 @@ where
     }
 }
 
+/// A table engine and the operations its tables support, for `SHOW ENGINES` and for telling an
+/// unsupported operation before it is run. The tables of any engine can be read.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageDescription {
+    pub engine_name: String,
+    pub comment: String,
+    pub supports_append: bool,
+    pub supports_truncate: bool,
+    pub supports_delete: bool,
+    pub supports_time_travel: bool,
+}
+
+impl StorageDescription {
+    pub fn supports(&self, operation: StorageOperation) -> bool {
+        match operation {
+            StorageOperation::Append => self.supports_append,
+            StorageOperation::Truncate => self.supports_truncate,
+            StorageOperation::Delete => self.supports_delete,
+            StorageOperation::TimeTravel => self.supports_time_travel,
+        }
+    }
+}
+
+/// The operations on the tables not supported by every engine.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StorageOperation {
+    Append,
+    Truncate,
+    Delete,
+    TimeTravel,
+}
+
+impl Display for StorageOperation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            StorageOperation::Append => write!(f, "INSERT"),
+            StorageOperation::Truncate => write!(f, "TRUNCATE"),
+            StorageOperation::Delete => write!(f, "DELETE"),
+            StorageOperation::TimeTravel => write!(f, "time travel"),
+        }
+    }
+}
+
+pub trait StorageDescriptor: Send + Sync {
+    fn description(&self) -> StorageDescription;
+}
+
+impl<T> StorageDescriptor for T
+where
+    T: Fn() -> StorageDescription,
+    T: Send + Sync,
+{
+    fn description(&self) -> StorageDescription {
+        self()
+    }
+}
+
+pub struct Storage {
+    creator: Arc<dyn StorageCreator>,
+    descriptor: Arc<dyn StorageDescriptor>,
+}
+
+impl Storage {
+    pub fn create(
+        creator: Arc<dyn StorageCreator>,
+        descriptor: Arc<dyn StorageDescriptor>,
+    ) -> Storage {
+        Storage {
+            creator,
+            descriptor,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct StorageFactory {
-    creators: RwLock<HashMap<String, Arc<dyn StorageCreator>>>,
+    storages: RwLock<HashMap<String, Storage>>,
 }
 
 impl StorageFactory {
     pub fn create(conf: Config) -> Self {
-        let mut creators: HashMap<String, Arc<dyn StorageCreator>> = Default::default();
+        let mut storages: HashMap<String, Storage> = Default::default();
 
         // Register memory table engine.
         if conf.query.table_engine_memory_enabled {
-            creators.insert("MEMORY".to_string(), Arc::new(MemoryTable::try_create));
+            storages.insert(
+                "MEMORY".to_string(),
+                Storage::create(
+                    Arc::new(MemoryTable::try_create),
+                    Arc::new(MemoryTable::description),
+                ),
+            );
         }
 
         // Register github table engine;
         if conf.query.database_engine_github_enabled {
-            creators.insert("GITHUB".to_string(), Arc::new(GithubTable::try_create));
+            storages.insert(
+                "GITHUB".to_string(),
+                Storage::create(
+                    Arc::new(GithubTable::try_create),
+                    Arc::new(GithubTable::description),
+                ),
+            );
         }
 
         // Register NULL table engine.
-        creators.insert("NULL".to_string(), Arc::new(NullTable::try_create));
+        storages.insert(
+            "NULL".to_string(),
+            Storage::create(
+                Arc::new(NullTable::try_create),
+                Arc::new(NullTable::description),
+            ),
+        );
 
         // Register FUSE table engine.
-        creators.insert("FUSE".to_string(), Arc::new(FuseTable::try_create));
+        storages.insert(
+            "FUSE".to_string(),
+            Storage::create(
+                Arc::new(FuseTable::try_create),
+                Arc::new(FuseTable::description),
+            ),
+        );
+
+        // Register EXTERNAL table engine.
+        storages.insert(
+            "EXTERNAL".to_string(),
+            Storage::create(
+                Arc::new(ExternalTable::try_create),
+                Arc::new(ExternalTable::description),
+            ),
+        );
+
+        // Register STREAM table engine.
+        storages.insert(
+            STREAM_ENGINE.to_string(),
+            Storage::create(
+                Arc::new(StreamTable::try_create),
+                Arc::new(StreamTable::description),
+            ),
+        );
 
         StorageFactory {
-            creators: RwLock::new(creators),
+            storages: RwLock::new(storages),
         }
     }
 
     pub fn get_table(&self, ctx: StorageContext, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let engine = table_info.engine().to_uppercase();
-        let lock = self.creators.read();
-        let factory = lock.get(&engine).ok_or_else(|| {
+        let lock = self.storages.read();
+        let storage = lock.get(&engine).ok_or_else(|| {
             ErrorCode::UnknownTableEngine(format!("Unknown table engine {}", engine))
         })?;
 
-        let table: Arc<dyn Table> = factory.try_create(ctx, table_info.clone())?.into();
+        let table: Arc<dyn Table> = storage.creator.try_create(ctx, table_info.clone())?.into();
         Ok(table)
     }
+
+    pub fn get_storage_descriptors(&self) -> Vec<StorageDescription> {
+        let lock = self.storages.read();
+        lock.values()
+            .map(|storage| storage.descriptor.description())
+            .collect()
+    }
 }
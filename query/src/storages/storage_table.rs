@@ -13,15 +13,20 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
 use common_meta_types::TableInfo;
+use common_planners::AlterColumnOperation;
+use common_planners::DeletePlan;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Part;
@@ -51,6 +56,14 @@ pub trait Table: Sync + Send {
         self.get_table_info().options()
     }
 
+    /// The options set by the user, sorted by their names, as shown by SHOW CREATE TABLE.
+    fn user_options(&self) -> BTreeMap<String, String> {
+        self.options()
+            .iter()
+            .map(|(k, v)| (k.to_uppercase(), v.clone()))
+            .collect()
+    }
+
     fn get_id(&self) -> MetaId {
         self.get_table_info().ident.table_id
     }
@@ -68,6 +81,17 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// whether the sampling of the push downs is applied by the table read
+    fn support_sampling(&self) -> bool {
+        false
+    }
+
+    /// the columns filled by the table read instead of being stored, which are projected by
+    /// the indices following the ones of the schema, and left out of `*`
+    fn virtual_columns(&self) -> Vec<DataField> {
+        vec![]
+    }
+
     // defaults to generate one single part and empty statistics
     async fn read_partitions(
         &self,
@@ -84,6 +108,22 @@ pub trait Table: Sync + Send {
         None
     }
 
+    /// The sort keys (`Expression::Sort`) the rows of each partition are read in, which spare
+    /// the sorts by them. Empty if the rows are in no particular order.
+    fn read_order(&self) -> Vec<Expression> {
+        vec![]
+    }
+
+    /// The row and byte counters maintained by the engine, None if it does not keep them.
+    fn statistics(&self) -> Result<Option<TableStatistics>> {
+        Ok(None)
+    }
+
+    /// Brings the statistics up to date with the data, returns whether they are updated.
+    async fn refresh_statistics(&self, _ctx: Arc<QueryContext>) -> Result<bool> {
+        Ok(false)
+    }
+
     // Read block data from the underling.
     async fn read(
         &self,
@@ -123,7 +163,135 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Deletes the rows matching the predicate of the plan, or all the rows without one.
+    async fn delete(&self, _ctx: Arc<QueryContext>, _delete_plan: DeletePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     async fn optimize(&self, _ctx: Arc<QueryContext>, _keep_last_snapshot: bool) -> Result<()> {
         Ok(())
     }
+
+    async fn compact(&self, _ctx: Arc<QueryContext>) -> Result<()> {
+        Ok(())
+    }
+
+    /// The table as of the given point of its history, for `SELECT ... FROM t AT (...)`.
+    async fn navigate_to(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        Err(ErrorCode::UnImplement(format!(
+            "time travel for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Removes the data only referenced by the history before the given unix millis, the table
+    /// can still be read as of any time after it. Nothing is removed if `dry_run` is set.
+    async fn vacuum(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _retain_since: u64,
+        _dry_run: bool,
+    ) -> Result<VacuumStatistics> {
+        Err(ErrorCode::UnImplement(format!(
+            "vacuum for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Computes and persists the column statistics of the table, for `ANALYZE TABLE`.
+    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "analyze for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Adds, drops or renames a column of the table, for `ALTER TABLE ... COLUMN`.
+    async fn alter_column(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _operation: &AlterColumnOperation,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "alter column for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Sets the options of the table, for `ALTER TABLE ... SET name = value, ...`.
+    async fn set_options(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _options: &HashMap<String, String>,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "set options for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// The statistics persisted by the latest [`Table::analyze`], None if never analyzed.
+    async fn analyzed_statistics(
+        &self,
+        _ctx: Arc<QueryContext>,
+    ) -> Result<Option<AnalyzedStatistics>> {
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableStatistics {
+    pub num_rows: u64,
+    pub data_size: u64,
+    pub data_size_compressed: u64,
+}
+
+/// What is (or would be, in a dry run) removed by [`Table::vacuum`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VacuumStatistics {
+    pub snapshots: u64,
+    pub segments: u64,
+    pub blocks: u64,
+    /// The size of the removed block files.
+    pub block_bytes: u64,
+}
+
+/// The statistics computed by [`Table::analyze`], which may be outdated by the later changes of
+/// the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AnalyzedStatistics {
+    /// The snapshot the statistics are computed of, if the table keeps snapshots.
+    pub snapshot: Option<String>,
+    pub num_rows: u64,
+    pub columns: Vec<AnalyzedColumnStatistics>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AnalyzedColumnStatistics {
+    pub name: String,
+    pub ndv: u64,
+    pub null_count: u64,
+    /// The upper bounds of the equi-height buckets of the non null values, in ascending order.
+    pub histogram_bounds: Vec<DataValue>,
+}
+
+/// A point of the history of a table to travel to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NavigationPoint {
+    SnapshotID(String),
+    /// Unix milliseconds, the table is read as of the latest snapshot committed at or before it.
+    TimePoint(u64),
 }
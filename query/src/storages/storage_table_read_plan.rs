@@ -45,12 +45,19 @@ impl ToReadDataSourcePlan for dyn Table {
         let table_info = self.get_table_info();
         let description = get_description(table_info, &statistics);
 
+        let schema = table_info.schema();
+        let num_columns = schema.fields().len();
         let scan_fields = match (self.benefit_column_prune(), &push_downs) {
             (true, Some(push_downs)) => match &push_downs.projection {
-                Some(projection) if projection.len() < table_info.schema().fields().len() => {
-                    let fields = projection
-                        .iter()
-                        .map(|i| table_info.schema().field(*i).clone());
+                Some(projection)
+                    if projection.len() < num_columns
+                        || projection.iter().any(|i| *i >= num_columns) =>
+                {
+                    let virtual_columns = self.virtual_columns();
+                    let fields = projection.iter().map(|i| match *i < num_columns {
+                        true => schema.field(*i).clone(),
+                        false => virtual_columns[*i - num_columns].clone(),
+                    });
 
                     Some((projection.iter().cloned().zip(fields)).collect::<BTreeMap<_, _>>())
                 }
@@ -67,6 +74,7 @@ impl ToReadDataSourcePlan for dyn Table {
             description,
             tbl_args: self.table_args(),
             push_downs,
+            read_order: self.read_order(),
         })
     }
 }
@@ -0,0 +1,180 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+/// The database and the name of the fuse table the changes of which are returned.
+pub const STREAM_TBL_OPT_KEY_TABLE_DATABASE: &str = "table_database";
+pub const STREAM_TBL_OPT_KEY_TABLE_NAME: &str = "table_name";
+/// The location of the snapshot of the table the stream is consumed up to, none if the table
+/// was empty.
+pub const STREAM_TBL_OPT_KEY_OFFSET: &str = "offset_snapshot_location";
+
+/// The rows appended to a fuse table since the offset of the stream, e.g. created by
+/// `CREATE STREAM s ON TABLE t`, of the schema of the table.
+///
+/// Reading a stream does not move its offset, an INSERT reading it does once committed, so
+/// that the next INSERT reads only the rows appended since then. The changes are told by the
+/// blocks of the snapshots, the rows of the blocks re-written by deletion and compaction are
+/// returned again.
+pub struct StreamTable {
+    table_info: TableInfo,
+}
+
+impl StreamTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        Self::option(options, STREAM_TBL_OPT_KEY_TABLE_DATABASE)?;
+        Self::option(options, STREAM_TBL_OPT_KEY_TABLE_NAME)?;
+        Ok(Box::new(Self { table_info }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "The rows appended to a fuse table since the offset".to_string(),
+            supports_append: false,
+            supports_truncate: false,
+            supports_delete: false,
+            supports_time_travel: false,
+        }
+    }
+
+    /// The options of a stream on the table, consumed up to the current snapshot of the table.
+    pub fn create_options(database: &str, table: &dyn Table) -> Result<HashMap<String, String>> {
+        let fuse_table = Self::as_fuse_table(table)?;
+        let mut options = HashMap::new();
+        options.insert(
+            STREAM_TBL_OPT_KEY_TABLE_DATABASE.to_string(),
+            database.to_string(),
+        );
+        options.insert(STREAM_TBL_OPT_KEY_TABLE_NAME.to_string(), table.name());
+        if let Some(loc) = fuse_table.snapshot_loc() {
+            options.insert(STREAM_TBL_OPT_KEY_OFFSET.to_string(), loc);
+        }
+        Ok(options)
+    }
+
+    fn option<'a>(options: &'a HashMap<String, String>, key: &str) -> Result<&'a String> {
+        options.get(key).ok_or_else(|| {
+            ErrorCode::BadOption(format!("Stream requires the option {} of its table", key))
+        })
+    }
+
+    fn as_fuse_table(table: &dyn Table) -> Result<&FuseTable> {
+        table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::UnImplement(format!(
+                "Stream on table {} of engine {} is not supported, only fuse tables are",
+                table.name(),
+                table.get_table_info().engine()
+            ))
+        })
+    }
+
+    // The table of the stream, as read by the query.
+    async fn source_table(&self, ctx: &QueryContext) -> Result<Arc<dyn Table>> {
+        let options = self.table_info.options();
+        let database = Self::option(options, STREAM_TBL_OPT_KEY_TABLE_DATABASE)?;
+        let name = Self::option(options, STREAM_TBL_OPT_KEY_TABLE_NAME)?;
+        let table = ctx.get_table(database, name).await?;
+        Self::as_fuse_table(table.as_ref())?;
+        if table.schema() != self.table_info.schema() {
+            return Err(ErrorCode::BadOption(format!(
+                "The schema of table {}.{} has been changed since stream {} was created",
+                database,
+                name,
+                self.name()
+            )));
+        }
+        Ok(table)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    // The blocks appended since the offset, which is advanced to the snapshot read once the
+    // query is an INSERT and it is committed.
+    async fn read_partitions(
+        &self,
+        ctx: Arc<QueryContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let table = self.source_table(ctx.as_ref()).await?;
+        let fuse_table = Self::as_fuse_table(table.as_ref())?;
+        let offset = self.table_info.options().get(STREAM_TBL_OPT_KEY_OFFSET);
+        let (blocks, snapshot_loc) = fuse_table
+            .blocks_added_since(ctx.as_ref(), offset.map(|loc| loc.as_str()))
+            .await?;
+
+        if let Some(loc) = snapshot_loc {
+            if Some(&loc) != offset {
+                let ident = &self.table_info.ident;
+                let req = UpsertTableOptionReq::new(ident, STREAM_TBL_OPT_KEY_OFFSET, loc);
+                ctx.set_stream_offset(ident.table_id, req);
+            }
+        }
+
+        let push_downs = match push_downs {
+            Some(mut extras) => {
+                if let Some(projection) = &extras.projection {
+                    extras.projection = Some(fuse_table.physical_projection(projection)?);
+                }
+                Some(extras)
+            }
+            None => None,
+        };
+        Ok(FuseTable::to_partitions(&blocks, push_downs))
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let table = self.source_table(ctx.as_ref()).await?;
+        table.read(ctx, plan).await
+    }
+}
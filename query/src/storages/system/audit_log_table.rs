@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+pub struct AuditLogTable {
+    table_info: TableInfo,
+}
+
+impl AuditLogTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("event_time", DataType::DateTime64(3, None), false),
+            DataField::new("event_type", DataType::String, false),
+            DataField::new("tenant_id", DataType::String, false),
+            DataField::new("handler_type", DataType::String, false),
+            DataField::new("session_id", DataType::String, false),
+            DataField::new("query_id", DataType::String, false),
+            DataField::new("sql_user", DataType::String, false),
+            DataField::new("client_address", DataType::String, false),
+            DataField::new("operation", DataType::String, false),
+            DataField::new("query_text", DataType::String, false),
+            DataField::new("success", DataType::Boolean, false),
+            DataField::new("exception_code", DataType::Int32, false),
+            DataField::new("exception_text", DataType::String, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'audit_log'".to_string(),
+            name: "audit_log".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemAuditLog".to_string(),
+
+                ..Default::default()
+            },
+        };
+        AuditLogTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for AuditLogTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let tenant_id = ctx.get_config().query.tenant_id;
+        let events = ctx
+            .get_sessions_manager()
+            .get_audit_log()
+            .events(&tenant_id);
+
+        let event_time: Vec<u64> = events.iter().map(|e| e.event_time).collect();
+        let event_type: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        let tenant_id: Vec<&str> = events.iter().map(|e| e.tenant_id.as_str()).collect();
+        let handler_type: Vec<&str> = events.iter().map(|e| e.handler_type.as_str()).collect();
+        let session_id: Vec<&str> = events.iter().map(|e| e.session_id.as_str()).collect();
+        let query_id: Vec<&str> = events.iter().map(|e| e.query_id.as_str()).collect();
+        let user: Vec<&str> = events.iter().map(|e| e.user.as_str()).collect();
+        let client_address: Vec<&str> = events.iter().map(|e| e.client_address.as_str()).collect();
+        let operation: Vec<&str> = events.iter().map(|e| e.operation.as_str()).collect();
+        let query_text: Vec<&str> = events.iter().map(|e| e.query_text.as_str()).collect();
+        let success: Vec<bool> = events.iter().map(|e| e.success).collect();
+        let exception_code: Vec<i32> = events.iter().map(|e| e.exception_code).collect();
+        let exception_text: Vec<&str> = events.iter().map(|e| e.exception.as_str()).collect();
+
+        let schema = self.table_info.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(event_time),
+            Series::new(event_type),
+            Series::new(tenant_id),
+            Series::new(handler_type),
+            Series::new(session_id),
+            Series::new(query_id),
+            Series::new(user),
+            Series::new(client_address),
+            Series::new(operation),
+            Series::new(query_text),
+            Series::new(success),
+            Series::new(exception_code),
+            Series::new(exception_text),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_meta_types::META_PROTOCOL_VERSION;
+use common_meta_types::MIN_METASRV_PROTOCOL_VERSION;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::configs::DATABEND_COMMIT_VERSION;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The options this binary is built with, and the versions it is compatible with.
+pub struct BuildOptionsTable {
+    table_info: TableInfo,
+}
+
+impl BuildOptionsTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String, false),
+            DataField::new("value", DataType::String, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'build_options'".to_string(),
+            name: "build_options".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemBuildOptions".to_string(),
+                ..Default::default()
+            },
+        };
+        BuildOptionsTable { table_info }
+    }
+
+    fn options() -> Vec<(&'static str, String)> {
+        let env = |v: Option<&str>| v.unwrap_or_default().to_string();
+        vec![
+            ("version", DATABEND_COMMIT_VERSION.to_string()),
+            ("semver", env(option_env!("VERGEN_BUILD_SEMVER"))),
+            ("git_sha", env(option_env!("VERGEN_GIT_SHA_SHORT"))),
+            (
+                "build_timestamp",
+                env(option_env!("VERGEN_BUILD_TIMESTAMP")),
+            ),
+            ("rustc_semver", env(option_env!("VERGEN_RUSTC_SEMVER"))),
+            (
+                "target_triple",
+                env(option_env!("VERGEN_CARGO_TARGET_TRIPLE")),
+            ),
+            ("profile", env(option_env!("VERGEN_CARGO_PROFILE"))),
+            ("features", env(option_env!("VERGEN_CARGO_FEATURES"))),
+            ("simd", cfg!(feature = "simd").to_string()),
+            ("meta_protocol_version", META_PROTOCOL_VERSION.to_string()),
+            (
+                "min_meta_service_protocol_version",
+                MIN_METASRV_PROTOCOL_VERSION.to_string(),
+            ),
+        ]
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for BuildOptionsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let options = Self::options();
+        let names: Vec<&str> = options.iter().map(|(name, _)| *name).collect();
+        let values: Vec<&str> = options.iter().map(|(_, value)| value.as_str()).collect();
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(names),
+            Series::new(values),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
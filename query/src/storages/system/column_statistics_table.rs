@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The column statistics persisted by `ANALYZE TABLE`, of the analyzed tables only.
+pub struct ColumnStatisticsTable {
+    table_info: TableInfo,
+}
+
+impl ColumnStatisticsTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", DataType::String, false),
+            DataField::new("table", DataType::String, false),
+            DataField::new("column", DataType::String, false),
+            DataField::new("num_rows", DataType::UInt64, false),
+            DataField::new("ndv", DataType::UInt64, false),
+            DataField::new("null_count", DataType::UInt64, false),
+            // the upper bounds of the equi-height buckets, e.g. [10, 20, 35]
+            DataField::new("histogram_bounds", DataType::String, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'column_statistics'".to_string(),
+            name: "column_statistics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemColumnStatistics".to_string(),
+                ..Default::default()
+            },
+        };
+
+        ColumnStatisticsTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ColumnStatisticsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let catalog = ctx.get_catalog();
+        let databases = catalog.list_databases().await?;
+
+        let mut database_names = vec![];
+        let mut table_names = vec![];
+        let mut column_names = vec![];
+        let mut num_rows = vec![];
+        let mut ndvs = vec![];
+        let mut null_counts = vec![];
+        let mut histograms = vec![];
+        for database in databases {
+            for table in catalog.list_tables(database.name()).await? {
+                let statistics = match table.analyzed_statistics(ctx.clone()).await? {
+                    Some(statistics) => statistics,
+                    None => continue,
+                };
+                for column in statistics.columns {
+                    let bounds = column
+                        .histogram_bounds
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>();
+                    database_names.push(database.name().to_string());
+                    table_names.push(table.name().to_string());
+                    column_names.push(column.name);
+                    num_rows.push(statistics.num_rows);
+                    ndvs.push(column.ndv);
+                    null_counts.push(column.null_count);
+                    histograms.push(format!("[{}]", bounds.join(", ")));
+                }
+            }
+        }
+
+        let database_names: Vec<&[u8]> = database_names.iter().map(|s| s.as_bytes()).collect();
+        let table_names: Vec<&[u8]> = table_names.iter().map(|s| s.as_bytes()).collect();
+        let column_names: Vec<&[u8]> = column_names.iter().map(|s| s.as_bytes()).collect();
+        let histograms: Vec<&[u8]> = histograms.iter().map(|s| s.as_bytes()).collect();
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(database_names),
+            Series::new(table_names),
+            Series::new(column_names),
+            Series::new(num_rows),
+            Series::new(ndvs),
+            Series::new(null_counts),
+            Series::new(histograms),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
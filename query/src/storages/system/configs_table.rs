@@ -29,6 +29,9 @@ use serde_json::Value;
 use crate::sessions::QueryContext;
 use crate::storages::Table;
 
+// The configs holding a secret, their values are masked.
+const SECRET_CONFIGS: &[&str] = &["connection_encryption_key"];
+
 pub struct ConfigsTable {
     table_info: TableInfo,
 }
@@ -66,6 +69,9 @@ impl ConfigsTable {
         for (k, v) in config_value.as_object().unwrap().into_iter() {
             names.push(k.to_string());
             match v {
+                Value::String(s) if !s.is_empty() && SECRET_CONFIGS.contains(&k.as_str()) => {
+                    values.push("******".to_string())
+                }
                 Value::String(s) => values.push(s.to_string()),
                 Value::Number(n) => values.push(n.to_string()),
                 Value::Bool(b) => values.push(b.to_string()),
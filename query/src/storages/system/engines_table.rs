@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The registered table engines, and the operations their tables support.
+pub struct EnginesTable {
+    table_info: TableInfo,
+}
+
+impl EnginesTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("engine", DataType::String, false),
+            DataField::new("comment", DataType::String, false),
+            DataField::new("supports_append", DataType::Boolean, false),
+            DataField::new("supports_truncate", DataType::Boolean, false),
+            DataField::new("supports_delete", DataType::Boolean, false),
+            DataField::new("supports_time_travel", DataType::Boolean, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'engines'".to_string(),
+            name: "engines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemEngines".to_string(),
+                ..Default::default()
+            },
+        };
+        EnginesTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for EnginesTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let mut engines = ctx.get_catalog().get_table_engines();
+        engines.sort_by(|a, b| a.engine_name.cmp(&b.engine_name));
+
+        let names: Vec<&str> = engines.iter().map(|e| e.engine_name.as_str()).collect();
+        let comments: Vec<&str> = engines.iter().map(|e| e.comment.as_str()).collect();
+        let appends: Vec<bool> = engines.iter().map(|e| e.supports_append).collect();
+        let truncates: Vec<bool> = engines.iter().map(|e| e.supports_truncate).collect();
+        let deletes: Vec<bool> = engines.iter().map(|e| e.supports_delete).collect();
+        let time_travels: Vec<bool> = engines.iter().map(|e| e.supports_time_travel).collect();
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(names),
+            Series::new(comments),
+            Series::new(appends),
+            Series::new(truncates),
+            Series::new(deletes),
+            Series::new(time_travels),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
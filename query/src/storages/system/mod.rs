@@ -12,35 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log_table;
+mod build_options_table;
 mod clusters_table;
+mod column_statistics_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
+mod engines_table;
 mod functions_table;
 mod metrics_table;
 mod one_table;
 mod processes_table;
 mod query_log_table;
+mod query_profile_table;
+mod settings_history_table;
 mod settings_table;
+mod storage_usage_table;
 mod tables_table;
 mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
 
+pub use audit_log_table::AuditLogTable;
+pub use build_options_table::BuildOptionsTable;
 pub use clusters_table::ClustersTable;
+pub use column_statistics_table::ColumnStatisticsTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
+pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
 pub use query_log_table::QueryLogTable;
+pub use query_profile_table::QueryProfileTable;
+pub use settings_history_table::SettingsHistoryTable;
 pub use settings_table::SettingsTable;
+pub use storage_usage_table::StorageUsageTable;
 pub use tables_table::TablesTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
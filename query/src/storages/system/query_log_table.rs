@@ -32,14 +32,16 @@ use futures::StreamExt;
 use crate::sessions::QueryContext;
 use crate::storages::Table;
 
+/// The latest records of the query log, at most `max_rows` of them, the oldest blocks are
+/// dropped first.
 pub struct QueryLogTable {
     table_info: TableInfo,
-    max_rows: i32,
+    max_rows: usize,
     data: RwLock<VecDeque<DataBlock>>,
 }
 
 impl QueryLogTable {
-    pub fn create(table_id: u64) -> Self {
+    pub fn create(table_id: u64, max_rows: usize) -> Self {
         let schema = DataSchemaRefExt::create(vec![
             // Type.
             DataField::new("log_type", DataType::Int8, false),
@@ -56,6 +58,7 @@ impl QueryLogTable {
             DataField::new("query_text", DataType::String, false),
             DataField::new("event_date", DataType::Date32, false),
             DataField::new("event_time", DataType::DateTime64(3, None), false),
+            DataField::new("query_duration_ms", DataType::UInt64, false),
             // Schema.
             DataField::new("current_database", DataType::String, false),
             DataField::new("databases", DataType::String, false),
@@ -74,6 +77,7 @@ impl QueryLogTable {
             DataField::new("result_bytes", DataType::UInt64, false),
             DataField::new("cpu_usage", DataType::UInt32, false),
             DataField::new("memory_usage", DataType::UInt64, false),
+            DataField::new("peak_memory_usage", DataType::UInt64, false),
             // Client.
             DataField::new("client_info", DataType::String, false),
             DataField::new("client_address", DataType::String, false),
@@ -99,15 +103,10 @@ impl QueryLogTable {
         };
         QueryLogTable {
             table_info,
-            max_rows: 200000,
+            max_rows,
             data: RwLock::new(VecDeque::new()),
         }
     }
-
-    #[allow(dead_code)]
-    pub fn set_max_rows(&mut self, max: i32) {
-        self.max_rows = max;
-    }
 }
 
 #[async_trait::async_trait]
@@ -148,10 +147,12 @@ impl Table for QueryLogTable {
         }
 
         // Check overflow.
-        let over = self.data.read().len() as i32 - self.max_rows;
-        if over > 0 {
-            for _x in 0..over {
-                self.data.write().pop_front();
+        let mut data = self.data.write();
+        let mut rows = data.iter().map(|block| block.num_rows()).sum::<usize>();
+        while rows > self.max_rows {
+            match data.pop_front() {
+                Some(block) => rows -= block.num_rows(),
+                None => break,
             }
         }
 
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_infallible::RwLock;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_planners::TruncateTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The scan metrics of the parts read by the recent queries, a block for each query.
+pub struct QueryProfileTable {
+    table_info: TableInfo,
+    max_rows: i32,
+    data: RwLock<VecDeque<DataBlock>>,
+}
+
+impl QueryProfileTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("query_id", DataType::String, false),
+            DataField::new("part", DataType::String, false),
+            DataField::new("read_bytes", DataType::UInt64, false),
+            DataField::new("read_cost_us", DataType::UInt64, false),
+            DataField::new("decode_cost_us", DataType::UInt64, false),
+            DataField::new("cache_hit", DataType::Boolean, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_profile'".to_string(),
+            name: "query_profile".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryProfile".to_string(),
+                ..Default::default()
+            },
+        };
+        QueryProfileTable {
+            table_info,
+            max_rows: 10000,
+            data: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn set_max_rows(&mut self, max: i32) {
+        self.max_rows = max;
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryProfileTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let data = self.data.read().clone();
+        let mut blocks = Vec::with_capacity(data.len());
+        for block in data {
+            blocks.push(block);
+        }
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            blocks,
+        )))
+    }
+
+    async fn append_data(
+        &self,
+        _ctx: Arc<QueryContext>,
+        mut stream: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            self.data.write().push_back(block);
+        }
+
+        // Check overflow.
+        let over = self.data.read().len() as i32 - self.max_rows;
+        if over > 0 {
+            for _x in 0..over {
+                self.data.write().pop_front();
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            std::sync::Arc::new(DataSchema::empty()),
+            None,
+            vec![],
+        )))
+    }
+
+    async fn truncate(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _truncate_plan: TruncateTablePlan,
+    ) -> Result<()> {
+        let mut data = self.data.write();
+        *data = VecDeque::new();
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+pub struct SettingsHistoryTable {
+    table_info: TableInfo,
+}
+
+impl SettingsHistoryTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("event_time", DataType::DateTime64(3, None), false),
+            DataField::new("tenant_id", DataType::String, false),
+            DataField::new("handler_type", DataType::String, false),
+            DataField::new("query_id", DataType::String, false),
+            DataField::new("sql_user", DataType::String, false),
+            DataField::new("client_address", DataType::String, false),
+            DataField::new("name", DataType::String, false),
+            DataField::new("old_value", DataType::String, false),
+            DataField::new("new_value", DataType::String, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'settings_history'".to_string(),
+            name: "settings_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSettingsHistory".to_string(),
+
+                ..Default::default()
+            },
+        };
+        SettingsHistoryTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for SettingsHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let changes = ctx.get_sessions_manager().get_settings_history().changes();
+
+        let event_time: Vec<u64> = changes.iter().map(|c| c.event_time).collect();
+        let tenant_id: Vec<&str> = changes.iter().map(|c| c.tenant_id.as_str()).collect();
+        let handler_type: Vec<&str> = changes.iter().map(|c| c.handler_type.as_str()).collect();
+        let query_id: Vec<&str> = changes.iter().map(|c| c.query_id.as_str()).collect();
+        let user: Vec<&str> = changes.iter().map(|c| c.user.as_str()).collect();
+        let client_address: Vec<&str> = changes.iter().map(|c| c.client_address.as_str()).collect();
+        let name: Vec<&str> = changes.iter().map(|c| c.name.as_str()).collect();
+        let old_value: Vec<&str> = changes.iter().map(|c| c.old_value.as_str()).collect();
+        let new_value: Vec<&str> = changes.iter().map(|c| c.new_value.as_str()).collect();
+
+        let schema = self.table_info.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(event_time),
+            Series::new(tenant_id),
+            Series::new(handler_type),
+            Series::new(query_id),
+            Series::new(user),
+            Series::new(client_address),
+            Series::new(name),
+            Series::new(old_value),
+            Series::new(new_value),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The storage usage per database, summed up from the counters the tables keep in their
+/// meta, the object storage is not scanned.
+pub struct StorageUsageTable {
+    table_info: TableInfo,
+}
+
+impl StorageUsageTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", DataType::String, false),
+            DataField::new("table_count", DataType::UInt64, false),
+            // the tables without counters, e.g. of the memory engine, are not summed up
+            DataField::new("untracked_table_count", DataType::UInt64, false),
+            DataField::new("num_rows", DataType::UInt64, false),
+            DataField::new("data_size", DataType::UInt64, false),
+            DataField::new("data_compressed_size", DataType::UInt64, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'storage_usage'".to_string(),
+            name: "storage_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemStorageUsage".to_string(),
+                ..Default::default()
+            },
+        };
+
+        StorageUsageTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StorageUsageTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let catalog = ctx.get_catalog();
+        let databases = catalog.list_databases().await?;
+
+        let mut names = Vec::with_capacity(databases.len());
+        let mut table_counts = Vec::with_capacity(databases.len());
+        let mut untracked_table_counts = Vec::with_capacity(databases.len());
+        let mut num_rows = Vec::with_capacity(databases.len());
+        let mut data_sizes = Vec::with_capacity(databases.len());
+        let mut data_compressed_sizes = Vec::with_capacity(databases.len());
+        for database in databases {
+            let tables = catalog.list_tables(database.name()).await?;
+
+            let (mut untracked, mut rows, mut size, mut compressed_size) = (0u64, 0u64, 0u64, 0u64);
+            for table in &tables {
+                match table.statistics()? {
+                    Some(stats) => {
+                        rows += stats.num_rows;
+                        size += stats.data_size;
+                        compressed_size += stats.data_size_compressed;
+                    }
+                    None => untracked += 1,
+                }
+            }
+
+            names.push(database.name().to_string());
+            table_counts.push(tables.len() as u64);
+            untracked_table_counts.push(untracked);
+            num_rows.push(rows);
+            data_sizes.push(size);
+            data_compressed_sizes.push(compressed_size);
+        }
+
+        let names: Vec<&[u8]> = names.iter().map(|s| s.as_bytes()).collect();
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(names),
+            Series::new(table_counts),
+            Series::new(untracked_table_counts),
+            Series::new(num_rows),
+            Series::new(data_sizes),
+            Series::new(data_compressed_sizes),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
@@ -40,6 +40,10 @@ impl TablesTable {
             DataField::new("name", DataType::String, false),
             DataField::new("engine", DataType::String, false),
             DataField::new("created_on", DataType::String, false),
+            DataField::new("num_rows", DataType::UInt64, true),
+            DataField::new("data_size", DataType::UInt64, true),
+            DataField::new("data_compressed_size", DataType::UInt64, true),
+            DataField::new("options", DataType::String, false),
         ]);
 
         let table_info = TableInfo {
@@ -105,11 +109,39 @@ impl Table for TablesTable {
             .collect();
         let created_ons: Vec<&[u8]> = created_ons.iter().map(|s| s.as_bytes()).collect();
 
+        // NULL if the engine does not keep the counters.
+        let mut num_rows: Vec<Option<u64>> = Vec::with_capacity(database_tables.len());
+        let mut data_sizes: Vec<Option<u64>> = Vec::with_capacity(database_tables.len());
+        let mut data_compressed_sizes: Vec<Option<u64>> = Vec::with_capacity(database_tables.len());
+        for (_, table) in &database_tables {
+            let stats = table.statistics()?;
+            num_rows.push(stats.as_ref().map(|s| s.num_rows));
+            data_sizes.push(stats.as_ref().map(|s| s.data_size));
+            data_compressed_sizes.push(stats.as_ref().map(|s| s.data_size_compressed));
+        }
+
+        // the options set by the user, as they are shown by SHOW CREATE TABLE
+        let options: Vec<String> = database_tables
+            .iter()
+            .map(|(_, v)| {
+                v.user_options()
+                    .iter()
+                    .map(|(k, v)| format!("{}='{}'", k, v))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        let options: Vec<&[u8]> = options.iter().map(|s| s.as_bytes()).collect();
+
         let block = DataBlock::create_by_array(self.table_info.schema(), vec![
             Series::new(databases),
             Series::new(names),
             Series::new(engines),
             Series::new(created_ons),
+            Series::new(num_rows),
+            Series::new(data_sizes),
+            Series::new(data_compressed_sizes),
+            Series::new(options),
         ]);
 
         Ok(Box::pin(DataBlockStream::create(
@@ -0,0 +1,188 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::any::Any;
+use std::sync::Arc;
+
+use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::ConnectionFactory;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::SendableDataBlockStream;
+use sqlparser::ast::ColumnOption;
+
+use crate::api::FlightClient;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sql::DfParser;
+use crate::sql::PlanParser;
+use crate::sql::SQLCommon;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const FLIGHT_EXCHANGE_FUNC: &str = "flight_exchange";
+
+/// A table transformed by an external Arrow Flight service, e.g. to score the features by a
+/// model: `SELECT * FROM flight_exchange('host:port', 'command', 'SELECT ...', 'columns')`.
+///
+/// The result of the query is streamed to the service by DoExchange, with the command as the
+/// descriptor, and the blocks returned by the service are the rows of the columns.
+pub struct FlightExchangeTable {
+    table_info: TableInfo,
+    arg_address: String,
+    arg_command: String,
+    arg_query: String,
+    arg_columns: String,
+}
+
+impl FlightExchangeTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = match &table_args {
+            Some(args) if args.len() == 4 => args
+                .iter()
+                .map(Self::string_arg)
+                .collect::<Result<Vec<_>>>()?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "expecting the address of the flight service, the command, the query and the output columns (as four string literals), but got {:?}",
+                    table_args
+                )));
+            }
+        };
+
+        let schema = DataSchemaRefExt::create(Self::output_fields(&args[3])?);
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "FlightExchange".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(FlightExchangeTable {
+            table_info,
+            arg_address: args[0].clone(),
+            arg_command: args[1].clone(),
+            arg_query: args[2].clone(),
+            arg_columns: args[3].clone(),
+        }))
+    }
+
+    fn string_arg(expr: &Expression) -> Result<String> {
+        match expr {
+            Expression::Literal { value, .. } => String::from_utf8(value.as_string()?)
+                .map_err(|e| ErrorCode::BadArguments(format!("invalid string. {}", e))),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "expecting string literal, but got {:?}",
+                expr
+            ))),
+        }
+    }
+
+    // The columns returned by the service, e.g. `id UInt64 NOT NULL, score Float64`.
+    fn output_fields(columns: &str) -> Result<Vec<DataField>> {
+        DfParser::parse_column_defs(columns)?
+            .iter()
+            .map(|column| {
+                let nullable = !column
+                    .options
+                    .iter()
+                    .any(|opt| matches!(opt.option, ColumnOption::NotNull));
+                let data_type = SQLCommon::make_data_type(&column.data_type)?;
+                Ok(DataField::new(&column.name.value, data_type, nullable))
+            })
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FlightExchangeTable {
+    // The input query is executed by the node of the table function.
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        let string_literal = |arg: &String| {
+            Expression::create_literal(DataValue::String(Some(arg.as_bytes().to_vec())))
+        };
+        Some(vec![
+            string_literal(&self.arg_address),
+            string_literal(&self.arg_command),
+            string_literal(&self.arg_query),
+            string_literal(&self.arg_columns),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = PlanParser::parse(&self.arg_query, ctx.clone()).await?;
+        let input_schema = plan.schema();
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let input = interpreter.execute(None).await?;
+
+        // The service is out of the cluster, connected without the TLS of the cluster.
+        let channel = ConnectionFactory::create_rpc_channel(&self.arg_address, None, None)?;
+        let mut client = FlightClient::new(FlightServiceClient::new(channel));
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        client
+            .exchange_stream(
+                &self.arg_command,
+                input,
+                input_schema,
+                self.table_info.schema(),
+                timeout,
+            )
+            .await
+    }
+}
+
+impl TableFunction for FlightExchangeTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
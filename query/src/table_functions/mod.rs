@@ -13,12 +13,15 @@
 //  limitations under the License.
 //
 
+mod flight_exchange_table;
 mod memory_block_part;
 mod numbers_stream;
 mod numbers_table;
 mod table_function;
 mod table_function_factory;
 
+pub use flight_exchange_table::FlightExchangeTable;
+pub use flight_exchange_table::FLIGHT_EXCHANGE_FUNC;
 pub use memory_block_part::generate_block_parts;
 pub use numbers_table::NumbersTable;
 pub use table_function::TableFunction;
@@ -29,6 +29,7 @@ use common_exception::Result;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_planners::sort;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Partitions;
@@ -137,6 +138,11 @@ impl Table for NumbersTable {
         )))])
     }
 
+    fn read_order(&self) -> Vec<Expression> {
+        // each partition is a range of the numbers, generated in ascending order
+        vec![sort("number", true, true)]
+    }
+
     async fn read(
         &self,
         ctx: Arc<QueryContext>,
@@ -24,10 +24,19 @@ use common_planners::Expression;
 
 use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
+use crate::storages::FuseBlocksTable;
 use crate::storages::FuseHistoryTable;
+use crate::storages::FuseSegmentsTable;
+use crate::storages::FuseSnapshotDiffTable;
+use crate::storages::FUSE_FUNC_BLOCKS;
 use crate::storages::FUSE_FUNC_HIST;
+use crate::storages::FUSE_FUNC_SEGMENTS;
+use crate::storages::FUSE_FUNC_SNAPSHOTS;
+use crate::storages::FUSE_FUNC_SNAPSHOT_DIFF;
+use crate::table_functions::FlightExchangeTable;
 use crate::table_functions::NumbersTable;
 use crate::table_functions::TableFunction;
+use crate::table_functions::FLIGHT_EXCHANGE_FUNC;
 
 pub type TableArgs = Option<Vec<Expression>>;
 type TableFunctionCreators = RwLock<HashMap<String, (MetaId, Arc<dyn TableFunctionCreator>)>>;
@@ -100,6 +109,31 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseHistoryTable::create)),
         );
 
+        creators.insert(
+            FUSE_FUNC_SNAPSHOTS.to_string(),
+            (next_id(), Arc::new(FuseHistoryTable::create)),
+        );
+
+        creators.insert(
+            FUSE_FUNC_SNAPSHOT_DIFF.to_string(),
+            (next_id(), Arc::new(FuseSnapshotDiffTable::create)),
+        );
+
+        creators.insert(
+            FUSE_FUNC_SEGMENTS.to_string(),
+            (next_id(), Arc::new(FuseSegmentsTable::create)),
+        );
+
+        creators.insert(
+            FUSE_FUNC_BLOCKS.to_string(),
+            (next_id(), Arc::new(FuseBlocksTable::create)),
+        );
+
+        creators.insert(
+            FLIGHT_EXCHANGE_FUNC.to_string(),
+            (next_id(), Arc::new(FlightExchangeTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UserInfo;
+
+use crate::configs::Config;
+use crate::sessions::SessionRef;
+use crate::users::auth::JwtAuthenticator;
+use crate::users::CertifiedInfo;
+use crate::users::UserApiProvider;
+
+#[derive(Clone)]
+pub enum Credential {
+    Jwt {
+        token: String,
+    },
+    Password {
+        name: String,
+        password: Option<Vec<u8>>,
+        hostname: Option<String>,
+    },
+}
+
+pub struct AuthMgr {
+    tenant_id: String,
+    users: Arc<UserApiProvider>,
+    jwt: Option<JwtAuthenticator>,
+}
+
+impl AuthMgr {
+    pub fn create(cfg: Config, users: Arc<UserApiProvider>) -> Arc<AuthMgr> {
+        Arc::new(AuthMgr {
+            jwt: JwtAuthenticator::try_create(&cfg),
+            tenant_id: cfg.query.tenant_id,
+            users,
+        })
+    }
+
+    /// Authenticate the credential and bind the user (and the roles it carries) to the session.
+    pub async fn auth(&self, session: &SessionRef, credential: &Credential) -> Result<()> {
+        let res = self.do_auth(session, credential).await;
+        let user_name = match credential {
+            Credential::Jwt { .. } => session
+                .get_current_user()
+                .map(|user| user.name)
+                .unwrap_or_default(),
+            Credential::Password { name, .. } => name.clone(),
+        };
+        let client_address = session
+            .get_client_host()
+            .map(|addr| addr.to_string())
+            .unwrap_or_default();
+        session.audit_login(&user_name, &client_address, res.as_ref().err());
+        res
+    }
+
+    async fn do_auth(&self, session: &SessionRef, credential: &Credential) -> Result<()> {
+        match credential {
+            Credential::Jwt { token } => {
+                let jwt = self
+                    .jwt
+                    .as_ref()
+                    .ok_or_else(|| ErrorCode::AuthenticateFailure("jwt auth not configured."))?;
+                let claims = jwt.parse_jwt(token).await?;
+                if let Some(tenant_id) = &claims.custom.tenant_id {
+                    if tenant_id != &self.tenant_id {
+                        return Err(ErrorCode::AuthenticateFailure(format!(
+                            "jwt is issued for tenant {}, but current tenant is {}",
+                            tenant_id, self.tenant_id
+                        )));
+                    }
+                }
+
+                // The subject is checked in parse_jwt.
+                let user_name = claims.subject.unwrap_or_default();
+                let user_info = self.users.get_user(&user_name, "%").await?;
+                self.verify_network_policy(session, &user_info).await?;
+                // The roles claim comes before the roles granted to the user, so that its
+                // first role is the current role.
+                let mut roles = claims.custom.roles.unwrap_or_default();
+                for role in &user_info.roles {
+                    if !roles.contains(role) {
+                        roles.push(role.clone());
+                    }
+                }
+                session.set_current_user(user_info);
+                session.set_auth_roles(roles);
+            }
+            Credential::Password {
+                name,
+                password,
+                hostname,
+            } => {
+                let hostname = hostname.as_deref().unwrap_or("%");
+                let password = password.as_deref().unwrap_or_default();
+                let user_info = self.users.get_user(name, hostname).await?;
+                let info = CertifiedInfo::create(name, password, hostname);
+                let authed = self.users.auth_user(user_info.clone(), info).await?;
+                if !authed {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "wrong password for user {}",
+                        name
+                    )));
+                }
+                self.verify_network_policy(session, &user_info).await?;
+                session.set_current_user(user_info);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn verify_network_policy(
+        &self,
+        session: &SessionRef,
+        user_info: &UserInfo,
+    ) -> Result<()> {
+        let client_address = session
+            .get_client_host()
+            .map(|addr| addr.to_string())
+            .unwrap_or_default();
+        self.users
+            .verify_network_policy(user_info, &client_address)
+            .await
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::RwLock;
+use jwt_simple::prelude::RS256PublicKey;
+use serde::Deserialize;
+use serde::Serialize;
+
+// Keys are reloaded from the JWKS endpoint after this interval.
+const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(600);
+// An unknown key id forces a reload, but not more often than this.
+const JWKS_REFRESH_THROTTLE: Duration = Duration::from_secs(10);
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct JwkKey {
+    pub kid: String,
+    pub kty: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    /// (Modulus) Parameter for kty `RSA`.
+    #[serde(default)]
+    pub n: String,
+    /// (Exponent) Parameter for kty `RSA`.
+    #[serde(default)]
+    pub e: String,
+}
+
+impl JwkKey {
+    fn get_public_key(&self) -> Result<PubKey> {
+        match self.kty.as_str() {
+            "RSA" => {
+                let n = decode_base64_url(&self.n)?;
+                let e = decode_base64_url(&self.e)?;
+                let key = RS256PublicKey::from_components(&n, &e).map_err(|e| {
+                    ErrorCode::AuthenticateFailure(format!("Invalid RSA key {}: {}", self.kid, e))
+                })?;
+                Ok(PubKey::RSA256(key))
+            }
+            _ => Err(ErrorCode::AuthenticateFailure(format!(
+                "Unsupported JWK key type: {}",
+                self.kty
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct JwkKeys {
+    pub keys: Vec<JwkKey>,
+}
+
+#[derive(Clone, Debug)]
+pub enum PubKey {
+    RSA256(RS256PublicKey),
+}
+
+/// Caches the public keys of a JWKS endpoint by key id.
+pub struct JwkKeyStore {
+    url: String,
+    keys: RwLock<HashMap<String, PubKey>>,
+    last_refreshed_at: RwLock<Option<Instant>>,
+}
+
+impl JwkKeyStore {
+    pub fn new(url: String) -> Self {
+        JwkKeyStore {
+            url,
+            keys: RwLock::new(HashMap::new()),
+            last_refreshed_at: RwLock::new(None),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    async fn load_keys(&self) -> Result<()> {
+        let response = reqwest::get(&self.url).await.map_err(|e| {
+            ErrorCode::NetworkRequestError(format!("Could not download JWKS {}: {}", self.url, e))
+        })?;
+        let body = response.bytes().await.map_err(|e| {
+            ErrorCode::NetworkRequestError(format!("Could not read JWKS {}: {}", self.url, e))
+        })?;
+        let jwk_keys: JwkKeys = serde_json::from_slice(&body).map_err(|e| {
+            ErrorCode::AuthenticateFailure(format!("Invalid JWKS {}: {}", self.url, e))
+        })?;
+
+        let mut new_keys = HashMap::with_capacity(jwk_keys.keys.len());
+        for key in &jwk_keys.keys {
+            new_keys.insert(key.kid.clone(), key.get_public_key()?);
+        }
+
+        *self.keys.write() = new_keys;
+        *self.last_refreshed_at.write() = Some(Instant::now());
+        Ok(())
+    }
+
+    fn need_refresh(&self, key_id: Option<&str>) -> bool {
+        let elapsed = match *self.last_refreshed_at.read() {
+            None => return true,
+            Some(at) => at.elapsed(),
+        };
+
+        if elapsed >= JWKS_REFRESH_INTERVAL {
+            return true;
+        }
+
+        let missing = match key_id {
+            Some(kid) => !self.keys.read().contains_key(kid),
+            None => self.keys.read().is_empty(),
+        };
+        missing && elapsed >= JWKS_REFRESH_THROTTLE
+    }
+
+    /// Get the key to verify a token signed with `key_id`.
+    /// A token without key id is accepted only if the JWKS holds exactly one key.
+    pub async fn get_key(&self, key_id: Option<&str>) -> Result<PubKey> {
+        if self.need_refresh(key_id) {
+            self.load_keys().await?;
+        }
+
+        let keys = self.keys.read();
+        match key_id {
+            Some(kid) => keys.get(kid).cloned().ok_or_else(|| {
+                ErrorCode::AuthenticateFailure(format!("key id {} not found in JWKS", kid))
+            }),
+            None if keys.len() == 1 => Ok(keys.values().next().cloned().unwrap()),
+            None => Err(ErrorCode::AuthenticateFailure(
+                "must specify key id in jwt header when JWKS has more than one key",
+            )),
+        }
+    }
+}
+
+fn decode_base64_url(v: &str) -> Result<Vec<u8>> {
+    base64::decode_config(v, base64::URL_SAFE_NO_PAD)
+        .map_err(|e| ErrorCode::AuthenticateFailure(format!("Invalid base64 in JWK: {}", e)))
+}
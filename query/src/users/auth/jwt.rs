@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::RSAPublicKeyLike;
+use jwt_simple::prelude::Token;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::configs::Config;
+use crate::users::auth::JwkKeyStore;
+use crate::users::auth::PubKey;
+
+/// Databend specific claims of the jwt, the user name is taken from the `sub` claim.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CustomClaims {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
+}
+
+impl CustomClaims {
+    pub fn new() -> Self {
+        CustomClaims::default()
+    }
+
+    #[must_use]
+    pub fn with_tenant_id(mut self, tenant_id: &str) -> Self {
+        self.tenant_id = Some(tenant_id.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_roles(mut self, roles: Vec<String>) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+}
+
+pub struct JwtAuthenticator {
+    key_store: JwkKeyStore,
+}
+
+impl JwtAuthenticator {
+    pub fn try_create(cfg: &Config) -> Option<Self> {
+        if cfg.query.jwt_key_url.is_empty() {
+            return None;
+        }
+
+        Some(JwtAuthenticator {
+            key_store: JwkKeyStore::new(cfg.query.jwt_key_url.clone()),
+        })
+    }
+
+    pub async fn parse_jwt(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
+        let metadata = Token::decode_metadata(token)
+            .map_err(|e| ErrorCode::AuthenticateFailure(format!("Invalid jwt: {}", e)))?;
+
+        let claims = match self.key_store.get_key(metadata.key_id()).await? {
+            PubKey::RSA256(key) => key
+                .verify_token::<CustomClaims>(token, None)
+                .map_err(|e| ErrorCode::AuthenticateFailure(format!("Invalid jwt: {}", e)))?,
+        };
+
+        match &claims.subject {
+            Some(_) => Ok(claims),
+            None => Err(ErrorCode::AuthenticateFailure(
+                "Invalid jwt: missing field `sub`",
+            )),
+        }
+    }
+}
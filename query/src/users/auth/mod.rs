@@ -0,0 +1,26 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod auth_mgr;
+mod jwk;
+mod jwt;
+
+pub use auth_mgr::AuthMgr;
+pub use auth_mgr::Credential;
+pub use jwk::JwkKey;
+pub use jwk::JwkKeyStore;
+pub use jwk::JwkKeys;
+pub use jwk::PubKey;
+pub use jwt::CustomClaims;
+pub use jwt::JwtAuthenticator;
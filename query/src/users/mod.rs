@@ -12,12 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod auth;
+
 mod user;
 mod user_api;
+mod user_cache;
+mod user_connection;
 mod user_mgr;
+mod user_network_policy;
+mod user_ownership;
+mod user_password;
+mod user_read_only;
+mod user_recycle_bin;
+mod user_role;
+mod user_row_access_policy;
 mod user_stage;
 mod user_udf;
 
 pub use user::CertifiedInfo;
 pub use user::User;
 pub use user_api::UserApiProvider;
+pub use user_cache::RowAccessPolicyCache;
+pub use user_cache::UserInfoCache;
+pub use user_password::encode_password;
+pub use user_password::encode_pbkdf2_sha256;
+pub use user_password::is_legacy_password;
+pub use user_password::verify_caching_sha2_scramble;
+pub use user_password::verify_pbkdf2_sha256;
@@ -54,6 +54,8 @@ impl From<&User> for UserInfo {
             password_type: user.password_type.clone(),
             grants,
             quota,
+            network_policy: None,
+            roles: vec![],
         }
     }
 }
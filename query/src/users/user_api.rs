@@ -13,8 +13,23 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::Result;
+use common_management::ConnectionMgr;
+use common_management::ConnectionMgrApi;
+use common_management::NetworkPolicyMgr;
+use common_management::NetworkPolicyMgrApi;
+use common_management::OwnershipMgr;
+use common_management::OwnershipMgrApi;
+use common_management::ReadOnlyMgr;
+use common_management::ReadOnlyMgrApi;
+use common_management::RecycleBinMgr;
+use common_management::RecycleBinMgrApi;
+use common_management::RoleMgr;
+use common_management::RoleMgrApi;
+use common_management::RowAccessPolicyMgr;
+use common_management::RowAccessPolicyMgrApi;
 use common_management::StageMgr;
 use common_management::StageMgrApi;
 use common_management::UdfMgr;
@@ -25,11 +40,24 @@ use common_meta_api::KVApi;
 
 use crate::common::MetaClientProvider;
 use crate::configs::Config;
+use crate::users::RowAccessPolicyCache;
+use crate::users::UserInfoCache;
 
 pub struct UserApiProvider {
     user_api_provider: Arc<dyn UserMgrApi>,
     stage_api_provider: Arc<dyn StageMgrApi>,
     udf_api_provider: Arc<dyn UdfMgrApi>,
+    row_access_policy_api_provider: Arc<dyn RowAccessPolicyMgrApi>,
+    ownership_api_provider: Arc<dyn OwnershipMgrApi>,
+    recycle_bin_api_provider: Arc<dyn RecycleBinMgrApi>,
+    read_only_api_provider: Arc<dyn ReadOnlyMgrApi>,
+    network_policy_api_provider: Arc<dyn NetworkPolicyMgrApi>,
+    connection_api_provider: Arc<dyn ConnectionMgrApi>,
+    role_api_provider: Arc<dyn RoleMgrApi>,
+    connection_encryption_key: String,
+    user_cache: UserInfoCache,
+    row_access_policy_cache: RowAccessPolicyCache,
+    rehash_on_login: bool,
 }
 
 impl UserApiProvider {
@@ -50,7 +78,23 @@ impl UserApiProvider {
         Ok(Arc::new(UserApiProvider {
             user_api_provider: Arc::new(UserMgr::new(client.clone(), tenant_id)),
             stage_api_provider: Arc::new(StageMgr::new(client.clone(), tenant_id)),
-            udf_api_provider: Arc::new(UdfMgr::new(client, tenant_id)),
+            udf_api_provider: Arc::new(UdfMgr::new(client.clone(), tenant_id)),
+            row_access_policy_api_provider: Arc::new(RowAccessPolicyMgr::new(
+                client.clone(),
+                tenant_id,
+            )),
+            ownership_api_provider: Arc::new(OwnershipMgr::new(client.clone(), tenant_id)),
+            recycle_bin_api_provider: Arc::new(RecycleBinMgr::new(client.clone(), tenant_id)),
+            read_only_api_provider: Arc::new(ReadOnlyMgr::new(client.clone(), tenant_id)),
+            network_policy_api_provider: Arc::new(NetworkPolicyMgr::new(client.clone(), tenant_id)),
+            connection_api_provider: Arc::new(ConnectionMgr::new(client.clone(), tenant_id)),
+            role_api_provider: Arc::new(RoleMgr::new(client, tenant_id)),
+            connection_encryption_key: cfg.query.connection_encryption_key.clone(),
+            user_cache: UserInfoCache::create(Duration::from_secs(cfg.query.user_cache_ttl_secs)),
+            row_access_policy_cache: RowAccessPolicyCache::create(Duration::from_secs(
+                cfg.query.user_cache_ttl_secs,
+            )),
+            rehash_on_login: cfg.query.user_password_rehash_on_login,
         }))
     }
 
@@ -65,4 +109,48 @@ impl UserApiProvider {
     pub fn get_udf_api_client(&self) -> Arc<dyn UdfMgrApi> {
         self.udf_api_provider.clone()
     }
+
+    pub fn get_row_access_policy_api_client(&self) -> Arc<dyn RowAccessPolicyMgrApi> {
+        self.row_access_policy_api_provider.clone()
+    }
+
+    pub fn get_ownership_api_client(&self) -> Arc<dyn OwnershipMgrApi> {
+        self.ownership_api_provider.clone()
+    }
+
+    pub fn get_recycle_bin_api_client(&self) -> Arc<dyn RecycleBinMgrApi> {
+        self.recycle_bin_api_provider.clone()
+    }
+
+    pub fn get_read_only_api_client(&self) -> Arc<dyn ReadOnlyMgrApi> {
+        self.read_only_api_provider.clone()
+    }
+
+    pub fn get_network_policy_api_client(&self) -> Arc<dyn NetworkPolicyMgrApi> {
+        self.network_policy_api_provider.clone()
+    }
+
+    pub fn get_connection_api_client(&self) -> Arc<dyn ConnectionMgrApi> {
+        self.connection_api_provider.clone()
+    }
+
+    pub fn get_role_api_client(&self) -> Arc<dyn RoleMgrApi> {
+        self.role_api_provider.clone()
+    }
+
+    pub(crate) fn get_connection_encryption_key(&self) -> &str {
+        &self.connection_encryption_key
+    }
+
+    pub fn get_user_cache(&self) -> &UserInfoCache {
+        &self.user_cache
+    }
+
+    pub fn get_row_access_policy_cache(&self) -> &RowAccessPolicyCache {
+        &self.row_access_policy_cache
+    }
+
+    pub(crate) fn get_rehash_on_login(&self) -> bool {
+        self.rehash_on_login
+    }
 }
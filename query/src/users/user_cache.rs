@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_infallible::RwLock;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::UserInfo;
+
+/// Caches the user infos fetched from meta by (user, host), so that the privileges of a user
+/// can be verified without a round trip to the meta service on each query. A node serves a
+/// single tenant, so the tenant is not a part of the key.
+///
+/// The entries of a user are invalidated when its grants or itself are changed on this node.
+/// The meta service has no change notification to subscribe to, so a change made on another
+/// node is seen here after the ttl at the latest: the ttl is the staleness window of a grant,
+/// a revoke or a drop of a user across the cluster.
+pub struct UserInfoCache {
+    ttl: Duration,
+    // Bumped on each invalidation, a user info fetched before it is not cached.
+    generation: AtomicU64,
+    users: RwLock<HashMap<(String, String), (Instant, UserInfo)>>,
+}
+
+impl UserInfoCache {
+    pub fn create(ttl: Duration) -> Self {
+        UserInfoCache {
+            ttl,
+            generation: AtomicU64::new(0),
+            users: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, username: &str, hostname: &str) -> Option<UserInfo> {
+        let key = self.key(username, hostname);
+        match self.users.read().get(&key) {
+            Some((cached_at, user)) if cached_at.elapsed() < self.ttl => Some(user.clone()),
+            _ => None,
+        }
+    }
+
+    // Get the cached user info even if it's expired, only for the hints which may be stale.
+    pub fn peek(&self, username: &str, hostname: &str) -> Option<UserInfo> {
+        let key = self.key(username, hostname);
+        self.users.read().get(&key).map(|(_, user)| user.clone())
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    // Cache the user info fetched at `generation`, unless the cache has been invalidated since.
+    pub fn insert(&self, username: &str, hostname: &str, user: UserInfo, generation: u64) {
+        if self.ttl.is_zero() {
+            return;
+        }
+
+        let key = self.key(username, hostname);
+        let mut users = self.users.write();
+        if self.generation() == generation {
+            users.insert(key, (Instant::now(), user));
+        }
+    }
+
+    // Invalidate the entries of the user on all the hosts, a grant to 'u'@'%' is
+    // also seen by a lookup of 'u'@'localhost'.
+    pub fn invalidate(&self, username: &str) {
+        let mut users = self.users.write();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        users.retain(|(cached_user, _), _| cached_user != username);
+    }
+
+    pub fn clear(&self) {
+        let mut users = self.users.write();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        users.clear();
+    }
+
+    fn key(&self, username: &str, hostname: &str) -> (String, String) {
+        (username.to_string(), hostname.to_string())
+    }
+}
+
+/// Caches the row access policies of the tenant fetched from meta, which every query
+/// scanning a table checks for the policies protecting it.
+///
+/// Invalidated when a policy is created or dropped on this node, the changes made on the
+/// other nodes are seen after the ttl at the latest, as for the user infos.
+pub struct RowAccessPolicyCache {
+    ttl: Duration,
+    // Bumped on each invalidation, the policies fetched before it are not cached.
+    generation: AtomicU64,
+    policies: RwLock<Option<(Instant, Vec<RowAccessPolicy>)>>,
+}
+
+impl RowAccessPolicyCache {
+    pub fn create(ttl: Duration) -> Self {
+        RowAccessPolicyCache {
+            ttl,
+            generation: AtomicU64::new(0),
+            policies: RwLock::new(None),
+        }
+    }
+
+    pub fn get(&self) -> Option<Vec<RowAccessPolicy>> {
+        match &*self.policies.read() {
+            Some((cached_at, policies)) if cached_at.elapsed() < self.ttl => Some(policies.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    // Cache the policies fetched at `generation`, unless the cache has been invalidated since.
+    pub fn insert(&self, policies: Vec<RowAccessPolicy>, generation: u64) {
+        if self.ttl.is_zero() {
+            return;
+        }
+
+        let mut cached = self.policies.write();
+        if self.generation() == generation {
+            *cached = Some((Instant::now(), policies));
+        }
+    }
+
+    pub fn invalidate(&self) {
+        let mut cached = self.policies.write();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        *cached = None;
+    }
+}
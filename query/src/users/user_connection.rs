@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserInfo;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+use sha2::Digest;
+
+use crate::users::UserApiProvider;
+
+/// Connection operations.
+impl UserApiProvider {
+    // Add a new connection, the values of its credentials are encrypted before they are stored.
+    pub async fn add_connection(&self, mut connection: ConnectionInfo) -> Result<u64> {
+        let key = self.connection_key()?;
+        for value in connection.credentials.values_mut() {
+            *value = encrypt_value(&key, value)?;
+        }
+
+        let connection_api_provider = self.get_connection_api_client();
+        let add_connection = connection_api_provider.add_connection(connection);
+        match add_connection.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while add connection).")),
+        }
+    }
+
+    // Get a connection by name, with the credentials decrypted.
+    pub async fn get_connection(&self, name: &str) -> Result<ConnectionInfo> {
+        let mut connection = self.get_encrypted_connection(name).await?;
+        let key = self.connection_key()?;
+        for value in connection.credentials.values_mut() {
+            *value = decrypt_value(&key, value)?;
+        }
+        Ok(connection)
+    }
+
+    // Get a connection by name, as it is stored in meta.
+    pub async fn get_encrypted_connection(&self, name: &str) -> Result<ConnectionInfo> {
+        let connection_api_provider = self.get_connection_api_client();
+        let get_connection = connection_api_provider.get_connection(name, None);
+        match get_connection.await {
+            Ok(res) => Ok(res.data),
+            Err(failure) => Err(failure.add_message_back("(while get connection).")),
+        }
+    }
+
+    // Drop a connection by name.
+    pub async fn drop_connection(&self, name: &str, if_exists: bool) -> Result<()> {
+        let connection_api_provider = self.get_connection_api_client();
+        let drop_connection = connection_api_provider.drop_connection(name, None);
+        match drop_connection.await {
+            Ok(res) => Ok(res),
+            Err(failure) => {
+                if if_exists && failure.code() == ErrorCode::UnknownConnectionCode() {
+                    Ok(())
+                } else {
+                    Err(failure.add_message_back("(while drop connection)"))
+                }
+            }
+        }
+    }
+
+    // Check that the user is allowed to use the connection: it must own the connection,
+    // have the USAGE privilege on it, or have the SUPER privilege on *.*.
+    pub async fn verify_connection_usage(&self, name: &str, user: Option<&UserInfo>) -> Result<()> {
+        self.get_encrypted_connection(name).await?;
+        let object = OwnershipObject::Connection(name.to_string());
+        self.verify_usage(&object, user).await
+    }
+
+    fn connection_key(&self) -> Result<LessSafeKey> {
+        let key = self.get_connection_encryption_key();
+        if key.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "connection_encryption_key is not set, the credentials of connections can not be encrypted",
+            ));
+        }
+
+        // The configured key may be of any length, the cipher key is derived from it.
+        let digest = sha2::Sha256::digest(key.as_bytes());
+        let unbound = UnboundKey::new(&AES_256_GCM, digest.as_slice()).map_err(|_| {
+            ErrorCode::ConnectionCredentialsError("Cannot create the encryption key")
+        })?;
+        Ok(LessSafeKey::new(unbound))
+    }
+}
+
+// The sealed value is base64(nonce + ciphertext + tag), each value has a random nonce.
+fn encrypt_value(key: &LessSafeKey, value: &str) -> Result<String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| ErrorCode::ConnectionCredentialsError("Cannot generate the nonce"))?;
+
+    let mut in_out = value.as_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::empty(),
+        &mut in_out,
+    )
+    .map_err(|_| ErrorCode::ConnectionCredentialsError("Cannot encrypt the credentials"))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&in_out);
+    Ok(base64::encode(sealed))
+}
+
+fn decrypt_value(key: &LessSafeKey, value: &str) -> Result<String> {
+    let illegal = || {
+        ErrorCode::ConnectionCredentialsError(
+            "Cannot decrypt the credentials, the connection_encryption_key may have been changed",
+        )
+    };
+
+    let sealed = base64::decode(value).map_err(|_| illegal())?;
+    if sealed.len() < NONCE_LEN {
+        return Err(illegal());
+    }
+    let (nonce, cipher) = sealed.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| illegal())?;
+
+    let mut in_out = cipher.to_vec();
+    let plain = key
+        .open_in_place(nonce, Aad::empty(), &mut in_out)
+        .map_err(|_| illegal())?;
+    String::from_utf8(plain.to_vec()).map_err(|_| illegal())
+}
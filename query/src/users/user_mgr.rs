@@ -12,14 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::SocketAddr;
+use std::str::FromStr;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_meta_types::PasswordType;
 use common_meta_types::UserInfo;
 use common_meta_types::UserPrivilegeSet;
+use common_tracing::tracing;
 use sha2::Digest;
 
+use crate::users::encode_password;
+use crate::users::is_legacy_password;
+use crate::users::verify_pbkdf2_sha256;
 use crate::users::CertifiedInfo;
 use crate::users::User;
 use crate::users::UserApiProvider;
@@ -43,18 +50,61 @@ impl UserApiProvider {
                 Ok(user_info)
             }
             _ => {
+                let user_cache = self.get_user_cache();
+                if let Some(user_info) = user_cache.get(username, hostname) {
+                    return Ok(user_info);
+                }
+                let generation = user_cache.generation();
+
                 let client = self.get_user_api_client();
                 let get_user = client.get_user(username.to_string(), hostname.to_string(), None);
-                Ok(get_user.await?.data)
+                let user_info = get_user.await?.data;
+                user_cache.insert(username, hostname, user_info.clone(), generation);
+                Ok(user_info)
+            }
+        }
+    }
+
+    // Get the user connecting from the client address, `ip` or `ip:port`: the user defined for
+    // the host of the client if any, else the one defined for any host.
+    pub async fn get_user_with_client_address(
+        &self,
+        username: &str,
+        client_address: &str,
+    ) -> Result<UserInfo> {
+        let client_host = SocketAddr::from_str(client_address)
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_else(|_| client_address.to_string());
+        match self.get_user(username, &client_host).await {
+            Err(cause) if cause.code() == ErrorCode::UnknownUserCode() => {
+                self.get_user(username, "%").await
             }
+            res => res,
         }
     }
 
+    // Get the password type of the user connecting from the client address from the cached
+    // user infos, without a round trip to the meta service. None if the user is not cached.
+    pub fn get_cached_password_type(
+        &self,
+        username: &str,
+        client_address: &str,
+    ) -> Option<PasswordType> {
+        let client_host = SocketAddr::from_str(client_address)
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_else(|_| client_address.to_string());
+        let user_cache = self.get_user_cache();
+        user_cache
+            .peek(username, &client_host)
+            .or_else(|| user_cache.peek(username, "%"))
+            .map(|user_info| user_info.password_type)
+    }
+
     // Auth the user and password for different Auth type.
     pub async fn auth_user(&self, user: UserInfo, info: CertifiedInfo) -> Result<bool> {
-        match user.password_type {
-            PasswordType::None => Ok(true),
-            PasswordType::PlainText => Ok(user.password == info.user_password),
+        let authed = match user.password_type {
+            PasswordType::None => true,
+            PasswordType::PlainText => user.password == info.user_password,
             // MySQL already did x = sha1(x)
             // so we just check double sha1(x)
             PasswordType::DoubleSha1 => {
@@ -65,12 +115,41 @@ impl UserApiProvider {
                 let mut m = sha1::Sha1::new();
                 m.update(&bs[..]);
 
-                Ok(user.password == m.digest().bytes().to_vec())
+                user.password == m.digest().bytes().to_vec()
             }
             PasswordType::Sha256 => {
                 let result = sha2::Sha256::digest(&info.user_password);
-                Ok(user.password == result.to_vec())
+                user.password == result.to_vec()
             }
+            PasswordType::Pbkdf2Sha256 => verify_pbkdf2_sha256(&user.password, &info.user_password),
+        };
+
+        // The legacy passwords are only verified against the plain passwords,
+        // so the password is the plain one here if authed.
+        if authed && self.get_rehash_on_login() && is_legacy_password(&user.password_type) {
+            self.rehash_password(&user, &info.user_password).await;
+        }
+        Ok(authed)
+    }
+
+    // Migrate the password of the user into a salted one, keeping the old one if failed.
+    async fn rehash_password(&self, user: &UserInfo, password: &[u8]) {
+        let password_type = PasswordType::Pbkdf2Sha256;
+        let password = encode_password(&password_type, password);
+        if let Err(cause) = self
+            .update_user(
+                &user.name,
+                &user.hostname,
+                Some(password_type),
+                Some(password),
+            )
+            .await
+        {
+            tracing::warn!(
+                "Failed to rehash the password of user {}: {}",
+                user.name,
+                cause
+            );
         }
     }
 
@@ -120,6 +199,10 @@ impl UserApiProvider {
             )
             .await
             .map_err(|failure| failure.add_message_back("(while set user privileges)"))
+            .map(|res| {
+                self.get_user_cache().invalidate(username);
+                res
+            })
     }
 
     pub async fn revoke_user_privileges(
@@ -140,6 +223,26 @@ impl UserApiProvider {
             )
             .await
             .map_err(|failure| failure.add_message_back("(while revoke user privileges)"))
+            .map(|res| {
+                self.get_user_cache().invalidate(username);
+                res
+            })
+    }
+
+    pub async fn revoke_all_user_privileges(
+        &self,
+        username: &str,
+        hostname: &str,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client();
+        client
+            .revoke_all_user_privileges(username.to_string(), hostname.to_string(), None)
+            .await
+            .map_err(|failure| failure.add_message_back("(while revoke all user privileges)"))
+            .map(|res| {
+                self.get_user_cache().invalidate(username);
+                res
+            })
     }
 
     // Drop a user by name and hostname.
@@ -147,7 +250,10 @@ impl UserApiProvider {
         let client = self.get_user_api_client();
         let drop_user = client.drop_user(username.to_string(), hostname.to_string(), None);
         match drop_user.await {
-            Ok(res) => Ok(res),
+            Ok(res) => {
+                self.get_user_cache().invalidate(username);
+                Ok(res)
+            }
             Err(failure) => {
                 if if_exist && failure.code() == ErrorCode::UnknownUserCode() {
                     Ok(())
@@ -175,7 +281,10 @@ impl UserApiProvider {
             None,
         );
         match update_user.await {
-            Ok(res) => Ok(res),
+            Ok(res) => {
+                self.get_user_cache().invalidate(username);
+                Ok(res)
+            }
             Err(failure) => Err(failure.add_message_back("(while alter user).")),
         }
     }
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::UserInfo;
+
+use crate::users::UserApiProvider;
+
+/// Network policy operations.
+impl UserApiProvider {
+    // Add a new network policy.
+    pub async fn add_network_policy(&self, policy: NetworkPolicy) -> Result<u64> {
+        let policy_api_provider = self.get_network_policy_api_client();
+        let add_policy = policy_api_provider.add_policy(policy);
+        match add_policy.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while add network policy).")),
+        }
+    }
+
+    // Get a network policy by name.
+    pub async fn get_network_policy(&self, name: &str) -> Result<NetworkPolicy> {
+        let policy_api_provider = self.get_network_policy_api_client();
+        let get_policy = policy_api_provider.get_policy(name, None);
+        match get_policy.await {
+            Ok(res) => Ok(res.data),
+            Err(failure) => Err(failure.add_message_back("(while get network policy).")),
+        }
+    }
+
+    // Drop a network policy by name, it must not be attached to any user.
+    pub async fn drop_network_policy(&self, name: &str, if_exists: bool) -> Result<()> {
+        let users = self.get_users().await?;
+        if let Some(user) = users
+            .iter()
+            .find(|u| u.network_policy.as_deref() == Some(name))
+        {
+            return Err(ErrorCode::NetworkPolicyIsUsedByUser(format!(
+                "Network policy {} is used by user '{}'@'{}'",
+                name, user.name, user.hostname
+            )));
+        }
+
+        let policy_api_provider = self.get_network_policy_api_client();
+        let drop_policy = policy_api_provider.drop_policy(name, None);
+        match drop_policy.await {
+            Ok(res) => Ok(res),
+            Err(failure) => {
+                if if_exists && failure.code() == ErrorCode::UnknownNetworkPolicyCode() {
+                    Ok(())
+                } else {
+                    Err(failure.add_message_back("(while drop network policy)"))
+                }
+            }
+        }
+    }
+
+    // Attach a network policy to the user, or detach it with None.
+    pub async fn set_user_network_policy(
+        &self,
+        username: &str,
+        hostname: &str,
+        network_policy: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(name) = &network_policy {
+            self.get_network_policy(name).await?;
+        }
+
+        let client = self.get_user_api_client();
+        let update_user = client.update_user_network_policy(
+            username.to_string(),
+            hostname.to_string(),
+            network_policy,
+            None,
+        );
+        match update_user.await {
+            Ok(res) => {
+                self.get_user_cache().invalidate(username);
+                Ok(res)
+            }
+            Err(failure) => Err(failure.add_message_back("(while set user network policy).")),
+        }
+    }
+
+    // Check the client address against the network policy of the user, if it has one.
+    // The address is either an IP address or a socket address.
+    pub async fn verify_network_policy(&self, user: &UserInfo, client_address: &str) -> Result<()> {
+        let name = match &user.network_policy {
+            None => return Ok(()),
+            Some(name) => name,
+        };
+
+        let policy = self.get_network_policy(name).await?;
+        let addr = SocketAddr::from_str(client_address)
+            .map(|addr| addr.ip())
+            .or_else(|_| IpAddr::from_str(client_address));
+        match addr {
+            Ok(addr) if policy.is_allowed(&addr) => Ok(()),
+            _ => Err(ErrorCode::AuthenticateFailure(format!(
+                "Client address '{}' is not allowed by network policy {} of user '{}'@'{}'",
+                client_address, name, user.name, user.hostname
+            ))),
+        }
+    }
+}
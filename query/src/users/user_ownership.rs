@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserIdentity;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+
+use crate::users::UserApiProvider;
+
+/// Object ownership operations.
+impl UserApiProvider {
+    // Get the owner of the object, None if it has no recorded owner.
+    pub async fn get_object_owner(&self, object: &OwnershipObject) -> Result<Option<UserIdentity>> {
+        let ownership_api_provider = self.get_ownership_api_client();
+        let get_owner = ownership_api_provider.get_owner(object);
+        match get_owner.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while get object owner).")),
+        }
+    }
+
+    // Make the user the owner of the object, and grant it all the privileges on the object.
+    // With `if_absent`, an object which already has an owner is left as it is.
+    pub async fn grant_ownership(
+        &self,
+        object: &OwnershipObject,
+        owner: &UserIdentity,
+        if_absent: bool,
+    ) -> Result<()> {
+        if if_absent && self.get_object_owner(object).await?.is_some() {
+            return Ok(());
+        }
+
+        let seq = if if_absent { Some(0) } else { None };
+        let ownership_api_provider = self.get_ownership_api_client();
+        let set_owner = ownership_api_provider.set_owner(object, owner, seq);
+        if let Err(failure) = set_owner.await {
+            return Err(failure.add_message_back("(while set object owner)."));
+        }
+
+        let (grant_object, privileges) = match object {
+            OwnershipObject::Database(db) => (
+                GrantObject::Database(db.clone()),
+                UserPrivilegeSet::available_privileges_on_database(),
+            ),
+            OwnershipObject::Table(db, table) => (
+                GrantObject::Table(db.clone(), table.clone()),
+                UserPrivilegeSet::available_privileges_on_table(),
+            ),
+            OwnershipObject::Connection(name) => (
+                GrantObject::Connection(name.clone()),
+                UserPrivilegeSet::available_privileges_on_connection(),
+            ),
+            OwnershipObject::Stage(name) => (
+                GrantObject::Stage(name.clone()),
+                UserPrivilegeSet::available_privileges_on_stage(),
+            ),
+            OwnershipObject::UDF(name) => (
+                GrantObject::UDF(name.clone()),
+                UserPrivilegeSet::available_privileges_on_udf(),
+            ),
+        };
+
+        let grant_privileges =
+            self.grant_user_privileges(&owner.username, &owner.hostname, grant_object, privileges);
+        match grant_privileges.await {
+            Ok(_) => Ok(()),
+            // The builtin users are not stored in meta, they can't hold grants.
+            Err(failure) if failure.code() == ErrorCode::UnknownUserCode() => Ok(()),
+            Err(failure) => Err(failure),
+        }
+    }
+
+    // Drop the ownership of the object, the tables of a database are dropped together.
+    pub async fn drop_object_owner(&self, object: &OwnershipObject) -> Result<()> {
+        let ownership_api_provider = self.get_ownership_api_client();
+        let drop_owner = ownership_api_provider.drop_owner(object);
+        match drop_owner.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while drop object owner).")),
+        }
+    }
+
+    // Check that the user is allowed to act on the object as its owner: it must own the
+    // object or hold the global privilege. Objects without a recorded owner are not checked.
+    pub async fn verify_ownership(
+        &self,
+        object: &OwnershipObject,
+        user: Option<&UserInfo>,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let owner = match self.get_object_owner(object).await? {
+            None => return Ok(()),
+            Some(owner) => owner,
+        };
+
+        let user = user.ok_or_else(|| ErrorCode::AuthenticateFailure("unauthenticated"))?;
+        if owner.username == user.name && owner.hostname == user.hostname {
+            return Ok(());
+        }
+
+        if user
+            .grants
+            .verify_global_privilege(&user.name, &user.hostname, privilege)
+        {
+            return Ok(());
+        }
+
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, {} is owned by '{}'@'{}', '{}'@'{}' needs to own it or have {} privilege on *.*",
+            object, owner.username, owner.hostname, user.name, user.hostname, privilege
+        )))
+    }
+
+    // Check that the user may create tables in the database: it must own the database or have
+    // the CREATE privilege on it.
+    pub async fn verify_database_create(&self, db: &str, user: &UserInfo) -> Result<()> {
+        let object = OwnershipObject::Database(db.to_string());
+        if let Some(owner) = self.get_object_owner(&object).await? {
+            if owner.username == user.name && owner.hostname == user.hostname {
+                return Ok(());
+            }
+        }
+
+        let create = UserPrivilegeType::Create;
+        if user
+            .grants
+            .verify_database_privilege(&user.name, &user.hostname, db, create)
+        {
+            return Ok(());
+        }
+
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, '{}'@'{}' needs to own {} or have {} privilege on it",
+            user.name, user.hostname, object, create
+        )))
+    }
+
+    // Check that the user may use the connection, stage or UDF: it must own the object, have
+    // the USAGE privilege on it, or have the SUPER privilege.
+    pub async fn verify_usage(
+        &self,
+        object: &OwnershipObject,
+        user: Option<&UserInfo>,
+    ) -> Result<()> {
+        let user = user.ok_or_else(|| ErrorCode::AuthenticateFailure("unauthenticated"))?;
+        if let Some(owner) = self.get_object_owner(object).await? {
+            if owner.username == user.name && owner.hostname == user.hostname {
+                return Ok(());
+            }
+        }
+
+        let (grants, name, host) = (&user.grants, &user.name, &user.hostname);
+        let usage = UserPrivilegeType::Usage;
+        let granted = match object {
+            OwnershipObject::Connection(n) => {
+                grants.verify_connection_privilege(name, host, n, usage)
+            }
+            OwnershipObject::Stage(n) => grants.verify_stage_privilege(name, host, n, usage),
+            OwnershipObject::UDF(n) => grants.verify_udf_privilege(name, host, n, usage),
+            OwnershipObject::Database(_) | OwnershipObject::Table(_, _) => false,
+        };
+        if granted || grants.verify_global_privilege(name, host, UserPrivilegeType::Super) {
+            return Ok(());
+        }
+
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, '{}'@'{}' needs the USAGE privilege on {}",
+            user.name, user.hostname, object
+        )))
+    }
+}
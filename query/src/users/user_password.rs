@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_types::PasswordType;
+use sha2::Digest;
+use sha2::Sha256;
+
+const PBKDF2_SHA256_ALGORITHM: &str = "pbkdf2_sha256";
+const PBKDF2_SHA256_ITERATIONS: u32 = 10000;
+const PBKDF2_SHA256_SALT_LEN: usize = 16;
+
+const SHA256_BLOCK_LEN: usize = 64;
+const SHA256_OUTPUT_LEN: usize = 32;
+
+/// Encodes the password given by `IDENTIFIED BY` into the one kept in the user info, the digest
+/// the logins of its type are verified against. The plain text passwords are kept as given.
+pub fn encode_password(password_type: &PasswordType, password: &[u8]) -> Vec<u8> {
+    match password_type {
+        PasswordType::Pbkdf2Sha256 => {
+            let salt: [u8; PBKDF2_SHA256_SALT_LEN] = rand::random();
+            encode_pbkdf2_sha256(password, &salt, PBKDF2_SHA256_ITERATIONS)
+        }
+        PasswordType::Sha256 => Sha256::digest(password).to_vec(),
+        PasswordType::DoubleSha1 => {
+            let mut m = sha1::Sha1::new();
+            m.update(password);
+            let stage1 = m.digest().bytes();
+            let mut m = sha1::Sha1::new();
+            m.update(&stage1[..]);
+            m.digest().bytes().to_vec()
+        }
+        PasswordType::None | PasswordType::PlainText => password.to_vec(),
+    }
+}
+
+/// The password types which are rehashed into pbkdf2_sha256 on login if
+/// `user_password_rehash_on_login` is on. Not sha256, which the MySQL clients need for the
+/// caching_sha2_password scramble: the salted hashes cannot be verified over MySQL.
+pub fn is_legacy_password(password_type: &PasswordType) -> bool {
+    matches!(password_type, PasswordType::PlainText)
+}
+
+/// The encoded password is `pbkdf2_sha256$<iterations>$<base64 salt>$<base64 hash>`.
+pub fn encode_pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32) -> Vec<u8> {
+    let hash = pbkdf2_sha256(password, salt, iterations);
+    format!(
+        "{}${}${}${}",
+        PBKDF2_SHA256_ALGORITHM,
+        iterations,
+        base64::encode(salt),
+        base64::encode(hash)
+    )
+    .into_bytes()
+}
+
+pub fn verify_pbkdf2_sha256(encoded: &[u8], password: &[u8]) -> bool {
+    let encoded = match std::str::from_utf8(encoded) {
+        Ok(encoded) => encoded,
+        Err(_) => return false,
+    };
+
+    let parts = encoded.split('$').collect::<Vec<_>>();
+    match parts.as_slice() {
+        [PBKDF2_SHA256_ALGORITHM, iterations, salt, hash] => {
+            match (
+                iterations.parse::<u32>(),
+                base64::decode(salt),
+                base64::decode(hash),
+            ) {
+                (Ok(iterations), Ok(salt), Ok(hash)) => {
+                    constant_time_eq(&pbkdf2_sha256(password, &salt, iterations), &hash)
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Verifies the scramble sent by the caching_sha2_password plugin of MySQL clients:
+/// XOR(SHA256(password), SHA256(SHA256(SHA256(password)), salt)),
+/// against the SHA256(password) kept by the sha256_password users.
+pub fn verify_caching_sha2_scramble(sha256_password: &[u8], salt: &[u8], scramble: &[u8]) -> bool {
+    if scramble.len() != SHA256_OUTPUT_LEN {
+        return false;
+    }
+
+    let stage2 = Sha256::digest(sha256_password);
+    let mut m = Sha256::new();
+    m.update(&stage2);
+    m.update(salt);
+    let mask = m.finalize();
+
+    let stage1 = scramble
+        .iter()
+        .zip(mask.iter())
+        .map(|(a, b)| a ^ b)
+        .collect::<Vec<_>>();
+    constant_time_eq(&stage1, sha256_password)
+}
+
+// PBKDF2-HMAC-SHA256 (RFC 8018) with a derived key of the length of a single block.
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32) -> [u8; SHA256_OUTPUT_LEN] {
+    let mut first = Vec::with_capacity(salt.len() + 4);
+    first.extend_from_slice(salt);
+    first.extend_from_slice(&1u32.to_be_bytes());
+
+    let mut u = hmac_sha256(password, &first);
+    let mut result = u;
+    for _ in 1..iterations {
+        u = hmac_sha256(password, &u);
+        for (r, x) in result.iter_mut().zip(u.iter()) {
+            *r ^= x;
+        }
+    }
+    result
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; SHA256_OUTPUT_LEN] {
+    let mut block = [0u8; SHA256_BLOCK_LEN];
+    if key.len() > SHA256_BLOCK_LEN {
+        block[..SHA256_OUTPUT_LEN].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.iter().map(|b| b ^ 0x36).collect::<Vec<_>>());
+    inner.update(message);
+    let inner = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block.iter().map(|b| b ^ 0x5c).collect::<Vec<_>>());
+    outer.update(&inner);
+
+    let mut result = [0u8; SHA256_OUTPUT_LEN];
+    result.copy_from_slice(&outer.finalize());
+    result
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
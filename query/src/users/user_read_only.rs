@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ReadOnlyObject;
+
+use crate::users::UserApiProvider;
+
+/// Read-only and maintenance mode operations.
+impl UserApiProvider {
+    // Switch the object to read-only, or back to writable.
+    pub async fn set_read_only(&self, object: &ReadOnlyObject, read_only: bool) -> Result<()> {
+        let read_only_api_provider = self.get_read_only_api_client();
+        let set_read_only = read_only_api_provider.set_read_only(object, read_only);
+        match set_read_only.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while set read only).")),
+        }
+    }
+
+    pub async fn is_read_only(&self, object: &ReadOnlyObject) -> Result<bool> {
+        let read_only_api_provider = self.get_read_only_api_client();
+        let is_read_only = read_only_api_provider.is_read_only(object);
+        match is_read_only.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while get read only).")),
+        }
+    }
+
+    // Drop the read-only entry of the object, the tables of a database are dropped together.
+    pub async fn drop_read_only(&self, object: &ReadOnlyObject) -> Result<()> {
+        let read_only_api_provider = self.get_read_only_api_client();
+        let drop_read_only = read_only_api_provider.drop_read_only(object);
+        match drop_read_only.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while drop read only).")),
+        }
+    }
+
+    pub async fn mget_read_only(&self, objects: &[ReadOnlyObject]) -> Result<Vec<bool>> {
+        let read_only_api_provider = self.get_read_only_api_client();
+        let mget_read_only = read_only_api_provider.mget_read_only(objects);
+        match mget_read_only.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while get read only).")),
+        }
+    }
+
+    // Check that the table accepts writes: neither the cluster is in maintenance mode,
+    // nor the database or the table is read-only.
+    pub async fn verify_writable(&self, db: &str, table: &str) -> Result<()> {
+        self.verify_objects_writable(&[
+            ReadOnlyObject::Cluster,
+            ReadOnlyObject::Database(db.to_string()),
+            ReadOnlyObject::Table(db.to_string(), table.to_string()),
+        ])
+        .await
+    }
+
+    // Check that the database accepts writes, as creating or dropping it or a table in it:
+    // neither the cluster is in maintenance mode, nor the database is read-only.
+    pub async fn verify_database_writable(&self, db: &str) -> Result<()> {
+        self.verify_objects_writable(&[
+            ReadOnlyObject::Cluster,
+            ReadOnlyObject::Database(db.to_string()),
+        ])
+        .await
+    }
+
+    // The flags of the objects are fetched in one meta read.
+    async fn verify_objects_writable(&self, objects: &[ReadOnlyObject]) -> Result<()> {
+        let read_only = self.mget_read_only(objects).await?;
+        match objects
+            .iter()
+            .zip(read_only)
+            .find(|(_, read_only)| *read_only)
+        {
+            None => Ok(()),
+            Some((ReadOnlyObject::Cluster, _)) => Err(ErrorCode::MaintenanceMode(
+                "The cluster is in maintenance mode, writes are rejected",
+            )),
+            Some((object @ ReadOnlyObject::Database(_), _)) => Err(ErrorCode::DatabaseReadOnly(
+                format!("{} is read-only, writes are rejected", object),
+            )),
+            Some((object @ ReadOnlyObject::Table(_, _), _)) => Err(ErrorCode::TableReadOnly(
+                format!("{} is read-only, writes are rejected", object),
+            )),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_exception::Result;
+use common_meta_types::DroppedDatabase;
+use common_meta_types::DroppedTable;
+
+use crate::users::UserApiProvider;
+
+/// Recycle bin operations.
+impl UserApiProvider {
+    // Put a dropped table into the recycle bin.
+    pub async fn add_dropped_table(&self, table: DroppedTable) -> Result<u64> {
+        let recycle_bin_api_provider = self.get_recycle_bin_api_client();
+        let add_table = recycle_bin_api_provider.add_table(table);
+        match add_table.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while add dropped table).")),
+        }
+    }
+
+    // Get the dropped tables, the earliest dropped first.
+    pub async fn get_dropped_tables(&self) -> Result<Vec<DroppedTable>> {
+        let recycle_bin_api_provider = self.get_recycle_bin_api_client();
+        let get_tables = recycle_bin_api_provider.get_tables();
+        match get_tables.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while get dropped tables).")),
+        }
+    }
+
+    // Remove a dropped table from the recycle bin.
+    pub async fn remove_dropped_table(&self, table: &DroppedTable) -> Result<()> {
+        let recycle_bin_api_provider = self.get_recycle_bin_api_client();
+        let remove_table = recycle_bin_api_provider.remove_table(table);
+        match remove_table.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while remove dropped table).")),
+        }
+    }
+
+    // Put a dropped database into the recycle bin.
+    pub async fn add_dropped_database(&self, database: DroppedDatabase) -> Result<u64> {
+        let recycle_bin_api_provider = self.get_recycle_bin_api_client();
+        let add_database = recycle_bin_api_provider.add_database(database);
+        match add_database.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while add dropped database).")),
+        }
+    }
+
+    // Get the dropped databases, the earliest dropped first.
+    pub async fn get_dropped_databases(&self) -> Result<Vec<DroppedDatabase>> {
+        let recycle_bin_api_provider = self.get_recycle_bin_api_client();
+        let get_databases = recycle_bin_api_provider.get_databases();
+        match get_databases.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while get dropped databases).")),
+        }
+    }
+
+    // Remove a dropped database from the recycle bin.
+    pub async fn remove_dropped_database(&self, database: &DroppedDatabase) -> Result<()> {
+        let recycle_bin_api_provider = self.get_recycle_bin_api_client();
+        let remove_database = recycle_bin_api_provider.remove_database(database);
+        match remove_database.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while remove dropped database).")),
+        }
+    }
+}
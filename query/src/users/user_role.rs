@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+
+use crate::users::UserApiProvider;
+
+/// Role operations.
+impl UserApiProvider {
+    // Add a new role.
+    pub async fn add_role(&self, role_info: RoleInfo) -> Result<u64> {
+        let role_api_provider = self.get_role_api_client();
+        let add_role = role_api_provider.add_role(role_info);
+        match add_role.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while add role).")),
+        }
+    }
+
+    // Get a role by name.
+    pub async fn get_role(&self, name: &str) -> Result<RoleInfo> {
+        let role_api_provider = self.get_role_api_client();
+        let get_role = role_api_provider.get_role(name, None);
+        match get_role.await {
+            Ok(res) => Ok(res.data),
+            Err(failure) => Err(failure.add_message_back("(while get role).")),
+        }
+    }
+
+    // Get all the roles.
+    pub async fn get_roles(&self) -> Result<Vec<RoleInfo>> {
+        let role_api_provider = self.get_role_api_client();
+        let get_roles = role_api_provider.get_roles();
+        match get_roles.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while get roles).")),
+        }
+    }
+
+    // Drop a role by name, and revoke it from the users it was granted to.
+    pub async fn drop_role(&self, name: &str, if_exists: bool) -> Result<()> {
+        let role_api_provider = self.get_role_api_client();
+        let drop_role = role_api_provider.drop_role(name, None);
+        match drop_role.await {
+            Ok(_) => {}
+            Err(failure) => {
+                return if if_exists && failure.code() == ErrorCode::UnknownRoleCode() {
+                    Ok(())
+                } else {
+                    Err(failure.add_message_back("(while drop role)"))
+                };
+            }
+        }
+
+        let users = self.get_users().await?;
+        for user in users.iter().filter(|u| u.roles.iter().any(|r| r == name)) {
+            self.revoke_user_role(&user.name, &user.hostname, name)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn grant_role_privileges(
+        &self,
+        name: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<u64> {
+        let role_api_provider = self.get_role_api_client();
+        role_api_provider
+            .grant_role_privileges(name, object, privileges, None)
+            .await
+            .map_err(|failure| failure.add_message_back("(while set role privileges)"))
+    }
+
+    pub async fn revoke_role_privileges(
+        &self,
+        name: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<u64> {
+        let role_api_provider = self.get_role_api_client();
+        role_api_provider
+            .revoke_role_privileges(name, object, privileges, None)
+            .await
+            .map_err(|failure| failure.add_message_back("(while revoke role privileges)"))
+    }
+
+    // Grant an existing role to the user.
+    pub async fn grant_user_role(
+        &self,
+        username: &str,
+        hostname: &str,
+        role: &str,
+    ) -> Result<Option<u64>> {
+        self.get_role(role).await?;
+
+        let client = self.get_user_api_client();
+        client
+            .grant_user_role(
+                username.to_string(),
+                hostname.to_string(),
+                role.to_string(),
+                None,
+            )
+            .await
+            .map_err(|failure| failure.add_message_back("(while grant user role)"))
+            .map(|res| {
+                self.get_user_cache().invalidate(username);
+                res
+            })
+    }
+
+    pub async fn revoke_user_role(
+        &self,
+        username: &str,
+        hostname: &str,
+        role: &str,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client();
+        client
+            .revoke_user_role(
+                username.to_string(),
+                hostname.to_string(),
+                role.to_string(),
+                None,
+            )
+            .await
+            .map_err(|failure| failure.add_message_back("(while revoke user role)"))
+            .map(|res| {
+                self.get_user_cache().invalidate(username);
+                res
+            })
+    }
+
+    // Get the user with the privileges of the given roles granted to it as well. The roles
+    // which do not exist (any more) grant nothing.
+    pub async fn get_user_with_roles(&self, user: UserInfo, roles: &[String]) -> Result<UserInfo> {
+        let mut user = user;
+        for name in roles {
+            let role = match self.get_role(name).await {
+                Ok(role) => role,
+                Err(e) if e.code() == ErrorCode::UnknownRoleCode() => continue,
+                Err(e) => return Err(e),
+            };
+            user.grants
+                .grant_all_of(&user.name, &user.hostname, &role.grants);
+        }
+        Ok(user)
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+
+use crate::users::UserApiProvider;
+
+/// Row access policy operations.
+impl UserApiProvider {
+    // Add a new row access policy.
+    pub async fn add_row_access_policy(&self, policy: RowAccessPolicy) -> Result<u64> {
+        let policy_api_provider = self.get_row_access_policy_api_client();
+        let add_policy = policy_api_provider.add_policy(policy);
+        match add_policy.await {
+            Ok(res) => {
+                self.get_row_access_policy_cache().invalidate();
+                Ok(res)
+            }
+            Err(failure) => Err(failure.add_message_back("(while add row access policy).")),
+        }
+    }
+
+    // Get all the row access policies of the tenant, from the cache if they are cached.
+    pub async fn get_row_access_policies(&self) -> Result<Vec<RowAccessPolicy>> {
+        let policy_cache = self.get_row_access_policy_cache();
+        if let Some(policies) = policy_cache.get() {
+            return Ok(policies);
+        }
+
+        let generation = policy_cache.generation();
+        let policy_api_provider = self.get_row_access_policy_api_client();
+        let get_policies = policy_api_provider.get_policies();
+        match get_policies.await {
+            Ok(res) => {
+                policy_cache.insert(res.clone(), generation);
+                Ok(res)
+            }
+            Err(failure) => Err(failure.add_message_back("(while get row access policies).")),
+        }
+    }
+
+    // Drop a row access policy by name.
+    pub async fn drop_row_access_policy(&self, name: &str, if_exists: bool) -> Result<()> {
+        let policy_api_provider = self.get_row_access_policy_api_client();
+        let drop_policy = policy_api_provider.drop_policy(name, None);
+        match drop_policy.await {
+            Ok(res) => {
+                self.get_row_access_policy_cache().invalidate();
+                Ok(res)
+            }
+            Err(failure) => {
+                if if_exists && failure.code() == ErrorCode::UnknownRowAccessPolicyCode() {
+                    Ok(())
+                } else {
+                    Err(failure.add_message_back("(while drop row access policy)"))
+                }
+            }
+        }
+    }
+}
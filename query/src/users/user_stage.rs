@@ -30,6 +30,16 @@ impl UserApiProvider {
         }
     }
 
+    // Add a stage, or replace the present one with the same name.
+    pub async fn replace_stage(&self, info: UserStageInfo) -> Result<u64> {
+        let stage_api_provider = self.get_stage_api_client();
+        let replace_stage = stage_api_provider.replace_stage(info);
+        match replace_stage.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while replace stage).")),
+        }
+    }
+
     // Get one stage from by tenant.
     pub async fn get_stage(&self, stage_name: &str) -> Result<UserStageInfo> {
         let stage_api_provider = self.get_stage_api_client();
@@ -31,6 +31,16 @@ impl UserApiProvider {
         }
     }
 
+    // Add a UDF, or replace the present one with the same name.
+    pub async fn replace_udf(&self, info: UserDefinedFunction) -> Result<u64> {
+        let udf_api_client = self.get_udf_api_client();
+        let replace_udf = udf_api_client.replace_udf(info);
+        match replace_udf.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while replace UDF).")),
+        }
+    }
+
     // Update a UDF.
     pub async fn update_udf(&self, info: UserDefinedFunction) -> Result<u64> {
         let udf_api_client = self.get_udf_api_client();
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::api::http::v1::log_level::*;
+use poem::get;
+use poem::http::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::Body;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_log_level() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let sessions = SessionManagerBuilder::create().build()?;
+    let router = Route::new()
+        .at(
+            "/v1/log_level",
+            get(get_log_level_handler).put(set_log_level_handler),
+        )
+        .data(sessions.clone());
+
+    let call = |method: Method, body: &str| {
+        let request = Request::builder()
+            .uri("/v1/log_level".parse().unwrap())
+            .header(header::CONTENT_TYPE, "application/json")
+            .method(method)
+            .body(Body::from_string(body.to_string()));
+        router.call(request)
+    };
+
+    let response = call(Method::PUT, r#"{"level": "info,databend_query=debug"}"#)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = call(Method::GET, "").await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_vec().await.unwrap();
+    let level = serde_json::from_slice::<LogLevel>(&body).unwrap();
+    assert!(level.level.contains("databend_query=debug"));
+
+    // Not a level.
+    let response = call(Method::PUT, r#"{"level": "databend_query=loud"}"#)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // The level of the unit tests.
+    let response = call(Method::PUT, r#"{"level": "DEBUG"}"#).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The changes are recorded, but not the failed one.
+    let changes = sessions.get_settings_history().changes();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].handler_type, "HTTPAPI");
+    assert_eq!(changes[0].new_value, "info,databend_query=debug");
+    assert!(changes[1].old_value.contains("databend_query=debug"));
+    assert_eq!(changes[1].new_value, "DEBUG");
+
+    Ok(())
+}
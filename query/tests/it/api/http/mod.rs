@@ -15,4 +15,6 @@
 mod cluster;
 mod config;
 mod health;
+mod log_level;
 mod logs;
+mod users;
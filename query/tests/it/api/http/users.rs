@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use databend_query::api::http::v1::users::*;
+use databend_query::users::User;
+use poem::delete;
+use poem::get;
+use poem::http::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::post;
+use poem::Body;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Response;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_users() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+
+    // the users are managed by the users with the GRANT privilege on *.*
+    let user_mgr = sessions.get_user_manager();
+    let mut admin: UserInfo = User::new("admin", "%", "admin", PasswordType::PlainText).into();
+    admin.grants.grant_privileges(
+        "admin",
+        "%",
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    user_mgr.add_user(admin).await?;
+    let guest = User::new("guest", "%", "guest", PasswordType::PlainText);
+    user_mgr.add_user(guest.into()).await?;
+
+    let router = Route::new()
+        .at("/v1/users", post(create_user_handler))
+        .at("/v1/users/:name", delete(drop_user_handler))
+        .at(
+            "/v1/users/:name/grants",
+            get(list_grants_handler)
+                .post(grant_handler)
+                .delete(revoke_handler),
+        )
+        .data(sessions);
+
+    let call_as = |auth: Option<&str>, method: Method, uri: &str, body: &str| {
+        let mut request = Request::builder()
+            .uri(uri.parse().unwrap())
+            .header(header::CONTENT_TYPE, "application/json")
+            .method(method);
+        if let Some(auth) = auth {
+            request = request.header(header::AUTHORIZATION, auth);
+        }
+        router.call(request.body(Body::from_string(body.to_string())))
+    };
+    // admin:admin
+    let call = |method: Method, uri: &str, body: &str| {
+        call_as(Some("Basic YWRtaW46YWRtaW4="), method, uri, body)
+    };
+    let list_grants = |response: Response| async move {
+        let body = response.into_body().into_vec().await.unwrap();
+        serde_json::from_slice::<Vec<String>>(&body).unwrap()
+    };
+
+    // create user
+    let user = r#"{"name": "u1", "password": "123"}"#;
+    let response = call_as(None, Method::POST, "/v1/users", user)
+        .await
+        .unwrap_or_else(|err| err.as_response());
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // guest:guest, without the GRANT privilege
+    let guest = Some("Basic Z3Vlc3Q6Z3Vlc3Q=");
+    let response = call_as(guest, Method::POST, "/v1/users", user)
+        .await
+        .unwrap_or_else(|err| err.as_response());
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = call(Method::POST, "/v1/users", user).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = call(Method::POST, "/v1/users", user).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // grant
+    let grant = r#"{"privileges": ["Select"], "on": {"Database": "default"}}"#;
+    let response = call(Method::POST, "/v1/users/u1/grants", grant)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let grant = r#"{"privileges": ["Select"], "on": {"Database": "not_exists"}}"#;
+    let response = call(Method::POST, "/v1/users/u1/grants", grant)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = call(Method::GET, "/v1/users/u1/grants?hostname=%25", "")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(list_grants(response).await, vec![
+        "GRANT SELECT ON 'default'.* TO 'u1'@'%'".to_string()
+    ]);
+
+    // revoke
+    let revoke = r#"{"privileges": ["Select"], "on": {"Database": "default"}}"#;
+    let response = call(Method::DELETE, "/v1/users/u1/grants", revoke)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = call(Method::GET, "/v1/users/u1/grants", "").await.unwrap();
+    assert_eq!(list_grants(response).await, Vec::<String>::new());
+
+    // drop user
+    let response = call(Method::DELETE, "/v1/users/u1", "").await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = call(Method::GET, "/v1/users/u1/grants", "").await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::audit::AuditEvent;
+use databend_query::audit::AuditEventType;
+use databend_query::audit::AuditLog;
+use databend_query::configs::Config;
+
+#[test]
+fn test_audit_log_file_sink() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("audit.log");
+
+    let mut conf = Config::default();
+    conf.query.tenant_id = "tenant1".to_string();
+    conf.query.audit_log_file = path.display().to_string();
+
+    // Events are appended to the existing file.
+    for round in 0..2 {
+        let audit_log = AuditLog::try_create(&conf)?;
+        let mut event = AuditEvent::create(AuditEventType::Ddl, &conf.query.tenant_id);
+        event.operation = format!("CreateDatabasePlan{}", round);
+        audit_log.append(event);
+        let failure = ErrorCode::AuthenticateFailure("wrong password");
+        audit_log.append(
+            AuditEvent::create(AuditEventType::LoginFailure, "tenant1").with_error(&failure),
+        );
+        assert_eq!(audit_log.events("tenant1").len(), 2);
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let lines: Vec<serde_json::Value> = content
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<std::result::Result<_, _>>()?;
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0]["event_type"], "DDL");
+    assert_eq!(lines[0]["tenant_id"], "tenant1");
+    assert_eq!(lines[0]["operation"], "CreateDatabasePlan0");
+    assert_eq!(lines[1]["event_type"], "LOGIN_FAILURE");
+    assert_eq!(lines[1]["success"], false);
+    assert_eq!(lines[1]["exception_code"], 51);
+    assert_eq!(lines[2]["operation"], "CreateDatabasePlan1");
+
+    Ok(())
+}
+
+#[test]
+fn test_audit_log_tenant_retention() -> Result<()> {
+    let mut conf = Config::default();
+    conf.query.audit_log_retention_days = 30;
+    let audit_log = AuditLog::try_create(&conf)?;
+
+    // The events are kept per tenant.
+    audit_log.append(AuditEvent::create(AuditEventType::Ddl, "tenant1"));
+    audit_log.append(AuditEvent::create(AuditEventType::Dml, "tenant2"));
+    assert_eq!(audit_log.events("tenant1").len(), 1);
+    assert_eq!(audit_log.events("tenant2").len(), 1);
+    assert_eq!(audit_log.events("tenant3").len(), 0);
+
+    // The retention of a tenant does not change the others.
+    assert_eq!(audit_log.get_retention_days("tenant2"), 30);
+    audit_log.set_retention_days("tenant2", 0);
+    assert_eq!(audit_log.get_retention_days("tenant2"), 0);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    assert_eq!(audit_log.events("tenant1").len(), 1);
+    assert_eq!(audit_log.events("tenant2").len(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_audit_log_bad_file() -> Result<()> {
+    let mut conf = Config::default();
+    conf.query.audit_log_file = "/not/exists/dir/audit.log".to_string();
+
+    match AuditLog::try_create(&conf) {
+        Ok(_) => panic!("expect open audit log file failure"),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::InvalidConfig("").code()),
+    }
+
+    Ok(())
+}
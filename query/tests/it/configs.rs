@@ -65,14 +65,46 @@ table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+query_log_table = \"\"
 table_cache_enabled = false
 table_memory_cache_mb_size = 256
 table_disk_cache_root = \"_cache\"
 table_disk_cache_mb_size = 1024
+jwt_key_url = \"\"
+audit_log_file = \"\"
+audit_log_retention_days = 30
+drop_retention_hours = 24
+history_retention_hours = 24
+statistics_refresh_interval_secs = 0
+snapshot_expiry_interval_secs = 0
+connection_encryption_key = \"\"
+user_cache_ttl_secs = 5
+table_cache_parquet_meta_count = 10000
+table_cache_segment_info_count = 1000
+table_block_cache_root = \"_block_cache\"
+table_block_cache_mb_size = 0
+user_password_rehash_on_login = false
+table_change_webhook_url = \"\"
+table_upload_max_retries = 5
+table_upload_retry_backoff_ms = 100
+table_upload_retry_max_backoff_ms = 10000
+table_upload_max_concurrency = 0
+spill_dir = \"_spill\"
 
 [log]
 log_level = \"INFO\"
 log_dir = \"./_logs\"
+log_redact_literals = false
+log_redact_pattern = \"\"
+log_stdout_format = \"text\"
+log_file_format = \"bunyan\"
+log_rotation = \"hourly\"
+log_rotation_size_mb = 512
+log_max_files = 0
+log_max_total_size_mb = 0
+log_compress = false
+log_tracing_exporter = \"off\"
+log_tracing_endpoint = \"\"
 
 [meta]
 meta_embedded_dir = \"./_meta_embedded\"
@@ -139,6 +171,7 @@ fn test_env_config() -> Result<()> {
     std::env::set_var("QUERY_TABLE_ENGINE_PARQUET_ENABLED", "true");
     std::env::set_var("QUERY_TABLE_ENGINE_MEMORY_ENABLED", "true");
     std::env::set_var("QUERY_DATABASE_ENGINE_GITHUB_ENABLED", "false");
+    std::env::set_var("META_EMBEDDED_DIR", "/tmp/meta_embedded");
     std::env::remove_var("CONFIG_FILE");
 
     let default = Config::default();
@@ -178,6 +211,9 @@ fn test_env_config() -> Result<()> {
     assert_eq!("_cache_env", configured.query.table_disk_cache_root);
     assert_eq!(512, configured.query.table_disk_cache_mb_size);
 
+    assert_eq!("/tmp/meta_embedded", configured.meta.meta_embedded_dir);
+    assert!(configured.meta.is_embedded_meta());
+
     // clean up
     std::env::remove_var("LOG_LEVEL");
     std::env::remove_var("QUERY_TENANT_ID");
@@ -206,6 +242,7 @@ fn test_env_config() -> Result<()> {
     std::env::remove_var("QUERY_TABLE_ENGINE_PARQUET_ENABLED");
     std::env::remove_var("QUERY_TABLE_ENGINE_MEMORY_ENABLED");
     std::env::remove_var("QUERY_DATABASE_ENGINE_GITHUB_ENABLED");
+    std::env::remove_var("META_EMBEDDED_DIR");
     Ok(())
 }
 
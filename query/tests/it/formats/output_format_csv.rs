@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::formats::CsvOutputFormat;
+use databend_query::formats::CsvOutputOptions;
+
+fn options(kvs: &[(&str, &str)]) -> HashMap<String, String> {
+    kvs.iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_csv_output_options() -> Result<()> {
+    assert_eq!(
+        CsvOutputOptions::from_options(&HashMap::new())?,
+        CsvOutputOptions::default()
+    );
+
+    let opts = CsvOutputOptions::from_options(&options(&[
+        ("CSV_HEADER", "1"),
+        ("field_delimitor", ";"),
+        ("record_delimitor", "|"),
+        ("line_ending", "CRLF"),
+        ("field_enclosure", "'"),
+        ("enclose_all", "1"),
+        ("decimal_separator", ","),
+        ("null_display", "NULL"),
+        ("bom", "1"),
+    ]))?;
+    assert_eq!(opts, CsvOutputOptions {
+        header: true,
+        field_delimiter: b';',
+        record_delimiter: b"\r\n".to_vec(),
+        field_enclosure: b'\'',
+        enclose_all: true,
+        decimal_separator: b',',
+        null_display: "NULL".to_string(),
+        bom: true,
+        datetime_output: DateTimeOutput::default(),
+    });
+
+    let res = CsvOutputOptions::from_options(&options(&[("line_ending", "cr")]));
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BadOption("").code());
+    Ok(())
+}
+
+#[test]
+fn test_csv_output_format() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::Int32, false),
+        DataField::new("price", DataType::Float64, true),
+        DataField::new("name", DataType::String, true),
+    ]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![1i32, 2]),
+        Series::new(vec![Some(1.5f64), None]),
+        Series::new(vec![Some("a;\"b\""), None]),
+    ]);
+
+    let cases = vec![
+        (
+            "default",
+            options(&[]),
+            "1,1.5,\"a;\"\"b\"\"\"\n2,,\n".as_bytes().to_vec(),
+        ),
+        (
+            "excel",
+            options(&[
+                ("csv_header", "1"),
+                ("field_delimitor", ";"),
+                ("line_ending", "crlf"),
+                ("decimal_separator", ","),
+                ("null_display", "NULL"),
+                ("bom", "1"),
+            ]),
+            [
+                &[0xEF, 0xBB, 0xBF][..],
+                b"id;price;name\r\n1;1,5;\"a;\"\"b\"\"\"\r\n2;NULL;NULL\r\n",
+            ]
+            .concat(),
+        ),
+        (
+            "enclose_all",
+            options(&[("enclose_all", "1"), ("field_enclosure", "'")]),
+            b"'1','1.5','a;\"b\"'\n'2',,\n".to_vec(),
+        ),
+    ];
+
+    for (name, opts, expected) in cases {
+        let format =
+            CsvOutputFormat::create(schema.clone(), CsvOutputOptions::from_options(&opts)?);
+        let mut buf = vec![];
+        format.serialize_prefix(&mut buf);
+        format.serialize_block(&block, &mut buf)?;
+        assert_eq!(
+            String::from_utf8_lossy(&buf),
+            String::from_utf8_lossy(&expected),
+            "case {}",
+            name
+        );
+    }
+    Ok(())
+}
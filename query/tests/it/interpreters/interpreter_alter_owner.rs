@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::OwnershipObject;
+use common_meta_types::UserIdentity;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sql::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_alter_owner_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    let object = OwnershipObject::Table("default".to_string(), "owned_t1".to_string());
+
+    // The creator `test_user`@`%` owns the table.
+    {
+        static TEST_QUERY: &str = "create table default.owned_t1(a UInt64) Engine = Memory";
+        if let PlanNode::CreateTable(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan)?;
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        assert_eq!(
+            user_mgr.get_object_owner(&object).await?,
+            Some(UserIdentity {
+                username: "test_user".to_string(),
+                hostname: "%".to_string(),
+            })
+        );
+    }
+
+    // The owner transfers the table.
+    {
+        static TEST_QUERY: &str = "ALTER TABLE default.owned_t1 OWNER TO 'root'@'localhost'";
+        if let PlanNode::AlterOwner(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = AlterOwnerInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "AlterOwnerInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        assert_eq!(
+            user_mgr.get_object_owner(&object).await?,
+            Some(UserIdentity {
+                username: "root".to_string(),
+                hostname: "localhost".to_string(),
+            })
+        );
+    }
+
+    // Neither transferring back nor dropping is allowed anymore.
+    {
+        static TEST_QUERY: &str = "ALTER TABLE default.owned_t1 OWNER TO 'default'";
+        if let PlanNode::AlterOwner(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = AlterOwnerInterpreter::try_create(ctx.clone(), plan)?;
+            let res = executor.execute(None).await;
+            assert_eq!(res.err().unwrap().code(), 62);
+        } else {
+            panic!()
+        }
+
+        static DROP_QUERY: &str = "drop table default.owned_t1";
+        if let PlanNode::DropTable(plan) = PlanParser::parse(DROP_QUERY, ctx.clone()).await? {
+            let executor = DropTableInterpreter::try_create(ctx.clone(), plan)?;
+            let res = executor.execute(None).await;
+            assert_eq!(res.err().unwrap().code(), 62);
+        } else {
+            panic!()
+        }
+    }
+
+    // Unknown objects can't be transferred.
+    {
+        static TEST_QUERY: &str = "ALTER STAGE unknown_stage OWNER TO 'root'@'localhost'";
+        if let PlanNode::AlterOwner(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = AlterOwnerInterpreter::try_create(ctx.clone(), plan)?;
+            let res = executor.execute(None).await;
+            assert!(res.is_err());
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
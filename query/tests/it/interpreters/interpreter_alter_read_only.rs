@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::ReadOnlyObject;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_alter_read_only_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    let object = ReadOnlyObject::Table("default".to_string(), "ro_t1".to_string());
+
+    execute(&ctx, "create table default.ro_t1(a UInt64) Engine = Memory").await?;
+    execute(&ctx, "insert into default.ro_t1 values(1)").await?;
+
+    // Switch the table to read-only.
+    {
+        static TEST_QUERY: &str = "ALTER TABLE default.ro_t1 SET READ_ONLY = true";
+        if let PlanNode::AlterReadOnly(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = AlterReadOnlyInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "AlterReadOnlyInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        assert!(user_mgr.is_read_only(&object).await?);
+    }
+
+    // Writes are rejected, reads are not.
+    {
+        let res = execute(&ctx, "insert into default.ro_t1 values(2)").await;
+        assert_eq!(res.err().unwrap().code(), 4100);
+        let res = execute(&ctx, "truncate table default.ro_t1").await;
+        assert_eq!(res.err().unwrap().code(), 4100);
+        execute(&ctx, "select * from default.ro_t1").await?;
+    }
+
+    // Switch it back.
+    {
+        execute(&ctx, "ALTER TABLE default.ro_t1 SET READ_ONLY = false").await?;
+        assert!(!user_mgr.is_read_only(&object).await?);
+        execute(&ctx, "insert into default.ro_t1 values(2)").await?;
+    }
+
+    // The database is read-only.
+    {
+        execute(&ctx, "create database ro_db").await?;
+        execute(&ctx, "create table ro_db.ro_t1(a UInt64) Engine = Memory").await?;
+        execute(&ctx, "ALTER DATABASE ro_db SET READ_ONLY = true").await?;
+        let res = execute(&ctx, "insert into ro_db.ro_t1 values(1)").await;
+        assert_eq!(res.err().unwrap().code(), 4101);
+        execute(&ctx, "ALTER DATABASE ro_db SET READ_ONLY = false").await?;
+        execute(&ctx, "insert into ro_db.ro_t1 values(1)").await?;
+    }
+
+    // Neither a read-only table nor a table of a read-only database can be dropped,
+    // and no table can be created in a read-only database.
+    {
+        execute(&ctx, "ALTER TABLE ro_db.ro_t1 SET READ_ONLY = true").await?;
+        let res = execute(&ctx, "drop table ro_db.ro_t1").await;
+        assert_eq!(res.err().unwrap().code(), 4100);
+        execute(&ctx, "ALTER TABLE ro_db.ro_t1 SET READ_ONLY = false").await?;
+
+        execute(&ctx, "ALTER DATABASE ro_db SET READ_ONLY = true").await?;
+        let res = execute(&ctx, "drop table ro_db.ro_t1").await;
+        assert_eq!(res.err().unwrap().code(), 4101);
+        let res = execute(&ctx, "create table ro_db.ro_t2(a UInt64) Engine = Memory").await;
+        assert_eq!(res.err().unwrap().code(), 4101);
+        let res = execute(&ctx, "drop database ro_db").await;
+        assert_eq!(res.err().unwrap().code(), 4101);
+        execute(&ctx, "ALTER DATABASE ro_db SET READ_ONLY = false").await?;
+    }
+
+    // In maintenance mode, the databases and the tables can be neither created nor dropped.
+    {
+        user_mgr
+            .set_read_only(&ReadOnlyObject::Cluster, true)
+            .await?;
+        let res = execute(&ctx, "create database ro_db2").await;
+        assert_eq!(res.err().unwrap().code(), 4102);
+        let res = execute(&ctx, "create table ro_db.ro_t2(a UInt64) Engine = Memory").await;
+        assert_eq!(res.err().unwrap().code(), 4102);
+        let res = execute(&ctx, "drop table ro_db.ro_t1").await;
+        assert_eq!(res.err().unwrap().code(), 4102);
+        let res = execute(&ctx, "drop database ro_db").await;
+        assert_eq!(res.err().unwrap().code(), 4102);
+        user_mgr
+            .set_read_only(&ReadOnlyObject::Cluster, false)
+            .await?;
+
+        execute(&ctx, "drop table ro_db.ro_t1").await?;
+        execute(&ctx, "drop database ro_db").await?;
+    }
+
+    // Unknown table.
+    {
+        let res = execute(&ctx, "ALTER TABLE default.ro_t2 SET READ_ONLY = true").await;
+        assert_eq!(res.err().unwrap().code(), 25);
+    }
+
+    // Without SUPER on *.*, the maintenance mode can't be switched.
+    {
+        let res = execute(&ctx, "ALTER CLUSTER SET MAINTENANCE_MODE = true").await;
+        assert_eq!(res.err().unwrap().code(), 62);
+        assert!(!user_mgr.is_read_only(&ReadOnlyObject::Cluster).await?);
+    }
+
+    Ok(())
+}
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    Ok(())
+}
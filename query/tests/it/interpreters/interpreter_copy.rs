@@ -0,0 +1,222 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+// The load results without the first errors, of which the messages are up to the parsers.
+fn load_results(blocks: &[DataBlock]) -> Vec<DataBlock> {
+    blocks
+        .iter()
+        .map(|block| {
+            let fields = block.schema().fields()[..4].to_vec();
+            let columns = block.columns()[..4].to_vec();
+            DataBlock::create(DataSchemaRefExt::create(fields), columns)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_copy_from_internal_stage() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // the files of an internal stage are kept in the storage of the query
+    let acc = ctx.get_data_accessor()?;
+    acc.put("stage/s1/data/a.csv", b"1\n2\n".to_vec()).await?;
+    acc.put("stage/s1/data/b.csv", b"3\nx\n4\n".to_vec())
+        .await?;
+    acc.put("stage/s1/other/c.csv", b"5\n".to_vec()).await?;
+    execute_command("create stage s1", ctx.clone()).await?;
+
+    // 1. the malformed rows are skipped
+    let qry = format!(
+        "copy into {}.{} from '@s1/data/' format csv on_error = 'continue'",
+        db, tbl
+    );
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+------------+------------------+-------------+-------------+",
+        "| file       | status           | rows_loaded | errors_seen |",
+        "+------------+------------------+-------------+-------------+",
+        "| data/a.csv | LOADED           | 2           | 0           |",
+        "| data/b.csv | PARTIALLY_LOADED | 2           | 1           |",
+        "+------------+------------------+-------------+-------------+",
+    ];
+    assert_blocks_eq(expected, &load_results(&blocks));
+    let first_error = blocks[0].column(4).try_get(1)?.to_string();
+    assert!(first_error.contains("line 1"), "{}", first_error);
+
+    // 2. the statement fails by default
+    let qry = format!("copy into {}.{} from '@s1/data/b.csv' format csv", db, tbl);
+    assert!(execute_command(&qry, ctx.clone()).await.is_err());
+
+    // 3. or the file is skipped
+    let qry = format!(
+        "copy into {}.{} from '@s1/data/' format csv on_error = 'skip_file'",
+        db, tbl
+    );
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+------------+-------------+-------------+-------------+",
+        "| file       | status      | rows_loaded | errors_seen |",
+        "+------------+-------------+-------------+-------------+",
+        "| data/a.csv | LOADED      | 2           | 0           |",
+        "| data/b.csv | LOAD_FAILED | 0           | 1           |",
+        "+------------+-------------+-------------+-------------+",
+    ];
+    assert_blocks_eq(expected, &load_results(&blocks));
+
+    let qry = format!("select count(*) as c, sum(id) as s from {}.{}", db, tbl);
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+---+----+",
+        "| c | s  |",
+        "+---+----+",
+        "| 6 | 13 |",
+        "+---+----+",
+    ];
+    assert_blocks_eq(expected, &blocks);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_fills_column_defaults() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+
+    let acc = ctx.get_data_accessor()?;
+    acc.put("stage/s1/ids.csv", b"1\n2\n".to_vec()).await?;
+    execute_command("create stage s1", ctx.clone()).await?;
+
+    let qry = format!(
+        "create table {}.t(id Int64, a Int64 default 5, b Int64 default id * 10, c Int64)",
+        db
+    );
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("copy into {}.t(id) from '@s1/ids.csv' format csv", db);
+    execute_command(&qry, ctx.clone()).await?;
+
+    let qry = format!("select id, a, b, c from {}.t order by id", db);
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+----+---+----+------+",
+        "| id | a | b  | c    |",
+        "+----+---+----+------+",
+        "| 1  | 5 | 10 | NULL |",
+        "| 2  | 5 | 20 | NULL |",
+        "+----+---+----+------+",
+    ];
+    assert_blocks_eq(expected, &blocks);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_into_internal_stage() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    execute_command("create stage s2", ctx.clone()).await?;
+
+    for qry in [
+        "insert into {} values (1), (2)",
+        "insert into {} values (3)",
+    ] {
+        let qry = qry.replace("{}", &format!("{}.{}", db, tbl));
+        execute_command(&qry, ctx.clone()).await?;
+    }
+
+    // 1. every block is in a file of its own, the files are no larger than max_file_size
+    let qry = format!(
+        "copy into '@s2/unload/' from {}.{} format csv max_file_size = 1",
+        db, tbl
+    );
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(blocks.len(), 1);
+    let manifest = &blocks[0];
+    assert_eq!(manifest.num_rows(), 2);
+    let mut rows = 0;
+    for i in 0..manifest.num_rows() {
+        let file = manifest.column(0).try_get(i)?.to_string();
+        assert!(file.starts_with("unload/data_"), "{}", file);
+        assert!(file.ends_with(".csv"), "{}", file);
+        rows += manifest.column(1).try_get(i)?.as_u64()?;
+    }
+    assert_eq!(rows, 3);
+
+    // 2. the query results are unloaded as parquet
+    let qry = format!(
+        "copy into '@s2/parquet/' from (select id from {}.{} where id > 1) format parquet",
+        db, tbl
+    );
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(blocks[0].num_rows(), 1);
+    assert_eq!(blocks[0].column(1).try_get(0)?.as_u64()?, 2);
+
+    // 3. the unloaded files are loaded back
+    for (path, format) in [("unload/", "csv"), ("parquet/", "parquet")] {
+        let qry = format!(
+            "copy into {}.{} from '@s2/{}' format {}",
+            db, tbl, path, format
+        );
+        execute_command(&qry, ctx.clone()).await?;
+    }
+
+    let qry = format!("select count(*) as c, sum(id) as s from {}.{}", db, tbl);
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+---+----+",
+        "| c | s  |",
+        "+---+----+",
+        "| 8 | 17 |",
+        "+---+----+",
+    ];
+    assert_blocks_eq(expected, &blocks);
+    Ok(())
+}
@@ -51,8 +51,9 @@ async fn test_desc_stageinterpreter() -> Result<()> {
                 "| parent_properties | properties        | property_types | property_values  | property_defaults | property_changed |",
                 "+-------------------+-------------------+----------------+------------------+-------------------+------------------+",
                 "| stage_params      | url               | String         | s3://load/files/ |                   | true             |",
+                "| stage_params      | connection        | String         |                  |                   | false            |",
                 "| credentials       | access_key_id     | String         | 1a2b3c           |                   | true             |",
-                "| credentials       | secret_access_key | String         | 4x5y6z           |                   | true             |",
+                "| credentials       | secret_access_key | String         | ******           |                   | true             |",
                 "| file_format       | format            | String         | Csv              | Csv               | false            |",
                 "| file_format       | record_delimiter  | String         | |                |                   | true             |",
                 // default record_delimiter is \n, so it breaks with an empty line
@@ -57,3 +57,58 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_estimate_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    static TEST_QUERY: &str = "EXPLAIN ESTIMATE SELECT number FROM numbers_mt(10) WHERE number > 1";
+
+    if let PlanNode::Explain(plan) = parse_query(TEST_QUERY, &ctx)? {
+        let executor = ExplainInterpreter::try_create(ctx, plan)?;
+        let schema = executor.schema();
+        assert_eq!(schema.fields().len(), 5);
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-----------------------+------------+------+-------+-------+",
+            "| table                 | partitions | rows | bytes | exact |",
+            "+-----------------------+------------+------+-------+-------+",
+            "| 'system'.'numbers_mt' | 8          | 10   | 80    | true  |",
+            "+-----------------------+------------+------+-------+-------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    } else {
+        panic!()
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    static TEST_QUERY: &str = "EXPLAIN ANALYZE SELECT number FROM numbers_mt(10) WHERE number > 6";
+
+    if let PlanNode::Explain(plan) = parse_query(TEST_QUERY, &ctx)? {
+        let executor = ExplainInterpreter::try_create(ctx, plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let column = result[0].column(0).to_array()?;
+        let lines = (0..column.len())
+            .map(|row| column.try_get(row).map(|v| v.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        // the bytes and the busy time vary
+        assert!(lines[0].starts_with("Result rows: 3, elapsed: "));
+        assert!(lines[1].starts_with("ProjectionTransform × 8 processors (rows: 3, bytes: "));
+        assert!(lines[2].starts_with("  FilterTransform × 8 processors (rows: 3, bytes: "));
+        assert!(lines[3].starts_with("    SourceTransform × 8 processors (rows: 10, bytes: "));
+    } else {
+        panic!()
+    }
+
+    Ok(())
+}
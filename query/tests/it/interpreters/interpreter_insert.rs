@@ -135,3 +135,146 @@ async fn test_insert_into_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_insert_into_column_list_validation() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    {
+        static TEST_QUERY: &str =
+            "create table default.t(a UInt8, b String, c String DEFAULT 'c') Engine = Memory";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    let tests = vec![
+        (
+            "insert into default.t(a, x) values(1, 'x')",
+            "Code: 58, displayText = Unknown column `x` in table `t`.",
+        ),
+        (
+            "insert into default.t(a, b, a) values(1, 'b', 1)",
+            "Code: 6, displayText = Column `a` specified more than once in insert column list.",
+        ),
+        (
+            "insert into default.t(a, b) values(1, 'b'), (2)",
+            "Code: 6, displayText = Number of values in row 2 (1) does not match number of insert columns (2).",
+        ),
+        (
+            "insert into default.t(a, b) select 1",
+            "Code: 6, displayText = Insert select returns 1 columns, but no value is given for column `b`.",
+        ),
+        (
+            "insert into default.t(a, b) select 1, 'b', 'c'",
+            "Code: 6, displayText = Insert select returns 3 columns, but only 2 columns are inserted.",
+        ),
+        (
+            "insert into default.t select 1, 'b', 'c', 'd'",
+            "Code: 6, displayText = Insert select returns 4 columns, but only 3 columns are inserted.",
+        ),
+    ];
+
+    for (query, expect) in tests {
+        let result = PlanParser::parse(query, ctx.clone()).await;
+        assert_eq!(expect, result.unwrap_err().to_string(), "{}", query);
+    }
+
+    // Listed columns may come in any order, missing ones are filled with defaults.
+    {
+        static TEST_QUERY: &str = "insert into default.t(b, a) values('b', 1)";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    {
+        static TEST_QUERY: &str = "select * from default.t";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+---+---+",
+            "| a | b | c |",
+            "+---+---+---+",
+            "| 1 | b | c |",
+            "+---+---+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_values_with_expressions() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    {
+        static TEST_QUERY: &str = "create table default.v(a Int32, b String, c Date16, d Float64, e Boolean) Engine = Memory";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    let queries = vec![
+        // Pure literals.
+        "insert into default.v values(1, 'x', '2021-01-01', 1.5, true), (-2, 'y', '2021-01-02', -0.5, false)",
+        // Expressions.
+        "insert into default.v values(1 + 2, concat('a', 'b'), toDate('2021-01-03'), CAST(5 AS Float64) / 2, not false)",
+    ];
+    for query in queries {
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    {
+        static TEST_QUERY: &str = "select * from default.v";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----+----+------------+------+-------+",
+            "| a  | b  | c          | d    | e     |",
+            "+----+----+------------+------+-------+",
+            "| -2 | y  | 2021-01-02 | -0.5 | false |",
+            "| 1  | x  | 2021-01-01 | 1.5  | true  |",
+            "| 3  | ab | 2021-01-03 | 2.5  | true  |",
+            "+----+----+------------+------+-------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Parse failures point to the row and the column.
+    let tests = vec![
+        (
+            "insert into default.v values(1, 'x', 'not a date', 1.5, true)",
+            "Code: 46, displayText = Cannot parse value to Date type",
+            "(while in parse value of column `c` at row 1).",
+        ),
+        (
+            "insert into default.v values(1, 'x', '2021-01-01', 1.5, true), (30000000000, 'y', '2021-01-02', 1.5, true)",
+            "Code: 46, displayText = Incorrect number value",
+            "(while in parse value of column `a` at row 2).",
+        ),
+        (
+            "insert into default.v values(1, 'x', '2021-01-01', 1.5, true), (2, 'y', toDate('2021-01-02'), 1.5, not_exists(1))",
+            "Code: 8, displayText = Unsupported Function",
+            "(while in analyze value of column `e` at row 2).",
+        ),
+    ];
+
+    for (query, prefix, suffix) in tests {
+        let error = PlanParser::parse(query, ctx.clone())
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with(prefix), "{}: {}", query, error);
+        assert!(error.ends_with(suffix), "{}: {}", query, error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_network_policy_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // Create the policy.
+    {
+        static TEST_QUERY: &str = "CREATE NETWORK POLICY np_office ALLOWED_IP_LIST = ('192.168.1.0/24') BLOCKED_IP_LIST = ('192.168.1.99')";
+        if let PlanNode::CreateNetworkPolicy(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = CreateNetworkPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "CreateNetworkPolicyInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let policy = user_mgr.get_network_policy("np_office").await?;
+        assert_eq!(policy.allowed_ip_list, vec!["192.168.1.0/24".to_string()]);
+
+        let res = execute(&ctx, "CREATE NETWORK POLICY np_office").await;
+        assert_eq!(res.err().unwrap().code(), 4111);
+        execute(&ctx, "CREATE NETWORK POLICY IF NOT EXISTS np_office").await?;
+
+        let res = execute(
+            &ctx,
+            "CREATE NETWORK POLICY np_bad ALLOWED_IP_LIST = ('x.y')",
+        )
+        .await;
+        assert_eq!(res.err().unwrap().code(), 4112);
+    }
+
+    // Attach it to a user.
+    {
+        execute(&ctx, "CREATE USER 'np_user'@'%' IDENTIFIED BY 'password'").await?;
+
+        static TEST_QUERY: &str = "ALTER USER 'np_user'@'%' SET NETWORK_POLICY = 'np_office'";
+        if let PlanNode::AlterUserNetworkPolicy(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = AlterUserNetworkPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "AlterUserNetworkPolicyInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let user = user_mgr.get_user("np_user", "%").await?;
+        assert_eq!(user.network_policy, Some("np_office".to_string()));
+        user_mgr
+            .verify_network_policy(&user, "192.168.1.1:3306")
+            .await?;
+        let res = user_mgr
+            .verify_network_policy(&user, "192.168.1.99:3306")
+            .await;
+        assert_eq!(res.err().unwrap().code(), 51);
+        let res = user_mgr.verify_network_policy(&user, "10.0.0.1").await;
+        assert_eq!(res.err().unwrap().code(), 51);
+
+        let res = execute(
+            &ctx,
+            "ALTER USER 'np_user'@'%' SET NETWORK_POLICY = 'np_unknown'",
+        )
+        .await;
+        assert_eq!(res.err().unwrap().code(), 4110);
+    }
+
+    // The policy can't be dropped while it's in use.
+    {
+        let res = execute(&ctx, "DROP NETWORK POLICY np_office").await;
+        assert_eq!(res.err().unwrap().code(), 4113);
+
+        execute(&ctx, "ALTER USER 'np_user'@'%' UNSET NETWORK_POLICY").await?;
+        let user = user_mgr.get_user("np_user", "%").await?;
+        assert_eq!(user.network_policy, None);
+        user_mgr.verify_network_policy(&user, "10.0.0.1").await?;
+
+        static TEST_QUERY: &str = "DROP NETWORK POLICY np_office";
+        if let PlanNode::DropNetworkPolicy(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = DropNetworkPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "DropNetworkPolicyInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let res = execute(&ctx, "DROP NETWORK POLICY np_office").await;
+        assert_eq!(res.err().unwrap().code(), 4110);
+        execute(&ctx, "DROP NETWORK POLICY IF EXISTS np_office").await?;
+    }
+
+    Ok(())
+}
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    Ok(())
+}
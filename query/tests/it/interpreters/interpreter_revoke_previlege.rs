@@ -55,3 +55,50 @@ async fn test_revoke_privilege_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_revoke_all_privileges_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let name = "test_revoke_all";
+    let hostname = "localhost";
+    let user_info = UserInfo::new(
+        name.to_string(),
+        hostname.to_string(),
+        Vec::from("test"),
+        PasswordType::PlainText,
+    );
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    user_mgr.add_user(user_info).await?;
+
+    for query in [
+        format!("GRANT ALL ON *.* TO '{}'@'{}'", name, hostname),
+        format!("GRANT SELECT ON default.* TO '{}'@'{}'", name, hostname),
+    ] {
+        let plan = PlanParser::parse(&query, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+    }
+    let user = user_mgr.get_user(name, hostname).await?;
+    assert_eq!(user.grants.entries().len(), 2);
+
+    let test_query = format!(
+        "REVOKE ALL PRIVILEGES, GRANT OPTION FROM '{}'@'{}'",
+        name, hostname
+    );
+    if let PlanNode::RevokeAllPrivileges(plan) = PlanParser::parse(&test_query, ctx.clone()).await?
+    {
+        let executor = RevokeAllPrivilegesInterpreter::try_create(ctx, plan.clone())?;
+        assert_eq!(executor.name(), "RevokeAllPrivilegesInterpreter");
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+        let new_user = user_mgr.get_user(name, hostname).await?;
+        assert_eq!(new_user.grants, UserGrantSet::empty());
+    } else {
+        panic!()
+    }
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::UserPrivilegeType;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // Create the roles.
+    {
+        static TEST_QUERY: &str = "CREATE ROLE 'cluster_admin'";
+        if let PlanNode::CreateRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateRoleInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "CreateRoleInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let res = execute(&ctx, "CREATE ROLE 'cluster_admin'").await;
+        assert_eq!(res.err().unwrap().code(), 4132);
+        execute(&ctx, "CREATE ROLE IF NOT EXISTS 'cluster_admin'").await?;
+        execute(&ctx, "CREATE ROLE 'reader'").await?;
+    }
+
+    // Grant privileges to the role.
+    {
+        static TEST_QUERY: &str = "GRANT ALL ON *.* TO ROLE 'cluster_admin'";
+        if let PlanNode::GrantRolePrivilege(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = GrantRolePrivilegeInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "GrantRolePrivilegeInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let role = user_mgr.get_role("cluster_admin").await?;
+        assert!(role.grants.verify_global_privilege(
+            "cluster_admin",
+            "%",
+            UserPrivilegeType::Super
+        ));
+
+        let res = execute(&ctx, "GRANT SELECT ON *.* TO ROLE 'unknown_role'").await;
+        assert_eq!(res.err().unwrap().code(), 4131);
+    }
+
+    // Grant the roles to the user of the session, which has no privilege of its own.
+    {
+        execute(&ctx, "CREATE USER 'test_user'@'%' IDENTIFIED BY 'pass'").await?;
+
+        static TEST_QUERY: &str = "GRANT ROLE 'cluster_admin' TO 'test_user'@'%'";
+        if let PlanNode::GrantRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = GrantRoleInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "GrantRoleInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+        execute(&ctx, "GRANT ROLE 'reader' TO 'test_user'@'%'").await?;
+
+        let res = execute(&ctx, "GRANT ROLE 'unknown_role' TO 'test_user'@'%'").await;
+        assert_eq!(res.err().unwrap().code(), 4131);
+
+        let user = user_mgr.get_user("test_user", "%").await?;
+        assert_eq!(user.roles, vec![
+            "cluster_admin".to_string(),
+            "reader".to_string()
+        ]);
+
+        // Login again, the first role of the user is the current role.
+        ctx.get_session().set_current_user(user);
+        assert_eq!(Some("cluster_admin".to_string()), ctx.get_current_role());
+    }
+
+    // The privileges of the active roles are checked, SUPER on *.* is needed here.
+    {
+        static TEST_QUERY: &str = "ALTER CLUSTER SET MAINTENANCE_MODE = false";
+        execute(&ctx, TEST_QUERY).await?;
+
+        execute(&ctx, "SET ROLE 'reader'").await?;
+        let res = execute(&ctx, TEST_QUERY).await;
+        assert_eq!(res.err().unwrap().code(), 62);
+
+        execute(&ctx, "SET SECONDARY ROLES ALL").await?;
+        execute(&ctx, TEST_QUERY).await?;
+
+        execute(&ctx, "SET SECONDARY ROLES NONE").await?;
+        let res = execute(&ctx, TEST_QUERY).await;
+        assert_eq!(res.err().unwrap().code(), 62);
+
+        execute(&ctx, "SET ROLE 'cluster_admin'").await?;
+        execute(&ctx, TEST_QUERY).await?;
+    }
+
+    // Revoke the privileges from the role.
+    {
+        static TEST_QUERY: &str = "REVOKE ALL ON *.* FROM ROLE 'cluster_admin'";
+        if let PlanNode::RevokeRolePrivilege(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = RevokeRolePrivilegeInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "RevokeRolePrivilegeInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let res = execute(&ctx, "ALTER CLUSTER SET MAINTENANCE_MODE = false").await;
+        assert_eq!(res.err().unwrap().code(), 62);
+    }
+
+    // Revoke a role from the user.
+    {
+        static TEST_QUERY: &str = "REVOKE ROLE 'cluster_admin' FROM 'test_user'@'%'";
+        if let PlanNode::RevokeRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = RevokeRoleInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "RevokeRoleInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let user = user_mgr.get_user("test_user", "%").await?;
+        assert_eq!(user.roles, vec!["reader".to_string()]);
+    }
+
+    // Drop a role, it is revoked from the users.
+    {
+        static TEST_QUERY: &str = "DROP ROLE 'reader'";
+        if let PlanNode::DropRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = DropRoleInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "DropRoleInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        let user = user_mgr.get_user("test_user", "%").await?;
+        assert!(user.roles.is_empty());
+
+        let res = execute(&ctx, "DROP ROLE 'reader'").await;
+        assert_eq!(res.err().unwrap().code(), 4131);
+        execute(&ctx, "DROP ROLE IF EXISTS 'reader'").await?;
+    }
+
+    Ok(())
+}
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    Ok(())
+}
@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::*;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_row_access_policy_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+
+    // Prepare the protected table.
+    {
+        static TEST_QUERY: &str =
+            "create table default.sales(region String, amount UInt64) Engine = Memory";
+        if let PlanNode::CreateTable(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+
+        static INSERT_QUERY: &str =
+            "insert into default.sales values('eu', 1), ('eu', 2), ('us', 3)";
+        if let PlanNode::Insert(plan) = PlanParser::parse(INSERT_QUERY, ctx.clone()).await? {
+            let executor = InsertInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+    }
+
+    // Create policies, only the first one applies to the current user `test_user`@`%`.
+    {
+        static TEST_QUERY: &str =
+            "CREATE ROW ACCESS POLICY eu_only ON default.sales AS region = 'eu'";
+        if let PlanNode::CreateRowAccessPolicy(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = CreateRowAccessPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "CreateRowAccessPolicyInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        static OTHER_QUERY: &str =
+            "CREATE ROW ACCESS POLICY nothing ON sales TO 'other_user'@'%' AS 1 = 0";
+        if let PlanNode::CreateRowAccessPolicy(plan) =
+            PlanParser::parse(OTHER_QUERY, ctx.clone()).await?
+        {
+            let executor = CreateRowAccessPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        // Already exists.
+        if let PlanNode::CreateRowAccessPolicy(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = CreateRowAccessPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            let res = executor.execute(None).await;
+            assert_eq!(res.err().unwrap().code(), 4081);
+        } else {
+            panic!()
+        }
+    }
+
+    // The scans of the protected table are filtered.
+    {
+        static TEST_QUERY: &str = "select region, amount from default.sales where amount > 1";
+        if let PlanNode::Select(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+--------+--------+",
+                "| region | amount |",
+                "+--------+--------+",
+                "| eu     | 2      |",
+                "+--------+--------+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+
+        // The predicates are not injected into the joins, the protected table is refused.
+        static JOIN_QUERY: &str =
+            "select count(*) from default.sales as s join numbers(3) as n on s.amount = n.number";
+        let res = PlanParser::parse(JOIN_QUERY, ctx.clone()).await;
+        assert_eq!(res.err().unwrap().code(), 62);
+    }
+
+    // Drop the policy, all the rows are visible again.
+    {
+        static TEST_QUERY: &str = "DROP ROW ACCESS POLICY eu_only";
+        if let PlanNode::DropRowAccessPolicy(plan) =
+            PlanParser::parse(TEST_QUERY, ctx.clone()).await?
+        {
+            let executor = DropRowAccessPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "DropRowAccessPolicyInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+
+        static SELECT_QUERY: &str = "select count(*) from default.sales";
+        if let PlanNode::Select(plan) = PlanParser::parse(SELECT_QUERY, ctx.clone()).await? {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+----------+",
+                "| count(0) |",
+                "+----------+",
+                "| 3        |",
+                "+----------+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+
+        static DROP_UNKNOWN_QUERY: &str = "DROP ROW ACCESS POLICY IF EXISTS eu_only";
+        if let PlanNode::DropRowAccessPolicy(plan) =
+            PlanParser::parse(DROP_UNKNOWN_QUERY, ctx.clone()).await?
+        {
+            let executor = DropRowAccessPolicyInterpreter::try_create(ctx.clone(), plan)?;
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+    }
+
+    // A policy to a role applies to the sessions with the role active.
+    {
+        execute(
+            &ctx,
+            "CREATE ROW ACCESS POLICY eu_role ON default.sales TO ROLE 'sales_eu' AS region = 'eu'",
+        )
+        .await?;
+
+        let session = ctx.get_session();
+        session.set_auth_roles(vec!["sales_eu".to_string()]);
+        assert_eq!(count_sales(&ctx).await?, 2);
+        session.set_auth_roles(vec![]);
+        assert_eq!(count_sales(&ctx).await?, 3);
+    }
+
+    Ok(())
+}
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    Ok(())
+}
+
+async fn count_sales(ctx: &Arc<QueryContext>) -> Result<u64> {
+    let plan = PlanParser::parse("select count(*) from default.sales", ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    result[0].column(0).try_get(0)?.as_u64()
+}
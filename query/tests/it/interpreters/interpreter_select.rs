@@ -77,3 +77,126 @@ async fn test_select_interpreter() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_with_query_functions() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    static TEST_QUERY: &str =
+        "WITH FUNCTION twice(x) AS (x * 2), FUNCTION sum_of(x, y) AS (x + y) \
+        SELECT sum_of(twice(number), 1) AS n FROM numbers(3)";
+    let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+---+", //
+        "| n |", "+---+", "| 1 |", "| 3 |", "| 5 |", "+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // the functions are not kept beyond the query
+    let ctx = crate::tests::create_query_context()?;
+    let result = PlanParser::parse("SELECT twice(1)", ctx.clone()).await;
+    assert!(result.is_err());
+
+    // a builtin function can not be re-defined
+    static TEST_QUERY_BUILTIN: &str = "WITH FUNCTION abs(x) AS (x * 2) SELECT abs(1)";
+    let result = PlanParser::parse(TEST_QUERY_BUILTIN, ctx.clone()).await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_join_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "create table default.t1(id UInt64, a String) Engine = Memory",
+        "create table default.t2(id UInt64, b String) Engine = Memory",
+        "insert into default.t1 values(1, 'x'), (2, 'y'), (3, 'z')",
+        "insert into default.t2 values(2, 'p'), (3, 'q'), (4, 'r')",
+    ] {
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    let tests = vec![
+        ("select t1.id as id, a, b from t1 join t2 on t1.id = t2.id", vec![
+            "+----+---+---+",
+            "| id | a | b |",
+            "+----+---+---+",
+            "| 2  | y | p |",
+            "| 3  | z | q |",
+            "+----+---+---+",
+        ]),
+        ("select l.id as id, a, b from t1 as l left join t2 as r on r.id = l.id", vec![
+            "+----+---+------+",
+            "| id | a | b    |",
+            "+----+---+------+",
+            "| 1  | x | NULL |",
+            "| 2  | y | p    |",
+            "| 3  | z | q    |",
+            "+----+---+------+",
+        ]),
+        ("select r.id as id, a, b from t1 as l right join t2 as r on l.id = r.id", vec![
+            "+----+------+---+",
+            "| id | a    | b |",
+            "+----+------+---+",
+            "| 2  | y    | p |",
+            "| 3  | z    | q |",
+            "| 4  | NULL | r |",
+            "+----+------+---+",
+        ]),
+        ("select l.id as x, r.id as y from t1 as l full join t2 as r on l.id = r.id", vec![
+            "+------+------+",
+            "| x    | y    |",
+            "+------+------+",
+            "| 1    | NULL |",
+            "| 2    | 2    |",
+            "| 3    | 3    |",
+            "| NULL | 4    |",
+            "+------+------+",
+        ]),
+        // Equalities joined by AND, and a table function joined to a table.
+        ("select count() as c from numbers(10) as n join t2 on n.number = t2.id and n.number = t2.id", vec![
+            "+---+",
+            "| c |",
+            "+---+",
+            "| 3 |",
+            "+---+",
+        ]),
+    ];
+
+    for (query, expected) in tests {
+        if let PlanNode::Select(plan) = PlanParser::parse(query, ctx.clone()).await? {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+    }
+
+    let errors = vec![
+        // Not an equality of a column of each side.
+        ("select * from t1 join t2 on t1.id > t2.id", 2),
+        ("select * from t1 join t2 on t1.id = 1", 2),
+        // Cross joins are not planned.
+        ("select * from t1, t2", 2),
+        // The column is in both tables.
+        ("select id from t1 join t2 on t1.id = t2.id", 58),
+        // The same table on both sides needs aliases.
+        ("select * from t1 join t1 on t1.id = t1.id", 5),
+    ];
+    for (query, code) in errors {
+        let res = PlanParser::parse(query, ctx.clone()).await;
+        assert_eq!(res.err().unwrap().code(), code, "{}", query);
+    }
+
+    Ok(())
+}
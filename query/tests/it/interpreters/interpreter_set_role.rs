@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use common_planners::*;
+use databend_query::clusters::Cluster;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use futures::stream::StreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::parse_query;
+use crate::tests::SessionManagerBuilder;
+
+fn create_query_context_with_roles(roles: Vec<String>) -> Result<Arc<QueryContext>> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user(UserInfo::new(
+        "test_user".to_string(),
+        "%".to_string(),
+        Vec::from("pass"),
+        PasswordType::Sha256,
+    ));
+    session.set_auth_roles(roles);
+
+    Ok(QueryContext::from_shared(QueryContextShared::try_create(
+        sessions.get_conf().clone(),
+        Arc::new(session.as_ref().clone()),
+        Cluster::empty(),
+    )?))
+}
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<()> {
+    let interpreter = match parse_query(query, ctx)? {
+        PlanNode::SetRole(plan) => SetRoleInterpreter::try_create(ctx.clone(), plan)?,
+        PlanNode::SetSecondaryRoles(plan) => {
+            SetSecondaryRolesInterpreter::try_create(ctx.clone(), plan)?
+        }
+        _ => panic!("unexpected plan of {}", query),
+    };
+
+    let mut stream = interpreter.execute(None).await?;
+    while let Some(_block) = stream.next().await {}
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_role_interpreter() -> Result<()> {
+    let ctx = create_query_context_with_roles(vec!["analyst".to_string(), "loader".to_string()])?;
+
+    // The first granted role is the current role, no secondary roles by default.
+    assert_eq!(Some("analyst".to_string()), ctx.get_current_role());
+    assert_eq!(vec!["analyst".to_string()], ctx.get_active_roles());
+
+    execute(&ctx, "SET ROLE loader").await?;
+    assert_eq!(Some("loader".to_string()), ctx.get_current_role());
+    assert_eq!(vec!["loader".to_string()], ctx.get_active_roles());
+
+    execute(&ctx, "SET SECONDARY ROLES ALL").await?;
+    assert_eq!(
+        vec!["loader".to_string(), "analyst".to_string()],
+        ctx.get_active_roles()
+    );
+
+    execute(&ctx, "SET SECONDARY ROLES NONE").await?;
+    assert_eq!(vec!["loader".to_string()], ctx.get_active_roles());
+
+    // Only the granted roles can be set.
+    let res = execute(&ctx, "SET ROLE admin").await;
+    assert_eq!(
+        "Code: 4130, displayText = Role 'admin' is not granted to the user of the session.",
+        res.unwrap_err().to_string()
+    );
+    assert_eq!(Some("loader".to_string()), ctx.get_current_role());
+
+    Ok(())
+}
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_datavalues::DateTimeOutput;
 use common_exception::Result;
+use common_functions::scalars::DivisionByZeroMode;
+use common_functions::scalars::OverflowMode;
 use common_planners::*;
 use databend_query::interpreters::*;
 use futures::stream::StreamExt;
@@ -54,3 +57,122 @@ async fn test_setting_interpreter_error() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_time_zone() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let cases = vec![
+        ("SET time_zone = 'Asia/Shanghai'", None),
+        ("SET datetime_output_format = 'EPOCH'", None),
+        ("SET time_zone = 'Mars/Olympus'", Some(6)),
+        ("SET datetime_output_format = 'iso'", Some(6)),
+    ];
+    for (query, error_code) in cases {
+        if let PlanNode::SetVariable(plan) = parse_query(query, &ctx)? {
+            let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+            let result = executor.execute(None).await;
+            match error_code {
+                None => assert!(result.is_ok(), "{}", query),
+                Some(code) => assert_eq!(result.err().map(|e| e.code()), Some(code), "{}", query),
+            }
+        } else {
+            panic!()
+        }
+    }
+
+    let settings = ctx.get_settings();
+    assert_eq!(settings.get_time_zone()?, "Asia/Shanghai");
+    assert_eq!(settings.get_datetime_output_format()?, "epoch");
+    assert_eq!(settings.get_datetime_output()?, DateTimeOutput::Epoch);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_numeric_overflow_mode() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    assert_eq!(ctx.get_settings().get_overflow_mode()?, OverflowMode::Wrap);
+
+    let cases = vec![
+        ("SET numeric_overflow_mode = 'Error'", None),
+        ("SET numeric_overflow_mode = 'clamp'", Some(6)),
+    ];
+    for (query, error_code) in cases {
+        if let PlanNode::SetVariable(plan) = parse_query(query, &ctx)? {
+            let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+            let result = executor.execute(None).await;
+            match error_code {
+                None => assert!(result.is_ok(), "{}", query),
+                Some(code) => assert_eq!(result.err().map(|e| e.code()), Some(code), "{}", query),
+            }
+        } else {
+            panic!()
+        }
+    }
+
+    let settings = ctx.get_settings();
+    assert_eq!(settings.get_numeric_overflow_mode()?, "error");
+    assert_eq!(settings.get_overflow_mode()?, OverflowMode::Error);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_division_by_zero() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    assert_eq!(
+        ctx.get_settings().get_division_by_zero_mode()?,
+        DivisionByZeroMode::Null
+    );
+
+    let cases = vec![
+        ("SET error_on_division_by_zero = 1", None),
+        ("SET div_precision_increment = 4", None),
+        ("SET div_precision_increment = 31", Some(6)),
+    ];
+    for (query, error_code) in cases {
+        if let PlanNode::SetVariable(plan) = parse_query(query, &ctx)? {
+            let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+            let result = executor.execute(None).await;
+            match error_code {
+                None => assert!(result.is_ok(), "{}", query),
+                Some(code) => assert_eq!(result.err().map(|e| e.code()), Some(code), "{}", query),
+            }
+        } else {
+            panic!()
+        }
+    }
+
+    let settings = ctx.get_settings();
+    assert_eq!(settings.get_div_precision_increment()?, 4);
+    assert_eq!(
+        settings.get_division_by_zero_mode()?,
+        DivisionByZeroMode::Error
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_global() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // SET GLOBAL needs SUPER on *.*.
+    if let PlanNode::SetVariable(plan) = parse_query("SET GLOBAL log_level = 'DEBUG'", &ctx)? {
+        assert!(plan.vars[0].is_global);
+        let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), 62);
+    } else {
+        panic!()
+    }
+
+    if let PlanNode::SetVariable(plan) = parse_query("SET log_level = 'DEBUG'", &ctx)? {
+        assert!(!plan.vars[0].is_global);
+    } else {
+        panic!()
+    }
+
+    Ok(())
+}
@@ -69,5 +69,33 @@ async fn test_show_create_database_interpreter() -> Result<()> {
         }
     }
 
+    // show create database with the retention of its tables
+    {
+        if let PlanNode::CreateDatabase(plan) =
+            parse_query("create database db1 data_retention_time_in_days = 7", &ctx)?
+        {
+            let executor = CreateDatabaseInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!();
+        }
+
+        if let PlanNode::ShowCreateDatabase(plan) = parse_query("show create database db1", &ctx)? {
+            let executor = ShowCreateDatabaseInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+----------+-------------------------------------------------------+",
+                "| Database | Create Database                                       |",
+                "+----------+-------------------------------------------------------+",
+                "| db1      | CREATE DATABASE `db1` DATA_RETENTION_TIME_IN_DAYS='7' |",
+                "+----------+-------------------------------------------------------+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!();
+        }
+    }
+
     Ok(())
 }
@@ -65,5 +65,38 @@ async fn interpreter_show_create_table_test() -> Result<()> {
         }
     }
 
+    // Show create table with the column defaults.
+    {
+        static TEST_CREATE_QUERY: &str = "\
+            CREATE TABLE default.b(\
+                a bigint not null default 3, b int default a + 3\
+            ) Engine = Null\
+        ";
+
+        if let PlanNode::CreateTable(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+
+        if let PlanNode::ShowCreateTable(plan) = parse_query("SHOW CREATE TABLE b", &ctx)? {
+            let executor = ShowCreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+-------+------------------------------+",
+                "| Table | Create Table                 |",
+                "+-------+------------------------------+",
+                "| b     | CREATE TABLE `b` (           |",
+                "|       |   `a` Int64 DEFAULT 3,       |",
+                "|       |   `b` Int32 DEFAULT (a + 3), |",
+                "|       | ) ENGINE=Null                |",
+                "+-------+------------------------------+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+    }
+
     Ok(())
 }
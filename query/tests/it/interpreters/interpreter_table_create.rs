@@ -14,7 +14,13 @@
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
 use common_planners::*;
+use databend_query::catalogs::Catalog;
 use databend_query::interpreters::*;
 use futures::stream::StreamExt;
 
@@ -100,3 +106,54 @@ async fn test_create_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_or_replace_table_privileges() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    static TEST_CREATE_QUERY: &str = "CREATE TABLE default.t(a int) Engine = Null";
+    if let PlanNode::CreateTable(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+        let interpreter = CreateTableInterpreter::try_create(ctx.clone(), plan)?;
+        let _ = interpreter.execute(None).await?;
+    } else {
+        panic!()
+    }
+
+    // The user owns the table, but may not create tables in the database.
+    static TEST_REPLACE_QUERY: &str = "CREATE OR REPLACE TABLE default.t(b int) Engine = Null";
+    if let PlanNode::CreateTable(plan) = parse_query(TEST_REPLACE_QUERY, &ctx)? {
+        let interpreter = CreateTableInterpreter::try_create(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), 62);
+    } else {
+        panic!()
+    }
+
+    // With the CREATE privilege on the database, the table is replaced.
+    let mut user = UserInfo::new(
+        "test_user".to_string(),
+        "%".to_string(),
+        Vec::from("pass"),
+        PasswordType::Sha256,
+    );
+    let mut privileges = UserPrivilegeSet::empty();
+    privileges.set_privilege(UserPrivilegeType::Create);
+    user.grants.grant_privileges(
+        "test_user",
+        "%",
+        &GrantObject::Database("default".to_string()),
+        privileges,
+    );
+    ctx.get_session().set_current_user(user);
+
+    if let PlanNode::CreateTable(plan) = parse_query(TEST_REPLACE_QUERY, &ctx)? {
+        let interpreter = CreateTableInterpreter::try_create(ctx.clone(), plan)?;
+        let _ = interpreter.execute(None).await?;
+    } else {
+        panic!()
+    }
+    let table = ctx.get_catalog().get_table("default", "t").await?;
+    assert!(table.schema().field_with_name("b").is_ok());
+
+    Ok(())
+}
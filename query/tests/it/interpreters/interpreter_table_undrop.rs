@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::catalogs::Catalog;
+use databend_query::interpreters::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::parse_query;
+
+#[tokio::test]
+async fn test_undrop_table_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create and drop table.
+    {
+        static TEST_CREATE_QUERY: &str = "\
+            CREATE TABLE default.a(\
+                a bigint, b int, c varchar(255)\
+            ) Engine = Null\
+        ";
+
+        let plan = parse_query(TEST_CREATE_QUERY, &ctx)?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+
+        let plan = parse_query("DROP TABLE a", &ctx)?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Undrop table.
+    {
+        if let PlanNode::UndropTable(plan) = parse_query("UNDROP TABLE a", &ctx)? {
+            let executor = UndropTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "UndropTableInterpreter");
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+
+        let table = ctx.get_catalog().get_table("default", "a").await?;
+        assert_eq!(table.get_table_info().schema().fields().len(), 3);
+    }
+
+    // Undrop table again, nothing is left in the recycle bin.
+    {
+        let plan = parse_query("UNDROP TABLE a", &ctx)?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = executor.execute(None).await;
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().code(), 4090);
+    }
+
+    // Vacuum drop table.
+    {
+        if let PlanNode::VacuumDropTable(plan) =
+            parse_query("VACUUM DROP TABLE RETAIN 0 HOURS", &ctx)?
+        {
+            let executor = VacuumDropTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "VacuumDropTableInterpreter");
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
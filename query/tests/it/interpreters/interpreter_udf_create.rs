@@ -14,6 +14,8 @@
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
 use common_planners::*;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
@@ -86,5 +88,79 @@ async fn test_create_udf_interpreter() -> Result<()> {
     } else {
         panic!()
     }
+
+    static TEST_QUERY2: &str =
+        "CREATE OR REPLACE FUNCTION isnotempty AS (d) -> not(isnull(d)) DESC = 'This is a new description'";
+    if let PlanNode::CreateUDF(plan) = PlanParser::parse(TEST_QUERY2, ctx.clone()).await? {
+        let executor = CreatUDFInterpreter::try_create(ctx.clone(), plan.clone())?;
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+        let udf = ctx
+            .get_sessions_manager()
+            .get_user_manager()
+            .get_udf("isnotempty")
+            .await?;
+
+        assert_eq!(udf.name, "isnotempty");
+        assert_eq!(udf.parameters, vec!["d".to_string()]);
+        assert_eq!(udf.definition, "not(isnull(d))");
+        assert_eq!(udf.description, "This is a new description")
+    } else {
+        panic!()
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_udf_usage() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+
+    // The owner of the UDF may call it.
+    let plan = PlanParser::parse(
+        "CREATE FUNCTION usage_udf AS (p) -> not(isnull(p))",
+        ctx.clone(),
+    )
+    .await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    PlanParser::parse("SELECT usage_udf(1)", ctx.clone()).await?;
+
+    // The other users need the USAGE privilege on it.
+    let plan = PlanParser::parse(
+        "CREATE USER 'udf_user'@'%' IDENTIFIED BY 'pass'",
+        ctx.clone(),
+    )
+    .await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    ctx.get_session().set_current_user(UserInfo::new(
+        "udf_user".to_string(),
+        "%".to_string(),
+        Vec::from("pass"),
+        PasswordType::Sha256,
+    ));
+    let res = PlanParser::parse("SELECT usage_udf(1)", ctx.clone()).await;
+    assert_eq!(res.err().unwrap().code(), 62);
+    let res = PlanParser::parse(
+        "SELECT number FROM numbers(1) WHERE usage_udf(number)",
+        ctx.clone(),
+    )
+    .await;
+    assert_eq!(res.err().unwrap().code(), 62);
+
+    let plan = PlanParser::parse(
+        "GRANT USAGE ON FUNCTION usage_udf TO 'udf_user'@'%'",
+        ctx.clone(),
+    )
+    .await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    ctx.get_session()
+        .set_current_user(user_mgr.get_user("udf_user", "%").await?);
+    PlanParser::parse("SELECT usage_udf(1)", ctx.clone()).await?;
+
     Ok(())
 }
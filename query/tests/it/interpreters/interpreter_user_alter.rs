@@ -21,6 +21,8 @@ use databend_query::interpreters::*;
 use databend_query::sql::*;
 use futures::stream::StreamExt;
 use pretty_assertions::assert_eq;
+use sha2::Digest;
+use sha2::Sha256;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_alter_user_interpreter() -> Result<()> {
@@ -54,7 +56,12 @@ async fn test_alter_user_interpreter() -> Result<()> {
         let mut stream = executor.execute(None).await?;
         while let Some(_block) = stream.next().await {}
         let new_user = user_mgr.get_user(name, hostname).await?;
-        assert_eq!(new_user.password, Vec::from(new_password))
+        // IDENTIFIED BY keeps the sha256 digest of the password by default
+        assert_eq!(new_user.password_type, PasswordType::Sha256);
+        assert_eq!(
+            new_user.password,
+            Sha256::digest(new_password.as_bytes()).to_vec()
+        );
     } else {
         panic!()
     }
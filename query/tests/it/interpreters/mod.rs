@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod interpreter_alter_owner;
+mod interpreter_alter_read_only;
+mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_describe_stage;
@@ -20,8 +23,12 @@ mod interpreter_explain;
 mod interpreter_grant_privilege;
 mod interpreter_insert;
 mod interpreter_interceptor;
+mod interpreter_network_policy;
 mod interpreter_revoke_previlege;
+mod interpreter_role;
+mod interpreter_row_access_policy;
 mod interpreter_select;
+mod interpreter_set_role;
 mod interpreter_setting;
 mod interpreter_show_create_database;
 mod interpreter_show_create_table;
@@ -31,6 +38,7 @@ mod interpreter_table_create;
 mod interpreter_table_drop;
 mod interpreter_table_optimize;
 mod interpreter_table_truncate;
+mod interpreter_table_undrop;
 mod interpreter_udf_alter;
 mod interpreter_udf_create;
 mod interpreter_udf_drop;
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod api;
+mod audit;
 mod clusters;
 mod common;
 mod configs;
+mod formats;
 mod functions;
 mod interpreters;
 mod metrics;
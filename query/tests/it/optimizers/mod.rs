@@ -13,7 +13,10 @@
 // limitations under the License.
 
 mod optimizer;
+mod optimizer_approx_count_distinct;
 mod optimizer_constant_folding;
+mod optimizer_distinct_aggregate;
+mod optimizer_empty_result;
 mod optimizer_expression_transform;
 mod optimizer_scatters;
 mod optimizer_statistics_exact;
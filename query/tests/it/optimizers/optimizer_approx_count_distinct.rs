@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::optimizers::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_approx_count_distinct_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        min_rows: u64,
+        expect: &'static str,
+    }
+
+    let query = "select count(distinct number), sum(number) from numbers(10) group by number % 3";
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "Disabled",
+            min_rows: 0,
+            expect: "\
+            Projection: count(distinct number):UInt64, sum(number):UInt64\
+            \n  AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[count(distinct number), sum(number)]]\
+            \n    AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[count(distinct number), sum(number)]]\
+            \n      Expression: (number % 3):UInt8, number:UInt64 (Before GroupBy)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Fewer rows than the threshold",
+            min_rows: 10,
+            expect: "\
+            Projection: count(distinct number):UInt64, sum(number):UInt64\
+            \n  AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[count(distinct number), sum(number)]]\
+            \n    AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[count(distinct number), sum(number)]]\
+            \n      Expression: (number % 3):UInt8, number:UInt64 (Before GroupBy)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "More rows than the threshold",
+            min_rows: 5,
+            expect: "\
+            Projection: count(distinct number):UInt64, sum(number):UInt64\
+            \n  Projection: count(distinct number):UInt64, sum(number):UInt64, (number % 3):UInt8\
+            \n    Expression: approx_count_distinct(number) as count(distinct number):UInt64 (Approximate COUNT DISTINCT)\
+            \n      AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[approx_count_distinct(number), sum(number)]]\
+            \n        AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[approx_count_distinct(number), sum(number)]]\
+            \n          Expression: (number % 3):UInt8, number:UInt64 (Before GroupBy)\
+            \n            ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context()?;
+        ctx.get_settings()
+            .set_approx_count_distinct_min_rows(test.min_rows)?;
+
+        let plan = crate::tests::parse_query(query, &ctx)?;
+        let mut optimizer = ApproxCountDistinctOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan)?;
+        let actual = format!("{:?}", optimized);
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::optimizers::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_distinct_aggregate_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "Distinct aggregates of one argument",
+            query: "select count(distinct number), sum(distinct number) from numbers(10) group by number % 3",
+            expect: "\
+            Projection: count(distinct number):UInt64, sum(distinct number):UInt64\
+            \n  Projection: count(number) as count(distinct number):UInt64, sum(number) as sum(distinct number):UInt64, (number % 3):UInt8\
+            \n    AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[count(number), sum(number)]]\
+            \n      AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[count(number), sum(number)]]\
+            \n        AggregatorFinal: groupBy=[[(number % 3), number]], aggr=[[]]\
+            \n          AggregatorPartial: groupBy=[[(number % 3), number]], aggr=[[]]\
+            \n            Expression: (number % 3):UInt8, number:UInt64 (Before GroupBy)\
+            \n              ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Distinct aggregates of different arguments",
+            query: "select count(distinct number), sum(distinct number + 1) from numbers(10)",
+            expect: "\
+            Projection: count(distinct number):UInt64, sum(distinct (number + 1)):UInt64\
+            \n  AggregatorFinal: groupBy=[[]], aggr=[[count(distinct number), sum(distinct (number + 1))]]\
+            \n    AggregatorPartial: groupBy=[[]], aggr=[[count(distinct number), sum(distinct (number + 1))]]\
+            \n      Expression: number:UInt64, (number + 1):UInt64 (Before GroupBy)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Distinct aggregate mixed with non-distinct aggregate",
+            query: "select count(distinct number), count() from numbers(10)",
+            expect: "\
+            Projection: count(distinct number):UInt64, count():UInt64\
+            \n  AggregatorFinal: groupBy=[[]], aggr=[[count(distinct number), count()]]\
+            \n    AggregatorPartial: groupBy=[[]], aggr=[[count(distinct number), count()]]\
+            \n      Expression: number:UInt64 (Before GroupBy)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context()?;
+
+        let plan = crate::tests::parse_query(test.query, &ctx)?;
+        let mut optimizer = DistinctAggregateOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan)?;
+        let actual = format!("{:?}", optimized);
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_distinct_aggregate_optimizer_disabled() -> Result<()> {
+    let query = "select count(distinct number) from numbers(10)";
+    let ctx = crate::tests::create_query_context()?;
+    ctx.get_settings()
+        .set_enable_distinct_aggregate_rewrite(0)?;
+
+    let plan = crate::tests::parse_query(query, &ctx)?;
+    let mut optimizer = DistinctAggregateOptimizer::create(ctx);
+    let optimized = optimizer.optimize(&plan)?;
+    assert_eq!(format!("{:?}", plan), format!("{:?}", optimized));
+    Ok(())
+}
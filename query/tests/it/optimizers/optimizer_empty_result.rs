@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_planners::PlanNode;
+use databend_query::optimizers::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_empty_result_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        empty: bool,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "Filter with literal false",
+            query: "select number from numbers_mt(10) where 1 + 2 = 2",
+            empty: true,
+        },
+        Test {
+            name: "Limit with zero",
+            query: "select number from numbers_mt(10) order by number limit 0",
+            empty: true,
+        },
+        Test {
+            name: "Group by with having literal false",
+            query: "select avg(number) from numbers_mt(100) group by number % 10 having 1 + 1 = 3",
+            empty: true,
+        },
+        Test {
+            name: "Aggregation without group by returns one row",
+            query: "select count() from numbers_mt(10) where 1 = 2",
+            empty: false,
+        },
+        Test {
+            name: "Filter without literal false",
+            query: "select number from numbers_mt(10) where number > 20",
+            empty: false,
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context()?;
+
+        let plan = match crate::tests::parse_query(test.query, &ctx)? {
+            PlanNode::Select(plan) => plan.input.as_ref().clone(),
+            other => other,
+        };
+        let plan = Optimizers::without_scatters(ctx.clone()).optimize(&plan)?;
+        let optimized = EmptyResultOptimizer::create(ctx).optimize(&plan)?;
+        match optimized {
+            PlanNode::Empty(empty) => {
+                assert!(test.empty, "{:#?}", test.name);
+                assert_eq!(plan.schema(), empty.schema(), "{:#?}", test.name);
+            }
+            other => {
+                assert!(!test.empty, "{:#?}", test.name);
+                assert_eq!(format!("{:?}", plan), format!("{:?}", other));
+            }
+        }
+    }
+    Ok(())
+}
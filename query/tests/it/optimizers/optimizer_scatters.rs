@@ -159,8 +159,9 @@ async fn test_scatter_optimizer() -> Result<()> {
             Projection: number:UInt64\
             \n  Filter: exists(subquery(_subquery_1))\
             \n    Create sub queries sets: [_subquery_1]\
-            \n      Projection: number:UInt64\
-            \n        ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
+            \n      Limit: 1\
+            \n        Projection: number:UInt64\
+            \n          ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
             \n      ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
         },
         Test {
@@ -169,9 +170,10 @@ async fn test_scatter_optimizer() -> Result<()> {
             expect: "Projection: number:UInt64\
             \n  Filter: exists(subquery(_subquery_1))\
             \n    Create sub queries sets: [_subquery_1]\
-            \n      RedistributeStage[expr: 0]\
-            \n        Projection: number:UInt64\
-            \n          ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
+            \n      Limit: 1\
+            \n        RedistributeStage[expr: 0]\
+            \n          Projection: number:UInt64\
+            \n            ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
             \n      ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
         },
         Test {
@@ -183,8 +185,9 @@ async fn test_scatter_optimizer() -> Result<()> {
             \n    Filter: exists(subquery(_subquery_1))\
             \n      Create sub queries sets: [_subquery_1]\
             \n        Broadcast in cluster\
-            \n          Projection: number:UInt64\
-            \n            ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
+            \n          Limit: 1\
+            \n            Projection: number:UInt64\
+            \n              ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
             \n        ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
         },
         Test {
@@ -196,8 +199,10 @@ async fn test_scatter_optimizer() -> Result<()> {
             \n    Filter: exists(subquery(_subquery_1))\
             \n      Create sub queries sets: [_subquery_1]\
             \n        Broadcast in cluster\
-            \n          Projection: number:UInt64\
-            \n            ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
+            \n          Limit: 1\
+            \n            RedistributeStage[expr: 0]\
+            \n              Projection: number:UInt64\
+            \n                ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
             \n        ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
         },
     ];
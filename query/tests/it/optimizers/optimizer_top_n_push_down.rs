@@ -36,6 +36,29 @@ fn test_simple() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_simple_with_distinct() -> Result<()> {
+    let query = "select distinct number from numbers(1000) order by number limit 10;";
+    let ctx = crate::tests::create_query_context()?;
+
+    let plan = crate::tests::parse_query(query, &ctx)?;
+
+    let mut optimizer = TopNPushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+
+    // The top 10 rows may be duplicated, the limit is not pushed down.
+    let expect = "\
+    Limit: 10\
+    \n  Distinct\
+    \n    Projection: number:UInt64\
+    \n      Sort: number:UInt64\
+    \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000], push_downs: [projections: [0]]";
+
+    let actual = format!("{:?}", plan_node);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
 #[test]
 fn test_simple_with_offset() -> Result<()> {
     let query = "select number from numbers(1000) order by number limit 10 offset 5;";
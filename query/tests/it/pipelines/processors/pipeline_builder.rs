@@ -155,8 +155,7 @@ async fn test_local_pipeline_builds() -> Result<()> {
             \n    SortMergeTransform × 1 processor\
             \n      Merge (SortMergeTransform × 8 processors) to (SortMergeTransform × 1)\
             \n        SortMergeTransform × 8 processors\
-            \n          SortPartialTransform × 8 processors\
-            \n            SourceTransform × 8 processors",
+            \n          SourceTransform × 8 processors",
 
             block: vec![
                 "+--------+",
@@ -170,6 +169,29 @@ async fn test_local_pipeline_builds() -> Result<()> {
                 "+--------+",
             ]
         },
+        Test {
+            name: "select-order-by-sorted-partition-pass",
+            query: "select number from numbers_mt(3) order by number",
+
+            plan: "\
+            Projection: number:UInt64\
+            \n  Sort: number:UInt64\
+            \n    ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 3, read_bytes: 24], push_downs: [projections: [0]]",
+
+            pipeline: "\
+            ProjectionTransform × 1 processor\
+            \n  SourceTransform × 1 processor",
+
+            block: vec![
+                "+--------+",
+                "| number |",
+                "+--------+",
+                "| 0      |",
+                "| 1      |",
+                "| 2      |",
+                "+--------+",
+            ]
+        },
     ];
 
     let ctx = crate::tests::create_query_context()?;
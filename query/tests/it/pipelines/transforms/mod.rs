@@ -14,10 +14,12 @@
 
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
+mod transform_block_rechunk;
 mod transform_expression;
 mod transform_filter;
 mod transform_group_by_final;
 mod transform_group_by_partial;
+mod transform_hash_join;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
@@ -91,3 +91,220 @@ async fn test_transform_final_group_by() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_pass_through() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    // numbers(5) is read in blocks of [0, 1], [2, 3] and [4].
+    ctx.get_settings().set_max_block_size(2)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number), avg(number)
+    let aggr_exprs = &[sum(col("number")), avg(col("number"))];
+
+    let group_exprs = &[col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .build()?;
+
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(
+            test_source.number_schema_for_test()?,
+            aggr_exprs,
+            group_exprs,
+        )?
+        .build()?;
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(5)?;
+    let source_schema = test_source.number_schema_for_test()?;
+    pipeline.add_source(Arc::new(source))?;
+    // Every row is a group, the blocks after the first one are passed through.
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(
+            GroupByPartialTransform::create(
+                aggr_partial.schema(),
+                source_schema.clone(),
+                aggr_exprs.to_vec(),
+                group_exprs.to_vec(),
+            )
+            .with_pass_through(2, 90),
+        ))
+    })?;
+    pipeline.merge_processor()?;
+
+    let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByFinalTransform::create(
+            aggr_final.schema(),
+            max_block_size,
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    // SELECT SUM(number), AVG(number), number from numbers(5) group by number;
+    let expected = vec![
+        "+-------------+-------------+--------+",
+        "| sum(number) | avg(number) | number |",
+        "+-------------+-------------+--------+",
+        "| 0           | 0           | 0      |",
+        "| 1           | 1           | 1      |",
+        "| 2           | 2           | 2      |",
+        "| 3           | 3           | 3      |",
+        "| 4           | 4           | 4      |",
+        "+-------------+-------------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_scatter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number), avg(number)
+    let aggr_exprs = &[sum(col("number")), avg(col("number"))];
+
+    let group_exprs = &[col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .build()?;
+
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(
+            test_source.number_schema_for_test()?,
+            aggr_exprs,
+            group_exprs,
+        )?
+        .build()?;
+
+    // Every number is read twice, by either of the sources.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source_schema = test_source.number_schema_for_test()?;
+    pipeline.add_source(Arc::new(test_source.number_source_transform_for_test(5)?))?;
+    pipeline.add_source(Arc::new(test_source.number_source_transform_for_test(5)?))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByPartialTransform::create(
+            aggr_partial.schema(),
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+    // The states of a group are merged by one of the final transforms.
+    let scatter = GroupByFinalTransform::scatter_by_group_key(aggr_exprs.len());
+    pipeline.scatter_processor(3, scatter)?;
+
+    let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByFinalTransform::create(
+            aggr_final.schema(),
+            max_block_size,
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+    assert_eq!(pipeline.nums(), 3);
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+-------------+-------------+--------+",
+        "| sum(number) | avg(number) | number |",
+        "+-------------+-------------+--------+",
+        "| 0           | 0           | 0      |",
+        "| 2           | 1           | 1      |",
+        "| 4           | 2           | 2      |",
+        "| 6           | 3           | 3      |",
+        "| 8           | 4           | 4      |",
+        "+-------------+-------------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_spill() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    // numbers(5) is read in blocks of [0, 1], [2, 3] and [4].
+    ctx.get_settings().set_max_block_size(2)?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number), avg(number)
+    let aggr_exprs = &[sum(col("number")), avg(col("number"))];
+
+    let group_exprs = &[col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .build()?;
+
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(
+            test_source.number_schema_for_test()?,
+            aggr_exprs,
+            group_exprs,
+        )?
+        .build()?;
+
+    // Every number is read twice, by either of the sources.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source_schema = test_source.number_schema_for_test()?;
+    pipeline.add_source(Arc::new(test_source.number_source_transform_for_test(5)?))?;
+    pipeline.add_source(Arc::new(test_source.number_source_transform_for_test(5)?))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByPartialTransform::create(
+            aggr_partial.schema(),
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+    pipeline.merge_processor()?;
+
+    // The groups are spilled after the first block, and merged from the spilled files.
+    let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+    let spill_dir = ctx.get_spill_dir()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(
+            GroupByFinalTransform::create(
+                aggr_final.schema(),
+                max_block_size,
+                source_schema.clone(),
+                aggr_exprs.to_vec(),
+                group_exprs.to_vec(),
+            )
+            .with_spill(1, spill_dir.clone()),
+        ))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+-------------+-------------+--------+",
+        "| sum(number) | avg(number) | number |",
+        "+-------------+-------------+--------+",
+        "| 0           | 0           | 0      |",
+        "| 2           | 1           | 1      |",
+        "| 4           | 2           | 2      |",
+        "| 6           | 3           | 3      |",
+        "| 8           | 4           | 4      |",
+        "+-------------+-------------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::pipelines::processors::*;
+use databend_query::sessions::QueryContext;
+use futures::TryStreamExt;
+
+// a in [0, 4) joined to b in [2, 6).
+fn join_plan(ctx: Arc<QueryContext>, join_type: JoinType) -> Result<PlanNode> {
+    let test_source = crate::tests::NumberTestData::create(ctx);
+    let left = PlanNode::ReadSource(test_source.number_read_source_plan_for_test(4)?);
+    let left = PlanBuilder::from(&left)
+        .project(&[col("number").alias("a")])?
+        .build()?;
+
+    let right = PlanNode::ReadSource(test_source.number_read_source_plan_for_test(6)?);
+    let right = PlanBuilder::from(&right)
+        .filter(col("number").gt_eq(lit(2u64)))?
+        .project(&[col("number").alias("b")])?
+        .build()?;
+
+    PlanBuilder::from(&left)
+        .join(&right, join_type, &[col("a")], &[col("b")])?
+        .build()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join() -> Result<()> {
+    let tests = vec![
+        (JoinType::Inner, vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 2 | 2 |",
+            "| 3 | 3 |",
+            "+---+---+",
+        ]),
+        (JoinType::Left, vec![
+            "+---+------+",
+            "| a | b    |",
+            "+---+------+",
+            "| 0 | NULL |",
+            "| 1 | NULL |",
+            "| 2 | 2    |",
+            "| 3 | 3    |",
+            "+---+------+",
+        ]),
+        (JoinType::Right, vec![
+            "+------+---+",
+            "| a    | b |",
+            "+------+---+",
+            "| 2    | 2 |",
+            "| 3    | 3 |",
+            "| NULL | 4 |",
+            "| NULL | 5 |",
+            "+------+---+",
+        ]),
+        (JoinType::Full, vec![
+            "+------+------+",
+            "| a    | b    |",
+            "+------+------+",
+            "| 0    | NULL |",
+            "| 1    | NULL |",
+            "| 2    | 2    |",
+            "| 3    | 3    |",
+            "| NULL | 4    |",
+            "| NULL | 5    |",
+            "+------+------+",
+        ]),
+    ];
+
+    for (join_type, expected) in tests {
+        let ctx = crate::tests::create_query_context()?;
+        let plan = join_plan(ctx.clone(), join_type)?;
+        let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+        let stream = pipeline.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join_spill() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    // The numbers are read in blocks of 2 rows, both sides are spilled after the first one.
+    ctx.get_settings().set_max_block_size(2)?;
+    ctx.get_settings().set_join_max_memory_bytes(1)?;
+
+    let plan = join_plan(ctx.clone(), JoinType::Full)?;
+    let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+------+------+",
+        "| a    | b    |",
+        "+------+------+",
+        "| 0    | NULL |",
+        "| 1    | NULL |",
+        "| 2    | 2    |",
+        "| 3    | 3    |",
+        "| NULL | 4    |",
+        "| NULL | 5    |",
+        "+------+------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_transform_hash_join_parallel_probe() -> Result<()> {
+    let expected = vec![
+        "+------+------+",
+        "| a    | b    |",
+        "+------+------+",
+        "| 0    | NULL |",
+        "| 1    | NULL |",
+        "| 2    | 2    |",
+        "| 3    | 3    |",
+        "| NULL | 4    |",
+        "| NULL | 5    |",
+        "+------+------+",
+    ];
+
+    // In memory, then spilled.
+    for max_memory_bytes in [0, 1] {
+        let ctx = crate::tests::create_query_context()?;
+        ctx.get_settings().set_max_threads(4)?;
+        ctx.get_settings().set_max_block_size(2)?;
+        ctx.get_settings()
+            .set_join_max_memory_bytes(max_memory_bytes)?;
+
+        let plan = join_plan(ctx.clone(), JoinType::Full)?;
+        let mut pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+        // The probe side is not merged, each of its processors probes the same built table.
+        assert!(pipeline.nums() > 1);
+
+        let stream = pipeline.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        // The unmatched rows of the build side are returned once, by the last processor.
+        common_datablocks::assert_blocks_sorted_eq(expected.clone(), result.as_slice());
+    }
+    Ok(())
+}
@@ -89,3 +89,44 @@ async fn test_transform_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_sort_merge_limit() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // Pipeline.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let a = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(a))?;
+    pipeline.merge_processor()?;
+
+    // The top rows are merged as the blocks arrive.
+    let sort_expression = &[sort("number", false, false)];
+    let plan = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .sort(sort_expression)?
+        .build()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortMergeTransform::try_create(
+            plan.schema(),
+            sort_expression.to_vec(),
+            Some(3),
+        )?))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+--------+",
+        "| number |",
+        "+--------+",
+        "| 7      |",
+        "| 6      |",
+        "| 5      |",
+        "+--------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -91,6 +91,59 @@ async fn test_clickhouse_insert_data() -> Result<()> {
     Ok(())
 }
 
+#[derive(Debug)]
+struct NullableTemp {
+    a: u64,
+    b: Option<i64>,
+    c: Option<String>,
+    d: u8,
+}
+
+impl clickhouse_driver::prelude::Deserialize for NullableTemp {
+    fn deserialize(row: Row) -> errors::Result<Self> {
+        let a = row.value(0).unwrap().unwrap();
+        let b = row.value(1).unwrap();
+        let c: Option<&str> = row.value(2).unwrap();
+        let d = row.value(3).unwrap().unwrap();
+        Ok(NullableTemp {
+            a,
+            b,
+            c: c.map(|c| c.to_string()),
+            d,
+        })
+    }
+}
+
+// The result columns are written from their arrow arrays, with the null maps of the nullable ones.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_clickhouse_query_nullable_data() -> Result<()> {
+    let (_, listening) = start_server(1).await?;
+    let mut conn = create_conn(listening.port()).await?;
+
+    let query_str =
+        "CREATE TABLE nullable_test(a UInt64 not null, b Int64 null, c String null) Engine = Memory";
+    execute(&mut conn, query_str).await?;
+    let query_str = "INSERT INTO nullable_test VALUES (1, NULL, 'x'), (2, 22, NULL), (3, 33, 'z')";
+    execute(&mut conn, query_str).await?;
+
+    let query_str = "SELECT a, b, c, a % 2 = 1 FROM nullable_test ORDER BY a";
+    let datas = query::<NullableTemp>(&mut conn, query_str).await?;
+    assert_eq!(datas.len(), 3);
+    assert_eq!(
+        (datas[0].a, datas[0].b, datas[0].c.as_deref(), datas[0].d),
+        (1, None, Some("x"), 1)
+    );
+    assert_eq!(
+        (datas[1].a, datas[1].b, datas[1].c.as_deref(), datas[1].d),
+        (2, Some(22), None, 0)
+    );
+    assert_eq!(
+        (datas[2].a, datas[2].b, datas[2].c.as_deref(), datas[2].d),
+        (3, Some(33), Some("z"), 1)
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[ignore]
 async fn test_clickhouse_insert_to_fuse_table() -> Result<()> {
@@ -44,7 +44,7 @@ fn test_data_block(is_nullable: bool) -> Result<()> {
             .cast_with_type(&DataType::Date16)
             .unwrap(),
     ]);
-    let json_block = block_to_json(&block)?;
+    let json_block = block_to_json(&block, &DateTimeOutput::default())?;
     let expect = vec![
         vec![val(1), val("a"), val(true), val(1.1), val("1970-01-02")],
         vec![val(2), val("b"), val(true), val(2.2), val("1970-01-03")],
@@ -64,3 +64,35 @@ fn test_data_block_nullable() -> Result<()> {
 fn test_data_block_not_nullable() -> Result<()> {
     test_data_block(false)
 }
+
+#[test]
+fn test_data_block_datetime_output() -> Result<()> {
+    for is_nullable in [true, false] {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("c1", DataType::DateTime32(None), is_nullable),
+            DataField::new(
+                "c2",
+                DataType::DateTime32(Some("Asia/Tokyo".to_string())),
+                is_nullable,
+            ),
+        ]);
+        let block = DataBlock::create_by_array(schema, vec![
+            Series::new(vec![1630320462u32]),
+            Series::new(vec![1630320462u32]),
+        ]);
+
+        let shanghai = DateTimeOutput::try_create("Asia/Shanghai", "time_zone")?;
+        let json_block = block_to_json(&block, &shanghai)?;
+        let expect = vec![vec![val("2021-08-30 18:47:42"), val("2021-08-30 19:47:42")]];
+        assert_eq!(json_block, expect);
+
+        let json_block = block_to_json(&block, &DateTimeOutput::Utc)?;
+        let expect = vec![vec![val("2021-08-30 10:47:42"), val("2021-08-30 10:47:42")]];
+        assert_eq!(json_block, expect);
+
+        let json_block = block_to_json(&block, &DateTimeOutput::Epoch)?;
+        let expect = vec![vec![val(1630320462u32), val(1630320462u32)]];
+        assert_eq!(json_block, expect);
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::servers::http::v1::download;
+use databend_query::servers::http::HTTPSessionMiddleware;
+use poem::http::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::post;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_download() -> Result<()> {
+    let sql = "select number, number / 2 as half, if(number = 1, null, 'x') as s from numbers(2)";
+    {
+        let (status, body) = download_sql(sql, "").await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, b"0,0,x\n1,0.5,\n".to_vec());
+    }
+    {
+        let query = "?csv_header=1&bom=1&line_ending=crlf&field_delimitor=;&decimal_separator=,&null_display=NULL";
+        let (status, body) = download_sql(sql, query).await?;
+        assert_eq!(status, StatusCode::OK);
+        let expected = [
+            &[0xEF, 0xBB, 0xBF][..],
+            b"number;half;s\r\n0;0;x\r\n1;0,5;NULL\r\n",
+        ]
+        .concat();
+        assert_eq!(body, expected);
+    }
+    {
+        let (status, _) = download_sql(sql, "?format=parquet").await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+    {
+        let (status, _) = download_sql("bad sql", "").await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+    Ok(())
+}
+
+async fn download_sql(sql: &'static str, query: &str) -> Result<(StatusCode, Vec<u8>)> {
+    let path = "/v1/download";
+    let sessions = SessionManagerBuilder::create().build()?;
+    let router = Route::new()
+        .at(path, post(download))
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
+    let uri = format!("{}{}", path, query);
+    let response = router
+        .call(
+            Request::builder()
+                .uri(uri.parse().unwrap())
+                .method(Method::POST)
+                .header(header::AUTHORIZATION, "Basic cm9vdDo=")
+                .body(sql),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().into_vec().await.unwrap();
+    Ok((status, body))
+}
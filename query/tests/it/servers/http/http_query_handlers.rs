@@ -18,15 +18,28 @@ use std::sync::Arc;
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::PasswordType;
+use databend_query::common::service::HttpShutdownHandler;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::ColumnStats;
 use databend_query::servers::http::v1::ExecuteStateName;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::HTTPSessionEndpoint;
+use databend_query::servers::http::HTTPSessionMiddleware;
 use databend_query::servers::HttpHandler;
+use databend_query::servers::Server;
 use databend_query::sessions::SessionManager;
+use databend_query::users::auth::CustomClaims;
+use databend_query::users::User;
 use hyper::header;
+use jwt_simple::prelude::Claims;
+use jwt_simple::prelude::RS256KeyPair;
+use jwt_simple::prelude::RSAKeyPairLike;
+use poem::get;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::middleware::AddDataEndpoint;
@@ -59,7 +72,10 @@ pub fn get_page_uri(query_id: &str, page_no: usize, wait_time: u32) -> String {
     )
 }
 
-type RouteWithData = AddDataEndpoint<Route, Arc<SessionManager>>;
+type RouteWithData = AddDataEndpoint<HTTPSessionEndpoint<Route>, Arc<SessionManager>>;
+
+// root:
+const ROOT_AUTH: &str = "Basic cm9vdDo=";
 
 #[tokio::test]
 async fn test_simple_sql() -> Result<()> {
@@ -91,7 +107,10 @@ async fn test_bad_sql() -> Result<()> {
 #[tokio::test]
 async fn test_async() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
-    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+    let route = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
     let sql = "select sleep(2)";
     let json = serde_json::json!({"sql": sql.to_string()});
 
@@ -148,10 +167,56 @@ async fn test_async() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_column_stats() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let route = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
+
+    for sql in [
+        "create table t(a int, b varchar) engine=fuse",
+        "insert into t(a, b) values (3, 'x'), (null, 'z'), (-1, null), (null, 'y')",
+    ] {
+        let json = serde_json::json!({"sql": sql.to_string()});
+        let (_, result) = post_json_to_router(&route, &json, 3).await?;
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert!(result.column_stats.is_none());
+    }
+
+    let json = serde_json::json!({"sql": "select * from t", "column_stats": true});
+    let (status, result) = post_json_to_router(&route, &json, 3).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.data.len(), 4);
+    assert_eq!(
+        result.column_stats,
+        Some(vec![
+            ColumnStats {
+                name: "a".to_string(),
+                min: serde_json::json!(-1),
+                max: serde_json::json!(3),
+                null_count: 2,
+            },
+            ColumnStats {
+                name: "b".to_string(),
+                min: serde_json::json!("x"),
+                max: serde_json::json!("z"),
+                null_count: 1,
+            },
+        ])
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_page() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
-    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+    let route = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
 
     let max_block_size = 10000;
     let num_parts = num_cpus::get();
@@ -185,7 +250,10 @@ async fn test_multi_page() -> Result<()> {
 #[tokio::test]
 async fn test_insert() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
-    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+    let route = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
 
     let sqls = vec![
         ("create table t(a int) engine=fuse", 0),
@@ -247,7 +315,10 @@ async fn post_sql(sql: &'static str, wait_time: i32) -> Result<(StatusCode, Quer
 
 pub fn create_router() -> RouteWithData {
     let sessions = SessionManagerBuilder::create().build().unwrap();
-    Route::new().nest("/v1/query", query_route()).data(sessions)
+    Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware)
+        .data(sessions)
 }
 
 async fn post_json(
@@ -274,6 +345,7 @@ async fn post_json_to_router(
                 .uri(uri.parse().unwrap())
                 .method(Method::POST)
                 .header(header::CONTENT_TYPE, content_type)
+                .header(header::AUTHORIZATION, ROOT_AUTH)
                 .body(body),
         )
         .await
@@ -282,6 +354,188 @@ async fn post_json_to_router(
     check_response(response).await
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_jwt() -> Result<()> {
+    let kid = "test_kid";
+    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+    let rsa_components = key_pair.public_key().to_components();
+    let e = base64::encode_config(rsa_components.e, base64::URL_SAFE_NO_PAD);
+    let n = base64::encode_config(rsa_components.n, base64::URL_SAFE_NO_PAD);
+    let jwks = serde_json::json!({"keys": [{"kty": "RSA", "kid": kid, "e": e, "n": n}]});
+
+    let mut jwks_srv = HttpShutdownHandler::create("jwks".to_string());
+    let jwks_route = Route::new().at(
+        "/jwks.json",
+        get(poem::endpoint::make_sync(move |_| jwks.to_string())),
+    );
+    let jwks_addr = jwks_srv
+        .start_service("127.0.0.1:0".parse()?, None, jwks_route)
+        .await?;
+
+    let sessions = SessionManagerBuilder::create()
+        .jwt_key_url(format!("http://{}/jwks.json", jwks_addr))
+        .build()?;
+    let route = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
+
+    let post_with_token = |token: String| {
+        let body = serde_json::json!({"sql": "select current_user()"}).to_string();
+        Request::builder()
+            .uri("/v1/query".parse().unwrap())
+            .method(Method::POST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(body)
+    };
+
+    // signed by the key in JWKS
+    {
+        let claims = Claims::with_custom_claims(
+            CustomClaims::new().with_roles(vec!["analyst".to_string()]),
+            jwt_simple::prelude::Duration::from_hours(2),
+        )
+        .with_subject("root");
+        let token = key_pair.sign(claims)?;
+        let response = route.call(post_with_token(token)).await.unwrap();
+        let (status, result) = check_response(response).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert_eq!(result.state, ExecuteStateName::Succeeded);
+        assert_eq!(result.data.len(), 1);
+    }
+
+    // without subject
+    {
+        let claims = Claims::with_custom_claims(
+            CustomClaims::new(),
+            jwt_simple::prelude::Duration::from_hours(2),
+        );
+        let token = key_pair.sign(claims)?;
+        let response = route.call(post_with_token(token)).await.unwrap();
+        let (_, result) = check_response(response).await?;
+        let error = result.error.unwrap();
+        assert_eq!(error.message, "Invalid jwt: missing field `sub`");
+    }
+
+    // signed by an unknown key
+    {
+        let other_key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+        let claims = Claims::with_custom_claims(
+            CustomClaims::new(),
+            jwt_simple::prelude::Duration::from_hours(2),
+        )
+        .with_subject("root");
+        let token = other_key_pair.sign(claims)?;
+        let response = route.call(post_with_token(token)).await.unwrap();
+        let (_, result) = check_response(response).await?;
+        assert!(result.error.is_some());
+        assert_eq!(result.state, ExecuteStateName::Failed);
+    }
+
+    // malformed authorization header
+    {
+        let request = Request::builder()
+            .uri("/v1/query".parse().unwrap())
+            .method(Method::POST)
+            .header(header::AUTHORIZATION, "Unknown xxx")
+            .body("{}");
+        let response = route
+            .call(request)
+            .await
+            .unwrap_or_else(|err| err.as_response());
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    // without authorization header
+    {
+        let body = serde_json::json!({"sql": "select current_user()"}).to_string();
+        let request = Request::builder()
+            .uri("/v1/query".parse().unwrap())
+            .method(Method::POST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body);
+        let response = route
+            .call(request)
+            .await
+            .unwrap_or_else(|err| err.as_response());
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    jwks_srv.shutdown(true).await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_network_policy() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let user_mgr = sessions.get_user_manager();
+    user_mgr
+        .add_network_policy(NetworkPolicy::new(
+            "np_local",
+            vec!["127.0.0.0/8".to_string()],
+            vec![],
+            "",
+        ))
+        .await?;
+    user_mgr
+        .add_network_policy(NetworkPolicy::new(
+            "np_office",
+            vec!["192.168.1.0/24".to_string()],
+            vec![],
+            "",
+        ))
+        .await?;
+    for (name, policy) in [("local", "np_local"), ("office", "np_office")] {
+        let user = User::new(name, "%", name, PasswordType::PlainText);
+        user_mgr.add_user(user.into()).await?;
+        user_mgr
+            .set_user_network_policy(name, "%", Some(policy.to_string()))
+            .await?;
+    }
+
+    let mut srv = HttpHandler::create(sessions);
+    let listening = srv.start("127.0.0.1:0".parse()?).await?;
+    let url = format!("http://{}/v1/query", listening);
+    let json = serde_json::json!({"sql": "select current_user()"});
+    let client = reqwest::Client::builder().build().unwrap();
+
+    // the peer address 127.0.0.1 is allowed
+    let resp = client
+        .post(&url)
+        .basic_auth("local", Some("local"))
+        .json(&json)
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+    let res = resp.json::<QueryResponse>().await.unwrap();
+    assert!(res.error.is_none(), "{:?}", res.error);
+    assert_eq!(res.data.len(), 1);
+
+    // the peer address 127.0.0.1 is not in 192.168.1.0/24
+    let resp = client
+        .post(&url)
+        .basic_auth("office", Some("office"))
+        .json(&json)
+        .send()
+        .await
+        .unwrap();
+    let res = resp.json::<QueryResponse>().await.unwrap();
+    let error = res.error.unwrap();
+    assert!(
+        error
+            .message
+            .contains("is not allowed by network policy np_office"),
+        "{}",
+        error.message
+    );
+
+    srv.shutdown(true).await;
+    Ok(())
+}
+
 // need to support local_addr, but axum_server do not have local_addr callback
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_http_handler_tls_server() -> Result<()> {
@@ -309,7 +563,12 @@ async fn test_http_handler_tls_server() -> Result<()> {
         .add_root_certificate(cert)
         .build()
         .unwrap();
-    let resp = client.post(&url).json(&json).send().await;
+    let resp = client
+        .post(&url)
+        .basic_auth("root", Some(""))
+        .json(&json)
+        .send()
+        .await;
     assert!(resp.is_ok(), "{:?}", resp.err());
     let resp = resp.unwrap();
     assert!(resp.status().is_success());
@@ -339,7 +598,12 @@ async fn test_http_handler_tls_server_failed_case_1() -> Result<()> {
 
     // kick off
     let client = reqwest::Client::builder().build().unwrap();
-    let resp = client.post(&url).json(&json).send().await;
+    let resp = client
+        .post(&url)
+        .basic_auth("root", Some(""))
+        .json(&json)
+        .send()
+        .await;
     assert!(resp.is_err(), "{:?}", resp.err());
     Ok(())
 }
@@ -374,7 +638,12 @@ async fn test_http_service_tls_server_mutual_tls() -> Result<()> {
         .add_root_certificate(cert)
         .build()
         .expect("preconfigured rustls tls");
-    let resp = client.post(&url).json(&json).send().await;
+    let resp = client
+        .post(&url)
+        .basic_auth("root", Some(""))
+        .json(&json)
+        .send()
+        .await;
     assert!(resp.is_ok(), "{:?}", resp.err());
     let resp = resp.unwrap();
     assert!(resp.status().is_success());
@@ -411,7 +680,12 @@ async fn test_http_service_tls_server_mutual_tls_failed() -> Result<()> {
         .add_root_certificate(cert)
         .build()
         .expect("preconfigured rustls tls");
-    let resp = client.post(&url).json(&json).send().await;
+    let resp = client
+        .post(&url)
+        .basic_auth("root", Some(""))
+        .json(&json)
+        .send()
+        .await;
     assert!(resp.is_err(), "{:?}", resp.err());
     Ok(())
 }
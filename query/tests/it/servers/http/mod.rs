@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod block_to_json;
+mod download;
 mod http_query_handlers;
 mod statement;
+mod upload_to_stage;
@@ -16,6 +16,8 @@ use common_base::tokio;
 use common_exception::Result;
 use databend_query::servers::http::v1::statement_handler;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::HTTPSessionMiddleware;
+use poem::http::header;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::post;
@@ -70,6 +72,7 @@ async fn test_sql(
     let sessions = SessionManagerBuilder::create().build()?;
     let cluster_router = Route::new()
         .at(path, post(statement_handler))
+        .with(HTTPSessionMiddleware)
         .data(sessions);
     let uri = match database {
         Some(db) => format!("{}?db={:}", path, db),
@@ -80,6 +83,7 @@ async fn test_sql(
             Request::builder()
                 .uri(uri.parse().unwrap())
                 .method(Method::POST)
+                .header(header::AUTHORIZATION, "Basic cm9vdDo=")
                 .body(sql),
         )
         .await
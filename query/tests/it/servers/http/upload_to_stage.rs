@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::upload_to_stage;
+use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::UploadToStageResponse;
+use databend_query::servers::http::HTTPSessionMiddleware;
+use hyper::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::put;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use crate::tests::SessionManagerBuilder;
+
+const BOUNDARY: &str = "upload-to-stage-boundary";
+// root:
+const ROOT_AUTH: &str = "Basic cm9vdDo=";
+
+fn multipart_body(files: &[(&str, &str)]) -> String {
+    let mut body = String::new();
+    for (name, content) in files {
+        body.push_str(&format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"upload\"; filename=\"{}\"\r\n\r\n{}\r\n",
+            BOUNDARY, name, content
+        ));
+    }
+    body.push_str(&format!("--{}--\r\n", BOUNDARY));
+    body
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_upload_to_stage() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let data_path = tmp_dir.path().to_str().unwrap().to_string();
+    let sessions = SessionManagerBuilder::create()
+        .disk_storage_path(data_path.clone())
+        .build()?;
+    let route = Route::new()
+        .nest("/v1/query", query_route())
+        .at("/v1/upload_to_stage", put(upload_to_stage))
+        .with(HTTPSessionMiddleware)
+        .data(sessions);
+
+    let json = serde_json::json!({"sql": "create stage s1"});
+    let response = route
+        .call(
+            Request::builder()
+                .uri("/v1/query?wait_time=3".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, ROOT_AUTH)
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<QueryResponse>(&body)?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let content_type = format!("multipart/form-data; boundary={}", BOUNDARY);
+
+    // Upload two files under a relative path.
+    let body = multipart_body(&[("a.csv", "1,2\n"), ("b.csv", "3,4\n")]);
+    let response = route
+        .call(
+            Request::builder()
+                .uri("/v1/upload_to_stage".parse().unwrap())
+                .method(Method::PUT)
+                .header(header::CONTENT_TYPE, content_type.clone())
+                .header(header::AUTHORIZATION, ROOT_AUTH)
+                .header("stage_name", "@s1")
+                .header("relative_path", "/dir/")
+                .body(body),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<UploadToStageResponse>(&body)?;
+    assert_eq!(result.stage_name, "s1");
+    assert_eq!(result.state, "SUCCESS");
+    assert_eq!(result.files, vec!["dir/a.csv", "dir/b.csv"]);
+
+    let stage_dir = Path::new(&data_path).join("stage").join("s1").join("dir");
+    assert_eq!(std::fs::read_to_string(stage_dir.join("a.csv"))?, "1,2\n");
+    assert_eq!(std::fs::read_to_string(stage_dir.join("b.csv"))?, "3,4\n");
+
+    // An unknown stage.
+    let response = route
+        .call(
+            Request::builder()
+                .uri("/v1/upload_to_stage".parse().unwrap())
+                .method(Method::PUT)
+                .header(header::CONTENT_TYPE, content_type)
+                .header(header::AUTHORIZATION, ROOT_AUTH)
+                .header("stage_name", "unknown")
+                .body(multipart_body(&[("a.csv", "1,2\n")])),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
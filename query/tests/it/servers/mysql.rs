@@ -21,6 +21,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use databend_query::servers::MySQLHandler;
+use mysql_async::consts::ColumnFlags;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -45,6 +47,116 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_result_set_column_metadata() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let query = "select dummy, dummy as d, dummy * 1.5 as e, 'x' as s from system.one";
+    let mut result = connection
+        .query_iter(query)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || query)?;
+    let columns = result.columns_ref().to_vec();
+    result
+        .drop_result()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || query)?;
+
+    // the columns read from the table as they are tell the table
+    let metadata = columns
+        .iter()
+        .map(|c| {
+            (
+                c.name_str().to_string(),
+                c.table_str().to_string(),
+                c.column_type(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(metadata, vec![
+        (
+            "dummy".to_string(),
+            "one".to_string(),
+            ColumnType::MYSQL_TYPE_TINY
+        ),
+        (
+            "d".to_string(),
+            "one".to_string(),
+            ColumnType::MYSQL_TYPE_TINY
+        ),
+        (
+            "e".to_string(),
+            "".to_string(),
+            ColumnType::MYSQL_TYPE_DOUBLE
+        ),
+        (
+            "s".to_string(),
+            "".to_string(),
+            ColumnType::MYSQL_TYPE_VAR_STRING
+        ),
+    ]);
+
+    let flags = columns[0].flags();
+    assert!(flags.contains(ColumnFlags::NOT_NULL_FLAG | ColumnFlags::UNSIGNED_FLAG));
+    assert!(flags.contains(ColumnFlags::NUM_FLAG | ColumnFlags::BINARY_FLAG));
+    assert!(columns[2].flags().contains(ColumnFlags::NUM_FLAG));
+    assert!(!columns[2].flags().contains(ColumnFlags::UNSIGNED_FLAG));
+    assert!(!columns[3].flags().contains(ColumnFlags::NUM_FLAG));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_result_set_values() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let query = "SELECT number, CAST(number AS INT8) - 2 AS i, number * 1.5 AS f, 'x' AS s, \
+        number > 1 AS b, NULL AS n, toDate('2021-03-05') AS d, \
+        toDateTime('2021-03-05 01:01:01') AS dt FROM numbers(3)";
+    let rows: Vec<Row> = connection
+        .query(query)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || query)?;
+
+    let values = rows
+        .iter()
+        .map(|row| {
+            (0..row.len())
+                .map(|i| row.get::<Option<String>, _>(i).unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let expect = |number: &str, i: &str, f: &str, b: &str| {
+        vec![
+            Some(number.to_string()),
+            Some(i.to_string()),
+            Some(f.to_string()),
+            Some("x".to_string()),
+            Some(b.to_string()),
+            None,
+            Some("2021-03-05".to_string()),
+            Some("2021-03-05 01:01:01".to_string()),
+        ]
+    };
+    assert_eq!(values, vec![
+        expect("0", "-2", "0", "0"),
+        expect("1", "-1", "1.5", "0"),
+        expect("2", "0", "3", "1"),
+    ]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let mut handler =
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_context_pin_tables() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+    for tbl in ["t1", "t2"] {
+        let qry = format!("create table {}.{} (id int)", db, tbl);
+        execute_command(&qry, ctx.clone()).await?;
+    }
+
+    let tables = vec![
+        (db.clone(), "t1".to_string()),
+        (db.clone(), "t2".to_string()),
+        (db.clone(), "not_exists".to_string()),
+    ];
+    ctx.pin_tables(&tables).await?;
+    let t1 = ctx.get_table(&db, "t1").await?;
+    let t2 = ctx.get_table(&db, "t2").await?;
+
+    // the changes committed later are not seen in the same query
+    let qry = format!("insert into {}.t1 values (1)", db);
+    execute_command(&qry, fixture.ctx()).await?;
+    let catalog = ctx.get_catalog();
+    let latest = catalog.get_table(&db, "t1").await?;
+    assert_ne!(latest.get_table_info().ident, t1.get_table_info().ident);
+    assert_eq!(
+        ctx.get_table(&db, "t1").await?.get_table_info().ident,
+        t1.get_table_info().ident
+    );
+    assert_eq!(
+        ctx.get_table(&db, "t2").await?.get_table_info().ident,
+        t2.get_table_info().ident
+    );
+
+    // the tables failed to resolve are left to get_table
+    assert!(ctx.get_table(&db, "not_exists").await.is_err());
+    Ok(())
+}
@@ -195,6 +195,17 @@ fn test_plan_parser() -> Result<()> {
             \n                  ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0], filters: [(number > 1)]]",
             error: "",
         },
+        Test {
+            name: "select-distinct",
+            sql: "select distinct number % 3 as n from numbers(10) limit 2",
+            expect: "\
+            Limit: 2\
+            \n  Distinct\
+            \n    Projection: (number % 3) as n:UInt8\
+            \n      Expression: (number % 3):UInt8 (Before Projection)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80], push_downs: [projections: [0]]",
+            error: "",
+        },
         Test {
             name: "unimplemented-cte",
             sql: "with t as ( select sum(number) n from numbers_mt(1000) )select * from t",
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use common_exception::Result;
@@ -25,33 +26,70 @@ use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
 use common_planners::Optimization;
+use common_planners::TransactionAction;
+use databend_query::sql::statements::CopyIntoStageSource;
+use databend_query::sql::statements::DfAlterColumnOperation;
+use databend_query::sql::statements::DfAlterOwner;
+use databend_query::sql::statements::DfAlterOwnerObject;
+use databend_query::sql::statements::DfAlterReadOnly;
+use databend_query::sql::statements::DfAlterTableColumn;
+use databend_query::sql::statements::DfAlterTableOptions;
 use databend_query::sql::statements::DfAlterUDF;
 use databend_query::sql::statements::DfAlterUser;
+use databend_query::sql::statements::DfAlterUserNetworkPolicy;
+use databend_query::sql::statements::DfAnalyzeTable;
 use databend_query::sql::statements::DfCopy;
+use databend_query::sql::statements::DfCopyIntoStage;
+use databend_query::sql::statements::DfCreateConnection;
 use databend_query::sql::statements::DfCreateDatabase;
+use databend_query::sql::statements::DfCreateNetworkPolicy;
+use databend_query::sql::statements::DfCreateRole;
+use databend_query::sql::statements::DfCreateRowAccessPolicy;
 use databend_query::sql::statements::DfCreateStage;
+use databend_query::sql::statements::DfCreateStream;
 use databend_query::sql::statements::DfCreateTable;
 use databend_query::sql::statements::DfCreateUDF;
 use databend_query::sql::statements::DfCreateUser;
+use databend_query::sql::statements::DfDeleteStatement;
 use databend_query::sql::statements::DfDescribeTable;
+use databend_query::sql::statements::DfDropConnection;
 use databend_query::sql::statements::DfDropDatabase;
+use databend_query::sql::statements::DfDropNetworkPolicy;
+use databend_query::sql::statements::DfDropRole;
+use databend_query::sql::statements::DfDropRowAccessPolicy;
 use databend_query::sql::statements::DfDropStage;
 use databend_query::sql::statements::DfDropTable;
 use databend_query::sql::statements::DfDropUDF;
 use databend_query::sql::statements::DfDropUser;
+use databend_query::sql::statements::DfExportTable;
 use databend_query::sql::statements::DfGrantObject;
+use databend_query::sql::statements::DfGrantRole;
+use databend_query::sql::statements::DfGrantRolePrivilege;
 use databend_query::sql::statements::DfGrantStatement;
 use databend_query::sql::statements::DfOptimizeTable;
+use databend_query::sql::statements::DfQueryFunction;
 use databend_query::sql::statements::DfQueryStatement;
+use databend_query::sql::statements::DfReadOnlyObject;
+use databend_query::sql::statements::DfRevokeAllStatement;
+use databend_query::sql::statements::DfRevokeRole;
+use databend_query::sql::statements::DfRevokeRolePrivilege;
 use databend_query::sql::statements::DfRevokeStatement;
+use databend_query::sql::statements::DfSetRole;
+use databend_query::sql::statements::DfSetSecondaryRoles;
+use databend_query::sql::statements::DfSetVariable;
 use databend_query::sql::statements::DfShowCreateDatabase;
 use databend_query::sql::statements::DfShowCreateTable;
 use databend_query::sql::statements::DfShowDatabases;
 use databend_query::sql::statements::DfShowGrants;
 use databend_query::sql::statements::DfShowTables;
 use databend_query::sql::statements::DfShowUDF;
+use databend_query::sql::statements::DfTransaction;
 use databend_query::sql::statements::DfTruncateTable;
+use databend_query::sql::statements::DfUndropDatabase;
+use databend_query::sql::statements::DfUndropTable;
 use databend_query::sql::statements::DfUseDatabase;
+use databend_query::sql::statements::DfVacuumDropTable;
+use databend_query::sql::statements::DfVacuumTable;
 use databend_query::sql::*;
 use sqlparser::ast::*;
 use sqlparser::dialect::GenericDialect;
@@ -137,6 +175,18 @@ fn create_database() -> Result<()> {
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "CREATE DATABASE db1 DATA_RETENTION_TIME_IN_DAYS = 7";
+        let expected = DfStatement::CreateDatabase(DfCreateDatabase {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("db1")]),
+            engine: "".to_string(),
+            engine_options: HashMap::new(),
+            options: maplit::hashmap! {"DATA_RETENTION_TIME_IN_DAYS".into() => "7".into()},
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
     {
         let sql = "CREATE DATABASE IF NOT EXISTS db1";
         let expected = DfStatement::CreateDatabase(DfCreateDatabase {
@@ -180,6 +230,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE t(c1 int) ENGINE = Fuse location = '/data/33.csv' ";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", DataType::Int(None))],
         engine: "Fuse".to_string(),
@@ -189,10 +240,35 @@ fn create_table() -> Result<()> {
     });
     expect_parse_ok(sql, expected)?;
 
+    // create or replace table
+    let sql = "CREATE OR REPLACE TABLE t(c1 int) ENGINE = Fuse";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        or_replace: true,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", DataType::Int(None))],
+        engine: "Fuse".to_string(),
+        options: maplit::hashmap! {},
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    expect_parse_err_contains(
+        "CREATE OR REPLACE TABLE IF NOT EXISTS t(c1 int)",
+        "OR REPLACE and IF NOT EXISTS cannot be used together".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE OR REPLACE DATABASE db1",
+        "Expected TABLE, FUNCTION or STAGE after OR REPLACE".to_string(),
+    )?;
+
     // positive case: it is ok for parquet files not to have columns specified
     let sql = "CREATE TABLE t(c1 int, c2 bigint, c3 varchar(255) ) ENGINE = Fuse location = 'foo.parquet' comment = 'foo'";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![
             make_column_def("c1", DataType::Int(None)),
@@ -214,6 +290,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE db1.test1 LIKE db2.test2 ENGINE = Parquet location = 'batcave'";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
         columns: vec![],
         engine: "Parquet".to_string(),
@@ -228,6 +305,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE db1.test1(c1 int, c2 varchar(255)) ENGINE = Parquet location = 'batcave' AS SELECT * FROM t2";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
         columns: vec![
             make_column_def("c1", DataType::Int(None)),
@@ -238,6 +316,7 @@ fn create_table() -> Result<()> {
         options: maplit::hashmap! {"location".into() => "batcave".into()},
         like: None,
         query: Some(Box::new(DfQueryStatement {
+            distinct: false,
             from: vec![TableWithJoins {
                 relation: TableFactor::Table {
                     name: ObjectName(vec![Ident::new("t2")]),
@@ -254,6 +333,7 @@ fn create_table() -> Result<()> {
             order_by: vec![],
             limit: None,
             offset: None,
+            functions: vec![],
         })),
     });
     expect_parse_ok(sql, expected)?;
@@ -284,6 +364,174 @@ fn drop_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn undrop_test() -> Result<()> {
+    {
+        let sql = "UNDROP TABLE db1.t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "undrop database db1";
+        let expected = DfStatement::UndropDatabase(DfUndropDatabase {
+            name: ObjectName(vec![Ident::new("db1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UNDROP STAGE s1";
+        expect_parse_err_contains(
+            sql,
+            "Expected one of DATABASE, TABLE, found: STAGE".to_string(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn vacuum_drop_table_test() -> Result<()> {
+    {
+        let sql = "VACUUM DROP TABLE";
+        let expected = DfStatement::VacuumDropTable(DfVacuumDropTable { retain_hours: None });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "vacuum drop table retain 12 hours";
+        let expected = DfStatement::VacuumDropTable(DfVacuumDropTable {
+            retain_hours: Some(12),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "VACUUM DROP TABLE RETAIN 12";
+        expect_parse_err_contains(sql, "Expected HOURS, found: EOF".to_string())?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn vacuum_table_test() -> Result<()> {
+    {
+        let sql = "VACUUM TABLE t1";
+        let expected = DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            retain_hours: None,
+            dry_run: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "vacuum table db1.t1 retain 0 hours dry run";
+        let expected = DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            retain_hours: Some(0),
+            dry_run: true,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "VACUUM TABLE t1 DRY";
+        expect_parse_err_contains(sql, "Expected RUN, found: EOF".to_string())?;
+    }
+
+    {
+        let sql = "VACUUM t1";
+        expect_parse_err_contains(sql, "Expected one of DROP, TABLE, found: t1".to_string())?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn analyze_table_test() -> Result<()> {
+    {
+        let sql = "ANALYZE TABLE db1.t1";
+        let expected = DfStatement::AnalyzeTable(DfAnalyzeTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ANALYZE t1";
+        expect_parse_err_contains(sql, "Expected TABLE, found: t1".to_string())?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn delete_test() -> Result<()> {
+    {
+        let sql = "DELETE FROM t1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "delete from db1.t1 where id = 1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DELETE t1";
+        expect_parse_err_contains(sql, "Expected FROM, found: t1".to_string())?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn export_table_test() -> Result<()> {
+    {
+        let sql = "EXPORT TABLE db1.t1 TO '@my_stage/exports/'";
+        let expected = DfStatement::ExportTable(DfExportTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            location: "@my_stage/exports/".to_string(),
+            snapshot_id: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "export table t1 to '@my_stage/exports/' snapshot = 'a1b2'";
+        let expected = DfStatement::ExportTable(DfExportTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            location: "@my_stage/exports/".to_string(),
+            snapshot_id: Some("a1b2".to_string()),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "EXPORT TABLE t1";
+        expect_parse_err_contains(sql, "Expected TO, found: EOF".to_string())?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn describe_table() -> Result<()> {
     {
@@ -306,6 +554,7 @@ fn describe_table() -> Result<()> {
 
 #[test]
 fn show_queries() -> Result<()> {
+    use databend_query::sql::statements::DfShowEngines;
     use databend_query::sql::statements::DfShowSettings;
     use databend_query::sql::statements::DfShowTables;
 
@@ -313,6 +562,7 @@ fn show_queries() -> Result<()> {
     expect_parse_ok("SHOW TABLES", DfStatement::ShowTables(DfShowTables::All))?;
     expect_parse_ok("SHOW TABLES;", DfStatement::ShowTables(DfShowTables::All))?;
     expect_parse_ok("SHOW SETTINGS", DfStatement::ShowSettings(DfShowSettings))?;
+    expect_parse_ok("SHOW ENGINES", DfStatement::ShowEngines(DfShowEngines))?;
     expect_parse_ok(
         "SHOW TABLES LIKE 'aaa'",
         DfStatement::ShowTables(DfShowTables::Like(Ident::with_quote('\'', "aaa"))),
@@ -538,6 +788,58 @@ fn copy_test() -> Result<()> {
 
     )?;
 
+    expect_parse_ok(
+        "copy into test_csv from '@my_stage/data/' format ndjson compression = 'gzip' on_error = 'continue' max_errors = 10;",
+        DfStatement::Copy(DfCopy {
+            name: ObjectName(vec![Ident::new("test_csv")]),
+            columns: vec![],
+            location: "@my_stage/data/".to_string(),
+            format: "ndjson".to_string(),
+            options: maplit::hashmap! {
+                "compression".into() => "gzip".into(),
+                "on_error".into() => "continue".into(),
+                "max_errors".into() => "10".into(),
+            },
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn copy_into_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "copy into '@my_stage/unload/' from db1.t1 format parquet max_file_size = 1048576;",
+        DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location: "@my_stage/unload/".to_string(),
+            source: CopyIntoStageSource::Table(ObjectName(vec![
+                Ident::new("db1"),
+                Ident::new("t1"),
+            ])),
+            format: "parquet".to_string(),
+            options: maplit::hashmap! {
+                "max_file_size".into() => "1048576".into(),
+            },
+        }),
+    )?;
+
+    let (statements, _) = DfParser::parse_sql(
+        "copy into '@my_stage/unload/' from (select a, b from t1 where a > 1) format csv csv_header = 1",
+    )?;
+    match &statements[0] {
+        DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location,
+            source: CopyIntoStageSource::Query(_),
+            format,
+            options,
+        }) => {
+            assert_eq!(location, "@my_stage/unload/");
+            assert_eq!(format, "csv");
+            assert_eq!(options.get("csv_header"), Some(&"1".to_string()));
+        }
+        other => panic!("Unexpected statement: {:?}", other),
+    }
+
     Ok(())
 }
 
@@ -660,6 +962,17 @@ fn create_user_test() -> Result<()> {
         }),
     )?;
 
+    expect_parse_ok(
+        "CREATE USER 'test'@'localhost' IDENTIFIED WITH pbkdf2_sha256_password BY 'password'",
+        DfStatement::CreateUser(DfCreateUser {
+            if_not_exists: false,
+            name: String::from("test"),
+            hostname: String::from("localhost"),
+            password_type: PasswordType::Pbkdf2Sha256,
+            password: String::from("password"),
+        }),
+    )?;
+
     expect_parse_ok(
         "CREATE USER 'test'@'localhost' IDENTIFIED WITH plaintext_password BY 'password'",
         DfStatement::CreateUser(DfCreateUser {
@@ -1092,107 +1405,708 @@ fn revoke_privilege_test() -> Result<()> {
         String::from("sql parser error: Expected keyword FROM, found: 'test'"),
     )?;
 
-    Ok(())
-}
-
-#[test]
-fn create_stage_test() -> Result<()> {
     expect_parse_ok(
-        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z')",
-        DfStatement::CreateStage(DfCreateStage {
-            if_not_exists: false,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format: FileFormat::default(),
-            comments: "".to_string(),
+        "REVOKE ALL PRIVILEGES ON * FROM 'test'@'localhost'",
+        DfStatement::RevokePrivilege(DfRevokeStatement {
+            username: String::from("test"),
+            hostname: String::from("localhost"),
+            on: DfGrantObject::Database(None),
+            priv_types: UserPrivilegeSet::all_privileges(),
         }),
     )?;
 
     expect_parse_ok(
-        "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z')",
-        DfStatement::CreateStage(DfCreateStage {
-            if_not_exists: true,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format: FileFormat::default(),
-            comments: "".to_string(),
+        "REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'test'@'localhost'",
+        DfStatement::RevokeAllPrivileges(DfRevokeAllStatement {
+            username: String::from("test"),
+            hostname: String::from("localhost"),
         }),
     )?;
 
     expect_parse_ok(
-        "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=CSV compression=GZIP record_delimiter=',')",
-        DfStatement::CreateStage(DfCreateStage {
-            if_not_exists: true,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format:  FileFormat { compression: Compression::Gzip, record_delimiter: ",".to_string(),..Default::default()},
-            comments: "".to_string(),
+        "REVOKE ALL, GRANT OPTION FROM 'test'",
+        DfStatement::RevokeAllPrivileges(DfRevokeAllStatement {
+            username: String::from("test"),
+            hostname: String::from("%"),
         }),
     )?;
 
-    expect_parse_ok(
-        "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=CSV compression=GZIP record_delimiter=',') comments='test'",
-        DfStatement::CreateStage(DfCreateStage {
-            if_not_exists: true,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format:  FileFormat { compression: Compression::Gzip, record_delimiter: ",".to_string(),..Default::default()},
-            comments: "test".to_string(),
-        }),
+    expect_parse_err(
+        "REVOKE ALL PRIVILEGES, GRANT FROM 'test'@'localhost'",
+        String::from("sql parser error: Expected OPTION, found: FROM"),
     )?;
 
+    Ok(())
+}
+
+#[test]
+fn row_access_policy_test() -> Result<()> {
+    let predicate = Expr::BinaryOp {
+        left: Box::new(Expr::Identifier(Ident::new("region"))),
+        op: BinaryOperator::Eq,
+        right: Box::new(Expr::Value(Value::SingleQuotedString("eu".to_string()))),
+    };
+
     expect_parse_ok(
-        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=Parquet compression=AUTO) comments='test'",
-        DfStatement::CreateStage(DfCreateStage {
+        "CREATE ROW ACCESS POLICY eu_only ON db1.sales AS region = 'eu'",
+        DfStatement::CreateRowAccessPolicy(DfCreateRowAccessPolicy {
             if_not_exists: false,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format:  FileFormat { format: Format::Parquet, compression: Compression::Auto ,..Default::default()},
-            comments: "test".to_string(),
+            name: "eu_only".to_string(),
+            table: ObjectName(vec![Ident::new("db1"), Ident::new("sales")]),
+            users: vec![],
+            roles: vec![],
+            predicate: predicate.clone(),
         }),
     )?;
 
     expect_parse_ok(
-        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO) comments='test'",
-        DfStatement::CreateStage(DfCreateStage {
-            if_not_exists: false,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format:  FileFormat { format: Format::Csv, compression: Compression::Auto,..Default::default()},
-            comments: "test".to_string(),
+        "CREATE ROW ACCESS POLICY IF NOT EXISTS eu_only ON sales TO 'u1'@'localhost', 'u2' AS region = 'eu'",
+        DfStatement::CreateRowAccessPolicy(DfCreateRowAccessPolicy {
+            if_not_exists: true,
+            name: "eu_only".to_string(),
+            table: ObjectName(vec![Ident::new("sales")]),
+            users: vec![
+                UserIdentity {
+                    username: "u1".to_string(),
+                    hostname: "localhost".to_string(),
+                },
+                UserIdentity {
+                    username: "u2".to_string(),
+                    hostname: "%".to_string(),
+                },
+            ],
+            roles: vec![],
+            predicate: predicate.clone(),
         }),
     )?;
 
     expect_parse_ok(
-        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=json) comments='test'",
-        DfStatement::CreateStage(DfCreateStage {
+        "CREATE ROW ACCESS POLICY eu_only ON sales TO ROLE 'analyst', 'u1', ROLE 'auditor' AS region = 'eu'",
+        DfStatement::CreateRowAccessPolicy(DfCreateRowAccessPolicy {
             if_not_exists: false,
-            stage_name: "test_stage".to_string(),
-            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
-            file_format:  FileFormat { format: Format::Json,..Default::default()},
-            comments: "test".to_string(),
+            name: "eu_only".to_string(),
+            table: ObjectName(vec![Ident::new("sales")]),
+            users: vec![UserIdentity {
+                username: "u1".to_string(),
+                hostname: "%".to_string(),
+            }],
+            roles: vec!["analyst".to_string(), "auditor".to_string()],
+            predicate,
         }),
     )?;
 
-    expect_parse_err(
-        "CREATE STAGE test_stage credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
-        String::from("sql parser error: Missing URL"),
+    expect_parse_err_contains(
+        "CREATE ROW ACCESS POLICY eu_only ON sales",
+        "Expected AS, found: EOF".to_string(),
     )?;
 
-    expect_parse_err(
-        "CREATE STAGE test_stage url='s3://load/files/' password=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
-        String::from("sql parser error: Expected end of statement, found: password"),
+    expect_parse_err_contains(
+        "CREATE ROW POLICY eu_only ON sales AS region = 'eu'",
+        "Expected ACCESS, found: POLICY".to_string(),
     )?;
 
-    expect_parse_err(
-        "CREATE STAGE test_stage url='s4://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
-        String::from("sql parser error: Not supported storage"),
+    expect_parse_ok(
+        "DROP ROW ACCESS POLICY IF EXISTS eu_only",
+        DfStatement::DropRowAccessPolicy(DfDropRowAccessPolicy {
+            if_exists: true,
+            name: "eu_only".to_string(),
+        }),
     )?;
 
-    expect_parse_err(
-        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
-        String::from("sql parser error: Invalid credentials options: unknown field `access_key`, expected `access_key_id` or `secret_access_key`"),
-    )?;
+    Ok(())
+}
+
+#[test]
+fn network_policy_test() -> Result<()> {
+    expect_parse_ok(
+        "CREATE NETWORK POLICY office_only",
+        DfStatement::CreateNetworkPolicy(DfCreateNetworkPolicy {
+            if_not_exists: false,
+            name: "office_only".to_string(),
+            allowed_ip_list: vec![],
+            blocked_ip_list: vec![],
+            comment: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE NETWORK POLICY IF NOT EXISTS office_only ALLOWED_IP_LIST = ('192.168.1.0/24', '10.0.0.1') BLOCKED_IP_LIST = ('192.168.1.99') COMMENT = 'office'",
+        DfStatement::CreateNetworkPolicy(DfCreateNetworkPolicy {
+            if_not_exists: true,
+            name: "office_only".to_string(),
+            allowed_ip_list: vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+            blocked_ip_list: vec!["192.168.1.99".to_string()],
+            comment: "office".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE NETWORK POLICY office_only ALLOWED_IP_LIST = '192.168.1.0/24'",
+        "Expected (, found: '192.168.1.0/24'".to_string(),
+    )?;
+
+    expect_parse_ok(
+        "DROP NETWORK POLICY IF EXISTS office_only",
+        DfStatement::DropNetworkPolicy(DfDropNetworkPolicy {
+            if_exists: true,
+            name: "office_only".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER USER 'test'@'localhost' SET NETWORK_POLICY = 'office_only'",
+        DfStatement::AlterUserNetworkPolicy(DfAlterUserNetworkPolicy {
+            name: "test".to_string(),
+            hostname: "localhost".to_string(),
+            network_policy: Some("office_only".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER USER 'test'@'localhost' UNSET NETWORK_POLICY",
+        DfStatement::AlterUserNetworkPolicy(DfAlterUserNetworkPolicy {
+            name: "test".to_string(),
+            hostname: "localhost".to_string(),
+            network_policy: None,
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn set_role_test() -> Result<()> {
+    expect_parse_ok(
+        "SET ROLE analyst",
+        DfStatement::SetRole(DfSetRole {
+            role_name: "analyst".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SET SECONDARY ROLES ALL",
+        DfStatement::SetSecondaryRoles(DfSetSecondaryRoles { all: true }),
+    )?;
+
+    expect_parse_ok(
+        "SET SECONDARY ROLES NONE",
+        DfStatement::SetSecondaryRoles(DfSetSecondaryRoles { all: false }),
+    )?;
+
+    expect_parse_err_contains(
+        "SET SECONDARY ROLES analyst",
+        "Expected ALL or NONE, found: analyst".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn role_test() -> Result<()> {
+    expect_parse_ok(
+        "CREATE ROLE IF NOT EXISTS 'analyst'",
+        DfStatement::CreateRole(DfCreateRole {
+            if_not_exists: true,
+            role_name: "analyst".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP ROLE 'analyst'",
+        DfStatement::DropRole(DfDropRole {
+            if_exists: false,
+            role_name: "analyst".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT ROLE 'analyst' TO 'test'@'localhost'",
+        DfStatement::GrantRole(DfGrantRole {
+            role_name: "analyst".to_string(),
+            username: "test".to_string(),
+            hostname: "localhost".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE ROLE 'analyst' FROM 'test'",
+        DfStatement::RevokeRole(DfRevokeRole {
+            role_name: "analyst".to_string(),
+            username: "test".to_string(),
+            hostname: "%".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT SELECT ON db1.* TO ROLE 'analyst'",
+        DfStatement::GrantRolePrivilege(DfGrantRolePrivilege {
+            role_name: "analyst".to_string(),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Select);
+                privileges
+            },
+            on: DfGrantObject::Database(Some("db1".into())),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE SELECT ON db1.* FROM ROLE 'analyst'",
+        DfStatement::RevokeRolePrivilege(DfRevokeRolePrivilege {
+            role_name: "analyst".to_string(),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Select);
+                privileges
+            },
+            on: DfGrantObject::Database(Some("db1".into())),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "GRANT ROLE 'analyst' ON *.* TO 'test'",
+        "Expected keyword TO, found: ON".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn set_variable_test() -> Result<()> {
+    expect_parse_ok(
+        "SET max_threads = 4",
+        DfStatement::SetVariable(DfSetVariable {
+            local: false,
+            global: false,
+            hivevar: false,
+            variable: Ident::new("max_threads"),
+            value: vec![SetVariableValue::Literal(Value::Number(
+                "4".to_string(),
+                false,
+            ))],
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SET GLOBAL log_level = 'DEBUG'",
+        DfStatement::SetVariable(DfSetVariable {
+            local: false,
+            global: true,
+            hivevar: false,
+            variable: Ident::new("log_level"),
+            value: vec![SetVariableValue::Literal(Value::SingleQuotedString(
+                "DEBUG".to_string(),
+            ))],
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn connection_test() -> Result<()> {
+    let credentials = BTreeMap::from([
+        ("access_key_id".to_string(), "AKIA".to_string()),
+        ("secret_access_key".to_string(), "secret".to_string()),
+    ]);
+
+    expect_parse_ok(
+        "CREATE CONNECTION s3_conn STORAGE_TYPE = 's3' CREDENTIALS = (ACCESS_KEY_ID = 'AKIA' SECRET_ACCESS_KEY = 'secret')",
+        DfStatement::CreateConnection(DfCreateConnection {
+            if_not_exists: false,
+            name: "s3_conn".to_string(),
+            storage_type: "s3".to_string(),
+            params: BTreeMap::new(),
+            credentials: credentials.clone(),
+            comment: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE CONNECTION IF NOT EXISTS 's3_conn' STORAGE_TYPE = 's3' PARAMS = (region = 'us-east-2') CREDENTIALS = (access_key_id = 'AKIA' secret_access_key = 'secret') COMMENT = 'data lake'",
+        DfStatement::CreateConnection(DfCreateConnection {
+            if_not_exists: true,
+            name: "s3_conn".to_string(),
+            storage_type: "s3".to_string(),
+            params: BTreeMap::from([("region".to_string(), "us-east-2".to_string())]),
+            credentials,
+            comment: "data lake".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE CONNECTION s3_conn CREDENTIALS = (ACCESS_KEY_ID = 'AKIA')",
+        "Missing STORAGE_TYPE".to_string(),
+    )?;
+
+    expect_parse_ok(
+        "DROP CONNECTION IF EXISTS s3_conn",
+        DfStatement::DropConnection(DfDropConnection {
+            if_exists: true,
+            name: "s3_conn".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT USAGE ON CONNECTION s3_conn TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantStatement {
+            name: String::from("test"),
+            hostname: String::from("localhost"),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Usage);
+                privileges
+            },
+            on: DfGrantObject::Connection("s3_conn".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT USAGE ON STAGE 's1' TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantStatement {
+            name: String::from("test"),
+            hostname: String::from("localhost"),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Usage);
+                privileges
+            },
+            on: DfGrantObject::Stage("s1".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT USAGE ON FUNCTION 'f1' TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantStatement {
+            name: String::from("test"),
+            hostname: String::from("localhost"),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Usage);
+                privileges
+            },
+            on: DfGrantObject::UDF("f1".to_string()),
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn alter_read_only_test() -> Result<()> {
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 SET READ_ONLY = true",
+        DfStatement::AlterReadOnly(DfAlterReadOnly {
+            object: DfReadOnlyObject::Table(ObjectName(vec![Ident::new("db1"), Ident::new("t1")])),
+            read_only: true,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "alter database db1 set read_only = false",
+        DfStatement::AlterReadOnly(DfAlterReadOnly {
+            object: DfReadOnlyObject::Database(ObjectName(vec![Ident::new("db1")])),
+            read_only: false,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER CLUSTER SET MAINTENANCE_MODE = TRUE",
+        DfStatement::AlterReadOnly(DfAlterReadOnly {
+            object: DfReadOnlyObject::Cluster,
+            read_only: true,
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 SET READ_ONLY = 1",
+        "Expected TRUE or FALSE, found: 1".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER CLUSTER SET READ_ONLY = true",
+        "Expected MAINTENANCE_MODE, found: READ_ONLY".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn alter_table_column_test() -> Result<()> {
+    let name = ObjectName(vec![Ident::new("db1"), Ident::new("t1")]);
+
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 ADD COLUMN c2 BIGINT",
+        DfStatement::AlterTableColumn(DfAlterTableColumn {
+            name: name.clone(),
+            operation: DfAlterColumnOperation::Add(make_column_def("c2", DataType::BigInt(None))),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 ADD c2 BIGINT",
+        DfStatement::AlterTableColumn(DfAlterTableColumn {
+            name: name.clone(),
+            operation: DfAlterColumnOperation::Add(make_column_def("c2", DataType::BigInt(None))),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 DROP COLUMN c1",
+        DfStatement::AlterTableColumn(DfAlterTableColumn {
+            name: name.clone(),
+            operation: DfAlterColumnOperation::Drop(Ident::new("c1")),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "alter table db1.t1 rename column c1 to c3",
+        DfStatement::AlterTableColumn(DfAlterTableColumn {
+            name,
+            operation: DfAlterColumnOperation::Rename {
+                from: Ident::new("c1"),
+                to: Ident::new("c3"),
+            },
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 RENAME c1 TO c3",
+        "Expected COLUMN, found: c1".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn alter_table_options_test() -> Result<()> {
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 SET compression = 'zstd' CHANGE_TRACKING = true",
+        DfStatement::AlterTableOptions(DfAlterTableOptions {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            options: maplit::hashmap! {
+                "compression".into() => "zstd".into(),
+                "CHANGE_TRACKING".into() => "true".into(),
+            },
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 SET",
+        "Expected table option, found: EOF".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn alter_owner_test() -> Result<()> {
+    let owner = UserIdentity {
+        username: "u1".to_string(),
+        hostname: "localhost".to_string(),
+    };
+
+    expect_parse_ok(
+        "ALTER DATABASE db1 OWNER TO 'u1'@'localhost'",
+        DfStatement::AlterOwner(DfAlterOwner {
+            object: DfAlterOwnerObject::Database(ObjectName(vec![Ident::new("db1")])),
+            owner: owner.clone(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 OWNER TO 'u1'@'localhost'",
+        DfStatement::AlterOwner(DfAlterOwner {
+            object: DfAlterOwnerObject::Table(ObjectName(vec![
+                Ident::new("db1"),
+                Ident::new("t1"),
+            ])),
+            owner: owner.clone(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER STAGE test_stage OWNER TO 'u1'@'localhost'",
+        DfStatement::AlterOwner(DfAlterOwner {
+            object: DfAlterOwnerObject::Stage("test_stage".to_string()),
+            owner: owner.clone(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER FUNCTION test_udf OWNER TO 'u1'",
+        DfStatement::AlterOwner(DfAlterOwner {
+            object: DfAlterOwnerObject::UDF("test_udf".to_string()),
+            owner: UserIdentity {
+                username: "u1".to_string(),
+                hostname: "%".to_string(),
+            },
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 OWNER 'u1'",
+        "Expected keyword TO, found: 'u1'".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 RENAME TO t2",
+        "Expected keyword OWNER, found: RENAME".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn create_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z')",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format: FileFormat::default(),
+            comments: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE OR REPLACE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z')",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: true,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format: FileFormat::default(),
+            comments: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z')",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: true,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format: FileFormat::default(),
+            comments: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=CSV compression=GZIP record_delimiter=',')",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: true,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format:  FileFormat { compression: Compression::Gzip, record_delimiter: ",".to_string(),..Default::default()},
+            comments: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=CSV compression=GZIP record_delimiter=',') comments='test'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: true,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format:  FileFormat { compression: Compression::Gzip, record_delimiter: ",".to_string(),..Default::default()},
+            comments: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=Parquet compression=AUTO) comments='test'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format:  FileFormat { format: Format::Parquet, compression: Compression::Auto ,..Default::default()},
+            comments: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO) comments='test'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format:  FileFormat { format: Format::Csv, compression: Compression::Auto,..Default::default()},
+            comments: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=json) comments='test'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::new("s3://load/files/", Credentials { access_key_id: "1a2b3c".to_string(), secret_access_key: "4x5y6z".to_string() }),
+            file_format:  FileFormat { format: Format::Json,..Default::default()},
+            comments: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE test_stage url='s3://load/files/' connection='my_conn' file_format=(FORMAT=json) comments='test'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::with_connection("s3://load/files/", "my_conn"),
+            file_format:  FileFormat { format: Format::Json,..Default::default()},
+            comments: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_err(
+        "CREATE STAGE test_stage url='s3://load/files/' connection='my_conn' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z')",
+        String::from("sql parser error: CREDENTIALS can not be used with CONNECTION"),
+    )?;
+
+    expect_parse_err(
+        "CREATE STAGE test_stage credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
+        String::from("sql parser error: Missing URL, internal stages take no credentials"),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE test_stage file_format=(FORMAT=csv compression=GZIP) comments='test'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            or_replace: false,
+            stage_name: "test_stage".to_string(),
+            stage_params: StageParams::default(),
+            file_format: FileFormat {
+                format: Format::Csv,
+                compression: Compression::Gzip,
+                ..Default::default()
+            },
+            comments: "test".to_string(),
+        }),
+    )?;
+
+    expect_parse_err(
+        "CREATE STAGE test_stage url='s3://load/files/' password=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
+        String::from("sql parser error: Expected end of statement, found: password"),
+    )?;
+
+    expect_parse_err(
+        "CREATE STAGE test_stage url='s4://load/files/' credentials=(access_key_id='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
+        String::from("sql parser error: Not supported storage"),
+    )?;
+
+    expect_parse_err(
+        "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key='1a2b3c' secret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
+        String::from("sql parser error: Invalid credentials options: unknown field `access_key`, expected `access_key_id` or `secret_access_key`"),
+    )?;
 
     expect_parse_err(
         "CREATE STAGE test_stage url='s3://load/files/' credentials=(access_key_id='1a2b3c' aecret_access_key='4x5y6z') file_format=(FORMAT=csv compression=AUTO record_delimiter=NONE) comments='test'",
@@ -1223,6 +2137,7 @@ fn create_table_select() -> Result<()> {
         "CREATE TABLE foo AS SELECT a, b FROM bar",
         DfStatement::CreateTable(DfCreateTable {
             if_not_exists: false,
+            or_replace: false,
             name: ObjectName(vec![Ident::new("foo")]),
             columns: vec![],
             engine: "FUSE".to_string(),
@@ -1236,6 +2151,7 @@ fn create_table_select() -> Result<()> {
         "CREATE TABLE foo (a INT) SELECT a, b FROM bar",
         DfStatement::CreateTable(DfCreateTable {
             if_not_exists: false,
+            or_replace: false,
             name: ObjectName(vec![Ident::new("foo")]),
             columns: vec![make_column_def("a", DataType::Int(None))],
             engine: "FUSE".to_string(),
@@ -1378,6 +2294,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf AS (p) -> not(isnotnull(p))",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string()],
             definition: "not(isnotnull(p))".to_string(),
@@ -1389,6 +2306,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf AS (p, d) -> not(isnotnull(p, d))",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -1396,6 +2314,18 @@ fn test_create_udf() -> Result<()> {
         }),
     )?;
 
+    expect_parse_ok(
+        "CREATE OR REPLACE FUNCTION test_udf AS (p) -> not(isnotnull(p))",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            or_replace: true,
+            udf_name: "test_udf".to_string(),
+            parameters: vec!["p".to_string()],
+            definition: "not(isnotnull(p))".to_string(),
+            description: "".to_string(),
+        }),
+    )?;
+
     expect_parse_err_contains(
         "CREATE FUNCTION test_udf AS (p) -> not(isnotnull(p)) DESC",
         "Expected =, found: ".to_string(),
@@ -1410,6 +2340,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf AS (p, d) -> not(isnotnull(p, d)) DESC = 'this is a description'",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -1421,6 +2352,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf as (p, d) -> not(isnotnull(p, d)) DESC = 'this is a description'",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -1543,3 +2475,138 @@ fn test_alter_udf() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_query_functions() -> Result<()> {
+    let query = verified_query(
+        "WITH FUNCTION twice(x) AS (x * 2), FUNCTION sum_of(x, y) AS (x + y) SELECT twice(number) FROM numbers(3)",
+    )?;
+    assert_eq!(query.functions, vec![
+        DfQueryFunction {
+            name: "twice".to_string(),
+            parameters: vec!["x".to_string()],
+            definition: parse_sql_to_expr("x * 2"),
+        },
+        DfQueryFunction {
+            name: "sum_of".to_string(),
+            parameters: vec!["x".to_string(), "y".to_string()],
+            definition: parse_sql_to_expr("x + y"),
+        },
+    ]);
+
+    expect_parse_err_contains(
+        "WITH FUNCTION twice(x) AS (x * 2), FUNCTION twice(y) AS (y * 2) SELECT 1",
+        "Function twice is defined more than once".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "WITH FUNCTION twice(x) AS (x * 2), sum_of(x, y) AS (x + y) SELECT 1",
+        "Expected FUNCTION, found: sum_of".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_table_sample() -> Result<()> {
+    // the sample clauses are rewritten to the table arguments
+    let cases = vec![
+        (
+            "SELECT * FROM t SAMPLE 10%",
+            "SELECT * FROM t(SAMPLE_BLOCK => 10)",
+        ),
+        (
+            "SELECT * FROM db.t TABLESAMPLE SYSTEM (10 PERCENT) AS x",
+            "SELECT * FROM db.t(SAMPLE_BLOCK => 10) AS x",
+        ),
+        (
+            "SELECT * FROM t SAMPLE BLOCK (0.5) WHERE a > 1",
+            "SELECT * FROM t(SAMPLE_BLOCK => 0.5) WHERE a > 1",
+        ),
+        (
+            "SELECT * FROM t sample row (20)",
+            "SELECT * FROM t(SAMPLE_ROW => 20)",
+        ),
+        (
+            "SELECT * FROM t TABLESAMPLE BERNOULLI (20)",
+            "SELECT * FROM t(SAMPLE_ROW => 20)",
+        ),
+        (
+            "SELECT * FROM t AT (SNAPSHOT => 'id') SAMPLE 10%",
+            "SELECT * FROM t(SNAPSHOT => 'id', SAMPLE_BLOCK => 10)",
+        ),
+        // not a sample clause
+        ("SELECT sample FROM t sample", "SELECT sample FROM t sample"),
+    ];
+    for (sql, expected) in cases {
+        assert_eq!(
+            DfParser::parse_sql(sql)?.0,
+            DfParser::parse_sql(expected)?.0,
+            "{}",
+            sql
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_transaction() -> Result<()> {
+    let begin = DfStatement::Transaction(DfTransaction {
+        action: TransactionAction::Begin,
+    });
+    expect_parse_ok("BEGIN", begin.clone())?;
+    expect_parse_ok("BEGIN TRANSACTION", begin.clone())?;
+    expect_parse_ok("begin work", begin.clone())?;
+    expect_parse_ok("START TRANSACTION", begin)?;
+
+    let commit = DfStatement::Transaction(DfTransaction {
+        action: TransactionAction::Commit,
+    });
+    expect_parse_ok("COMMIT", commit.clone())?;
+    expect_parse_ok("COMMIT WORK", commit)?;
+
+    let rollback = DfStatement::Transaction(DfTransaction {
+        action: TransactionAction::Rollback,
+    });
+    expect_parse_ok("ROLLBACK", rollback.clone())?;
+    expect_parse_ok("ROLLBACK WORK", rollback)?;
+
+    expect_parse_err_contains("START", "Expected TRANSACTION".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_stream() -> Result<()> {
+    expect_parse_ok(
+        "CREATE STREAM s ON TABLE t",
+        DfStatement::CreateStream(DfCreateStream {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("s")]),
+            table: ObjectName(vec![Ident::new("t")]),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STREAM IF NOT EXISTS db1.s ON TABLE db2.t",
+        DfStatement::CreateStream(DfCreateStream {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("s")]),
+            table: ObjectName(vec![Ident::new("db2"), Ident::new("t")]),
+        }),
+    )?;
+
+    expect_parse_err_contains("CREATE STREAM s ON t", "Expected TABLE".to_string())?;
+
+    // a stream is dropped as the table it is
+    expect_parse_ok(
+        "DROP STREAM IF EXISTS s",
+        DfStatement::DropTable(DfDropTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("s")]),
+        }),
+    )?;
+
+    Ok(())
+}
@@ -15,3 +15,4 @@
 mod query_normalizer;
 mod query_qualified_rewriter;
 mod query_schema_joined_analyzer;
+mod query_tables_collector;
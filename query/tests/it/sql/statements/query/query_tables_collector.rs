@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::statements::query::QueryTablesCollector;
+use databend_query::sql::DfParser;
+use databend_query::sql::DfStatement;
+
+#[test]
+fn test_query_tables_collector() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: Vec<&'static str>,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "Table function query",
+            query: "SELECT * FROM numbers(100)",
+            expect: vec![],
+        },
+        TestCase {
+            name: "Join query",
+            query: "SELECT * FROM t1 JOIN db2.t2 ON t1.a = t2.a, (SELECT * FROM t3) AS t",
+            expect: vec!["db1.t1", "db2.t2", "db1.t3"],
+        },
+        TestCase {
+            name: "Subquery query",
+            query: "SELECT (SELECT MAX(a) FROM t1) FROM t2 WHERE EXISTS (SELECT * FROM t3 WHERE a = 1) AND a > (SELECT a FROM t1)",
+            expect: vec!["db1.t1", "db1.t2", "db1.t3"],
+        },
+        TestCase {
+            name: "Navigation query",
+            query: "SELECT * FROM t1 AT (SNAPSHOT => 'abc'), t2",
+            expect: vec!["db1.t1", "db1.t2"],
+        },
+    ];
+
+    for test_case in &tests {
+        let (mut statements, _) = DfParser::parse_sql(test_case.query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query) => {
+                let tables = QueryTablesCollector::collect("db1".to_string(), &query);
+                let tables = tables
+                    .iter()
+                    .map(|(db, tbl)| format!("{}.{}", db, tbl))
+                    .collect::<Vec<_>>();
+                assert_eq!(test_case.expect, tables, "{:#?}", test_case.name);
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_external_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    execute_command("create stage s3", ctx.clone()).await?;
+
+    // the files of the table, the first one of two row groups
+    for qry in [
+        "insert into {} values (1), (2)",
+        "insert into {} values (3)",
+        "copy into '@s3/ext/' from {} format parquet",
+        "insert into {} values (4)",
+        "copy into '@s3/ext/' from (select * from {} where id = 4) format parquet",
+    ] {
+        let qry = qry.replace("{}", &format!("{}.{}", db, tbl));
+        execute_command(&qry, ctx.clone()).await?;
+    }
+
+    // 1. the schema is inferred from the files
+    let qry = format!(
+        "create table {}.ext engine = EXTERNAL location = '@s3/ext/'",
+        db
+    );
+    execute_command(&qry, ctx.clone()).await?;
+    let table = ctx.get_table(&db, "ext").await?;
+    let fields = table.schema().fields().clone();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].name(), "id");
+
+    let qry = format!("select count(*) as c, sum(id) as s from {}.ext", db);
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+---+----+",
+        "| c | s  |",
+        "+---+----+",
+        "| 4 | 10 |",
+        "+---+----+",
+    ];
+    assert_blocks_eq(expected, &blocks);
+
+    // 2. the table is read-only
+    let qry = format!("insert into {}.ext values (5)", db);
+    assert!(execute_command(&qry, ctx.clone()).await.is_err());
+
+    // 3. the location is required, and has files to infer the schema from
+    let qry = format!("create table {}.ext2 engine = EXTERNAL", db);
+    assert!(execute_command(&qry, ctx.clone()).await.is_err());
+    let qry = format!(
+        "create table {}.ext3 engine = EXTERNAL location = '@s3/empty/'",
+        db
+    );
+    assert!(execute_command(&qry, ctx.clone()).await.is_err());
+    Ok(())
+}
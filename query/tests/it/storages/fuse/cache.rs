@@ -0,0 +1,130 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_dal::DataAccessor;
+use common_dal::Local;
+use common_exception::Result;
+use databend_query::storages::fuse::cache::BlockDataCache;
+use databend_query::storages::fuse::cache::CachedDataAccessor;
+use databend_query::storages::fuse::cache::SegmentInfoCache;
+use databend_query::storages::fuse::io::SegmentReader;
+use databend_query::storages::fuse::meta::SegmentInfo;
+use databend_query::storages::fuse::meta::SEGMENT_FORMAT_VERSION;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn test_block_data_cache() -> Result<()> {
+    let remote_dir = TempDir::new()?;
+    let cache_dir = TempDir::new()?;
+    let remote: Arc<dyn DataAccessor> = Arc::new(Local::with_path(remote_dir.path().to_owned()));
+
+    let location = "_b/block.parquet";
+    let content = b"block content".to_vec();
+    remote.put(location, content.clone()).await?;
+
+    let cache = Arc::new(BlockDataCache::create(
+        cache_dir.path().to_str().unwrap(),
+        1,
+    )?);
+    assert!(cache.is_empty());
+
+    // fetched blocks are kept on the local disk
+    cache.fetch(remote.as_ref(), location).await?;
+    cache.fetch(remote.as_ref(), location).await?;
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.size(), content.len() as u64);
+
+    // and served from there, even if the remote copy is gone
+    remote.remove(location).await?;
+    let da = CachedDataAccessor::new(cache.clone(), remote.clone());
+    assert_eq!(da.read(location).await?, content);
+
+    // removing the block through the accessor evicts it
+    remote.put(location, content.clone()).await?;
+    da.remove(location).await?;
+    assert!(cache.is_empty());
+    assert!(da.read(location).await.is_err());
+
+    // blocks larger than the whole cache are not cached
+    let large = vec![0u8; 2 * 1024 * 1024];
+    remote.put("_b/large.parquet", large.clone()).await?;
+    cache.fetch(remote.as_ref(), "_b/large.parquet").await?;
+    assert!(cache.is_empty());
+    assert_eq!(da.read("_b/large.parquet").await?, large);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_segment_info_cache() -> Result<()> {
+    let dir = TempDir::new()?;
+    let da: Arc<dyn DataAccessor> = Arc::new(Local::with_path(dir.path().to_owned()));
+
+    let locations = (0..5)
+        .map(|i| format!("_sg/segment_{}.json", i))
+        .collect::<Vec<_>>();
+    for (i, location) in locations.iter().enumerate() {
+        let mut segment = SegmentInfo {
+            format_version: SEGMENT_FORMAT_VERSION,
+            blocks: vec![],
+            summary: Default::default(),
+            ndv_sketches: Default::default(),
+        };
+        segment.summary.row_count = i as u64;
+        da.put(location, serde_json::to_vec(&segment)?).await?;
+    }
+
+    let segment_cache = Arc::new(Some(SegmentInfoCache::create(10)));
+    let segments = SegmentReader::read_segments(
+        da.as_ref(),
+        &locations,
+        Arc::new(None),
+        segment_cache.clone(),
+        2,
+    )
+    .await?;
+    // in the order of the locations
+    let row_counts = segments
+        .iter()
+        .map(|s| s.summary.row_count)
+        .collect::<Vec<_>>();
+    assert_eq!(row_counts, vec![0, 1, 2, 3, 4]);
+    assert_eq!(segment_cache.as_ref().as_ref().unwrap().len(), 5);
+
+    // served from the cache, even if the segments are gone
+    for location in &locations {
+        da.remove(location).await?;
+    }
+    let cached =
+        SegmentReader::read_segments(da.as_ref(), &locations, Arc::new(None), segment_cache, 2)
+            .await?;
+    assert!(Arc::ptr_eq(&cached[3], &segments[3]));
+
+    // without the cache
+    assert!(SegmentReader::read_segments(
+        da.as_ref(),
+        &locations,
+        Arc::new(None),
+        Arc::new(None),
+        2
+    )
+    .await
+    .is_err());
+
+    Ok(())
+}
@@ -13,17 +13,34 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_arrow::parquet::compression::Compression;
+use common_arrow::parquet::read::read_metadata;
 use common_base::tokio;
+use common_dal::DataAccessor;
+use common_dal::InputStream;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::SeriesFrom;
 use common_datavalues::series::Series;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::configs::Config;
+use databend_query::storages::fuse::io::BlockCompression;
 use databend_query::storages::fuse::io::BlockStreamWriter;
+use databend_query::storages::fuse::io::UploadDataAccessor;
+use databend_query::storages::fuse::io::UploadPolicy;
 use databend_query::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
+use databend_query::storages::fuse::TBL_OPT_KEY_COLUMN_COMPRESSION;
+use databend_query::storages::fuse::TBL_OPT_KEY_COMPRESSION;
+use futures::Stream;
 use futures::StreamExt;
 use tempfile::TempDir;
 
@@ -44,6 +61,8 @@ async fn test_fuse_table_block_appender() {
         schema.clone(),
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
+        vec![],
+        BlockCompression::default(),
     )
     .await
     .collect::<Vec<_>>()
@@ -69,6 +88,8 @@ async fn test_fuse_table_block_appender() {
         schema.clone(),
         chunk_size,
         0,
+        vec![],
+        BlockCompression::default(),
     )
     .await
     .collect::<Vec<_>>()
@@ -88,6 +109,8 @@ async fn test_fuse_table_block_appender() {
         schema,
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
+        vec![],
+        BlockCompression::default(),
     )
     .await
     .collect::<Vec<_>>()
@@ -96,6 +119,73 @@ async fn test_fuse_table_block_appender() {
     assert!(segments.is_empty())
 }
 
+#[tokio::test]
+async fn test_fuse_table_block_compression() -> common_exception::Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let local_fs = Arc::new(common_dal::Local::with_path(tmp_dir.path().to_owned()));
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+        DataField::new("c", DataType::Int32, false),
+    ]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![1, 2, 3]),
+        Series::new(vec!["x", "y", "z"]),
+        Series::new(vec![4, 5, 6]),
+    ]);
+
+    let mut options = HashMap::new();
+    options.insert(TBL_OPT_KEY_COMPRESSION.to_string(), "ZSTD".to_string());
+    options.insert(
+        TBL_OPT_KEY_COLUMN_COMPRESSION.to_string(),
+        "b:snappy, c:none".to_string(),
+    );
+    let compression = BlockCompression::try_create(&options)?;
+
+    let segments = BlockStreamWriter::write_block_stream(
+        local_fs,
+        Box::pin(futures::stream::iter(vec![Ok(block)])),
+        schema,
+        DEFAULT_CHUNK_BLOCK_NUM,
+        0,
+        vec![],
+        compression,
+    )
+    .await
+    .collect::<Vec<_>>()
+    .await;
+    let segment = segments[0].as_ref().unwrap();
+
+    let path = tmp_dir.path().join(&segment.blocks[0].location.path);
+    let metadata = read_metadata(&mut std::fs::File::open(path)?).unwrap();
+    let codecs = (0..3)
+        .map(|i| metadata.row_groups[0].column(i).compression())
+        .collect::<Vec<_>>();
+    assert_eq!(codecs, vec![
+        Compression::Zstd,
+        Compression::Snappy,
+        Compression::Uncompressed
+    ]);
+
+    // lz4 by default
+    assert_eq!(
+        BlockCompression::try_create(&HashMap::new())?,
+        BlockCompression::default()
+    );
+
+    // unknown codecs and malformed overrides
+    for (key, value) in [
+        (TBL_OPT_KEY_COMPRESSION, "gzip"),
+        (TBL_OPT_KEY_COLUMN_COMPRESSION, "b"),
+        (TBL_OPT_KEY_COLUMN_COMPRESSION, "b:brotli"),
+    ] {
+        let mut options = HashMap::new();
+        options.insert(key.to_string(), value.to_string());
+        assert!(BlockCompression::try_create(&options).is_err());
+    }
+    Ok(())
+}
+
 #[test]
 fn test_fuse_table_block_appender_reshape() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
@@ -164,3 +254,86 @@ fn gen_blocks(sample_block: &DataBlock, num: usize) -> (impl Iterator<Item = Dat
     let ideal_threshold = block_size * num;
     (blocks, ideal_threshold)
 }
+
+// Fails the first `failures` puts, as a throttling object store.
+struct FlakyDataAccessor {
+    failures: AtomicUsize,
+    puts: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl DataAccessor for FlakyDataAccessor {
+    fn get_input_stream(&self, _path: &str, _stream_len: Option<u64>) -> Result<InputStream> {
+        unimplemented!()
+    }
+
+    async fn put(&self, _path: &str, _content: Vec<u8>) -> Result<()> {
+        self.puts.fetch_add(1, Ordering::Relaxed);
+        match self.failures.load(Ordering::Relaxed) {
+            0 => Ok(()),
+            n => {
+                self.failures.store(n - 1, Ordering::Relaxed);
+                Err(ErrorCode::DalTransportError("SlowDown"))
+            }
+        }
+    }
+
+    async fn put_stream(
+        &self,
+        _path: &str,
+        _input_stream: Box<
+            dyn Stream<Item = std::result::Result<bytes::Bytes, std::io::Error>>
+                + Send
+                + Unpin
+                + 'static,
+        >,
+        _stream_len: usize,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    async fn remove(&self, _path: &str) -> Result<()> {
+        unimplemented!()
+    }
+}
+
+#[tokio::test]
+async fn test_fuse_table_upload_retry() -> Result<()> {
+    let mut conf = Config::default();
+    conf.query.table_upload_max_retries = 3;
+    conf.query.table_upload_retry_backoff_ms = 1;
+    conf.query.table_upload_retry_max_backoff_ms = 4;
+    conf.query.table_upload_max_concurrency = 1;
+    let policy = Arc::new(UploadPolicy::create(&conf));
+
+    // the backoff doubles, capped by the max backoff
+    for retry in 1..6 {
+        let max = Duration::from_millis(4.min(1 << (retry - 1)));
+        let backoff = policy.backoff(retry);
+        assert!(backoff >= max / 2 && backoff <= max, "{:?}", backoff);
+    }
+
+    // throttled uploads are retried
+    let flaky = Arc::new(FlakyDataAccessor {
+        failures: AtomicUsize::new(3),
+        puts: AtomicUsize::new(0),
+    });
+    let da = UploadDataAccessor::new(policy.clone(), flaky.clone());
+    da.put("_b/1.parquet", vec![1, 2, 3]).await?;
+    assert_eq!(flaky.puts.load(Ordering::Relaxed), 4);
+
+    // until they run out of retries
+    let flaky = Arc::new(FlakyDataAccessor {
+        failures: AtomicUsize::new(4),
+        puts: AtomicUsize::new(0),
+    });
+    let da = UploadDataAccessor::new(policy, flaky.clone());
+    let res = da.put("_b/2.parquet", vec![1, 2, 3]).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::DalTransportError("").code()
+    );
+    assert_eq!(flaky.puts.load(Ordering::Relaxed), 4);
+
+    Ok(())
+}
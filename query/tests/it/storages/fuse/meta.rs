@@ -0,0 +1,79 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::storages::fuse::meta::decode;
+use databend_query::storages::fuse::meta::encode;
+use databend_query::storages::fuse::meta::SegmentInfo;
+use databend_query::storages::fuse::meta::META_BINARY_MAGIC;
+use databend_query::storages::fuse::meta::SEGMENT_FORMAT_VERSION;
+
+#[test]
+fn test_decode_versioned_segment() -> Result<()> {
+    // written before the format version is kept
+    let legacy = r#"{"blocks":[],"summary":{"row_count":3,"block_count":0,"uncompressed_byte_size":0,"compressed_byte_size":0,"col_stats":{}}}"#;
+    let segment: SegmentInfo = decode(legacy.as_bytes())?;
+    assert_eq!(segment.format_version, 0);
+    assert_eq!(segment.summary.row_count, 3);
+
+    let current = SegmentInfo {
+        format_version: SEGMENT_FORMAT_VERSION,
+        blocks: vec![],
+        summary: Default::default(),
+        ndv_sketches: Default::default(),
+    };
+    let mut value = serde_json::to_value(&current)?;
+    let segment: SegmentInfo = decode(&serde_json::to_vec(&value)?)?;
+    assert_eq!(segment.format_version, SEGMENT_FORMAT_VERSION);
+
+    // the fields added by a newer build of the same format are ignored
+    value["cluster_key"] = serde_json::json!("(a, b)");
+    let segment: SegmentInfo = decode(&serde_json::to_vec(&value)?)?;
+    assert_eq!(segment.format_version, SEGMENT_FORMAT_VERSION);
+
+    // a newer format is refused
+    value["format_version"] = serde_json::json!(SEGMENT_FORMAT_VERSION + 1);
+    let r = decode::<SegmentInfo>(&serde_json::to_vec(&value)?);
+    assert_eq!(
+        r.unwrap_err().code(),
+        ErrorCode::UnknownFormatVersion("").code()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_encode_binary_segment() -> Result<()> {
+    let mut segment = SegmentInfo {
+        format_version: SEGMENT_FORMAT_VERSION,
+        blocks: vec![],
+        summary: Default::default(),
+        ndv_sketches: Default::default(),
+    };
+    segment.summary.row_count = 5;
+
+    let bytes = encode(&segment)?;
+    assert!(bytes.starts_with(META_BINARY_MAGIC));
+    let decoded: SegmentInfo = decode(&bytes)?;
+    assert_eq!(decoded.format_version, SEGMENT_FORMAT_VERSION);
+    assert_eq!(decoded.summary.row_count, 5);
+
+    // a corrupted object
+    let r = decode::<SegmentInfo>(&[META_BINARY_MAGIC, b"not zstd"].concat());
+    assert!(r.is_err());
+
+    Ok(())
+}
@@ -12,10 +12,13 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod cache;
 mod io;
+mod meta;
 mod operations;
 mod pruning;
 mod statistics;
 mod table;
 mod table_functions;
+mod table_options;
 mod table_test_fixture;
@@ -0,0 +1,100 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_alter_column() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+
+    let qry = format!("create table {}.t(a Int, b Int) Engine = Fuse", db);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("insert into {}.t values(1, 10), (2, 20)", db);
+    execute_command(&qry, ctx.clone()).await?;
+
+    // the blocks written before the column is added have it filled with the default
+    let qry = format!("alter table {}.t add column c Int default 3", db);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("select * from {}.t order by a", db);
+    expects_ok("add_column", execute_query(&qry, ctx.clone()).await, vec![
+        "+---+----+---+",
+        "| a | b  | c |",
+        "+---+----+---+",
+        "| 1 | 10 | 3 |",
+        "| 2 | 20 | 3 |",
+        "+---+----+---+",
+    ])
+    .await?;
+
+    // the dropped column is neither read, nor written
+    let qry = format!("alter table {}.t drop column b", db);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("insert into {}.t values(3, 30)", db);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("select * from {}.t order by a", db);
+    expects_ok("drop_column", execute_query(&qry, ctx.clone()).await, vec![
+        "+---+----+",
+        "| a | c  |",
+        "+---+----+",
+        "| 1 | 3  |",
+        "| 2 | 3  |",
+        "| 3 | 30 |",
+        "+---+----+",
+    ])
+    .await?;
+
+    // the statistics of the renamed column still prune the blocks
+    let qry = format!("alter table {}.t rename column c to d", db);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("select a, d from {}.t where d > 3", db);
+    expects_ok(
+        "rename_column",
+        execute_query(&qry, ctx.clone()).await,
+        vec![
+            "+---+----+",
+            "| a | d  |",
+            "+---+----+",
+            "| 3 | 30 |",
+            "+---+----+",
+        ],
+    )
+    .await?;
+
+    let qry = format!("alter table {}.t drop column c", db);
+    expects_err(
+        "drop_unknown_column",
+        ErrorCode::UnknownColumn("").code(),
+        execute_command(&qry, ctx.clone()).await,
+    );
+
+    let qry = format!("alter table {}.t add column a Int", db);
+    expects_err(
+        "add_existing_column",
+        ErrorCode::BadArguments("").code(),
+        execute_command(&qry, ctx.clone()).await,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_analyze() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 1. the ids are 1, 2, 3 in each block
+    append_sample_data(2, &fixture).await?;
+    let qry = format!("analyze table {}.{}", db, tbl);
+    execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let qry =
+        "select column, num_rows, ndv, null_count, histogram_bounds from system.column_statistics";
+    let stream = execute_query(qry, ctx.clone()).await?;
+    let expected = vec![
+        "+--------+----------+-----+------------+------------------+",
+        "| column | num_rows | ndv | null_count | histogram_bounds |",
+        "+--------+----------+-----+------------+------------------+",
+        "| id     | 6        | 3   | 0          | [1, 2, 3]        |",
+        "+--------+----------+-----+------------+------------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+
+    // 2. exposed to the planning
+    let table = fixture.latest_default_table().await?;
+    let (stats, _) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(stats.column_ndvs, [("id".to_string(), 3)].into());
+    assert_eq!(stats.column_null_counts, [("id".to_string(), 0)].into());
+
+    // 3. but not once the table is changed, until analyzed again
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let (stats, _) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(stats.column_ndvs, [("id".to_string(), 3)].into());
+    assert!(stats.column_null_counts.is_empty());
+
+    let qry = format!("analyze table {}.{}", db, tbl);
+    execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let table = fixture.latest_default_table().await?;
+    let statistics = table.analyzed_statistics(ctx.clone()).await?.unwrap();
+    assert_eq!(statistics.num_rows, 9);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_delete() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 2 segments of 1 block each, the ids of each block are 1, 2, 3
+    append_sample_data(2, &fixture).await?;
+    let case_name = "delete";
+
+    // 1. no rows match, according to the column statistics, nothing is committed
+    let qry = format!("delete from {}.{} where id = 4", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+    check_data_dir(&fixture, case_name, 1, 2, 2).await;
+
+    // 2. the blocks are re-written without the matching rows
+    let qry = format!("delete from {}.{} where id = 2", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+    check_data_dir(&fixture, case_name, 2, 4, 4).await;
+
+    let qry = format!(
+        "select count(*) as count, sum(id) as sum from {}.{}",
+        db, tbl
+    );
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    let expected = vec![
+        "+-------+-----+",
+        "| count | sum |",
+        "+-------+-----+",
+        "| 4     | 8   |",
+        "+-------+-----+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+
+    // 3. all the rows are deleted without a predicate
+    let qry = format!("delete from {}.{}", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+
+    let qry = format!("select count(*) as count from {}.{}", db, tbl);
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 0     |",
+        "+-------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+
+    // 4. the predicate must be evaluable on the columns of the table
+    let qry = format!("delete from {}.{} where not_exists = 1", db, tbl);
+    assert!(execute_command(&qry, ctx.clone()).await.is_err());
+
+    Ok(())
+}
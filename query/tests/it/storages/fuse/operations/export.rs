@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::storages::fuse::FuseTable;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_export_manifest() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    fixture.create_default_table().await?;
+
+    // no snapshot yet, only the schema is exported
+    {
+        let table = fixture.latest_default_table().await?;
+        let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+        let manifest = fuse_table
+            .do_export_manifest(ctx.clone(), &db, None)
+            .await?;
+        assert_eq!(manifest.database, db);
+        assert_eq!(manifest.table, fixture.default_table_name());
+        assert_eq!(manifest.snapshot_id, None);
+        assert_eq!(manifest.schema.len(), 1);
+        assert_eq!(manifest.schema[0].name, "id");
+        assert_eq!(manifest.schema[0].data_type, "Int32");
+        assert!(!manifest.schema[0].nullable);
+        assert!(manifest.files.is_empty());
+    }
+
+    // 2 blocks of 3 rows
+    append_sample_data(2, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let first = fuse_table
+        .do_export_manifest(ctx.clone(), &db, None)
+        .await?;
+    assert!(first.snapshot_id.is_some());
+    assert_eq!(first.row_count, 6);
+    assert_eq!(first.files.len(), 2);
+    for file in &first.files {
+        assert_eq!(file.row_count, 3);
+        assert!(std::path::Path::new(&file.path).exists());
+    }
+
+    // the previous snapshot can still be exported after a new insertion
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let latest = fuse_table
+        .do_export_manifest(ctx.clone(), &db, None)
+        .await?;
+    assert_eq!(latest.row_count, 9);
+    assert_eq!(latest.files.len(), 3);
+    assert_ne!(latest.snapshot_id, first.snapshot_id);
+
+    let previous = fuse_table
+        .do_export_manifest(ctx.clone(), &db, first.snapshot_id.as_deref())
+        .await?;
+    assert_eq!(previous, first);
+
+    let res = fuse_table
+        .do_export_manifest(ctx.clone(), &db, Some("not_a_snapshot"))
+        .await;
+    assert_eq!(res.err().unwrap().code(), ErrorCode::BadArgumentsCode());
+
+    Ok(())
+}
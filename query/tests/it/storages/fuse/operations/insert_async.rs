@@ -0,0 +1,99 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use futures::future::try_join_all;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_async_insert() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let settings = ctx.get_settings();
+    settings.set_enable_async_insert(1)?;
+    settings.set_async_insert_busy_timeout_ms(1000)?;
+
+    // the concurrent inserts are committed together, as one snapshot
+    let inserts = (0..5).map(|i| {
+        let qry = format!("insert into {}.{} values({}), ({})", db, tbl, i, i + 10);
+        let ctx = ctx.clone();
+        async move { execute_command(&qry, ctx).await }
+    });
+    try_join_all(inserts).await?;
+
+    let qry = format!("select count(*) as count from {}.{}", db, tbl);
+    expects_ok(
+        "rows_of_all_inserts",
+        execute_query(&qry, ctx.clone()).await,
+        vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 10    |",
+            "+-------+",
+        ],
+    )
+    .await?;
+
+    let qry = format!(
+        "select count(*) as count from fuse_history('{}', '{}')",
+        db, tbl
+    );
+    expects_ok(
+        "one_snapshot",
+        execute_query(&qry, ctx.clone()).await,
+        vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 1     |",
+            "+-------+",
+        ],
+    )
+    .await?;
+
+    // the inserts reaching the max data size are written at once, without waiting
+    settings.set_async_insert_max_data_size(1)?;
+    let qry = format!("insert into {}.{} values(100)", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+
+    let qry = format!(
+        "select count(*) as count from fuse_history('{}', '{}')",
+        db, tbl
+    );
+    expects_ok(
+        "written_at_once",
+        execute_query(&qry, ctx.clone()).await,
+        vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 2     |",
+            "+-------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
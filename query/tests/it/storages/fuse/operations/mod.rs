@@ -13,8 +13,22 @@
 //  limitations under the License.
 //
 
+mod alter_column;
+mod analyze;
+mod delete;
+mod export;
+mod insert_async;
+mod navigate;
+mod notify;
 mod optimize;
 mod part_info;
 mod purge_drop;
 mod purge_truncate;
+mod read;
 mod read_plan;
+mod refresh;
+mod sample;
+mod set_options;
+mod transaction;
+mod vacuum;
+mod virtual_columns;
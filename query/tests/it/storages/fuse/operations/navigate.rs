@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::storages::fuse::FuseTable;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_navigate() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    // 1 block of 3 rows
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let first_snapshot = fuse_table
+        .do_export_manifest(ctx.clone(), &db, None)
+        .await?
+        .snapshot_id
+        .unwrap();
+
+    // then 2 more blocks
+    append_sample_data(2, &fixture).await?;
+
+    let cases = vec![
+        ("latest", "".to_string(), "9"),
+        (
+            "snapshot",
+            format!("at (snapshot => '{}')", first_snapshot),
+            "3",
+        ),
+        (
+            "timestamp",
+            "AT (TIMESTAMP => '2999-01-01 00:00:00')".to_string(),
+            "9",
+        ),
+    ];
+    for (case_name, at, count) in cases {
+        let qry = format!("select count(*) from {}.{} {}", db, tbl, at);
+        let row = format!("| {:<8} |", count);
+        let expected = vec![
+            "+----------+",
+            "| count(0) |",
+            "+----------+",
+            row.as_str(),
+            "+----------+",
+        ];
+        expects_ok(case_name, execute_query(&qry, ctx.clone()).await, expected).await?;
+    }
+
+    let qry = format!(
+        "select * from {}.{} at (snapshot => 'not_a_snapshot')",
+        db, tbl
+    );
+    expects_err(
+        "unknown_snapshot",
+        ErrorCode::bad_arguments_code(),
+        execute_query(&qry, ctx.clone()).await,
+    );
+
+    let qry = format!(
+        "select * from {}.{} at (timestamp => '2000-01-01')",
+        db, tbl
+    );
+    expects_err(
+        "no_snapshot_before",
+        ErrorCode::bad_arguments_code(),
+        execute_query(&qry, ctx.clone()).await,
+    );
+
+    Ok(())
+}
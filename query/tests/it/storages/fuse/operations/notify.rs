@@ -0,0 +1,85 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_infallible::Mutex;
+use databend_query::common::service::HttpShutdownHandler;
+use databend_query::configs::Config;
+use databend_query::storages::fuse::operations::TableChangeEvent;
+use poem::post;
+use poem::Request;
+use poem::Route;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fuse_commit_notify() -> Result<()> {
+    let events = Arc::new(Mutex::new(Vec::<TableChangeEvent>::new()));
+    let received = events.clone();
+    let mut webhook_srv = HttpShutdownHandler::create("webhook".to_string());
+    let webhook_route = Route::new().at(
+        "/changes",
+        post(poem::endpoint::make(move |req: Request| {
+            let received = received.clone();
+            async move {
+                let body = req.into_body().into_vec().await.unwrap();
+                received.lock().push(serde_json::from_slice(&body).unwrap());
+            }
+        })),
+    );
+    let webhook_addr = webhook_srv
+        .start_service("127.0.0.1:0".parse()?, None, webhook_route)
+        .await?;
+
+    let mut config = Config::default();
+    config.query.table_change_webhook_url = format!("http://{}/changes", webhook_addr);
+    let fixture = TestFixture::new_with_config(config).await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values (1), (2), (3)", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("delete from {}.{} where id = 2", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("truncate table {}.{}", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+
+    let events = events.lock().clone();
+    let deltas = events.iter().map(|e| e.row_delta).collect::<Vec<_>>();
+    assert_eq!(deltas, vec![3, -1, -2]);
+
+    let counts = events.iter().map(|e| e.row_count).collect::<Vec<_>>();
+    assert_eq!(counts, vec![3, 2, 0]);
+
+    // the events are chained by the snapshots
+    assert_eq!(events[0].prev_snapshot_id, None);
+    assert_eq!(
+        events[1].prev_snapshot_id.as_ref(),
+        Some(&events[0].snapshot_id)
+    );
+    assert_eq!(
+        events[2].prev_snapshot_id.as_ref(),
+        Some(&events[1].snapshot_id)
+    );
+    assert!(events.iter().all(|e| e.table_id == events[0].table_id));
+
+    webhook_srv.shutdown(true).await;
+    Ok(())
+}
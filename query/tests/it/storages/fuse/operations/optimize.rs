@@ -104,5 +104,28 @@ async fn test_fuse_history_optimize_compact() -> Result<()> {
         execute_query(qry.as_str(), fixture.ctx()).await,
         expected,
     )
+    .await?;
+
+    // the 5 small blocks are merged into 1 block of a new segment,
+    // the replaced ones are kept until purged
+    check_data_dir(&fixture, "compact", 6, 6, 6).await;
+
+    // the latest snapshot
+    let expected = vec![
+        "+---------------+-------------+-----------+",
+        "| segment_count | block_count | row_count |",
+        "+---------------+-------------+-----------+",
+        "| 1             | 1           | 15        |",
+        "+---------------+-------------+-----------+",
+    ];
+    let qry = format!(
+        "select segment_count, block_count, row_count from fuse_history('{}', '{}') limit 1",
+        db, tbl
+    );
+    expects_ok(
+        "compacted_snapshot",
+        execute_query(qry.as_str(), fixture.ctx()).await,
+        expected,
+    )
     .await
 }
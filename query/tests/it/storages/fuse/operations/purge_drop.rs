@@ -15,6 +15,12 @@
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+use databend_query::configs::Config;
 
 use crate::storages::fuse::table_test_fixture::append_sample_data;
 use crate::storages::fuse::table_test_fixture::check_data_dir;
@@ -23,7 +29,10 @@ use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test]
 async fn test_fuse_history_truncate_in_drop_stmt() -> Result<()> {
-    let fixture = TestFixture::new().await;
+    // without the recycle bin, the files are purged by the drop
+    let mut config = Config::default();
+    config.query.drop_retention_hours = 0;
+    let fixture = TestFixture::new_with_config(config).await;
     let db = fixture.default_db_name();
     let tbl = fixture.default_table_name();
     let ctx = fixture.ctx();
@@ -45,3 +54,126 @@ async fn test_fuse_history_truncate_in_drop_stmt() -> Result<()> {
     .await;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fuse_drop_table_into_recycle_bin() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // ingests some test data: 1 snapshot, 1 segment, 1 block
+    append_sample_data(1, &fixture).await?;
+    let qry = format!("drop table '{}'.'{}'", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    // the dropped table is kept in the recycle bin, so are its files
+    check_data_dir(
+        &fixture,
+        "drop table: files are kept in the recycle bin",
+        1,
+        1,
+        1,
+    )
+    .await;
+
+    // undrop brings it back, and it could be dropped again
+    let qry = format!("undrop table '{}'.'{}'", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("drop table '{}'.'{}'", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    // vacuum purges the expired dropped tables
+    execute_command("vacuum drop table retain 0 hours", ctx.clone()).await?;
+    check_data_dir(
+        &fixture,
+        "vacuum drop table: there should be no file left",
+        0,
+        0,
+        0,
+    )
+    .await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_replace_table_purges_the_replaced_data() -> Result<()> {
+    // without the recycle bin, the files of the replaced table are purged by the replace
+    let mut config = Config::default();
+    config.query.drop_retention_hours = 0;
+    let fixture = TestFixture::new_with_config(config).await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    grant_create_on_database(&fixture);
+    fixture.create_default_table().await?;
+
+    append_sample_data(10, &fixture).await?;
+    let qry = format!("create or replace table '{}'.'{}' (id int)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(
+        &fixture,
+        "replace table: there should be no file left",
+        0,
+        0,
+        0,
+    )
+    .await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_replace_table_into_recycle_bin() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    grant_create_on_database(&fixture);
+    fixture.create_default_table().await?;
+
+    // ingests some test data: 1 snapshot, 1 segment, 1 block
+    append_sample_data(1, &fixture).await?;
+    let qry = format!("create or replace table '{}'.'{}' (id int)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    // the replaced table is kept in the recycle bin, so are its files
+    check_data_dir(
+        &fixture,
+        "replace table: files are kept in the recycle bin",
+        1,
+        1,
+        1,
+    )
+    .await;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    let dropped = user_mgr.get_dropped_tables().await?;
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(dropped[0].name(), tbl);
+
+    // vacuum purges the expired dropped tables
+    execute_command("vacuum drop table retain 0 hours", ctx.clone()).await?;
+    check_data_dir(
+        &fixture,
+        "vacuum drop table: there should be no file left",
+        0,
+        0,
+        0,
+    )
+    .await;
+    Ok(())
+}
+
+// Replacing a table needs the CREATE privilege on its database.
+fn grant_create_on_database(fixture: &TestFixture) {
+    let mut user = UserInfo::new(
+        "test_user".to_string(),
+        "%".to_string(),
+        Vec::from("pass"),
+        PasswordType::Sha256,
+    );
+    let mut privileges = UserPrivilegeSet::empty();
+    privileges.set_privilege(UserPrivilegeType::Create);
+    let object = GrantObject::Database(fixture.default_db_name());
+    user.grants
+        .grant_privileges("test_user", "%", &object, privileges);
+    fixture.ctx().get_session().set_current_user(user);
+}
@@ -14,10 +14,13 @@
 //
 use common_base::tokio;
 use common_exception::Result;
+use databend_query::storages::fuse::FuseTable;
 
 use crate::storages::fuse::table_test_fixture::append_sample_data;
 use crate::storages::fuse::table_test_fixture::check_data_dir;
 use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
 use crate::storages::fuse::table_test_fixture::history_should_have_only_one_item;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
@@ -51,3 +54,52 @@ async fn test_fuse_truncate_purge_stmt() -> Result<()> {
     check_data_dir(&fixture, "truncate_after_purge_check_file_items", 1, 0, 0).await;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fuse_truncate_keeps_history() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 2 blocks of 3 rows
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let truncated_snapshot = fuse_table
+        .do_export_manifest(ctx.clone(), &db, None)
+        .await?
+        .snapshot_id
+        .unwrap();
+
+    let qry = format!("truncate table '{}'.'{}'", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    // an empty snapshot is committed, the files of the previous ones are left there
+    check_data_dir(&fixture, "truncate_keeps_history", 3, 2, 2).await;
+
+    let cases = vec![
+        ("latest", "".to_string(), "0"),
+        (
+            "before_truncate",
+            format!("at (snapshot => '{}')", truncated_snapshot),
+            "6",
+        ),
+    ];
+    for (case_name, at, count) in cases {
+        let qry = format!("select count(*) from {}.{} {}", db, tbl, at);
+        let row = format!("| {:<8} |", count);
+        let expected = vec![
+            "+----------+",
+            "| count(0) |",
+            "+----------+",
+            row.as_str(),
+            "+----------+",
+        ];
+        expects_ok(case_name, execute_query(&qry, ctx.clone()).await, expected).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use common_base::tokio;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fuse_read_part_scan_metrics() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // a block for each insertion
+    let qry = format!("insert into {}.{} values (1), (2)", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("insert into {}.{} values (3)", db, tbl);
+    execute_command(&qry, ctx.clone()).await?;
+    assert!(ctx.get_query_profile().get_part_scans().is_empty());
+
+    let qry = format!("select * from {}.{}", db, tbl);
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await?;
+
+    let part_scans = ctx.get_query_profile().get_part_scans();
+    assert_eq!(part_scans.len(), 2);
+    assert!(part_scans.iter().all(|p| p.read_bytes > 0 && !p.cache_hit));
+
+    let scan_profile = ctx.get_query_profile().get_scan_profile();
+    assert_eq!(scan_profile.parts, 2);
+    assert_eq!(scan_profile.cache_misses, 2);
+    assert_eq!(
+        scan_profile.read_bytes,
+        part_scans.iter().map(|p| p.read_bytes).sum::<u64>()
+    );
+    assert!(part_scans
+        .iter()
+        .any(|p| p.part == scan_profile.slowest_part));
+
+    Ok(())
+}
@@ -53,6 +53,7 @@ fn test_to_partitions() -> Result<()> {
             path: "".to_string(),
             meta_size: 0,
         },
+        bloom_filter_location: None,
     };
 
     let blocks_metas = (0..num_of_block)
@@ -88,6 +89,7 @@ fn test_to_partitions() -> Result<()> {
         filters: vec![],
         limit: None,
         order_by: vec![],
+        sample: None,
     });
     let (stats, _) = FuseTable::to_partitions(&blocks_metas, push_down);
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
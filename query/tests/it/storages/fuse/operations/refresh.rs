@@ -0,0 +1,66 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpsertTableOptionReq;
+use databend_query::catalogs::Catalog;
+use databend_query::storages::fuse::TBL_OPT_KEY_DATA_SIZE;
+use databend_query::storages::fuse::TBL_OPT_KEY_DATA_SIZE_COMPRESSED;
+use databend_query::storages::fuse::TBL_OPT_KEY_ROW_COUNT;
+use databend_query::storages::CommitWatcher;
+use maplit::hashmap;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_refresh_statistics() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(2, &fixture).await?;
+
+    // the counters are missing, as if the snapshot is committed by a node not keeping them
+    let table = fixture.latest_default_table().await?;
+    let ident = &table.get_table_info().ident;
+    ctx.get_catalog()
+        .upsert_table_option(UpsertTableOptionReq {
+            table_id: ident.table_id,
+            seq: MatchSeq::Exact(ident.version),
+            options: hashmap! {
+                TBL_OPT_KEY_ROW_COUNT.to_string() => None,
+                TBL_OPT_KEY_DATA_SIZE.to_string() => None,
+                TBL_OPT_KEY_DATA_SIZE_COMPRESSED.to_string() => None,
+            },
+            schema: None,
+        })
+        .await?;
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.statistics()?, None);
+
+    // refreshed from the snapshot
+    let mut watcher = CommitWatcher::default();
+    assert_eq!(watcher.refresh(ctx.clone()).await?, 1);
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.statistics()?.map(|s| s.num_rows), Some(2 * 3));
+
+    // up to date
+    assert_eq!(watcher.refresh(ctx.clone()).await?, 0);
+    assert!(!table.refresh_statistics(ctx.clone()).await?);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_sample() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    // 4 blocks of 3 rows
+    append_sample_data(4, &fixture).await?;
+
+    // the blocks kept are rounded up, while all the rows are kept by a sample of 100%
+    let cases = vec![
+        ("all", "SAMPLE 100%", "12"),
+        ("one block", "SAMPLE BLOCK (1)", "3"),
+        ("half of the blocks", "TABLESAMPLE SYSTEM (50 PERCENT)", "6"),
+        ("alias", "sample 25% AS s", "3"),
+        ("all rows", "SAMPLE ROW (100)", "12"),
+        (
+            "time travel",
+            "AT (TIMESTAMP => '2999-01-01 00:00:00') SAMPLE 75%",
+            "9",
+        ),
+    ];
+    for (case_name, sample, count) in cases {
+        let qry = format!("select count(*) from {}.{} {}", db, tbl, sample);
+        let row = format!("| {:<8} |", count);
+        let expected = vec![
+            "+----------+",
+            "| count(0) |",
+            "+----------+",
+            row.as_str(),
+            "+----------+",
+        ];
+        expects_ok(case_name, execute_query(&qry, ctx.clone()).await, expected).await?;
+    }
+
+    for percent in ["0", "101"] {
+        let qry = format!("select * from {}.{} sample {}%", db, tbl, percent);
+        expects_err(
+            "percent out of range",
+            ErrorCode::bad_arguments_code(),
+            execute_query(&qry, ctx.clone()).await,
+        );
+    }
+
+    expects_err(
+        "not a fuse table",
+        ErrorCode::un_implement_code(),
+        execute_query("select * from system.one sample 10%", ctx.clone()).await,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_options() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+
+    let qry = format!(
+        "create table {}.t(a Int) Engine = Fuse compression = 'zstd' no_such_option = 1",
+        db
+    );
+    expects_err(
+        "unknown_option",
+        ErrorCode::BadOption("").code(),
+        execute_command(&qry, ctx.clone()).await,
+    );
+
+    let qry = format!(
+        "create table {}.t(a Int) Engine = Fuse compression = 'zstd' bloom_filter_columns = 'a'",
+        db
+    );
+    execute_command(&qry, ctx.clone()).await?;
+    let qry = format!("insert into {}.t values(1)", db);
+    execute_command(&qry, ctx.clone()).await?;
+
+    let qry = format!("alter table {}.t set change_tracking = 'yes'", db);
+    expects_err(
+        "bad_value",
+        ErrorCode::BadOption("").code(),
+        execute_command(&qry, ctx.clone()).await,
+    );
+
+    let qry = format!(
+        "alter table {}.t set change_tracking = true data_retention_time_in_days = 7",
+        db
+    );
+    execute_command(&qry, ctx.clone()).await?;
+
+    // the options kept by the engine itself, e.g. the snapshot location, are not shown
+    let qry = format!(
+        "select name, options from system.tables where database = '{}'",
+        db
+    );
+    expects_ok(
+        "system_tables",
+        execute_query(&qry, ctx.clone()).await,
+        vec![
+            "+------+----------------------------------------------------------------------------------------------------+",
+            "| name | options                                                                                            |",
+            "+------+----------------------------------------------------------------------------------------------------+",
+            "| t    | BLOOM_FILTER_COLUMNS='a' CHANGE_TRACKING='true' COMPRESSION='zstd' DATA_RETENTION_TIME_IN_DAYS='7' |",
+            "+------+----------------------------------------------------------------------------------------------------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
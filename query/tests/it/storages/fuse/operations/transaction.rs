@@ -0,0 +1,137 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::clusters::Cluster;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sessions::SessionRef;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_transaction() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+    let qry = format!("create table {}.t2(id Int)", db);
+    execute_command(&qry, fixture.ctx()).await?;
+
+    let sessions = fixture.ctx().get_sessions_manager();
+    let session = sessions.create_session("TestSession")?;
+    let other_session = sessions.create_session("TestSession")?;
+
+    // each statement runs in a query context of its own, like in a client session
+    let run = |session: &SessionRef, qry: String| {
+        let ctx = new_query_ctx(&fixture, session);
+        async move { execute_command(&qry, ctx?).await }
+    };
+
+    expects_count(&fixture, &other_session, &db, &tbl, "empty", 0).await?;
+
+    run(&session, "begin".to_string()).await?;
+    expects_err(
+        "nested_transaction",
+        ErrorCode::TransactionError("").code(),
+        run(&session, "begin".to_string()).await,
+    );
+    run(
+        &session,
+        format!("insert into {}.{} values(1), (2)", db, tbl),
+    )
+    .await?;
+    run(&session, format!("insert into {}.t2 values(3)", db)).await?;
+    run(&session, format!("insert into {}.{} values(4)", db, tbl)).await?;
+
+    // the changes are seen in the transaction only
+    expects_count(&fixture, &session, &db, &tbl, "staged_tbl", 3).await?;
+    expects_count(&fixture, &session, &db, "t2", "staged_t2", 1).await?;
+    expects_count(&fixture, &other_session, &db, &tbl, "invisible_tbl", 0).await?;
+    expects_count(&fixture, &other_session, &db, "t2", "invisible_t2", 0).await?;
+
+    expects_err(
+        "truncate_in_transaction",
+        ErrorCode::TransactionError("").code(),
+        run(&session, format!("truncate table {}.{}", db, tbl)).await,
+    );
+
+    run(&session, "commit".to_string()).await?;
+    expects_count(&fixture, &other_session, &db, &tbl, "committed_tbl", 3).await?;
+    expects_count(&fixture, &other_session, &db, "t2", "committed_t2", 1).await?;
+
+    // the changes are discarded by ROLLBACK
+    run(&session, "start transaction".to_string()).await?;
+    run(&session, format!("insert into {}.{} values(5)", db, tbl)).await?;
+    run(&session, format!("insert into {}.t2 values(6)", db)).await?;
+    run(&session, "rollback".to_string()).await?;
+    expects_count(&fixture, &session, &db, &tbl, "rolled_back_tbl", 3).await?;
+    expects_count(&fixture, &session, &db, "t2", "rolled_back_t2", 1).await?;
+
+    // COMMIT fails if a table is changed by others meanwhile, none of the tables is changed
+    run(&session, "begin".to_string()).await?;
+    run(&session, format!("insert into {}.t2 values(7)", db)).await?;
+    run(&session, format!("insert into {}.{} values(8)", db, tbl)).await?;
+    run(
+        &other_session,
+        format!("insert into {}.{} values(9)", db, tbl),
+    )
+    .await?;
+    expects_err(
+        "conflict",
+        ErrorCode::TableVersionMissMatch("").code(),
+        run(&session, "commit".to_string()).await,
+    );
+    expects_count(&fixture, &session, &db, &tbl, "conflict_tbl", 4).await?;
+    expects_count(&fixture, &session, &db, "t2", "conflict_t2", 1).await?;
+
+    Ok(())
+}
+
+fn new_query_ctx(fixture: &TestFixture, session: &SessionRef) -> Result<Arc<QueryContext>> {
+    Ok(QueryContext::from_shared(QueryContextShared::try_create(
+        fixture.ctx().get_config(),
+        Arc::new(session.as_ref().clone()),
+        Cluster::empty(),
+    )?))
+}
+
+async fn expects_count(
+    fixture: &TestFixture,
+    session: &SessionRef,
+    db: &str,
+    tbl: &str,
+    case_name: &str,
+    count: u64,
+) -> Result<()> {
+    let qry = format!("select count(*) as count from {}.{}", db, tbl);
+    let ctx = new_query_ctx(fixture, session)?;
+    expects_ok(case_name, execute_query(&qry, ctx).await, vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        &format!("| {:<5} |", count),
+        "+-------+",
+    ])
+    .await
+}
@@ -0,0 +1,135 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::storages::expire_snapshots;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::append_sample_data_overwrite;
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::history_should_have_only_one_item;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_vacuum() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 2 snapshots, the data of the first one is overwritten by the second one
+    append_sample_data(1, &fixture).await?;
+    append_sample_data_overwrite(1, true, &fixture).await?;
+    let case_name = "vacuum";
+
+    // 1. the history is within the default retention
+    let qry = format!("vacuum table {}.{}", db, tbl);
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    let expected = vec![
+        "+-----------+----------+--------+-------------+",
+        "| snapshots | segments | blocks | block_bytes |",
+        "+-----------+----------+--------+-------------+",
+        "| 0         | 0        | 0      | 0           |",
+        "+-----------+----------+--------+-------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+    check_data_dir(&fixture, case_name, 2, 2, 2).await;
+
+    // 2. dry run reports the expired snapshot and its data, nothing is removed
+    let qry = format!("vacuum table {}.{} retain 0 hours dry run", db, tbl);
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    let expected = vec![
+        r"\+-----------\+----------\+--------\+-------------\+",
+        r"\| snapshots \| segments \| blocks \| block_bytes \|",
+        r"\+-----------\+----------\+--------\+-------------\+",
+        r"\| 1         \| 1        \| 1      \| [1-9]\d*\s*\|",
+        r"\+-----------\+----------\+--------\+-------------\+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq_with_regex(
+        expected.clone(),
+        &stream.try_collect::<Vec<_>>().await?,
+    );
+    check_data_dir(&fixture, case_name, 2, 2, 2).await;
+
+    // 3. removes them
+    let qry = format!("vacuum table {}.{} retain 0 hours", db, tbl);
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    common_datablocks::assert_blocks_sorted_eq_with_regex(
+        expected,
+        &stream.try_collect::<Vec<_>>().await?,
+    );
+    check_data_dir(&fixture, case_name, 1, 1, 1).await;
+    history_should_have_only_one_item(&fixture, case_name).await?;
+
+    // 4. the current snapshot is always kept
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    let expected = vec![
+        "+-----------+----------+--------+-------------+",
+        "| snapshots | segments | blocks | block_bytes |",
+        "+-----------+----------+--------+-------------+",
+        "| 0         | 0        | 0      | 0           |",
+        "+-----------+----------+--------+-------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+
+    let qry = format!("select count(*) as count from {}.{}", db, tbl);
+    let stream = execute_query(&qry, ctx.clone()).await?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_expire_snapshots() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    append_sample_data(1, &fixture).await?;
+    append_sample_data_overwrite(1, true, &fixture).await?;
+    let case_name = "expire_snapshots";
+
+    // 1. the history is within the default retention
+    assert_eq!(expire_snapshots(ctx.clone()).await?, 0);
+    check_data_dir(&fixture, case_name, 2, 2, 2).await;
+
+    // 2. the retention of the table overrides the default one
+    let qry = format!(
+        "alter table {}.{} set data_retention_time_in_days = 0",
+        db, tbl
+    );
+    execute_command(&qry, ctx.clone()).await?;
+    assert_eq!(expire_snapshots(ctx.clone()).await?, 1);
+    check_data_dir(&fixture, case_name, 1, 1, 1).await;
+    history_should_have_only_one_item(&fixture, case_name).await?;
+
+    // 3. the current snapshot is always kept
+    assert_eq!(expire_snapshots(ctx.clone()).await?, 0);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_virtual_columns() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    // 4 blocks of 3 rows
+    append_sample_data(4, &fixture).await?;
+
+    // the virtual columns are left out of `*`
+    let qry = format!("select * from {}.{} limit 1", db, tbl);
+    let expected = vec!["+----+", "| id |", "+----+", "| 1  |", "+----+"];
+    expects_ok("wildcard", execute_query(&qry, ctx.clone()).await, expected).await?;
+
+    let qry = format!(
+        "select count(distinct _block_location) as blocks, count(distinct _snapshot_id) as snapshots, \
+         max(_row_number) as max_row from {}.{}",
+        db, tbl
+    );
+    let expected = vec![
+        "+--------+-----------+---------+",
+        "| blocks | snapshots | max_row |",
+        "+--------+-----------+---------+",
+        "| 4      | 1         | 2       |",
+        "+--------+-----------+---------+",
+    ];
+    expects_ok(
+        "only virtual",
+        execute_query(&qry, ctx.clone()).await,
+        expected,
+    )
+    .await?;
+
+    let qry = format!(
+        "select count(*) as c from {}.{} where _row_number = id - 1",
+        db, tbl
+    );
+    let expected = vec!["+----+", "| c  |", "+----+", "| 12 |", "+----+"];
+    expects_ok(
+        "stored and virtual",
+        execute_query(&qry, ctx.clone()).await,
+        expected,
+    )
+    .await?;
+
+    // the id of the snapshot read is the latest one
+    let first_value = |blocks: Vec<DataBlock>| blocks[0].column(0).try_get(0);
+    let qry = format!("select _snapshot_id from {}.{} limit 1", db, tbl);
+    let snapshot_id = first_value(
+        execute_query(&qry, ctx.clone())
+            .await?
+            .try_collect()
+            .await?,
+    )?;
+    let qry = format!("select snapshot_id from fuse_history('{}', '{}')", db, tbl);
+    let latest = first_value(
+        execute_query(&qry, ctx.clone())
+            .await?
+            .try_collect()
+            .await?,
+    )?;
+    assert_eq!(latest, snapshot_id);
+
+    Ok(())
+}
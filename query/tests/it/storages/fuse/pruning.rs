@@ -29,6 +29,7 @@ use common_planners::Extras;
 use databend_query::catalogs::Catalog;
 use databend_query::storages::fuse::io::SnapshotReader;
 use databend_query::storages::fuse::pruning::apply_block_pruning;
+use databend_query::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
 use databend_query::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
 use databend_query::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use futures::TryStreamExt;
@@ -49,6 +50,7 @@ async fn test_block_pruner() -> Result<()> {
     // create test table
     let crate_table_plan = CreateTableReq {
         if_not_exists: false,
+        or_replace: false,
         db: fixture.default_db_name(),
         table: test_tbl_name.to_string(),
         table_meta: TableMeta {
@@ -138,11 +140,120 @@ async fn test_block_pruner() -> Result<()> {
         &snapshot,
         table.get_table_info().schema(),
         &Some(extra),
-        da,
+        da.clone(),
         ctx.clone(),
     )
     .await?;
     assert_eq!(num - 1, blocks.len() as u64);
 
+    // all the pushed down filters are applied
+    let mut extra = Extras::default();
+    extra.filters = vec![col("a").gt(lit(0)), col("b").gt(lit(30))];
+
+    let blocks = apply_block_pruning(
+        &snapshot,
+        table.get_table_info().schema(),
+        &Some(extra),
+        da,
+        ctx.clone(),
+    )
+    .await?;
+    assert_eq!(num - 3, blocks.len() as u64);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_block_pruner_with_bloom_filter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let test_tbl_name = "test_bloom_filter";
+    let test_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt64, false),
+        DataField::new("b", DataType::UInt64, false),
+    ]);
+
+    let crate_table_plan = CreateTableReq {
+        if_not_exists: false,
+        or_replace: false,
+        db: fixture.default_db_name(),
+        table: test_tbl_name.to_string(),
+        table_meta: TableMeta {
+            schema: test_schema.clone(),
+            engine: "FUSE".to_string(),
+            options: [
+                (TBL_OPT_KEY_CHUNK_BLOCK_NUM.to_owned(), "1".to_owned()),
+                (TBL_OPT_KEY_BLOOM_FILTER_COLUMNS.to_owned(), "b".to_owned()),
+            ]
+            .into(),
+            ..Default::default()
+        },
+    };
+
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+    let table = catalog
+        .get_table(fixture.default_db_name().as_str(), test_tbl_name)
+        .await?;
+
+    // the min/max of b are the same in all the blocks, only the bloom filters can prune them
+    let num = 10;
+    let blocks = (0..num)
+        .into_iter()
+        .map(|idx| {
+            Ok(DataBlock::create_by_array(test_schema.clone(), vec![
+                Series::new(vec![idx, idx, idx]),
+                Series::new(vec![0u64, idx * 2 + 1, 100]),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let da = ctx.get_data_accessor()?;
+    let stream = Box::pin(futures::stream::iter(blocks));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    let table = catalog
+        .get_table(fixture.default_db_name().as_str(), test_tbl_name)
+        .await?;
+    let snapshot_loc = table
+        .get_table_info()
+        .options()
+        .get(TBL_OPT_KEY_SNAPSHOT_LOC)
+        .unwrap();
+    let snapshot =
+        SnapshotReader::read(da.as_ref(), snapshot_loc.clone(), ctx.get_table_cache()).await?;
+
+    let prune = |pred| {
+        let (snapshot, schema) = (&snapshot, table.get_table_info().schema());
+        let (da, ctx) = (da.clone(), ctx.clone());
+        async move {
+            let mut extra = Extras::default();
+            extra.filters = vec![pred];
+            let push_downs = Some(extra);
+            apply_block_pruning(snapshot, schema, &push_downs, da, ctx).await
+        }
+    };
+
+    // point query, only the block with b = 5 is kept
+    let blocks = prune(col("b").eq(lit(5))).await?;
+    assert_eq!(1, blocks.len());
+    assert!(blocks[0].bloom_filter_location.is_some());
+
+    // in the range of all the blocks, but in none of them
+    let blocks = prune(col("b").eq(lit(4))).await?;
+    assert_eq!(0, blocks.len());
+
+    // the range filter and the bloom filters are both applied
+    let blocks = prune(col("a").gt(lit(2)).and(col("b").eq(lit(5)))).await?;
+    assert_eq!(0, blocks.len());
+
+    // no bloom filter on a
+    let blocks = prune(col("a").eq(lit(100)).or(col("b").eq(lit(5)))).await?;
+    assert_eq!(num as usize, blocks.len());
+
     Ok(())
 }
@@ -56,13 +56,40 @@ fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_col_stats_reduce_partial_columns() -> common_exception::Result<()> {
+    // the block written before column `b` is added has no statistics of it
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::Int32, false),
+    ]);
+    let old_block = DataBlock::create_by_array(
+        DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]),
+        vec![Series::new(vec![1, 2, 3])],
+    );
+    let new_block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![4, 5, 6]),
+        Series::new(vec![7, 8, 9]),
+    ]);
+    let col_stats = vec![
+        StatisticsAccumulator::acc_columns(&old_block)?,
+        StatisticsAccumulator::acc_columns(&new_block)?,
+    ];
+    let r = reducers::reduce_block_stats(&col_stats, &schema)?;
+    assert_eq!(1, r.len());
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.min, DataValue::Int32(Some(1)));
+    assert_eq!(col_stats.max, DataValue::Int32(Some(6)));
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_accumulator() -> common_exception::Result<()> {
     let blocks = TestFixture::gen_sample_blocks(10, 1);
     let mut stats_acc = accumulator::StatisticsAccumulator::new();
     for item in blocks {
         let block_acc = stats_acc.begin(&item?)?;
-        stats_acc = block_acc.end(1, "".to_owned());
+        stats_acc = block_acc.end(1, "".to_owned(), None);
     }
     assert_eq!(10, stats_acc.blocks_statistics.len());
     // TODO more cases here pls
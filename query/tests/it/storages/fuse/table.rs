@@ -21,9 +21,13 @@ use databend_query::catalogs::Catalog;
 use databend_query::interpreters::InterpreterFactory;
 use databend_query::sql::PlanParser;
 use databend_query::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+use databend_query::storages::TableStatistics;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::append_sample_data_overwrite;
+use crate::storages::fuse::table_test_fixture::execute_query;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test]
@@ -69,12 +73,20 @@ async fn test_fuse_table_simple_case() -> Result<()> {
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
+            read_order: vec![],
         })
         .await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
     assert_eq!(rows, num_blocks as usize * 3);
 
+    // the footers of the blocks read are cached
+    let meta_cache = ctx.get_parquet_meta_cache();
+    assert_eq!(
+        (*meta_cache).as_ref().map(|c| c.len()),
+        Some(num_blocks as usize)
+    );
+
     let expected = vec![
         "+----+", //
         "| id |", //
@@ -130,6 +142,7 @@ async fn test_fuse_table_simple_case() -> Result<()> {
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
+            read_order: vec![],
         })
         .await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
@@ -226,6 +239,90 @@ async fn test_fuse_table_truncate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_table_statistics() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 1. a table without snapshot is empty
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.statistics()?, Some(TableStatistics::default()));
+
+    // 2. counters are accumulated by appends
+    append_sample_data(2, &fixture).await?;
+    append_sample_data(3, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let stats = table.statistics()?.unwrap();
+    assert_eq!(stats.num_rows, 5 * 3);
+    assert!(stats.data_size > 0);
+    assert!(stats.data_size_compressed > 0);
+
+    // 3. and replaced by overwrites
+    append_sample_data_overwrite(1, true, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let overwritten = table.statistics()?.unwrap();
+    assert_eq!(overwritten.num_rows, 3);
+    assert!(overwritten.data_size < stats.data_size);
+
+    // 4. the usage of the database is summed up from the counters
+    let query = format!(
+        "select table_count, num_rows from system.storage_usage where database = '{}'",
+        fixture.default_db_name()
+    );
+    let stream = execute_query(&query, ctx.clone()).await?;
+    let expected = vec![
+        "+-------------+----------+",
+        "| table_count | num_rows |",
+        "+-------------+----------+",
+        "| 1           | 3        |",
+        "+-------------+----------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, &stream.try_collect::<Vec<_>>().await?);
+
+    // 5. reset by truncate
+    let truncate_plan = TruncateTablePlan {
+        db: fixture.default_db_name(),
+        table: fixture.default_table_name(),
+        purge: false,
+    };
+    table.truncate(ctx.clone(), truncate_plan).await?;
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.statistics()?, Some(TableStatistics::default()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_ndv_estimates() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 1. the sketches of the segments are merged at plan time, the ids are 1, 2, 3 in each block
+    append_sample_data(2, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let (stats, _) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(stats.column_ndvs, [("id".to_string(), 3)].into());
+
+    // 2. and kept by the compaction
+    let query = format!(
+        "optimize table {}.{} compact",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    execute_query(&query, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let table = fixture.latest_default_table().await?;
+    let (stats, _) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(stats.read_rows, 6);
+    assert_eq!(stats.column_ndvs, [("id".to_string(), 3)].into());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_fuse_table_optimize() -> Result<()> {
     let fixture = TestFixture::new().await;
@@ -0,0 +1,76 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::*;
+
+#[tokio::test]
+async fn test_fuse_blocks_table_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 2 segments of 1 block each, of the values 1, 2, 3
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+
+    let qry = format!(
+        "select column_name, min, max, null_count, row_count from fuse_blocks('{}', '{}')",
+        db, tbl
+    );
+    let expected = vec![
+        "+-------------+-----+-----+------------+-----------+",
+        "| column_name | min | max | null_count | row_count |",
+        "+-------------+-----+-----+------------+-----------+",
+        "| id          | 1   | 3   | 0          | 3         |",
+        "| id          | 1   | 3   | 0          | 3         |",
+        "+-------------+-----+-----+------------+-----------+",
+    ];
+    expects_ok("blocks", execute_query(&qry, ctx.clone()).await, expected).await?;
+
+    // the blocks of the current snapshot only
+    append_sample_data_overwrite(1, true, &fixture).await?;
+    let qry = format!(
+        "select count(*) as count from fuse_blocks('{}', '{}')",
+        db, tbl
+    );
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 1     |",
+        "+-------+",
+    ];
+    expects_ok(
+        "overwritten",
+        execute_query(&qry, ctx.clone()).await,
+        expected,
+    )
+    .await?;
+
+    let qry = "select * from fuse_blocks('system', 'tables')";
+    expects_err(
+        "not_fuse_table",
+        ErrorCode::bad_arguments_code(),
+        execute_query(qry, ctx.clone()).await,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::*;
+
+#[tokio::test]
+async fn test_fuse_segments_table_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!(
+        "select count(*) as count, sum(block_count) as blocks, sum(row_count) as rows from fuse_segments('{}', '{}')",
+        db, tbl
+    );
+
+    // no snapshot yet
+    {
+        let qry = format!(
+            "select count(*) as count from fuse_segments('{}', '{}')",
+            db, tbl
+        );
+        let expected = vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 0     |",
+            "+-------+",
+        ];
+        expects_ok(
+            "empty_table",
+            execute_query(&qry, ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    // 1 segment of 1 block, then 1 segment of 2 blocks
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(2, &fixture).await?;
+    {
+        let expected = vec![
+            "+-------+--------+------+",
+            "| count | blocks | rows |",
+            "+-------+--------+------+",
+            "| 2     | 3      | 9    |",
+            "+-------+--------+------+",
+        ];
+        expects_ok(
+            "two_segments",
+            execute_query(&qry, ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    let qry = format!("select * from fuse_segments('{}')", db);
+    expects_err(
+        "missing_table_name",
+        ErrorCode::bad_arguments_code(),
+        execute_query(&qry, ctx.clone()).await,
+    );
+
+    Ok(())
+}
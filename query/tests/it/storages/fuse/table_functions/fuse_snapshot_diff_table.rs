@@ -0,0 +1,120 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::*;
+
+#[tokio::test]
+async fn test_fuse_snapshot_diff_table_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 2 snapshots: 1 segment of 1 block, then 2 more segments of 1 block each
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(2, &fixture).await?;
+
+    // the latest snapshot comes first
+    let qry = format!("select snapshot_id from fuse_history('{}', '{}')", db, tbl);
+    let blocks = execute_query(&qry, ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let ids = blocks[0].column(0).to_values()?;
+    let snapshot_id = |i: usize| String::from_utf8(ids[i].as_string().unwrap()).unwrap();
+    let (first, second) = (snapshot_id(1), snapshot_id(0));
+
+    let diff_query = |from: &str, to: &str| {
+        format!(
+            "select object_type, change, row_count from fuse_snapshot_diff('{}', '{}', '{}', '{}') order by object_type, change",
+            db, tbl, from, to
+        )
+    };
+
+    {
+        let expected = vec![
+            "+-------------+--------+-----------+",
+            "| object_type | change | row_count |",
+            "+-------------+--------+-----------+",
+            "| block       | added  | 3         |",
+            "| block       | added  | 3         |",
+            "| segment     | added  | 3         |",
+            "| segment     | added  | 3         |",
+            "+-------------+--------+-----------+",
+        ];
+        expects_ok(
+            "added",
+            execute_query(&diff_query(&first, &second), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        let expected = vec![
+            "+-------------+---------+-----------+",
+            "| object_type | change  | row_count |",
+            "+-------------+---------+-----------+",
+            "| block       | removed | 3         |",
+            "| block       | removed | 3         |",
+            "| segment     | removed | 3         |",
+            "| segment     | removed | 3         |",
+            "+-------------+---------+-----------+",
+        ];
+        expects_ok(
+            "removed",
+            execute_query(&diff_query(&second, &first), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        let expected = vec![
+            "+-------------+--------+-----------+",
+            "| object_type | change | row_count |",
+            "+-------------+--------+-----------+",
+            "+-------------+--------+-----------+",
+        ];
+        expects_ok(
+            "same_snapshot",
+            execute_query(&diff_query(&second, &second), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    expects_err(
+        "unknown_snapshot",
+        ErrorCode::bad_arguments_code(),
+        execute_query(&diff_query(&first, "not_exist"), ctx.clone()).await,
+    );
+
+    let qry = format!("select * from fuse_snapshot_diff('{}', '{}')", db, tbl);
+    expects_err(
+        "missing_snapshot_ids",
+        ErrorCode::bad_arguments_code(),
+        execute_query(&qry, ctx.clone()).await,
+    );
+
+    Ok(())
+}
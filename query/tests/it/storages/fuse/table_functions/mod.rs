@@ -13,4 +13,7 @@
 //  limitations under the License.
 //
 
+mod fuse_blocks_table;
 mod fuse_history_table;
+mod fuse_segments_table;
+mod fuse_snapshot_diff_table;
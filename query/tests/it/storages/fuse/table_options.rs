@@ -0,0 +1,87 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use databend_query::storages::fuse::table_options::user_table_options;
+use databend_query::storages::fuse::table_options::validate_table_options;
+
+#[test]
+fn test_fuse_table_options_validate() -> common_exception::Result<()> {
+    let schema = DataSchema::new(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+
+    // the names are case insensitive, and the values normalized
+    let options = [
+        ("compression", "zstd"),
+        ("Column_Compression", "b:snappy"),
+        ("BLOOM_FILTER_COLUMNS", " a , b "),
+        ("data_retention_time_in_days", "7"),
+        ("change_tracking", "TRUE"),
+        ("comment", "foo"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect::<HashMap<_, _>>();
+    let validated = validate_table_options(&options, &schema)?;
+    assert_eq!(validated.len(), 6);
+    assert_eq!(validated["COMPRESSION"], "zstd");
+    assert_eq!(validated["COLUMN_COMPRESSION"], "b:snappy");
+    assert_eq!(validated["BLOOM_FILTER_COLUMNS"], "a,b");
+    assert_eq!(validated["DATA_RETENTION_TIME_IN_DAYS"], "7");
+    assert_eq!(validated["CHANGE_TRACKING"], "true");
+    assert_eq!(validated["COMMENT"], "foo");
+
+    // unknown options, options kept by the engine, and bad values
+    for (key, value) in [
+        ("NO_SUCH_OPTION", "1"),
+        ("SNAPSHOT_LOC", "_ss/1"),
+        ("CHUNK_BLOCK_NUM", "-1"),
+        ("CHANGE_TRACKING", "yes"),
+        ("BLOOM_FILTER_COLUMNS", "a,c"),
+        ("COLUMN_COMPRESSION", "c:zstd"),
+        ("COMPRESSION", "gzip"),
+    ] {
+        let mut options = HashMap::new();
+        options.insert(key.to_string(), value.to_string());
+        let r = validate_table_options(&options, &schema);
+        assert!(r.is_err(), "{}={} should be rejected", key, value);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fuse_table_options_user_options() -> common_exception::Result<()> {
+    let options = [
+        ("SNAPSHOT_LOC", "_ss/1"),
+        ("ROW_COUNT", "3"),
+        ("COMPRESSION", "zstd"),
+        ("CHANGE_TRACKING", "true"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect::<HashMap<_, _>>();
+    let user_options = user_table_options(&options).into_iter().collect::<Vec<_>>();
+    assert_eq!(user_options, vec![
+        ("CHANGE_TRACKING".to_string(), "true".to_string()),
+        ("COMPRESSION".to_string(), "zstd".to_string()),
+    ]);
+    Ok(())
+}
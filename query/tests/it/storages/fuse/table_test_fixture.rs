@@ -58,8 +58,11 @@ pub struct TestFixture {
 
 impl TestFixture {
     pub async fn new() -> TestFixture {
+        Self::new_with_config(Config::default()).await
+    }
+
+    pub async fn new_with_config(mut config: Config) -> TestFixture {
         let tmp_dir = TempDir::new().unwrap();
-        let mut config = Config::default();
         // make sure we are suing `Disk` storage
         config.storage.storage_type = "Disk".to_string();
         // use `TempDir` as root path (auto clean)
@@ -109,6 +112,7 @@ impl TestFixture {
     pub fn default_crate_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            or_replace: false,
             db: self.default_db_name(),
             table: self.default_table_name(),
             table_meta: TableMeta {
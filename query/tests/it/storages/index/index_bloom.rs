@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use databend_query::storages::index::BlockBloomFilter;
+use databend_query::storages::index::BloomFilter;
+use databend_query::storages::index::BloomFilterPredicate;
+
+#[test]
+fn test_bloom_filter() -> Result<()> {
+    let mut filter = BloomFilter::with_rate(1000, 0.01);
+    assert!(filter.num_hashes() > 1);
+    assert!(filter.num_bits() >= 9585);
+
+    let column: DataColumn = Series::new((0..1000i64).collect::<Vec<_>>()).into();
+    filter.add_column(&column)?;
+
+    // no false negative
+    for i in 0..1000i64 {
+        assert!(filter.maybe_contains(&DataValue::Int64(Some(i))));
+    }
+
+    // the false positive rate is around the expected one
+    let false_positives = (1000..11000i64)
+        .filter(|i| filter.maybe_contains(&DataValue::Int64(Some(*i))))
+        .count();
+    assert!(
+        false_positives < 300,
+        "false positives: {}",
+        false_positives
+    );
+
+    // NULLs are never in the filter, but probing one can not prune anything
+    assert!(filter.maybe_contains(&DataValue::Int64(None)));
+
+    // survives the round trip through the meta files
+    let json = serde_json::to_vec(&filter)?;
+    assert_eq!(filter, serde_json::from_slice::<BloomFilter>(&json)?);
+    Ok(())
+}
+
+#[test]
+fn test_bloom_filter_values() -> Result<()> {
+    let mut filter = BloomFilter::with_rate(3, 0.01);
+    filter.add(&DataValue::String(Some(b"jack".to_vec())));
+    filter.add(&DataValue::Float64(Some(-0.0)));
+    filter.add(&DataValue::Boolean(Some(true)));
+
+    assert!(filter.maybe_contains(&DataValue::String(Some(b"jack".to_vec()))));
+    assert!(!filter.maybe_contains(&DataValue::String(Some(b"ace".to_vec()))));
+    assert!(filter.maybe_contains(&DataValue::Float64(Some(0.0))));
+    assert!(filter.maybe_contains(&DataValue::Boolean(Some(true))));
+    assert!(!filter.maybe_contains(&DataValue::Boolean(Some(false))));
+    Ok(())
+}
+
+#[test]
+fn test_bloom_filter_predicate() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt64, false),
+        DataField::new("b", DataType::String, true),
+    ]);
+
+    let mut filter_a = BloomFilter::with_rate(3, 0.01);
+    filter_a.add_column(&Series::new(vec![1u64, 2, 3]).into())?;
+    let mut filters = BlockBloomFilter::new();
+    filters.insert(0, filter_a);
+
+    struct Test {
+        name: &'static str,
+        expr: common_planners::Expression,
+        expect: Option<bool>,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "a = 2",
+            // the literal is cast to the type of the column
+            expr: col("a").eq(lit(2i32)),
+            expect: Some(true),
+        },
+        Test {
+            name: "5 = a",
+            expr: lit(5u8).eq(col("a")),
+            expect: Some(false),
+        },
+        Test {
+            name: "a = 2 and b = 'x'",
+            // b has no bloom filter in the block
+            expr: col("a").eq(lit(2)).and(col("b").eq(lit("x".as_bytes()))),
+            expect: Some(true),
+        },
+        Test {
+            name: "a > 0 and a = 5",
+            expr: col("a").gt(lit(0)).and(col("a").eq(lit(5))),
+            expect: Some(false),
+        },
+        Test {
+            name: "a = 5 or a = 2",
+            expr: col("a").eq(lit(5)).or(col("a").eq(lit(2))),
+            expect: None,
+        },
+        Test {
+            name: "c = 5",
+            expr: col("c").eq(lit(5)),
+            expect: None,
+        },
+    ];
+
+    for test in tests {
+        let pred = BloomFilterPredicate::try_create(&test.expr, &schema)?;
+        assert_eq!(
+            test.expect,
+            pred.map(|p| p.eval(&filters)),
+            "case: {}",
+            test.name
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::collections::HashMap;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::storages::fuse::statistics::reduce_ndv_sketches;
+use databend_query::storages::index::NdvSketch;
+
+#[test]
+fn test_ndv_sketch() -> Result<()> {
+    let mut sketch = NdvSketch::new();
+    assert_eq!(sketch.estimate(), 0);
+
+    // small cardinalities are almost exact
+    sketch.add_column(&Series::new(vec![1i64, 2, 3, 3, 2, 1]).into())?;
+    assert_eq!(sketch.estimate(), 3);
+
+    // NULLs are not counted
+    sketch.add(&DataValue::Int64(None));
+    assert_eq!(sketch.estimate(), 3);
+
+    // within 10% of the large ones
+    let mut sketch = NdvSketch::new();
+    sketch.add_column(&Series::new((0..100000i64).collect::<Vec<_>>()).into())?;
+    let estimate = sketch.estimate();
+    assert!(
+        (90000..110000).contains(&estimate),
+        "estimate: {}",
+        estimate
+    );
+
+    // survives the round trip through the meta files
+    let json = serde_json::to_vec(&sketch)?;
+    assert_eq!(sketch, serde_json::from_slice::<NdvSketch>(&json)?);
+    Ok(())
+}
+
+#[test]
+fn test_ndv_sketch_merge() -> Result<()> {
+    let mut sketch_a = NdvSketch::new();
+    sketch_a.add_column(&Series::new((0..1000u64).collect::<Vec<_>>()).into())?;
+    let mut sketch_b = NdvSketch::new();
+    sketch_b.add_column(&Series::new((500..1500u64).collect::<Vec<_>>()).into())?;
+
+    let mut merged = sketch_a.clone();
+    merged.merge(&sketch_b);
+    let estimate = merged.estimate();
+    assert!((1350..1650).contains(&estimate), "estimate: {}", estimate);
+
+    // only the columns sketched by all the segments are merged
+    let segment_a: HashMap<u32, NdvSketch> = [(0, sketch_a.clone()), (1, sketch_a)].into();
+    let segment_b: HashMap<u32, NdvSketch> = [(0, sketch_b)].into();
+    let reduced = reduce_ndv_sketches(&[segment_a, segment_b]);
+    assert_eq!(reduced.len(), 1);
+    assert_eq!(reduced.get(&0), Some(&merged));
+    Ok(())
+}
@@ -25,6 +25,9 @@ use databend_query::storages::index::range_filter::BlockStatistics;
 use databend_query::storages::index::range_filter::StatColumns;
 use databend_query::storages::index::ColumnStatistics;
 use databend_query::storages::index::RangeFilter;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
 
 #[test]
 fn test_range_filter() -> Result<()> {
@@ -250,6 +253,36 @@ fn test_build_verifiable_function() -> Result<()> {
             ]),
             expect: "(min_c < ffffff)",
         },
+        Test {
+            name: "not (a < 1)",
+            expr: not(col("a").lt(lit(1))),
+            expect: "(max_a >= 1)",
+        },
+        Test {
+            name: "not (a = 1 or b is null)",
+            expr: not(col("a")
+                .eq(lit(1))
+                .or(Expression::create_scalar_function("isNull", vec![col("b")]))),
+            expect: "(((min_a != 1) or (max_a != 1)) and isNotNull(min_b))",
+        },
+        Test {
+            name: "not not (b > 3)",
+            expr: not(not(col("b").gt(lit(3)))),
+            expect: "(max_b > 3)",
+        },
+        Test {
+            name: "not (c like 'sys%')",
+            expr: not(Expression::create_binary_expression("like", vec![
+                col("c"),
+                lit("sys%".as_bytes()),
+            ])),
+            expect: "((min_c < sys) or (max_c >= syt))",
+        },
+        Test {
+            name: "not (a % b > 1)",
+            expr: not(modular(col("a"), col("b")).gt(lit(1))),
+            expect: "true",
+        },
     ];
 
     for test in tests {
@@ -325,3 +358,334 @@ fn test_bound_for_like_pattern() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_range_filter_with_null_stats() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Int64, true),
+    ]);
+
+    // All the values of a are NULL.
+    let mut stats: BlockStatistics = HashMap::new();
+    stats.insert(0u32, ColumnStatistics {
+        min: DataValue::Int64(None),
+        max: DataValue::Int64(None),
+        null_count: 10,
+        in_memory_size: 0,
+    });
+    stats.insert(1u32, ColumnStatistics {
+        min: DataValue::Int64(Some(1)),
+        max: DataValue::Int64(Some(10)),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+
+    let is_null = |name| Expression::create_scalar_function("isNull", vec![col(name)]);
+    let is_not_null = |name| Expression::create_scalar_function("isNotNull", vec![col(name)]);
+    let tests = vec![
+        ("a > 5", col("a").gt(lit(5i64)), false),
+        ("not (a > 5)", not(col("a").gt(lit(5i64))), false),
+        ("-a < 5", neg(col("a")).lt(lit(5i64)), false),
+        ("a is null", is_null("a"), true),
+        ("not (a is not null)", not(is_not_null("a")), true),
+        ("a is not null", is_not_null("a"), false),
+        (
+            "a > 5 or b > 5",
+            col("a").gt(lit(5i64)).or(col("b").gt(lit(5i64))),
+            true,
+        ),
+        (
+            "not (a > 5 or b > 5)",
+            not(col("a").gt(lit(5i64)).or(col("b").gt(lit(5i64)))),
+            false,
+        ),
+        (
+            "not (a > 5 and b > 5)",
+            not(col("a").gt(lit(5i64)).and(col("b").gt(lit(5i64)))),
+            true,
+        ),
+        (
+            "not null",
+            not(Expression::create_literal(DataValue::Null)),
+            false,
+        ),
+        ("not false", not(lit(false)), true),
+    ];
+
+    for (name, expr, expect) in tests {
+        let prune = RangeFilter::try_create(&expr, schema.clone())?;
+        assert_eq!(expect, prune.eval(&stats)?, "{:#?}", name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_range_filter_with_non_literal_bound() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "ts",
+        DataType::DateTime32(None),
+        false,
+    )]);
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32;
+    let stats_of = |min: u32, max: u32| {
+        let mut stats: BlockStatistics = HashMap::new();
+        stats.insert(0u32, ColumnStatistics {
+            min: DataValue::UInt32(Some(min)),
+            max: DataValue::UInt32(Some(max)),
+            null_count: 0,
+            in_memory_size: 0,
+        });
+        stats
+    };
+
+    // ts > now() - INTERVAL 1 DAY
+    let one_day = Expression::Literal {
+        value: DataValue::Int64(Some(24 * 3600 * 1000)),
+        column_name: None,
+        data_type: DataType::Interval(IntervalUnit::DayTime),
+    };
+    let expr = col("ts").gt(Expression::create_binary_expression("-", vec![
+        Expression::create_scalar_function("now", vec![]),
+        one_day,
+    ]));
+    let filter = RangeFilter::try_create(&expr, schema)?;
+
+    let ten_days_ago = now - 10 * 24 * 3600;
+    assert!(!filter.eval(&stats_of(ten_days_ago, ten_days_ago + 3600))?);
+    assert!(filter.eval(&stats_of(ten_days_ago, now))?);
+    Ok(())
+}
+
+#[test]
+fn test_range_filter_with_interval_arithmetic() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Int32, true),
+        DataField::new("c", DataType::Int64, true),
+    ]);
+
+    let mut stats: BlockStatistics = HashMap::new();
+    stats.insert(0u32, ColumnStatistics {
+        min: DataValue::Int64(Some(1)),
+        max: DataValue::Int64(Some(20)),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+    stats.insert(1u32, ColumnStatistics {
+        min: DataValue::Int32(Some(3)),
+        max: DataValue::Int32(Some(10)),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+    // All the values of c are NULL.
+    stats.insert(2u32, ColumnStatistics {
+        min: DataValue::Int64(None),
+        max: DataValue::Int64(None),
+        null_count: 10,
+        in_memory_size: 0,
+    });
+
+    let mul = |l, r| Expression::create_binary_expression("*", vec![l, r]);
+    let minus = |l, r| Expression::create_binary_expression("-", vec![l, r]);
+    let tests = vec![
+        ("a + b > 29", add(col("a"), col("b")).gt(lit(29)), true),
+        ("a + b > 31", add(col("a"), col("b")).gt(lit(31)), false),
+        ("a + b < 3", add(col("a"), col("b")).lt(lit(3)), false),
+        ("a - b > 17", minus(col("a"), col("b")).gt(lit(17)), true),
+        ("a - b > 18", minus(col("a"), col("b")).gt(lit(18)), false),
+        ("a * -2 > -1", mul(col("a"), lit(-2)).gt(lit(-1)), false),
+        ("-a <= -20", neg(col("a")).lt_eq(lit(-20)), true),
+        ("a > b * 3", col("a").gt(mul(col("b"), lit(3))), true),
+        ("a < b - 10", col("a").lt(minus(col("b"), lit(10))), false),
+        ("a = b + 11", col("a").eq(add(col("b"), lit(11))), true),
+        ("a = b + 21", col("a").eq(add(col("b"), lit(21))), false),
+        (
+            "not (a + b <= 31)",
+            not(add(col("a"), col("b")).lt_eq(lit(31))),
+            false,
+        ),
+        ("a + b != 5", add(col("a"), col("b")).not_eq(lit(5)), true),
+        ("a * b > 1000", mul(col("a"), col("b")).gt(lit(1000)), true),
+        ("a + c > 1", add(col("a"), col("c")).gt(lit(1)), false),
+        (
+            "a + b > 31 or a < b - 10",
+            add(col("a"), col("b"))
+                .gt(lit(31))
+                .or(col("a").lt(minus(col("b"), lit(10)))),
+            false,
+        ),
+    ];
+
+    for (name, expr, expect) in tests {
+        let prune = RangeFilter::try_create(&expr, schema.clone())?;
+        assert_eq!(expect, prune.eval(&stats)?, "{:#?}", name);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+enum Predicate {
+    Compare(usize, &'static str, i64),
+    CompareSum(&'static str, i64),
+    CompareColumns(&'static str),
+    IsNull(usize),
+    IsNotNull(usize),
+    Literal(Option<bool>),
+    Not(Box<Predicate>),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+}
+
+const COLUMNS: [&str; 2] = ["a", "b"];
+const COMPARISONS: [&str; 6] = ["=", "!=", "<", "<=", ">", ">="];
+
+impl Predicate {
+    fn random(rng: &mut StdRng, depth: usize) -> Predicate {
+        let leaf = depth == 0 || rng.gen_bool(0.3);
+        match (leaf, rng.gen_range(0..4)) {
+            (true, 0) => Predicate::IsNull(rng.gen_range(0..2)),
+            (true, 1) => Predicate::IsNotNull(rng.gen_range(0..2)),
+            (true, 2) if rng.gen_bool(0.2) => {
+                Predicate::Literal([None, Some(true), Some(false)][rng.gen_range(0..3)])
+            }
+            (true, _) => {
+                let op = COMPARISONS[rng.gen_range(0..COMPARISONS.len())];
+                match rng.gen_range(0..3) {
+                    0 => Predicate::CompareSum(op, rng.gen_range(-10..10)),
+                    1 => Predicate::CompareColumns(op),
+                    _ => Predicate::Compare(rng.gen_range(0..2), op, rng.gen_range(-5..5)),
+                }
+            }
+            (false, 0) | (false, 1) => Predicate::Not(Box::new(Self::random(rng, depth - 1))),
+            (false, 2) => Predicate::And(
+                Box::new(Self::random(rng, depth - 1)),
+                Box::new(Self::random(rng, depth - 1)),
+            ),
+            (false, _) => Predicate::Or(
+                Box::new(Self::random(rng, depth - 1)),
+                Box::new(Self::random(rng, depth - 1)),
+            ),
+        }
+    }
+
+    /// Evaluate the predicate on a row under the SQL three-valued logic.
+    fn eval(&self, row: &[Option<i64>]) -> Option<bool> {
+        match self {
+            Predicate::Compare(c, op, v) => row[*c].map(|x| compare(op, x, *v)),
+            Predicate::CompareSum(op, v) => match (row[0], row[1]) {
+                (Some(a), Some(b)) => Some(compare(op, a + b, *v)),
+                _ => None,
+            },
+            Predicate::CompareColumns(op) => match (row[0], row[1]) {
+                (Some(a), Some(b)) => Some(compare(op, a, b)),
+                _ => None,
+            },
+            Predicate::IsNull(c) => Some(row[*c].is_none()),
+            Predicate::IsNotNull(c) => Some(row[*c].is_some()),
+            Predicate::Literal(v) => *v,
+            Predicate::Not(p) => p.eval(row).map(|v| !v),
+            Predicate::And(l, r) => match (l.eval(row), r.eval(row)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            Predicate::Or(l, r) => match (l.eval(row), r.eval(row)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+        }
+    }
+
+    fn to_expression(&self) -> Expression {
+        match self {
+            Predicate::Compare(c, op, v) => {
+                Expression::create_binary_expression(op, vec![col(COLUMNS[*c]), lit(*v)])
+            }
+            Predicate::CompareSum(op, v) => Expression::create_binary_expression(op, vec![
+                add(col(COLUMNS[0]), col(COLUMNS[1])),
+                lit(*v),
+            ]),
+            Predicate::CompareColumns(op) => {
+                Expression::create_binary_expression(op, vec![col(COLUMNS[0]), col(COLUMNS[1])])
+            }
+            Predicate::IsNull(c) => {
+                Expression::create_scalar_function("isNull", vec![col(COLUMNS[*c])])
+            }
+            Predicate::IsNotNull(c) => {
+                Expression::create_scalar_function("isNotNull", vec![col(COLUMNS[*c])])
+            }
+            Predicate::Literal(v) => Expression::create_literal(DataValue::Boolean(*v)),
+            Predicate::Not(p) => not(p.to_expression()),
+            Predicate::And(l, r) => l.to_expression().and(r.to_expression()),
+            Predicate::Or(l, r) => l.to_expression().or(r.to_expression()),
+        }
+    }
+}
+
+fn compare(op: &str, x: i64, v: i64) -> bool {
+    match op {
+        "=" => x == v,
+        "!=" => x != v,
+        "<" => x < v,
+        "<=" => x <= v,
+        ">" => x > v,
+        _ => x >= v,
+    }
+}
+
+fn column_statistics(values: &[Option<i64>]) -> ColumnStatistics {
+    let non_nulls = values.iter().flatten();
+    ColumnStatistics {
+        min: DataValue::Int64(non_nulls.clone().min().cloned()),
+        max: DataValue::Int64(non_nulls.max().cloned()),
+        null_count: values.iter().filter(|v| v.is_none()).count() as u64,
+        in_memory_size: 0,
+    }
+}
+
+/// A block must never be pruned if one of its rows satisfies the predicate.
+#[test]
+fn test_range_filter_is_sound_with_nulls() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Int64, true),
+    ]);
+
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    for _ in 0..500 {
+        let null_ratio = [0.0, 0.5, 1.0][rng.gen_range(0..3)];
+        let rows = (0..rng.gen_range(1..8))
+            .map(|_| {
+                (0..COLUMNS.len())
+                    .map(|_| match rng.gen_bool(null_ratio) {
+                        true => None,
+                        false => Some(rng.gen_range(-5..5)),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut stats: BlockStatistics = HashMap::new();
+        for (id, _) in COLUMNS.iter().enumerate() {
+            let values = rows.iter().map(|row| row[id]).collect::<Vec<_>>();
+            stats.insert(id as u32, column_statistics(&values));
+        }
+
+        let predicate = Predicate::random(&mut rng, 3);
+        let expected = rows.iter().any(|row| predicate.eval(row) == Some(true));
+        let prune = RangeFilter::try_create(&predicate.to_expression(), schema.clone())?;
+        if expected {
+            assert!(prune.eval(&stats)?, "{:?} on {:?}", predicate, rows);
+        }
+    }
+
+    Ok(())
+}
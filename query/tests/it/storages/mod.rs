@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod external;
 mod fuse;
 mod index;
 mod memory;
 mod null;
+mod stream;
 mod system;
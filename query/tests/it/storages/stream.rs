@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::clusters::Cluster;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sessions::SessionRef;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_stream_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+    let session = fixture
+        .ctx()
+        .get_sessions_manager()
+        .create_session("TestSession")?;
+    let query_ctx = || new_query_ctx(&fixture, &session);
+
+    // the rows appended before the stream is created are not returned
+    let qry = format!("create stream {}.s on table {}.{}", db, db, tbl);
+    execute_command(&qry, query_ctx()?).await?;
+    expects_count(&query_ctx, &format!("{}.s", db), "created", 0).await?;
+
+    append_sample_data(2, &fixture).await?;
+    expects_count(&query_ctx, &format!("{}.s", db), "appended", 6).await?;
+    // reading the stream does not consume it
+    expects_count(&query_ctx, &format!("{}.s", db), "read_again", 6).await?;
+
+    // INSERT consumes the stream
+    let qry = format!("create table {}.t2(id Int)", db);
+    execute_command(&qry, query_ctx()?).await?;
+    let qry = format!("insert into {}.t2 select * from {}.s", db, db);
+    execute_command(&qry, query_ctx()?).await?;
+    expects_count(&query_ctx, &format!("{}.t2", db), "inserted", 6).await?;
+    expects_count(&query_ctx, &format!("{}.s", db), "consumed", 0).await?;
+
+    append_sample_data(1, &fixture).await?;
+    let qry = format!("select sum(id) as s from {}.s", db);
+    expects_ok(
+        "appended_after_consumed",
+        execute_query(&qry, query_ctx()?).await,
+        vec!["+---+", "| s |", "+---+", "| 6 |", "+---+"],
+    )
+    .await?;
+
+    // only fuse tables are supported
+    let qry = format!("create table {}.t3(id Int) engine = Memory", db);
+    execute_command(&qry, query_ctx()?).await?;
+    let qry = format!("create stream {}.s3 on table {}.t3", db, db);
+    expects_err(
+        "memory_table",
+        ErrorCode::UnImplement("").code(),
+        execute_command(&qry, query_ctx()?).await,
+    );
+
+    Ok(())
+}
+
+// each statement runs in a query context of its own, so that the tables are not cached
+fn new_query_ctx(fixture: &TestFixture, session: &SessionRef) -> Result<Arc<QueryContext>> {
+    Ok(QueryContext::from_shared(QueryContextShared::try_create(
+        fixture.ctx().get_config(),
+        Arc::new(session.as_ref().clone()),
+        Cluster::empty(),
+    )?))
+}
+
+async fn expects_count(
+    query_ctx: &impl Fn() -> Result<Arc<QueryContext>>,
+    tbl: &str,
+    case_name: &str,
+    count: u64,
+) -> Result<()> {
+    let qry = format!("select count(*) as count from {}", tbl);
+    expects_ok(case_name, execute_query(&qry, query_ctx()?).await, vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        &format!("| {:<5} |", count),
+        "+-------+",
+    ])
+    .await
+}
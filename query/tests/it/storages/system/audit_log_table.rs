@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::*;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_audit_log_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // DDL success and failure, the password is masked in the audit log.
+    for query in [
+        "CREATE DATABASE audit_db",
+        "DROP DATABASE audit_not_exists",
+        "CREATE USER 'audit_u'@'%' IDENTIFIED BY 'pass1'",
+        "CREATE FUNCTION audit_udf AS (p) -> not(isnull(p))",
+    ] {
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = interpreter.execute(None).await;
+    }
+
+    // Statements other than DDL/DML/DCL are not audited.
+    {
+        let query = "SELECT 1";
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.execute(None).await?;
+    }
+
+    // Login success and failure.
+    let session = ctx.get_session();
+    session.audit_login("test_user", "127.0.0.1:3306", None);
+    session.audit_login(
+        "test_user",
+        "127.0.0.1:3307",
+        Some(&ErrorCode::AuthenticateFailure("wrong password")),
+    );
+
+    // A privilege denied while analyzing the query.
+    {
+        let user_mgr = ctx.get_sessions_manager().get_user_manager();
+        session.set_current_user(user_mgr.get_user("audit_u", "%").await?);
+        let query = "SELECT audit_udf(1)";
+        ctx.attach_query_str(query);
+        let res = PlanParser::parse(query, ctx.clone()).await;
+        assert_eq!(
+            res.err().unwrap().code(),
+            ErrorCode::PermissionDenied("").code()
+        );
+    }
+
+    let query = "select event_type, handler_type, sql_user, client_address, operation, query_text, success, exception_code from system.audit_log";
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+------------------+--------------+-----------+----------------+--------------------+----------------------------------------------------+---------+----------------+",
+        "| event_type       | handler_type | sql_user  | client_address | operation          | query_text                                         | success | exception_code |",
+        "+------------------+--------------+-----------+----------------+--------------------+----------------------------------------------------+---------+----------------+",
+        "| DCL              | TestSession  | test_user |                | CreateUser         | CREATE USER 'audit_u'@'%' IDENTIFIED BY '******'   | true    | 0              |",
+        "| DDL              | TestSession  | test_user |                | CreateDatabasePlan | CREATE DATABASE audit_db                           | true    | 0              |",
+        "| DDL              | TestSession  | test_user |                | CreateUDFPlan      | CREATE FUNCTION audit_udf AS (p) -> not(isnull(p)) | true    | 0              |",
+        "| DDL              | TestSession  | test_user |                | DropDatabasePlan   | DROP DATABASE audit_not_exists                     | false   | 3              |",
+        "| LOGIN_FAILURE    | TestSession  | test_user | 127.0.0.1:3307 | Login              |                                                    | false   | 51             |",
+        "| LOGIN_SUCCESS    | TestSession  | test_user | 127.0.0.1:3306 | Login              |                                                    | true    | 0              |",
+        "| PRIVILEGE_DENIED | TestSession  | audit_u   |                | Analyze            | SELECT audit_udf(1)                                | false   | 62             |",
+        "+------------------+--------------+-----------+----------------+--------------------+----------------------------------------------------+---------+----------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -27,7 +27,12 @@ use crate::tests::create_query_context_with_config;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_configs_table() -> Result<()> {
-    let config = Config::default();
+    let mut config = Config::default();
+    config.query.connection_encryption_key = "the key".to_string();
+    // The config is logged at startup, the key is masked as in the table.
+    let debug = format!("{:?}", config);
+    assert!(debug.contains("connection_encryption_key: \"******\""));
+    assert!(!debug.contains("the key"));
     let ctx = create_query_context_with_config(config)?;
     ctx.get_settings().set_max_threads(8)?;
 
@@ -38,7 +43,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 42);
+    assert_eq!(block.num_rows(), 66);
 
     let expected = vec![
         "+--------------------------------------+------------------+-------+-------------+",
@@ -58,7 +63,18 @@ async fn test_configs_table() -> Result<()> {
         "| http_handler_tls_server_key          |                  | query |             |",
         "| http_handler_tls_server_root_ca_cert |                  | query |             |",
         "| log_dir                              | ./_logs          | log   |             |",
+        "| log_file_format                      | bunyan           | log   |             |",
+        "| log_compress                         | false            | log   |             |",
         "| log_level                            | INFO             | log   |             |",
+        "| log_max_files                        | 0                | log   |             |",
+        "| log_max_total_size_mb                | 0                | log   |             |",
+        "| log_redact_literals                  | false            | log   |             |",
+        "| log_redact_pattern                   |                  | log   |             |",
+        "| log_rotation                         | hourly           | log   |             |",
+        "| log_rotation_size_mb                 | 512              | log   |             |",
+        "| log_stdout_format                    | text             | log   |             |",
+        "| log_tracing_endpoint                 |                  | log   |             |",
+        "| log_tracing_exporter                 | off              | log   |             |",
         "| max_active_sessions                  | 256              | query |             |",
         "| max_query_log_size                   | 10000            | query |             |",
         "| meta_address                         |                  | meta  |             |",
@@ -70,6 +86,7 @@ async fn test_configs_table() -> Result<()> {
         "| mysql_handler_host                   | 127.0.0.1        | query |             |",
         "| mysql_handler_port                   | 3307             | query |             |",
         "| num_cpus                             | 8                | query |             |",
+        "| query_log_table                      |                  | query |             |",
         "| rpc_tls_meta_server_root_ca_cert     |                  | meta  |             |",
         "| rpc_tls_meta_service_domain_name     | localhost        | meta  |             |",
         "| rpc_tls_query_server_root_ca_cert    |                  | query |             |",
@@ -86,6 +103,26 @@ async fn test_configs_table() -> Result<()> {
         "| table_memory_cache_mb_size           | 256              | query |             |",
         "| table_disk_cache_root                | _cache           | query |             |",
         "| table_disk_cache_mb_size             | 1024             | query |             |",
+        "| jwt_key_url                          |                  | query |             |",
+        "| audit_log_file                       |                  | query |             |",
+        "| audit_log_retention_days             | 30               | query |             |",
+        "| drop_retention_hours                 | 24               | query |             |",
+        "| history_retention_hours              | 24               | query |             |",
+        "| statistics_refresh_interval_secs     | 0                | query |             |",
+        "| snapshot_expiry_interval_secs        | 0                | query |             |",
+        "| connection_encryption_key            | ******           | query |             |",
+        "| user_cache_ttl_secs                  | 5                | query |             |",
+        "| table_cache_parquet_meta_count       | 10000            | query |             |",
+        "| table_cache_segment_info_count       | 1000             | query |             |",
+        "| spill_dir                            | _spill           | query |             |",
+        "| table_block_cache_root               | _block_cache     | query |             |",
+        "| table_block_cache_mb_size            | 0                | query |             |",
+        "| user_password_rehash_on_login        | false            | query |             |",
+        "| table_change_webhook_url             |                  | query |             |",
+        "| table_upload_max_concurrency         | 0                | query |             |",
+        "| table_upload_max_retries             | 5                | query |             |",
+        "| table_upload_retry_backoff_ms        | 100              | query |             |",
+        "| table_upload_retry_max_backoff_ms    | 10000            | query |             |",
         "+--------------------------------------+------------------+-------+-------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
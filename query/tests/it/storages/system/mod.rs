@@ -12,16 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log_table;
+mod build_options_table;
 mod clusters_table;
+mod column_statistics_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
+mod engines_table;
 mod functions_table;
 mod metrics_table;
 mod query_log_table;
+mod settings_history_table;
 mod settings_table;
+mod storage_usage_table;
 mod tables_table;
 mod tracing_table;
 mod users_table;
@@ -30,8 +30,7 @@ async fn test_query_log_table() -> Result<()> {
     let ctx = crate::tests::create_query_context()?;
     ctx.get_settings().set_max_threads(2)?;
 
-    let mut query_log = QueryLogTable::create(0);
-    query_log.set_max_rows(2);
+    let query_log = QueryLogTable::create(0, 2);
     let schema = query_log.schema();
     let table: Arc<dyn Table> = Arc::new(query_log);
 
@@ -55,12 +54,12 @@ async fn test_query_log_table() -> Result<()> {
         assert_blocks_sorted_eq(
             vec![
 
-                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+------------------+-----------+--------+---------+-------------+--------------+---------------+-----------+------------+-------------------+------------+-------------------+-------------+--------------+-----------+--------------+-------------+----------------+----------------+----------------+-------------+----------------+-------+",
-                "| log_type | handler_type | tenant_id | cluster_id | sql_user | sql_user_quota | sql_user_privileges | query_id | query_kind | query_text | event_date | event_time | current_database | databases | tables | columns | projections | written_rows | written_bytes | scan_rows | scan_bytes | scan_byte_cost_ms | scan_seeks | scan_seek_cost_ms | result_rows | result_bytes | cpu_usage | memory_usage | client_info | client_address | exception_code | exception_text | stack_trace | server_version | extra |",
-                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+------------------+-----------+--------+---------+-------------+--------------+---------------+-----------+------------+-------------------+------------+-------------------+-------------+--------------+-----------+--------------+-------------+----------------+----------------+----------------+-------------+----------------+-------+",
-                "| 2        |              |           |            |          |                |                     |          |            |            |            |            |                  |           |        |         |             |              |               |           |            |                   |            |                   |             |              |           |              |             |                |                |                |             |                |       |",
-                "| 3        |              |           |            |          |                |                     |          |            |            |            |            |                  |           |        |         |             |              |               |           |            |                   |            |                   |             |              |           |              |             |                |                |                |             |                |       |",
-                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+------------------+-----------+--------+---------+-------------+--------------+---------------+-----------+------------+-------------------+------------+-------------------+-------------+--------------+-----------+--------------+-------------+----------------+----------------+----------------+-------------+----------------+-------+",
+                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+-------------------+------------------+-----------+--------+---------+-------------+--------------+---------------+-----------+------------+-------------------+------------+-------------------+-------------+--------------+-----------+--------------+-------------------+-------------+----------------+----------------+----------------+-------------+----------------+-------+",
+                "| log_type | handler_type | tenant_id | cluster_id | sql_user | sql_user_quota | sql_user_privileges | query_id | query_kind | query_text | event_date | event_time | query_duration_ms | current_database | databases | tables | columns | projections | written_rows | written_bytes | scan_rows | scan_bytes | scan_byte_cost_ms | scan_seeks | scan_seek_cost_ms | result_rows | result_bytes | cpu_usage | memory_usage | peak_memory_usage | client_info | client_address | exception_code | exception_text | stack_trace | server_version | extra |",
+                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+-------------------+------------------+-----------+--------+---------+-------------+--------------+---------------+-----------+------------+-------------------+------------+-------------------+-------------+--------------+-----------+--------------+-------------------+-------------+----------------+----------------+----------------+-------------+----------------+-------+",
+                "| 2        |              |           |            |          |                |                     |          |            |            |            |            |                   |                  |           |        |         |             |              |               |           |            |                   |            |                   |             |              |           |              |                   |             |                |                |                |             |                |       |",
+                "| 3        |              |           |            |          |                |                     |          |            |            |            |            |                   |                  |           |        |         |             |              |               |           |            |                   |            |                   |             |              |           |              |                   |             |                |                |                |             |                |       |",
+                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+-------------------+------------------+-----------+--------+---------+-------------+--------------+---------------+-----------+------------+-------------------+------------+-------------------+-------------+--------------+-----------+--------------+-------------------+-------------+----------------+----------------+----------------+-------------+----------------+-------+",
 
             ],
             &result,
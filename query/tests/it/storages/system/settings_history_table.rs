@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::audit::SettingChange;
+use databend_query::interpreters::*;
+use databend_query::sql::*;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_settings_history_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let history = ctx.get_sessions_manager().get_settings_history();
+    for (old_value, new_value) in [("INFO", "DEBUG"), ("DEBUG", "info,databend_query=debug")] {
+        let mut change = SettingChange::create("", "log_level", old_value, new_value);
+        change.handler_type = "HTTPAPI".to_string();
+        change.user = "root".to_string();
+        history.append(change);
+    }
+
+    let query =
+        "select handler_type, sql_user, name, old_value, new_value from system.settings_history";
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+--------------+----------+-----------+-----------+---------------------------+",
+        "| handler_type | sql_user | name      | old_value | new_value                 |",
+        "+--------------+----------+-----------+-----------+---------------------------+",
+        "| HTTPAPI      | root     | log_level | DEBUG     | info,databend_query=debug |",
+        "| HTTPAPI      | root     | log_level | INFO      | DEBUG                     |",
+        "+--------------+----------+-----------+-----------+---------------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
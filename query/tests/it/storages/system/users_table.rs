@@ -39,6 +39,8 @@ async fn test_users_table() -> Result<()> {
             password_type: PasswordType::None,
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
+            network_policy: None,
+            roles: vec![],
         })
         .await?;
     ctx.get_sessions_manager()
@@ -50,6 +52,8 @@ async fn test_users_table() -> Result<()> {
             password_type: PasswordType::PlainText,
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
+            network_policy: None,
+            roles: vec![],
         })
         .await?;
 
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::table_functions::FlightExchangeTable;
+
+fn string_literal(value: &str) -> Expression {
+    Expression::create_literal(DataValue::String(Some(value.as_bytes().to_vec())))
+}
+
+#[test]
+fn test_flight_exchange_table_schema() -> Result<()> {
+    let tbl_args = Some(vec![
+        string_literal("127.0.0.1:8815"),
+        string_literal("score"),
+        string_literal("SELECT number AS id FROM numbers(10)"),
+        string_literal("id UInt64 NOT NULL, score Float64"),
+    ]);
+    let table = FlightExchangeTable::create("", "flight_exchange", 1, tbl_args.clone())?;
+
+    let table = table.as_table();
+    let expected = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("score", DataType::Float64, true),
+    ]);
+    assert_eq!(table.schema(), expected);
+    assert_eq!(table.table_args(), tbl_args);
+    Ok(())
+}
+
+#[test]
+fn test_flight_exchange_table_bad_args() -> Result<()> {
+    let tbl_args = Some(vec![string_literal("127.0.0.1:8815")]);
+    let result = FlightExchangeTable::create("", "flight_exchange", 1, tbl_args);
+    assert!(result.is_err());
+
+    let tbl_args = Some(vec![
+        string_literal("127.0.0.1:8815"),
+        string_literal("score"),
+        string_literal("SELECT number AS id FROM numbers(10)"),
+        string_literal("id UInt64,"),
+    ]);
+    let result = FlightExchangeTable::create("", "flight_exchange", 1, tbl_args);
+    assert!(result.is_err());
+    Ok(())
+}
@@ -12,5 +12,6 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.W
 
+mod flight_exchange_table;
 mod memory_block_part;
 mod numbers_table;
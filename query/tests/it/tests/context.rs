@@ -33,15 +33,7 @@ use crate::tests::SessionManagerBuilder;
 pub fn create_query_context() -> Result<Arc<QueryContext>> {
     let sessions = SessionManagerBuilder::create().build()?;
     let dummy_session = sessions.create_session("TestSession")?;
-
-    // Set user.
-    let user_info = UserInfo::new(
-        "test_user".to_string(),
-        "%".to_string(),
-        Vec::from("pass"),
-        PasswordType::Sha256,
-    );
-    dummy_session.set_current_user(user_info);
+    dummy_session.set_current_user(create_test_user());
 
     let context = QueryContext::from_shared(QueryContextShared::try_create(
         sessions.get_conf().clone(),
@@ -56,6 +48,7 @@ pub fn create_query_context() -> Result<Arc<QueryContext>> {
 pub fn create_query_context_with_config(config: Config) -> Result<Arc<QueryContext>> {
     let sessions = SessionManagerBuilder::create().build()?;
     let dummy_session = sessions.create_session("TestSession")?;
+    dummy_session.set_current_user(create_test_user());
 
     let context = QueryContext::from_shared(QueryContextShared::try_create(
         config,
@@ -67,6 +60,15 @@ pub fn create_query_context_with_config(config: Config) -> Result<Arc<QueryConte
     Ok(context)
 }
 
+fn create_test_user() -> UserInfo {
+    UserInfo::new(
+        "test_user".to_string(),
+        "%".to_string(),
+        Vec::from("pass"),
+        PasswordType::Sha256,
+    )
+}
+
 #[allow(dead_code)]
 pub fn create_catalog_context() -> Result<CatalogContext> {
     let meta_embedded = futures::executor::block_on(MetaEmbedded::new_temp()).unwrap();
@@ -105,6 +105,12 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::inner_create(new_config)
     }
 
+    pub fn jwt_key_url(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.jwt_key_url = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
     pub fn disk_storage_path(self, path: String) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.storage.disk.data_path = path;
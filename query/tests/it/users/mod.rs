@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod user_cache;
+mod user_connection;
 mod user_mgr;
+mod user_ownership;
+mod user_password;
 mod user_stage;
 mod user_udf;
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordType;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+use databend_query::configs::Config;
+use databend_query::users::RowAccessPolicyCache;
+use databend_query::users::User;
+use databend_query::users::UserApiProvider;
+use databend_query::users::UserInfoCache;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_user_info_cache() -> Result<()> {
+    let user: UserInfo = User::new("u1", "%", "", PasswordType::None).into();
+
+    // Cached by host, and invalidated on all the hosts.
+    {
+        let cache = UserInfoCache::create(Duration::from_secs(60));
+        assert_eq!(None, cache.get("u1", "%"));
+
+        cache.insert("u1", "%", user.clone(), cache.generation());
+        cache.insert("u1", "localhost", user.clone(), cache.generation());
+        assert_eq!(Some(user.clone()), cache.get("u1", "%"));
+        assert_eq!(Some(user.clone()), cache.get("u1", "localhost"));
+
+        cache.invalidate("u1");
+        assert_eq!(None, cache.get("u1", "%"));
+        assert_eq!(None, cache.get("u1", "localhost"));
+    }
+
+    // A user info fetched before an invalidation is not cached.
+    {
+        let cache = UserInfoCache::create(Duration::from_secs(60));
+        let generation = cache.generation();
+        cache.invalidate("u1");
+        cache.insert("u1", "%", user.clone(), generation);
+        assert_eq!(None, cache.get("u1", "%"));
+    }
+
+    // Zero ttl disables the cache.
+    {
+        let cache = UserInfoCache::create(Duration::from_secs(0));
+        cache.insert("u1", "%", user.clone(), cache.generation());
+        assert_eq!(None, cache.get("u1", "%"));
+    }
+
+    // Expired after the ttl, a change made on another node is seen then. Still peeked.
+    {
+        let cache = UserInfoCache::create(Duration::from_millis(10));
+        cache.insert("u1", "%", user.clone(), cache.generation());
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(None, cache.get("u1", "%"));
+        assert_eq!(Some(user), cache.peek("u1", "%"));
+        assert_eq!(None, cache.peek("u1", "localhost"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_row_access_policy_cache() -> Result<()> {
+    let policies = vec![RowAccessPolicy::new(
+        "eu_only",
+        "db1",
+        "sales",
+        "region = 'eu'",
+        vec![],
+        vec![],
+    )];
+
+    let cache = RowAccessPolicyCache::create(Duration::from_secs(60));
+    assert_eq!(None, cache.get());
+    cache.insert(policies.clone(), cache.generation());
+    assert_eq!(Some(policies.clone()), cache.get());
+    cache.invalidate();
+    assert_eq!(None, cache.get());
+
+    // The policies fetched before an invalidation are not cached.
+    let generation = cache.generation();
+    cache.invalidate();
+    cache.insert(policies, generation);
+    assert_eq!(None, cache.get());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_cache_invalidation() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant_id = "tenant_user_cache".to_string();
+    let user_mgr = UserApiProvider::create_global(config).await?;
+
+    let (username, hostname) = ("cached_user", "%");
+    let user_info = User::new(username, hostname, "pwd", PasswordType::PlainText);
+    user_mgr.add_user(user_info.into()).await?;
+
+    assert_eq!(
+        None,
+        user_mgr.get_cached_password_type(username, "127.0.0.1:3307")
+    );
+    let user = user_mgr.get_user(username, hostname).await?;
+    assert_eq!(
+        Some(user),
+        user_mgr.get_user_cache().get(username, hostname)
+    );
+    // The MySQL handler picks the auth plugin by it, the client host falls back to '%'.
+    assert_eq!(
+        Some(PasswordType::PlainText),
+        user_mgr.get_cached_password_type(username, "127.0.0.1:3307")
+    );
+
+    // Grant.
+    {
+        let mut privileges = UserPrivilegeSet::empty();
+        privileges.set_privilege(UserPrivilegeType::Select);
+        user_mgr
+            .grant_user_privileges(username, hostname, GrantObject::Global, privileges)
+            .await?;
+        assert_eq!(None, user_mgr.get_user_cache().get(username, hostname));
+
+        let user = user_mgr.get_user(username, hostname).await?;
+        assert!(user
+            .grants
+            .verify_global_privilege(username, hostname, UserPrivilegeType::Select));
+    }
+
+    // Revoke.
+    {
+        let mut privileges = UserPrivilegeSet::empty();
+        privileges.set_privilege(UserPrivilegeType::Select);
+        user_mgr
+            .revoke_user_privileges(username, hostname, GrantObject::Global, privileges)
+            .await?;
+
+        let user = user_mgr.get_user(username, hostname).await?;
+        assert!(!user.grants.verify_global_privilege(
+            username,
+            hostname,
+            UserPrivilegeType::Select
+        ));
+    }
+
+    // Drop.
+    {
+        user_mgr.drop_user(username, hostname, false).await?;
+        let res = user_mgr.get_user(username, hostname).await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+use databend_query::configs::Config;
+use databend_query::users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+fn s3_connection(name: &str) -> ConnectionInfo {
+    let credentials = BTreeMap::from([
+        ("access_key_id".to_string(), "AKIA".to_string()),
+        ("secret_access_key".to_string(), "secret".to_string()),
+    ]);
+    ConnectionInfo::new(name, "S3", BTreeMap::new(), credentials, "")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_connection() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant_id = "tenant_connection".to_string();
+    config.query.connection_encryption_key = "the key".to_string();
+    let user_mgr = UserApiProvider::create_global(config).await?;
+
+    let connection = s3_connection("s3_conn");
+    user_mgr.add_connection(connection.clone()).await?;
+
+    // The credentials are encrypted at rest.
+    {
+        let stored = user_mgr.get_encrypted_connection("s3_conn").await?;
+        assert_eq!("s3", stored.storage_type);
+        assert_eq!(2, stored.credentials.len());
+        assert_ne!(connection.credentials, stored.credentials);
+    }
+
+    // And decrypted when the connection is used.
+    {
+        let decrypted = user_mgr.get_connection("s3_conn").await?;
+        assert_eq!(connection, decrypted);
+    }
+
+    // Another key can't decrypt them.
+    {
+        let mut config = Config::default();
+        config.query.tenant_id = "tenant_connection".to_string();
+        config.query.connection_encryption_key = "another key".to_string();
+        let other_mgr = UserApiProvider::create_global(config).await?;
+        let res = other_mgr.get_connection("s3_conn").await;
+        assert_eq!(4123, res.unwrap_err().code());
+    }
+
+    // Drop.
+    {
+        user_mgr.drop_connection("s3_conn", false).await?;
+        user_mgr.drop_connection("s3_conn", true).await?;
+        let res = user_mgr.drop_connection("s3_conn", false).await;
+        assert_eq!(4120, res.unwrap_err().code());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_connection_without_key() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant_id = "tenant_connection_without_key".to_string();
+    let user_mgr = UserApiProvider::create_global(config).await?;
+
+    let res = user_mgr.add_connection(s3_connection("s3_conn")).await;
+    assert_eq!(2301, res.unwrap_err().code());
+
+    Ok(())
+}
@@ -68,6 +68,19 @@ async fn test_user_manager() -> Result<()> {
         assert_eq!(pwd.as_bytes(), user.password);
     }
 
+    // get user by client address, the user of the host first.
+    {
+        let user1 = user_mgr
+            .get_user_with_client_address(user, "localhost")
+            .await?;
+        assert_eq!(hostname, user1.hostname);
+
+        let user2 = user_mgr
+            .get_user_with_client_address(user, "10.0.0.1:3307")
+            .await?;
+        assert_eq!(hostname2, user2.hostname);
+    }
+
     // drop.
     {
         user_mgr.drop_user(user, hostname, false).await?;
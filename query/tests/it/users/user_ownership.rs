@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::OwnershipObject;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+use databend_query::configs::Config;
+use databend_query::users::User;
+use databend_query::users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_ownership() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant_id = "tenant_ownership".to_string();
+    let user_mgr = UserApiProvider::create_global(config).await?;
+
+    let owner: UserInfo = User::new("owner", "%", "pwd", PasswordType::PlainText).into();
+    let other: UserInfo = User::new("other", "%", "pwd", PasswordType::PlainText).into();
+    user_mgr.add_user(owner.clone()).await?;
+    user_mgr.add_user(other.clone()).await?;
+
+    let table = OwnershipObject::Table("db1".to_string(), "t1".to_string());
+
+    // Objects without owner are not checked.
+    user_mgr
+        .verify_ownership(&table, Some(&other), UserPrivilegeType::Drop)
+        .await?;
+
+    // The owner is granted all the privileges on the table.
+    {
+        user_mgr
+            .grant_ownership(&table, &owner.identity(), false)
+            .await?;
+        assert_eq!(
+            user_mgr.get_object_owner(&table).await?,
+            Some(owner.identity())
+        );
+
+        let user = user_mgr.get_user("owner", "%").await?;
+        assert!(user.grants.verify_table_privilege(
+            "owner",
+            "%",
+            "db1",
+            "t1",
+            UserPrivilegeType::Drop
+        ));
+    }
+
+    // An existing owner is kept with if_absent.
+    {
+        user_mgr
+            .grant_ownership(&table, &other.identity(), true)
+            .await?;
+        assert_eq!(
+            user_mgr.get_object_owner(&table).await?,
+            Some(owner.identity())
+        );
+    }
+
+    // Only the owner or a user with the global privilege passes the check.
+    {
+        user_mgr
+            .verify_ownership(&table, Some(&owner), UserPrivilegeType::Drop)
+            .await?;
+
+        let res = user_mgr
+            .verify_ownership(&table, Some(&other), UserPrivilegeType::Drop)
+            .await;
+        assert_eq!(res.unwrap_err().code(), 62);
+
+        let res = user_mgr
+            .verify_ownership(&table, None, UserPrivilegeType::Drop)
+            .await;
+        assert!(res.is_err());
+
+        let mut privileges = UserPrivilegeSet::empty();
+        privileges.set_privilege(UserPrivilegeType::Drop);
+        user_mgr
+            .grant_user_privileges("other", "%", GrantObject::Global, privileges)
+            .await?;
+        let other = user_mgr.get_user("other", "%").await?;
+        user_mgr
+            .verify_ownership(&table, Some(&other), UserPrivilegeType::Drop)
+            .await?;
+    }
+
+    // Dropping the database drops the ownership of its tables.
+    {
+        let db = OwnershipObject::Database("db1".to_string());
+        user_mgr
+            .grant_ownership(&db, &owner.identity(), false)
+            .await?;
+        user_mgr.drop_object_owner(&db).await?;
+        assert_eq!(user_mgr.get_object_owner(&db).await?, None);
+        assert_eq!(user_mgr.get_object_owner(&table).await?, None);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use databend_query::configs::Config;
+use databend_query::users::encode_password;
+use databend_query::users::verify_caching_sha2_scramble;
+use databend_query::users::verify_pbkdf2_sha256;
+use databend_query::users::CertifiedInfo;
+use databend_query::users::User;
+use databend_query::users::UserApiProvider;
+use pretty_assertions::assert_eq;
+use sha2::Digest;
+
+#[test]
+fn test_pbkdf2_sha256() -> Result<()> {
+    // PBKDF2-HMAC-SHA256 test vectors
+    let cases = vec![
+        (
+            1,
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b",
+        ),
+        (
+            2,
+            "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43",
+        ),
+        (
+            4096,
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a",
+        ),
+    ];
+
+    for (iterations, expected) in cases {
+        let hash = (0..expected.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&expected[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+        let encoded = format!(
+            "pbkdf2_sha256${}${}${}",
+            iterations,
+            base64::encode("salt"),
+            base64::encode(hash)
+        );
+        assert!(verify_pbkdf2_sha256(encoded.as_bytes(), b"password"));
+        assert!(!verify_pbkdf2_sha256(encoded.as_bytes(), b"passwore"));
+    }
+
+    // malformed
+    assert!(!verify_pbkdf2_sha256(b"password", b"password"));
+    assert!(!verify_pbkdf2_sha256(
+        b"pbkdf2_sha256$x$c2FsdA==$",
+        b"password"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_encode_password() -> Result<()> {
+    let encoded = encode_password(&PasswordType::Pbkdf2Sha256, b"password");
+    assert!(String::from_utf8_lossy(&encoded).starts_with("pbkdf2_sha256$10000$"));
+    assert!(verify_pbkdf2_sha256(&encoded, b"password"));
+    assert!(!verify_pbkdf2_sha256(&encoded, b"password1"));
+
+    // salted
+    let encoded2 = encode_password(&PasswordType::Pbkdf2Sha256, b"password");
+    assert_ne!(encoded, encoded2);
+
+    // the digests the logins are verified against
+    assert_eq!(
+        encode_password(&PasswordType::Sha256, b"password"),
+        sha2::Sha256::digest(b"password").to_vec()
+    );
+    let mut m = sha1::Sha1::new();
+    m.update(&sha1::Sha1::from(b"password").digest().bytes());
+    assert_eq!(
+        encode_password(&PasswordType::DoubleSha1, b"password"),
+        m.digest().bytes().to_vec()
+    );
+
+    // the plain text passwords are kept as given
+    assert_eq!(
+        encode_password(&PasswordType::PlainText, b"password"),
+        b"password".to_vec()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_verify_caching_sha2_scramble() -> Result<()> {
+    let salt = b"01234567890123456789";
+    let stage1 = sha2::Sha256::digest(b"password");
+    let stage2 = sha2::Sha256::digest(&stage1);
+    let mask = sha2::Sha256::digest(&[stage2.as_slice(), salt].concat());
+    let scramble = stage1
+        .iter()
+        .zip(mask.iter())
+        .map(|(a, b)| a ^ b)
+        .collect::<Vec<_>>();
+
+    assert!(verify_caching_sha2_scramble(&stage1, salt, &scramble));
+    assert!(!verify_caching_sha2_scramble(
+        &stage1,
+        b"98765432109876543210",
+        &scramble
+    ));
+    assert!(!verify_caching_sha2_scramble(
+        &sha2::Sha256::digest(b"password1"),
+        salt,
+        &scramble
+    ));
+    assert!(!verify_caching_sha2_scramble(&stage1, salt, &scramble[1..]));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rehash_password_on_login() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant_id = "tenant1".to_string();
+    config.query.user_password_rehash_on_login = true;
+    let user_mgr = UserApiProvider::create_global(config).await?;
+
+    let (user, hostname) = ("test-rehash", "%");
+    let user_info: UserInfo = User::new(user, hostname, "password", PasswordType::PlainText).into();
+    user_mgr.add_user(user_info.clone()).await?;
+
+    // wrong password, not migrated
+    let info = CertifiedInfo::create(user, "password1", "127.0.0.1");
+    assert!(!user_mgr.auth_user(user_info.clone(), info).await?);
+    let old_user = user_mgr.get_user(user, hostname).await?;
+    assert_eq!(old_user.password_type, PasswordType::PlainText);
+
+    // migrated once authed
+    let info = CertifiedInfo::create(user, "password", "127.0.0.1");
+    assert!(user_mgr.auth_user(user_info, info).await?);
+    let new_user = user_mgr.get_user(user, hostname).await?;
+    assert_eq!(new_user.password_type, PasswordType::Pbkdf2Sha256);
+    assert!(verify_pbkdf2_sha256(&new_user.password, b"password"));
+
+    let info = CertifiedInfo::create(user, "password", "127.0.0.1");
+    assert!(user_mgr.auth_user(new_user, info).await?);
+
+    // the sha256 passwords are kept for the MySQL clients
+    let (user, hostname) = ("test-rehash-sha256", "%");
+    let mut user_info: UserInfo = User::new(user, hostname, "", PasswordType::Sha256).into();
+    user_info.password = sha2::Sha256::digest(b"password").to_vec();
+    user_mgr.add_user(user_info.clone()).await?;
+
+    let info = CertifiedInfo::create(user, "password", "127.0.0.1");
+    assert!(user_mgr.auth_user(user_info, info).await?);
+    let old_user = user_mgr.get_user(user, hostname).await?;
+    assert_eq!(old_user.password_type, PasswordType::Sha256);
+
+    Ok(())
+}
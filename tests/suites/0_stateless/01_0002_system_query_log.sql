@@ -1,2 +1,5 @@
 select * from numbers(100) where number > 95;
 select count(*) > 0 from system.query_log;
+SET error_on_division_by_zero = 1;
+SELECT 10 % number FROM numbers(3); -- {ErrorCode 6}
+SELECT exception_code, query_duration_ms >= 0 FROM system.query_log WHERE log_type = 3 AND query_text LIKE 'SELECT 10 %' LIMIT 1;
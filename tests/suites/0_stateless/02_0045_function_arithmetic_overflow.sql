@@ -0,0 +1,13 @@
+SELECT toInt64(9223372036854775807) + 1;
+SET numeric_overflow_mode = 'error';
+SELECT toInt64(9223372036854775806) + 1;
+SELECT toInt64(9223372036854775807) + 1; -- {ErrorCode 49}
+SELECT toInt64(9223372036854775807) * 2; -- {ErrorCode 49}
+SELECT toInt32(2147483647) + toInt32(2147483647);
+SELECT sum(number) FROM numbers(10);
+SET numeric_overflow_mode = 'saturate';
+SELECT toInt64(9223372036854775807) + 1, toUInt64(18446744073709551615) * 2;
+SET numeric_overflow_mode = 'promote';
+SELECT toTypeName(toInt64(3) * 2), toInt64(3) * 2;
+SELECT toTypeName(sum(number)), sum(number) FROM numbers(10);
+SET numeric_overflow_mode = 'clamp'; -- {ErrorCode 6}
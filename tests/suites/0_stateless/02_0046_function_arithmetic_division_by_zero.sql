@@ -0,0 +1,11 @@
+SELECT 1 / 0, div(1, 0), 1 % 0, mod(1, 0);
+SELECT number, 10 / number, div(10, number), 10 % number FROM numbers(3) ORDER BY number;
+SET div_precision_increment = 4;
+SELECT 1 / 3, 10 / 4;
+SET div_precision_increment = 0;
+SET error_on_division_by_zero = 1;
+SELECT 1 / 0; -- {ErrorCode 6}
+SELECT div(1, 0); -- {ErrorCode 6}
+SELECT 10 % number FROM numbers(3); -- {ErrorCode 6}
+SELECT 10 / 4;
+SET div_precision_increment = 31; -- {ErrorCode 6}
@@ -0,0 +1,4 @@
+SELECT cosine_distance((1, 0), (0, 2)), cosine_distance((1, 0), (3, 0)), cosine_distance((0, 0), (1, 1));
+SELECT l2_distance((0, 0), (3, 4)), l2_distance((1, 2, 3), (1, 2, 3));
+SELECT number, l2_distance((number, 0), (0, 0)) AS d FROM numbers(100) ORDER BY d DESC LIMIT 3;
+SELECT l2_distance((1, 2), (1, 2, 3)); -- {ErrorCode 6}
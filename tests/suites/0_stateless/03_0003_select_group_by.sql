@@ -14,3 +14,7 @@ SELECT count(1), name FROM system.credits WHERE name='ahash' GROUP BY name;
 
 SELECT 'GROUP BY Strings';
 SELECT a,b,count() from (SELECT cast((number%4) AS bigint) as a, cast((number%20) AS bigint) as b from numbers(100)) group by a,b order by a,b limit 3 ;
+
+SELECT 'GROUP BY spill';
+SET group_by_max_memory_bytes=1;
+SELECT number%3 AS c, count(), sum(number) FROM numbers(1000) GROUP BY c ORDER BY c;
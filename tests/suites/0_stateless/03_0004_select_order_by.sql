@@ -3,3 +3,7 @@ SELECT number, number + 3 FROM numbers_mt (1000) where number > 5 order by numbe
 SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1 desc, c2 asc;
 EXPLAIN SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1, number desc;
 SELECT number%3 as c1, number%2 as c2 FROM numbers_mt (10) order by c1, number desc;
+set max_block_size = 10;
+set sort_max_memory_bytes = 1;
+SELECT number%7 as c, number FROM numbers_mt (100) order by c desc, number limit 3;
+SELECT number FROM numbers_mt (1000) order by number % 10, number desc limit 2 offset 99;
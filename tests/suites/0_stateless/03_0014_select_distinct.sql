@@ -0,0 +1,6 @@
+SELECT DISTINCT number % 3 AS n FROM numbers(100) ORDER BY n;
+SELECT DISTINCT number % 3 AS n, number % 2 AS m FROM numbers_mt(100) ORDER BY n, m;
+SELECT count() FROM (SELECT DISTINCT number % 7 FROM numbers_mt(10000));
+SELECT DISTINCT number % 3 AS n FROM numbers(100) ORDER BY n LIMIT 2;
+SELECT DISTINCT number % 3 AS n FROM numbers(100) ORDER BY n DESC LIMIT 1 OFFSET 1;
+SELECT count() FROM (SELECT DISTINCT number % 5 FROM numbers(100) LIMIT 3);
@@ -0,0 +1,9 @@
+SET enable_distinct_aggregate_rewrite = 1;
+select count(distinct number), sum(distinct number), min(distinct number), max(distinct number), avg(distinct number) from numbers(10);
+select number % 3 as n, count(distinct number), sum(distinct number) from numbers(10) group by n order by n;
+select count(distinct number) from numbers(10) where 1 = 2;
+select count(distinct number), count() from numbers(10);
+
+SET enable_distinct_aggregate_rewrite = 0;
+select count(distinct number), sum(distinct number), min(distinct number), max(distinct number), avg(distinct number) from numbers(10);
+select number % 3 as n, count(distinct number), sum(distinct number) from numbers(10) group by n order by n;
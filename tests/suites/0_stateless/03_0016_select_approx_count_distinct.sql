@@ -0,0 +1,7 @@
+select approx_count_distinct(number) >= 990 and approx_count_distinct(number) <= 1010 from numbers(1000);
+select approx_count_distinct(number, number + 1) >= 990 and approx_count_distinct(number, number + 1) <= 1010 from numbers(1000);
+select approx_count_distinct(number) from numbers(10) where 1 = 2;
+
+SET approx_count_distinct_min_rows = 1000;
+select count(distinct number) >= 95000 and count(distinct number) <= 105000 from numbers(100000);
+select count(distinct number) from numbers(10);
@@ -0,0 +1,10 @@
+SELECT number FROM numbers_mt(10) WHERE 1 = 2;
+SELECT number FROM numbers_mt(10) ORDER BY number LIMIT 0;
+SELECT number % 3 AS c, count() FROM numbers_mt(10) WHERE 1 = 2 GROUP BY c;
+SELECT count() FROM numbers_mt(10) WHERE 1 = 2;
+
+CREATE TABLE t_empty_result(a Int32, b Int32) Engine = Fuse;
+SELECT a FROM t_empty_result WHERE b > 10;
+INSERT INTO t_empty_result VALUES(1, 20);
+SELECT a FROM t_empty_result WHERE b > 10;
+DROP TABLE t_empty_result;
@@ -0,0 +1,14 @@
+CREATE TABLE t_join_left(id UInt64, a String) Engine = Memory;
+CREATE TABLE t_join_right(id UInt64, b String) Engine = Memory;
+INSERT INTO t_join_left VALUES(1, 'x'), (2, 'y'), (3, 'z');
+INSERT INTO t_join_right VALUES(2, 'p'), (3, 'q'), (3, 'r'), (4, 's');
+
+SELECT l.id, a, b FROM t_join_left AS l JOIN t_join_right AS r ON l.id = r.id ORDER BY l.id, b;
+SELECT l.id, a, b FROM t_join_left AS l LEFT JOIN t_join_right AS r ON l.id = r.id ORDER BY l.id, b;
+SELECT r.id, a, b FROM t_join_left AS l RIGHT JOIN t_join_right AS r ON r.id = l.id ORDER BY r.id, b;
+SELECT l.id, r.id FROM t_join_left AS l FULL JOIN t_join_right AS r ON l.id = r.id ORDER BY l.id, r.id;
+SELECT count(), sum(number) FROM numbers(10) AS n JOIN t_join_right AS r ON n.number = r.id;
+SELECT count() FROM t_join_left AS l JOIN t_join_right AS m ON l.id = m.id JOIN t_join_right AS r ON m.id = r.id;
+
+DROP TABLE t_join_left;
+DROP TABLE t_join_right;
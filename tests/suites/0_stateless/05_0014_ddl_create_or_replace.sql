@@ -0,0 +1,16 @@
+DROP TABLE IF EXISTS t;
+
+CREATE OR REPLACE TABLE t(a int) ENGINE = Null;
+DESCRIBE t;
+CREATE OR REPLACE TABLE t(a int, b varchar) ENGINE = Null;
+DESCRIBE t;
+SELECT COUNT(1) FROM system.tables WHERE name = 't' AND database = 'default';
+CREATE OR REPLACE TABLE IF NOT EXISTS t(a int) ENGINE = Null; -- {ErrorCode 5}
+CREATE TABLE t(a int) ENGINE = Null; -- {ErrorCode 4003}
+
+DROP TABLE t;
+
+CREATE OR REPLACE FUNCTION cr_udf AS (p) -> not(isnull(p));
+CREATE OR REPLACE FUNCTION cr_udf AS (p) -> isnull(p);
+SELECT cr_udf(NULL);
+DROP FUNCTION cr_udf;
@@ -0,0 +1,10 @@
+SHOW ENGINES;
+
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(a Int32) ENGINE = Memory;
+INSERT INTO t VALUES(1), (2);
+DELETE FROM t WHERE a = 1; -- {ErrorCode 8004}
+SELECT a FROM t AT (SNAPSHOT => 'xxx'); -- {ErrorCode 8004}
+TRUNCATE TABLE t;
+SELECT COUNT(*) FROM t;
+DROP TABLE t;
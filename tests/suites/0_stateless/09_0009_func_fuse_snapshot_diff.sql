@@ -0,0 +1,20 @@
+DROP DATABASE IF EXISTS db_09_0009;
+CREATE DATABASE db_09_0009;
+USE db_09_0009;
+
+create table t(a uint64);
+
+insert into t values (1);
+
+-- unknown snapshots
+select * from fuse_snapshot_diff('db_09_0009', 't', 'not_exist', 'not_exist'); -- {ErrorCode 6}
+
+-- missing arguments
+select * from fuse_snapshot_diff('db_09_0009', 't'); -- {ErrorCode 6}
+
+-- unknown objects
+select * from fuse_snapshot_diff('db_09_0009', 'not_exist', 'a', 'b'); -- {ErrorCode 25}
+select * from fuse_snapshot_diff('not_exist', 'not_exist', 'a', 'b'); -- {ErrorCode 3}
+
+DROP TABLE t;
+DROP DATABASE db_09_0009;
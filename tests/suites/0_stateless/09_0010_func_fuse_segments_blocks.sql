@@ -0,0 +1,27 @@
+DROP DATABASE IF EXISTS db_09_0010;
+CREATE DATABASE db_09_0010;
+USE db_09_0010;
+
+create table t(a uint64, b varchar);
+
+insert into t values (1, 'a'), (3, 'c');
+insert into t values (2, 'b');
+
+-- expects 2 snapshots
+select count(*) from fuse_snapshots('db_09_0010', 't');
+
+-- expects 2 segments, 3 rows
+select count(*), sum(block_count), sum(row_count) from fuse_segments('db_09_0010', 't');
+
+-- the min and max of each column of each block
+select column_name, min, max, row_count from fuse_blocks('db_09_0010', 't') order by column_name, min;
+
+-- missing arguments
+select * from fuse_blocks('db_09_0010'); -- {ErrorCode 6}
+
+-- unknown objects
+select * from fuse_segments('db_09_0010', 'not_exist'); -- {ErrorCode 25}
+select * from fuse_blocks('not_exist', 'not_exist'); -- {ErrorCode 3}
+
+DROP TABLE t;
+DROP DATABASE db_09_0010;